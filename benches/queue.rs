@@ -0,0 +1,149 @@
+#[macro_use]
+extern crate criterion;
+
+use std::fs::{create_dir_all, remove_dir_all};
+use std::io::ErrorKind;
+use std::sync::mpsc::channel;
+use std::thread;
+
+use criterion::{BatchSize, Criterion};
+
+use brqueue::internal_queue_file_manager::InternalQueueFileManager;
+use brqueue::models::{Priority, QueueItem, Tags};
+use brqueue::queue_server::QueueServer;
+
+// Gives every benchmark a clean storage folder, and returns the filename prefix to use in it
+fn setup_storage(name: &str) -> String {
+    let folder = format!("bench_storage/{}/", name);
+    match remove_dir_all(&folder) {
+        Ok(()) => {}
+        Err(ref e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => panic!("Failed to clean bench storage: {}", e),
+    }
+    create_dir_all(&folder).expect("Failed to create bench storage");
+    format!("{}tasks", folder)
+}
+
+fn new_item() -> QueueItem<Vec<u8>> {
+    QueueItem::new(vec![0; 64], Tags::from(vec!["foo".to_string()]), Priority::High)
+}
+
+fn enqueue_throughput(c: &mut Criterion) {
+    c.bench_function("save item with flush", |b| {
+        let file_manager = InternalQueueFileManager::new(setup_storage("save_with_flush"), true)
+            .expect("Failed to create file manager");
+        let item = new_item();
+        b.iter(|| file_manager.save_item(&item).expect("Failed to save item"))
+    });
+
+    c.bench_function("save item without flush", |b| {
+        let file_manager = InternalQueueFileManager::new(setup_storage("save_without_flush"), false)
+            .expect("Failed to create file manager");
+        let item = new_item();
+        b.iter(|| file_manager.save_item(&item).expect("Failed to save item"))
+    });
+
+    c.bench_function("enqueue batch of 100", |b| {
+        let mut qs = QueueServer::new_with_filename(setup_storage("enqueue_batch"))
+            .expect("Failed to create queue server");
+        b.iter(|| {
+            let batch = (0..100)
+                .map(|_| (vec![0u8; 64], Priority::High, vec!["foo".to_string()]))
+                .collect();
+            qs.enqueue_batch(batch).expect("Failed to enqueue batch")
+        })
+    });
+
+    c.bench_function("enqueue", |b| {
+        let mut qs = QueueServer::new_with_filename(setup_storage("enqueue"))
+            .expect("Failed to create queue server");
+        b.iter(|| {
+            qs.enqueue(vec![0u8; 64], Priority::High, vec!["foo".to_string()])
+                .expect("Failed to enqueue")
+        })
+    });
+}
+
+fn pop_latency(c: &mut Criterion) {
+    c.bench_function("pop", |b| {
+        let mut qs = QueueServer::new_with_filename(setup_storage("pop"))
+            .expect("Failed to create queue server");
+        let mut producer = qs.clone();
+        // Only the pop itself is measured, the item is enqueued while setting up
+        b.iter_batched(
+            || {
+                producer.enqueue(vec![0u8; 64], Priority::High, vec!["foo".to_string()])
+                    .expect("Failed to enqueue");
+            },
+            |_| qs.pop(vec!["foo".to_string()], false).expect("Failed to pop"),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+// Measures how long it takes from an item is enqueued, until a worker
+// that is already waiting for it has it
+fn waiting_pop_wakeup(c: &mut Criterion) {
+    c.bench_function("waiting pop wakeup", |b| {
+        let mut qs = QueueServer::new_with_filename(setup_storage("waiting_pop"))
+            .expect("Failed to create queue server");
+        let mut worker = qs.clone();
+        let (popped_sender, popped) = channel();
+        let (stop_sender, stop) = channel();
+
+        let handle = thread::spawn(move || {
+            while stop.try_recv().is_err() {
+                let item = worker.pop(vec!["foo".to_string()], true).expect("Failed to pop");
+                popped_sender.send(item).expect("Failed to report popped item");
+            }
+        });
+
+        b.iter(|| {
+            qs.enqueue(vec![0u8; 64], Priority::High, vec!["foo".to_string()])
+                .expect("Failed to enqueue");
+            popped.recv().expect("Worker stopped")
+        });
+
+        // Wake the worker one last time, so it sees it should stop
+        stop_sender.send(()).expect("Failed to stop worker");
+        qs.enqueue(vec![0u8; 64], Priority::High, vec!["foo".to_string()])
+            .expect("Failed to enqueue");
+        handle.join().expect("Worker panicked");
+    });
+}
+
+fn garbage_collection(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "garbage collection",
+        |b, &&item_count| {
+            let prefix = setup_storage(&format!("gc_{}", item_count));
+            b.iter_batched(
+                || {
+                    let _ = remove_dir_all(format!("bench_storage/gc_{}/", item_count));
+                    let file_manager = InternalQueueFileManager::new(prefix.clone(), false)
+                        .expect("Failed to create file manager");
+                    // Half the items are completed, so garbage collection has something to remove
+                    for i in 0..item_count {
+                        let item = QueueItem::new(vec![0u8; 64], Tags::new(), Priority::High);
+                        file_manager.save_item(&item).expect("Failed to save item");
+                        if i % 2 == 0 {
+                            file_manager.mark_as_completed(&item.id).expect("Failed to complete item");
+                        }
+                    }
+                    file_manager
+                },
+                |mut file_manager| file_manager.run_garbage_collection().expect("Failed to collect garbage"),
+                BatchSize::PerIteration,
+            )
+        },
+        &[1000, 10000, 100000],
+    );
+}
+
+criterion_group!(benches, enqueue_throughput, pop_latency, waiting_pop_wakeup);
+criterion_group! {
+    name = slow_benches;
+    config = Criterion::default().sample_size(10);
+    targets = garbage_collection
+}
+criterion_main!(benches, slow_benches);
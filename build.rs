@@ -1,6 +1,7 @@
 extern crate protoc_rust;
 
 use std::fs;
+use std::process::Command;
 
 use protoc_rust::Customize;
 
@@ -15,6 +16,23 @@ fn main() {
     })
         .expect("protoc generation failed");
 
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/proto/queue.proto");
+
+    // Make the commit the server was built from available to the server info,
+    // if we are building from a git checkout
+    if let Some(commit) = git(&["rev-parse", "HEAD"]) {
+        println!("cargo:rustc-env=BRQUEUE_GIT_COMMIT={}", commit);
+
+        // Rebuild when HEAD moves, either to another branch or because the
+        // branch it points to got a new commit
+        rerun_if_git_path_changed("HEAD");
+        if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+            rerun_if_git_path_changed(&head_ref);
+            rerun_if_git_path_changed("packed-refs");
+        }
+    }
+
     // Remove test storage as these will be regenerated every time we
     // run the tests
     match fs::remove_dir_all("test_storage") {
@@ -23,3 +41,18 @@ fn main() {
         _ => {},
     }
 }
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn rerun_if_git_path_changed(path: &str) {
+    if let Some(path) = git(&["rev-parse", "--git-path", path]) {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+}
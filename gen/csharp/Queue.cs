@@ -2,7 +2,7 @@
 //     Generated by the protocol buffer compiler.  DO NOT EDIT!
 //     source: queue.proto
 // </auto-generated>
-#pragma warning disable 1591, 0612, 3021
+#pragma warning disable 1591, 0612, 3021, 8981
 #region Designer generated code
 
 using pb = global::Google.Protobuf;
@@ -24,42 +24,258 @@ namespace brqueue {
     static QueueReflection() {
       byte[] descriptorData = global::System.Convert.FromBase64String(
           string.Concat(
-            "CgtxdWV1ZS5wcm90byI5ChNBdXRoZW50aWNhdGVSZXF1ZXN0EhAKCHVzZXJu",
-            "YW1lGAEgASgJEhAKCHBhc3N3b3JkGAIgASgJIicKFEF1dGhlbnRpY2F0ZVJl",
-            "c3BvbnNlEg8KB3N1Y2Nlc3MYASABKAgiXAoORW5xdWV1ZVJlcXVlc3QSDwoH",
+            "CgtxdWV1ZS5wcm90byJPChNBdXRoZW50aWNhdGVSZXF1ZXN0EhAKCHVzZXJu",
+            "YW1lGAEgASgJEhAKCHBhc3N3b3JkGAIgASgJEhQKDHNlc3Npb25Ub2tlbhgD",
+            "IAEoCSI9ChRBdXRoZW50aWNhdGVSZXNwb25zZRIPCgdzdWNjZXNzGAEgASgI",
+            "EhQKDHNlc3Npb25Ub2tlbhgCIAEoCSLiAQoORW5xdWV1ZVJlcXVlc3QSDwoH",
             "bWVzc2FnZRgBIAEoDBIbCghwcmlvcml0eRgCIAEoDjIJLlByaW9yaXR5EhwK",
-            "FHJlcXVpcmVkQ2FwYWJpbGl0aWVzGAMgAygJIh0KD0VucXVldWVSZXNwb25z",
-            "ZRIKCgJpZBgBIAEoCSJDCgpQb3BSZXF1ZXN0Eh0KFWF2YWlsYWJsZUNhcGFi",
-            "aWxpdGllcxgBIAMoCRIWCg53YWl0Rm9yTWVzc2FnZRgCIAEoCCI9CgtQb3BS",
-            "ZXNwb25zZRIRCgloYWRSZXN1bHQYAyABKAgSDwoHbWVzc2FnZRgBIAEoDBIK",
-            "CgJpZBgCIAEoCSIgChJBY2tub3dsZWRnZVJlcXVlc3QSCgoCaWQYASABKAki",
-            "FQoTQWNrbm93bGVkZ2VSZXNwb25zZSIgCg1FcnJvclJlc3BvbnNlEg8KB21l",
-            "c3NhZ2UYASABKAkixAEKDlJlcXVlc3RXcmFwcGVyEg0KBXJlZklkGAogASgF",
-            "EiIKB2VucXVldWUYASABKAsyDy5FbnF1ZXVlUmVxdWVzdEgAEhoKA3BvcBgC",
-            "IAEoCzILLlBvcFJlcXVlc3RIABIqCgthY2tub3dsZWRnZRgDIAEoCzITLkFj",
-            "a25vd2xlZGdlUmVxdWVzdEgAEiwKDGF1dGhlbnRpY2F0ZRgGIAEoCzIULkF1",
-            "dGhlbnRpY2F0ZVJlcXVlc3RIAEIJCgdtZXNzYWdlIuoBCg9SZXNwb25zZVdy",
-            "YXBwZXISDQoFcmVmSWQYCiABKAUSIwoHZW5xdWV1ZRgBIAEoCzIQLkVucXVl",
-            "dWVSZXNwb25zZUgAEhsKA3BvcBgCIAEoCzIMLlBvcFJlc3BvbnNlSAASKwoL",
-            "YWNrbm93bGVkZ2UYAyABKAsyFC5BY2tub3dsZWRnZVJlc3BvbnNlSAASHwoF",
-            "ZXJyb3IYBCABKAsyDi5FcnJvclJlc3BvbnNlSAASLQoMYXV0aGVudGljYXRl",
-            "GAYgASgLMhUuQXV0aGVudGljYXRlUmVzcG9uc2VIAEIJCgdtZXNzYWdlKh0K",
-            "CFByaW9yaXR5EgcKA0xPVxAAEggKBEhJR0gQAUITWgdicnF1ZXVlqgIHYnJx",
-            "dWV1ZWIGcHJvdG8z"));
+            "FHJlcXVpcmVkQ2FwYWJpbGl0aWVzGAMgAygJEhkKEWRlbGF5TWlsbGlzZWNv",
+            "bmRzGAQgASgEEhMKC2F2YWlsYWJsZUF0GAUgASgJEhYKDmlkZW1wb3RlbmN5",
+            "S2V5GAYgASgJEhEKCXF1ZXVlTmFtZRgHIAEoCRISCgp0dGxTZWNvbmRzGAgg",
+            "ASgEEhUKDXByaW9yaXR5TGV2ZWwYCSABKA0iPQoPRW5xdWV1ZVJlc3BvbnNl",
+            "EgoKAmlkGAEgASgJEh4KBnN0YXR1cxgCIAEoDjIOLkVucXVldWVTdGF0dXMi",
+            "RQoTRW5xdWV1ZUJhdGNoUmVxdWVzdBIeCgVpdGVtcxgBIAMoCzIPLkVucXVl",
+            "dWVSZXF1ZXN0Eg4KBmF0b21pYxgCIAEoCCJPChJFbnF1ZXVlQmF0Y2hSZXN1",
+            "bHQSCgoCaWQYASABKAkSHgoGc3RhdHVzGAIgASgOMg4uRW5xdWV1ZVN0YXR1",
+            "cxINCgVlcnJvchgDIAEoCSI8ChRFbnF1ZXVlQmF0Y2hSZXNwb25zZRIkCgdy",
+            "ZXN1bHRzGAEgAygLMhMuRW5xdWV1ZUJhdGNoUmVzdWx0IqsBChNCZWdpbkVu",
+            "cXVldWVSZXF1ZXN0EhsKCHByaW9yaXR5GAEgASgOMgkuUHJpb3JpdHkSHAoU",
+            "cmVxdWlyZWRDYXBhYmlsaXRpZXMYAiADKAkSGQoRZGVsYXlNaWxsaXNlY29u",
+            "ZHMYAyABKAQSEwoLYXZhaWxhYmxlQXQYBCABKAkSEgoKdHRsU2Vjb25kcxgF",
+            "IAEoBBIVCg1wcmlvcml0eUxldmVsGAYgASgNIioKFEJlZ2luRW5xdWV1ZVJl",
+            "c3BvbnNlEhIKCnRyYW5zZmVySWQYASABKAkiRwoTRW5xdWV1ZUNodW5rUmVx",
+            "dWVzdBISCgp0cmFuc2ZlcklkGAEgASgJEg4KBm9mZnNldBgCIAEoBBIMCgRk",
+            "YXRhGAMgASgMIigKFEVucXVldWVDaHVua1Jlc3BvbnNlEhAKCHJlY2VpdmVk",
+            "GAEgASgEIj0KFENvbW1pdEVucXVldWVSZXF1ZXN0EhIKCnRyYW5zZmVySWQY",
+            "ASABKAkSEQoJcXVldWVOYW1lGAIgASgJIiMKFUNvbW1pdEVucXVldWVSZXNw",
+            "b25zZRIKCgJpZBgBIAEoCSJ9CgpQb3BSZXF1ZXN0Eh0KFWF2YWlsYWJsZUNh",
+            "cGFiaWxpdGllcxgBIAMoCRIWCg53YWl0Rm9yTWVzc2FnZRgCIAEoCBIlCh12",
+            "aXNpYmlsaXR5VGltZW91dE1pbGxpc2Vjb25kcxgDIAEoBBIRCglxdWV1ZU5h",
+            "bWUYBCABKAkivQIKC1BvcFJlc3BvbnNlEhEKCWhhZFJlc3VsdBgDIAEoCBIP",
+            "CgdtZXNzYWdlGAEgASgMEgoKAmlkGAIgASgJEhwKFGhhc1VubWF0Y2hlZE1l",
+            "c3NhZ2VzGAQgASgIEhEKCWNhbmNlbGxlZBgFIAEoCBISCgplbnF1ZXVlZEJ5",
+            "GAYgASgJEhQKDGJhY2twcmVzc3VyZRgHIAEoCBIQCghzZXF1ZW5jZRgIIAEo",
+            "BBITCgtyYXRlTGltaXRlZBgJIAEoCBIQCghub3RSZWFkeRgKIAEoCBIlCh12",
+            "aXNpYmlsaXR5VGltZW91dE1pbGxpc2Vjb25kcxgLIAEoBBIcChRyZXF1aXJl",
+            "ZENhcGFiaWxpdGllcxgMIAMoCRIlCh1yZXF1aXJlZENhcGFiaWxpdGllc1Ry",
+            "dW5jYXRlZBgNIAEoCCJWChBTdWJzY3JpYmVSZXF1ZXN0Eh0KFWF2YWlsYWJs",
+            "ZUNhcGFiaWxpdGllcxgBIAMoCRIQCghwcmVmZXRjaBgCIAEoDRIRCglxdWV1",
+            "ZU5hbWUYAyABKAkiEwoRU3Vic2NyaWJlUmVzcG9uc2UiYwoOUmVzZXJ2ZVJl",
+            "cXVlc3QSHQoVYXZhaWxhYmxlQ2FwYWJpbGl0aWVzGAEgAygJEh8KF3Jlc2Vy",
+            "dmF0aW9uTWlsbGlzZWNvbmRzGAIgASgEEhEKCXF1ZXVlTmFtZRgDIAEoCSJE",
+            "Cg9SZXNlcnZlUmVzcG9uc2USEQoJaGFkUmVzdWx0GAEgASgIEh4KB21lc3Nh",
+            "Z2UYAiABKAsyDS5Gb3VuZE1lc3NhZ2UiOgoZQ29uZmlybVJlc2VydmF0aW9u",
+            "UmVxdWVzdBIKCgJpZBgBIAEoCRIRCglxdWV1ZU5hbWUYAiABKAkiLwoaQ29u",
+            "ZmlybVJlc2VydmF0aW9uUmVzcG9uc2USEQoJY29uZmlybWVkGAEgASgIIigK",
+            "EENhbmNlbFBvcFJlcXVlc3QSFAoMc2Vzc2lvblRva2VuGAEgASgJIiYKEUNh",
+            "bmNlbFBvcFJlc3BvbnNlEhEKCWNhbmNlbGxlZBgBIAEoCCIuCg1DYW5jZWxS",
+            "ZXF1ZXN0EgoKAmlkGAEgASgJEhEKCXF1ZXVlTmFtZRgCIAEoCSIjCg5DYW5j",
+            "ZWxSZXNwb25zZRIRCgljYW5jZWxsZWQYASABKAgiIAoSQWNrbm93bGVkZ2VS",
+            "ZXF1ZXN0EgoKAmlkGAEgASgJIi8KE0Fja25vd2xlZGdlUmVzcG9uc2USGAoQ",
+            "YWxyZWFkeUNvbXBsZXRlZBgBIAEoCCJOCgtGYWlsUmVxdWVzdBIKCgJpZBgB",
+            "IAEoCRIWCg5jaGFuZ2VQcmlvcml0eRgCIAEoCBIbCghwcmlvcml0eRgDIAEo",
+            "DjIJLlByaW9yaXR5Ig4KDEZhaWxSZXNwb25zZSIcCg5EZWNsaW5lUmVxdWVz",
+            "dBIKCgJpZBgBIAEoCSIRCg9EZWNsaW5lUmVzcG9uc2UiQAoURmFpbFdpdGhS",
+            "ZXRhZ1JlcXVlc3QSCgoCaWQYASABKAkSHAoUcmVxdWlyZWRDYXBhYmlsaXRp",
+            "ZXMYAiADKAkiFwoVRmFpbFdpdGhSZXRhZ1Jlc3BvbnNlImsKGkFja25vd2xl",
+            "ZGdlTWF0Y2hpbmdSZXF1ZXN0EhoKEnJlcXVpcmVkQ2FwYWJpbGl0eRgBIAEo",
+            "CRIeChZtaW5pbXVtQWdlTWlsbGlzZWNvbmRzGAIgASgEEhEKCXF1ZXVlTmFt",
+            "ZRgDIAEoCSIsChtBY2tub3dsZWRnZU1hdGNoaW5nUmVzcG9uc2USDQoFY291",
+            "bnQYASABKAQidAoQRmluZEJ5VGFnUmVxdWVzdBIMCgR0YWdzGAEgAygJEiAK",
+            "CW1hdGNoTW9kZRgCIAEoDjINLlRhZ01hdGNoTW9kZRIOCgZvZmZzZXQYAyAB",
+            "KAQSDQoFbGltaXQYBCABKAQSEQoJcXVldWVOYW1lGAUgASgJIrgBCgxGb3Vu",
+            "ZE1lc3NhZ2USCgoCaWQYASABKAkSDwoHbWVzc2FnZRgCIAEoDBIbCghwcmlv",
+            "cml0eRgDIAEoDjIJLlByaW9yaXR5EhwKFHJlcXVpcmVkQ2FwYWJpbGl0aWVz",
+            "GAQgAygJEhIKCmVucXVldWVkQnkYBSABKAkSJQodcmVxdWlyZWRDYXBhYmls",
+            "aXRpZXNUcnVuY2F0ZWQYBiABKAgSFQoNcHJpb3JpdHlMZXZlbBgHIAEoDSJD",
+            "ChFGaW5kQnlUYWdSZXNwb25zZRIfCghtZXNzYWdlcxgBIAMoCzINLkZvdW5k",
+            "TWVzc2FnZRINCgV0b3RhbBgCIAEoBCJyCgtQZWVrUmVxdWVzdBIdChVhdmFp",
+            "bGFibGVDYXBhYmlsaXRpZXMYASADKAkSFAoMb25seVByaW9yaXR5GAIgASgI",
+            "EhsKCHByaW9yaXR5GAMgASgOMgkuUHJpb3JpdHkSEQoJcXVldWVOYW1lGAQg",
+            "ASgJIkEKDFBlZWtSZXNwb25zZRIRCgloYWRSZXN1bHQYASABKAgSHgoHbWVz",
+            "c2FnZRgCIAEoCzINLkZvdW5kTWVzc2FnZSJFChFMaXN0UXVldWVkUmVxdWVz",
+            "dBIOCgZjdXJzb3IYASABKAkSDQoFbGltaXQYAiABKAQSEQoJcXVldWVOYW1l",
+            "GAMgASgJIkkKEkxpc3RRdWV1ZWRSZXNwb25zZRIfCghtZXNzYWdlcxgBIAMo",
+            "CzINLkZvdW5kTWVzc2FnZRISCgpuZXh0Q3Vyc29yGAIgASgJIioKFUxpc3RQ",
+            "cm9jZXNzaW5nUmVxdWVzdBIRCglxdWV1ZU5hbWUYASABKAki1QEKEVByb2Nl",
+            "c3NpbmdNZXNzYWdlEgoKAmlkGAEgASgJEhsKCHByaW9yaXR5GAIgASgOMgku",
+            "UHJpb3JpdHkSHAoUcmVxdWlyZWRDYXBhYmlsaXRpZXMYAyADKAkSFwoPYWdl",
+            "TWlsbGlzZWNvbmRzGAQgASgEEg4KBndvcmtlchgFIAEoCRISCgplbnF1ZXVl",
+            "ZEJ5GAYgASgJEiUKHXJlcXVpcmVkQ2FwYWJpbGl0aWVzVHJ1bmNhdGVkGAcg",
+            "ASgIEhUKDXByaW9yaXR5TGV2ZWwYCCABKA0iPgoWTGlzdFByb2Nlc3NpbmdS",
+            "ZXNwb25zZRIkCghtZXNzYWdlcxgBIAMoCzISLlByb2Nlc3NpbmdNZXNzYWdl",
+            "IkAKGlNldENhcGFiaWxpdHlQYXVzZWRSZXF1ZXN0EhIKCmNhcGFiaWxpdHkY",
+            "ASABKAkSDgoGcGF1c2VkGAIgASgIIjkKG1NldENhcGFiaWxpdHlQYXVzZWRS",
+            "ZXNwb25zZRIaChJwYXVzZWRDYXBhYmlsaXRpZXMYASADKAkiDwoNSGVhbHRo",
+            "UmVxdWVzdCI6Cg5MZXZlbFNjYW5EZXB0aBIVCg1wcmlvcml0eUxldmVsGAEg",
+            "ASgNEhEKCXNjYW5EZXB0aBgCIAEoASKmAQoOSGVhbHRoUmVzcG9uc2USHQoV",
+            "aGlnaFByaW9yaXR5U2NhbkRlcHRoGAEgASgBEhwKFGxvd1ByaW9yaXR5U2Nh",
+            "bkRlcHRoGAIgASgBEh0KFWVzdGltYXRlZERyYWluU2Vjb25kcxgDIAEoARIN",
+            "CgVyZWFkeRgEIAEoCBIpChBzY2FuRGVwdGhCeUxldmVsGAUgAygLMg8uTGV2",
+            "ZWxTY2FuRGVwdGgiIQoMU3RhdHNSZXF1ZXN0EhEKCXF1ZXVlTmFtZRgBIAEo",
+            "CSI0CgxQZW5kaW5nQ291bnQSFQoNcHJpb3JpdHlMZXZlbBgBIAEoDRINCgVj",
+            "b3VudBgCIAEoBCLFAQoNU3RhdHNSZXNwb25zZRIeCgdwZW5kaW5nGAEgAygL",
+            "Mg0uUGVuZGluZ0NvdW50Eg4KBnF1ZXVlZBgCIAEoBBISCgpwcm9jZXNzaW5n",
+            "GAMgASgEEhQKDGhpZ2hQcmlvcml0eRgEIAEoBBITCgtsb3dQcmlvcml0eRgF",
+            "IAEoBBIQCghlbnF1ZXVlZBgGIAEoBBIUCgxhY2tub3dsZWRnZWQYByABKAQS",
+            "HQoVZXN0aW1hdGVkRHJhaW5TZWNvbmRzGAggASgBIiEKDFB1cmdlUmVxdWVz",
+            "dBIRCglxdWV1ZU5hbWUYASABKAkiIAoNUHVyZ2VSZXNwb25zZRIPCgdyZW1v",
+            "dmVkGAEgASgEIjwKGEV4cG9ydERlYWRMZXR0ZXJzUmVxdWVzdBIRCglxdWV1",
+            "ZU5hbWUYASABKAkSDQoFY2xlYXIYAiABKAgiOwoZRXhwb3J0RGVhZExldHRl",
+            "cnNSZXNwb25zZRIQCghleHBvcnRlZBgBIAEoBBIMCgRqc29uGAIgASgJIjwK",
+            "FUV2ZW50U3Vic2NyaWJlUmVxdWVzdBIRCglxdWV1ZU5hbWUYASABKAkSEAoI",
+            "cHJlZmV0Y2gYAiABKA0iGAoWRXZlbnRTdWJzY3JpYmVSZXNwb25zZSK5AQoS",
+            "UXVldWVFdmVudFJlc3BvbnNlEhgKBGtpbmQYASABKA4yCi5FdmVudEtpbmQS",
+            "CgoCaWQYAiABKAkSEwoLaGFzUHJpb3JpdHkYAyABKAgSFQoNcHJpb3JpdHlM",
+            "ZXZlbBgEIAEoDRIcChRyZXF1aXJlZENhcGFiaWxpdGllcxgFIAMoCRIdChV0",
+            "aW1lc3RhbXBNaWxsaXNlY29uZHMYBiABKAQSFAoMbWlzc2VkRXZlbnRzGAcg",
+            "ASgEIhMKEVNlcnZlckluZm9SZXF1ZXN0IrYBChJTZXJ2ZXJJbmZvUmVzcG9u",
+            "c2USGgoSdXB0aW1lTWlsbGlzZWNvbmRzGAEgASgEEg8KB3ZlcnNpb24YAiAB",
+            "KAkSEQoJZ2l0Q29tbWl0GAMgASgJEisKEHN0b3JhZ2VGaWxlU2l6ZXMYBCAB",
+            "KAsyES5TdG9yYWdlRmlsZVNpemVzEhUKDXByaW9yaXR5QmFuZHMYBSADKAkS",
+            "HAoUZGVmYXVsdFByaW9yaXR5TGV2ZWwYBiABKA0iXwoQU3RvcmFnZUZpbGVT",
+            "aXplcxIZChFoaWdoUHJpb3JpdHlCeXRlcxgBIAEoBBIYChBsb3dQcmlvcml0",
+            "eUJ5dGVzGAIgASgEEhYKDmNvbXBsZXRlZEJ5dGVzGAMgASgEIhcKFURpYWdu",
+            "b3N0aWNEdW1wUmVxdWVzdCImChZEaWFnbm9zdGljRHVtcFJlc3BvbnNlEgwK",
+            "BGpzb24YASABKAkiOgoNRXJyb3JSZXNwb25zZRIPCgdtZXNzYWdlGAEgASgJ",
+            "EhgKBGNvZGUYAiABKA4yCi5FcnJvckNvZGUiggoKDlJlcXVlc3RXcmFwcGVy",
+            "Eg0KBXJlZklkGAogASgFEiIKB2VucXVldWUYASABKAsyDy5FbnF1ZXVlUmVx",
+            "dWVzdEgAEhoKA3BvcBgCIAEoCzILLlBvcFJlcXVlc3RIABIqCgthY2tub3ds",
+            "ZWRnZRgDIAEoCzITLkFja25vd2xlZGdlUmVxdWVzdEgAEiwKDGF1dGhlbnRp",
+            "Y2F0ZRgGIAEoCzIULkF1dGhlbnRpY2F0ZVJlcXVlc3RIABIgCgZoZWFsdGgY",
+            "ByABKAsyDi5IZWFsdGhSZXF1ZXN0SAASOgoTYWNrbm93bGVkZ2VNYXRjaGlu",
+            "ZxgIIAEoCzIbLkFja25vd2xlZGdlTWF0Y2hpbmdSZXF1ZXN0SAASLgoNZmFp",
+            "bFdpdGhSZXRhZxgJIAEoCzIVLkZhaWxXaXRoUmV0YWdSZXF1ZXN0SAASKAoK",
+            "c2VydmVySW5mbxgLIAEoCzISLlNlcnZlckluZm9SZXF1ZXN0SAASHAoEZmFp",
+            "bBgMIAEoCzIMLkZhaWxSZXF1ZXN0SAASMAoOZGlhZ25vc3RpY0R1bXAYDSAB",
+            "KAsyFi5EaWFnbm9zdGljRHVtcFJlcXVlc3RIABIsCgxiZWdpbkVucXVldWUY",
+            "DiABKAsyFC5CZWdpbkVucXVldWVSZXF1ZXN0SAASLAoMZW5xdWV1ZUNodW5r",
+            "GA8gASgLMhQuRW5xdWV1ZUNodW5rUmVxdWVzdEgAEi4KDWNvbW1pdEVucXVl",
+            "dWUYECABKAsyFS5Db21taXRFbnF1ZXVlUmVxdWVzdEgAEiYKCWNhbmNlbFBv",
+            "cBgRIAEoCzIRLkNhbmNlbFBvcFJlcXVlc3RIABIgCgZjYW5jZWwYEiABKAsy",
+            "Di5DYW5jZWxSZXF1ZXN0SAASJgoJZmluZEJ5VGFnGBMgASgLMhEuRmluZEJ5",
+            "VGFnUmVxdWVzdEgAEjAKDmxpc3RQcm9jZXNzaW5nGBQgASgLMhYuTGlzdFBy",
+            "b2Nlc3NpbmdSZXF1ZXN0SAASOgoTc2V0Q2FwYWJpbGl0eVBhdXNlZBgVIAEo",
+            "CzIbLlNldENhcGFiaWxpdHlQYXVzZWRSZXF1ZXN0SAASJgoJc3Vic2NyaWJl",
+            "GBYgASgLMhEuU3Vic2NyaWJlUmVxdWVzdEgAEiIKB3Jlc2VydmUYFyABKAsy",
+            "Dy5SZXNlcnZlUmVxdWVzdEgAEjgKEmNvbmZpcm1SZXNlcnZhdGlvbhgYIAEo",
+            "CzIaLkNvbmZpcm1SZXNlcnZhdGlvblJlcXVlc3RIABIiCgdkZWNsaW5lGBkg",
+            "ASgLMg8uRGVjbGluZVJlcXVlc3RIABIsCgxlbnF1ZXVlQmF0Y2gYGiABKAsy",
+            "FC5FbnF1ZXVlQmF0Y2hSZXF1ZXN0SAASKAoKbGlzdFF1ZXVlZBgbIAEoCzIS",
+            "Lkxpc3RRdWV1ZWRSZXF1ZXN0SAASHAoEcGVlaxgcIAEoCzIMLlBlZWtSZXF1",
+            "ZXN0SAASHgoFc3RhdHMYHSABKAsyDS5TdGF0c1JlcXVlc3RIABIeCgVwdXJn",
+            "ZRgeIAEoCzINLlB1cmdlUmVxdWVzdEgAEjAKDmV2ZW50U3Vic2NyaWJlGB8g",
+            "ASgLMhYuRXZlbnRTdWJzY3JpYmVSZXF1ZXN0SAASNgoRZXhwb3J0RGVhZExl",
+            "dHRlcnMYICABKAsyGS5FeHBvcnREZWFkTGV0dGVyc1JlcXVlc3RIAEIJCgdt",
+            "ZXNzYWdlIucKCg9SZXNwb25zZVdyYXBwZXISDQoFcmVmSWQYCiABKAUSIwoH",
+            "ZW5xdWV1ZRgBIAEoCzIQLkVucXVldWVSZXNwb25zZUgAEhsKA3BvcBgCIAEo",
+            "CzIMLlBvcFJlc3BvbnNlSAASKwoLYWNrbm93bGVkZ2UYAyABKAsyFC5BY2tu",
+            "b3dsZWRnZVJlc3BvbnNlSAASHwoFZXJyb3IYBCABKAsyDi5FcnJvclJlc3Bv",
+            "bnNlSAASLQoMYXV0aGVudGljYXRlGAYgASgLMhUuQXV0aGVudGljYXRlUmVz",
+            "cG9uc2VIABIhCgZoZWFsdGgYByABKAsyDy5IZWFsdGhSZXNwb25zZUgAEjsK",
+            "E2Fja25vd2xlZGdlTWF0Y2hpbmcYCCABKAsyHC5BY2tub3dsZWRnZU1hdGNo",
+            "aW5nUmVzcG9uc2VIABIvCg1mYWlsV2l0aFJldGFnGAkgASgLMhYuRmFpbFdp",
+            "dGhSZXRhZ1Jlc3BvbnNlSAASKQoKc2VydmVySW5mbxgLIAEoCzITLlNlcnZl",
+            "ckluZm9SZXNwb25zZUgAEh0KBGZhaWwYDCABKAsyDS5GYWlsUmVzcG9uc2VI",
+            "ABIxCg5kaWFnbm9zdGljRHVtcBgNIAEoCzIXLkRpYWdub3N0aWNEdW1wUmVz",
+            "cG9uc2VIABItCgxiZWdpbkVucXVldWUYDiABKAsyFS5CZWdpbkVucXVldWVS",
+            "ZXNwb25zZUgAEi0KDGVucXVldWVDaHVuaxgPIAEoCzIVLkVucXVldWVDaHVu",
+            "a1Jlc3BvbnNlSAASLwoNY29tbWl0RW5xdWV1ZRgQIAEoCzIWLkNvbW1pdEVu",
+            "cXVldWVSZXNwb25zZUgAEicKCWNhbmNlbFBvcBgRIAEoCzISLkNhbmNlbFBv",
+            "cFJlc3BvbnNlSAASIQoGY2FuY2VsGBIgASgLMg8uQ2FuY2VsUmVzcG9uc2VI",
+            "ABInCglmaW5kQnlUYWcYEyABKAsyEi5GaW5kQnlUYWdSZXNwb25zZUgAEjEK",
+            "Dmxpc3RQcm9jZXNzaW5nGBQgASgLMhcuTGlzdFByb2Nlc3NpbmdSZXNwb25z",
+            "ZUgAEjsKE3NldENhcGFiaWxpdHlQYXVzZWQYFSABKAsyHC5TZXRDYXBhYmls",
+            "aXR5UGF1c2VkUmVzcG9uc2VIABInCglzdWJzY3JpYmUYFiABKAsyEi5TdWJz",
+            "Y3JpYmVSZXNwb25zZUgAEiMKB3Jlc2VydmUYFyABKAsyEC5SZXNlcnZlUmVz",
+            "cG9uc2VIABI5ChJjb25maXJtUmVzZXJ2YXRpb24YGCABKAsyGy5Db25maXJt",
+            "UmVzZXJ2YXRpb25SZXNwb25zZUgAEiMKB2RlY2xpbmUYGSABKAsyEC5EZWNs",
+            "aW5lUmVzcG9uc2VIABItCgxlbnF1ZXVlQmF0Y2gYGiABKAsyFS5FbnF1ZXVl",
+            "QmF0Y2hSZXNwb25zZUgAEikKCmxpc3RRdWV1ZWQYGyABKAsyEy5MaXN0UXVl",
+            "dWVkUmVzcG9uc2VIABIdCgRwZWVrGBwgASgLMg0uUGVla1Jlc3BvbnNlSAAS",
+            "HwoFc3RhdHMYHSABKAsyDi5TdGF0c1Jlc3BvbnNlSAASHwoFcHVyZ2UYHiAB",
+            "KAsyDi5QdXJnZVJlc3BvbnNlSAASMQoOZXZlbnRTdWJzY3JpYmUYHyABKAsy",
+            "Fy5FdmVudFN1YnNjcmliZVJlc3BvbnNlSAASJAoFZXZlbnQYICABKAsyEy5R",
+            "dWV1ZUV2ZW50UmVzcG9uc2VIABI3ChFleHBvcnREZWFkTGV0dGVycxghIAEo",
+            "CzIaLkV4cG9ydERlYWRMZXR0ZXJzUmVzcG9uc2VIAEIJCgdtZXNzYWdlKh0K",
+            "CFByaW9yaXR5EgcKA0xPVxAAEggKBEhJR0gQASowCg1FbnF1ZXVlU3RhdHVz",
+            "EgsKB0NSRUFURUQQABISCg5BTFJFQURZX0VYSVNUUxABKiAKDFRhZ01hdGNo",
+            "TW9kZRIHCgNBTEwQABIHCgNBTlkQASpWCglFdmVudEtpbmQSDAoIRU5RVUVV",
+            "RUQQABIKCgZQT1BQRUQQARIQCgxBQ0tOT1dMRURHRUQQAhIKCgZGQUlMRUQQ",
+            "AxIRCg1ERUFEX0xFVFRFUkVEEAQqSgoJRXJyb3JDb2RlEgsKB1VOS05PV04Q",
+            "ABIQCgxTVE9SQUdFX0ZVTEwQARIPCgtCQURfUkVRVUVTVBACEg0KCU5PVF9G",
+            "T1VORBADQhNaB2JycXVldWWqAgdicnF1ZXVlYgZwcm90bzM="));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
           new pbr::FileDescriptor[] { },
-          new pbr::GeneratedClrTypeInfo(new[] {typeof(global::brqueue.Priority), }, new pbr::GeneratedClrTypeInfo[] {
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AuthenticateRequest), global::brqueue.AuthenticateRequest.Parser, new[]{ "Username", "Password" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AuthenticateResponse), global::brqueue.AuthenticateResponse.Parser, new[]{ "Success" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueRequest), global::brqueue.EnqueueRequest.Parser, new[]{ "Message", "Priority", "RequiredCapabilities" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueResponse), global::brqueue.EnqueueResponse.Parser, new[]{ "Id" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PopRequest), global::brqueue.PopRequest.Parser, new[]{ "AvailableCapabilities", "WaitForMessage" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PopResponse), global::brqueue.PopResponse.Parser, new[]{ "HadResult", "Message", "Id" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeRequest), global::brqueue.AcknowledgeRequest.Parser, new[]{ "Id" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeResponse), global::brqueue.AcknowledgeResponse.Parser, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ErrorResponse), global::brqueue.ErrorResponse.Parser, new[]{ "Message" }, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.RequestWrapper), global::brqueue.RequestWrapper.Parser, new[]{ "RefId", "Enqueue", "Pop", "Acknowledge", "Authenticate" }, new[]{ "Message" }, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ResponseWrapper), global::brqueue.ResponseWrapper.Parser, new[]{ "RefId", "Enqueue", "Pop", "Acknowledge", "Error", "Authenticate" }, new[]{ "Message" }, null, null)
+          new pbr::GeneratedClrTypeInfo(new[] {typeof(global::brqueue.Priority), typeof(global::brqueue.EnqueueStatus), typeof(global::brqueue.TagMatchMode), typeof(global::brqueue.EventKind), typeof(global::brqueue.ErrorCode), }, null, new pbr::GeneratedClrTypeInfo[] {
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AuthenticateRequest), global::brqueue.AuthenticateRequest.Parser, new[]{ "Username", "Password", "SessionToken" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AuthenticateResponse), global::brqueue.AuthenticateResponse.Parser, new[]{ "Success", "SessionToken" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueRequest), global::brqueue.EnqueueRequest.Parser, new[]{ "Message", "Priority", "RequiredCapabilities", "DelayMilliseconds", "AvailableAt", "IdempotencyKey", "QueueName", "TtlSeconds", "PriorityLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueResponse), global::brqueue.EnqueueResponse.Parser, new[]{ "Id", "Status" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueBatchRequest), global::brqueue.EnqueueBatchRequest.Parser, new[]{ "Items", "Atomic" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueBatchResult), global::brqueue.EnqueueBatchResult.Parser, new[]{ "Id", "Status", "Error" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueBatchResponse), global::brqueue.EnqueueBatchResponse.Parser, new[]{ "Results" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.BeginEnqueueRequest), global::brqueue.BeginEnqueueRequest.Parser, new[]{ "Priority", "RequiredCapabilities", "DelayMilliseconds", "AvailableAt", "TtlSeconds", "PriorityLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.BeginEnqueueResponse), global::brqueue.BeginEnqueueResponse.Parser, new[]{ "TransferId" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueChunkRequest), global::brqueue.EnqueueChunkRequest.Parser, new[]{ "TransferId", "Offset", "Data" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EnqueueChunkResponse), global::brqueue.EnqueueChunkResponse.Parser, new[]{ "Received" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CommitEnqueueRequest), global::brqueue.CommitEnqueueRequest.Parser, new[]{ "TransferId", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CommitEnqueueResponse), global::brqueue.CommitEnqueueResponse.Parser, new[]{ "Id" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PopRequest), global::brqueue.PopRequest.Parser, new[]{ "AvailableCapabilities", "WaitForMessage", "VisibilityTimeoutMilliseconds", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PopResponse), global::brqueue.PopResponse.Parser, new[]{ "HadResult", "Message", "Id", "HasUnmatchedMessages", "Cancelled", "EnqueuedBy", "Backpressure", "Sequence", "RateLimited", "NotReady", "VisibilityTimeoutMilliseconds", "RequiredCapabilities", "RequiredCapabilitiesTruncated" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.SubscribeRequest), global::brqueue.SubscribeRequest.Parser, new[]{ "AvailableCapabilities", "Prefetch", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.SubscribeResponse), global::brqueue.SubscribeResponse.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ReserveRequest), global::brqueue.ReserveRequest.Parser, new[]{ "AvailableCapabilities", "ReservationMilliseconds", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ReserveResponse), global::brqueue.ReserveResponse.Parser, new[]{ "HadResult", "Message" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ConfirmReservationRequest), global::brqueue.ConfirmReservationRequest.Parser, new[]{ "Id", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ConfirmReservationResponse), global::brqueue.ConfirmReservationResponse.Parser, new[]{ "Confirmed" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CancelPopRequest), global::brqueue.CancelPopRequest.Parser, new[]{ "SessionToken" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CancelPopResponse), global::brqueue.CancelPopResponse.Parser, new[]{ "Cancelled" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CancelRequest), global::brqueue.CancelRequest.Parser, new[]{ "Id", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.CancelResponse), global::brqueue.CancelResponse.Parser, new[]{ "Cancelled" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeRequest), global::brqueue.AcknowledgeRequest.Parser, new[]{ "Id" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeResponse), global::brqueue.AcknowledgeResponse.Parser, new[]{ "AlreadyCompleted" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FailRequest), global::brqueue.FailRequest.Parser, new[]{ "Id", "ChangePriority", "Priority" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FailResponse), global::brqueue.FailResponse.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.DeclineRequest), global::brqueue.DeclineRequest.Parser, new[]{ "Id" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.DeclineResponse), global::brqueue.DeclineResponse.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FailWithRetagRequest), global::brqueue.FailWithRetagRequest.Parser, new[]{ "Id", "RequiredCapabilities" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FailWithRetagResponse), global::brqueue.FailWithRetagResponse.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeMatchingRequest), global::brqueue.AcknowledgeMatchingRequest.Parser, new[]{ "RequiredCapability", "MinimumAgeMilliseconds", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.AcknowledgeMatchingResponse), global::brqueue.AcknowledgeMatchingResponse.Parser, new[]{ "Count" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FindByTagRequest), global::brqueue.FindByTagRequest.Parser, new[]{ "Tags", "MatchMode", "Offset", "Limit", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FoundMessage), global::brqueue.FoundMessage.Parser, new[]{ "Id", "Message", "Priority", "RequiredCapabilities", "EnqueuedBy", "RequiredCapabilitiesTruncated", "PriorityLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.FindByTagResponse), global::brqueue.FindByTagResponse.Parser, new[]{ "Messages", "Total" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PeekRequest), global::brqueue.PeekRequest.Parser, new[]{ "AvailableCapabilities", "OnlyPriority", "Priority", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PeekResponse), global::brqueue.PeekResponse.Parser, new[]{ "HadResult", "Message" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ListQueuedRequest), global::brqueue.ListQueuedRequest.Parser, new[]{ "Cursor", "Limit", "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ListQueuedResponse), global::brqueue.ListQueuedResponse.Parser, new[]{ "Messages", "NextCursor" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ListProcessingRequest), global::brqueue.ListProcessingRequest.Parser, new[]{ "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ProcessingMessage), global::brqueue.ProcessingMessage.Parser, new[]{ "Id", "Priority", "RequiredCapabilities", "AgeMilliseconds", "Worker", "EnqueuedBy", "RequiredCapabilitiesTruncated", "PriorityLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ListProcessingResponse), global::brqueue.ListProcessingResponse.Parser, new[]{ "Messages" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.SetCapabilityPausedRequest), global::brqueue.SetCapabilityPausedRequest.Parser, new[]{ "Capability", "Paused" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.SetCapabilityPausedResponse), global::brqueue.SetCapabilityPausedResponse.Parser, new[]{ "PausedCapabilities" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.HealthRequest), global::brqueue.HealthRequest.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.LevelScanDepth), global::brqueue.LevelScanDepth.Parser, new[]{ "PriorityLevel", "ScanDepth" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.HealthResponse), global::brqueue.HealthResponse.Parser, new[]{ "HighPriorityScanDepth", "LowPriorityScanDepth", "EstimatedDrainSeconds", "Ready", "ScanDepthByLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.StatsRequest), global::brqueue.StatsRequest.Parser, new[]{ "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PendingCount), global::brqueue.PendingCount.Parser, new[]{ "PriorityLevel", "Count" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.StatsResponse), global::brqueue.StatsResponse.Parser, new[]{ "Pending", "Queued", "Processing", "HighPriority", "LowPriority", "Enqueued", "Acknowledged", "EstimatedDrainSeconds" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PurgeRequest), global::brqueue.PurgeRequest.Parser, new[]{ "QueueName" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.PurgeResponse), global::brqueue.PurgeResponse.Parser, new[]{ "Removed" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ExportDeadLettersRequest), global::brqueue.ExportDeadLettersRequest.Parser, new[]{ "QueueName", "Clear" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ExportDeadLettersResponse), global::brqueue.ExportDeadLettersResponse.Parser, new[]{ "Exported", "Json" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EventSubscribeRequest), global::brqueue.EventSubscribeRequest.Parser, new[]{ "QueueName", "Prefetch" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.EventSubscribeResponse), global::brqueue.EventSubscribeResponse.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.QueueEventResponse), global::brqueue.QueueEventResponse.Parser, new[]{ "Kind", "Id", "HasPriority", "PriorityLevel", "RequiredCapabilities", "TimestampMilliseconds", "MissedEvents" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ServerInfoRequest), global::brqueue.ServerInfoRequest.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ServerInfoResponse), global::brqueue.ServerInfoResponse.Parser, new[]{ "UptimeMilliseconds", "Version", "GitCommit", "StorageFileSizes", "PriorityBands", "DefaultPriorityLevel" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.StorageFileSizes), global::brqueue.StorageFileSizes.Parser, new[]{ "HighPriorityBytes", "LowPriorityBytes", "CompletedBytes" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.DiagnosticDumpRequest), global::brqueue.DiagnosticDumpRequest.Parser, null, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.DiagnosticDumpResponse), global::brqueue.DiagnosticDumpResponse.Parser, new[]{ "Json" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ErrorResponse), global::brqueue.ErrorResponse.Parser, new[]{ "Message", "Code" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.RequestWrapper), global::brqueue.RequestWrapper.Parser, new[]{ "RefId", "Enqueue", "Pop", "Acknowledge", "Authenticate", "Health", "AcknowledgeMatching", "FailWithRetag", "ServerInfo", "Fail", "DiagnosticDump", "BeginEnqueue", "EnqueueChunk", "CommitEnqueue", "CancelPop", "Cancel", "FindByTag", "ListProcessing", "SetCapabilityPaused", "Subscribe", "Reserve", "ConfirmReservation", "Decline", "EnqueueBatch", "ListQueued", "Peek", "Stats", "Purge", "EventSubscribe", "ExportDeadLetters" }, new[]{ "Message" }, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::brqueue.ResponseWrapper), global::brqueue.ResponseWrapper.Parser, new[]{ "RefId", "Enqueue", "Pop", "Acknowledge", "Error", "Authenticate", "Health", "AcknowledgeMatching", "FailWithRetag", "ServerInfo", "Fail", "DiagnosticDump", "BeginEnqueue", "EnqueueChunk", "CommitEnqueue", "CancelPop", "Cancel", "FindByTag", "ListProcessing", "SetCapabilityPaused", "Subscribe", "Reserve", "ConfirmReservation", "Decline", "EnqueueBatch", "ListQueued", "Peek", "Stats", "Purge", "EventSubscribe", "Event", "ExportDeadLetters" }, new[]{ "Message" }, null, null, null)
           }));
     }
     #endregion
@@ -71,29 +287,89 @@ namespace brqueue {
     [pbr::OriginalName("HIGH")] High = 1,
   }
 
+  public enum EnqueueStatus {
+    /// <summary>
+    /// A new task was enqueued
+    /// </summary>
+    [pbr::OriginalName("CREATED")] Created = 0,
+    /// <summary>
+    /// A task with the same idempotency key was already enqueued, and nothing was changed
+    /// </summary>
+    [pbr::OriginalName("ALREADY_EXISTS")] AlreadyExists = 1,
+  }
+
+  public enum TagMatchMode {
+    /// <summary>
+    /// The message requires all of the tags
+    /// </summary>
+    [pbr::OriginalName("ALL")] All = 0,
+    /// <summary>
+    /// The message requires at least one of the tags
+    /// </summary>
+    [pbr::OriginalName("ANY")] Any = 1,
+  }
+
+  public enum EventKind {
+    [pbr::OriginalName("ENQUEUED")] Enqueued = 0,
+    [pbr::OriginalName("POPPED")] Popped = 1,
+    [pbr::OriginalName("ACKNOWLEDGED")] Acknowledged = 2,
+    [pbr::OriginalName("FAILED")] Failed = 3,
+    [pbr::OriginalName("DEAD_LETTERED")] DeadLettered = 4,
+  }
+
+  /// <summary>
+  /// Errors clients might want to handle specifically
+  /// </summary>
+  public enum ErrorCode {
+    [pbr::OriginalName("UNKNOWN")] Unknown = 0,
+    /// <summary>
+    /// The server has run out of disk space, so new messages can't be saved.
+    /// Try again later.
+    /// </summary>
+    [pbr::OriginalName("STORAGE_FULL")] StorageFull = 1,
+    /// <summary>
+    /// The request was rejected, for example because the payload is invalid.
+    /// Sending it again won't help.
+    /// </summary>
+    [pbr::OriginalName("BAD_REQUEST")] BadRequest = 2,
+    /// <summary>
+    /// The request refers to something that doesn't exist, for example a queue nothing has been enqueued to
+    /// </summary>
+    [pbr::OriginalName("NOT_FOUND")] NotFound = 3,
+  }
+
   #endregion
 
   #region Messages
   /// <summary>
   /// Authenticates the single connection
   /// </summary>
-  public sealed partial class AuthenticateRequest : pb::IMessage<AuthenticateRequest> {
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AuthenticateRequest : pb::IMessage<AuthenticateRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
     private static readonly pb::MessageParser<AuthenticateRequest> _parser = new pb::MessageParser<AuthenticateRequest>(() => new AuthenticateRequest());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pb::MessageParser<AuthenticateRequest> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[0]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateRequest() {
       OnConstruction();
     }
@@ -101,13 +377,16 @@ namespace brqueue {
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateRequest(AuthenticateRequest other) : this() {
       username_ = other.username_;
       password_ = other.password_;
+      sessionToken_ = other.sessionToken_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateRequest Clone() {
       return new AuthenticateRequest(this);
     }
@@ -116,6 +395,7 @@ namespace brqueue {
     public const int UsernameFieldNumber = 1;
     private string username_ = "";
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public string Username {
       get { return username_; }
       set {
@@ -127,6 +407,7 @@ namespace brqueue {
     public const int PasswordFieldNumber = 2;
     private string password_ = "";
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public string Password {
       get { return password_; }
       set {
@@ -134,12 +415,31 @@ namespace brqueue {
       }
     }
 
+    /// <summary>Field number for the "sessionToken" field.</summary>
+    public const int SessionTokenFieldNumber = 3;
+    private string sessionToken_ = "";
+    /// <summary>
+    /// The token received from a previous connection.
+    /// If set the worker will get back the tasks it was processing,
+    /// if it reconnects within the grace period
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string SessionToken {
+      get { return sessionToken_; }
+      set {
+        sessionToken_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
       return Equals(other as AuthenticateRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public bool Equals(AuthenticateRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
@@ -149,14 +449,17 @@ namespace brqueue {
       }
       if (Username != other.Username) return false;
       if (Password != other.Password) return false;
+      if (SessionToken != other.SessionToken) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
       if (Username.Length != 0) hash ^= Username.GetHashCode();
       if (Password.Length != 0) hash ^= Password.GetHashCode();
+      if (SessionToken.Length != 0) hash ^= SessionToken.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -164,12 +467,17 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
       if (Username.Length != 0) {
         output.WriteRawTag(10);
         output.WriteString(Username);
@@ -178,12 +486,40 @@ namespace brqueue {
         output.WriteRawTag(18);
         output.WriteString(Password);
       }
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(SessionToken);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Username.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Username);
+      }
+      if (Password.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Password);
+      }
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(SessionToken);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
       if (Username.Length != 0) {
@@ -192,6 +528,9 @@ namespace brqueue {
       if (Password.Length != 0) {
         size += 1 + pb::CodedOutputStream.ComputeStringSize(Password);
       }
+      if (SessionToken.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(SessionToken);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -199,6 +538,7 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(AuthenticateRequest other) {
       if (other == null) {
         return;
@@ -209,14 +549,25 @@ namespace brqueue {
       if (other.Password.Length != 0) {
         Password = other.Password;
       }
+      if (other.SessionToken.Length != 0) {
+        SessionToken = other.SessionToken;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
@@ -228,29 +579,74 @@ namespace brqueue {
             Password = input.ReadString();
             break;
           }
+          case 26: {
+            SessionToken = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Username = input.ReadString();
+            break;
+          }
+          case 18: {
+            Password = input.ReadString();
+            break;
+          }
+          case 26: {
+            SessionToken = input.ReadString();
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
-  public sealed partial class AuthenticateResponse : pb::IMessage<AuthenticateResponse> {
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AuthenticateResponse : pb::IMessage<AuthenticateResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
     private static readonly pb::MessageParser<AuthenticateResponse> _parser = new pb::MessageParser<AuthenticateResponse>(() => new AuthenticateResponse());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pb::MessageParser<AuthenticateResponse> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[1]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateResponse() {
       OnConstruction();
     }
@@ -258,12 +654,15 @@ namespace brqueue {
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateResponse(AuthenticateResponse other) : this() {
       success_ = other.success_;
+      sessionToken_ = other.sessionToken_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public AuthenticateResponse Clone() {
       return new AuthenticateResponse(this);
     }
@@ -276,6 +675,7 @@ namespace brqueue {
     /// false otherwise
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public bool Success {
       get { return success_; }
       set {
@@ -283,12 +683,29 @@ namespace brqueue {
       }
     }
 
+    /// <summary>Field number for the "sessionToken" field.</summary>
+    public const int SessionTokenFieldNumber = 2;
+    private string sessionToken_ = "";
+    /// <summary>
+    /// Identifies the worker, should be sent again when reconnecting
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string SessionToken {
+      get { return sessionToken_; }
+      set {
+        sessionToken_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
       return Equals(other as AuthenticateResponse);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public bool Equals(AuthenticateResponse other) {
       if (ReferenceEquals(other, null)) {
         return false;
@@ -297,13 +714,16 @@ namespace brqueue {
         return true;
       }
       if (Success != other.Success) return false;
+      if (SessionToken != other.SessionToken) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
       if (Success != false) hash ^= Success.GetHashCode();
+      if (SessionToken.Length != 0) hash ^= SessionToken.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -311,27 +731,59 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
       if (Success != false) {
         output.WriteRawTag(8);
         output.WriteBool(Success);
       }
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(SessionToken);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Success != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Success);
+      }
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(SessionToken);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
       if (Success != false) {
         size += 1 + 1;
       }
+      if (SessionToken.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(SessionToken);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -339,6 +791,7 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(AuthenticateResponse other) {
       if (other == null) {
         return;
@@ -346,14 +799,25 @@ namespace brqueue {
       if (other.Success != false) {
         Success = other.Success;
       }
+      if (other.SessionToken.Length != 0) {
+        SessionToken = other.SessionToken;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
@@ -361,32 +825,73 @@ namespace brqueue {
             Success = input.ReadBool();
             break;
           }
+          case 18: {
+            SessionToken = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Success = input.ReadBool();
+            break;
+          }
+          case 18: {
+            SessionToken = input.ReadString();
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
   /// <summary>
   /// Puts a new message in the queue
   /// </summary>
-  public sealed partial class EnqueueRequest : pb::IMessage<EnqueueRequest> {
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueRequest : pb::IMessage<EnqueueRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
     private static readonly pb::MessageParser<EnqueueRequest> _parser = new pb::MessageParser<EnqueueRequest>(() => new EnqueueRequest());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pb::MessageParser<EnqueueRequest> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[2]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueRequest() {
       OnConstruction();
     }
@@ -394,14 +899,22 @@ namespace brqueue {
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueRequest(EnqueueRequest other) : this() {
       message_ = other.message_;
       priority_ = other.priority_;
       requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      delayMilliseconds_ = other.delayMilliseconds_;
+      availableAt_ = other.availableAt_;
+      idempotencyKey_ = other.idempotencyKey_;
+      queueName_ = other.queueName_;
+      ttlSeconds_ = other.ttlSeconds_;
+      priorityLevel_ = other.priorityLevel_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueRequest Clone() {
       return new EnqueueRequest(this);
     }
@@ -413,6 +926,7 @@ namespace brqueue {
     /// The actual task to enqueue
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public pb::ByteString Message {
       get { return message_; }
       set {
@@ -422,11 +936,12 @@ namespace brqueue {
 
     /// <summary>Field number for the "priority" field.</summary>
     public const int PriorityFieldNumber = 2;
-    private global::brqueue.Priority priority_ = 0;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
     /// <summary>
     /// The priority of the task
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public global::brqueue.Priority Priority {
       get { return priority_; }
       set {
@@ -443,16 +958,116 @@ namespace brqueue {
     /// What capabilities are required to handle the task
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public pbc::RepeatedField<string> RequiredCapabilities {
       get { return requiredCapabilities_; }
     }
 
+    /// <summary>Field number for the "delayMilliseconds" field.</summary>
+    public const int DelayMillisecondsFieldNumber = 4;
+    private ulong delayMilliseconds_;
+    /// <summary>
+    /// How long to wait before the task is handed out to workers
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong DelayMilliseconds {
+      get { return delayMilliseconds_; }
+      set {
+        delayMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "availableAt" field.</summary>
+    public const int AvailableAtFieldNumber = 5;
+    private string availableAt_ = "";
+    /// <summary>
+    /// When the task can be handed out to workers, as an RFC3339 timestamp in UTC,
+    /// for example "2018-12-24T18:00:00Z". Can't be combined with delayMilliseconds.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string AvailableAt {
+      get { return availableAt_; }
+      set {
+        availableAt_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "idempotencyKey" field.</summary>
+    public const int IdempotencyKeyFieldNumber = 6;
+    private string idempotencyKey_ = "";
+    /// <summary>
+    /// If set the task is only enqueued if no task with the same key is queued or being processed.
+    /// Lets clients retry enqueues safely, for example after losing the connection.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string IdempotencyKey {
+      get { return idempotencyKey_; }
+      set {
+        idempotencyKey_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 7;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to put the task in. Uses the default queue if empty.
+    /// The queue is created if it doesn't exist yet.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "ttlSeconds" field.</summary>
+    public const int TtlSecondsFieldNumber = 8;
+    private ulong ttlSeconds_;
+    /// <summary>
+    /// If set the task is dropped instead of handed out, if it hasn't been popped
+    /// within this many seconds of being enqueued. The delay counts towards this.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong TtlSeconds {
+      get { return ttlSeconds_; }
+      set {
+        ttlSeconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 9;
+    private uint priorityLevel_;
+    /// <summary>
+    /// Used instead of priority if set. LOW is level 0 and HIGH is level 1,
+    /// tasks with a higher level are popped first. Can be at most 255.
+    /// Tasks above HIGH are reported as HIGH in the priority of responses,
+    /// responses with a priorityLevel has the actual level.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
       return Equals(other as EnqueueRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public bool Equals(EnqueueRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
@@ -463,15 +1078,28 @@ namespace brqueue {
       if (Message != other.Message) return false;
       if (Priority != other.Priority) return false;
       if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (DelayMilliseconds != other.DelayMilliseconds) return false;
+      if (AvailableAt != other.AvailableAt) return false;
+      if (IdempotencyKey != other.IdempotencyKey) return false;
+      if (QueueName != other.QueueName) return false;
+      if (TtlSeconds != other.TtlSeconds) return false;
+      if (PriorityLevel != other.PriorityLevel) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
       if (Message.Length != 0) hash ^= Message.GetHashCode();
-      if (Priority != 0) hash ^= Priority.GetHashCode();
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
       hash ^= requiredCapabilities_.GetHashCode();
+      if (DelayMilliseconds != 0UL) hash ^= DelayMilliseconds.GetHashCode();
+      if (AvailableAt.Length != 0) hash ^= AvailableAt.GetHashCode();
+      if (IdempotencyKey.Length != 0) hash ^= IdempotencyKey.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (TtlSeconds != 0UL) hash ^= TtlSeconds.GetHashCode();
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -479,36 +1107,128 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
       if (Message.Length != 0) {
         output.WriteRawTag(10);
         output.WriteBytes(Message);
       }
-      if (Priority != 0) {
+      if (Priority != global::brqueue.Priority.Low) {
         output.WriteRawTag(16);
         output.WriteEnum((int) Priority);
       }
       requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(AvailableAt);
+      }
+      if (IdempotencyKey.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(IdempotencyKey);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(58);
+        output.WriteString(QueueName);
+      }
+      if (TtlSeconds != 0UL) {
+        output.WriteRawTag(64);
+        output.WriteUInt64(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(72);
+        output.WriteUInt32(PriorityLevel);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Message.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteBytes(Message);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(AvailableAt);
+      }
+      if (IdempotencyKey.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(IdempotencyKey);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(58);
+        output.WriteString(QueueName);
+      }
+      if (TtlSeconds != 0UL) {
+        output.WriteRawTag(64);
+        output.WriteUInt64(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(72);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
       if (Message.Length != 0) {
         size += 1 + pb::CodedOutputStream.ComputeBytesSize(Message);
       }
-      if (Priority != 0) {
+      if (Priority != global::brqueue.Priority.Low) {
         size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
       }
       size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(AvailableAt);
+      }
+      if (IdempotencyKey.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(IdempotencyKey);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (TtlSeconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -516,6 +1236,7 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(EnqueueRequest other) {
       if (other == null) {
         return;
@@ -523,18 +1244,44 @@ namespace brqueue {
       if (other.Message.Length != 0) {
         Message = other.Message;
       }
-      if (other.Priority != 0) {
+      if (other.Priority != global::brqueue.Priority.Low) {
         Priority = other.Priority;
       }
       requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.DelayMilliseconds != 0UL) {
+        DelayMilliseconds = other.DelayMilliseconds;
+      }
+      if (other.AvailableAt.Length != 0) {
+        AvailableAt = other.AvailableAt;
+      }
+      if (other.IdempotencyKey.Length != 0) {
+        IdempotencyKey = other.IdempotencyKey;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      if (other.TtlSeconds != 0UL) {
+        TtlSeconds = other.TtlSeconds;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
@@ -543,36 +1290,125 @@ namespace brqueue {
             break;
           }
           case 16: {
-            priority_ = (global::brqueue.Priority) input.ReadEnum();
+            Priority = (global::brqueue.Priority) input.ReadEnum();
             break;
           }
           case 26: {
             requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
             break;
           }
+          case 32: {
+            DelayMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            AvailableAt = input.ReadString();
+            break;
+          }
+          case 50: {
+            IdempotencyKey = input.ReadString();
+            break;
+          }
+          case 58: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 64: {
+            TtlSeconds = input.ReadUInt64();
+            break;
+          }
+          case 72: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Message = input.ReadBytes();
+            break;
+          }
+          case 16: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 26: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 32: {
+            DelayMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            AvailableAt = input.ReadString();
+            break;
+          }
+          case 50: {
+            IdempotencyKey = input.ReadString();
+            break;
+          }
+          case 58: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 64: {
+            TtlSeconds = input.ReadUInt64();
+            break;
+          }
+          case 72: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
-  public sealed partial class EnqueueResponse : pb::IMessage<EnqueueResponse> {
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueResponse : pb::IMessage<EnqueueResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
     private static readonly pb::MessageParser<EnqueueResponse> _parser = new pb::MessageParser<EnqueueResponse>(() => new EnqueueResponse());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pb::MessageParser<EnqueueResponse> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[3]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueResponse() {
       OnConstruction();
     }
@@ -580,12 +1416,15 @@ namespace brqueue {
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueResponse(EnqueueResponse other) : this() {
       id_ = other.id_;
+      status_ = other.status_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public EnqueueResponse Clone() {
       return new EnqueueResponse(this);
     }
@@ -594,9 +1433,10 @@ namespace brqueue {
     public const int IdFieldNumber = 1;
     private string id_ = "";
     /// <summary>
-    /// The id of the created task
+    /// The id of the created task, or the existing task with the same idempotency key
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public string Id {
       get { return id_; }
       set {
@@ -604,12 +1444,26 @@ namespace brqueue {
       }
     }
 
+    /// <summary>Field number for the "status" field.</summary>
+    public const int StatusFieldNumber = 2;
+    private global::brqueue.EnqueueStatus status_ = global::brqueue.EnqueueStatus.Created;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public override bool Equals(object other) {
-      return Equals(other as EnqueueResponse);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueStatus Status {
+      get { return status_; }
+      set {
+        status_ = value;
+      }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as EnqueueResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public bool Equals(EnqueueResponse other) {
       if (ReferenceEquals(other, null)) {
         return false;
@@ -618,13 +1472,16 @@ namespace brqueue {
         return true;
       }
       if (Id != other.Id) return false;
+      if (Status != other.Status) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
       if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (Status != global::brqueue.EnqueueStatus.Created) hash ^= Status.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -632,27 +1489,59 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
       if (Id.Length != 0) {
         output.WriteRawTag(10);
         output.WriteString(Id);
       }
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Status);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Status);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
       if (Id.Length != 0) {
         size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
       }
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Status);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -660,6 +1549,7 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(EnqueueResponse other) {
       if (other == null) {
         return;
@@ -667,14 +1557,25 @@ namespace brqueue {
       if (other.Id.Length != 0) {
         Id = other.Id;
       }
+      if (other.Status != global::brqueue.EnqueueStatus.Created) {
+        Status = other.Status;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
@@ -682,101 +1583,147 @@ namespace brqueue {
             Id = input.ReadString();
             break;
           }
+          case 16: {
+            Status = (global::brqueue.EnqueueStatus) input.ReadEnum();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 16: {
+            Status = (global::brqueue.EnqueueStatus) input.ReadEnum();
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
   /// <summary>
-  /// Removes a single message from the queue
+  /// Enqueues several tasks at once
   /// </summary>
-  public sealed partial class PopRequest : pb::IMessage<PopRequest> {
-    private static readonly pb::MessageParser<PopRequest> _parser = new pb::MessageParser<PopRequest>(() => new PopRequest());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueBatchRequest : pb::IMessage<EnqueueBatchRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EnqueueBatchRequest> _parser = new pb::MessageParser<EnqueueBatchRequest>(() => new EnqueueBatchRequest());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<PopRequest> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EnqueueBatchRequest> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[4]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopRequest() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchRequest() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopRequest(PopRequest other) : this() {
-      availableCapabilities_ = other.availableCapabilities_.Clone();
-      waitForMessage_ = other.waitForMessage_;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchRequest(EnqueueBatchRequest other) : this() {
+      items_ = other.items_.Clone();
+      atomic_ = other.atomic_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopRequest Clone() {
-      return new PopRequest(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchRequest Clone() {
+      return new EnqueueBatchRequest(this);
     }
 
-    /// <summary>Field number for the "availableCapabilities" field.</summary>
-    public const int AvailableCapabilitiesFieldNumber = 1;
-    private static readonly pb::FieldCodec<string> _repeated_availableCapabilities_codec
-        = pb::FieldCodec.ForString(10);
-    private readonly pbc::RepeatedField<string> availableCapabilities_ = new pbc::RepeatedField<string>();
-    /// <summary>
-    /// What capabilities the client has available
-    /// </summary>
+    /// <summary>Field number for the "items" field.</summary>
+    public const int ItemsFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.EnqueueRequest> _repeated_items_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.EnqueueRequest.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.EnqueueRequest> items_ = new pbc::RepeatedField<global::brqueue.EnqueueRequest>();
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public pbc::RepeatedField<string> AvailableCapabilities {
-      get { return availableCapabilities_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.EnqueueRequest> Items {
+      get { return items_; }
     }
 
-    /// <summary>Field number for the "waitForMessage" field.</summary>
-    public const int WaitForMessageFieldNumber = 2;
-    private bool waitForMessage_;
+    /// <summary>Field number for the "atomic" field.</summary>
+    public const int AtomicFieldNumber = 2;
+    private bool atomic_;
     /// <summary>
-    /// If the server should wait for a message to come in, before returning
-    /// with a response
+    /// If set either every task is enqueued, or none of them are, in which case an error is returned.
+    /// Otherwise every task is enqueued on its own, and the result of each is returned.
+    /// Idempotency keys can't be used in atomic batches, and every task has to go in the same queue.
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool WaitForMessage {
-      get { return waitForMessage_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Atomic {
+      get { return atomic_; }
       set {
-        waitForMessage_ = value;
+        atomic_ = value;
       }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as PopRequest);
+      return Equals(other as EnqueueBatchRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(PopRequest other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EnqueueBatchRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if(!availableCapabilities_.Equals(other.availableCapabilities_)) return false;
-      if (WaitForMessage != other.WaitForMessage) return false;
+      if(!items_.Equals(other.items_)) return false;
+      if (Atomic != other.Atomic) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      hash ^= availableCapabilities_.GetHashCode();
-      if (WaitForMessage != false) hash ^= WaitForMessage.GetHashCode();
+      hash ^= items_.GetHashCode();
+      if (Atomic != false) hash ^= Atomic.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -784,27 +1731,49 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      availableCapabilities_.WriteTo(output, _repeated_availableCapabilities_codec);
-      if (WaitForMessage != false) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      items_.WriteTo(output, _repeated_items_codec);
+      if (Atomic != false) {
         output.WriteRawTag(16);
-        output.WriteBool(WaitForMessage);
+        output.WriteBool(Atomic);
       }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      items_.WriteTo(ref output, _repeated_items_codec);
+      if (Atomic != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(Atomic);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      size += availableCapabilities_.CalculateSize(_repeated_availableCapabilities_codec);
-      if (WaitForMessage != false) {
+      size += items_.CalculateSize(_repeated_items_codec);
+      if (Atomic != false) {
         size += 1 + 1;
       }
       if (_unknownFields != null) {
@@ -814,143 +1783,193 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(PopRequest other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EnqueueBatchRequest other) {
       if (other == null) {
         return;
       }
-      availableCapabilities_.Add(other.availableCapabilities_);
-      if (other.WaitForMessage != false) {
-        WaitForMessage = other.WaitForMessage;
+      items_.Add(other.items_);
+      if (other.Atomic != false) {
+        Atomic = other.Atomic;
       }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            availableCapabilities_.AddEntriesFrom(input, _repeated_availableCapabilities_codec);
+            items_.AddEntriesFrom(input, _repeated_items_codec);
             break;
           }
           case 16: {
-            WaitForMessage = input.ReadBool();
+            Atomic = input.ReadBool();
             break;
           }
         }
       }
+    #endif
     }
 
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            items_.AddEntriesFrom(ref input, _repeated_items_codec);
+            break;
+          }
+          case 16: {
+            Atomic = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
   }
 
-  public sealed partial class PopResponse : pb::IMessage<PopResponse> {
-    private static readonly pb::MessageParser<PopResponse> _parser = new pb::MessageParser<PopResponse>(() => new PopResponse());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueBatchResult : pb::IMessage<EnqueueBatchResult>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EnqueueBatchResult> _parser = new pb::MessageParser<EnqueueBatchResult>(() => new EnqueueBatchResult());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<PopResponse> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EnqueueBatchResult> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[5]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopResponse() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResult() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopResponse(PopResponse other) : this() {
-      hadResult_ = other.hadResult_;
-      message_ = other.message_;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResult(EnqueueBatchResult other) : this() {
       id_ = other.id_;
+      status_ = other.status_;
+      error_ = other.error_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public PopResponse Clone() {
-      return new PopResponse(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResult Clone() {
+      return new EnqueueBatchResult(this);
     }
 
-    /// <summary>Field number for the "hadResult" field.</summary>
-    public const int HadResultFieldNumber = 3;
-    private bool hadResult_;
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
     /// <summary>
-    /// True if there was a message available
+    /// The id of the task, if it was enqueued
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool HadResult {
-      get { return hadResult_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
       set {
-        hadResult_ = value;
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
       }
     }
 
-    /// <summary>Field number for the "message" field.</summary>
-    public const int MessageFieldNumber = 1;
-    private pb::ByteString message_ = pb::ByteString.Empty;
-    /// <summary>
-    /// The actual message to process
-    /// </summary>
+    /// <summary>Field number for the "status" field.</summary>
+    public const int StatusFieldNumber = 2;
+    private global::brqueue.EnqueueStatus status_ = global::brqueue.EnqueueStatus.Created;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public pb::ByteString Message {
-      get { return message_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueStatus Status {
+      get { return status_; }
       set {
-        message_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+        status_ = value;
       }
     }
 
-    /// <summary>Field number for the "id" field.</summary>
-    public const int IdFieldNumber = 2;
-    private string id_ = "";
+    /// <summary>Field number for the "error" field.</summary>
+    public const int ErrorFieldNumber = 3;
+    private string error_ = "";
     /// <summary>
-    /// The id of the message
-    /// Should be returned with the acknowledge request
+    /// Why the task wasn't enqueued, empty if it was
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public string Id {
-      get { return id_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Error {
+      get { return error_; }
       set {
-        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+        error_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
       }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as PopResponse);
+      return Equals(other as EnqueueBatchResult);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(PopResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EnqueueBatchResult other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if (HadResult != other.HadResult) return false;
-      if (Message != other.Message) return false;
       if (Id != other.Id) return false;
+      if (Status != other.Status) return false;
+      if (Error != other.Error) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      if (HadResult != false) hash ^= HadResult.GetHashCode();
-      if (Message.Length != 0) hash ^= Message.GetHashCode();
       if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (Status != global::brqueue.EnqueueStatus.Created) hash ^= Status.GetHashCode();
+      if (Error.Length != 0) hash ^= Error.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -958,41 +1977,70 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      if (Message.Length != 0) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
         output.WriteRawTag(10);
-        output.WriteBytes(Message);
+        output.WriteString(Id);
+      }
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Status);
+      }
+      if (Error.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(Error);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
       if (Id.Length != 0) {
-        output.WriteRawTag(18);
+        output.WriteRawTag(10);
         output.WriteString(Id);
       }
-      if (HadResult != false) {
-        output.WriteRawTag(24);
-        output.WriteBool(HadResult);
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Status);
+      }
+      if (Error.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(Error);
       }
       if (_unknownFields != null) {
-        _unknownFields.WriteTo(output);
+        _unknownFields.WriteTo(ref output);
       }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      if (HadResult != false) {
-        size += 1 + 1;
-      }
-      if (Message.Length != 0) {
-        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Message);
-      }
       if (Id.Length != 0) {
         size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
       }
+      if (Status != global::brqueue.EnqueueStatus.Created) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Status);
+      }
+      if (Error.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Error);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1000,117 +2048,172 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(PopResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EnqueueBatchResult other) {
       if (other == null) {
         return;
       }
-      if (other.HadResult != false) {
-        HadResult = other.HadResult;
-      }
-      if (other.Message.Length != 0) {
-        Message = other.Message;
-      }
       if (other.Id.Length != 0) {
         Id = other.Id;
       }
+      if (other.Status != global::brqueue.EnqueueStatus.Created) {
+        Status = other.Status;
+      }
+      if (other.Error.Length != 0) {
+        Error = other.Error;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            Message = input.ReadBytes();
+            Id = input.ReadString();
             break;
           }
-          case 18: {
+          case 16: {
+            Status = (global::brqueue.EnqueueStatus) input.ReadEnum();
+            break;
+          }
+          case 26: {
+            Error = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
             Id = input.ReadString();
             break;
           }
-          case 24: {
-            HadResult = input.ReadBool();
+          case 16: {
+            Status = (global::brqueue.EnqueueStatus) input.ReadEnum();
+            break;
+          }
+          case 26: {
+            Error = input.ReadString();
             break;
           }
         }
       }
     }
+    #endif
 
   }
 
-  public sealed partial class AcknowledgeRequest : pb::IMessage<AcknowledgeRequest> {
-    private static readonly pb::MessageParser<AcknowledgeRequest> _parser = new pb::MessageParser<AcknowledgeRequest>(() => new AcknowledgeRequest());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueBatchResponse : pb::IMessage<EnqueueBatchResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EnqueueBatchResponse> _parser = new pb::MessageParser<EnqueueBatchResponse>(() => new EnqueueBatchResponse());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<AcknowledgeRequest> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EnqueueBatchResponse> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[6]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeRequest() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResponse() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeRequest(AcknowledgeRequest other) : this() {
-      id_ = other.id_;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResponse(EnqueueBatchResponse other) : this() {
+      results_ = other.results_.Clone();
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeRequest Clone() {
-      return new AcknowledgeRequest(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueBatchResponse Clone() {
+      return new EnqueueBatchResponse(this);
     }
 
-    /// <summary>Field number for the "id" field.</summary>
-    public const int IdFieldNumber = 1;
-    private string id_ = "";
+    /// <summary>Field number for the "results" field.</summary>
+    public const int ResultsFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.EnqueueBatchResult> _repeated_results_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.EnqueueBatchResult.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.EnqueueBatchResult> results_ = new pbc::RepeatedField<global::brqueue.EnqueueBatchResult>();
     /// <summary>
-    /// The id of the message to acknowledge
+    /// The result of every task, in the order of the request
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public string Id {
-      get { return id_; }
-      set {
-        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
-      }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.EnqueueBatchResult> Results {
+      get { return results_; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as AcknowledgeRequest);
+      return Equals(other as EnqueueBatchResponse);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(AcknowledgeRequest other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EnqueueBatchResponse other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if (Id != other.Id) return false;
+      if(!results_.Equals(other.results_)) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      hash ^= results_.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1118,27 +2221,40 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      if (Id.Length != 0) {
-        output.WriteRawTag(10);
-        output.WriteString(Id);
-      }
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      results_.WriteTo(output, _repeated_results_codec);
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      results_.WriteTo(ref output, _repeated_results_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      if (Id.Length != 0) {
-        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
-      }
+      size += results_.CalculateSize(_repeated_results_codec);
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1146,89 +2262,235 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(AcknowledgeRequest other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EnqueueBatchResponse other) {
       if (other == null) {
         return;
       }
-      if (other.Id.Length != 0) {
-        Id = other.Id;
-      }
+      results_.Add(other.results_);
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            Id = input.ReadString();
+            results_.AddEntriesFrom(input, _repeated_results_codec);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            results_.AddEntriesFrom(ref input, _repeated_results_codec);
             break;
           }
         }
       }
     }
+    #endif
 
   }
 
   /// <summary>
-  /// Returned when a message has been acknowledged
+  /// Starts enqueuing a message that is too big to send in one request.
+  /// The message is sent in chunks with EnqueueChunkRequest,
+  /// and put in the queue with CommitEnqueueRequest.
   /// </summary>
-  public sealed partial class AcknowledgeResponse : pb::IMessage<AcknowledgeResponse> {
-    private static readonly pb::MessageParser<AcknowledgeResponse> _parser = new pb::MessageParser<AcknowledgeResponse>(() => new AcknowledgeResponse());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class BeginEnqueueRequest : pb::IMessage<BeginEnqueueRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<BeginEnqueueRequest> _parser = new pb::MessageParser<BeginEnqueueRequest>(() => new BeginEnqueueRequest());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<AcknowledgeResponse> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<BeginEnqueueRequest> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[7]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeResponse() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueRequest() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeResponse(AcknowledgeResponse other) : this() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueRequest(BeginEnqueueRequest other) : this() {
+      priority_ = other.priority_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      delayMilliseconds_ = other.delayMilliseconds_;
+      availableAt_ = other.availableAt_;
+      ttlSeconds_ = other.ttlSeconds_;
+      priorityLevel_ = other.priorityLevel_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public AcknowledgeResponse Clone() {
-      return new AcknowledgeResponse(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueRequest Clone() {
+      return new BeginEnqueueRequest(this);
+    }
+
+    /// <summary>Field number for the "priority" field.</summary>
+    public const int PriorityFieldNumber = 1;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
+    /// <summary>
+    /// The priority of the task
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.Priority Priority {
+      get { return priority_; }
+      set {
+        priority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 2;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(18);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// What capabilities are required to handle the task
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    /// <summary>Field number for the "delayMilliseconds" field.</summary>
+    public const int DelayMillisecondsFieldNumber = 3;
+    private ulong delayMilliseconds_;
+    /// <summary>
+    /// The same as in EnqueueRequest. The delay and the time to live
+    /// are counted from when the transfer begins.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong DelayMilliseconds {
+      get { return delayMilliseconds_; }
+      set {
+        delayMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "availableAt" field.</summary>
+    public const int AvailableAtFieldNumber = 4;
+    private string availableAt_ = "";
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string AvailableAt {
+      get { return availableAt_; }
+      set {
+        availableAt_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "ttlSeconds" field.</summary>
+    public const int TtlSecondsFieldNumber = 5;
+    private ulong ttlSeconds_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong TtlSeconds {
+      get { return ttlSeconds_; }
+      set {
+        ttlSeconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 6;
+    private uint priorityLevel_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as AcknowledgeResponse);
+      return Equals(other as BeginEnqueueRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(AcknowledgeResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(BeginEnqueueRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
+      if (Priority != other.Priority) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (DelayMilliseconds != other.DelayMilliseconds) return false;
+      if (AvailableAt != other.AvailableAt) return false;
+      if (TtlSeconds != other.TtlSeconds) return false;
+      if (PriorityLevel != other.PriorityLevel) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (DelayMilliseconds != 0UL) hash ^= DelayMilliseconds.GetHashCode();
+      if (AvailableAt.Length != 0) hash ^= AvailableAt.GetHashCode();
+      if (TtlSeconds != 0UL) hash ^= TtlSeconds.GetHashCode();
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1236,20 +2498,95 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(AvailableAt);
+      }
+      if (TtlSeconds != 0UL) {
+        output.WriteRawTag(40);
+        output.WriteUInt64(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(48);
+        output.WriteUInt32(PriorityLevel);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(AvailableAt);
+      }
+      if (TtlSeconds != 0UL) {
+        output.WriteRawTag(40);
+        output.WriteUInt64(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(48);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
+      if (Priority != global::brqueue.Priority.Low) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (DelayMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(DelayMilliseconds);
+      }
+      if (AvailableAt.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(AvailableAt);
+      }
+      if (TtlSeconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(TtlSeconds);
+      }
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1257,96 +2594,204 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(AcknowledgeResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(BeginEnqueueRequest other) {
       if (other == null) {
         return;
       }
+      if (other.Priority != global::brqueue.Priority.Low) {
+        Priority = other.Priority;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.DelayMilliseconds != 0UL) {
+        DelayMilliseconds = other.DelayMilliseconds;
+      }
+      if (other.AvailableAt.Length != 0) {
+        AvailableAt = other.AvailableAt;
+      }
+      if (other.TtlSeconds != 0UL) {
+        TtlSeconds = other.TtlSeconds;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
+          case 8: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 18: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 24: {
+            DelayMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 34: {
+            AvailableAt = input.ReadString();
+            break;
+          }
+          case 40: {
+            TtlSeconds = input.ReadUInt64();
+            break;
+          }
+          case 48: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 18: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 24: {
+            DelayMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 34: {
+            AvailableAt = input.ReadString();
+            break;
+          }
+          case 40: {
+            TtlSeconds = input.ReadUInt64();
+            break;
+          }
+          case 48: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
-  public sealed partial class ErrorResponse : pb::IMessage<ErrorResponse> {
-    private static readonly pb::MessageParser<ErrorResponse> _parser = new pb::MessageParser<ErrorResponse>(() => new ErrorResponse());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class BeginEnqueueResponse : pb::IMessage<BeginEnqueueResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<BeginEnqueueResponse> _parser = new pb::MessageParser<BeginEnqueueResponse>(() => new BeginEnqueueResponse());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<ErrorResponse> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<BeginEnqueueResponse> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[8]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ErrorResponse() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueResponse() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ErrorResponse(ErrorResponse other) : this() {
-      message_ = other.message_;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueResponse(BeginEnqueueResponse other) : this() {
+      transferId_ = other.transferId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ErrorResponse Clone() {
-      return new ErrorResponse(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public BeginEnqueueResponse Clone() {
+      return new BeginEnqueueResponse(this);
     }
 
-    /// <summary>Field number for the "message" field.</summary>
-    public const int MessageFieldNumber = 1;
-    private string message_ = "";
+    /// <summary>Field number for the "transferId" field.</summary>
+    public const int TransferIdFieldNumber = 1;
+    private string transferId_ = "";
     /// <summary>
-    /// What went wrong
+    /// Identifies the transfer in the following requests
     /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public string Message {
-      get { return message_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string TransferId {
+      get { return transferId_; }
       set {
-        message_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+        transferId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
       }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as ErrorResponse);
+      return Equals(other as BeginEnqueueResponse);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(ErrorResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(BeginEnqueueResponse other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if (Message != other.Message) return false;
+      if (TransferId != other.TransferId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      if (Message.Length != 0) hash ^= Message.GetHashCode();
+      if (TransferId.Length != 0) hash ^= TransferId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1354,26 +2799,47 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      if (Message.Length != 0) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (TransferId.Length != 0) {
         output.WriteRawTag(10);
-        output.WriteString(Message);
+        output.WriteString(TransferId);
       }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (TransferId.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(TransferId);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      if (Message.Length != 0) {
-        size += 1 + pb::CodedOutputStream.ComputeStringSize(Message);
+      if (TransferId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(TransferId);
       }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
@@ -1382,193 +2848,186 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(ErrorResponse other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(BeginEnqueueResponse other) {
       if (other == null) {
         return;
       }
-      if (other.Message.Length != 0) {
-        Message = other.Message;
+      if (other.TransferId.Length != 0) {
+        TransferId = other.TransferId;
       }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            Message = input.ReadString();
+            TransferId = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            TransferId = input.ReadString();
             break;
           }
         }
       }
     }
+    #endif
 
   }
 
   /// <summary>
-  /// Wraps the requests for easier parsing on the socket layer
+  /// Sends the next part of a message started with BeginEnqueueRequest
   /// </summary>
-  public sealed partial class RequestWrapper : pb::IMessage<RequestWrapper> {
-    private static readonly pb::MessageParser<RequestWrapper> _parser = new pb::MessageParser<RequestWrapper>(() => new RequestWrapper());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueChunkRequest : pb::IMessage<EnqueueChunkRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EnqueueChunkRequest> _parser = new pb::MessageParser<EnqueueChunkRequest>(() => new EnqueueChunkRequest());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<RequestWrapper> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EnqueueChunkRequest> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[9]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public RequestWrapper() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkRequest() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public RequestWrapper(RequestWrapper other) : this() {
-      refId_ = other.refId_;
-      switch (other.MessageCase) {
-        case MessageOneofCase.Enqueue:
-          Enqueue = other.Enqueue.Clone();
-          break;
-        case MessageOneofCase.Pop:
-          Pop = other.Pop.Clone();
-          break;
-        case MessageOneofCase.Acknowledge:
-          Acknowledge = other.Acknowledge.Clone();
-          break;
-        case MessageOneofCase.Authenticate:
-          Authenticate = other.Authenticate.Clone();
-          break;
-      }
-
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkRequest(EnqueueChunkRequest other) : this() {
+      transferId_ = other.transferId_;
+      offset_ = other.offset_;
+      data_ = other.data_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public RequestWrapper Clone() {
-      return new RequestWrapper(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkRequest Clone() {
+      return new EnqueueChunkRequest(this);
     }
 
-    /// <summary>Field number for the "refId" field.</summary>
-    public const int RefIdFieldNumber = 10;
-    private int refId_;
+    /// <summary>Field number for the "transferId" field.</summary>
+    public const int TransferIdFieldNumber = 1;
+    private string transferId_ = "";
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public int RefId {
-      get { return refId_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string TransferId {
+      get { return transferId_; }
       set {
-        refId_ = value;
+        transferId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
       }
     }
 
-    /// <summary>Field number for the "enqueue" field.</summary>
-    public const int EnqueueFieldNumber = 1;
+    /// <summary>Field number for the "offset" field.</summary>
+    public const int OffsetFieldNumber = 2;
+    private ulong offset_;
+    /// <summary>
+    /// Where in the message the chunk starts.
+    /// Has to be the number of bytes received so far, which makes it
+    /// possible to resume the transfer after a lost connection
+    /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.EnqueueRequest Enqueue {
-      get { return messageCase_ == MessageOneofCase.Enqueue ? (global::brqueue.EnqueueRequest) message_ : null; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Offset {
+      get { return offset_; }
       set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Enqueue;
+        offset_ = value;
       }
     }
 
-    /// <summary>Field number for the "pop" field.</summary>
-    public const int PopFieldNumber = 2;
+    /// <summary>Field number for the "data" field.</summary>
+    public const int DataFieldNumber = 3;
+    private pb::ByteString data_ = pb::ByteString.Empty;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.PopRequest Pop {
-      get { return messageCase_ == MessageOneofCase.Pop ? (global::brqueue.PopRequest) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Pop;
-      }
-    }
-
-    /// <summary>Field number for the "acknowledge" field.</summary>
-    public const int AcknowledgeFieldNumber = 3;
-    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.AcknowledgeRequest Acknowledge {
-      get { return messageCase_ == MessageOneofCase.Acknowledge ? (global::brqueue.AcknowledgeRequest) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Acknowledge;
-      }
-    }
-
-    /// <summary>Field number for the "authenticate" field.</summary>
-    public const int AuthenticateFieldNumber = 6;
-    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.AuthenticateRequest Authenticate {
-      get { return messageCase_ == MessageOneofCase.Authenticate ? (global::brqueue.AuthenticateRequest) message_ : null; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pb::ByteString Data {
+      get { return data_; }
       set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Authenticate;
+        data_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
       }
     }
 
-    private object message_;
-    /// <summary>Enum of possible cases for the "message" oneof.</summary>
-    public enum MessageOneofCase {
-      None = 0,
-      Enqueue = 1,
-      Pop = 2,
-      Acknowledge = 3,
-      Authenticate = 6,
-    }
-    private MessageOneofCase messageCase_ = MessageOneofCase.None;
-    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public MessageOneofCase MessageCase {
-      get { return messageCase_; }
-    }
-
-    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void ClearMessage() {
-      messageCase_ = MessageOneofCase.None;
-      message_ = null;
-    }
-
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as RequestWrapper);
+      return Equals(other as EnqueueChunkRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(RequestWrapper other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EnqueueChunkRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if (RefId != other.RefId) return false;
-      if (!object.Equals(Enqueue, other.Enqueue)) return false;
-      if (!object.Equals(Pop, other.Pop)) return false;
-      if (!object.Equals(Acknowledge, other.Acknowledge)) return false;
-      if (!object.Equals(Authenticate, other.Authenticate)) return false;
-      if (MessageCase != other.MessageCase) return false;
+      if (TransferId != other.TransferId) return false;
+      if (Offset != other.Offset) return false;
+      if (Data != other.Data) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      if (RefId != 0) hash ^= RefId.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Enqueue) hash ^= Enqueue.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Pop) hash ^= Pop.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Acknowledge) hash ^= Acknowledge.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Authenticate) hash ^= Authenticate.GetHashCode();
-      hash ^= (int) messageCase_;
+      if (TransferId.Length != 0) hash ^= TransferId.GetHashCode();
+      if (Offset != 0UL) hash ^= Offset.GetHashCode();
+      if (Data.Length != 0) hash ^= Data.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1576,54 +3035,69 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      if (messageCase_ == MessageOneofCase.Enqueue) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (TransferId.Length != 0) {
         output.WriteRawTag(10);
-        output.WriteMessage(Enqueue);
+        output.WriteString(TransferId);
       }
-      if (messageCase_ == MessageOneofCase.Pop) {
-        output.WriteRawTag(18);
-        output.WriteMessage(Pop);
+      if (Offset != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Offset);
       }
-      if (messageCase_ == MessageOneofCase.Acknowledge) {
+      if (Data.Length != 0) {
         output.WriteRawTag(26);
-        output.WriteMessage(Acknowledge);
+        output.WriteBytes(Data);
       }
-      if (messageCase_ == MessageOneofCase.Authenticate) {
-        output.WriteRawTag(50);
-        output.WriteMessage(Authenticate);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
       }
-      if (RefId != 0) {
-        output.WriteRawTag(80);
-        output.WriteInt32(RefId);
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (TransferId.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(TransferId);
+      }
+      if (Offset != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Offset);
+      }
+      if (Data.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteBytes(Data);
       }
       if (_unknownFields != null) {
-        _unknownFields.WriteTo(output);
+        _unknownFields.WriteTo(ref output);
       }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      if (RefId != 0) {
-        size += 1 + pb::CodedOutputStream.ComputeInt32Size(RefId);
+      if (TransferId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(TransferId);
       }
-      if (messageCase_ == MessageOneofCase.Enqueue) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Enqueue);
-      }
-      if (messageCase_ == MessageOneofCase.Pop) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Pop);
-      }
-      if (messageCase_ == MessageOneofCase.Acknowledge) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Acknowledge);
+      if (Offset != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Offset);
       }
-      if (messageCase_ == MessageOneofCase.Authenticate) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Authenticate);
+      if (Data.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Data);
       }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
@@ -1632,273 +3106,393 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(RequestWrapper other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EnqueueChunkRequest other) {
       if (other == null) {
         return;
       }
-      if (other.RefId != 0) {
-        RefId = other.RefId;
+      if (other.TransferId.Length != 0) {
+        TransferId = other.TransferId;
       }
-      switch (other.MessageCase) {
-        case MessageOneofCase.Enqueue:
-          if (Enqueue == null) {
-            Enqueue = new global::brqueue.EnqueueRequest();
-          }
-          Enqueue.MergeFrom(other.Enqueue);
-          break;
-        case MessageOneofCase.Pop:
-          if (Pop == null) {
-            Pop = new global::brqueue.PopRequest();
-          }
-          Pop.MergeFrom(other.Pop);
-          break;
-        case MessageOneofCase.Acknowledge:
-          if (Acknowledge == null) {
-            Acknowledge = new global::brqueue.AcknowledgeRequest();
-          }
-          Acknowledge.MergeFrom(other.Acknowledge);
-          break;
-        case MessageOneofCase.Authenticate:
-          if (Authenticate == null) {
-            Authenticate = new global::brqueue.AuthenticateRequest();
-          }
-          Authenticate.MergeFrom(other.Authenticate);
-          break;
+      if (other.Offset != 0UL) {
+        Offset = other.Offset;
+      }
+      if (other.Data.Length != 0) {
+        Data = other.Data;
       }
-
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            global::brqueue.EnqueueRequest subBuilder = new global::brqueue.EnqueueRequest();
-            if (messageCase_ == MessageOneofCase.Enqueue) {
-              subBuilder.MergeFrom(Enqueue);
-            }
-            input.ReadMessage(subBuilder);
-            Enqueue = subBuilder;
+            TransferId = input.ReadString();
             break;
           }
-          case 18: {
-            global::brqueue.PopRequest subBuilder = new global::brqueue.PopRequest();
-            if (messageCase_ == MessageOneofCase.Pop) {
-              subBuilder.MergeFrom(Pop);
-            }
-            input.ReadMessage(subBuilder);
-            Pop = subBuilder;
+          case 16: {
+            Offset = input.ReadUInt64();
             break;
           }
           case 26: {
-            global::brqueue.AcknowledgeRequest subBuilder = new global::brqueue.AcknowledgeRequest();
-            if (messageCase_ == MessageOneofCase.Acknowledge) {
-              subBuilder.MergeFrom(Acknowledge);
-            }
-            input.ReadMessage(subBuilder);
-            Acknowledge = subBuilder;
+            Data = input.ReadBytes();
             break;
           }
-          case 50: {
-            global::brqueue.AuthenticateRequest subBuilder = new global::brqueue.AuthenticateRequest();
-            if (messageCase_ == MessageOneofCase.Authenticate) {
-              subBuilder.MergeFrom(Authenticate);
-            }
-            input.ReadMessage(subBuilder);
-            Authenticate = subBuilder;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            TransferId = input.ReadString();
             break;
           }
-          case 80: {
-            RefId = input.ReadInt32();
+          case 16: {
+            Offset = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            Data = input.ReadBytes();
             break;
           }
         }
       }
     }
+    #endif
 
   }
 
-  public sealed partial class ResponseWrapper : pb::IMessage<ResponseWrapper> {
-    private static readonly pb::MessageParser<ResponseWrapper> _parser = new pb::MessageParser<ResponseWrapper>(() => new ResponseWrapper());
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EnqueueChunkResponse : pb::IMessage<EnqueueChunkResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EnqueueChunkResponse> _parser = new pb::MessageParser<EnqueueChunkResponse>(() => new EnqueueChunkResponse());
     private pb::UnknownFieldSet _unknownFields;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public static pb::MessageParser<ResponseWrapper> Parser { get { return _parser; } }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EnqueueChunkResponse> Parser { get { return _parser; } }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
       get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[10]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     pbr::MessageDescriptor pb::IMessage.Descriptor {
       get { return Descriptor; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ResponseWrapper() {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkResponse() {
       OnConstruction();
     }
 
     partial void OnConstruction();
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ResponseWrapper(ResponseWrapper other) : this() {
-      refId_ = other.refId_;
-      switch (other.MessageCase) {
-        case MessageOneofCase.Enqueue:
-          Enqueue = other.Enqueue.Clone();
-          break;
-        case MessageOneofCase.Pop:
-          Pop = other.Pop.Clone();
-          break;
-        case MessageOneofCase.Acknowledge:
-          Acknowledge = other.Acknowledge.Clone();
-          break;
-        case MessageOneofCase.Error:
-          Error = other.Error.Clone();
-          break;
-        case MessageOneofCase.Authenticate:
-          Authenticate = other.Authenticate.Clone();
-          break;
-      }
-
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkResponse(EnqueueChunkResponse other) : this() {
+      received_ = other.received_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public ResponseWrapper Clone() {
-      return new ResponseWrapper(this);
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EnqueueChunkResponse Clone() {
+      return new EnqueueChunkResponse(this);
     }
 
-    /// <summary>Field number for the "refId" field.</summary>
-    public const int RefIdFieldNumber = 10;
-    private int refId_;
+    /// <summary>Field number for the "received" field.</summary>
+    public const int ReceivedFieldNumber = 1;
+    private ulong received_;
+    /// <summary>
+    /// The number of bytes received so far
+    /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public int RefId {
-      get { return refId_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Received {
+      get { return received_; }
       set {
-        refId_ = value;
+        received_ = value;
       }
     }
 
-    /// <summary>Field number for the "enqueue" field.</summary>
-    public const int EnqueueFieldNumber = 1;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.EnqueueResponse Enqueue {
-      get { return messageCase_ == MessageOneofCase.Enqueue ? (global::brqueue.EnqueueResponse) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Enqueue;
-      }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as EnqueueChunkResponse);
     }
 
-    /// <summary>Field number for the "pop" field.</summary>
-    public const int PopFieldNumber = 2;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.PopResponse Pop {
-      get { return messageCase_ == MessageOneofCase.Pop ? (global::brqueue.PopResponse) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Pop;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EnqueueChunkResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
       }
+      if (Received != other.Received) return false;
+      return Equals(_unknownFields, other._unknownFields);
     }
 
-    /// <summary>Field number for the "acknowledge" field.</summary>
-    public const int AcknowledgeFieldNumber = 3;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.AcknowledgeResponse Acknowledge {
-      get { return messageCase_ == MessageOneofCase.Acknowledge ? (global::brqueue.AcknowledgeResponse) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Acknowledge;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Received != 0UL) hash ^= Received.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
       }
+      return hash;
     }
 
-    /// <summary>Field number for the "error" field.</summary>
-    public const int ErrorFieldNumber = 4;
-    /// <summary>
-    /// If something went wrong
-    /// </summary>
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.ErrorResponse Error {
-      get { return messageCase_ == MessageOneofCase.Error ? (global::brqueue.ErrorResponse) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Error;
-      }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
-    /// <summary>Field number for the "authenticate" field.</summary>
-    public const int AuthenticateFieldNumber = 6;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public global::brqueue.AuthenticateResponse Authenticate {
-      get { return messageCase_ == MessageOneofCase.Authenticate ? (global::brqueue.AuthenticateResponse) message_ : null; }
-      set {
-        message_ = value;
-        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Authenticate;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Received != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Received);
       }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
     }
 
-    private object message_;
-    /// <summary>Enum of possible cases for the "message" oneof.</summary>
-    public enum MessageOneofCase {
-      None = 0,
-      Enqueue = 1,
-      Pop = 2,
-      Acknowledge = 3,
-      Error = 4,
-      Authenticate = 6,
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Received != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Received);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
     }
-    private MessageOneofCase messageCase_ = MessageOneofCase.None;
+    #endif
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public MessageOneofCase MessageCase {
-      get { return messageCase_; }
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Received != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Received);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void ClearMessage() {
-      messageCase_ = MessageOneofCase.None;
-      message_ = null;
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EnqueueChunkResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Received != 0UL) {
+        Received = other.Received;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Received = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Received = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Puts a message that has been sent in chunks in the queue
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CommitEnqueueRequest : pb::IMessage<CommitEnqueueRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CommitEnqueueRequest> _parser = new pb::MessageParser<CommitEnqueueRequest>(() => new CommitEnqueueRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CommitEnqueueRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[11]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueRequest(CommitEnqueueRequest other) : this() {
+      transferId_ = other.transferId_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueRequest Clone() {
+      return new CommitEnqueueRequest(this);
+    }
+
+    /// <summary>Field number for the "transferId" field.</summary>
+    public const int TransferIdFieldNumber = 1;
+    private string transferId_ = "";
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string TransferId {
+      get { return transferId_; }
+      set {
+        transferId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 2;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to put the task in. Uses the default queue if empty.
+    /// The queue is created if it doesn't exist yet.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
-      return Equals(other as ResponseWrapper);
+      return Equals(other as CommitEnqueueRequest);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public bool Equals(ResponseWrapper other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CommitEnqueueRequest other) {
       if (ReferenceEquals(other, null)) {
         return false;
       }
       if (ReferenceEquals(other, this)) {
         return true;
       }
-      if (RefId != other.RefId) return false;
-      if (!object.Equals(Enqueue, other.Enqueue)) return false;
-      if (!object.Equals(Pop, other.Pop)) return false;
-      if (!object.Equals(Acknowledge, other.Acknowledge)) return false;
-      if (!object.Equals(Error, other.Error)) return false;
-      if (!object.Equals(Authenticate, other.Authenticate)) return false;
-      if (MessageCase != other.MessageCase) return false;
+      if (TransferId != other.TransferId) return false;
+      if (QueueName != other.QueueName) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override int GetHashCode() {
       int hash = 1;
-      if (RefId != 0) hash ^= RefId.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Enqueue) hash ^= Enqueue.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Pop) hash ^= Pop.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Acknowledge) hash ^= Acknowledge.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Error) hash ^= Error.GetHashCode();
-      if (messageCase_ == MessageOneofCase.Authenticate) hash ^= Authenticate.GetHashCode();
-      hash ^= (int) messageCase_;
+      if (TransferId.Length != 0) hash ^= TransferId.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1906,61 +3500,58 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override string ToString() {
       return pb::JsonFormatter.ToDiagnosticString(this);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void WriteTo(pb::CodedOutputStream output) {
-      if (messageCase_ == MessageOneofCase.Enqueue) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (TransferId.Length != 0) {
         output.WriteRawTag(10);
-        output.WriteMessage(Enqueue);
+        output.WriteString(TransferId);
       }
-      if (messageCase_ == MessageOneofCase.Pop) {
+      if (QueueName.Length != 0) {
         output.WriteRawTag(18);
-        output.WriteMessage(Pop);
-      }
-      if (messageCase_ == MessageOneofCase.Acknowledge) {
-        output.WriteRawTag(26);
-        output.WriteMessage(Acknowledge);
+        output.WriteString(QueueName);
       }
-      if (messageCase_ == MessageOneofCase.Error) {
-        output.WriteRawTag(34);
-        output.WriteMessage(Error);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
       }
-      if (messageCase_ == MessageOneofCase.Authenticate) {
-        output.WriteRawTag(50);
-        output.WriteMessage(Authenticate);
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (TransferId.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(TransferId);
       }
-      if (RefId != 0) {
-        output.WriteRawTag(80);
-        output.WriteInt32(RefId);
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(QueueName);
       }
       if (_unknownFields != null) {
-        _unknownFields.WriteTo(output);
+        _unknownFields.WriteTo(ref output);
       }
     }
+    #endif
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public int CalculateSize() {
       int size = 0;
-      if (RefId != 0) {
-        size += 1 + pb::CodedOutputStream.ComputeInt32Size(RefId);
-      }
-      if (messageCase_ == MessageOneofCase.Enqueue) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Enqueue);
-      }
-      if (messageCase_ == MessageOneofCase.Pop) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Pop);
-      }
-      if (messageCase_ == MessageOneofCase.Acknowledge) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Acknowledge);
-      }
-      if (messageCase_ == MessageOneofCase.Error) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Error);
+      if (TransferId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(TransferId);
       }
-      if (messageCase_ == MessageOneofCase.Authenticate) {
-        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Authenticate);
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
       }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
@@ -1969,109 +3560,17410 @@ namespace brqueue {
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
-    public void MergeFrom(ResponseWrapper other) {
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CommitEnqueueRequest other) {
       if (other == null) {
         return;
       }
-      if (other.RefId != 0) {
-        RefId = other.RefId;
+      if (other.TransferId.Length != 0) {
+        TransferId = other.TransferId;
       }
-      switch (other.MessageCase) {
-        case MessageOneofCase.Enqueue:
-          if (Enqueue == null) {
-            Enqueue = new global::brqueue.EnqueueResponse();
-          }
-          Enqueue.MergeFrom(other.Enqueue);
-          break;
-        case MessageOneofCase.Pop:
-          if (Pop == null) {
-            Pop = new global::brqueue.PopResponse();
-          }
-          Pop.MergeFrom(other.Pop);
-          break;
-        case MessageOneofCase.Acknowledge:
-          if (Acknowledge == null) {
-            Acknowledge = new global::brqueue.AcknowledgeResponse();
-          }
-          Acknowledge.MergeFrom(other.Acknowledge);
-          break;
-        case MessageOneofCase.Error:
-          if (Error == null) {
-            Error = new global::brqueue.ErrorResponse();
-          }
-          Error.MergeFrom(other.Error);
-          break;
-        case MessageOneofCase.Authenticate:
-          if (Authenticate == null) {
-            Authenticate = new global::brqueue.AuthenticateResponse();
-          }
-          Authenticate.MergeFrom(other.Authenticate);
-          break;
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
       }
-
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
       uint tag;
       while ((tag = input.ReadTag()) != 0) {
-        switch(tag) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
           default:
             _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
             break;
           case 10: {
-            global::brqueue.EnqueueResponse subBuilder = new global::brqueue.EnqueueResponse();
-            if (messageCase_ == MessageOneofCase.Enqueue) {
-              subBuilder.MergeFrom(Enqueue);
-            }
-            input.ReadMessage(subBuilder);
-            Enqueue = subBuilder;
+            TransferId = input.ReadString();
             break;
           }
           case 18: {
-            global::brqueue.PopResponse subBuilder = new global::brqueue.PopResponse();
-            if (messageCase_ == MessageOneofCase.Pop) {
-              subBuilder.MergeFrom(Pop);
-            }
-            input.ReadMessage(subBuilder);
-            Pop = subBuilder;
+            QueueName = input.ReadString();
             break;
           }
-          case 26: {
-            global::brqueue.AcknowledgeResponse subBuilder = new global::brqueue.AcknowledgeResponse();
-            if (messageCase_ == MessageOneofCase.Acknowledge) {
-              subBuilder.MergeFrom(Acknowledge);
-            }
-            input.ReadMessage(subBuilder);
-            Acknowledge = subBuilder;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
             break;
-          }
-          case 34: {
-            global::brqueue.ErrorResponse subBuilder = new global::brqueue.ErrorResponse();
-            if (messageCase_ == MessageOneofCase.Error) {
-              subBuilder.MergeFrom(Error);
-            }
-            input.ReadMessage(subBuilder);
-            Error = subBuilder;
+          case 10: {
+            TransferId = input.ReadString();
             break;
           }
-          case 50: {
-            global::brqueue.AuthenticateResponse subBuilder = new global::brqueue.AuthenticateResponse();
-            if (messageCase_ == MessageOneofCase.Authenticate) {
-              subBuilder.MergeFrom(Authenticate);
-            }
-            input.ReadMessage(subBuilder);
-            Authenticate = subBuilder;
+          case 18: {
+            QueueName = input.ReadString();
             break;
           }
-          case 80: {
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CommitEnqueueResponse : pb::IMessage<CommitEnqueueResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CommitEnqueueResponse> _parser = new pb::MessageParser<CommitEnqueueResponse>(() => new CommitEnqueueResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CommitEnqueueResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[12]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueResponse(CommitEnqueueResponse other) : this() {
+      id_ = other.id_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CommitEnqueueResponse Clone() {
+      return new CommitEnqueueResponse(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the created task
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CommitEnqueueResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CommitEnqueueResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CommitEnqueueResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Removes a single message from the queue
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PopRequest : pb::IMessage<PopRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PopRequest> _parser = new pb::MessageParser<PopRequest>(() => new PopRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PopRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[13]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopRequest(PopRequest other) : this() {
+      availableCapabilities_ = other.availableCapabilities_.Clone();
+      waitForMessage_ = other.waitForMessage_;
+      visibilityTimeoutMilliseconds_ = other.visibilityTimeoutMilliseconds_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopRequest Clone() {
+      return new PopRequest(this);
+    }
+
+    /// <summary>Field number for the "availableCapabilities" field.</summary>
+    public const int AvailableCapabilitiesFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_availableCapabilities_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> availableCapabilities_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// What capabilities the client has available
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> AvailableCapabilities {
+      get { return availableCapabilities_; }
+    }
+
+    /// <summary>Field number for the "waitForMessage" field.</summary>
+    public const int WaitForMessageFieldNumber = 2;
+    private bool waitForMessage_;
+    /// <summary>
+    /// If the server should wait for a message to come in, before returning
+    /// with a response
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool WaitForMessage {
+      get { return waitForMessage_; }
+      set {
+        waitForMessage_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "visibilityTimeoutMilliseconds" field.</summary>
+    public const int VisibilityTimeoutMillisecondsFieldNumber = 3;
+    private ulong visibilityTimeoutMilliseconds_;
+    /// <summary>
+    /// How long the message can be processing before it's put back in the queue,
+    /// if it hasn't been acknowledged or failed. Uses the server default if zero.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong VisibilityTimeoutMilliseconds {
+      get { return visibilityTimeoutMilliseconds_; }
+      set {
+        visibilityTimeoutMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 4;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to pop from. Uses the default queue if empty.
+    /// Acknowledging or failing the message goes to the same queue.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PopRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PopRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!availableCapabilities_.Equals(other.availableCapabilities_)) return false;
+      if (WaitForMessage != other.WaitForMessage) return false;
+      if (VisibilityTimeoutMilliseconds != other.VisibilityTimeoutMilliseconds) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= availableCapabilities_.GetHashCode();
+      if (WaitForMessage != false) hash ^= WaitForMessage.GetHashCode();
+      if (VisibilityTimeoutMilliseconds != 0UL) hash ^= VisibilityTimeoutMilliseconds.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      availableCapabilities_.WriteTo(output, _repeated_availableCapabilities_codec);
+      if (WaitForMessage != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(WaitForMessage);
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(VisibilityTimeoutMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      availableCapabilities_.WriteTo(ref output, _repeated_availableCapabilities_codec);
+      if (WaitForMessage != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(WaitForMessage);
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(VisibilityTimeoutMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += availableCapabilities_.CalculateSize(_repeated_availableCapabilities_codec);
+      if (WaitForMessage != false) {
+        size += 1 + 1;
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(VisibilityTimeoutMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PopRequest other) {
+      if (other == null) {
+        return;
+      }
+      availableCapabilities_.Add(other.availableCapabilities_);
+      if (other.WaitForMessage != false) {
+        WaitForMessage = other.WaitForMessage;
+      }
+      if (other.VisibilityTimeoutMilliseconds != 0UL) {
+        VisibilityTimeoutMilliseconds = other.VisibilityTimeoutMilliseconds;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            WaitForMessage = input.ReadBool();
+            break;
+          }
+          case 24: {
+            VisibilityTimeoutMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 34: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(ref input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            WaitForMessage = input.ReadBool();
+            break;
+          }
+          case 24: {
+            VisibilityTimeoutMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 34: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PopResponse : pb::IMessage<PopResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PopResponse> _parser = new pb::MessageParser<PopResponse>(() => new PopResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PopResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[14]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopResponse(PopResponse other) : this() {
+      hadResult_ = other.hadResult_;
+      message_ = other.message_;
+      id_ = other.id_;
+      hasUnmatchedMessages_ = other.hasUnmatchedMessages_;
+      cancelled_ = other.cancelled_;
+      enqueuedBy_ = other.enqueuedBy_;
+      backpressure_ = other.backpressure_;
+      sequence_ = other.sequence_;
+      rateLimited_ = other.rateLimited_;
+      notReady_ = other.notReady_;
+      visibilityTimeoutMilliseconds_ = other.visibilityTimeoutMilliseconds_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      requiredCapabilitiesTruncated_ = other.requiredCapabilitiesTruncated_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PopResponse Clone() {
+      return new PopResponse(this);
+    }
+
+    /// <summary>Field number for the "hadResult" field.</summary>
+    public const int HadResultFieldNumber = 3;
+    private bool hadResult_;
+    /// <summary>
+    /// True if there was a message available
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool HadResult {
+      get { return hadResult_; }
+      set {
+        hadResult_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "message" field.</summary>
+    public const int MessageFieldNumber = 1;
+    private pb::ByteString message_ = pb::ByteString.Empty;
+    /// <summary>
+    /// The actual message to process
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pb::ByteString Message {
+      get { return message_; }
+      set {
+        message_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 2;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message
+    /// Should be returned with the acknowledge request
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "hasUnmatchedMessages" field.</summary>
+    public const int HasUnmatchedMessagesFieldNumber = 4;
+    private bool hasUnmatchedMessages_;
+    /// <summary>
+    /// True if there was no message for the worker, but the queue has messages
+    /// requiring capabilities the worker doesn't have
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool HasUnmatchedMessages {
+      get { return hasUnmatchedMessages_; }
+      set {
+        hasUnmatchedMessages_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "cancelled" field.</summary>
+    public const int CancelledFieldNumber = 5;
+    private bool cancelled_;
+    /// <summary>
+    /// True if the waiting pop was cancelled with a CancelPopRequest
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Cancelled {
+      get { return cancelled_; }
+      set {
+        cancelled_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "enqueuedBy" field.</summary>
+    public const int EnqueuedByFieldNumber = 6;
+    private string enqueuedBy_ = "";
+    /// <summary>
+    /// The user that enqueued the message, empty if unknown
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string EnqueuedBy {
+      get { return enqueuedBy_; }
+      set {
+        enqueuedBy_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "backpressure" field.</summary>
+    public const int BackpressureFieldNumber = 7;
+    private bool backpressure_;
+    /// <summary>
+    /// True if no message was handed out because too many messages are being processed.
+    /// Try again once some of them has been acknowledged.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Backpressure {
+      get { return backpressure_; }
+      set {
+        backpressure_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "sequence" field.</summary>
+    public const int SequenceFieldNumber = 8;
+    private ulong sequence_;
+    /// <summary>
+    /// Increases with every enqueued message, so messages can be ordered by when they were enqueued
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Sequence {
+      get { return sequence_; }
+      set {
+        sequence_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "rateLimited" field.</summary>
+    public const int RateLimitedFieldNumber = 9;
+    private bool rateLimited_;
+    /// <summary>
+    /// True if no message was handed out because the connection pops too often.
+    /// Wait a little before popping again.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool RateLimited {
+      get { return rateLimited_; }
+      set {
+        rateLimited_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "notReady" field.</summary>
+    public const int NotReadyFieldNumber = 10;
+    private bool notReady_;
+    /// <summary>
+    /// True if no message was handed out because the server is still loading the stored messages.
+    /// Try again shortly.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool NotReady {
+      get { return notReady_; }
+      set {
+        notReady_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "visibilityTimeoutMilliseconds" field.</summary>
+    public const int VisibilityTimeoutMillisecondsFieldNumber = 11;
+    private ulong visibilityTimeoutMilliseconds_;
+    /// <summary>
+    /// How long the worker has to acknowledge or fail the message, before it's put back
+    /// in the queue for another worker. Zero if the message doesn't time out.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong VisibilityTimeoutMilliseconds {
+      get { return visibilityTimeoutMilliseconds_; }
+      set {
+        visibilityTimeoutMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 12;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(98);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// What capabilities are required to handle the message
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    /// <summary>Field number for the "requiredCapabilitiesTruncated" field.</summary>
+    public const int RequiredCapabilitiesTruncatedFieldNumber = 13;
+    private bool requiredCapabilitiesTruncated_;
+    /// <summary>
+    /// True if the message requires more capabilities than the server returns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool RequiredCapabilitiesTruncated {
+      get { return requiredCapabilitiesTruncated_; }
+      set {
+        requiredCapabilitiesTruncated_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PopResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PopResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (HadResult != other.HadResult) return false;
+      if (Message != other.Message) return false;
+      if (Id != other.Id) return false;
+      if (HasUnmatchedMessages != other.HasUnmatchedMessages) return false;
+      if (Cancelled != other.Cancelled) return false;
+      if (EnqueuedBy != other.EnqueuedBy) return false;
+      if (Backpressure != other.Backpressure) return false;
+      if (Sequence != other.Sequence) return false;
+      if (RateLimited != other.RateLimited) return false;
+      if (NotReady != other.NotReady) return false;
+      if (VisibilityTimeoutMilliseconds != other.VisibilityTimeoutMilliseconds) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (RequiredCapabilitiesTruncated != other.RequiredCapabilitiesTruncated) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (HadResult != false) hash ^= HadResult.GetHashCode();
+      if (Message.Length != 0) hash ^= Message.GetHashCode();
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (HasUnmatchedMessages != false) hash ^= HasUnmatchedMessages.GetHashCode();
+      if (Cancelled != false) hash ^= Cancelled.GetHashCode();
+      if (EnqueuedBy.Length != 0) hash ^= EnqueuedBy.GetHashCode();
+      if (Backpressure != false) hash ^= Backpressure.GetHashCode();
+      if (Sequence != 0UL) hash ^= Sequence.GetHashCode();
+      if (RateLimited != false) hash ^= RateLimited.GetHashCode();
+      if (NotReady != false) hash ^= NotReady.GetHashCode();
+      if (VisibilityTimeoutMilliseconds != 0UL) hash ^= VisibilityTimeoutMilliseconds.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (RequiredCapabilitiesTruncated != false) hash ^= RequiredCapabilitiesTruncated.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Message.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteBytes(Message);
+      }
+      if (Id.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Id);
+      }
+      if (HadResult != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(HadResult);
+      }
+      if (HasUnmatchedMessages != false) {
+        output.WriteRawTag(32);
+        output.WriteBool(HasUnmatchedMessages);
+      }
+      if (Cancelled != false) {
+        output.WriteRawTag(40);
+        output.WriteBool(Cancelled);
+      }
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(EnqueuedBy);
+      }
+      if (Backpressure != false) {
+        output.WriteRawTag(56);
+        output.WriteBool(Backpressure);
+      }
+      if (Sequence != 0UL) {
+        output.WriteRawTag(64);
+        output.WriteUInt64(Sequence);
+      }
+      if (RateLimited != false) {
+        output.WriteRawTag(72);
+        output.WriteBool(RateLimited);
+      }
+      if (NotReady != false) {
+        output.WriteRawTag(80);
+        output.WriteBool(NotReady);
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        output.WriteRawTag(88);
+        output.WriteUInt64(VisibilityTimeoutMilliseconds);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(104);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Message.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteBytes(Message);
+      }
+      if (Id.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Id);
+      }
+      if (HadResult != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(HadResult);
+      }
+      if (HasUnmatchedMessages != false) {
+        output.WriteRawTag(32);
+        output.WriteBool(HasUnmatchedMessages);
+      }
+      if (Cancelled != false) {
+        output.WriteRawTag(40);
+        output.WriteBool(Cancelled);
+      }
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(EnqueuedBy);
+      }
+      if (Backpressure != false) {
+        output.WriteRawTag(56);
+        output.WriteBool(Backpressure);
+      }
+      if (Sequence != 0UL) {
+        output.WriteRawTag(64);
+        output.WriteUInt64(Sequence);
+      }
+      if (RateLimited != false) {
+        output.WriteRawTag(72);
+        output.WriteBool(RateLimited);
+      }
+      if (NotReady != false) {
+        output.WriteRawTag(80);
+        output.WriteBool(NotReady);
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        output.WriteRawTag(88);
+        output.WriteUInt64(VisibilityTimeoutMilliseconds);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(104);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (HadResult != false) {
+        size += 1 + 1;
+      }
+      if (Message.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Message);
+      }
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (HasUnmatchedMessages != false) {
+        size += 1 + 1;
+      }
+      if (Cancelled != false) {
+        size += 1 + 1;
+      }
+      if (EnqueuedBy.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(EnqueuedBy);
+      }
+      if (Backpressure != false) {
+        size += 1 + 1;
+      }
+      if (Sequence != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Sequence);
+      }
+      if (RateLimited != false) {
+        size += 1 + 1;
+      }
+      if (NotReady != false) {
+        size += 1 + 1;
+      }
+      if (VisibilityTimeoutMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(VisibilityTimeoutMilliseconds);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (RequiredCapabilitiesTruncated != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PopResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.HadResult != false) {
+        HadResult = other.HadResult;
+      }
+      if (other.Message.Length != 0) {
+        Message = other.Message;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.HasUnmatchedMessages != false) {
+        HasUnmatchedMessages = other.HasUnmatchedMessages;
+      }
+      if (other.Cancelled != false) {
+        Cancelled = other.Cancelled;
+      }
+      if (other.EnqueuedBy.Length != 0) {
+        EnqueuedBy = other.EnqueuedBy;
+      }
+      if (other.Backpressure != false) {
+        Backpressure = other.Backpressure;
+      }
+      if (other.Sequence != 0UL) {
+        Sequence = other.Sequence;
+      }
+      if (other.RateLimited != false) {
+        RateLimited = other.RateLimited;
+      }
+      if (other.NotReady != false) {
+        NotReady = other.NotReady;
+      }
+      if (other.VisibilityTimeoutMilliseconds != 0UL) {
+        VisibilityTimeoutMilliseconds = other.VisibilityTimeoutMilliseconds;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.RequiredCapabilitiesTruncated != false) {
+        RequiredCapabilitiesTruncated = other.RequiredCapabilitiesTruncated;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Message = input.ReadBytes();
+            break;
+          }
+          case 18: {
+            Id = input.ReadString();
+            break;
+          }
+          case 24: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 32: {
+            HasUnmatchedMessages = input.ReadBool();
+            break;
+          }
+          case 40: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+          case 50: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 56: {
+            Backpressure = input.ReadBool();
+            break;
+          }
+          case 64: {
+            Sequence = input.ReadUInt64();
+            break;
+          }
+          case 72: {
+            RateLimited = input.ReadBool();
+            break;
+          }
+          case 80: {
+            NotReady = input.ReadBool();
+            break;
+          }
+          case 88: {
+            VisibilityTimeoutMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 98: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 104: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Message = input.ReadBytes();
+            break;
+          }
+          case 18: {
+            Id = input.ReadString();
+            break;
+          }
+          case 24: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 32: {
+            HasUnmatchedMessages = input.ReadBool();
+            break;
+          }
+          case 40: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+          case 50: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 56: {
+            Backpressure = input.ReadBool();
+            break;
+          }
+          case 64: {
+            Sequence = input.ReadUInt64();
+            break;
+          }
+          case 72: {
+            RateLimited = input.ReadBool();
+            break;
+          }
+          case 80: {
+            NotReady = input.ReadBool();
+            break;
+          }
+          case 88: {
+            VisibilityTimeoutMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 98: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 104: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Cancels a waiting pop, which will then return with cancelled set.
+  /// Since the connection doing the pop is blocked until the pop returns,
+  /// this has to be sent on another connection.
+  /// Makes the server push tasks to the connection as they become available, instead of
+  /// the worker popping them one at a time. The tasks are sent as PopResponses with the refId
+  /// of the subscribe request. At most prefetch tasks are pushed before some of them are
+  /// acknowledged or failed. Subscribing again replaces the subscription, and a prefetch of 0 ends it.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class SubscribeRequest : pb::IMessage<SubscribeRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<SubscribeRequest> _parser = new pb::MessageParser<SubscribeRequest>(() => new SubscribeRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<SubscribeRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[15]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeRequest(SubscribeRequest other) : this() {
+      availableCapabilities_ = other.availableCapabilities_.Clone();
+      prefetch_ = other.prefetch_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeRequest Clone() {
+      return new SubscribeRequest(this);
+    }
+
+    /// <summary>Field number for the "availableCapabilities" field.</summary>
+    public const int AvailableCapabilitiesFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_availableCapabilities_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> availableCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> AvailableCapabilities {
+      get { return availableCapabilities_; }
+    }
+
+    /// <summary>Field number for the "prefetch" field.</summary>
+    public const int PrefetchFieldNumber = 2;
+    private uint prefetch_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint Prefetch {
+      get { return prefetch_; }
+      set {
+        prefetch_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 3;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to push tasks from. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as SubscribeRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(SubscribeRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!availableCapabilities_.Equals(other.availableCapabilities_)) return false;
+      if (Prefetch != other.Prefetch) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= availableCapabilities_.GetHashCode();
+      if (Prefetch != 0) hash ^= Prefetch.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      availableCapabilities_.WriteTo(output, _repeated_availableCapabilities_codec);
+      if (Prefetch != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(Prefetch);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      availableCapabilities_.WriteTo(ref output, _repeated_availableCapabilities_codec);
+      if (Prefetch != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(Prefetch);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += availableCapabilities_.CalculateSize(_repeated_availableCapabilities_codec);
+      if (Prefetch != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(Prefetch);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(SubscribeRequest other) {
+      if (other == null) {
+        return;
+      }
+      availableCapabilities_.Add(other.availableCapabilities_);
+      if (other.Prefetch != 0) {
+        Prefetch = other.Prefetch;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            Prefetch = input.ReadUInt32();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(ref input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            Prefetch = input.ReadUInt32();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class SubscribeResponse : pb::IMessage<SubscribeResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<SubscribeResponse> _parser = new pb::MessageParser<SubscribeResponse>(() => new SubscribeResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<SubscribeResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[16]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeResponse(SubscribeResponse other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SubscribeResponse Clone() {
+      return new SubscribeResponse(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as SubscribeResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(SubscribeResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(SubscribeResponse other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Takes the next message matching the capabilities, and hides it from other workers
+  /// for reservationMilliseconds, so the worker can look at it before deciding to process it.
+  /// The message is only processed once confirmed with a ConfirmReservationRequest,
+  /// otherwise it's put back in the queue when the reservation expires.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ReserveRequest : pb::IMessage<ReserveRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ReserveRequest> _parser = new pb::MessageParser<ReserveRequest>(() => new ReserveRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ReserveRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[17]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveRequest(ReserveRequest other) : this() {
+      availableCapabilities_ = other.availableCapabilities_.Clone();
+      reservationMilliseconds_ = other.reservationMilliseconds_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveRequest Clone() {
+      return new ReserveRequest(this);
+    }
+
+    /// <summary>Field number for the "availableCapabilities" field.</summary>
+    public const int AvailableCapabilitiesFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_availableCapabilities_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> availableCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> AvailableCapabilities {
+      get { return availableCapabilities_; }
+    }
+
+    /// <summary>Field number for the "reservationMilliseconds" field.</summary>
+    public const int ReservationMillisecondsFieldNumber = 2;
+    private ulong reservationMilliseconds_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong ReservationMilliseconds {
+      get { return reservationMilliseconds_; }
+      set {
+        reservationMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 3;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to reserve from. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ReserveRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ReserveRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!availableCapabilities_.Equals(other.availableCapabilities_)) return false;
+      if (ReservationMilliseconds != other.ReservationMilliseconds) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= availableCapabilities_.GetHashCode();
+      if (ReservationMilliseconds != 0UL) hash ^= ReservationMilliseconds.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      availableCapabilities_.WriteTo(output, _repeated_availableCapabilities_codec);
+      if (ReservationMilliseconds != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(ReservationMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      availableCapabilities_.WriteTo(ref output, _repeated_availableCapabilities_codec);
+      if (ReservationMilliseconds != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(ReservationMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += availableCapabilities_.CalculateSize(_repeated_availableCapabilities_codec);
+      if (ReservationMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(ReservationMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ReserveRequest other) {
+      if (other == null) {
+        return;
+      }
+      availableCapabilities_.Add(other.availableCapabilities_);
+      if (other.ReservationMilliseconds != 0UL) {
+        ReservationMilliseconds = other.ReservationMilliseconds;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            ReservationMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(ref input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            ReservationMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ReserveResponse : pb::IMessage<ReserveResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ReserveResponse> _parser = new pb::MessageParser<ReserveResponse>(() => new ReserveResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ReserveResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[18]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveResponse(ReserveResponse other) : this() {
+      hadResult_ = other.hadResult_;
+      message_ = other.message_ != null ? other.message_.Clone() : null;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ReserveResponse Clone() {
+      return new ReserveResponse(this);
+    }
+
+    /// <summary>Field number for the "hadResult" field.</summary>
+    public const int HadResultFieldNumber = 1;
+    private bool hadResult_;
+    /// <summary>
+    /// True if there was a message available
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool HadResult {
+      get { return hadResult_; }
+      set {
+        hadResult_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "message" field.</summary>
+    public const int MessageFieldNumber = 2;
+    private global::brqueue.FoundMessage message_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FoundMessage Message {
+      get { return message_; }
+      set {
+        message_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ReserveResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ReserveResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (HadResult != other.HadResult) return false;
+      if (!object.Equals(Message, other.Message)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (HadResult != false) hash ^= HadResult.GetHashCode();
+      if (message_ != null) hash ^= Message.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (HadResult != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(HadResult);
+      }
+      if (message_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Message);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (HadResult != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(HadResult);
+      }
+      if (message_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Message);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (HadResult != false) {
+        size += 1 + 1;
+      }
+      if (message_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Message);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ReserveResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.HadResult != false) {
+        HadResult = other.HadResult;
+      }
+      if (other.message_ != null) {
+        if (message_ == null) {
+          Message = new global::brqueue.FoundMessage();
+        }
+        Message.MergeFrom(other.Message);
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 18: {
+            if (message_ == null) {
+              Message = new global::brqueue.FoundMessage();
+            }
+            input.ReadMessage(Message);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 18: {
+            if (message_ == null) {
+              Message = new global::brqueue.FoundMessage();
+            }
+            input.ReadMessage(Message);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ConfirmReservationRequest : pb::IMessage<ConfirmReservationRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ConfirmReservationRequest> _parser = new pb::MessageParser<ConfirmReservationRequest>(() => new ConfirmReservationRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ConfirmReservationRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[19]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationRequest(ConfirmReservationRequest other) : this() {
+      id_ = other.id_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationRequest Clone() {
+      return new ConfirmReservationRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the reserved message
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 2;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue the message was reserved from
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ConfirmReservationRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ConfirmReservationRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ConfirmReservationRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ConfirmReservationResponse : pb::IMessage<ConfirmReservationResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ConfirmReservationResponse> _parser = new pb::MessageParser<ConfirmReservationResponse>(() => new ConfirmReservationResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ConfirmReservationResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[20]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationResponse(ConfirmReservationResponse other) : this() {
+      confirmed_ = other.confirmed_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ConfirmReservationResponse Clone() {
+      return new ConfirmReservationResponse(this);
+    }
+
+    /// <summary>Field number for the "confirmed" field.</summary>
+    public const int ConfirmedFieldNumber = 1;
+    private bool confirmed_;
+    /// <summary>
+    /// True if the message is now being processed by the connection.
+    /// False if it wasn't reserved, for example if the reservation expired.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Confirmed {
+      get { return confirmed_; }
+      set {
+        confirmed_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ConfirmReservationResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ConfirmReservationResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Confirmed != other.Confirmed) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Confirmed != false) hash ^= Confirmed.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Confirmed != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Confirmed);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Confirmed != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Confirmed);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Confirmed != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ConfirmReservationResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Confirmed != false) {
+        Confirmed = other.Confirmed;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Confirmed = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Confirmed = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CancelPopRequest : pb::IMessage<CancelPopRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CancelPopRequest> _parser = new pb::MessageParser<CancelPopRequest>(() => new CancelPopRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CancelPopRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[21]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopRequest(CancelPopRequest other) : this() {
+      sessionToken_ = other.sessionToken_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopRequest Clone() {
+      return new CancelPopRequest(this);
+    }
+
+    /// <summary>Field number for the "sessionToken" field.</summary>
+    public const int SessionTokenFieldNumber = 1;
+    private string sessionToken_ = "";
+    /// <summary>
+    /// The session token of the connection doing the pop.
+    /// Only pops of the same user can be cancelled, others are reported as NOT_FOUND.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string SessionToken {
+      get { return sessionToken_; }
+      set {
+        sessionToken_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CancelPopRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CancelPopRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (SessionToken != other.SessionToken) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (SessionToken.Length != 0) hash ^= SessionToken.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(SessionToken);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (SessionToken.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(SessionToken);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (SessionToken.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(SessionToken);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CancelPopRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.SessionToken.Length != 0) {
+        SessionToken = other.SessionToken;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            SessionToken = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            SessionToken = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CancelPopResponse : pb::IMessage<CancelPopResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CancelPopResponse> _parser = new pb::MessageParser<CancelPopResponse>(() => new CancelPopResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CancelPopResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[22]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopResponse(CancelPopResponse other) : this() {
+      cancelled_ = other.cancelled_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelPopResponse Clone() {
+      return new CancelPopResponse(this);
+    }
+
+    /// <summary>Field number for the "cancelled" field.</summary>
+    public const int CancelledFieldNumber = 1;
+    private bool cancelled_;
+    /// <summary>
+    /// True if a waiting pop was cancelled
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Cancelled {
+      get { return cancelled_; }
+      set {
+        cancelled_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CancelPopResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CancelPopResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Cancelled != other.Cancelled) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Cancelled != false) hash ^= Cancelled.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Cancelled != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Cancelled);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Cancelled != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Cancelled);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Cancelled != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CancelPopResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Cancelled != false) {
+        Cancelled = other.Cancelled;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Removes a message from the queue before it's handed to a worker
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CancelRequest : pb::IMessage<CancelRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CancelRequest> _parser = new pb::MessageParser<CancelRequest>(() => new CancelRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CancelRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[23]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelRequest(CancelRequest other) : this() {
+      id_ = other.id_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelRequest Clone() {
+      return new CancelRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message to cancel
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 2;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue the message is in. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CancelRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CancelRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CancelRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CancelResponse : pb::IMessage<CancelResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CancelResponse> _parser = new pb::MessageParser<CancelResponse>(() => new CancelResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CancelResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[24]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelResponse(CancelResponse other) : this() {
+      cancelled_ = other.cancelled_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CancelResponse Clone() {
+      return new CancelResponse(this);
+    }
+
+    /// <summary>Field number for the "cancelled" field.</summary>
+    public const int CancelledFieldNumber = 1;
+    private bool cancelled_;
+    /// <summary>
+    /// False if the message wasn't waiting in the queue,
+    /// e.g. because a worker is already processing it
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Cancelled {
+      get { return cancelled_; }
+      set {
+        cancelled_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CancelResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CancelResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Cancelled != other.Cancelled) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Cancelled != false) hash ^= Cancelled.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Cancelled != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Cancelled);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Cancelled != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(Cancelled);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Cancelled != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CancelResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Cancelled != false) {
+        Cancelled = other.Cancelled;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Cancelled = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AcknowledgeRequest : pb::IMessage<AcknowledgeRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<AcknowledgeRequest> _parser = new pb::MessageParser<AcknowledgeRequest>(() => new AcknowledgeRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<AcknowledgeRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[25]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeRequest(AcknowledgeRequest other) : this() {
+      id_ = other.id_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeRequest Clone() {
+      return new AcknowledgeRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message to acknowledge
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as AcknowledgeRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(AcknowledgeRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(AcknowledgeRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Returned when a message has been acknowledged
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AcknowledgeResponse : pb::IMessage<AcknowledgeResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<AcknowledgeResponse> _parser = new pb::MessageParser<AcknowledgeResponse>(() => new AcknowledgeResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<AcknowledgeResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[26]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeResponse(AcknowledgeResponse other) : this() {
+      alreadyCompleted_ = other.alreadyCompleted_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeResponse Clone() {
+      return new AcknowledgeResponse(this);
+    }
+
+    /// <summary>Field number for the "alreadyCompleted" field.</summary>
+    public const int AlreadyCompletedFieldNumber = 1;
+    private bool alreadyCompleted_;
+    /// <summary>
+    /// Set if the message had already been acknowledged, in which case nothing was changed
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool AlreadyCompleted {
+      get { return alreadyCompleted_; }
+      set {
+        alreadyCompleted_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as AcknowledgeResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(AcknowledgeResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (AlreadyCompleted != other.AlreadyCompleted) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (AlreadyCompleted != false) hash ^= AlreadyCompleted.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (AlreadyCompleted != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(AlreadyCompleted);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (AlreadyCompleted != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(AlreadyCompleted);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (AlreadyCompleted != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(AcknowledgeResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.AlreadyCompleted != false) {
+        AlreadyCompleted = other.AlreadyCompleted;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            AlreadyCompleted = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            AlreadyCompleted = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Marks a message as failed, and puts it back in the queue
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FailRequest : pb::IMessage<FailRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FailRequest> _parser = new pb::MessageParser<FailRequest>(() => new FailRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FailRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[27]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailRequest(FailRequest other) : this() {
+      id_ = other.id_;
+      changePriority_ = other.changePriority_;
+      priority_ = other.priority_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailRequest Clone() {
+      return new FailRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message that failed
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "changePriority" field.</summary>
+    public const int ChangePriorityFieldNumber = 2;
+    private bool changePriority_;
+    /// <summary>
+    /// If set the message is put back in the queue with the given priority,
+    /// instead of the priority it originally had
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool ChangePriority {
+      get { return changePriority_; }
+      set {
+        changePriority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priority" field.</summary>
+    public const int PriorityFieldNumber = 3;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.Priority Priority {
+      get { return priority_; }
+      set {
+        priority_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FailRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FailRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if (ChangePriority != other.ChangePriority) return false;
+      if (Priority != other.Priority) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (ChangePriority != false) hash ^= ChangePriority.GetHashCode();
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (ChangePriority != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(ChangePriority);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (ChangePriority != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(ChangePriority);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (ChangePriority != false) {
+        size += 1 + 1;
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FailRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.ChangePriority != false) {
+        ChangePriority = other.ChangePriority;
+      }
+      if (other.Priority != global::brqueue.Priority.Low) {
+        Priority = other.Priority;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 16: {
+            ChangePriority = input.ReadBool();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 16: {
+            ChangePriority = input.ReadBool();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FailResponse : pb::IMessage<FailResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FailResponse> _parser = new pb::MessageParser<FailResponse>(() => new FailResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FailResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[28]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailResponse(FailResponse other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailResponse Clone() {
+      return new FailResponse(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FailResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FailResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FailResponse other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Gives a message back without failing it, because the worker can't handle it
+  /// right now, e.g. because it's missing a dependency.
+  /// The message is offered to other workers first, and doesn't count as failed.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class DeclineRequest : pb::IMessage<DeclineRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<DeclineRequest> _parser = new pb::MessageParser<DeclineRequest>(() => new DeclineRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<DeclineRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[29]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineRequest(DeclineRequest other) : this() {
+      id_ = other.id_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineRequest Clone() {
+      return new DeclineRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the declined message
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as DeclineRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(DeclineRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(DeclineRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class DeclineResponse : pb::IMessage<DeclineResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<DeclineResponse> _parser = new pb::MessageParser<DeclineResponse>(() => new DeclineResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<DeclineResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[30]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineResponse(DeclineResponse other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DeclineResponse Clone() {
+      return new DeclineResponse(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as DeclineResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(DeclineResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(DeclineResponse other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Marks a message as failed, and puts it back in the queue
+  /// requiring a different set of capabilities
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FailWithRetagRequest : pb::IMessage<FailWithRetagRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FailWithRetagRequest> _parser = new pb::MessageParser<FailWithRetagRequest>(() => new FailWithRetagRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FailWithRetagRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[31]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagRequest(FailWithRetagRequest other) : this() {
+      id_ = other.id_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagRequest Clone() {
+      return new FailWithRetagRequest(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message that failed
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 2;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(18);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// The capabilities required to handle the message from now on
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FailWithRetagRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FailWithRetagRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FailWithRetagRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FailWithRetagResponse : pb::IMessage<FailWithRetagResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FailWithRetagResponse> _parser = new pb::MessageParser<FailWithRetagResponse>(() => new FailWithRetagResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FailWithRetagResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[32]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagResponse(FailWithRetagResponse other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FailWithRetagResponse Clone() {
+      return new FailWithRetagResponse(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FailWithRetagResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FailWithRetagResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FailWithRetagResponse other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Acknowledges every processing message matching the filters.
+  /// Intended for cleanup tooling, e.g. when a type of worker has been removed.
+  /// Only available to admins.
+  /// At least one filter has to be set, all set filters has to match.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AcknowledgeMatchingRequest : pb::IMessage<AcknowledgeMatchingRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<AcknowledgeMatchingRequest> _parser = new pb::MessageParser<AcknowledgeMatchingRequest>(() => new AcknowledgeMatchingRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<AcknowledgeMatchingRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[33]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingRequest(AcknowledgeMatchingRequest other) : this() {
+      requiredCapability_ = other.requiredCapability_;
+      minimumAgeMilliseconds_ = other.minimumAgeMilliseconds_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingRequest Clone() {
+      return new AcknowledgeMatchingRequest(this);
+    }
+
+    /// <summary>Field number for the "requiredCapability" field.</summary>
+    public const int RequiredCapabilityFieldNumber = 1;
+    private string requiredCapability_ = "";
+    /// <summary>
+    /// Only acknowledge messages requiring this capability
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string RequiredCapability {
+      get { return requiredCapability_; }
+      set {
+        requiredCapability_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "minimumAgeMilliseconds" field.</summary>
+    public const int MinimumAgeMillisecondsFieldNumber = 2;
+    private ulong minimumAgeMilliseconds_;
+    /// <summary>
+    /// Only acknowledge messages that has been processing for at least
+    /// this many milliseconds
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong MinimumAgeMilliseconds {
+      get { return minimumAgeMilliseconds_; }
+      set {
+        minimumAgeMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 3;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to acknowledge messages in. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as AcknowledgeMatchingRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(AcknowledgeMatchingRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (RequiredCapability != other.RequiredCapability) return false;
+      if (MinimumAgeMilliseconds != other.MinimumAgeMilliseconds) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (RequiredCapability.Length != 0) hash ^= RequiredCapability.GetHashCode();
+      if (MinimumAgeMilliseconds != 0UL) hash ^= MinimumAgeMilliseconds.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (RequiredCapability.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(RequiredCapability);
+      }
+      if (MinimumAgeMilliseconds != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(MinimumAgeMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (RequiredCapability.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(RequiredCapability);
+      }
+      if (MinimumAgeMilliseconds != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(MinimumAgeMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (RequiredCapability.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(RequiredCapability);
+      }
+      if (MinimumAgeMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(MinimumAgeMilliseconds);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(AcknowledgeMatchingRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.RequiredCapability.Length != 0) {
+        RequiredCapability = other.RequiredCapability;
+      }
+      if (other.MinimumAgeMilliseconds != 0UL) {
+        MinimumAgeMilliseconds = other.MinimumAgeMilliseconds;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            RequiredCapability = input.ReadString();
+            break;
+          }
+          case 16: {
+            MinimumAgeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            RequiredCapability = input.ReadString();
+            break;
+          }
+          case 16: {
+            MinimumAgeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class AcknowledgeMatchingResponse : pb::IMessage<AcknowledgeMatchingResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<AcknowledgeMatchingResponse> _parser = new pb::MessageParser<AcknowledgeMatchingResponse>(() => new AcknowledgeMatchingResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<AcknowledgeMatchingResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[34]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingResponse(AcknowledgeMatchingResponse other) : this() {
+      count_ = other.count_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public AcknowledgeMatchingResponse Clone() {
+      return new AcknowledgeMatchingResponse(this);
+    }
+
+    /// <summary>Field number for the "count" field.</summary>
+    public const int CountFieldNumber = 1;
+    private ulong count_;
+    /// <summary>
+    /// How many messages were acknowledged
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Count {
+      get { return count_; }
+      set {
+        count_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as AcknowledgeMatchingResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(AcknowledgeMatchingResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Count != other.Count) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Count != 0UL) hash ^= Count.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Count != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Count);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Count != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Count);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Count != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Count);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(AcknowledgeMatchingResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Count != 0UL) {
+        Count = other.Count;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Count = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Count = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Finds the queued messages requiring the given tags, without removing them.
+  /// Intended for dashboards, so the messages are matched on the tags they require,
+  /// and not on whether a worker with the tags could handle them.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FindByTagRequest : pb::IMessage<FindByTagRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FindByTagRequest> _parser = new pb::MessageParser<FindByTagRequest>(() => new FindByTagRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FindByTagRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[35]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagRequest(FindByTagRequest other) : this() {
+      tags_ = other.tags_.Clone();
+      matchMode_ = other.matchMode_;
+      offset_ = other.offset_;
+      limit_ = other.limit_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagRequest Clone() {
+      return new FindByTagRequest(this);
+    }
+
+    /// <summary>Field number for the "tags" field.</summary>
+    public const int TagsFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_tags_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> tags_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> Tags {
+      get { return tags_; }
+    }
+
+    /// <summary>Field number for the "matchMode" field.</summary>
+    public const int MatchModeFieldNumber = 2;
+    private global::brqueue.TagMatchMode matchMode_ = global::brqueue.TagMatchMode.All;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.TagMatchMode MatchMode {
+      get { return matchMode_; }
+      set {
+        matchMode_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "offset" field.</summary>
+    public const int OffsetFieldNumber = 3;
+    private ulong offset_;
+    /// <summary>
+    /// How many matching messages to skip
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Offset {
+      get { return offset_; }
+      set {
+        offset_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "limit" field.</summary>
+    public const int LimitFieldNumber = 4;
+    private ulong limit_;
+    /// <summary>
+    /// The maximum number of messages to return, everything if 0
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Limit {
+      get { return limit_; }
+      set {
+        limit_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 5;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to search. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FindByTagRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FindByTagRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!tags_.Equals(other.tags_)) return false;
+      if (MatchMode != other.MatchMode) return false;
+      if (Offset != other.Offset) return false;
+      if (Limit != other.Limit) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= tags_.GetHashCode();
+      if (MatchMode != global::brqueue.TagMatchMode.All) hash ^= MatchMode.GetHashCode();
+      if (Offset != 0UL) hash ^= Offset.GetHashCode();
+      if (Limit != 0UL) hash ^= Limit.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      tags_.WriteTo(output, _repeated_tags_codec);
+      if (MatchMode != global::brqueue.TagMatchMode.All) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) MatchMode);
+      }
+      if (Offset != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(Offset);
+      }
+      if (Limit != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(Limit);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      tags_.WriteTo(ref output, _repeated_tags_codec);
+      if (MatchMode != global::brqueue.TagMatchMode.All) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) MatchMode);
+      }
+      if (Offset != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(Offset);
+      }
+      if (Limit != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(Limit);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += tags_.CalculateSize(_repeated_tags_codec);
+      if (MatchMode != global::brqueue.TagMatchMode.All) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) MatchMode);
+      }
+      if (Offset != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Offset);
+      }
+      if (Limit != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Limit);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FindByTagRequest other) {
+      if (other == null) {
+        return;
+      }
+      tags_.Add(other.tags_);
+      if (other.MatchMode != global::brqueue.TagMatchMode.All) {
+        MatchMode = other.MatchMode;
+      }
+      if (other.Offset != 0UL) {
+        Offset = other.Offset;
+      }
+      if (other.Limit != 0UL) {
+        Limit = other.Limit;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            tags_.AddEntriesFrom(input, _repeated_tags_codec);
+            break;
+          }
+          case 16: {
+            MatchMode = (global::brqueue.TagMatchMode) input.ReadEnum();
+            break;
+          }
+          case 24: {
+            Offset = input.ReadUInt64();
+            break;
+          }
+          case 32: {
+            Limit = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            tags_.AddEntriesFrom(ref input, _repeated_tags_codec);
+            break;
+          }
+          case 16: {
+            MatchMode = (global::brqueue.TagMatchMode) input.ReadEnum();
+            break;
+          }
+          case 24: {
+            Offset = input.ReadUInt64();
+            break;
+          }
+          case 32: {
+            Limit = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FoundMessage : pb::IMessage<FoundMessage>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FoundMessage> _parser = new pb::MessageParser<FoundMessage>(() => new FoundMessage());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FoundMessage> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[36]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FoundMessage() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FoundMessage(FoundMessage other) : this() {
+      id_ = other.id_;
+      message_ = other.message_;
+      priority_ = other.priority_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      enqueuedBy_ = other.enqueuedBy_;
+      requiredCapabilitiesTruncated_ = other.requiredCapabilitiesTruncated_;
+      priorityLevel_ = other.priorityLevel_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FoundMessage Clone() {
+      return new FoundMessage(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "message" field.</summary>
+    public const int MessageFieldNumber = 2;
+    private pb::ByteString message_ = pb::ByteString.Empty;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pb::ByteString Message {
+      get { return message_; }
+      set {
+        message_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "priority" field.</summary>
+    public const int PriorityFieldNumber = 3;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.Priority Priority {
+      get { return priority_; }
+      set {
+        priority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 4;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(34);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    /// <summary>Field number for the "enqueuedBy" field.</summary>
+    public const int EnqueuedByFieldNumber = 5;
+    private string enqueuedBy_ = "";
+    /// <summary>
+    /// The user that enqueued the message, empty if unknown
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string EnqueuedBy {
+      get { return enqueuedBy_; }
+      set {
+        enqueuedBy_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilitiesTruncated" field.</summary>
+    public const int RequiredCapabilitiesTruncatedFieldNumber = 6;
+    private bool requiredCapabilitiesTruncated_;
+    /// <summary>
+    /// True if the message requires more capabilities than the server returns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool RequiredCapabilitiesTruncated {
+      get { return requiredCapabilitiesTruncated_; }
+      set {
+        requiredCapabilitiesTruncated_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 7;
+    private uint priorityLevel_;
+    /// <summary>
+    /// The priority level the message was enqueued with
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FoundMessage);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FoundMessage other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if (Message != other.Message) return false;
+      if (Priority != other.Priority) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (EnqueuedBy != other.EnqueuedBy) return false;
+      if (RequiredCapabilitiesTruncated != other.RequiredCapabilitiesTruncated) return false;
+      if (PriorityLevel != other.PriorityLevel) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (Message.Length != 0) hash ^= Message.GetHashCode();
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (EnqueuedBy.Length != 0) hash ^= EnqueuedBy.GetHashCode();
+      if (RequiredCapabilitiesTruncated != false) hash ^= RequiredCapabilitiesTruncated.GetHashCode();
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (Message.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteBytes(Message);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(48);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(56);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (Message.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteBytes(Message);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(48);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(56);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (Message.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Message);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (EnqueuedBy.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        size += 1 + 1;
+      }
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FoundMessage other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.Message.Length != 0) {
+        Message = other.Message;
+      }
+      if (other.Priority != global::brqueue.Priority.Low) {
+        Priority = other.Priority;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.EnqueuedBy.Length != 0) {
+        EnqueuedBy = other.EnqueuedBy;
+      }
+      if (other.RequiredCapabilitiesTruncated != false) {
+        RequiredCapabilitiesTruncated = other.RequiredCapabilitiesTruncated;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            Message = input.ReadBytes();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 34: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 42: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 48: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+          case 56: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 18: {
+            Message = input.ReadBytes();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 34: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 42: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 48: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+          case 56: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class FindByTagResponse : pb::IMessage<FindByTagResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FindByTagResponse> _parser = new pb::MessageParser<FindByTagResponse>(() => new FindByTagResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<FindByTagResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[37]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagResponse(FindByTagResponse other) : this() {
+      messages_ = other.messages_.Clone();
+      total_ = other.total_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public FindByTagResponse Clone() {
+      return new FindByTagResponse(this);
+    }
+
+    /// <summary>Field number for the "messages" field.</summary>
+    public const int MessagesFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.FoundMessage> _repeated_messages_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.FoundMessage.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.FoundMessage> messages_ = new pbc::RepeatedField<global::brqueue.FoundMessage>();
+    /// <summary>
+    /// The requested page of matching messages, high priority messages first
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.FoundMessage> Messages {
+      get { return messages_; }
+    }
+
+    /// <summary>Field number for the "total" field.</summary>
+    public const int TotalFieldNumber = 2;
+    private ulong total_;
+    /// <summary>
+    /// The total number of matching messages
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Total {
+      get { return total_; }
+      set {
+        total_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as FindByTagResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(FindByTagResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!messages_.Equals(other.messages_)) return false;
+      if (Total != other.Total) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= messages_.GetHashCode();
+      if (Total != 0UL) hash ^= Total.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      messages_.WriteTo(output, _repeated_messages_codec);
+      if (Total != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Total);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      messages_.WriteTo(ref output, _repeated_messages_codec);
+      if (Total != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Total);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += messages_.CalculateSize(_repeated_messages_codec);
+      if (Total != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Total);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(FindByTagResponse other) {
+      if (other == null) {
+        return;
+      }
+      messages_.Add(other.messages_);
+      if (other.Total != 0UL) {
+        Total = other.Total;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(input, _repeated_messages_codec);
+            break;
+          }
+          case 16: {
+            Total = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(ref input, _repeated_messages_codec);
+            break;
+          }
+          case 16: {
+            Total = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Gets the message a worker with the capabilities would get next, without removing it
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PeekRequest : pb::IMessage<PeekRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PeekRequest> _parser = new pb::MessageParser<PeekRequest>(() => new PeekRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PeekRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[38]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekRequest(PeekRequest other) : this() {
+      availableCapabilities_ = other.availableCapabilities_.Clone();
+      onlyPriority_ = other.onlyPriority_;
+      priority_ = other.priority_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekRequest Clone() {
+      return new PeekRequest(this);
+    }
+
+    /// <summary>Field number for the "availableCapabilities" field.</summary>
+    public const int AvailableCapabilitiesFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_availableCapabilities_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> availableCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> AvailableCapabilities {
+      get { return availableCapabilities_; }
+    }
+
+    /// <summary>Field number for the "onlyPriority" field.</summary>
+    public const int OnlyPriorityFieldNumber = 2;
+    private bool onlyPriority_;
+    /// <summary>
+    /// If set only messages with the given priority are looked at
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool OnlyPriority {
+      get { return onlyPriority_; }
+      set {
+        onlyPriority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priority" field.</summary>
+    public const int PriorityFieldNumber = 3;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.Priority Priority {
+      get { return priority_; }
+      set {
+        priority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 4;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to look in. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PeekRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PeekRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!availableCapabilities_.Equals(other.availableCapabilities_)) return false;
+      if (OnlyPriority != other.OnlyPriority) return false;
+      if (Priority != other.Priority) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= availableCapabilities_.GetHashCode();
+      if (OnlyPriority != false) hash ^= OnlyPriority.GetHashCode();
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      availableCapabilities_.WriteTo(output, _repeated_availableCapabilities_codec);
+      if (OnlyPriority != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(OnlyPriority);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      availableCapabilities_.WriteTo(ref output, _repeated_availableCapabilities_codec);
+      if (OnlyPriority != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(OnlyPriority);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(24);
+        output.WriteEnum((int) Priority);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += availableCapabilities_.CalculateSize(_repeated_availableCapabilities_codec);
+      if (OnlyPriority != false) {
+        size += 1 + 1;
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PeekRequest other) {
+      if (other == null) {
+        return;
+      }
+      availableCapabilities_.Add(other.availableCapabilities_);
+      if (other.OnlyPriority != false) {
+        OnlyPriority = other.OnlyPriority;
+      }
+      if (other.Priority != global::brqueue.Priority.Low) {
+        Priority = other.Priority;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            OnlyPriority = input.ReadBool();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 34: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            availableCapabilities_.AddEntriesFrom(ref input, _repeated_availableCapabilities_codec);
+            break;
+          }
+          case 16: {
+            OnlyPriority = input.ReadBool();
+            break;
+          }
+          case 24: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 34: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PeekResponse : pb::IMessage<PeekResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PeekResponse> _parser = new pb::MessageParser<PeekResponse>(() => new PeekResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PeekResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[39]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekResponse(PeekResponse other) : this() {
+      hadResult_ = other.hadResult_;
+      message_ = other.message_ != null ? other.message_.Clone() : null;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PeekResponse Clone() {
+      return new PeekResponse(this);
+    }
+
+    /// <summary>Field number for the "hadResult" field.</summary>
+    public const int HadResultFieldNumber = 1;
+    private bool hadResult_;
+    /// <summary>
+    /// True if there was a message available
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool HadResult {
+      get { return hadResult_; }
+      set {
+        hadResult_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "message" field.</summary>
+    public const int MessageFieldNumber = 2;
+    private global::brqueue.FoundMessage message_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FoundMessage Message {
+      get { return message_; }
+      set {
+        message_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PeekResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PeekResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (HadResult != other.HadResult) return false;
+      if (!object.Equals(Message, other.Message)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (HadResult != false) hash ^= HadResult.GetHashCode();
+      if (message_ != null) hash ^= Message.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (HadResult != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(HadResult);
+      }
+      if (message_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Message);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (HadResult != false) {
+        output.WriteRawTag(8);
+        output.WriteBool(HadResult);
+      }
+      if (message_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Message);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (HadResult != false) {
+        size += 1 + 1;
+      }
+      if (message_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Message);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PeekResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.HadResult != false) {
+        HadResult = other.HadResult;
+      }
+      if (other.message_ != null) {
+        if (message_ == null) {
+          Message = new global::brqueue.FoundMessage();
+        }
+        Message.MergeFrom(other.Message);
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 18: {
+            if (message_ == null) {
+              Message = new global::brqueue.FoundMessage();
+            }
+            input.ReadMessage(Message);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            HadResult = input.ReadBool();
+            break;
+          }
+          case 18: {
+            if (message_ == null) {
+              Message = new global::brqueue.FoundMessage();
+            }
+            input.ReadMessage(Message);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Lists the queued messages a page at a time, in the order they were enqueued, without removing them.
+  /// Messages popped or enqueued while paging don't make the pages skip or repeat messages.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ListQueuedRequest : pb::IMessage<ListQueuedRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ListQueuedRequest> _parser = new pb::MessageParser<ListQueuedRequest>(() => new ListQueuedRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ListQueuedRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[40]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedRequest(ListQueuedRequest other) : this() {
+      cursor_ = other.cursor_;
+      limit_ = other.limit_;
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedRequest Clone() {
+      return new ListQueuedRequest(this);
+    }
+
+    /// <summary>Field number for the "cursor" field.</summary>
+    public const int CursorFieldNumber = 1;
+    private string cursor_ = "";
+    /// <summary>
+    /// The nextCursor of the previous page, empty for the first page
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Cursor {
+      get { return cursor_; }
+      set {
+        cursor_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "limit" field.</summary>
+    public const int LimitFieldNumber = 2;
+    private ulong limit_;
+    /// <summary>
+    /// The maximum number of messages to return, everything if 0
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Limit {
+      get { return limit_; }
+      set {
+        limit_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 3;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to list. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ListQueuedRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ListQueuedRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Cursor != other.Cursor) return false;
+      if (Limit != other.Limit) return false;
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Cursor.Length != 0) hash ^= Cursor.GetHashCode();
+      if (Limit != 0UL) hash ^= Limit.GetHashCode();
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Cursor.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Cursor);
+      }
+      if (Limit != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Limit);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Cursor.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Cursor);
+      }
+      if (Limit != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Limit);
+      }
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Cursor.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Cursor);
+      }
+      if (Limit != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Limit);
+      }
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ListQueuedRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Cursor.Length != 0) {
+        Cursor = other.Cursor;
+      }
+      if (other.Limit != 0UL) {
+        Limit = other.Limit;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Cursor = input.ReadString();
+            break;
+          }
+          case 16: {
+            Limit = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Cursor = input.ReadString();
+            break;
+          }
+          case 16: {
+            Limit = input.ReadUInt64();
+            break;
+          }
+          case 26: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ListQueuedResponse : pb::IMessage<ListQueuedResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ListQueuedResponse> _parser = new pb::MessageParser<ListQueuedResponse>(() => new ListQueuedResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ListQueuedResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[41]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedResponse(ListQueuedResponse other) : this() {
+      messages_ = other.messages_.Clone();
+      nextCursor_ = other.nextCursor_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListQueuedResponse Clone() {
+      return new ListQueuedResponse(this);
+    }
+
+    /// <summary>Field number for the "messages" field.</summary>
+    public const int MessagesFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.FoundMessage> _repeated_messages_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.FoundMessage.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.FoundMessage> messages_ = new pbc::RepeatedField<global::brqueue.FoundMessage>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.FoundMessage> Messages {
+      get { return messages_; }
+    }
+
+    /// <summary>Field number for the "nextCursor" field.</summary>
+    public const int NextCursorFieldNumber = 2;
+    private string nextCursor_ = "";
+    /// <summary>
+    /// Passed in the next request to get the next page, empty if there are no more messages
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string NextCursor {
+      get { return nextCursor_; }
+      set {
+        nextCursor_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ListQueuedResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ListQueuedResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!messages_.Equals(other.messages_)) return false;
+      if (NextCursor != other.NextCursor) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= messages_.GetHashCode();
+      if (NextCursor.Length != 0) hash ^= NextCursor.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      messages_.WriteTo(output, _repeated_messages_codec);
+      if (NextCursor.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(NextCursor);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      messages_.WriteTo(ref output, _repeated_messages_codec);
+      if (NextCursor.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(NextCursor);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += messages_.CalculateSize(_repeated_messages_codec);
+      if (NextCursor.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(NextCursor);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ListQueuedResponse other) {
+      if (other == null) {
+        return;
+      }
+      messages_.Add(other.messages_);
+      if (other.NextCursor.Length != 0) {
+        NextCursor = other.NextCursor;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(input, _repeated_messages_codec);
+            break;
+          }
+          case 18: {
+            NextCursor = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(ref input, _repeated_messages_codec);
+            break;
+          }
+          case 18: {
+            NextCursor = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Lists the messages currently being processed, for finding stuck work.
+  /// Only admins can do this.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ListProcessingRequest : pb::IMessage<ListProcessingRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ListProcessingRequest> _parser = new pb::MessageParser<ListProcessingRequest>(() => new ListProcessingRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ListProcessingRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[42]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingRequest(ListProcessingRequest other) : this() {
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingRequest Clone() {
+      return new ListProcessingRequest(this);
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 1;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to list. Uses the default queue if empty.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ListProcessingRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ListProcessingRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ListProcessingRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ProcessingMessage : pb::IMessage<ProcessingMessage>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ProcessingMessage> _parser = new pb::MessageParser<ProcessingMessage>(() => new ProcessingMessage());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ProcessingMessage> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[43]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ProcessingMessage() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ProcessingMessage(ProcessingMessage other) : this() {
+      id_ = other.id_;
+      priority_ = other.priority_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      ageMilliseconds_ = other.ageMilliseconds_;
+      worker_ = other.worker_;
+      enqueuedBy_ = other.enqueuedBy_;
+      requiredCapabilitiesTruncated_ = other.requiredCapabilitiesTruncated_;
+      priorityLevel_ = other.priorityLevel_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ProcessingMessage Clone() {
+      return new ProcessingMessage(this);
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 1;
+    private string id_ = "";
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "priority" field.</summary>
+    public const int PriorityFieldNumber = 2;
+    private global::brqueue.Priority priority_ = global::brqueue.Priority.Low;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.Priority Priority {
+      get { return priority_; }
+      set {
+        priority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 3;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(26);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    /// <summary>Field number for the "ageMilliseconds" field.</summary>
+    public const int AgeMillisecondsFieldNumber = 4;
+    private ulong ageMilliseconds_;
+    /// <summary>
+    /// How long ago the message was popped
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong AgeMilliseconds {
+      get { return ageMilliseconds_; }
+      set {
+        ageMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "worker" field.</summary>
+    public const int WorkerFieldNumber = 5;
+    private string worker_ = "";
+    /// <summary>
+    /// The session of the worker processing the message, empty if unknown
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Worker {
+      get { return worker_; }
+      set {
+        worker_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "enqueuedBy" field.</summary>
+    public const int EnqueuedByFieldNumber = 6;
+    private string enqueuedBy_ = "";
+    /// <summary>
+    /// The user that enqueued the message, empty if unknown
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string EnqueuedBy {
+      get { return enqueuedBy_; }
+      set {
+        enqueuedBy_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilitiesTruncated" field.</summary>
+    public const int RequiredCapabilitiesTruncatedFieldNumber = 7;
+    private bool requiredCapabilitiesTruncated_;
+    /// <summary>
+    /// True if the message requires more capabilities than the server returns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool RequiredCapabilitiesTruncated {
+      get { return requiredCapabilitiesTruncated_; }
+      set {
+        requiredCapabilitiesTruncated_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 8;
+    private uint priorityLevel_;
+    /// <summary>
+    /// The priority level the message was enqueued with
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ProcessingMessage);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ProcessingMessage other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Id != other.Id) return false;
+      if (Priority != other.Priority) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (AgeMilliseconds != other.AgeMilliseconds) return false;
+      if (Worker != other.Worker) return false;
+      if (EnqueuedBy != other.EnqueuedBy) return false;
+      if (RequiredCapabilitiesTruncated != other.RequiredCapabilitiesTruncated) return false;
+      if (PriorityLevel != other.PriorityLevel) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (Priority != global::brqueue.Priority.Low) hash ^= Priority.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (AgeMilliseconds != 0UL) hash ^= AgeMilliseconds.GetHashCode();
+      if (Worker.Length != 0) hash ^= Worker.GetHashCode();
+      if (EnqueuedBy.Length != 0) hash ^= EnqueuedBy.GetHashCode();
+      if (RequiredCapabilitiesTruncated != false) hash ^= RequiredCapabilitiesTruncated.GetHashCode();
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (AgeMilliseconds != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(AgeMilliseconds);
+      }
+      if (Worker.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(Worker);
+      }
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(56);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(64);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Id.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Id);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Priority);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (AgeMilliseconds != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(AgeMilliseconds);
+      }
+      if (Worker.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(Worker);
+      }
+      if (EnqueuedBy.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        output.WriteRawTag(56);
+        output.WriteBool(RequiredCapabilitiesTruncated);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(64);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (Priority != global::brqueue.Priority.Low) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Priority);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (AgeMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(AgeMilliseconds);
+      }
+      if (Worker.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Worker);
+      }
+      if (EnqueuedBy.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(EnqueuedBy);
+      }
+      if (RequiredCapabilitiesTruncated != false) {
+        size += 1 + 1;
+      }
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ProcessingMessage other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.Priority != global::brqueue.Priority.Low) {
+        Priority = other.Priority;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.AgeMilliseconds != 0UL) {
+        AgeMilliseconds = other.AgeMilliseconds;
+      }
+      if (other.Worker.Length != 0) {
+        Worker = other.Worker;
+      }
+      if (other.EnqueuedBy.Length != 0) {
+        EnqueuedBy = other.EnqueuedBy;
+      }
+      if (other.RequiredCapabilitiesTruncated != false) {
+        RequiredCapabilitiesTruncated = other.RequiredCapabilitiesTruncated;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 16: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 26: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 32: {
+            AgeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            Worker = input.ReadString();
+            break;
+          }
+          case 50: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 56: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+          case 64: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Id = input.ReadString();
+            break;
+          }
+          case 16: {
+            Priority = (global::brqueue.Priority) input.ReadEnum();
+            break;
+          }
+          case 26: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 32: {
+            AgeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 42: {
+            Worker = input.ReadString();
+            break;
+          }
+          case 50: {
+            EnqueuedBy = input.ReadString();
+            break;
+          }
+          case 56: {
+            RequiredCapabilitiesTruncated = input.ReadBool();
+            break;
+          }
+          case 64: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ListProcessingResponse : pb::IMessage<ListProcessingResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ListProcessingResponse> _parser = new pb::MessageParser<ListProcessingResponse>(() => new ListProcessingResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ListProcessingResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[44]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingResponse(ListProcessingResponse other) : this() {
+      messages_ = other.messages_.Clone();
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ListProcessingResponse Clone() {
+      return new ListProcessingResponse(this);
+    }
+
+    /// <summary>Field number for the "messages" field.</summary>
+    public const int MessagesFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.ProcessingMessage> _repeated_messages_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.ProcessingMessage.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.ProcessingMessage> messages_ = new pbc::RepeatedField<global::brqueue.ProcessingMessage>();
+    /// <summary>
+    /// The messages being processed, the one that has been processing the longest first
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.ProcessingMessage> Messages {
+      get { return messages_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ListProcessingResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ListProcessingResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!messages_.Equals(other.messages_)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= messages_.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      messages_.WriteTo(output, _repeated_messages_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      messages_.WriteTo(ref output, _repeated_messages_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += messages_.CalculateSize(_repeated_messages_codec);
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ListProcessingResponse other) {
+      if (other == null) {
+        return;
+      }
+      messages_.Add(other.messages_);
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(input, _repeated_messages_codec);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            messages_.AddEntriesFrom(ref input, _repeated_messages_codec);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Checks how well the server is doing
+  /// Stops or resumes handing out tasks that require a capability, while tasks
+  /// not requiring it are still handed out. Only admins can do this.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class SetCapabilityPausedRequest : pb::IMessage<SetCapabilityPausedRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<SetCapabilityPausedRequest> _parser = new pb::MessageParser<SetCapabilityPausedRequest>(() => new SetCapabilityPausedRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<SetCapabilityPausedRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[45]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedRequest(SetCapabilityPausedRequest other) : this() {
+      capability_ = other.capability_;
+      paused_ = other.paused_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedRequest Clone() {
+      return new SetCapabilityPausedRequest(this);
+    }
+
+    /// <summary>Field number for the "capability" field.</summary>
+    public const int CapabilityFieldNumber = 1;
+    private string capability_ = "";
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Capability {
+      get { return capability_; }
+      set {
+        capability_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "paused" field.</summary>
+    public const int PausedFieldNumber = 2;
+    private bool paused_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Paused {
+      get { return paused_; }
+      set {
+        paused_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as SetCapabilityPausedRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(SetCapabilityPausedRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Capability != other.Capability) return false;
+      if (Paused != other.Paused) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Capability.Length != 0) hash ^= Capability.GetHashCode();
+      if (Paused != false) hash ^= Paused.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Capability.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Capability);
+      }
+      if (Paused != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(Paused);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Capability.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Capability);
+      }
+      if (Paused != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(Paused);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Capability.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Capability);
+      }
+      if (Paused != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(SetCapabilityPausedRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Capability.Length != 0) {
+        Capability = other.Capability;
+      }
+      if (other.Paused != false) {
+        Paused = other.Paused;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Capability = input.ReadString();
+            break;
+          }
+          case 16: {
+            Paused = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Capability = input.ReadString();
+            break;
+          }
+          case 16: {
+            Paused = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class SetCapabilityPausedResponse : pb::IMessage<SetCapabilityPausedResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<SetCapabilityPausedResponse> _parser = new pb::MessageParser<SetCapabilityPausedResponse>(() => new SetCapabilityPausedResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<SetCapabilityPausedResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[46]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedResponse(SetCapabilityPausedResponse other) : this() {
+      pausedCapabilities_ = other.pausedCapabilities_.Clone();
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public SetCapabilityPausedResponse Clone() {
+      return new SetCapabilityPausedResponse(this);
+    }
+
+    /// <summary>Field number for the "pausedCapabilities" field.</summary>
+    public const int PausedCapabilitiesFieldNumber = 1;
+    private static readonly pb::FieldCodec<string> _repeated_pausedCapabilities_codec
+        = pb::FieldCodec.ForString(10);
+    private readonly pbc::RepeatedField<string> pausedCapabilities_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// Every capability that is paused after the change
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> PausedCapabilities {
+      get { return pausedCapabilities_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as SetCapabilityPausedResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(SetCapabilityPausedResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!pausedCapabilities_.Equals(other.pausedCapabilities_)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= pausedCapabilities_.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      pausedCapabilities_.WriteTo(output, _repeated_pausedCapabilities_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      pausedCapabilities_.WriteTo(ref output, _repeated_pausedCapabilities_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += pausedCapabilities_.CalculateSize(_repeated_pausedCapabilities_codec);
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(SetCapabilityPausedResponse other) {
+      if (other == null) {
+        return;
+      }
+      pausedCapabilities_.Add(other.pausedCapabilities_);
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            pausedCapabilities_.AddEntriesFrom(input, _repeated_pausedCapabilities_codec);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            pausedCapabilities_.AddEntriesFrom(ref input, _repeated_pausedCapabilities_codec);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class HealthRequest : pb::IMessage<HealthRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<HealthRequest> _parser = new pb::MessageParser<HealthRequest>(() => new HealthRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<HealthRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[47]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthRequest(HealthRequest other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthRequest Clone() {
+      return new HealthRequest(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as HealthRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(HealthRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(HealthRequest other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class LevelScanDepth : pb::IMessage<LevelScanDepth>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<LevelScanDepth> _parser = new pb::MessageParser<LevelScanDepth>(() => new LevelScanDepth());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<LevelScanDepth> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[48]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public LevelScanDepth() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public LevelScanDepth(LevelScanDepth other) : this() {
+      priorityLevel_ = other.priorityLevel_;
+      scanDepth_ = other.scanDepth_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public LevelScanDepth Clone() {
+      return new LevelScanDepth(this);
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 1;
+    private uint priorityLevel_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "scanDepth" field.</summary>
+    public const int ScanDepthFieldNumber = 2;
+    private double scanDepth_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public double ScanDepth {
+      get { return scanDepth_; }
+      set {
+        scanDepth_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as LevelScanDepth);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(LevelScanDepth other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (PriorityLevel != other.PriorityLevel) return false;
+      if (!pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.Equals(ScanDepth, other.ScanDepth)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
+      if (ScanDepth != 0D) hash ^= pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.GetHashCode(ScanDepth);
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (ScanDepth != 0D) {
+        output.WriteRawTag(17);
+        output.WriteDouble(ScanDepth);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (ScanDepth != 0D) {
+        output.WriteRawTag(17);
+        output.WriteDouble(ScanDepth);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
+      if (ScanDepth != 0D) {
+        size += 1 + 8;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(LevelScanDepth other) {
+      if (other == null) {
+        return;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
+      if (other.ScanDepth != 0D) {
+        ScanDepth = other.ScanDepth;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 17: {
+            ScanDepth = input.ReadDouble();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 17: {
+            ScanDepth = input.ReadDouble();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class HealthResponse : pb::IMessage<HealthResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<HealthResponse> _parser = new pb::MessageParser<HealthResponse>(() => new HealthResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<HealthResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[49]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthResponse(HealthResponse other) : this() {
+      highPriorityScanDepth_ = other.highPriorityScanDepth_;
+      lowPriorityScanDepth_ = other.lowPriorityScanDepth_;
+      estimatedDrainSeconds_ = other.estimatedDrainSeconds_;
+      ready_ = other.ready_;
+      scanDepthByLevel_ = other.scanDepthByLevel_.Clone();
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public HealthResponse Clone() {
+      return new HealthResponse(this);
+    }
+
+    /// <summary>Field number for the "highPriorityScanDepth" field.</summary>
+    public const int HighPriorityScanDepthFieldNumber = 1;
+    private double highPriorityScanDepth_;
+    /// <summary>
+    /// The average number of distinct sets of required capabilities per pop in the high priority
+    /// queue, that the popping worker couldn't handle. A consistently high number indicates
+    /// that the queued tasks doesn't match the capabilities of the workers
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public double HighPriorityScanDepth {
+      get { return highPriorityScanDepth_; }
+      set {
+        highPriorityScanDepth_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "lowPriorityScanDepth" field.</summary>
+    public const int LowPriorityScanDepthFieldNumber = 2;
+    private double lowPriorityScanDepth_;
+    /// <summary>
+    /// The same for the low priority queue
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public double LowPriorityScanDepth {
+      get { return lowPriorityScanDepth_; }
+      set {
+        lowPriorityScanDepth_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "estimatedDrainSeconds" field.</summary>
+    public const int EstimatedDrainSecondsFieldNumber = 3;
+    private double estimatedDrainSeconds_;
+    /// <summary>
+    /// How long it will take to empty the queue at the rate tasks has been popped recently.
+    /// Negative if nothing has been popped recently, so there is nothing to estimate from.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public double EstimatedDrainSeconds {
+      get { return estimatedDrainSeconds_; }
+      set {
+        estimatedDrainSeconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "ready" field.</summary>
+    public const int ReadyFieldNumber = 4;
+    private bool ready_;
+    /// <summary>
+    /// False while the server is loading the stored tasks, during which pops are rejected
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Ready {
+      get { return ready_; }
+      set {
+        ready_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "scanDepthByLevel" field.</summary>
+    public const int ScanDepthByLevelFieldNumber = 5;
+    private static readonly pb::FieldCodec<global::brqueue.LevelScanDepth> _repeated_scanDepthByLevel_codec
+        = pb::FieldCodec.ForMessage(42, global::brqueue.LevelScanDepth.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.LevelScanDepth> scanDepthByLevel_ = new pbc::RepeatedField<global::brqueue.LevelScanDepth>();
+    /// <summary>
+    /// The scan depth of every priority level, from the highest level down
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.LevelScanDepth> ScanDepthByLevel {
+      get { return scanDepthByLevel_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as HealthResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(HealthResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (!pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.Equals(HighPriorityScanDepth, other.HighPriorityScanDepth)) return false;
+      if (!pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.Equals(LowPriorityScanDepth, other.LowPriorityScanDepth)) return false;
+      if (!pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.Equals(EstimatedDrainSeconds, other.EstimatedDrainSeconds)) return false;
+      if (Ready != other.Ready) return false;
+      if(!scanDepthByLevel_.Equals(other.scanDepthByLevel_)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (HighPriorityScanDepth != 0D) hash ^= pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.GetHashCode(HighPriorityScanDepth);
+      if (LowPriorityScanDepth != 0D) hash ^= pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.GetHashCode(LowPriorityScanDepth);
+      if (EstimatedDrainSeconds != 0D) hash ^= pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.GetHashCode(EstimatedDrainSeconds);
+      if (Ready != false) hash ^= Ready.GetHashCode();
+      hash ^= scanDepthByLevel_.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (HighPriorityScanDepth != 0D) {
+        output.WriteRawTag(9);
+        output.WriteDouble(HighPriorityScanDepth);
+      }
+      if (LowPriorityScanDepth != 0D) {
+        output.WriteRawTag(17);
+        output.WriteDouble(LowPriorityScanDepth);
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        output.WriteRawTag(25);
+        output.WriteDouble(EstimatedDrainSeconds);
+      }
+      if (Ready != false) {
+        output.WriteRawTag(32);
+        output.WriteBool(Ready);
+      }
+      scanDepthByLevel_.WriteTo(output, _repeated_scanDepthByLevel_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (HighPriorityScanDepth != 0D) {
+        output.WriteRawTag(9);
+        output.WriteDouble(HighPriorityScanDepth);
+      }
+      if (LowPriorityScanDepth != 0D) {
+        output.WriteRawTag(17);
+        output.WriteDouble(LowPriorityScanDepth);
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        output.WriteRawTag(25);
+        output.WriteDouble(EstimatedDrainSeconds);
+      }
+      if (Ready != false) {
+        output.WriteRawTag(32);
+        output.WriteBool(Ready);
+      }
+      scanDepthByLevel_.WriteTo(ref output, _repeated_scanDepthByLevel_codec);
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (HighPriorityScanDepth != 0D) {
+        size += 1 + 8;
+      }
+      if (LowPriorityScanDepth != 0D) {
+        size += 1 + 8;
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        size += 1 + 8;
+      }
+      if (Ready != false) {
+        size += 1 + 1;
+      }
+      size += scanDepthByLevel_.CalculateSize(_repeated_scanDepthByLevel_codec);
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(HealthResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.HighPriorityScanDepth != 0D) {
+        HighPriorityScanDepth = other.HighPriorityScanDepth;
+      }
+      if (other.LowPriorityScanDepth != 0D) {
+        LowPriorityScanDepth = other.LowPriorityScanDepth;
+      }
+      if (other.EstimatedDrainSeconds != 0D) {
+        EstimatedDrainSeconds = other.EstimatedDrainSeconds;
+      }
+      if (other.Ready != false) {
+        Ready = other.Ready;
+      }
+      scanDepthByLevel_.Add(other.scanDepthByLevel_);
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 9: {
+            HighPriorityScanDepth = input.ReadDouble();
+            break;
+          }
+          case 17: {
+            LowPriorityScanDepth = input.ReadDouble();
+            break;
+          }
+          case 25: {
+            EstimatedDrainSeconds = input.ReadDouble();
+            break;
+          }
+          case 32: {
+            Ready = input.ReadBool();
+            break;
+          }
+          case 42: {
+            scanDepthByLevel_.AddEntriesFrom(input, _repeated_scanDepthByLevel_codec);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 9: {
+            HighPriorityScanDepth = input.ReadDouble();
+            break;
+          }
+          case 17: {
+            LowPriorityScanDepth = input.ReadDouble();
+            break;
+          }
+          case 25: {
+            EstimatedDrainSeconds = input.ReadDouble();
+            break;
+          }
+          case 32: {
+            Ready = input.ReadBool();
+            break;
+          }
+          case 42: {
+            scanDepthByLevel_.AddEntriesFrom(ref input, _repeated_scanDepthByLevel_codec);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Gets how many messages are waiting and being processed
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class StatsRequest : pb::IMessage<StatsRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<StatsRequest> _parser = new pb::MessageParser<StatsRequest>(() => new StatsRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<StatsRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[50]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsRequest(StatsRequest other) : this() {
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsRequest Clone() {
+      return new StatsRequest(this);
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 1;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to get the counts of, the default queue if empty
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as StatsRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(StatsRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(StatsRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PendingCount : pb::IMessage<PendingCount>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PendingCount> _parser = new pb::MessageParser<PendingCount>(() => new PendingCount());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PendingCount> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[51]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PendingCount() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PendingCount(PendingCount other) : this() {
+      priorityLevel_ = other.priorityLevel_;
+      count_ = other.count_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PendingCount Clone() {
+      return new PendingCount(this);
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 1;
+    private uint priorityLevel_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "count" field.</summary>
+    public const int CountFieldNumber = 2;
+    private ulong count_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Count {
+      get { return count_; }
+      set {
+        count_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PendingCount);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PendingCount other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (PriorityLevel != other.PriorityLevel) return false;
+      if (Count != other.Count) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
+      if (Count != 0UL) hash ^= Count.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (Count != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Count);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(PriorityLevel);
+      }
+      if (Count != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Count);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
+      if (Count != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Count);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PendingCount other) {
+      if (other == null) {
+        return;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
+      if (other.Count != 0UL) {
+        Count = other.Count;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 16: {
+            Count = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 16: {
+            Count = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class StatsResponse : pb::IMessage<StatsResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<StatsResponse> _parser = new pb::MessageParser<StatsResponse>(() => new StatsResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<StatsResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[52]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsResponse(StatsResponse other) : this() {
+      pending_ = other.pending_.Clone();
+      queued_ = other.queued_;
+      processing_ = other.processing_;
+      highPriority_ = other.highPriority_;
+      lowPriority_ = other.lowPriority_;
+      enqueued_ = other.enqueued_;
+      acknowledged_ = other.acknowledged_;
+      estimatedDrainSeconds_ = other.estimatedDrainSeconds_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StatsResponse Clone() {
+      return new StatsResponse(this);
+    }
+
+    /// <summary>Field number for the "pending" field.</summary>
+    public const int PendingFieldNumber = 1;
+    private static readonly pb::FieldCodec<global::brqueue.PendingCount> _repeated_pending_codec
+        = pb::FieldCodec.ForMessage(10, global::brqueue.PendingCount.Parser);
+    private readonly pbc::RepeatedField<global::brqueue.PendingCount> pending_ = new pbc::RepeatedField<global::brqueue.PendingCount>();
+    /// <summary>
+    /// How many messages are waiting in memory at each priority level, from the highest level down
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<global::brqueue.PendingCount> Pending {
+      get { return pending_; }
+    }
+
+    /// <summary>Field number for the "queued" field.</summary>
+    public const int QueuedFieldNumber = 2;
+    private ulong queued_;
+    /// <summary>
+    /// Every waiting message, including the ones only stored on disk because of the memory limit
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Queued {
+      get { return queued_; }
+      set {
+        queued_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "processing" field.</summary>
+    public const int ProcessingFieldNumber = 3;
+    private ulong processing_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Processing {
+      get { return processing_; }
+      set {
+        processing_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "highPriority" field.</summary>
+    public const int HighPriorityFieldNumber = 4;
+    private ulong highPriority_;
+    /// <summary>
+    /// How many messages are waiting in memory with high priority or above
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong HighPriority {
+      get { return highPriority_; }
+      set {
+        highPriority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "lowPriority" field.</summary>
+    public const int LowPriorityFieldNumber = 5;
+    private ulong lowPriority_;
+    /// <summary>
+    /// How many messages are waiting in memory with low priority
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong LowPriority {
+      get { return lowPriority_; }
+      set {
+        lowPriority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "enqueued" field.</summary>
+    public const int EnqueuedFieldNumber = 6;
+    private ulong enqueued_;
+    /// <summary>
+    /// How many messages have been enqueued and acknowledged since the server started
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Enqueued {
+      get { return enqueued_; }
+      set {
+        enqueued_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "acknowledged" field.</summary>
+    public const int AcknowledgedFieldNumber = 7;
+    private ulong acknowledged_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Acknowledged {
+      get { return acknowledged_; }
+      set {
+        acknowledged_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "estimatedDrainSeconds" field.</summary>
+    public const int EstimatedDrainSecondsFieldNumber = 8;
+    private double estimatedDrainSeconds_;
+    /// <summary>
+    /// How long it will take to empty the queue at the rate tasks has been popped recently.
+    /// Negative if nothing has been popped recently, so there is nothing to estimate from.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public double EstimatedDrainSeconds {
+      get { return estimatedDrainSeconds_; }
+      set {
+        estimatedDrainSeconds_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as StatsResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(StatsResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if(!pending_.Equals(other.pending_)) return false;
+      if (Queued != other.Queued) return false;
+      if (Processing != other.Processing) return false;
+      if (HighPriority != other.HighPriority) return false;
+      if (LowPriority != other.LowPriority) return false;
+      if (Enqueued != other.Enqueued) return false;
+      if (Acknowledged != other.Acknowledged) return false;
+      if (!pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.Equals(EstimatedDrainSeconds, other.EstimatedDrainSeconds)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      hash ^= pending_.GetHashCode();
+      if (Queued != 0UL) hash ^= Queued.GetHashCode();
+      if (Processing != 0UL) hash ^= Processing.GetHashCode();
+      if (HighPriority != 0UL) hash ^= HighPriority.GetHashCode();
+      if (LowPriority != 0UL) hash ^= LowPriority.GetHashCode();
+      if (Enqueued != 0UL) hash ^= Enqueued.GetHashCode();
+      if (Acknowledged != 0UL) hash ^= Acknowledged.GetHashCode();
+      if (EstimatedDrainSeconds != 0D) hash ^= pbc::ProtobufEqualityComparers.BitwiseDoubleEqualityComparer.GetHashCode(EstimatedDrainSeconds);
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      pending_.WriteTo(output, _repeated_pending_codec);
+      if (Queued != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Queued);
+      }
+      if (Processing != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(Processing);
+      }
+      if (HighPriority != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(HighPriority);
+      }
+      if (LowPriority != 0UL) {
+        output.WriteRawTag(40);
+        output.WriteUInt64(LowPriority);
+      }
+      if (Enqueued != 0UL) {
+        output.WriteRawTag(48);
+        output.WriteUInt64(Enqueued);
+      }
+      if (Acknowledged != 0UL) {
+        output.WriteRawTag(56);
+        output.WriteUInt64(Acknowledged);
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        output.WriteRawTag(65);
+        output.WriteDouble(EstimatedDrainSeconds);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      pending_.WriteTo(ref output, _repeated_pending_codec);
+      if (Queued != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(Queued);
+      }
+      if (Processing != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(Processing);
+      }
+      if (HighPriority != 0UL) {
+        output.WriteRawTag(32);
+        output.WriteUInt64(HighPriority);
+      }
+      if (LowPriority != 0UL) {
+        output.WriteRawTag(40);
+        output.WriteUInt64(LowPriority);
+      }
+      if (Enqueued != 0UL) {
+        output.WriteRawTag(48);
+        output.WriteUInt64(Enqueued);
+      }
+      if (Acknowledged != 0UL) {
+        output.WriteRawTag(56);
+        output.WriteUInt64(Acknowledged);
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        output.WriteRawTag(65);
+        output.WriteDouble(EstimatedDrainSeconds);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      size += pending_.CalculateSize(_repeated_pending_codec);
+      if (Queued != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Queued);
+      }
+      if (Processing != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Processing);
+      }
+      if (HighPriority != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(HighPriority);
+      }
+      if (LowPriority != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(LowPriority);
+      }
+      if (Enqueued != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Enqueued);
+      }
+      if (Acknowledged != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Acknowledged);
+      }
+      if (EstimatedDrainSeconds != 0D) {
+        size += 1 + 8;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(StatsResponse other) {
+      if (other == null) {
+        return;
+      }
+      pending_.Add(other.pending_);
+      if (other.Queued != 0UL) {
+        Queued = other.Queued;
+      }
+      if (other.Processing != 0UL) {
+        Processing = other.Processing;
+      }
+      if (other.HighPriority != 0UL) {
+        HighPriority = other.HighPriority;
+      }
+      if (other.LowPriority != 0UL) {
+        LowPriority = other.LowPriority;
+      }
+      if (other.Enqueued != 0UL) {
+        Enqueued = other.Enqueued;
+      }
+      if (other.Acknowledged != 0UL) {
+        Acknowledged = other.Acknowledged;
+      }
+      if (other.EstimatedDrainSeconds != 0D) {
+        EstimatedDrainSeconds = other.EstimatedDrainSeconds;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            pending_.AddEntriesFrom(input, _repeated_pending_codec);
+            break;
+          }
+          case 16: {
+            Queued = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            Processing = input.ReadUInt64();
+            break;
+          }
+          case 32: {
+            HighPriority = input.ReadUInt64();
+            break;
+          }
+          case 40: {
+            LowPriority = input.ReadUInt64();
+            break;
+          }
+          case 48: {
+            Enqueued = input.ReadUInt64();
+            break;
+          }
+          case 56: {
+            Acknowledged = input.ReadUInt64();
+            break;
+          }
+          case 65: {
+            EstimatedDrainSeconds = input.ReadDouble();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            pending_.AddEntriesFrom(ref input, _repeated_pending_codec);
+            break;
+          }
+          case 16: {
+            Queued = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            Processing = input.ReadUInt64();
+            break;
+          }
+          case 32: {
+            HighPriority = input.ReadUInt64();
+            break;
+          }
+          case 40: {
+            LowPriority = input.ReadUInt64();
+            break;
+          }
+          case 48: {
+            Enqueued = input.ReadUInt64();
+            break;
+          }
+          case 56: {
+            Acknowledged = input.ReadUInt64();
+            break;
+          }
+          case 65: {
+            EstimatedDrainSeconds = input.ReadDouble();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Removes every waiting and processing message of a queue. Only admins can purge.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PurgeRequest : pb::IMessage<PurgeRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PurgeRequest> _parser = new pb::MessageParser<PurgeRequest>(() => new PurgeRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PurgeRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[53]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeRequest(PurgeRequest other) : this() {
+      queueName_ = other.queueName_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeRequest Clone() {
+      return new PurgeRequest(this);
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 1;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to purge, the default queue if empty
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PurgeRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PurgeRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (QueueName != other.QueueName) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PurgeRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class PurgeResponse : pb::IMessage<PurgeResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<PurgeResponse> _parser = new pb::MessageParser<PurgeResponse>(() => new PurgeResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<PurgeResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[54]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeResponse(PurgeResponse other) : this() {
+      removed_ = other.removed_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public PurgeResponse Clone() {
+      return new PurgeResponse(this);
+    }
+
+    /// <summary>Field number for the "removed" field.</summary>
+    public const int RemovedFieldNumber = 1;
+    private ulong removed_;
+    /// <summary>
+    /// How many messages were removed
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Removed {
+      get { return removed_; }
+      set {
+        removed_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as PurgeResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(PurgeResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Removed != other.Removed) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Removed != 0UL) hash ^= Removed.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Removed != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Removed);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Removed != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Removed);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Removed != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Removed);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(PurgeResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Removed != 0UL) {
+        Removed = other.Removed;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Removed = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Removed = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Exports the dead letters of a queue as json, one message per line, for offline analysis.
+  /// Only admins can export dead letters.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ExportDeadLettersRequest : pb::IMessage<ExportDeadLettersRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ExportDeadLettersRequest> _parser = new pb::MessageParser<ExportDeadLettersRequest>(() => new ExportDeadLettersRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ExportDeadLettersRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[55]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersRequest(ExportDeadLettersRequest other) : this() {
+      queueName_ = other.queueName_;
+      clear_ = other.clear_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersRequest Clone() {
+      return new ExportDeadLettersRequest(this);
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 1;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to export the dead letters of, the default queue if empty
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "clear" field.</summary>
+    public const int ClearFieldNumber = 2;
+    private bool clear_;
+    /// <summary>
+    /// If set the exported messages are removed from the dead letters
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Clear {
+      get { return clear_; }
+      set {
+        clear_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ExportDeadLettersRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ExportDeadLettersRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (QueueName != other.QueueName) return false;
+      if (Clear != other.Clear) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (Clear != false) hash ^= Clear.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (Clear != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(Clear);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (Clear != false) {
+        output.WriteRawTag(16);
+        output.WriteBool(Clear);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (Clear != false) {
+        size += 1 + 1;
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ExportDeadLettersRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      if (other.Clear != false) {
+        Clear = other.Clear;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 16: {
+            Clear = input.ReadBool();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 16: {
+            Clear = input.ReadBool();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ExportDeadLettersResponse : pb::IMessage<ExportDeadLettersResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ExportDeadLettersResponse> _parser = new pb::MessageParser<ExportDeadLettersResponse>(() => new ExportDeadLettersResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ExportDeadLettersResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[56]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersResponse(ExportDeadLettersResponse other) : this() {
+      exported_ = other.exported_;
+      json_ = other.json_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ExportDeadLettersResponse Clone() {
+      return new ExportDeadLettersResponse(this);
+    }
+
+    /// <summary>Field number for the "exported" field.</summary>
+    public const int ExportedFieldNumber = 1;
+    private ulong exported_;
+    /// <summary>
+    /// How many dead letters were exported
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Exported {
+      get { return exported_; }
+      set {
+        exported_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "json" field.</summary>
+    public const int JsonFieldNumber = 2;
+    private string json_ = "";
+    /// <summary>
+    /// The dead letters with their attempts, as json objects separated by newlines
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Json {
+      get { return json_; }
+      set {
+        json_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ExportDeadLettersResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ExportDeadLettersResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Exported != other.Exported) return false;
+      if (Json != other.Json) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Exported != 0UL) hash ^= Exported.GetHashCode();
+      if (Json.Length != 0) hash ^= Json.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Exported != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Exported);
+      }
+      if (Json.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Json);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Exported != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Exported);
+      }
+      if (Json.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Json);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Exported != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Exported);
+      }
+      if (Json.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Json);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ExportDeadLettersResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Exported != 0UL) {
+        Exported = other.Exported;
+      }
+      if (other.Json.Length != 0) {
+        Json = other.Json;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Exported = input.ReadUInt64();
+            break;
+          }
+          case 18: {
+            Json = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Exported = input.ReadUInt64();
+            break;
+          }
+          case 18: {
+            Json = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Streams what happens to the messages of a queue to the connection, for monitoring.
+  /// The events are sent as event responses with the ref id of the request, until events
+  /// are subscribed to again with a prefetch of 0, or the connection is closed.
+  /// Only admins can subscribe to events.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EventSubscribeRequest : pb::IMessage<EventSubscribeRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EventSubscribeRequest> _parser = new pb::MessageParser<EventSubscribeRequest>(() => new EventSubscribeRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EventSubscribeRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[57]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeRequest(EventSubscribeRequest other) : this() {
+      queueName_ = other.queueName_;
+      prefetch_ = other.prefetch_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeRequest Clone() {
+      return new EventSubscribeRequest(this);
+    }
+
+    /// <summary>Field number for the "queueName" field.</summary>
+    public const int QueueNameFieldNumber = 1;
+    private string queueName_ = "";
+    /// <summary>
+    /// The queue to watch, the default queue if empty
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string QueueName {
+      get { return queueName_; }
+      set {
+        queueName_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "prefetch" field.</summary>
+    public const int PrefetchFieldNumber = 2;
+    private uint prefetch_;
+    /// <summary>
+    /// How many events can wait to be sent to the connection. Events past that are dropped,
+    /// so a connection that falls behind doesn't hold up the queue.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint Prefetch {
+      get { return prefetch_; }
+      set {
+        prefetch_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as EventSubscribeRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EventSubscribeRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (QueueName != other.QueueName) return false;
+      if (Prefetch != other.Prefetch) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (QueueName.Length != 0) hash ^= QueueName.GetHashCode();
+      if (Prefetch != 0) hash ^= Prefetch.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (Prefetch != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(Prefetch);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (QueueName.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(QueueName);
+      }
+      if (Prefetch != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(Prefetch);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (QueueName.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(QueueName);
+      }
+      if (Prefetch != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(Prefetch);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EventSubscribeRequest other) {
+      if (other == null) {
+        return;
+      }
+      if (other.QueueName.Length != 0) {
+        QueueName = other.QueueName;
+      }
+      if (other.Prefetch != 0) {
+        Prefetch = other.Prefetch;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 16: {
+            Prefetch = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            QueueName = input.ReadString();
+            break;
+          }
+          case 16: {
+            Prefetch = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class EventSubscribeResponse : pb::IMessage<EventSubscribeResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<EventSubscribeResponse> _parser = new pb::MessageParser<EventSubscribeResponse>(() => new EventSubscribeResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<EventSubscribeResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[58]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeResponse(EventSubscribeResponse other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public EventSubscribeResponse Clone() {
+      return new EventSubscribeResponse(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as EventSubscribeResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(EventSubscribeResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(EventSubscribeResponse other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class QueueEventResponse : pb::IMessage<QueueEventResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<QueueEventResponse> _parser = new pb::MessageParser<QueueEventResponse>(() => new QueueEventResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<QueueEventResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[59]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public QueueEventResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public QueueEventResponse(QueueEventResponse other) : this() {
+      kind_ = other.kind_;
+      id_ = other.id_;
+      hasPriority_ = other.hasPriority_;
+      priorityLevel_ = other.priorityLevel_;
+      requiredCapabilities_ = other.requiredCapabilities_.Clone();
+      timestampMilliseconds_ = other.timestampMilliseconds_;
+      missedEvents_ = other.missedEvents_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public QueueEventResponse Clone() {
+      return new QueueEventResponse(this);
+    }
+
+    /// <summary>Field number for the "kind" field.</summary>
+    public const int KindFieldNumber = 1;
+    private global::brqueue.EventKind kind_ = global::brqueue.EventKind.Enqueued;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EventKind Kind {
+      get { return kind_; }
+      set {
+        kind_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "id" field.</summary>
+    public const int IdFieldNumber = 2;
+    private string id_ = "";
+    /// <summary>
+    /// The id of the message the event happened to
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Id {
+      get { return id_; }
+      set {
+        id_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "hasPriority" field.</summary>
+    public const int HasPriorityFieldNumber = 3;
+    private bool hasPriority_;
+    /// <summary>
+    /// The priority and capabilities are only known for enqueued, popped and dead lettered events
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool HasPriority {
+      get { return hasPriority_; }
+      set {
+        hasPriority_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityLevel" field.</summary>
+    public const int PriorityLevelFieldNumber = 4;
+    private uint priorityLevel_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint PriorityLevel {
+      get { return priorityLevel_; }
+      set {
+        priorityLevel_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "requiredCapabilities" field.</summary>
+    public const int RequiredCapabilitiesFieldNumber = 5;
+    private static readonly pb::FieldCodec<string> _repeated_requiredCapabilities_codec
+        = pb::FieldCodec.ForString(42);
+    private readonly pbc::RepeatedField<string> requiredCapabilities_ = new pbc::RepeatedField<string>();
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> RequiredCapabilities {
+      get { return requiredCapabilities_; }
+    }
+
+    /// <summary>Field number for the "timestampMilliseconds" field.</summary>
+    public const int TimestampMillisecondsFieldNumber = 6;
+    private ulong timestampMilliseconds_;
+    /// <summary>
+    /// When the event happened, in milliseconds since the unix epoch
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong TimestampMilliseconds {
+      get { return timestampMilliseconds_; }
+      set {
+        timestampMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "missedEvents" field.</summary>
+    public const int MissedEventsFieldNumber = 7;
+    private ulong missedEvents_;
+    /// <summary>
+    /// How many events were dropped right before this one, because the connection fell behind
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong MissedEvents {
+      get { return missedEvents_; }
+      set {
+        missedEvents_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as QueueEventResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(QueueEventResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Kind != other.Kind) return false;
+      if (Id != other.Id) return false;
+      if (HasPriority != other.HasPriority) return false;
+      if (PriorityLevel != other.PriorityLevel) return false;
+      if(!requiredCapabilities_.Equals(other.requiredCapabilities_)) return false;
+      if (TimestampMilliseconds != other.TimestampMilliseconds) return false;
+      if (MissedEvents != other.MissedEvents) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Kind != global::brqueue.EventKind.Enqueued) hash ^= Kind.GetHashCode();
+      if (Id.Length != 0) hash ^= Id.GetHashCode();
+      if (HasPriority != false) hash ^= HasPriority.GetHashCode();
+      if (PriorityLevel != 0) hash ^= PriorityLevel.GetHashCode();
+      hash ^= requiredCapabilities_.GetHashCode();
+      if (TimestampMilliseconds != 0UL) hash ^= TimestampMilliseconds.GetHashCode();
+      if (MissedEvents != 0UL) hash ^= MissedEvents.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Kind != global::brqueue.EventKind.Enqueued) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Kind);
+      }
+      if (Id.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Id);
+      }
+      if (HasPriority != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(HasPriority);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(32);
+        output.WriteUInt32(PriorityLevel);
+      }
+      requiredCapabilities_.WriteTo(output, _repeated_requiredCapabilities_codec);
+      if (TimestampMilliseconds != 0UL) {
+        output.WriteRawTag(48);
+        output.WriteUInt64(TimestampMilliseconds);
+      }
+      if (MissedEvents != 0UL) {
+        output.WriteRawTag(56);
+        output.WriteUInt64(MissedEvents);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Kind != global::brqueue.EventKind.Enqueued) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Kind);
+      }
+      if (Id.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Id);
+      }
+      if (HasPriority != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(HasPriority);
+      }
+      if (PriorityLevel != 0) {
+        output.WriteRawTag(32);
+        output.WriteUInt32(PriorityLevel);
+      }
+      requiredCapabilities_.WriteTo(ref output, _repeated_requiredCapabilities_codec);
+      if (TimestampMilliseconds != 0UL) {
+        output.WriteRawTag(48);
+        output.WriteUInt64(TimestampMilliseconds);
+      }
+      if (MissedEvents != 0UL) {
+        output.WriteRawTag(56);
+        output.WriteUInt64(MissedEvents);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Kind != global::brqueue.EventKind.Enqueued) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Kind);
+      }
+      if (Id.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Id);
+      }
+      if (HasPriority != false) {
+        size += 1 + 1;
+      }
+      if (PriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(PriorityLevel);
+      }
+      size += requiredCapabilities_.CalculateSize(_repeated_requiredCapabilities_codec);
+      if (TimestampMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(TimestampMilliseconds);
+      }
+      if (MissedEvents != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(MissedEvents);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(QueueEventResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Kind != global::brqueue.EventKind.Enqueued) {
+        Kind = other.Kind;
+      }
+      if (other.Id.Length != 0) {
+        Id = other.Id;
+      }
+      if (other.HasPriority != false) {
+        HasPriority = other.HasPriority;
+      }
+      if (other.PriorityLevel != 0) {
+        PriorityLevel = other.PriorityLevel;
+      }
+      requiredCapabilities_.Add(other.requiredCapabilities_);
+      if (other.TimestampMilliseconds != 0UL) {
+        TimestampMilliseconds = other.TimestampMilliseconds;
+      }
+      if (other.MissedEvents != 0UL) {
+        MissedEvents = other.MissedEvents;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Kind = (global::brqueue.EventKind) input.ReadEnum();
+            break;
+          }
+          case 18: {
+            Id = input.ReadString();
+            break;
+          }
+          case 24: {
+            HasPriority = input.ReadBool();
+            break;
+          }
+          case 32: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 42: {
+            requiredCapabilities_.AddEntriesFrom(input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 48: {
+            TimestampMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 56: {
+            MissedEvents = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Kind = (global::brqueue.EventKind) input.ReadEnum();
+            break;
+          }
+          case 18: {
+            Id = input.ReadString();
+            break;
+          }
+          case 24: {
+            HasPriority = input.ReadBool();
+            break;
+          }
+          case 32: {
+            PriorityLevel = input.ReadUInt32();
+            break;
+          }
+          case 42: {
+            requiredCapabilities_.AddEntriesFrom(ref input, _repeated_requiredCapabilities_codec);
+            break;
+          }
+          case 48: {
+            TimestampMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 56: {
+            MissedEvents = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Gets information about the running server
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ServerInfoRequest : pb::IMessage<ServerInfoRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ServerInfoRequest> _parser = new pb::MessageParser<ServerInfoRequest>(() => new ServerInfoRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ServerInfoRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[60]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoRequest(ServerInfoRequest other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoRequest Clone() {
+      return new ServerInfoRequest(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ServerInfoRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ServerInfoRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ServerInfoRequest other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ServerInfoResponse : pb::IMessage<ServerInfoResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ServerInfoResponse> _parser = new pb::MessageParser<ServerInfoResponse>(() => new ServerInfoResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ServerInfoResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[61]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoResponse(ServerInfoResponse other) : this() {
+      uptimeMilliseconds_ = other.uptimeMilliseconds_;
+      version_ = other.version_;
+      gitCommit_ = other.gitCommit_;
+      storageFileSizes_ = other.storageFileSizes_ != null ? other.storageFileSizes_.Clone() : null;
+      priorityBands_ = other.priorityBands_.Clone();
+      defaultPriorityLevel_ = other.defaultPriorityLevel_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ServerInfoResponse Clone() {
+      return new ServerInfoResponse(this);
+    }
+
+    /// <summary>Field number for the "uptimeMilliseconds" field.</summary>
+    public const int UptimeMillisecondsFieldNumber = 1;
+    private ulong uptimeMilliseconds_;
+    /// <summary>
+    /// How long the server has been running in milliseconds
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong UptimeMilliseconds {
+      get { return uptimeMilliseconds_; }
+      set {
+        uptimeMilliseconds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "version" field.</summary>
+    public const int VersionFieldNumber = 2;
+    private string version_ = "";
+    /// <summary>
+    /// The version of the server
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Version {
+      get { return version_; }
+      set {
+        version_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "gitCommit" field.</summary>
+    public const int GitCommitFieldNumber = 3;
+    private string gitCommit_ = "";
+    /// <summary>
+    /// The commit the server was built from, empty if unknown
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string GitCommit {
+      get { return gitCommit_; }
+      set {
+        gitCommit_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "storageFileSizes" field.</summary>
+    public const int StorageFileSizesFieldNumber = 4;
+    private global::brqueue.StorageFileSizes storageFileSizes_;
+    /// <summary>
+    /// The current size of the storage files, for alerting when garbage collection
+    /// isn't keeping up. Not set if the sizes couldn't be read.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.StorageFileSizes StorageFileSizes {
+      get { return storageFileSizes_; }
+      set {
+        storageFileSizes_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "priorityBands" field.</summary>
+    public const int PriorityBandsFieldNumber = 5;
+    private static readonly pb::FieldCodec<string> _repeated_priorityBands_codec
+        = pb::FieldCodec.ForString(42);
+    private readonly pbc::RepeatedField<string> priorityBands_ = new pbc::RepeatedField<string>();
+    /// <summary>
+    /// The names of the priority levels messages can be enqueued with, from level 0 up.
+    /// Enqueuing with a higher level is rejected.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pbc::RepeatedField<string> PriorityBands {
+      get { return priorityBands_; }
+    }
+
+    /// <summary>Field number for the "defaultPriorityLevel" field.</summary>
+    public const int DefaultPriorityLevelFieldNumber = 6;
+    private uint defaultPriorityLevel_;
+    /// <summary>
+    /// The priority level of messages enqueued without a priority
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint DefaultPriorityLevel {
+      get { return defaultPriorityLevel_; }
+      set {
+        defaultPriorityLevel_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ServerInfoResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ServerInfoResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (UptimeMilliseconds != other.UptimeMilliseconds) return false;
+      if (Version != other.Version) return false;
+      if (GitCommit != other.GitCommit) return false;
+      if (!object.Equals(StorageFileSizes, other.StorageFileSizes)) return false;
+      if(!priorityBands_.Equals(other.priorityBands_)) return false;
+      if (DefaultPriorityLevel != other.DefaultPriorityLevel) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (UptimeMilliseconds != 0UL) hash ^= UptimeMilliseconds.GetHashCode();
+      if (Version.Length != 0) hash ^= Version.GetHashCode();
+      if (GitCommit.Length != 0) hash ^= GitCommit.GetHashCode();
+      if (storageFileSizes_ != null) hash ^= StorageFileSizes.GetHashCode();
+      hash ^= priorityBands_.GetHashCode();
+      if (DefaultPriorityLevel != 0) hash ^= DefaultPriorityLevel.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (UptimeMilliseconds != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(UptimeMilliseconds);
+      }
+      if (Version.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Version);
+      }
+      if (GitCommit.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(GitCommit);
+      }
+      if (storageFileSizes_ != null) {
+        output.WriteRawTag(34);
+        output.WriteMessage(StorageFileSizes);
+      }
+      priorityBands_.WriteTo(output, _repeated_priorityBands_codec);
+      if (DefaultPriorityLevel != 0) {
+        output.WriteRawTag(48);
+        output.WriteUInt32(DefaultPriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (UptimeMilliseconds != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(UptimeMilliseconds);
+      }
+      if (Version.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(Version);
+      }
+      if (GitCommit.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteString(GitCommit);
+      }
+      if (storageFileSizes_ != null) {
+        output.WriteRawTag(34);
+        output.WriteMessage(StorageFileSizes);
+      }
+      priorityBands_.WriteTo(ref output, _repeated_priorityBands_codec);
+      if (DefaultPriorityLevel != 0) {
+        output.WriteRawTag(48);
+        output.WriteUInt32(DefaultPriorityLevel);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (UptimeMilliseconds != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(UptimeMilliseconds);
+      }
+      if (Version.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Version);
+      }
+      if (GitCommit.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(GitCommit);
+      }
+      if (storageFileSizes_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(StorageFileSizes);
+      }
+      size += priorityBands_.CalculateSize(_repeated_priorityBands_codec);
+      if (DefaultPriorityLevel != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(DefaultPriorityLevel);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ServerInfoResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.UptimeMilliseconds != 0UL) {
+        UptimeMilliseconds = other.UptimeMilliseconds;
+      }
+      if (other.Version.Length != 0) {
+        Version = other.Version;
+      }
+      if (other.GitCommit.Length != 0) {
+        GitCommit = other.GitCommit;
+      }
+      if (other.storageFileSizes_ != null) {
+        if (storageFileSizes_ == null) {
+          StorageFileSizes = new global::brqueue.StorageFileSizes();
+        }
+        StorageFileSizes.MergeFrom(other.StorageFileSizes);
+      }
+      priorityBands_.Add(other.priorityBands_);
+      if (other.DefaultPriorityLevel != 0) {
+        DefaultPriorityLevel = other.DefaultPriorityLevel;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            UptimeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 18: {
+            Version = input.ReadString();
+            break;
+          }
+          case 26: {
+            GitCommit = input.ReadString();
+            break;
+          }
+          case 34: {
+            if (storageFileSizes_ == null) {
+              StorageFileSizes = new global::brqueue.StorageFileSizes();
+            }
+            input.ReadMessage(StorageFileSizes);
+            break;
+          }
+          case 42: {
+            priorityBands_.AddEntriesFrom(input, _repeated_priorityBands_codec);
+            break;
+          }
+          case 48: {
+            DefaultPriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            UptimeMilliseconds = input.ReadUInt64();
+            break;
+          }
+          case 18: {
+            Version = input.ReadString();
+            break;
+          }
+          case 26: {
+            GitCommit = input.ReadString();
+            break;
+          }
+          case 34: {
+            if (storageFileSizes_ == null) {
+              StorageFileSizes = new global::brqueue.StorageFileSizes();
+            }
+            input.ReadMessage(StorageFileSizes);
+            break;
+          }
+          case 42: {
+            priorityBands_.AddEntriesFrom(ref input, _repeated_priorityBands_codec);
+            break;
+          }
+          case 48: {
+            DefaultPriorityLevel = input.ReadUInt32();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class StorageFileSizes : pb::IMessage<StorageFileSizes>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<StorageFileSizes> _parser = new pb::MessageParser<StorageFileSizes>(() => new StorageFileSizes());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<StorageFileSizes> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[62]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StorageFileSizes() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StorageFileSizes(StorageFileSizes other) : this() {
+      highPriorityBytes_ = other.highPriorityBytes_;
+      lowPriorityBytes_ = other.lowPriorityBytes_;
+      completedBytes_ = other.completedBytes_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public StorageFileSizes Clone() {
+      return new StorageFileSizes(this);
+    }
+
+    /// <summary>Field number for the "highPriorityBytes" field.</summary>
+    public const int HighPriorityBytesFieldNumber = 1;
+    private ulong highPriorityBytes_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong HighPriorityBytes {
+      get { return highPriorityBytes_; }
+      set {
+        highPriorityBytes_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "lowPriorityBytes" field.</summary>
+    public const int LowPriorityBytesFieldNumber = 2;
+    private ulong lowPriorityBytes_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong LowPriorityBytes {
+      get { return lowPriorityBytes_; }
+      set {
+        lowPriorityBytes_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "completedBytes" field.</summary>
+    public const int CompletedBytesFieldNumber = 3;
+    private ulong completedBytes_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong CompletedBytes {
+      get { return completedBytes_; }
+      set {
+        completedBytes_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as StorageFileSizes);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(StorageFileSizes other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (HighPriorityBytes != other.HighPriorityBytes) return false;
+      if (LowPriorityBytes != other.LowPriorityBytes) return false;
+      if (CompletedBytes != other.CompletedBytes) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (HighPriorityBytes != 0UL) hash ^= HighPriorityBytes.GetHashCode();
+      if (LowPriorityBytes != 0UL) hash ^= LowPriorityBytes.GetHashCode();
+      if (CompletedBytes != 0UL) hash ^= CompletedBytes.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (HighPriorityBytes != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(HighPriorityBytes);
+      }
+      if (LowPriorityBytes != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(LowPriorityBytes);
+      }
+      if (CompletedBytes != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(CompletedBytes);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (HighPriorityBytes != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(HighPriorityBytes);
+      }
+      if (LowPriorityBytes != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(LowPriorityBytes);
+      }
+      if (CompletedBytes != 0UL) {
+        output.WriteRawTag(24);
+        output.WriteUInt64(CompletedBytes);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (HighPriorityBytes != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(HighPriorityBytes);
+      }
+      if (LowPriorityBytes != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(LowPriorityBytes);
+      }
+      if (CompletedBytes != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(CompletedBytes);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(StorageFileSizes other) {
+      if (other == null) {
+        return;
+      }
+      if (other.HighPriorityBytes != 0UL) {
+        HighPriorityBytes = other.HighPriorityBytes;
+      }
+      if (other.LowPriorityBytes != 0UL) {
+        LowPriorityBytes = other.LowPriorityBytes;
+      }
+      if (other.CompletedBytes != 0UL) {
+        CompletedBytes = other.CompletedBytes;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            HighPriorityBytes = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            LowPriorityBytes = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            CompletedBytes = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            HighPriorityBytes = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            LowPriorityBytes = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            CompletedBytes = input.ReadUInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Gets a snapshot of the complete state of the server, for debugging.
+  /// Only available to admins.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class DiagnosticDumpRequest : pb::IMessage<DiagnosticDumpRequest>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<DiagnosticDumpRequest> _parser = new pb::MessageParser<DiagnosticDumpRequest>(() => new DiagnosticDumpRequest());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<DiagnosticDumpRequest> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[63]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpRequest() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpRequest(DiagnosticDumpRequest other) : this() {
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpRequest Clone() {
+      return new DiagnosticDumpRequest(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as DiagnosticDumpRequest);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(DiagnosticDumpRequest other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(DiagnosticDumpRequest other) {
+      if (other == null) {
+        return;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class DiagnosticDumpResponse : pb::IMessage<DiagnosticDumpResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<DiagnosticDumpResponse> _parser = new pb::MessageParser<DiagnosticDumpResponse>(() => new DiagnosticDumpResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<DiagnosticDumpResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[64]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpResponse(DiagnosticDumpResponse other) : this() {
+      json_ = other.json_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public DiagnosticDumpResponse Clone() {
+      return new DiagnosticDumpResponse(this);
+    }
+
+    /// <summary>Field number for the "json" field.</summary>
+    public const int JsonFieldNumber = 1;
+    private string json_ = "";
+    /// <summary>
+    /// The snapshot serialized as json
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Json {
+      get { return json_; }
+      set {
+        json_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as DiagnosticDumpResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(DiagnosticDumpResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Json != other.Json) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Json.Length != 0) hash ^= Json.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Json.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Json);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Json.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Json);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Json.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Json);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(DiagnosticDumpResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Json.Length != 0) {
+        Json = other.Json;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Json = input.ReadString();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Json = input.ReadString();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ErrorResponse : pb::IMessage<ErrorResponse>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ErrorResponse> _parser = new pb::MessageParser<ErrorResponse>(() => new ErrorResponse());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ErrorResponse> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[65]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ErrorResponse() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ErrorResponse(ErrorResponse other) : this() {
+      message_ = other.message_;
+      code_ = other.code_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ErrorResponse Clone() {
+      return new ErrorResponse(this);
+    }
+
+    /// <summary>Field number for the "message" field.</summary>
+    public const int MessageFieldNumber = 1;
+    private string message_ = "";
+    /// <summary>
+    /// What went wrong
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string Message {
+      get { return message_; }
+      set {
+        message_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    /// <summary>Field number for the "code" field.</summary>
+    public const int CodeFieldNumber = 2;
+    private global::brqueue.ErrorCode code_ = global::brqueue.ErrorCode.Unknown;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ErrorCode Code {
+      get { return code_; }
+      set {
+        code_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ErrorResponse);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ErrorResponse other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Message != other.Message) return false;
+      if (Code != other.Code) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Message.Length != 0) hash ^= Message.GetHashCode();
+      if (Code != global::brqueue.ErrorCode.Unknown) hash ^= Code.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Message.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Message);
+      }
+      if (Code != global::brqueue.ErrorCode.Unknown) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Code);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Message.Length != 0) {
+        output.WriteRawTag(10);
+        output.WriteString(Message);
+      }
+      if (Code != global::brqueue.ErrorCode.Unknown) {
+        output.WriteRawTag(16);
+        output.WriteEnum((int) Code);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Message.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(Message);
+      }
+      if (Code != global::brqueue.ErrorCode.Unknown) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Code);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ErrorResponse other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Message.Length != 0) {
+        Message = other.Message;
+      }
+      if (other.Code != global::brqueue.ErrorCode.Unknown) {
+        Code = other.Code;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            Message = input.ReadString();
+            break;
+          }
+          case 16: {
+            Code = (global::brqueue.ErrorCode) input.ReadEnum();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            Message = input.ReadString();
+            break;
+          }
+          case 16: {
+            Code = (global::brqueue.ErrorCode) input.ReadEnum();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Wraps the requests for easier parsing on the socket layer
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class RequestWrapper : pb::IMessage<RequestWrapper>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<RequestWrapper> _parser = new pb::MessageParser<RequestWrapper>(() => new RequestWrapper());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<RequestWrapper> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[66]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public RequestWrapper() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public RequestWrapper(RequestWrapper other) : this() {
+      refId_ = other.refId_;
+      switch (other.MessageCase) {
+        case MessageOneofCase.Enqueue:
+          Enqueue = other.Enqueue.Clone();
+          break;
+        case MessageOneofCase.Pop:
+          Pop = other.Pop.Clone();
+          break;
+        case MessageOneofCase.Acknowledge:
+          Acknowledge = other.Acknowledge.Clone();
+          break;
+        case MessageOneofCase.Authenticate:
+          Authenticate = other.Authenticate.Clone();
+          break;
+        case MessageOneofCase.Health:
+          Health = other.Health.Clone();
+          break;
+        case MessageOneofCase.AcknowledgeMatching:
+          AcknowledgeMatching = other.AcknowledgeMatching.Clone();
+          break;
+        case MessageOneofCase.FailWithRetag:
+          FailWithRetag = other.FailWithRetag.Clone();
+          break;
+        case MessageOneofCase.ServerInfo:
+          ServerInfo = other.ServerInfo.Clone();
+          break;
+        case MessageOneofCase.Fail:
+          Fail = other.Fail.Clone();
+          break;
+        case MessageOneofCase.DiagnosticDump:
+          DiagnosticDump = other.DiagnosticDump.Clone();
+          break;
+        case MessageOneofCase.BeginEnqueue:
+          BeginEnqueue = other.BeginEnqueue.Clone();
+          break;
+        case MessageOneofCase.EnqueueChunk:
+          EnqueueChunk = other.EnqueueChunk.Clone();
+          break;
+        case MessageOneofCase.CommitEnqueue:
+          CommitEnqueue = other.CommitEnqueue.Clone();
+          break;
+        case MessageOneofCase.CancelPop:
+          CancelPop = other.CancelPop.Clone();
+          break;
+        case MessageOneofCase.Cancel:
+          Cancel = other.Cancel.Clone();
+          break;
+        case MessageOneofCase.FindByTag:
+          FindByTag = other.FindByTag.Clone();
+          break;
+        case MessageOneofCase.ListProcessing:
+          ListProcessing = other.ListProcessing.Clone();
+          break;
+        case MessageOneofCase.SetCapabilityPaused:
+          SetCapabilityPaused = other.SetCapabilityPaused.Clone();
+          break;
+        case MessageOneofCase.Subscribe:
+          Subscribe = other.Subscribe.Clone();
+          break;
+        case MessageOneofCase.Reserve:
+          Reserve = other.Reserve.Clone();
+          break;
+        case MessageOneofCase.ConfirmReservation:
+          ConfirmReservation = other.ConfirmReservation.Clone();
+          break;
+        case MessageOneofCase.Decline:
+          Decline = other.Decline.Clone();
+          break;
+        case MessageOneofCase.EnqueueBatch:
+          EnqueueBatch = other.EnqueueBatch.Clone();
+          break;
+        case MessageOneofCase.ListQueued:
+          ListQueued = other.ListQueued.Clone();
+          break;
+        case MessageOneofCase.Peek:
+          Peek = other.Peek.Clone();
+          break;
+        case MessageOneofCase.Stats:
+          Stats = other.Stats.Clone();
+          break;
+        case MessageOneofCase.Purge:
+          Purge = other.Purge.Clone();
+          break;
+        case MessageOneofCase.EventSubscribe:
+          EventSubscribe = other.EventSubscribe.Clone();
+          break;
+        case MessageOneofCase.ExportDeadLetters:
+          ExportDeadLetters = other.ExportDeadLetters.Clone();
+          break;
+      }
+
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public RequestWrapper Clone() {
+      return new RequestWrapper(this);
+    }
+
+    /// <summary>Field number for the "refId" field.</summary>
+    public const int RefIdFieldNumber = 10;
+    private int refId_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int RefId {
+      get { return refId_; }
+      set {
+        refId_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "enqueue" field.</summary>
+    public const int EnqueueFieldNumber = 1;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueRequest Enqueue {
+      get { return messageCase_ == MessageOneofCase.Enqueue ? (global::brqueue.EnqueueRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Enqueue;
+      }
+    }
+
+    /// <summary>Field number for the "pop" field.</summary>
+    public const int PopFieldNumber = 2;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PopRequest Pop {
+      get { return messageCase_ == MessageOneofCase.Pop ? (global::brqueue.PopRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Pop;
+      }
+    }
+
+    /// <summary>Field number for the "acknowledge" field.</summary>
+    public const int AcknowledgeFieldNumber = 3;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AcknowledgeRequest Acknowledge {
+      get { return messageCase_ == MessageOneofCase.Acknowledge ? (global::brqueue.AcknowledgeRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Acknowledge;
+      }
+    }
+
+    /// <summary>Field number for the "authenticate" field.</summary>
+    public const int AuthenticateFieldNumber = 6;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AuthenticateRequest Authenticate {
+      get { return messageCase_ == MessageOneofCase.Authenticate ? (global::brqueue.AuthenticateRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Authenticate;
+      }
+    }
+
+    /// <summary>Field number for the "health" field.</summary>
+    public const int HealthFieldNumber = 7;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.HealthRequest Health {
+      get { return messageCase_ == MessageOneofCase.Health ? (global::brqueue.HealthRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Health;
+      }
+    }
+
+    /// <summary>Field number for the "acknowledgeMatching" field.</summary>
+    public const int AcknowledgeMatchingFieldNumber = 8;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AcknowledgeMatchingRequest AcknowledgeMatching {
+      get { return messageCase_ == MessageOneofCase.AcknowledgeMatching ? (global::brqueue.AcknowledgeMatchingRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.AcknowledgeMatching;
+      }
+    }
+
+    /// <summary>Field number for the "failWithRetag" field.</summary>
+    public const int FailWithRetagFieldNumber = 9;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FailWithRetagRequest FailWithRetag {
+      get { return messageCase_ == MessageOneofCase.FailWithRetag ? (global::brqueue.FailWithRetagRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.FailWithRetag;
+      }
+    }
+
+    /// <summary>Field number for the "serverInfo" field.</summary>
+    public const int ServerInfoFieldNumber = 11;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ServerInfoRequest ServerInfo {
+      get { return messageCase_ == MessageOneofCase.ServerInfo ? (global::brqueue.ServerInfoRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ServerInfo;
+      }
+    }
+
+    /// <summary>Field number for the "fail" field.</summary>
+    public const int FailFieldNumber = 12;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FailRequest Fail {
+      get { return messageCase_ == MessageOneofCase.Fail ? (global::brqueue.FailRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Fail;
+      }
+    }
+
+    /// <summary>Field number for the "diagnosticDump" field.</summary>
+    public const int DiagnosticDumpFieldNumber = 13;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.DiagnosticDumpRequest DiagnosticDump {
+      get { return messageCase_ == MessageOneofCase.DiagnosticDump ? (global::brqueue.DiagnosticDumpRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.DiagnosticDump;
+      }
+    }
+
+    /// <summary>Field number for the "beginEnqueue" field.</summary>
+    public const int BeginEnqueueFieldNumber = 14;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.BeginEnqueueRequest BeginEnqueue {
+      get { return messageCase_ == MessageOneofCase.BeginEnqueue ? (global::brqueue.BeginEnqueueRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.BeginEnqueue;
+      }
+    }
+
+    /// <summary>Field number for the "enqueueChunk" field.</summary>
+    public const int EnqueueChunkFieldNumber = 15;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueChunkRequest EnqueueChunk {
+      get { return messageCase_ == MessageOneofCase.EnqueueChunk ? (global::brqueue.EnqueueChunkRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EnqueueChunk;
+      }
+    }
+
+    /// <summary>Field number for the "commitEnqueue" field.</summary>
+    public const int CommitEnqueueFieldNumber = 16;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CommitEnqueueRequest CommitEnqueue {
+      get { return messageCase_ == MessageOneofCase.CommitEnqueue ? (global::brqueue.CommitEnqueueRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.CommitEnqueue;
+      }
+    }
+
+    /// <summary>Field number for the "cancelPop" field.</summary>
+    public const int CancelPopFieldNumber = 17;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CancelPopRequest CancelPop {
+      get { return messageCase_ == MessageOneofCase.CancelPop ? (global::brqueue.CancelPopRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.CancelPop;
+      }
+    }
+
+    /// <summary>Field number for the "cancel" field.</summary>
+    public const int CancelFieldNumber = 18;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CancelRequest Cancel {
+      get { return messageCase_ == MessageOneofCase.Cancel ? (global::brqueue.CancelRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Cancel;
+      }
+    }
+
+    /// <summary>Field number for the "findByTag" field.</summary>
+    public const int FindByTagFieldNumber = 19;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FindByTagRequest FindByTag {
+      get { return messageCase_ == MessageOneofCase.FindByTag ? (global::brqueue.FindByTagRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.FindByTag;
+      }
+    }
+
+    /// <summary>Field number for the "listProcessing" field.</summary>
+    public const int ListProcessingFieldNumber = 20;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ListProcessingRequest ListProcessing {
+      get { return messageCase_ == MessageOneofCase.ListProcessing ? (global::brqueue.ListProcessingRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ListProcessing;
+      }
+    }
+
+    /// <summary>Field number for the "setCapabilityPaused" field.</summary>
+    public const int SetCapabilityPausedFieldNumber = 21;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.SetCapabilityPausedRequest SetCapabilityPaused {
+      get { return messageCase_ == MessageOneofCase.SetCapabilityPaused ? (global::brqueue.SetCapabilityPausedRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.SetCapabilityPaused;
+      }
+    }
+
+    /// <summary>Field number for the "subscribe" field.</summary>
+    public const int SubscribeFieldNumber = 22;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.SubscribeRequest Subscribe {
+      get { return messageCase_ == MessageOneofCase.Subscribe ? (global::brqueue.SubscribeRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Subscribe;
+      }
+    }
+
+    /// <summary>Field number for the "reserve" field.</summary>
+    public const int ReserveFieldNumber = 23;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ReserveRequest Reserve {
+      get { return messageCase_ == MessageOneofCase.Reserve ? (global::brqueue.ReserveRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Reserve;
+      }
+    }
+
+    /// <summary>Field number for the "confirmReservation" field.</summary>
+    public const int ConfirmReservationFieldNumber = 24;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ConfirmReservationRequest ConfirmReservation {
+      get { return messageCase_ == MessageOneofCase.ConfirmReservation ? (global::brqueue.ConfirmReservationRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ConfirmReservation;
+      }
+    }
+
+    /// <summary>Field number for the "decline" field.</summary>
+    public const int DeclineFieldNumber = 25;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.DeclineRequest Decline {
+      get { return messageCase_ == MessageOneofCase.Decline ? (global::brqueue.DeclineRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Decline;
+      }
+    }
+
+    /// <summary>Field number for the "enqueueBatch" field.</summary>
+    public const int EnqueueBatchFieldNumber = 26;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueBatchRequest EnqueueBatch {
+      get { return messageCase_ == MessageOneofCase.EnqueueBatch ? (global::brqueue.EnqueueBatchRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EnqueueBatch;
+      }
+    }
+
+    /// <summary>Field number for the "listQueued" field.</summary>
+    public const int ListQueuedFieldNumber = 27;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ListQueuedRequest ListQueued {
+      get { return messageCase_ == MessageOneofCase.ListQueued ? (global::brqueue.ListQueuedRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ListQueued;
+      }
+    }
+
+    /// <summary>Field number for the "peek" field.</summary>
+    public const int PeekFieldNumber = 28;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PeekRequest Peek {
+      get { return messageCase_ == MessageOneofCase.Peek ? (global::brqueue.PeekRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Peek;
+      }
+    }
+
+    /// <summary>Field number for the "stats" field.</summary>
+    public const int StatsFieldNumber = 29;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.StatsRequest Stats {
+      get { return messageCase_ == MessageOneofCase.Stats ? (global::brqueue.StatsRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Stats;
+      }
+    }
+
+    /// <summary>Field number for the "purge" field.</summary>
+    public const int PurgeFieldNumber = 30;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PurgeRequest Purge {
+      get { return messageCase_ == MessageOneofCase.Purge ? (global::brqueue.PurgeRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Purge;
+      }
+    }
+
+    /// <summary>Field number for the "eventSubscribe" field.</summary>
+    public const int EventSubscribeFieldNumber = 31;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EventSubscribeRequest EventSubscribe {
+      get { return messageCase_ == MessageOneofCase.EventSubscribe ? (global::brqueue.EventSubscribeRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EventSubscribe;
+      }
+    }
+
+    /// <summary>Field number for the "exportDeadLetters" field.</summary>
+    public const int ExportDeadLettersFieldNumber = 32;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ExportDeadLettersRequest ExportDeadLetters {
+      get { return messageCase_ == MessageOneofCase.ExportDeadLetters ? (global::brqueue.ExportDeadLettersRequest) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ExportDeadLetters;
+      }
+    }
+
+    private object message_;
+    /// <summary>Enum of possible cases for the "message" oneof.</summary>
+    public enum MessageOneofCase {
+      None = 0,
+      Enqueue = 1,
+      Pop = 2,
+      Acknowledge = 3,
+      Authenticate = 6,
+      Health = 7,
+      AcknowledgeMatching = 8,
+      FailWithRetag = 9,
+      ServerInfo = 11,
+      Fail = 12,
+      DiagnosticDump = 13,
+      BeginEnqueue = 14,
+      EnqueueChunk = 15,
+      CommitEnqueue = 16,
+      CancelPop = 17,
+      Cancel = 18,
+      FindByTag = 19,
+      ListProcessing = 20,
+      SetCapabilityPaused = 21,
+      Subscribe = 22,
+      Reserve = 23,
+      ConfirmReservation = 24,
+      Decline = 25,
+      EnqueueBatch = 26,
+      ListQueued = 27,
+      Peek = 28,
+      Stats = 29,
+      Purge = 30,
+      EventSubscribe = 31,
+      ExportDeadLetters = 32,
+    }
+    private MessageOneofCase messageCase_ = MessageOneofCase.None;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public MessageOneofCase MessageCase {
+      get { return messageCase_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void ClearMessage() {
+      messageCase_ = MessageOneofCase.None;
+      message_ = null;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as RequestWrapper);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(RequestWrapper other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (RefId != other.RefId) return false;
+      if (!object.Equals(Enqueue, other.Enqueue)) return false;
+      if (!object.Equals(Pop, other.Pop)) return false;
+      if (!object.Equals(Acknowledge, other.Acknowledge)) return false;
+      if (!object.Equals(Authenticate, other.Authenticate)) return false;
+      if (!object.Equals(Health, other.Health)) return false;
+      if (!object.Equals(AcknowledgeMatching, other.AcknowledgeMatching)) return false;
+      if (!object.Equals(FailWithRetag, other.FailWithRetag)) return false;
+      if (!object.Equals(ServerInfo, other.ServerInfo)) return false;
+      if (!object.Equals(Fail, other.Fail)) return false;
+      if (!object.Equals(DiagnosticDump, other.DiagnosticDump)) return false;
+      if (!object.Equals(BeginEnqueue, other.BeginEnqueue)) return false;
+      if (!object.Equals(EnqueueChunk, other.EnqueueChunk)) return false;
+      if (!object.Equals(CommitEnqueue, other.CommitEnqueue)) return false;
+      if (!object.Equals(CancelPop, other.CancelPop)) return false;
+      if (!object.Equals(Cancel, other.Cancel)) return false;
+      if (!object.Equals(FindByTag, other.FindByTag)) return false;
+      if (!object.Equals(ListProcessing, other.ListProcessing)) return false;
+      if (!object.Equals(SetCapabilityPaused, other.SetCapabilityPaused)) return false;
+      if (!object.Equals(Subscribe, other.Subscribe)) return false;
+      if (!object.Equals(Reserve, other.Reserve)) return false;
+      if (!object.Equals(ConfirmReservation, other.ConfirmReservation)) return false;
+      if (!object.Equals(Decline, other.Decline)) return false;
+      if (!object.Equals(EnqueueBatch, other.EnqueueBatch)) return false;
+      if (!object.Equals(ListQueued, other.ListQueued)) return false;
+      if (!object.Equals(Peek, other.Peek)) return false;
+      if (!object.Equals(Stats, other.Stats)) return false;
+      if (!object.Equals(Purge, other.Purge)) return false;
+      if (!object.Equals(EventSubscribe, other.EventSubscribe)) return false;
+      if (!object.Equals(ExportDeadLetters, other.ExportDeadLetters)) return false;
+      if (MessageCase != other.MessageCase) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (RefId != 0) hash ^= RefId.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Enqueue) hash ^= Enqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Pop) hash ^= Pop.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Acknowledge) hash ^= Acknowledge.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Authenticate) hash ^= Authenticate.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Health) hash ^= Health.GetHashCode();
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) hash ^= AcknowledgeMatching.GetHashCode();
+      if (messageCase_ == MessageOneofCase.FailWithRetag) hash ^= FailWithRetag.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ServerInfo) hash ^= ServerInfo.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Fail) hash ^= Fail.GetHashCode();
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) hash ^= DiagnosticDump.GetHashCode();
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) hash ^= BeginEnqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) hash ^= EnqueueChunk.GetHashCode();
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) hash ^= CommitEnqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.CancelPop) hash ^= CancelPop.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Cancel) hash ^= Cancel.GetHashCode();
+      if (messageCase_ == MessageOneofCase.FindByTag) hash ^= FindByTag.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ListProcessing) hash ^= ListProcessing.GetHashCode();
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) hash ^= SetCapabilityPaused.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Subscribe) hash ^= Subscribe.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Reserve) hash ^= Reserve.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) hash ^= ConfirmReservation.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Decline) hash ^= Decline.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) hash ^= EnqueueBatch.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ListQueued) hash ^= ListQueued.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Peek) hash ^= Peek.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Stats) hash ^= Stats.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Purge) hash ^= Purge.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EventSubscribe) hash ^= EventSubscribe.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) hash ^= ExportDeadLetters.GetHashCode();
+      hash ^= (int) messageCase_;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        output.WriteRawTag(26);
+        output.WriteMessage(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        output.WriteRawTag(58);
+        output.WriteMessage(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        output.WriteRawTag(66);
+        output.WriteMessage(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        output.WriteRawTag(74);
+        output.WriteMessage(FailWithRetag);
+      }
+      if (RefId != 0) {
+        output.WriteRawTag(80);
+        output.WriteInt32(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        output.WriteRawTag(90);
+        output.WriteMessage(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        output.WriteRawTag(98);
+        output.WriteMessage(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        output.WriteRawTag(106);
+        output.WriteMessage(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        output.WriteRawTag(114);
+        output.WriteMessage(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        output.WriteRawTag(122);
+        output.WriteMessage(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        output.WriteRawTag(130, 1);
+        output.WriteMessage(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        output.WriteRawTag(138, 1);
+        output.WriteMessage(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        output.WriteRawTag(146, 1);
+        output.WriteMessage(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        output.WriteRawTag(154, 1);
+        output.WriteMessage(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        output.WriteRawTag(162, 1);
+        output.WriteMessage(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        output.WriteRawTag(170, 1);
+        output.WriteMessage(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        output.WriteRawTag(178, 1);
+        output.WriteMessage(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        output.WriteRawTag(186, 1);
+        output.WriteMessage(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        output.WriteRawTag(194, 1);
+        output.WriteMessage(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        output.WriteRawTag(202, 1);
+        output.WriteMessage(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        output.WriteRawTag(218, 1);
+        output.WriteMessage(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        output.WriteRawTag(226, 1);
+        output.WriteMessage(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        output.WriteRawTag(234, 1);
+        output.WriteMessage(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        output.WriteRawTag(242, 1);
+        output.WriteMessage(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        output.WriteRawTag(250, 1);
+        output.WriteMessage(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        output.WriteRawTag(130, 2);
+        output.WriteMessage(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        output.WriteRawTag(26);
+        output.WriteMessage(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        output.WriteRawTag(58);
+        output.WriteMessage(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        output.WriteRawTag(66);
+        output.WriteMessage(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        output.WriteRawTag(74);
+        output.WriteMessage(FailWithRetag);
+      }
+      if (RefId != 0) {
+        output.WriteRawTag(80);
+        output.WriteInt32(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        output.WriteRawTag(90);
+        output.WriteMessage(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        output.WriteRawTag(98);
+        output.WriteMessage(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        output.WriteRawTag(106);
+        output.WriteMessage(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        output.WriteRawTag(114);
+        output.WriteMessage(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        output.WriteRawTag(122);
+        output.WriteMessage(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        output.WriteRawTag(130, 1);
+        output.WriteMessage(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        output.WriteRawTag(138, 1);
+        output.WriteMessage(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        output.WriteRawTag(146, 1);
+        output.WriteMessage(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        output.WriteRawTag(154, 1);
+        output.WriteMessage(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        output.WriteRawTag(162, 1);
+        output.WriteMessage(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        output.WriteRawTag(170, 1);
+        output.WriteMessage(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        output.WriteRawTag(178, 1);
+        output.WriteMessage(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        output.WriteRawTag(186, 1);
+        output.WriteMessage(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        output.WriteRawTag(194, 1);
+        output.WriteMessage(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        output.WriteRawTag(202, 1);
+        output.WriteMessage(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        output.WriteRawTag(218, 1);
+        output.WriteMessage(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        output.WriteRawTag(226, 1);
+        output.WriteMessage(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        output.WriteRawTag(234, 1);
+        output.WriteMessage(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        output.WriteRawTag(242, 1);
+        output.WriteMessage(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        output.WriteRawTag(250, 1);
+        output.WriteMessage(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        output.WriteRawTag(130, 2);
+        output.WriteMessage(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (RefId != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeInt32Size(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(FailWithRetag);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(RequestWrapper other) {
+      if (other == null) {
+        return;
+      }
+      if (other.RefId != 0) {
+        RefId = other.RefId;
+      }
+      switch (other.MessageCase) {
+        case MessageOneofCase.Enqueue:
+          if (Enqueue == null) {
+            Enqueue = new global::brqueue.EnqueueRequest();
+          }
+          Enqueue.MergeFrom(other.Enqueue);
+          break;
+        case MessageOneofCase.Pop:
+          if (Pop == null) {
+            Pop = new global::brqueue.PopRequest();
+          }
+          Pop.MergeFrom(other.Pop);
+          break;
+        case MessageOneofCase.Acknowledge:
+          if (Acknowledge == null) {
+            Acknowledge = new global::brqueue.AcknowledgeRequest();
+          }
+          Acknowledge.MergeFrom(other.Acknowledge);
+          break;
+        case MessageOneofCase.Authenticate:
+          if (Authenticate == null) {
+            Authenticate = new global::brqueue.AuthenticateRequest();
+          }
+          Authenticate.MergeFrom(other.Authenticate);
+          break;
+        case MessageOneofCase.Health:
+          if (Health == null) {
+            Health = new global::brqueue.HealthRequest();
+          }
+          Health.MergeFrom(other.Health);
+          break;
+        case MessageOneofCase.AcknowledgeMatching:
+          if (AcknowledgeMatching == null) {
+            AcknowledgeMatching = new global::brqueue.AcknowledgeMatchingRequest();
+          }
+          AcknowledgeMatching.MergeFrom(other.AcknowledgeMatching);
+          break;
+        case MessageOneofCase.FailWithRetag:
+          if (FailWithRetag == null) {
+            FailWithRetag = new global::brqueue.FailWithRetagRequest();
+          }
+          FailWithRetag.MergeFrom(other.FailWithRetag);
+          break;
+        case MessageOneofCase.ServerInfo:
+          if (ServerInfo == null) {
+            ServerInfo = new global::brqueue.ServerInfoRequest();
+          }
+          ServerInfo.MergeFrom(other.ServerInfo);
+          break;
+        case MessageOneofCase.Fail:
+          if (Fail == null) {
+            Fail = new global::brqueue.FailRequest();
+          }
+          Fail.MergeFrom(other.Fail);
+          break;
+        case MessageOneofCase.DiagnosticDump:
+          if (DiagnosticDump == null) {
+            DiagnosticDump = new global::brqueue.DiagnosticDumpRequest();
+          }
+          DiagnosticDump.MergeFrom(other.DiagnosticDump);
+          break;
+        case MessageOneofCase.BeginEnqueue:
+          if (BeginEnqueue == null) {
+            BeginEnqueue = new global::brqueue.BeginEnqueueRequest();
+          }
+          BeginEnqueue.MergeFrom(other.BeginEnqueue);
+          break;
+        case MessageOneofCase.EnqueueChunk:
+          if (EnqueueChunk == null) {
+            EnqueueChunk = new global::brqueue.EnqueueChunkRequest();
+          }
+          EnqueueChunk.MergeFrom(other.EnqueueChunk);
+          break;
+        case MessageOneofCase.CommitEnqueue:
+          if (CommitEnqueue == null) {
+            CommitEnqueue = new global::brqueue.CommitEnqueueRequest();
+          }
+          CommitEnqueue.MergeFrom(other.CommitEnqueue);
+          break;
+        case MessageOneofCase.CancelPop:
+          if (CancelPop == null) {
+            CancelPop = new global::brqueue.CancelPopRequest();
+          }
+          CancelPop.MergeFrom(other.CancelPop);
+          break;
+        case MessageOneofCase.Cancel:
+          if (Cancel == null) {
+            Cancel = new global::brqueue.CancelRequest();
+          }
+          Cancel.MergeFrom(other.Cancel);
+          break;
+        case MessageOneofCase.FindByTag:
+          if (FindByTag == null) {
+            FindByTag = new global::brqueue.FindByTagRequest();
+          }
+          FindByTag.MergeFrom(other.FindByTag);
+          break;
+        case MessageOneofCase.ListProcessing:
+          if (ListProcessing == null) {
+            ListProcessing = new global::brqueue.ListProcessingRequest();
+          }
+          ListProcessing.MergeFrom(other.ListProcessing);
+          break;
+        case MessageOneofCase.SetCapabilityPaused:
+          if (SetCapabilityPaused == null) {
+            SetCapabilityPaused = new global::brqueue.SetCapabilityPausedRequest();
+          }
+          SetCapabilityPaused.MergeFrom(other.SetCapabilityPaused);
+          break;
+        case MessageOneofCase.Subscribe:
+          if (Subscribe == null) {
+            Subscribe = new global::brqueue.SubscribeRequest();
+          }
+          Subscribe.MergeFrom(other.Subscribe);
+          break;
+        case MessageOneofCase.Reserve:
+          if (Reserve == null) {
+            Reserve = new global::brqueue.ReserveRequest();
+          }
+          Reserve.MergeFrom(other.Reserve);
+          break;
+        case MessageOneofCase.ConfirmReservation:
+          if (ConfirmReservation == null) {
+            ConfirmReservation = new global::brqueue.ConfirmReservationRequest();
+          }
+          ConfirmReservation.MergeFrom(other.ConfirmReservation);
+          break;
+        case MessageOneofCase.Decline:
+          if (Decline == null) {
+            Decline = new global::brqueue.DeclineRequest();
+          }
+          Decline.MergeFrom(other.Decline);
+          break;
+        case MessageOneofCase.EnqueueBatch:
+          if (EnqueueBatch == null) {
+            EnqueueBatch = new global::brqueue.EnqueueBatchRequest();
+          }
+          EnqueueBatch.MergeFrom(other.EnqueueBatch);
+          break;
+        case MessageOneofCase.ListQueued:
+          if (ListQueued == null) {
+            ListQueued = new global::brqueue.ListQueuedRequest();
+          }
+          ListQueued.MergeFrom(other.ListQueued);
+          break;
+        case MessageOneofCase.Peek:
+          if (Peek == null) {
+            Peek = new global::brqueue.PeekRequest();
+          }
+          Peek.MergeFrom(other.Peek);
+          break;
+        case MessageOneofCase.Stats:
+          if (Stats == null) {
+            Stats = new global::brqueue.StatsRequest();
+          }
+          Stats.MergeFrom(other.Stats);
+          break;
+        case MessageOneofCase.Purge:
+          if (Purge == null) {
+            Purge = new global::brqueue.PurgeRequest();
+          }
+          Purge.MergeFrom(other.Purge);
+          break;
+        case MessageOneofCase.EventSubscribe:
+          if (EventSubscribe == null) {
+            EventSubscribe = new global::brqueue.EventSubscribeRequest();
+          }
+          EventSubscribe.MergeFrom(other.EventSubscribe);
+          break;
+        case MessageOneofCase.ExportDeadLetters:
+          if (ExportDeadLetters == null) {
+            ExportDeadLetters = new global::brqueue.ExportDeadLettersRequest();
+          }
+          ExportDeadLetters.MergeFrom(other.ExportDeadLetters);
+          break;
+      }
+
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            global::brqueue.EnqueueRequest subBuilder = new global::brqueue.EnqueueRequest();
+            if (messageCase_ == MessageOneofCase.Enqueue) {
+              subBuilder.MergeFrom(Enqueue);
+            }
+            input.ReadMessage(subBuilder);
+            Enqueue = subBuilder;
+            break;
+          }
+          case 18: {
+            global::brqueue.PopRequest subBuilder = new global::brqueue.PopRequest();
+            if (messageCase_ == MessageOneofCase.Pop) {
+              subBuilder.MergeFrom(Pop);
+            }
+            input.ReadMessage(subBuilder);
+            Pop = subBuilder;
+            break;
+          }
+          case 26: {
+            global::brqueue.AcknowledgeRequest subBuilder = new global::brqueue.AcknowledgeRequest();
+            if (messageCase_ == MessageOneofCase.Acknowledge) {
+              subBuilder.MergeFrom(Acknowledge);
+            }
+            input.ReadMessage(subBuilder);
+            Acknowledge = subBuilder;
+            break;
+          }
+          case 50: {
+            global::brqueue.AuthenticateRequest subBuilder = new global::brqueue.AuthenticateRequest();
+            if (messageCase_ == MessageOneofCase.Authenticate) {
+              subBuilder.MergeFrom(Authenticate);
+            }
+            input.ReadMessage(subBuilder);
+            Authenticate = subBuilder;
+            break;
+          }
+          case 58: {
+            global::brqueue.HealthRequest subBuilder = new global::brqueue.HealthRequest();
+            if (messageCase_ == MessageOneofCase.Health) {
+              subBuilder.MergeFrom(Health);
+            }
+            input.ReadMessage(subBuilder);
+            Health = subBuilder;
+            break;
+          }
+          case 66: {
+            global::brqueue.AcknowledgeMatchingRequest subBuilder = new global::brqueue.AcknowledgeMatchingRequest();
+            if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+              subBuilder.MergeFrom(AcknowledgeMatching);
+            }
+            input.ReadMessage(subBuilder);
+            AcknowledgeMatching = subBuilder;
+            break;
+          }
+          case 74: {
+            global::brqueue.FailWithRetagRequest subBuilder = new global::brqueue.FailWithRetagRequest();
+            if (messageCase_ == MessageOneofCase.FailWithRetag) {
+              subBuilder.MergeFrom(FailWithRetag);
+            }
+            input.ReadMessage(subBuilder);
+            FailWithRetag = subBuilder;
+            break;
+          }
+          case 80: {
+            RefId = input.ReadInt32();
+            break;
+          }
+          case 90: {
+            global::brqueue.ServerInfoRequest subBuilder = new global::brqueue.ServerInfoRequest();
+            if (messageCase_ == MessageOneofCase.ServerInfo) {
+              subBuilder.MergeFrom(ServerInfo);
+            }
+            input.ReadMessage(subBuilder);
+            ServerInfo = subBuilder;
+            break;
+          }
+          case 98: {
+            global::brqueue.FailRequest subBuilder = new global::brqueue.FailRequest();
+            if (messageCase_ == MessageOneofCase.Fail) {
+              subBuilder.MergeFrom(Fail);
+            }
+            input.ReadMessage(subBuilder);
+            Fail = subBuilder;
+            break;
+          }
+          case 106: {
+            global::brqueue.DiagnosticDumpRequest subBuilder = new global::brqueue.DiagnosticDumpRequest();
+            if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+              subBuilder.MergeFrom(DiagnosticDump);
+            }
+            input.ReadMessage(subBuilder);
+            DiagnosticDump = subBuilder;
+            break;
+          }
+          case 114: {
+            global::brqueue.BeginEnqueueRequest subBuilder = new global::brqueue.BeginEnqueueRequest();
+            if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+              subBuilder.MergeFrom(BeginEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            BeginEnqueue = subBuilder;
+            break;
+          }
+          case 122: {
+            global::brqueue.EnqueueChunkRequest subBuilder = new global::brqueue.EnqueueChunkRequest();
+            if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+              subBuilder.MergeFrom(EnqueueChunk);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueChunk = subBuilder;
+            break;
+          }
+          case 130: {
+            global::brqueue.CommitEnqueueRequest subBuilder = new global::brqueue.CommitEnqueueRequest();
+            if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+              subBuilder.MergeFrom(CommitEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            CommitEnqueue = subBuilder;
+            break;
+          }
+          case 138: {
+            global::brqueue.CancelPopRequest subBuilder = new global::brqueue.CancelPopRequest();
+            if (messageCase_ == MessageOneofCase.CancelPop) {
+              subBuilder.MergeFrom(CancelPop);
+            }
+            input.ReadMessage(subBuilder);
+            CancelPop = subBuilder;
+            break;
+          }
+          case 146: {
+            global::brqueue.CancelRequest subBuilder = new global::brqueue.CancelRequest();
+            if (messageCase_ == MessageOneofCase.Cancel) {
+              subBuilder.MergeFrom(Cancel);
+            }
+            input.ReadMessage(subBuilder);
+            Cancel = subBuilder;
+            break;
+          }
+          case 154: {
+            global::brqueue.FindByTagRequest subBuilder = new global::brqueue.FindByTagRequest();
+            if (messageCase_ == MessageOneofCase.FindByTag) {
+              subBuilder.MergeFrom(FindByTag);
+            }
+            input.ReadMessage(subBuilder);
+            FindByTag = subBuilder;
+            break;
+          }
+          case 162: {
+            global::brqueue.ListProcessingRequest subBuilder = new global::brqueue.ListProcessingRequest();
+            if (messageCase_ == MessageOneofCase.ListProcessing) {
+              subBuilder.MergeFrom(ListProcessing);
+            }
+            input.ReadMessage(subBuilder);
+            ListProcessing = subBuilder;
+            break;
+          }
+          case 170: {
+            global::brqueue.SetCapabilityPausedRequest subBuilder = new global::brqueue.SetCapabilityPausedRequest();
+            if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+              subBuilder.MergeFrom(SetCapabilityPaused);
+            }
+            input.ReadMessage(subBuilder);
+            SetCapabilityPaused = subBuilder;
+            break;
+          }
+          case 178: {
+            global::brqueue.SubscribeRequest subBuilder = new global::brqueue.SubscribeRequest();
+            if (messageCase_ == MessageOneofCase.Subscribe) {
+              subBuilder.MergeFrom(Subscribe);
+            }
+            input.ReadMessage(subBuilder);
+            Subscribe = subBuilder;
+            break;
+          }
+          case 186: {
+            global::brqueue.ReserveRequest subBuilder = new global::brqueue.ReserveRequest();
+            if (messageCase_ == MessageOneofCase.Reserve) {
+              subBuilder.MergeFrom(Reserve);
+            }
+            input.ReadMessage(subBuilder);
+            Reserve = subBuilder;
+            break;
+          }
+          case 194: {
+            global::brqueue.ConfirmReservationRequest subBuilder = new global::brqueue.ConfirmReservationRequest();
+            if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+              subBuilder.MergeFrom(ConfirmReservation);
+            }
+            input.ReadMessage(subBuilder);
+            ConfirmReservation = subBuilder;
+            break;
+          }
+          case 202: {
+            global::brqueue.DeclineRequest subBuilder = new global::brqueue.DeclineRequest();
+            if (messageCase_ == MessageOneofCase.Decline) {
+              subBuilder.MergeFrom(Decline);
+            }
+            input.ReadMessage(subBuilder);
+            Decline = subBuilder;
+            break;
+          }
+          case 210: {
+            global::brqueue.EnqueueBatchRequest subBuilder = new global::brqueue.EnqueueBatchRequest();
+            if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+              subBuilder.MergeFrom(EnqueueBatch);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueBatch = subBuilder;
+            break;
+          }
+          case 218: {
+            global::brqueue.ListQueuedRequest subBuilder = new global::brqueue.ListQueuedRequest();
+            if (messageCase_ == MessageOneofCase.ListQueued) {
+              subBuilder.MergeFrom(ListQueued);
+            }
+            input.ReadMessage(subBuilder);
+            ListQueued = subBuilder;
+            break;
+          }
+          case 226: {
+            global::brqueue.PeekRequest subBuilder = new global::brqueue.PeekRequest();
+            if (messageCase_ == MessageOneofCase.Peek) {
+              subBuilder.MergeFrom(Peek);
+            }
+            input.ReadMessage(subBuilder);
+            Peek = subBuilder;
+            break;
+          }
+          case 234: {
+            global::brqueue.StatsRequest subBuilder = new global::brqueue.StatsRequest();
+            if (messageCase_ == MessageOneofCase.Stats) {
+              subBuilder.MergeFrom(Stats);
+            }
+            input.ReadMessage(subBuilder);
+            Stats = subBuilder;
+            break;
+          }
+          case 242: {
+            global::brqueue.PurgeRequest subBuilder = new global::brqueue.PurgeRequest();
+            if (messageCase_ == MessageOneofCase.Purge) {
+              subBuilder.MergeFrom(Purge);
+            }
+            input.ReadMessage(subBuilder);
+            Purge = subBuilder;
+            break;
+          }
+          case 250: {
+            global::brqueue.EventSubscribeRequest subBuilder = new global::brqueue.EventSubscribeRequest();
+            if (messageCase_ == MessageOneofCase.EventSubscribe) {
+              subBuilder.MergeFrom(EventSubscribe);
+            }
+            input.ReadMessage(subBuilder);
+            EventSubscribe = subBuilder;
+            break;
+          }
+          case 258: {
+            global::brqueue.ExportDeadLettersRequest subBuilder = new global::brqueue.ExportDeadLettersRequest();
+            if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+              subBuilder.MergeFrom(ExportDeadLetters);
+            }
+            input.ReadMessage(subBuilder);
+            ExportDeadLetters = subBuilder;
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            global::brqueue.EnqueueRequest subBuilder = new global::brqueue.EnqueueRequest();
+            if (messageCase_ == MessageOneofCase.Enqueue) {
+              subBuilder.MergeFrom(Enqueue);
+            }
+            input.ReadMessage(subBuilder);
+            Enqueue = subBuilder;
+            break;
+          }
+          case 18: {
+            global::brqueue.PopRequest subBuilder = new global::brqueue.PopRequest();
+            if (messageCase_ == MessageOneofCase.Pop) {
+              subBuilder.MergeFrom(Pop);
+            }
+            input.ReadMessage(subBuilder);
+            Pop = subBuilder;
+            break;
+          }
+          case 26: {
+            global::brqueue.AcknowledgeRequest subBuilder = new global::brqueue.AcknowledgeRequest();
+            if (messageCase_ == MessageOneofCase.Acknowledge) {
+              subBuilder.MergeFrom(Acknowledge);
+            }
+            input.ReadMessage(subBuilder);
+            Acknowledge = subBuilder;
+            break;
+          }
+          case 50: {
+            global::brqueue.AuthenticateRequest subBuilder = new global::brqueue.AuthenticateRequest();
+            if (messageCase_ == MessageOneofCase.Authenticate) {
+              subBuilder.MergeFrom(Authenticate);
+            }
+            input.ReadMessage(subBuilder);
+            Authenticate = subBuilder;
+            break;
+          }
+          case 58: {
+            global::brqueue.HealthRequest subBuilder = new global::brqueue.HealthRequest();
+            if (messageCase_ == MessageOneofCase.Health) {
+              subBuilder.MergeFrom(Health);
+            }
+            input.ReadMessage(subBuilder);
+            Health = subBuilder;
+            break;
+          }
+          case 66: {
+            global::brqueue.AcknowledgeMatchingRequest subBuilder = new global::brqueue.AcknowledgeMatchingRequest();
+            if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+              subBuilder.MergeFrom(AcknowledgeMatching);
+            }
+            input.ReadMessage(subBuilder);
+            AcknowledgeMatching = subBuilder;
+            break;
+          }
+          case 74: {
+            global::brqueue.FailWithRetagRequest subBuilder = new global::brqueue.FailWithRetagRequest();
+            if (messageCase_ == MessageOneofCase.FailWithRetag) {
+              subBuilder.MergeFrom(FailWithRetag);
+            }
+            input.ReadMessage(subBuilder);
+            FailWithRetag = subBuilder;
+            break;
+          }
+          case 80: {
+            RefId = input.ReadInt32();
+            break;
+          }
+          case 90: {
+            global::brqueue.ServerInfoRequest subBuilder = new global::brqueue.ServerInfoRequest();
+            if (messageCase_ == MessageOneofCase.ServerInfo) {
+              subBuilder.MergeFrom(ServerInfo);
+            }
+            input.ReadMessage(subBuilder);
+            ServerInfo = subBuilder;
+            break;
+          }
+          case 98: {
+            global::brqueue.FailRequest subBuilder = new global::brqueue.FailRequest();
+            if (messageCase_ == MessageOneofCase.Fail) {
+              subBuilder.MergeFrom(Fail);
+            }
+            input.ReadMessage(subBuilder);
+            Fail = subBuilder;
+            break;
+          }
+          case 106: {
+            global::brqueue.DiagnosticDumpRequest subBuilder = new global::brqueue.DiagnosticDumpRequest();
+            if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+              subBuilder.MergeFrom(DiagnosticDump);
+            }
+            input.ReadMessage(subBuilder);
+            DiagnosticDump = subBuilder;
+            break;
+          }
+          case 114: {
+            global::brqueue.BeginEnqueueRequest subBuilder = new global::brqueue.BeginEnqueueRequest();
+            if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+              subBuilder.MergeFrom(BeginEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            BeginEnqueue = subBuilder;
+            break;
+          }
+          case 122: {
+            global::brqueue.EnqueueChunkRequest subBuilder = new global::brqueue.EnqueueChunkRequest();
+            if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+              subBuilder.MergeFrom(EnqueueChunk);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueChunk = subBuilder;
+            break;
+          }
+          case 130: {
+            global::brqueue.CommitEnqueueRequest subBuilder = new global::brqueue.CommitEnqueueRequest();
+            if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+              subBuilder.MergeFrom(CommitEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            CommitEnqueue = subBuilder;
+            break;
+          }
+          case 138: {
+            global::brqueue.CancelPopRequest subBuilder = new global::brqueue.CancelPopRequest();
+            if (messageCase_ == MessageOneofCase.CancelPop) {
+              subBuilder.MergeFrom(CancelPop);
+            }
+            input.ReadMessage(subBuilder);
+            CancelPop = subBuilder;
+            break;
+          }
+          case 146: {
+            global::brqueue.CancelRequest subBuilder = new global::brqueue.CancelRequest();
+            if (messageCase_ == MessageOneofCase.Cancel) {
+              subBuilder.MergeFrom(Cancel);
+            }
+            input.ReadMessage(subBuilder);
+            Cancel = subBuilder;
+            break;
+          }
+          case 154: {
+            global::brqueue.FindByTagRequest subBuilder = new global::brqueue.FindByTagRequest();
+            if (messageCase_ == MessageOneofCase.FindByTag) {
+              subBuilder.MergeFrom(FindByTag);
+            }
+            input.ReadMessage(subBuilder);
+            FindByTag = subBuilder;
+            break;
+          }
+          case 162: {
+            global::brqueue.ListProcessingRequest subBuilder = new global::brqueue.ListProcessingRequest();
+            if (messageCase_ == MessageOneofCase.ListProcessing) {
+              subBuilder.MergeFrom(ListProcessing);
+            }
+            input.ReadMessage(subBuilder);
+            ListProcessing = subBuilder;
+            break;
+          }
+          case 170: {
+            global::brqueue.SetCapabilityPausedRequest subBuilder = new global::brqueue.SetCapabilityPausedRequest();
+            if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+              subBuilder.MergeFrom(SetCapabilityPaused);
+            }
+            input.ReadMessage(subBuilder);
+            SetCapabilityPaused = subBuilder;
+            break;
+          }
+          case 178: {
+            global::brqueue.SubscribeRequest subBuilder = new global::brqueue.SubscribeRequest();
+            if (messageCase_ == MessageOneofCase.Subscribe) {
+              subBuilder.MergeFrom(Subscribe);
+            }
+            input.ReadMessage(subBuilder);
+            Subscribe = subBuilder;
+            break;
+          }
+          case 186: {
+            global::brqueue.ReserveRequest subBuilder = new global::brqueue.ReserveRequest();
+            if (messageCase_ == MessageOneofCase.Reserve) {
+              subBuilder.MergeFrom(Reserve);
+            }
+            input.ReadMessage(subBuilder);
+            Reserve = subBuilder;
+            break;
+          }
+          case 194: {
+            global::brqueue.ConfirmReservationRequest subBuilder = new global::brqueue.ConfirmReservationRequest();
+            if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+              subBuilder.MergeFrom(ConfirmReservation);
+            }
+            input.ReadMessage(subBuilder);
+            ConfirmReservation = subBuilder;
+            break;
+          }
+          case 202: {
+            global::brqueue.DeclineRequest subBuilder = new global::brqueue.DeclineRequest();
+            if (messageCase_ == MessageOneofCase.Decline) {
+              subBuilder.MergeFrom(Decline);
+            }
+            input.ReadMessage(subBuilder);
+            Decline = subBuilder;
+            break;
+          }
+          case 210: {
+            global::brqueue.EnqueueBatchRequest subBuilder = new global::brqueue.EnqueueBatchRequest();
+            if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+              subBuilder.MergeFrom(EnqueueBatch);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueBatch = subBuilder;
+            break;
+          }
+          case 218: {
+            global::brqueue.ListQueuedRequest subBuilder = new global::brqueue.ListQueuedRequest();
+            if (messageCase_ == MessageOneofCase.ListQueued) {
+              subBuilder.MergeFrom(ListQueued);
+            }
+            input.ReadMessage(subBuilder);
+            ListQueued = subBuilder;
+            break;
+          }
+          case 226: {
+            global::brqueue.PeekRequest subBuilder = new global::brqueue.PeekRequest();
+            if (messageCase_ == MessageOneofCase.Peek) {
+              subBuilder.MergeFrom(Peek);
+            }
+            input.ReadMessage(subBuilder);
+            Peek = subBuilder;
+            break;
+          }
+          case 234: {
+            global::brqueue.StatsRequest subBuilder = new global::brqueue.StatsRequest();
+            if (messageCase_ == MessageOneofCase.Stats) {
+              subBuilder.MergeFrom(Stats);
+            }
+            input.ReadMessage(subBuilder);
+            Stats = subBuilder;
+            break;
+          }
+          case 242: {
+            global::brqueue.PurgeRequest subBuilder = new global::brqueue.PurgeRequest();
+            if (messageCase_ == MessageOneofCase.Purge) {
+              subBuilder.MergeFrom(Purge);
+            }
+            input.ReadMessage(subBuilder);
+            Purge = subBuilder;
+            break;
+          }
+          case 250: {
+            global::brqueue.EventSubscribeRequest subBuilder = new global::brqueue.EventSubscribeRequest();
+            if (messageCase_ == MessageOneofCase.EventSubscribe) {
+              subBuilder.MergeFrom(EventSubscribe);
+            }
+            input.ReadMessage(subBuilder);
+            EventSubscribe = subBuilder;
+            break;
+          }
+          case 258: {
+            global::brqueue.ExportDeadLettersRequest subBuilder = new global::brqueue.ExportDeadLettersRequest();
+            if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+              subBuilder.MergeFrom(ExportDeadLetters);
+            }
+            input.ReadMessage(subBuilder);
+            ExportDeadLetters = subBuilder;
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class ResponseWrapper : pb::IMessage<ResponseWrapper>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<ResponseWrapper> _parser = new pb::MessageParser<ResponseWrapper>(() => new ResponseWrapper());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<ResponseWrapper> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::brqueue.QueueReflection.Descriptor.MessageTypes[67]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ResponseWrapper() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ResponseWrapper(ResponseWrapper other) : this() {
+      refId_ = other.refId_;
+      switch (other.MessageCase) {
+        case MessageOneofCase.Enqueue:
+          Enqueue = other.Enqueue.Clone();
+          break;
+        case MessageOneofCase.Pop:
+          Pop = other.Pop.Clone();
+          break;
+        case MessageOneofCase.Acknowledge:
+          Acknowledge = other.Acknowledge.Clone();
+          break;
+        case MessageOneofCase.Error:
+          Error = other.Error.Clone();
+          break;
+        case MessageOneofCase.Authenticate:
+          Authenticate = other.Authenticate.Clone();
+          break;
+        case MessageOneofCase.Health:
+          Health = other.Health.Clone();
+          break;
+        case MessageOneofCase.AcknowledgeMatching:
+          AcknowledgeMatching = other.AcknowledgeMatching.Clone();
+          break;
+        case MessageOneofCase.FailWithRetag:
+          FailWithRetag = other.FailWithRetag.Clone();
+          break;
+        case MessageOneofCase.ServerInfo:
+          ServerInfo = other.ServerInfo.Clone();
+          break;
+        case MessageOneofCase.Fail:
+          Fail = other.Fail.Clone();
+          break;
+        case MessageOneofCase.DiagnosticDump:
+          DiagnosticDump = other.DiagnosticDump.Clone();
+          break;
+        case MessageOneofCase.BeginEnqueue:
+          BeginEnqueue = other.BeginEnqueue.Clone();
+          break;
+        case MessageOneofCase.EnqueueChunk:
+          EnqueueChunk = other.EnqueueChunk.Clone();
+          break;
+        case MessageOneofCase.CommitEnqueue:
+          CommitEnqueue = other.CommitEnqueue.Clone();
+          break;
+        case MessageOneofCase.CancelPop:
+          CancelPop = other.CancelPop.Clone();
+          break;
+        case MessageOneofCase.Cancel:
+          Cancel = other.Cancel.Clone();
+          break;
+        case MessageOneofCase.FindByTag:
+          FindByTag = other.FindByTag.Clone();
+          break;
+        case MessageOneofCase.ListProcessing:
+          ListProcessing = other.ListProcessing.Clone();
+          break;
+        case MessageOneofCase.SetCapabilityPaused:
+          SetCapabilityPaused = other.SetCapabilityPaused.Clone();
+          break;
+        case MessageOneofCase.Subscribe:
+          Subscribe = other.Subscribe.Clone();
+          break;
+        case MessageOneofCase.Reserve:
+          Reserve = other.Reserve.Clone();
+          break;
+        case MessageOneofCase.ConfirmReservation:
+          ConfirmReservation = other.ConfirmReservation.Clone();
+          break;
+        case MessageOneofCase.Decline:
+          Decline = other.Decline.Clone();
+          break;
+        case MessageOneofCase.EnqueueBatch:
+          EnqueueBatch = other.EnqueueBatch.Clone();
+          break;
+        case MessageOneofCase.ListQueued:
+          ListQueued = other.ListQueued.Clone();
+          break;
+        case MessageOneofCase.Peek:
+          Peek = other.Peek.Clone();
+          break;
+        case MessageOneofCase.Stats:
+          Stats = other.Stats.Clone();
+          break;
+        case MessageOneofCase.Purge:
+          Purge = other.Purge.Clone();
+          break;
+        case MessageOneofCase.EventSubscribe:
+          EventSubscribe = other.EventSubscribe.Clone();
+          break;
+        case MessageOneofCase.Event:
+          Event = other.Event.Clone();
+          break;
+        case MessageOneofCase.ExportDeadLetters:
+          ExportDeadLetters = other.ExportDeadLetters.Clone();
+          break;
+      }
+
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ResponseWrapper Clone() {
+      return new ResponseWrapper(this);
+    }
+
+    /// <summary>Field number for the "refId" field.</summary>
+    public const int RefIdFieldNumber = 10;
+    private int refId_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int RefId {
+      get { return refId_; }
+      set {
+        refId_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "enqueue" field.</summary>
+    public const int EnqueueFieldNumber = 1;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueResponse Enqueue {
+      get { return messageCase_ == MessageOneofCase.Enqueue ? (global::brqueue.EnqueueResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Enqueue;
+      }
+    }
+
+    /// <summary>Field number for the "pop" field.</summary>
+    public const int PopFieldNumber = 2;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PopResponse Pop {
+      get { return messageCase_ == MessageOneofCase.Pop ? (global::brqueue.PopResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Pop;
+      }
+    }
+
+    /// <summary>Field number for the "acknowledge" field.</summary>
+    public const int AcknowledgeFieldNumber = 3;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AcknowledgeResponse Acknowledge {
+      get { return messageCase_ == MessageOneofCase.Acknowledge ? (global::brqueue.AcknowledgeResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Acknowledge;
+      }
+    }
+
+    /// <summary>Field number for the "error" field.</summary>
+    public const int ErrorFieldNumber = 4;
+    /// <summary>
+    /// If something went wrong
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ErrorResponse Error {
+      get { return messageCase_ == MessageOneofCase.Error ? (global::brqueue.ErrorResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Error;
+      }
+    }
+
+    /// <summary>Field number for the "authenticate" field.</summary>
+    public const int AuthenticateFieldNumber = 6;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AuthenticateResponse Authenticate {
+      get { return messageCase_ == MessageOneofCase.Authenticate ? (global::brqueue.AuthenticateResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Authenticate;
+      }
+    }
+
+    /// <summary>Field number for the "health" field.</summary>
+    public const int HealthFieldNumber = 7;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.HealthResponse Health {
+      get { return messageCase_ == MessageOneofCase.Health ? (global::brqueue.HealthResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Health;
+      }
+    }
+
+    /// <summary>Field number for the "acknowledgeMatching" field.</summary>
+    public const int AcknowledgeMatchingFieldNumber = 8;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.AcknowledgeMatchingResponse AcknowledgeMatching {
+      get { return messageCase_ == MessageOneofCase.AcknowledgeMatching ? (global::brqueue.AcknowledgeMatchingResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.AcknowledgeMatching;
+      }
+    }
+
+    /// <summary>Field number for the "failWithRetag" field.</summary>
+    public const int FailWithRetagFieldNumber = 9;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FailWithRetagResponse FailWithRetag {
+      get { return messageCase_ == MessageOneofCase.FailWithRetag ? (global::brqueue.FailWithRetagResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.FailWithRetag;
+      }
+    }
+
+    /// <summary>Field number for the "serverInfo" field.</summary>
+    public const int ServerInfoFieldNumber = 11;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ServerInfoResponse ServerInfo {
+      get { return messageCase_ == MessageOneofCase.ServerInfo ? (global::brqueue.ServerInfoResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ServerInfo;
+      }
+    }
+
+    /// <summary>Field number for the "fail" field.</summary>
+    public const int FailFieldNumber = 12;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FailResponse Fail {
+      get { return messageCase_ == MessageOneofCase.Fail ? (global::brqueue.FailResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Fail;
+      }
+    }
+
+    /// <summary>Field number for the "diagnosticDump" field.</summary>
+    public const int DiagnosticDumpFieldNumber = 13;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.DiagnosticDumpResponse DiagnosticDump {
+      get { return messageCase_ == MessageOneofCase.DiagnosticDump ? (global::brqueue.DiagnosticDumpResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.DiagnosticDump;
+      }
+    }
+
+    /// <summary>Field number for the "beginEnqueue" field.</summary>
+    public const int BeginEnqueueFieldNumber = 14;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.BeginEnqueueResponse BeginEnqueue {
+      get { return messageCase_ == MessageOneofCase.BeginEnqueue ? (global::brqueue.BeginEnqueueResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.BeginEnqueue;
+      }
+    }
+
+    /// <summary>Field number for the "enqueueChunk" field.</summary>
+    public const int EnqueueChunkFieldNumber = 15;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueChunkResponse EnqueueChunk {
+      get { return messageCase_ == MessageOneofCase.EnqueueChunk ? (global::brqueue.EnqueueChunkResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EnqueueChunk;
+      }
+    }
+
+    /// <summary>Field number for the "commitEnqueue" field.</summary>
+    public const int CommitEnqueueFieldNumber = 16;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CommitEnqueueResponse CommitEnqueue {
+      get { return messageCase_ == MessageOneofCase.CommitEnqueue ? (global::brqueue.CommitEnqueueResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.CommitEnqueue;
+      }
+    }
+
+    /// <summary>Field number for the "cancelPop" field.</summary>
+    public const int CancelPopFieldNumber = 17;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CancelPopResponse CancelPop {
+      get { return messageCase_ == MessageOneofCase.CancelPop ? (global::brqueue.CancelPopResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.CancelPop;
+      }
+    }
+
+    /// <summary>Field number for the "cancel" field.</summary>
+    public const int CancelFieldNumber = 18;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.CancelResponse Cancel {
+      get { return messageCase_ == MessageOneofCase.Cancel ? (global::brqueue.CancelResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Cancel;
+      }
+    }
+
+    /// <summary>Field number for the "findByTag" field.</summary>
+    public const int FindByTagFieldNumber = 19;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.FindByTagResponse FindByTag {
+      get { return messageCase_ == MessageOneofCase.FindByTag ? (global::brqueue.FindByTagResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.FindByTag;
+      }
+    }
+
+    /// <summary>Field number for the "listProcessing" field.</summary>
+    public const int ListProcessingFieldNumber = 20;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ListProcessingResponse ListProcessing {
+      get { return messageCase_ == MessageOneofCase.ListProcessing ? (global::brqueue.ListProcessingResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ListProcessing;
+      }
+    }
+
+    /// <summary>Field number for the "setCapabilityPaused" field.</summary>
+    public const int SetCapabilityPausedFieldNumber = 21;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.SetCapabilityPausedResponse SetCapabilityPaused {
+      get { return messageCase_ == MessageOneofCase.SetCapabilityPaused ? (global::brqueue.SetCapabilityPausedResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.SetCapabilityPaused;
+      }
+    }
+
+    /// <summary>Field number for the "subscribe" field.</summary>
+    public const int SubscribeFieldNumber = 22;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.SubscribeResponse Subscribe {
+      get { return messageCase_ == MessageOneofCase.Subscribe ? (global::brqueue.SubscribeResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Subscribe;
+      }
+    }
+
+    /// <summary>Field number for the "reserve" field.</summary>
+    public const int ReserveFieldNumber = 23;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ReserveResponse Reserve {
+      get { return messageCase_ == MessageOneofCase.Reserve ? (global::brqueue.ReserveResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Reserve;
+      }
+    }
+
+    /// <summary>Field number for the "confirmReservation" field.</summary>
+    public const int ConfirmReservationFieldNumber = 24;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ConfirmReservationResponse ConfirmReservation {
+      get { return messageCase_ == MessageOneofCase.ConfirmReservation ? (global::brqueue.ConfirmReservationResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ConfirmReservation;
+      }
+    }
+
+    /// <summary>Field number for the "decline" field.</summary>
+    public const int DeclineFieldNumber = 25;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.DeclineResponse Decline {
+      get { return messageCase_ == MessageOneofCase.Decline ? (global::brqueue.DeclineResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Decline;
+      }
+    }
+
+    /// <summary>Field number for the "enqueueBatch" field.</summary>
+    public const int EnqueueBatchFieldNumber = 26;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EnqueueBatchResponse EnqueueBatch {
+      get { return messageCase_ == MessageOneofCase.EnqueueBatch ? (global::brqueue.EnqueueBatchResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EnqueueBatch;
+      }
+    }
+
+    /// <summary>Field number for the "listQueued" field.</summary>
+    public const int ListQueuedFieldNumber = 27;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ListQueuedResponse ListQueued {
+      get { return messageCase_ == MessageOneofCase.ListQueued ? (global::brqueue.ListQueuedResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ListQueued;
+      }
+    }
+
+    /// <summary>Field number for the "peek" field.</summary>
+    public const int PeekFieldNumber = 28;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PeekResponse Peek {
+      get { return messageCase_ == MessageOneofCase.Peek ? (global::brqueue.PeekResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Peek;
+      }
+    }
+
+    /// <summary>Field number for the "stats" field.</summary>
+    public const int StatsFieldNumber = 29;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.StatsResponse Stats {
+      get { return messageCase_ == MessageOneofCase.Stats ? (global::brqueue.StatsResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Stats;
+      }
+    }
+
+    /// <summary>Field number for the "purge" field.</summary>
+    public const int PurgeFieldNumber = 30;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.PurgeResponse Purge {
+      get { return messageCase_ == MessageOneofCase.Purge ? (global::brqueue.PurgeResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Purge;
+      }
+    }
+
+    /// <summary>Field number for the "eventSubscribe" field.</summary>
+    public const int EventSubscribeFieldNumber = 31;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.EventSubscribeResponse EventSubscribe {
+      get { return messageCase_ == MessageOneofCase.EventSubscribe ? (global::brqueue.EventSubscribeResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.EventSubscribe;
+      }
+    }
+
+    /// <summary>Field number for the "event" field.</summary>
+    public const int EventFieldNumber = 32;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.QueueEventResponse Event {
+      get { return messageCase_ == MessageOneofCase.Event ? (global::brqueue.QueueEventResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.Event;
+      }
+    }
+
+    /// <summary>Field number for the "exportDeadLetters" field.</summary>
+    public const int ExportDeadLettersFieldNumber = 33;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::brqueue.ExportDeadLettersResponse ExportDeadLetters {
+      get { return messageCase_ == MessageOneofCase.ExportDeadLetters ? (global::brqueue.ExportDeadLettersResponse) message_ : null; }
+      set {
+        message_ = value;
+        messageCase_ = value == null ? MessageOneofCase.None : MessageOneofCase.ExportDeadLetters;
+      }
+    }
+
+    private object message_;
+    /// <summary>Enum of possible cases for the "message" oneof.</summary>
+    public enum MessageOneofCase {
+      None = 0,
+      Enqueue = 1,
+      Pop = 2,
+      Acknowledge = 3,
+      Error = 4,
+      Authenticate = 6,
+      Health = 7,
+      AcknowledgeMatching = 8,
+      FailWithRetag = 9,
+      ServerInfo = 11,
+      Fail = 12,
+      DiagnosticDump = 13,
+      BeginEnqueue = 14,
+      EnqueueChunk = 15,
+      CommitEnqueue = 16,
+      CancelPop = 17,
+      Cancel = 18,
+      FindByTag = 19,
+      ListProcessing = 20,
+      SetCapabilityPaused = 21,
+      Subscribe = 22,
+      Reserve = 23,
+      ConfirmReservation = 24,
+      Decline = 25,
+      EnqueueBatch = 26,
+      ListQueued = 27,
+      Peek = 28,
+      Stats = 29,
+      Purge = 30,
+      EventSubscribe = 31,
+      Event = 32,
+      ExportDeadLetters = 33,
+    }
+    private MessageOneofCase messageCase_ = MessageOneofCase.None;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public MessageOneofCase MessageCase {
+      get { return messageCase_; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void ClearMessage() {
+      messageCase_ = MessageOneofCase.None;
+      message_ = null;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as ResponseWrapper);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(ResponseWrapper other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (RefId != other.RefId) return false;
+      if (!object.Equals(Enqueue, other.Enqueue)) return false;
+      if (!object.Equals(Pop, other.Pop)) return false;
+      if (!object.Equals(Acknowledge, other.Acknowledge)) return false;
+      if (!object.Equals(Error, other.Error)) return false;
+      if (!object.Equals(Authenticate, other.Authenticate)) return false;
+      if (!object.Equals(Health, other.Health)) return false;
+      if (!object.Equals(AcknowledgeMatching, other.AcknowledgeMatching)) return false;
+      if (!object.Equals(FailWithRetag, other.FailWithRetag)) return false;
+      if (!object.Equals(ServerInfo, other.ServerInfo)) return false;
+      if (!object.Equals(Fail, other.Fail)) return false;
+      if (!object.Equals(DiagnosticDump, other.DiagnosticDump)) return false;
+      if (!object.Equals(BeginEnqueue, other.BeginEnqueue)) return false;
+      if (!object.Equals(EnqueueChunk, other.EnqueueChunk)) return false;
+      if (!object.Equals(CommitEnqueue, other.CommitEnqueue)) return false;
+      if (!object.Equals(CancelPop, other.CancelPop)) return false;
+      if (!object.Equals(Cancel, other.Cancel)) return false;
+      if (!object.Equals(FindByTag, other.FindByTag)) return false;
+      if (!object.Equals(ListProcessing, other.ListProcessing)) return false;
+      if (!object.Equals(SetCapabilityPaused, other.SetCapabilityPaused)) return false;
+      if (!object.Equals(Subscribe, other.Subscribe)) return false;
+      if (!object.Equals(Reserve, other.Reserve)) return false;
+      if (!object.Equals(ConfirmReservation, other.ConfirmReservation)) return false;
+      if (!object.Equals(Decline, other.Decline)) return false;
+      if (!object.Equals(EnqueueBatch, other.EnqueueBatch)) return false;
+      if (!object.Equals(ListQueued, other.ListQueued)) return false;
+      if (!object.Equals(Peek, other.Peek)) return false;
+      if (!object.Equals(Stats, other.Stats)) return false;
+      if (!object.Equals(Purge, other.Purge)) return false;
+      if (!object.Equals(EventSubscribe, other.EventSubscribe)) return false;
+      if (!object.Equals(Event, other.Event)) return false;
+      if (!object.Equals(ExportDeadLetters, other.ExportDeadLetters)) return false;
+      if (MessageCase != other.MessageCase) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (RefId != 0) hash ^= RefId.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Enqueue) hash ^= Enqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Pop) hash ^= Pop.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Acknowledge) hash ^= Acknowledge.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Error) hash ^= Error.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Authenticate) hash ^= Authenticate.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Health) hash ^= Health.GetHashCode();
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) hash ^= AcknowledgeMatching.GetHashCode();
+      if (messageCase_ == MessageOneofCase.FailWithRetag) hash ^= FailWithRetag.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ServerInfo) hash ^= ServerInfo.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Fail) hash ^= Fail.GetHashCode();
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) hash ^= DiagnosticDump.GetHashCode();
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) hash ^= BeginEnqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) hash ^= EnqueueChunk.GetHashCode();
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) hash ^= CommitEnqueue.GetHashCode();
+      if (messageCase_ == MessageOneofCase.CancelPop) hash ^= CancelPop.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Cancel) hash ^= Cancel.GetHashCode();
+      if (messageCase_ == MessageOneofCase.FindByTag) hash ^= FindByTag.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ListProcessing) hash ^= ListProcessing.GetHashCode();
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) hash ^= SetCapabilityPaused.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Subscribe) hash ^= Subscribe.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Reserve) hash ^= Reserve.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) hash ^= ConfirmReservation.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Decline) hash ^= Decline.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) hash ^= EnqueueBatch.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ListQueued) hash ^= ListQueued.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Peek) hash ^= Peek.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Stats) hash ^= Stats.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Purge) hash ^= Purge.GetHashCode();
+      if (messageCase_ == MessageOneofCase.EventSubscribe) hash ^= EventSubscribe.GetHashCode();
+      if (messageCase_ == MessageOneofCase.Event) hash ^= Event.GetHashCode();
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) hash ^= ExportDeadLetters.GetHashCode();
+      hash ^= (int) messageCase_;
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        output.WriteRawTag(26);
+        output.WriteMessage(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Error) {
+        output.WriteRawTag(34);
+        output.WriteMessage(Error);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        output.WriteRawTag(58);
+        output.WriteMessage(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        output.WriteRawTag(66);
+        output.WriteMessage(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        output.WriteRawTag(74);
+        output.WriteMessage(FailWithRetag);
+      }
+      if (RefId != 0) {
+        output.WriteRawTag(80);
+        output.WriteInt32(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        output.WriteRawTag(90);
+        output.WriteMessage(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        output.WriteRawTag(98);
+        output.WriteMessage(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        output.WriteRawTag(106);
+        output.WriteMessage(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        output.WriteRawTag(114);
+        output.WriteMessage(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        output.WriteRawTag(122);
+        output.WriteMessage(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        output.WriteRawTag(130, 1);
+        output.WriteMessage(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        output.WriteRawTag(138, 1);
+        output.WriteMessage(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        output.WriteRawTag(146, 1);
+        output.WriteMessage(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        output.WriteRawTag(154, 1);
+        output.WriteMessage(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        output.WriteRawTag(162, 1);
+        output.WriteMessage(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        output.WriteRawTag(170, 1);
+        output.WriteMessage(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        output.WriteRawTag(178, 1);
+        output.WriteMessage(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        output.WriteRawTag(186, 1);
+        output.WriteMessage(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        output.WriteRawTag(194, 1);
+        output.WriteMessage(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        output.WriteRawTag(202, 1);
+        output.WriteMessage(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        output.WriteRawTag(218, 1);
+        output.WriteMessage(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        output.WriteRawTag(226, 1);
+        output.WriteMessage(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        output.WriteRawTag(234, 1);
+        output.WriteMessage(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        output.WriteRawTag(242, 1);
+        output.WriteMessage(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        output.WriteRawTag(250, 1);
+        output.WriteMessage(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Event) {
+        output.WriteRawTag(130, 2);
+        output.WriteMessage(Event);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        output.WriteRawTag(138, 2);
+        output.WriteMessage(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        output.WriteRawTag(18);
+        output.WriteMessage(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        output.WriteRawTag(26);
+        output.WriteMessage(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Error) {
+        output.WriteRawTag(34);
+        output.WriteMessage(Error);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        output.WriteRawTag(58);
+        output.WriteMessage(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        output.WriteRawTag(66);
+        output.WriteMessage(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        output.WriteRawTag(74);
+        output.WriteMessage(FailWithRetag);
+      }
+      if (RefId != 0) {
+        output.WriteRawTag(80);
+        output.WriteInt32(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        output.WriteRawTag(90);
+        output.WriteMessage(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        output.WriteRawTag(98);
+        output.WriteMessage(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        output.WriteRawTag(106);
+        output.WriteMessage(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        output.WriteRawTag(114);
+        output.WriteMessage(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        output.WriteRawTag(122);
+        output.WriteMessage(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        output.WriteRawTag(130, 1);
+        output.WriteMessage(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        output.WriteRawTag(138, 1);
+        output.WriteMessage(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        output.WriteRawTag(146, 1);
+        output.WriteMessage(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        output.WriteRawTag(154, 1);
+        output.WriteMessage(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        output.WriteRawTag(162, 1);
+        output.WriteMessage(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        output.WriteRawTag(170, 1);
+        output.WriteMessage(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        output.WriteRawTag(178, 1);
+        output.WriteMessage(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        output.WriteRawTag(186, 1);
+        output.WriteMessage(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        output.WriteRawTag(194, 1);
+        output.WriteMessage(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        output.WriteRawTag(202, 1);
+        output.WriteMessage(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        output.WriteRawTag(218, 1);
+        output.WriteMessage(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        output.WriteRawTag(226, 1);
+        output.WriteMessage(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        output.WriteRawTag(234, 1);
+        output.WriteMessage(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        output.WriteRawTag(242, 1);
+        output.WriteMessage(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        output.WriteRawTag(250, 1);
+        output.WriteMessage(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Event) {
+        output.WriteRawTag(130, 2);
+        output.WriteMessage(Event);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        output.WriteRawTag(138, 2);
+        output.WriteMessage(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (RefId != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeInt32Size(RefId);
+      }
+      if (messageCase_ == MessageOneofCase.Enqueue) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Enqueue);
+      }
+      if (messageCase_ == MessageOneofCase.Pop) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Pop);
+      }
+      if (messageCase_ == MessageOneofCase.Acknowledge) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Acknowledge);
+      }
+      if (messageCase_ == MessageOneofCase.Error) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Error);
+      }
+      if (messageCase_ == MessageOneofCase.Authenticate) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Authenticate);
+      }
+      if (messageCase_ == MessageOneofCase.Health) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Health);
+      }
+      if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(AcknowledgeMatching);
+      }
+      if (messageCase_ == MessageOneofCase.FailWithRetag) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(FailWithRetag);
+      }
+      if (messageCase_ == MessageOneofCase.ServerInfo) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(ServerInfo);
+      }
+      if (messageCase_ == MessageOneofCase.Fail) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Fail);
+      }
+      if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(DiagnosticDump);
+      }
+      if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(BeginEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(EnqueueChunk);
+      }
+      if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(CommitEnqueue);
+      }
+      if (messageCase_ == MessageOneofCase.CancelPop) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(CancelPop);
+      }
+      if (messageCase_ == MessageOneofCase.Cancel) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Cancel);
+      }
+      if (messageCase_ == MessageOneofCase.FindByTag) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(FindByTag);
+      }
+      if (messageCase_ == MessageOneofCase.ListProcessing) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ListProcessing);
+      }
+      if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(SetCapabilityPaused);
+      }
+      if (messageCase_ == MessageOneofCase.Subscribe) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Subscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Reserve) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Reserve);
+      }
+      if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ConfirmReservation);
+      }
+      if (messageCase_ == MessageOneofCase.Decline) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Decline);
+      }
+      if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(EnqueueBatch);
+      }
+      if (messageCase_ == MessageOneofCase.ListQueued) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ListQueued);
+      }
+      if (messageCase_ == MessageOneofCase.Peek) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Peek);
+      }
+      if (messageCase_ == MessageOneofCase.Stats) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Stats);
+      }
+      if (messageCase_ == MessageOneofCase.Purge) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Purge);
+      }
+      if (messageCase_ == MessageOneofCase.EventSubscribe) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(EventSubscribe);
+      }
+      if (messageCase_ == MessageOneofCase.Event) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(Event);
+      }
+      if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(ExportDeadLetters);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(ResponseWrapper other) {
+      if (other == null) {
+        return;
+      }
+      if (other.RefId != 0) {
+        RefId = other.RefId;
+      }
+      switch (other.MessageCase) {
+        case MessageOneofCase.Enqueue:
+          if (Enqueue == null) {
+            Enqueue = new global::brqueue.EnqueueResponse();
+          }
+          Enqueue.MergeFrom(other.Enqueue);
+          break;
+        case MessageOneofCase.Pop:
+          if (Pop == null) {
+            Pop = new global::brqueue.PopResponse();
+          }
+          Pop.MergeFrom(other.Pop);
+          break;
+        case MessageOneofCase.Acknowledge:
+          if (Acknowledge == null) {
+            Acknowledge = new global::brqueue.AcknowledgeResponse();
+          }
+          Acknowledge.MergeFrom(other.Acknowledge);
+          break;
+        case MessageOneofCase.Error:
+          if (Error == null) {
+            Error = new global::brqueue.ErrorResponse();
+          }
+          Error.MergeFrom(other.Error);
+          break;
+        case MessageOneofCase.Authenticate:
+          if (Authenticate == null) {
+            Authenticate = new global::brqueue.AuthenticateResponse();
+          }
+          Authenticate.MergeFrom(other.Authenticate);
+          break;
+        case MessageOneofCase.Health:
+          if (Health == null) {
+            Health = new global::brqueue.HealthResponse();
+          }
+          Health.MergeFrom(other.Health);
+          break;
+        case MessageOneofCase.AcknowledgeMatching:
+          if (AcknowledgeMatching == null) {
+            AcknowledgeMatching = new global::brqueue.AcknowledgeMatchingResponse();
+          }
+          AcknowledgeMatching.MergeFrom(other.AcknowledgeMatching);
+          break;
+        case MessageOneofCase.FailWithRetag:
+          if (FailWithRetag == null) {
+            FailWithRetag = new global::brqueue.FailWithRetagResponse();
+          }
+          FailWithRetag.MergeFrom(other.FailWithRetag);
+          break;
+        case MessageOneofCase.ServerInfo:
+          if (ServerInfo == null) {
+            ServerInfo = new global::brqueue.ServerInfoResponse();
+          }
+          ServerInfo.MergeFrom(other.ServerInfo);
+          break;
+        case MessageOneofCase.Fail:
+          if (Fail == null) {
+            Fail = new global::brqueue.FailResponse();
+          }
+          Fail.MergeFrom(other.Fail);
+          break;
+        case MessageOneofCase.DiagnosticDump:
+          if (DiagnosticDump == null) {
+            DiagnosticDump = new global::brqueue.DiagnosticDumpResponse();
+          }
+          DiagnosticDump.MergeFrom(other.DiagnosticDump);
+          break;
+        case MessageOneofCase.BeginEnqueue:
+          if (BeginEnqueue == null) {
+            BeginEnqueue = new global::brqueue.BeginEnqueueResponse();
+          }
+          BeginEnqueue.MergeFrom(other.BeginEnqueue);
+          break;
+        case MessageOneofCase.EnqueueChunk:
+          if (EnqueueChunk == null) {
+            EnqueueChunk = new global::brqueue.EnqueueChunkResponse();
+          }
+          EnqueueChunk.MergeFrom(other.EnqueueChunk);
+          break;
+        case MessageOneofCase.CommitEnqueue:
+          if (CommitEnqueue == null) {
+            CommitEnqueue = new global::brqueue.CommitEnqueueResponse();
+          }
+          CommitEnqueue.MergeFrom(other.CommitEnqueue);
+          break;
+        case MessageOneofCase.CancelPop:
+          if (CancelPop == null) {
+            CancelPop = new global::brqueue.CancelPopResponse();
+          }
+          CancelPop.MergeFrom(other.CancelPop);
+          break;
+        case MessageOneofCase.Cancel:
+          if (Cancel == null) {
+            Cancel = new global::brqueue.CancelResponse();
+          }
+          Cancel.MergeFrom(other.Cancel);
+          break;
+        case MessageOneofCase.FindByTag:
+          if (FindByTag == null) {
+            FindByTag = new global::brqueue.FindByTagResponse();
+          }
+          FindByTag.MergeFrom(other.FindByTag);
+          break;
+        case MessageOneofCase.ListProcessing:
+          if (ListProcessing == null) {
+            ListProcessing = new global::brqueue.ListProcessingResponse();
+          }
+          ListProcessing.MergeFrom(other.ListProcessing);
+          break;
+        case MessageOneofCase.SetCapabilityPaused:
+          if (SetCapabilityPaused == null) {
+            SetCapabilityPaused = new global::brqueue.SetCapabilityPausedResponse();
+          }
+          SetCapabilityPaused.MergeFrom(other.SetCapabilityPaused);
+          break;
+        case MessageOneofCase.Subscribe:
+          if (Subscribe == null) {
+            Subscribe = new global::brqueue.SubscribeResponse();
+          }
+          Subscribe.MergeFrom(other.Subscribe);
+          break;
+        case MessageOneofCase.Reserve:
+          if (Reserve == null) {
+            Reserve = new global::brqueue.ReserveResponse();
+          }
+          Reserve.MergeFrom(other.Reserve);
+          break;
+        case MessageOneofCase.ConfirmReservation:
+          if (ConfirmReservation == null) {
+            ConfirmReservation = new global::brqueue.ConfirmReservationResponse();
+          }
+          ConfirmReservation.MergeFrom(other.ConfirmReservation);
+          break;
+        case MessageOneofCase.Decline:
+          if (Decline == null) {
+            Decline = new global::brqueue.DeclineResponse();
+          }
+          Decline.MergeFrom(other.Decline);
+          break;
+        case MessageOneofCase.EnqueueBatch:
+          if (EnqueueBatch == null) {
+            EnqueueBatch = new global::brqueue.EnqueueBatchResponse();
+          }
+          EnqueueBatch.MergeFrom(other.EnqueueBatch);
+          break;
+        case MessageOneofCase.ListQueued:
+          if (ListQueued == null) {
+            ListQueued = new global::brqueue.ListQueuedResponse();
+          }
+          ListQueued.MergeFrom(other.ListQueued);
+          break;
+        case MessageOneofCase.Peek:
+          if (Peek == null) {
+            Peek = new global::brqueue.PeekResponse();
+          }
+          Peek.MergeFrom(other.Peek);
+          break;
+        case MessageOneofCase.Stats:
+          if (Stats == null) {
+            Stats = new global::brqueue.StatsResponse();
+          }
+          Stats.MergeFrom(other.Stats);
+          break;
+        case MessageOneofCase.Purge:
+          if (Purge == null) {
+            Purge = new global::brqueue.PurgeResponse();
+          }
+          Purge.MergeFrom(other.Purge);
+          break;
+        case MessageOneofCase.EventSubscribe:
+          if (EventSubscribe == null) {
+            EventSubscribe = new global::brqueue.EventSubscribeResponse();
+          }
+          EventSubscribe.MergeFrom(other.EventSubscribe);
+          break;
+        case MessageOneofCase.Event:
+          if (Event == null) {
+            Event = new global::brqueue.QueueEventResponse();
+          }
+          Event.MergeFrom(other.Event);
+          break;
+        case MessageOneofCase.ExportDeadLetters:
+          if (ExportDeadLetters == null) {
+            ExportDeadLetters = new global::brqueue.ExportDeadLettersResponse();
+          }
+          ExportDeadLetters.MergeFrom(other.ExportDeadLetters);
+          break;
+      }
+
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            global::brqueue.EnqueueResponse subBuilder = new global::brqueue.EnqueueResponse();
+            if (messageCase_ == MessageOneofCase.Enqueue) {
+              subBuilder.MergeFrom(Enqueue);
+            }
+            input.ReadMessage(subBuilder);
+            Enqueue = subBuilder;
+            break;
+          }
+          case 18: {
+            global::brqueue.PopResponse subBuilder = new global::brqueue.PopResponse();
+            if (messageCase_ == MessageOneofCase.Pop) {
+              subBuilder.MergeFrom(Pop);
+            }
+            input.ReadMessage(subBuilder);
+            Pop = subBuilder;
+            break;
+          }
+          case 26: {
+            global::brqueue.AcknowledgeResponse subBuilder = new global::brqueue.AcknowledgeResponse();
+            if (messageCase_ == MessageOneofCase.Acknowledge) {
+              subBuilder.MergeFrom(Acknowledge);
+            }
+            input.ReadMessage(subBuilder);
+            Acknowledge = subBuilder;
+            break;
+          }
+          case 34: {
+            global::brqueue.ErrorResponse subBuilder = new global::brqueue.ErrorResponse();
+            if (messageCase_ == MessageOneofCase.Error) {
+              subBuilder.MergeFrom(Error);
+            }
+            input.ReadMessage(subBuilder);
+            Error = subBuilder;
+            break;
+          }
+          case 50: {
+            global::brqueue.AuthenticateResponse subBuilder = new global::brqueue.AuthenticateResponse();
+            if (messageCase_ == MessageOneofCase.Authenticate) {
+              subBuilder.MergeFrom(Authenticate);
+            }
+            input.ReadMessage(subBuilder);
+            Authenticate = subBuilder;
+            break;
+          }
+          case 58: {
+            global::brqueue.HealthResponse subBuilder = new global::brqueue.HealthResponse();
+            if (messageCase_ == MessageOneofCase.Health) {
+              subBuilder.MergeFrom(Health);
+            }
+            input.ReadMessage(subBuilder);
+            Health = subBuilder;
+            break;
+          }
+          case 66: {
+            global::brqueue.AcknowledgeMatchingResponse subBuilder = new global::brqueue.AcknowledgeMatchingResponse();
+            if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+              subBuilder.MergeFrom(AcknowledgeMatching);
+            }
+            input.ReadMessage(subBuilder);
+            AcknowledgeMatching = subBuilder;
+            break;
+          }
+          case 74: {
+            global::brqueue.FailWithRetagResponse subBuilder = new global::brqueue.FailWithRetagResponse();
+            if (messageCase_ == MessageOneofCase.FailWithRetag) {
+              subBuilder.MergeFrom(FailWithRetag);
+            }
+            input.ReadMessage(subBuilder);
+            FailWithRetag = subBuilder;
+            break;
+          }
+          case 80: {
             RefId = input.ReadInt32();
             break;
           }
+          case 90: {
+            global::brqueue.ServerInfoResponse subBuilder = new global::brqueue.ServerInfoResponse();
+            if (messageCase_ == MessageOneofCase.ServerInfo) {
+              subBuilder.MergeFrom(ServerInfo);
+            }
+            input.ReadMessage(subBuilder);
+            ServerInfo = subBuilder;
+            break;
+          }
+          case 98: {
+            global::brqueue.FailResponse subBuilder = new global::brqueue.FailResponse();
+            if (messageCase_ == MessageOneofCase.Fail) {
+              subBuilder.MergeFrom(Fail);
+            }
+            input.ReadMessage(subBuilder);
+            Fail = subBuilder;
+            break;
+          }
+          case 106: {
+            global::brqueue.DiagnosticDumpResponse subBuilder = new global::brqueue.DiagnosticDumpResponse();
+            if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+              subBuilder.MergeFrom(DiagnosticDump);
+            }
+            input.ReadMessage(subBuilder);
+            DiagnosticDump = subBuilder;
+            break;
+          }
+          case 114: {
+            global::brqueue.BeginEnqueueResponse subBuilder = new global::brqueue.BeginEnqueueResponse();
+            if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+              subBuilder.MergeFrom(BeginEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            BeginEnqueue = subBuilder;
+            break;
+          }
+          case 122: {
+            global::brqueue.EnqueueChunkResponse subBuilder = new global::brqueue.EnqueueChunkResponse();
+            if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+              subBuilder.MergeFrom(EnqueueChunk);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueChunk = subBuilder;
+            break;
+          }
+          case 130: {
+            global::brqueue.CommitEnqueueResponse subBuilder = new global::brqueue.CommitEnqueueResponse();
+            if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+              subBuilder.MergeFrom(CommitEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            CommitEnqueue = subBuilder;
+            break;
+          }
+          case 138: {
+            global::brqueue.CancelPopResponse subBuilder = new global::brqueue.CancelPopResponse();
+            if (messageCase_ == MessageOneofCase.CancelPop) {
+              subBuilder.MergeFrom(CancelPop);
+            }
+            input.ReadMessage(subBuilder);
+            CancelPop = subBuilder;
+            break;
+          }
+          case 146: {
+            global::brqueue.CancelResponse subBuilder = new global::brqueue.CancelResponse();
+            if (messageCase_ == MessageOneofCase.Cancel) {
+              subBuilder.MergeFrom(Cancel);
+            }
+            input.ReadMessage(subBuilder);
+            Cancel = subBuilder;
+            break;
+          }
+          case 154: {
+            global::brqueue.FindByTagResponse subBuilder = new global::brqueue.FindByTagResponse();
+            if (messageCase_ == MessageOneofCase.FindByTag) {
+              subBuilder.MergeFrom(FindByTag);
+            }
+            input.ReadMessage(subBuilder);
+            FindByTag = subBuilder;
+            break;
+          }
+          case 162: {
+            global::brqueue.ListProcessingResponse subBuilder = new global::brqueue.ListProcessingResponse();
+            if (messageCase_ == MessageOneofCase.ListProcessing) {
+              subBuilder.MergeFrom(ListProcessing);
+            }
+            input.ReadMessage(subBuilder);
+            ListProcessing = subBuilder;
+            break;
+          }
+          case 170: {
+            global::brqueue.SetCapabilityPausedResponse subBuilder = new global::brqueue.SetCapabilityPausedResponse();
+            if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+              subBuilder.MergeFrom(SetCapabilityPaused);
+            }
+            input.ReadMessage(subBuilder);
+            SetCapabilityPaused = subBuilder;
+            break;
+          }
+          case 178: {
+            global::brqueue.SubscribeResponse subBuilder = new global::brqueue.SubscribeResponse();
+            if (messageCase_ == MessageOneofCase.Subscribe) {
+              subBuilder.MergeFrom(Subscribe);
+            }
+            input.ReadMessage(subBuilder);
+            Subscribe = subBuilder;
+            break;
+          }
+          case 186: {
+            global::brqueue.ReserveResponse subBuilder = new global::brqueue.ReserveResponse();
+            if (messageCase_ == MessageOneofCase.Reserve) {
+              subBuilder.MergeFrom(Reserve);
+            }
+            input.ReadMessage(subBuilder);
+            Reserve = subBuilder;
+            break;
+          }
+          case 194: {
+            global::brqueue.ConfirmReservationResponse subBuilder = new global::brqueue.ConfirmReservationResponse();
+            if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+              subBuilder.MergeFrom(ConfirmReservation);
+            }
+            input.ReadMessage(subBuilder);
+            ConfirmReservation = subBuilder;
+            break;
+          }
+          case 202: {
+            global::brqueue.DeclineResponse subBuilder = new global::brqueue.DeclineResponse();
+            if (messageCase_ == MessageOneofCase.Decline) {
+              subBuilder.MergeFrom(Decline);
+            }
+            input.ReadMessage(subBuilder);
+            Decline = subBuilder;
+            break;
+          }
+          case 210: {
+            global::brqueue.EnqueueBatchResponse subBuilder = new global::brqueue.EnqueueBatchResponse();
+            if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+              subBuilder.MergeFrom(EnqueueBatch);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueBatch = subBuilder;
+            break;
+          }
+          case 218: {
+            global::brqueue.ListQueuedResponse subBuilder = new global::brqueue.ListQueuedResponse();
+            if (messageCase_ == MessageOneofCase.ListQueued) {
+              subBuilder.MergeFrom(ListQueued);
+            }
+            input.ReadMessage(subBuilder);
+            ListQueued = subBuilder;
+            break;
+          }
+          case 226: {
+            global::brqueue.PeekResponse subBuilder = new global::brqueue.PeekResponse();
+            if (messageCase_ == MessageOneofCase.Peek) {
+              subBuilder.MergeFrom(Peek);
+            }
+            input.ReadMessage(subBuilder);
+            Peek = subBuilder;
+            break;
+          }
+          case 234: {
+            global::brqueue.StatsResponse subBuilder = new global::brqueue.StatsResponse();
+            if (messageCase_ == MessageOneofCase.Stats) {
+              subBuilder.MergeFrom(Stats);
+            }
+            input.ReadMessage(subBuilder);
+            Stats = subBuilder;
+            break;
+          }
+          case 242: {
+            global::brqueue.PurgeResponse subBuilder = new global::brqueue.PurgeResponse();
+            if (messageCase_ == MessageOneofCase.Purge) {
+              subBuilder.MergeFrom(Purge);
+            }
+            input.ReadMessage(subBuilder);
+            Purge = subBuilder;
+            break;
+          }
+          case 250: {
+            global::brqueue.EventSubscribeResponse subBuilder = new global::brqueue.EventSubscribeResponse();
+            if (messageCase_ == MessageOneofCase.EventSubscribe) {
+              subBuilder.MergeFrom(EventSubscribe);
+            }
+            input.ReadMessage(subBuilder);
+            EventSubscribe = subBuilder;
+            break;
+          }
+          case 258: {
+            global::brqueue.QueueEventResponse subBuilder = new global::brqueue.QueueEventResponse();
+            if (messageCase_ == MessageOneofCase.Event) {
+              subBuilder.MergeFrom(Event);
+            }
+            input.ReadMessage(subBuilder);
+            Event = subBuilder;
+            break;
+          }
+          case 266: {
+            global::brqueue.ExportDeadLettersResponse subBuilder = new global::brqueue.ExportDeadLettersResponse();
+            if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+              subBuilder.MergeFrom(ExportDeadLetters);
+            }
+            input.ReadMessage(subBuilder);
+            ExportDeadLetters = subBuilder;
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            global::brqueue.EnqueueResponse subBuilder = new global::brqueue.EnqueueResponse();
+            if (messageCase_ == MessageOneofCase.Enqueue) {
+              subBuilder.MergeFrom(Enqueue);
+            }
+            input.ReadMessage(subBuilder);
+            Enqueue = subBuilder;
+            break;
+          }
+          case 18: {
+            global::brqueue.PopResponse subBuilder = new global::brqueue.PopResponse();
+            if (messageCase_ == MessageOneofCase.Pop) {
+              subBuilder.MergeFrom(Pop);
+            }
+            input.ReadMessage(subBuilder);
+            Pop = subBuilder;
+            break;
+          }
+          case 26: {
+            global::brqueue.AcknowledgeResponse subBuilder = new global::brqueue.AcknowledgeResponse();
+            if (messageCase_ == MessageOneofCase.Acknowledge) {
+              subBuilder.MergeFrom(Acknowledge);
+            }
+            input.ReadMessage(subBuilder);
+            Acknowledge = subBuilder;
+            break;
+          }
+          case 34: {
+            global::brqueue.ErrorResponse subBuilder = new global::brqueue.ErrorResponse();
+            if (messageCase_ == MessageOneofCase.Error) {
+              subBuilder.MergeFrom(Error);
+            }
+            input.ReadMessage(subBuilder);
+            Error = subBuilder;
+            break;
+          }
+          case 50: {
+            global::brqueue.AuthenticateResponse subBuilder = new global::brqueue.AuthenticateResponse();
+            if (messageCase_ == MessageOneofCase.Authenticate) {
+              subBuilder.MergeFrom(Authenticate);
+            }
+            input.ReadMessage(subBuilder);
+            Authenticate = subBuilder;
+            break;
+          }
+          case 58: {
+            global::brqueue.HealthResponse subBuilder = new global::brqueue.HealthResponse();
+            if (messageCase_ == MessageOneofCase.Health) {
+              subBuilder.MergeFrom(Health);
+            }
+            input.ReadMessage(subBuilder);
+            Health = subBuilder;
+            break;
+          }
+          case 66: {
+            global::brqueue.AcknowledgeMatchingResponse subBuilder = new global::brqueue.AcknowledgeMatchingResponse();
+            if (messageCase_ == MessageOneofCase.AcknowledgeMatching) {
+              subBuilder.MergeFrom(AcknowledgeMatching);
+            }
+            input.ReadMessage(subBuilder);
+            AcknowledgeMatching = subBuilder;
+            break;
+          }
+          case 74: {
+            global::brqueue.FailWithRetagResponse subBuilder = new global::brqueue.FailWithRetagResponse();
+            if (messageCase_ == MessageOneofCase.FailWithRetag) {
+              subBuilder.MergeFrom(FailWithRetag);
+            }
+            input.ReadMessage(subBuilder);
+            FailWithRetag = subBuilder;
+            break;
+          }
+          case 80: {
+            RefId = input.ReadInt32();
+            break;
+          }
+          case 90: {
+            global::brqueue.ServerInfoResponse subBuilder = new global::brqueue.ServerInfoResponse();
+            if (messageCase_ == MessageOneofCase.ServerInfo) {
+              subBuilder.MergeFrom(ServerInfo);
+            }
+            input.ReadMessage(subBuilder);
+            ServerInfo = subBuilder;
+            break;
+          }
+          case 98: {
+            global::brqueue.FailResponse subBuilder = new global::brqueue.FailResponse();
+            if (messageCase_ == MessageOneofCase.Fail) {
+              subBuilder.MergeFrom(Fail);
+            }
+            input.ReadMessage(subBuilder);
+            Fail = subBuilder;
+            break;
+          }
+          case 106: {
+            global::brqueue.DiagnosticDumpResponse subBuilder = new global::brqueue.DiagnosticDumpResponse();
+            if (messageCase_ == MessageOneofCase.DiagnosticDump) {
+              subBuilder.MergeFrom(DiagnosticDump);
+            }
+            input.ReadMessage(subBuilder);
+            DiagnosticDump = subBuilder;
+            break;
+          }
+          case 114: {
+            global::brqueue.BeginEnqueueResponse subBuilder = new global::brqueue.BeginEnqueueResponse();
+            if (messageCase_ == MessageOneofCase.BeginEnqueue) {
+              subBuilder.MergeFrom(BeginEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            BeginEnqueue = subBuilder;
+            break;
+          }
+          case 122: {
+            global::brqueue.EnqueueChunkResponse subBuilder = new global::brqueue.EnqueueChunkResponse();
+            if (messageCase_ == MessageOneofCase.EnqueueChunk) {
+              subBuilder.MergeFrom(EnqueueChunk);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueChunk = subBuilder;
+            break;
+          }
+          case 130: {
+            global::brqueue.CommitEnqueueResponse subBuilder = new global::brqueue.CommitEnqueueResponse();
+            if (messageCase_ == MessageOneofCase.CommitEnqueue) {
+              subBuilder.MergeFrom(CommitEnqueue);
+            }
+            input.ReadMessage(subBuilder);
+            CommitEnqueue = subBuilder;
+            break;
+          }
+          case 138: {
+            global::brqueue.CancelPopResponse subBuilder = new global::brqueue.CancelPopResponse();
+            if (messageCase_ == MessageOneofCase.CancelPop) {
+              subBuilder.MergeFrom(CancelPop);
+            }
+            input.ReadMessage(subBuilder);
+            CancelPop = subBuilder;
+            break;
+          }
+          case 146: {
+            global::brqueue.CancelResponse subBuilder = new global::brqueue.CancelResponse();
+            if (messageCase_ == MessageOneofCase.Cancel) {
+              subBuilder.MergeFrom(Cancel);
+            }
+            input.ReadMessage(subBuilder);
+            Cancel = subBuilder;
+            break;
+          }
+          case 154: {
+            global::brqueue.FindByTagResponse subBuilder = new global::brqueue.FindByTagResponse();
+            if (messageCase_ == MessageOneofCase.FindByTag) {
+              subBuilder.MergeFrom(FindByTag);
+            }
+            input.ReadMessage(subBuilder);
+            FindByTag = subBuilder;
+            break;
+          }
+          case 162: {
+            global::brqueue.ListProcessingResponse subBuilder = new global::brqueue.ListProcessingResponse();
+            if (messageCase_ == MessageOneofCase.ListProcessing) {
+              subBuilder.MergeFrom(ListProcessing);
+            }
+            input.ReadMessage(subBuilder);
+            ListProcessing = subBuilder;
+            break;
+          }
+          case 170: {
+            global::brqueue.SetCapabilityPausedResponse subBuilder = new global::brqueue.SetCapabilityPausedResponse();
+            if (messageCase_ == MessageOneofCase.SetCapabilityPaused) {
+              subBuilder.MergeFrom(SetCapabilityPaused);
+            }
+            input.ReadMessage(subBuilder);
+            SetCapabilityPaused = subBuilder;
+            break;
+          }
+          case 178: {
+            global::brqueue.SubscribeResponse subBuilder = new global::brqueue.SubscribeResponse();
+            if (messageCase_ == MessageOneofCase.Subscribe) {
+              subBuilder.MergeFrom(Subscribe);
+            }
+            input.ReadMessage(subBuilder);
+            Subscribe = subBuilder;
+            break;
+          }
+          case 186: {
+            global::brqueue.ReserveResponse subBuilder = new global::brqueue.ReserveResponse();
+            if (messageCase_ == MessageOneofCase.Reserve) {
+              subBuilder.MergeFrom(Reserve);
+            }
+            input.ReadMessage(subBuilder);
+            Reserve = subBuilder;
+            break;
+          }
+          case 194: {
+            global::brqueue.ConfirmReservationResponse subBuilder = new global::brqueue.ConfirmReservationResponse();
+            if (messageCase_ == MessageOneofCase.ConfirmReservation) {
+              subBuilder.MergeFrom(ConfirmReservation);
+            }
+            input.ReadMessage(subBuilder);
+            ConfirmReservation = subBuilder;
+            break;
+          }
+          case 202: {
+            global::brqueue.DeclineResponse subBuilder = new global::brqueue.DeclineResponse();
+            if (messageCase_ == MessageOneofCase.Decline) {
+              subBuilder.MergeFrom(Decline);
+            }
+            input.ReadMessage(subBuilder);
+            Decline = subBuilder;
+            break;
+          }
+          case 210: {
+            global::brqueue.EnqueueBatchResponse subBuilder = new global::brqueue.EnqueueBatchResponse();
+            if (messageCase_ == MessageOneofCase.EnqueueBatch) {
+              subBuilder.MergeFrom(EnqueueBatch);
+            }
+            input.ReadMessage(subBuilder);
+            EnqueueBatch = subBuilder;
+            break;
+          }
+          case 218: {
+            global::brqueue.ListQueuedResponse subBuilder = new global::brqueue.ListQueuedResponse();
+            if (messageCase_ == MessageOneofCase.ListQueued) {
+              subBuilder.MergeFrom(ListQueued);
+            }
+            input.ReadMessage(subBuilder);
+            ListQueued = subBuilder;
+            break;
+          }
+          case 226: {
+            global::brqueue.PeekResponse subBuilder = new global::brqueue.PeekResponse();
+            if (messageCase_ == MessageOneofCase.Peek) {
+              subBuilder.MergeFrom(Peek);
+            }
+            input.ReadMessage(subBuilder);
+            Peek = subBuilder;
+            break;
+          }
+          case 234: {
+            global::brqueue.StatsResponse subBuilder = new global::brqueue.StatsResponse();
+            if (messageCase_ == MessageOneofCase.Stats) {
+              subBuilder.MergeFrom(Stats);
+            }
+            input.ReadMessage(subBuilder);
+            Stats = subBuilder;
+            break;
+          }
+          case 242: {
+            global::brqueue.PurgeResponse subBuilder = new global::brqueue.PurgeResponse();
+            if (messageCase_ == MessageOneofCase.Purge) {
+              subBuilder.MergeFrom(Purge);
+            }
+            input.ReadMessage(subBuilder);
+            Purge = subBuilder;
+            break;
+          }
+          case 250: {
+            global::brqueue.EventSubscribeResponse subBuilder = new global::brqueue.EventSubscribeResponse();
+            if (messageCase_ == MessageOneofCase.EventSubscribe) {
+              subBuilder.MergeFrom(EventSubscribe);
+            }
+            input.ReadMessage(subBuilder);
+            EventSubscribe = subBuilder;
+            break;
+          }
+          case 258: {
+            global::brqueue.QueueEventResponse subBuilder = new global::brqueue.QueueEventResponse();
+            if (messageCase_ == MessageOneofCase.Event) {
+              subBuilder.MergeFrom(Event);
+            }
+            input.ReadMessage(subBuilder);
+            Event = subBuilder;
+            break;
+          }
+          case 266: {
+            global::brqueue.ExportDeadLettersResponse subBuilder = new global::brqueue.ExportDeadLettersResponse();
+            if (messageCase_ == MessageOneofCase.ExportDeadLetters) {
+              subBuilder.MergeFrom(ExportDeadLetters);
+            }
+            input.ReadMessage(subBuilder);
+            ExportDeadLetters = subBuilder;
+            break;
+          }
         }
       }
     }
+    #endif
 
   }
 
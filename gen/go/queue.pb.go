@@ -38,13 +38,134 @@ func (x Priority) String() string {
 	return proto.EnumName(Priority_name, int32(x))
 }
 func (Priority) EnumDescriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{0}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{0}
+}
+
+type EnqueueStatus int32
+
+const (
+	// A new task was enqueued
+	EnqueueStatus_CREATED EnqueueStatus = 0
+	// A task with the same idempotency key was already enqueued, and nothing was changed
+	EnqueueStatus_ALREADY_EXISTS EnqueueStatus = 1
+)
+
+var EnqueueStatus_name = map[int32]string{
+	0: "CREATED",
+	1: "ALREADY_EXISTS",
+}
+var EnqueueStatus_value = map[string]int32{
+	"CREATED":        0,
+	"ALREADY_EXISTS": 1,
+}
+
+func (x EnqueueStatus) String() string {
+	return proto.EnumName(EnqueueStatus_name, int32(x))
+}
+func (EnqueueStatus) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{1}
+}
+
+type TagMatchMode int32
+
+const (
+	// The message requires all of the tags
+	TagMatchMode_ALL TagMatchMode = 0
+	// The message requires at least one of the tags
+	TagMatchMode_ANY TagMatchMode = 1
+)
+
+var TagMatchMode_name = map[int32]string{
+	0: "ALL",
+	1: "ANY",
+}
+var TagMatchMode_value = map[string]int32{
+	"ALL": 0,
+	"ANY": 1,
+}
+
+func (x TagMatchMode) String() string {
+	return proto.EnumName(TagMatchMode_name, int32(x))
+}
+func (TagMatchMode) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{2}
+}
+
+type EventKind int32
+
+const (
+	EventKind_ENQUEUED      EventKind = 0
+	EventKind_POPPED        EventKind = 1
+	EventKind_ACKNOWLEDGED  EventKind = 2
+	EventKind_FAILED        EventKind = 3
+	EventKind_DEAD_LETTERED EventKind = 4
+)
+
+var EventKind_name = map[int32]string{
+	0: "ENQUEUED",
+	1: "POPPED",
+	2: "ACKNOWLEDGED",
+	3: "FAILED",
+	4: "DEAD_LETTERED",
+}
+var EventKind_value = map[string]int32{
+	"ENQUEUED":      0,
+	"POPPED":        1,
+	"ACKNOWLEDGED":  2,
+	"FAILED":        3,
+	"DEAD_LETTERED": 4,
+}
+
+func (x EventKind) String() string {
+	return proto.EnumName(EventKind_name, int32(x))
+}
+func (EventKind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{3}
+}
+
+// Errors clients might want to handle specifically
+type ErrorCode int32
+
+const (
+	ErrorCode_UNKNOWN ErrorCode = 0
+	// The server has run out of disk space, so new messages can't be saved.
+	// Try again later.
+	ErrorCode_STORAGE_FULL ErrorCode = 1
+	// The request was rejected, for example because the payload is invalid.
+	// Sending it again won't help.
+	ErrorCode_BAD_REQUEST ErrorCode = 2
+	// The request refers to something that doesn't exist, for example a queue nothing has been enqueued to
+	ErrorCode_NOT_FOUND ErrorCode = 3
+)
+
+var ErrorCode_name = map[int32]string{
+	0: "UNKNOWN",
+	1: "STORAGE_FULL",
+	2: "BAD_REQUEST",
+	3: "NOT_FOUND",
+}
+var ErrorCode_value = map[string]int32{
+	"UNKNOWN":      0,
+	"STORAGE_FULL": 1,
+	"BAD_REQUEST":  2,
+	"NOT_FOUND":    3,
+}
+
+func (x ErrorCode) String() string {
+	return proto.EnumName(ErrorCode_name, int32(x))
+}
+func (ErrorCode) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{4}
 }
 
 // Authenticates the single connection
 type AuthenticateRequest struct {
-	Username             string   `protobuf:"bytes,1,opt,name=username,proto3" json:"username,omitempty"`
-	Password             string   `protobuf:"bytes,2,opt,name=password,proto3" json:"password,omitempty"`
+	Username string `protobuf:"bytes,1,opt,name=username,proto3" json:"username,omitempty"`
+	Password string `protobuf:"bytes,2,opt,name=password,proto3" json:"password,omitempty"`
+	// The token received from a previous connection.
+	// If set the worker will get back the tasks it was processing,
+	// if it reconnects within the grace period
+	SessionToken         string   `protobuf:"bytes,3,opt,name=sessionToken,proto3" json:"sessionToken,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -54,7 +175,7 @@ func (m *AuthenticateRequest) Reset()         { *m = AuthenticateRequest{} }
 func (m *AuthenticateRequest) String() string { return proto.CompactTextString(m) }
 func (*AuthenticateRequest) ProtoMessage()    {}
 func (*AuthenticateRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{0}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{0}
 }
 func (m *AuthenticateRequest) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_AuthenticateRequest.Unmarshal(m, b)
@@ -88,10 +209,19 @@ func (m *AuthenticateRequest) GetPassword() string {
 	return ""
 }
 
+func (m *AuthenticateRequest) GetSessionToken() string {
+	if m != nil {
+		return m.SessionToken
+	}
+	return ""
+}
+
 type AuthenticateResponse struct {
 	// True if the authentication was successful,
 	// false otherwise
-	Success              bool     `protobuf:"varint,1,opt,name=success,proto3" json:"success,omitempty"`
+	Success bool `protobuf:"varint,1,opt,name=success,proto3" json:"success,omitempty"`
+	// Identifies the worker, should be sent again when reconnecting
+	SessionToken         string   `protobuf:"bytes,2,opt,name=sessionToken,proto3" json:"sessionToken,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -101,7 +231,7 @@ func (m *AuthenticateResponse) Reset()         { *m = AuthenticateResponse{} }
 func (m *AuthenticateResponse) String() string { return proto.CompactTextString(m) }
 func (*AuthenticateResponse) ProtoMessage()    {}
 func (*AuthenticateResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{1}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{1}
 }
 func (m *AuthenticateResponse) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_AuthenticateResponse.Unmarshal(m, b)
@@ -128,6 +258,13 @@ func (m *AuthenticateResponse) GetSuccess() bool {
 	return false
 }
 
+func (m *AuthenticateResponse) GetSessionToken() string {
+	if m != nil {
+		return m.SessionToken
+	}
+	return ""
+}
+
 // Puts a new message in the queue
 type EnqueueRequest struct {
 	// The actual task to enqueue
@@ -136,6 +273,25 @@ type EnqueueRequest struct {
 	Priority Priority `protobuf:"varint,2,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
 	// What capabilities are required to handle the task
 	RequiredCapabilities []string `protobuf:"bytes,3,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// How long to wait before the task is handed out to workers
+	DelayMilliseconds uint64 `protobuf:"varint,4,opt,name=delayMilliseconds,proto3" json:"delayMilliseconds,omitempty"`
+	// When the task can be handed out to workers, as an RFC3339 timestamp in UTC,
+	// for example "2018-12-24T18:00:00Z". Can't be combined with delayMilliseconds.
+	AvailableAt string `protobuf:"bytes,5,opt,name=availableAt,proto3" json:"availableAt,omitempty"`
+	// If set the task is only enqueued if no task with the same key is queued or being processed.
+	// Lets clients retry enqueues safely, for example after losing the connection.
+	IdempotencyKey string `protobuf:"bytes,6,opt,name=idempotencyKey,proto3" json:"idempotencyKey,omitempty"`
+	// The queue to put the task in. Uses the default queue if empty.
+	// The queue is created if it doesn't exist yet.
+	QueueName string `protobuf:"bytes,7,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	// If set the task is dropped instead of handed out, if it hasn't been popped
+	// within this many seconds of being enqueued. The delay counts towards this.
+	TtlSeconds uint64 `protobuf:"varint,8,opt,name=ttlSeconds,proto3" json:"ttlSeconds,omitempty"`
+	// Used instead of priority if set. LOW is level 0 and HIGH is level 1,
+	// tasks with a higher level are popped first. Can be at most 255.
+	// Tasks above HIGH are reported as HIGH in the priority of responses,
+	// responses with a priorityLevel has the actual level.
+	PriorityLevel        uint32   `protobuf:"varint,9,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
@@ -145,7 +301,7 @@ func (m *EnqueueRequest) Reset()         { *m = EnqueueRequest{} }
 func (m *EnqueueRequest) String() string { return proto.CompactTextString(m) }
 func (*EnqueueRequest) ProtoMessage()    {}
 func (*EnqueueRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{2}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{2}
 }
 func (m *EnqueueRequest) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_EnqueueRequest.Unmarshal(m, b)
@@ -186,19 +342,62 @@ func (m *EnqueueRequest) GetRequiredCapabilities() []string {
 	return nil
 }
 
+func (m *EnqueueRequest) GetDelayMilliseconds() uint64 {
+	if m != nil {
+		return m.DelayMilliseconds
+	}
+	return 0
+}
+
+func (m *EnqueueRequest) GetAvailableAt() string {
+	if m != nil {
+		return m.AvailableAt
+	}
+	return ""
+}
+
+func (m *EnqueueRequest) GetIdempotencyKey() string {
+	if m != nil {
+		return m.IdempotencyKey
+	}
+	return ""
+}
+
+func (m *EnqueueRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+func (m *EnqueueRequest) GetTtlSeconds() uint64 {
+	if m != nil {
+		return m.TtlSeconds
+	}
+	return 0
+}
+
+func (m *EnqueueRequest) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
 type EnqueueResponse struct {
-	// The id of the created task
-	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
-	XXX_NoUnkeyedLiteral struct{} `json:"-"`
-	XXX_unrecognized     []byte   `json:"-"`
-	XXX_sizecache        int32    `json:"-"`
+	// The id of the created task, or the existing task with the same idempotency key
+	Id                   string        `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Status               EnqueueStatus `protobuf:"varint,2,opt,name=status,proto3,enum=EnqueueStatus" json:"status,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}      `json:"-"`
+	XXX_unrecognized     []byte        `json:"-"`
+	XXX_sizecache        int32         `json:"-"`
 }
 
 func (m *EnqueueResponse) Reset()         { *m = EnqueueResponse{} }
 func (m *EnqueueResponse) String() string { return proto.CompactTextString(m) }
 func (*EnqueueResponse) ProtoMessage()    {}
 func (*EnqueueResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{3}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{3}
 }
 func (m *EnqueueResponse) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_EnqueueResponse.Unmarshal(m, b)
@@ -225,231 +424,3275 @@ func (m *EnqueueResponse) GetId() string {
 	return ""
 }
 
-// Removes a single message from the queue
-type PopRequest struct {
-	// What capabilities the client has available
-	AvailableCapabilities []string `protobuf:"bytes,1,rep,name=availableCapabilities,proto3" json:"availableCapabilities,omitempty"`
-	// If the server should wait for a message to come in, before returning
-	// with a response
-	WaitForMessage       bool     `protobuf:"varint,2,opt,name=waitForMessage,proto3" json:"waitForMessage,omitempty"`
+func (m *EnqueueResponse) GetStatus() EnqueueStatus {
+	if m != nil {
+		return m.Status
+	}
+	return EnqueueStatus_CREATED
+}
+
+// Enqueues several tasks at once
+type EnqueueBatchRequest struct {
+	Items []*EnqueueRequest `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
+	// If set either every task is enqueued, or none of them are, in which case an error is returned.
+	// Otherwise every task is enqueued on its own, and the result of each is returned.
+	// Idempotency keys can't be used in atomic batches, and every task has to go in the same queue.
+	Atomic               bool     `protobuf:"varint,2,opt,name=atomic,proto3" json:"atomic,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
 }
 
-func (m *PopRequest) Reset()         { *m = PopRequest{} }
-func (m *PopRequest) String() string { return proto.CompactTextString(m) }
-func (*PopRequest) ProtoMessage()    {}
-func (*PopRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{4}
+func (m *EnqueueBatchRequest) Reset()         { *m = EnqueueBatchRequest{} }
+func (m *EnqueueBatchRequest) String() string { return proto.CompactTextString(m) }
+func (*EnqueueBatchRequest) ProtoMessage()    {}
+func (*EnqueueBatchRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{4}
 }
-func (m *PopRequest) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_PopRequest.Unmarshal(m, b)
+func (m *EnqueueBatchRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EnqueueBatchRequest.Unmarshal(m, b)
 }
-func (m *PopRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_PopRequest.Marshal(b, m, deterministic)
+func (m *EnqueueBatchRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EnqueueBatchRequest.Marshal(b, m, deterministic)
 }
-func (dst *PopRequest) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_PopRequest.Merge(dst, src)
+func (dst *EnqueueBatchRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EnqueueBatchRequest.Merge(dst, src)
 }
-func (m *PopRequest) XXX_Size() int {
-	return xxx_messageInfo_PopRequest.Size(m)
+func (m *EnqueueBatchRequest) XXX_Size() int {
+	return xxx_messageInfo_EnqueueBatchRequest.Size(m)
 }
-func (m *PopRequest) XXX_DiscardUnknown() {
-	xxx_messageInfo_PopRequest.DiscardUnknown(m)
+func (m *EnqueueBatchRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_EnqueueBatchRequest.DiscardUnknown(m)
 }
 
-var xxx_messageInfo_PopRequest proto.InternalMessageInfo
+var xxx_messageInfo_EnqueueBatchRequest proto.InternalMessageInfo
 
-func (m *PopRequest) GetAvailableCapabilities() []string {
+func (m *EnqueueBatchRequest) GetItems() []*EnqueueRequest {
 	if m != nil {
-		return m.AvailableCapabilities
+		return m.Items
 	}
 	return nil
 }
 
-func (m *PopRequest) GetWaitForMessage() bool {
+func (m *EnqueueBatchRequest) GetAtomic() bool {
 	if m != nil {
-		return m.WaitForMessage
+		return m.Atomic
 	}
 	return false
 }
 
-type PopResponse struct {
-	// True if there was a message available
-	HadResult bool `protobuf:"varint,3,opt,name=hadResult,proto3" json:"hadResult,omitempty"`
-	// The actual message to process
-	Message []byte `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"`
-	// The id of the message
-	// Should be returned with the acknowledge request
-	Id                   string   `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`
+type EnqueueBatchResult struct {
+	// The id of the task, if it was enqueued
+	Id     string        `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Status EnqueueStatus `protobuf:"varint,2,opt,name=status,proto3,enum=EnqueueStatus" json:"status,omitempty"`
+	// Why the task wasn't enqueued, empty if it was
+	Error                string   `protobuf:"bytes,3,opt,name=error,proto3" json:"error,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
 }
 
-func (m *PopResponse) Reset()         { *m = PopResponse{} }
-func (m *PopResponse) String() string { return proto.CompactTextString(m) }
-func (*PopResponse) ProtoMessage()    {}
-func (*PopResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{5}
+func (m *EnqueueBatchResult) Reset()         { *m = EnqueueBatchResult{} }
+func (m *EnqueueBatchResult) String() string { return proto.CompactTextString(m) }
+func (*EnqueueBatchResult) ProtoMessage()    {}
+func (*EnqueueBatchResult) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{5}
 }
-func (m *PopResponse) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_PopResponse.Unmarshal(m, b)
+func (m *EnqueueBatchResult) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EnqueueBatchResult.Unmarshal(m, b)
 }
-func (m *PopResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_PopResponse.Marshal(b, m, deterministic)
+func (m *EnqueueBatchResult) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EnqueueBatchResult.Marshal(b, m, deterministic)
 }
-func (dst *PopResponse) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_PopResponse.Merge(dst, src)
+func (dst *EnqueueBatchResult) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EnqueueBatchResult.Merge(dst, src)
 }
-func (m *PopResponse) XXX_Size() int {
-	return xxx_messageInfo_PopResponse.Size(m)
+func (m *EnqueueBatchResult) XXX_Size() int {
+	return xxx_messageInfo_EnqueueBatchResult.Size(m)
 }
-func (m *PopResponse) XXX_DiscardUnknown() {
-	xxx_messageInfo_PopResponse.DiscardUnknown(m)
+func (m *EnqueueBatchResult) XXX_DiscardUnknown() {
+	xxx_messageInfo_EnqueueBatchResult.DiscardUnknown(m)
 }
 
-var xxx_messageInfo_PopResponse proto.InternalMessageInfo
+var xxx_messageInfo_EnqueueBatchResult proto.InternalMessageInfo
 
-func (m *PopResponse) GetHadResult() bool {
+func (m *EnqueueBatchResult) GetId() string {
 	if m != nil {
-		return m.HadResult
+		return m.Id
 	}
-	return false
+	return ""
 }
 
-func (m *PopResponse) GetMessage() []byte {
+func (m *EnqueueBatchResult) GetStatus() EnqueueStatus {
 	if m != nil {
-		return m.Message
+		return m.Status
 	}
-	return nil
+	return EnqueueStatus_CREATED
 }
 
-func (m *PopResponse) GetId() string {
+func (m *EnqueueBatchResult) GetError() string {
 	if m != nil {
-		return m.Id
+		return m.Error
 	}
 	return ""
 }
 
-type AcknowledgeRequest struct {
-	// The id of the message to acknowledge
-	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+type EnqueueBatchResponse struct {
+	// The result of every task, in the order of the request
+	Results              []*EnqueueBatchResult `protobuf:"bytes,1,rep,name=results,proto3" json:"results,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}              `json:"-"`
+	XXX_unrecognized     []byte                `json:"-"`
+	XXX_sizecache        int32                 `json:"-"`
+}
+
+func (m *EnqueueBatchResponse) Reset()         { *m = EnqueueBatchResponse{} }
+func (m *EnqueueBatchResponse) String() string { return proto.CompactTextString(m) }
+func (*EnqueueBatchResponse) ProtoMessage()    {}
+func (*EnqueueBatchResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{6}
+}
+func (m *EnqueueBatchResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EnqueueBatchResponse.Unmarshal(m, b)
+}
+func (m *EnqueueBatchResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EnqueueBatchResponse.Marshal(b, m, deterministic)
+}
+func (dst *EnqueueBatchResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EnqueueBatchResponse.Merge(dst, src)
+}
+func (m *EnqueueBatchResponse) XXX_Size() int {
+	return xxx_messageInfo_EnqueueBatchResponse.Size(m)
+}
+func (m *EnqueueBatchResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_EnqueueBatchResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_EnqueueBatchResponse proto.InternalMessageInfo
+
+func (m *EnqueueBatchResponse) GetResults() []*EnqueueBatchResult {
+	if m != nil {
+		return m.Results
+	}
+	return nil
+}
+
+// Starts enqueuing a message that is too big to send in one request.
+// The message is sent in chunks with EnqueueChunkRequest,
+// and put in the queue with CommitEnqueueRequest.
+type BeginEnqueueRequest struct {
+	// The priority of the task
+	Priority Priority `protobuf:"varint,1,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
+	// What capabilities are required to handle the task
+	RequiredCapabilities []string `protobuf:"bytes,2,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// The same as in EnqueueRequest. The delay and the time to live
+	// are counted from when the transfer begins.
+	DelayMilliseconds    uint64   `protobuf:"varint,3,opt,name=delayMilliseconds,proto3" json:"delayMilliseconds,omitempty"`
+	AvailableAt          string   `protobuf:"bytes,4,opt,name=availableAt,proto3" json:"availableAt,omitempty"`
+	TtlSeconds           uint64   `protobuf:"varint,5,opt,name=ttlSeconds,proto3" json:"ttlSeconds,omitempty"`
+	PriorityLevel        uint32   `protobuf:"varint,6,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
 }
 
-func (m *AcknowledgeRequest) Reset()         { *m = AcknowledgeRequest{} }
-func (m *AcknowledgeRequest) String() string { return proto.CompactTextString(m) }
-func (*AcknowledgeRequest) ProtoMessage()    {}
-func (*AcknowledgeRequest) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{6}
+func (m *BeginEnqueueRequest) Reset()         { *m = BeginEnqueueRequest{} }
+func (m *BeginEnqueueRequest) String() string { return proto.CompactTextString(m) }
+func (*BeginEnqueueRequest) ProtoMessage()    {}
+func (*BeginEnqueueRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{7}
 }
-func (m *AcknowledgeRequest) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_AcknowledgeRequest.Unmarshal(m, b)
+func (m *BeginEnqueueRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_BeginEnqueueRequest.Unmarshal(m, b)
 }
-func (m *AcknowledgeRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_AcknowledgeRequest.Marshal(b, m, deterministic)
+func (m *BeginEnqueueRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_BeginEnqueueRequest.Marshal(b, m, deterministic)
 }
-func (dst *AcknowledgeRequest) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_AcknowledgeRequest.Merge(dst, src)
+func (dst *BeginEnqueueRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_BeginEnqueueRequest.Merge(dst, src)
 }
-func (m *AcknowledgeRequest) XXX_Size() int {
-	return xxx_messageInfo_AcknowledgeRequest.Size(m)
+func (m *BeginEnqueueRequest) XXX_Size() int {
+	return xxx_messageInfo_BeginEnqueueRequest.Size(m)
 }
-func (m *AcknowledgeRequest) XXX_DiscardUnknown() {
-	xxx_messageInfo_AcknowledgeRequest.DiscardUnknown(m)
+func (m *BeginEnqueueRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_BeginEnqueueRequest.DiscardUnknown(m)
 }
 
-var xxx_messageInfo_AcknowledgeRequest proto.InternalMessageInfo
+var xxx_messageInfo_BeginEnqueueRequest proto.InternalMessageInfo
 
-func (m *AcknowledgeRequest) GetId() string {
+func (m *BeginEnqueueRequest) GetPriority() Priority {
 	if m != nil {
-		return m.Id
+		return m.Priority
+	}
+	return Priority_LOW
+}
+
+func (m *BeginEnqueueRequest) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+func (m *BeginEnqueueRequest) GetDelayMilliseconds() uint64 {
+	if m != nil {
+		return m.DelayMilliseconds
+	}
+	return 0
+}
+
+func (m *BeginEnqueueRequest) GetAvailableAt() string {
+	if m != nil {
+		return m.AvailableAt
 	}
 	return ""
 }
 
-// Returned when a message has been acknowledged
-type AcknowledgeResponse struct {
+func (m *BeginEnqueueRequest) GetTtlSeconds() uint64 {
+	if m != nil {
+		return m.TtlSeconds
+	}
+	return 0
+}
+
+func (m *BeginEnqueueRequest) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+type BeginEnqueueResponse struct {
+	// Identifies the transfer in the following requests
+	TransferId           string   `protobuf:"bytes,1,opt,name=transferId,proto3" json:"transferId,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
 }
 
-func (m *AcknowledgeResponse) Reset()         { *m = AcknowledgeResponse{} }
-func (m *AcknowledgeResponse) String() string { return proto.CompactTextString(m) }
-func (*AcknowledgeResponse) ProtoMessage()    {}
-func (*AcknowledgeResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{7}
+func (m *BeginEnqueueResponse) Reset()         { *m = BeginEnqueueResponse{} }
+func (m *BeginEnqueueResponse) String() string { return proto.CompactTextString(m) }
+func (*BeginEnqueueResponse) ProtoMessage()    {}
+func (*BeginEnqueueResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{8}
 }
-func (m *AcknowledgeResponse) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_AcknowledgeResponse.Unmarshal(m, b)
+func (m *BeginEnqueueResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_BeginEnqueueResponse.Unmarshal(m, b)
 }
-func (m *AcknowledgeResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_AcknowledgeResponse.Marshal(b, m, deterministic)
+func (m *BeginEnqueueResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_BeginEnqueueResponse.Marshal(b, m, deterministic)
 }
-func (dst *AcknowledgeResponse) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_AcknowledgeResponse.Merge(dst, src)
+func (dst *BeginEnqueueResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_BeginEnqueueResponse.Merge(dst, src)
 }
-func (m *AcknowledgeResponse) XXX_Size() int {
-	return xxx_messageInfo_AcknowledgeResponse.Size(m)
+func (m *BeginEnqueueResponse) XXX_Size() int {
+	return xxx_messageInfo_BeginEnqueueResponse.Size(m)
 }
-func (m *AcknowledgeResponse) XXX_DiscardUnknown() {
-	xxx_messageInfo_AcknowledgeResponse.DiscardUnknown(m)
+func (m *BeginEnqueueResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_BeginEnqueueResponse.DiscardUnknown(m)
 }
 
-var xxx_messageInfo_AcknowledgeResponse proto.InternalMessageInfo
+var xxx_messageInfo_BeginEnqueueResponse proto.InternalMessageInfo
 
-type ErrorResponse struct {
-	// What went wrong
-	Message              string   `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"`
+func (m *BeginEnqueueResponse) GetTransferId() string {
+	if m != nil {
+		return m.TransferId
+	}
+	return ""
+}
+
+// Sends the next part of a message started with BeginEnqueueRequest
+type EnqueueChunkRequest struct {
+	TransferId string `protobuf:"bytes,1,opt,name=transferId,proto3" json:"transferId,omitempty"`
+	// Where in the message the chunk starts.
+	// Has to be the number of bytes received so far, which makes it
+	// possible to resume the transfer after a lost connection
+	Offset               uint64   `protobuf:"varint,2,opt,name=offset,proto3" json:"offset,omitempty"`
+	Data                 []byte   `protobuf:"bytes,3,opt,name=data,proto3" json:"data,omitempty"`
 	XXX_NoUnkeyedLiteral struct{} `json:"-"`
 	XXX_unrecognized     []byte   `json:"-"`
 	XXX_sizecache        int32    `json:"-"`
 }
 
-func (m *ErrorResponse) Reset()         { *m = ErrorResponse{} }
-func (m *ErrorResponse) String() string { return proto.CompactTextString(m) }
-func (*ErrorResponse) ProtoMessage()    {}
-func (*ErrorResponse) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{8}
+func (m *EnqueueChunkRequest) Reset()         { *m = EnqueueChunkRequest{} }
+func (m *EnqueueChunkRequest) String() string { return proto.CompactTextString(m) }
+func (*EnqueueChunkRequest) ProtoMessage()    {}
+func (*EnqueueChunkRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{9}
 }
-func (m *ErrorResponse) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_ErrorResponse.Unmarshal(m, b)
+func (m *EnqueueChunkRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EnqueueChunkRequest.Unmarshal(m, b)
 }
-func (m *ErrorResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_ErrorResponse.Marshal(b, m, deterministic)
+func (m *EnqueueChunkRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EnqueueChunkRequest.Marshal(b, m, deterministic)
 }
-func (dst *ErrorResponse) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_ErrorResponse.Merge(dst, src)
+func (dst *EnqueueChunkRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EnqueueChunkRequest.Merge(dst, src)
 }
-func (m *ErrorResponse) XXX_Size() int {
-	return xxx_messageInfo_ErrorResponse.Size(m)
+func (m *EnqueueChunkRequest) XXX_Size() int {
+	return xxx_messageInfo_EnqueueChunkRequest.Size(m)
 }
-func (m *ErrorResponse) XXX_DiscardUnknown() {
-	xxx_messageInfo_ErrorResponse.DiscardUnknown(m)
+func (m *EnqueueChunkRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_EnqueueChunkRequest.DiscardUnknown(m)
 }
 
-var xxx_messageInfo_ErrorResponse proto.InternalMessageInfo
+var xxx_messageInfo_EnqueueChunkRequest proto.InternalMessageInfo
 
-func (m *ErrorResponse) GetMessage() string {
+func (m *EnqueueChunkRequest) GetTransferId() string {
 	if m != nil {
-		return m.Message
+		return m.TransferId
 	}
 	return ""
 }
 
-// Wraps the requests for easier parsing on the socket layer
-type RequestWrapper struct {
-	RefId int32 `protobuf:"varint,10,opt,name=refId,proto3" json:"refId,omitempty"`
-	// Types that are valid to be assigned to Message:
-	//	*RequestWrapper_Enqueue
-	//	*RequestWrapper_Pop
-	//	*RequestWrapper_Acknowledge
+func (m *EnqueueChunkRequest) GetOffset() uint64 {
+	if m != nil {
+		return m.Offset
+	}
+	return 0
+}
+
+func (m *EnqueueChunkRequest) GetData() []byte {
+	if m != nil {
+		return m.Data
+	}
+	return nil
+}
+
+type EnqueueChunkResponse struct {
+	// The number of bytes received so far
+	Received             uint64   `protobuf:"varint,1,opt,name=received,proto3" json:"received,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *EnqueueChunkResponse) Reset()         { *m = EnqueueChunkResponse{} }
+func (m *EnqueueChunkResponse) String() string { return proto.CompactTextString(m) }
+func (*EnqueueChunkResponse) ProtoMessage()    {}
+func (*EnqueueChunkResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{10}
+}
+func (m *EnqueueChunkResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EnqueueChunkResponse.Unmarshal(m, b)
+}
+func (m *EnqueueChunkResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EnqueueChunkResponse.Marshal(b, m, deterministic)
+}
+func (dst *EnqueueChunkResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EnqueueChunkResponse.Merge(dst, src)
+}
+func (m *EnqueueChunkResponse) XXX_Size() int {
+	return xxx_messageInfo_EnqueueChunkResponse.Size(m)
+}
+func (m *EnqueueChunkResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_EnqueueChunkResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_EnqueueChunkResponse proto.InternalMessageInfo
+
+func (m *EnqueueChunkResponse) GetReceived() uint64 {
+	if m != nil {
+		return m.Received
+	}
+	return 0
+}
+
+// Puts a message that has been sent in chunks in the queue
+type CommitEnqueueRequest struct {
+	TransferId string `protobuf:"bytes,1,opt,name=transferId,proto3" json:"transferId,omitempty"`
+	// The queue to put the task in. Uses the default queue if empty.
+	// The queue is created if it doesn't exist yet.
+	QueueName            string   `protobuf:"bytes,2,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CommitEnqueueRequest) Reset()         { *m = CommitEnqueueRequest{} }
+func (m *CommitEnqueueRequest) String() string { return proto.CompactTextString(m) }
+func (*CommitEnqueueRequest) ProtoMessage()    {}
+func (*CommitEnqueueRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{11}
+}
+func (m *CommitEnqueueRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CommitEnqueueRequest.Unmarshal(m, b)
+}
+func (m *CommitEnqueueRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CommitEnqueueRequest.Marshal(b, m, deterministic)
+}
+func (dst *CommitEnqueueRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CommitEnqueueRequest.Merge(dst, src)
+}
+func (m *CommitEnqueueRequest) XXX_Size() int {
+	return xxx_messageInfo_CommitEnqueueRequest.Size(m)
+}
+func (m *CommitEnqueueRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_CommitEnqueueRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CommitEnqueueRequest proto.InternalMessageInfo
+
+func (m *CommitEnqueueRequest) GetTransferId() string {
+	if m != nil {
+		return m.TransferId
+	}
+	return ""
+}
+
+func (m *CommitEnqueueRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type CommitEnqueueResponse struct {
+	// The id of the created task
+	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CommitEnqueueResponse) Reset()         { *m = CommitEnqueueResponse{} }
+func (m *CommitEnqueueResponse) String() string { return proto.CompactTextString(m) }
+func (*CommitEnqueueResponse) ProtoMessage()    {}
+func (*CommitEnqueueResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{12}
+}
+func (m *CommitEnqueueResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CommitEnqueueResponse.Unmarshal(m, b)
+}
+func (m *CommitEnqueueResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CommitEnqueueResponse.Marshal(b, m, deterministic)
+}
+func (dst *CommitEnqueueResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CommitEnqueueResponse.Merge(dst, src)
+}
+func (m *CommitEnqueueResponse) XXX_Size() int {
+	return xxx_messageInfo_CommitEnqueueResponse.Size(m)
+}
+func (m *CommitEnqueueResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_CommitEnqueueResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CommitEnqueueResponse proto.InternalMessageInfo
+
+func (m *CommitEnqueueResponse) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+// Removes a single message from the queue
+type PopRequest struct {
+	// What capabilities the client has available
+	AvailableCapabilities []string `protobuf:"bytes,1,rep,name=availableCapabilities,proto3" json:"availableCapabilities,omitempty"`
+	// If the server should wait for a message to come in, before returning
+	// with a response
+	WaitForMessage bool `protobuf:"varint,2,opt,name=waitForMessage,proto3" json:"waitForMessage,omitempty"`
+	// How long the message can be processing before it's put back in the queue,
+	// if it hasn't been acknowledged or failed. Uses the server default if zero.
+	VisibilityTimeoutMilliseconds uint64 `protobuf:"varint,3,opt,name=visibilityTimeoutMilliseconds,proto3" json:"visibilityTimeoutMilliseconds,omitempty"`
+	// The queue to pop from. Uses the default queue if empty.
+	// Acknowledging or failing the message goes to the same queue.
+	QueueName            string   `protobuf:"bytes,4,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PopRequest) Reset()         { *m = PopRequest{} }
+func (m *PopRequest) String() string { return proto.CompactTextString(m) }
+func (*PopRequest) ProtoMessage()    {}
+func (*PopRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{13}
+}
+func (m *PopRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PopRequest.Unmarshal(m, b)
+}
+func (m *PopRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PopRequest.Marshal(b, m, deterministic)
+}
+func (dst *PopRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PopRequest.Merge(dst, src)
+}
+func (m *PopRequest) XXX_Size() int {
+	return xxx_messageInfo_PopRequest.Size(m)
+}
+func (m *PopRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_PopRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PopRequest proto.InternalMessageInfo
+
+func (m *PopRequest) GetAvailableCapabilities() []string {
+	if m != nil {
+		return m.AvailableCapabilities
+	}
+	return nil
+}
+
+func (m *PopRequest) GetWaitForMessage() bool {
+	if m != nil {
+		return m.WaitForMessage
+	}
+	return false
+}
+
+func (m *PopRequest) GetVisibilityTimeoutMilliseconds() uint64 {
+	if m != nil {
+		return m.VisibilityTimeoutMilliseconds
+	}
+	return 0
+}
+
+func (m *PopRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type PopResponse struct {
+	// True if there was a message available
+	HadResult bool `protobuf:"varint,3,opt,name=hadResult,proto3" json:"hadResult,omitempty"`
+	// The actual message to process
+	Message []byte `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"`
+	// The id of the message
+	// Should be returned with the acknowledge request
+	Id string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`
+	// True if there was no message for the worker, but the queue has messages
+	// requiring capabilities the worker doesn't have
+	HasUnmatchedMessages bool `protobuf:"varint,4,opt,name=hasUnmatchedMessages,proto3" json:"hasUnmatchedMessages,omitempty"`
+	// True if the waiting pop was cancelled with a CancelPopRequest
+	Cancelled bool `protobuf:"varint,5,opt,name=cancelled,proto3" json:"cancelled,omitempty"`
+	// The user that enqueued the message, empty if unknown
+	EnqueuedBy string `protobuf:"bytes,6,opt,name=enqueuedBy,proto3" json:"enqueuedBy,omitempty"`
+	// True if no message was handed out because too many messages are being processed.
+	// Try again once some of them has been acknowledged.
+	Backpressure bool `protobuf:"varint,7,opt,name=backpressure,proto3" json:"backpressure,omitempty"`
+	// Increases with every enqueued message, so messages can be ordered by when they were enqueued
+	Sequence uint64 `protobuf:"varint,8,opt,name=sequence,proto3" json:"sequence,omitempty"`
+	// True if no message was handed out because the connection pops too often.
+	// Wait a little before popping again.
+	RateLimited bool `protobuf:"varint,9,opt,name=rateLimited,proto3" json:"rateLimited,omitempty"`
+	// True if no message was handed out because the server is still loading the stored messages.
+	// Try again shortly.
+	NotReady bool `protobuf:"varint,10,opt,name=notReady,proto3" json:"notReady,omitempty"`
+	// How long the worker has to acknowledge or fail the message, before it's put back
+	// in the queue for another worker. Zero if the message doesn't time out.
+	VisibilityTimeoutMilliseconds uint64 `protobuf:"varint,11,opt,name=visibilityTimeoutMilliseconds,proto3" json:"visibilityTimeoutMilliseconds,omitempty"`
+	// What capabilities are required to handle the message
+	RequiredCapabilities []string `protobuf:"bytes,12,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// True if the message requires more capabilities than the server returns
+	RequiredCapabilitiesTruncated bool     `protobuf:"varint,13,opt,name=requiredCapabilitiesTruncated,proto3" json:"requiredCapabilitiesTruncated,omitempty"`
+	XXX_NoUnkeyedLiteral          struct{} `json:"-"`
+	XXX_unrecognized              []byte   `json:"-"`
+	XXX_sizecache                 int32    `json:"-"`
+}
+
+func (m *PopResponse) Reset()         { *m = PopResponse{} }
+func (m *PopResponse) String() string { return proto.CompactTextString(m) }
+func (*PopResponse) ProtoMessage()    {}
+func (*PopResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{14}
+}
+func (m *PopResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PopResponse.Unmarshal(m, b)
+}
+func (m *PopResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PopResponse.Marshal(b, m, deterministic)
+}
+func (dst *PopResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PopResponse.Merge(dst, src)
+}
+func (m *PopResponse) XXX_Size() int {
+	return xxx_messageInfo_PopResponse.Size(m)
+}
+func (m *PopResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_PopResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PopResponse proto.InternalMessageInfo
+
+func (m *PopResponse) GetHadResult() bool {
+	if m != nil {
+		return m.HadResult
+	}
+	return false
+}
+
+func (m *PopResponse) GetMessage() []byte {
+	if m != nil {
+		return m.Message
+	}
+	return nil
+}
+
+func (m *PopResponse) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *PopResponse) GetHasUnmatchedMessages() bool {
+	if m != nil {
+		return m.HasUnmatchedMessages
+	}
+	return false
+}
+
+func (m *PopResponse) GetCancelled() bool {
+	if m != nil {
+		return m.Cancelled
+	}
+	return false
+}
+
+func (m *PopResponse) GetEnqueuedBy() string {
+	if m != nil {
+		return m.EnqueuedBy
+	}
+	return ""
+}
+
+func (m *PopResponse) GetBackpressure() bool {
+	if m != nil {
+		return m.Backpressure
+	}
+	return false
+}
+
+func (m *PopResponse) GetSequence() uint64 {
+	if m != nil {
+		return m.Sequence
+	}
+	return 0
+}
+
+func (m *PopResponse) GetRateLimited() bool {
+	if m != nil {
+		return m.RateLimited
+	}
+	return false
+}
+
+func (m *PopResponse) GetNotReady() bool {
+	if m != nil {
+		return m.NotReady
+	}
+	return false
+}
+
+func (m *PopResponse) GetVisibilityTimeoutMilliseconds() uint64 {
+	if m != nil {
+		return m.VisibilityTimeoutMilliseconds
+	}
+	return 0
+}
+
+func (m *PopResponse) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+func (m *PopResponse) GetRequiredCapabilitiesTruncated() bool {
+	if m != nil {
+		return m.RequiredCapabilitiesTruncated
+	}
+	return false
+}
+
+// Cancels a waiting pop, which will then return with cancelled set.
+// Since the connection doing the pop is blocked until the pop returns,
+// this has to be sent on another connection.
+// Makes the server push tasks to the connection as they become available, instead of
+// the worker popping them one at a time. The tasks are sent as PopResponses with the refId
+// of the subscribe request. At most prefetch tasks are pushed before some of them are
+// acknowledged or failed. Subscribing again replaces the subscription, and a prefetch of 0 ends it.
+type SubscribeRequest struct {
+	AvailableCapabilities []string `protobuf:"bytes,1,rep,name=availableCapabilities,proto3" json:"availableCapabilities,omitempty"`
+	Prefetch              uint32   `protobuf:"varint,2,opt,name=prefetch,proto3" json:"prefetch,omitempty"`
+	// The queue to push tasks from. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,3,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *SubscribeRequest) Reset()         { *m = SubscribeRequest{} }
+func (m *SubscribeRequest) String() string { return proto.CompactTextString(m) }
+func (*SubscribeRequest) ProtoMessage()    {}
+func (*SubscribeRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{15}
+}
+func (m *SubscribeRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_SubscribeRequest.Unmarshal(m, b)
+}
+func (m *SubscribeRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_SubscribeRequest.Marshal(b, m, deterministic)
+}
+func (dst *SubscribeRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_SubscribeRequest.Merge(dst, src)
+}
+func (m *SubscribeRequest) XXX_Size() int {
+	return xxx_messageInfo_SubscribeRequest.Size(m)
+}
+func (m *SubscribeRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_SubscribeRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_SubscribeRequest proto.InternalMessageInfo
+
+func (m *SubscribeRequest) GetAvailableCapabilities() []string {
+	if m != nil {
+		return m.AvailableCapabilities
+	}
+	return nil
+}
+
+func (m *SubscribeRequest) GetPrefetch() uint32 {
+	if m != nil {
+		return m.Prefetch
+	}
+	return 0
+}
+
+func (m *SubscribeRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type SubscribeResponse struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *SubscribeResponse) Reset()         { *m = SubscribeResponse{} }
+func (m *SubscribeResponse) String() string { return proto.CompactTextString(m) }
+func (*SubscribeResponse) ProtoMessage()    {}
+func (*SubscribeResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{16}
+}
+func (m *SubscribeResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_SubscribeResponse.Unmarshal(m, b)
+}
+func (m *SubscribeResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_SubscribeResponse.Marshal(b, m, deterministic)
+}
+func (dst *SubscribeResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_SubscribeResponse.Merge(dst, src)
+}
+func (m *SubscribeResponse) XXX_Size() int {
+	return xxx_messageInfo_SubscribeResponse.Size(m)
+}
+func (m *SubscribeResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_SubscribeResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_SubscribeResponse proto.InternalMessageInfo
+
+// Takes the next message matching the capabilities, and hides it from other workers
+// for reservationMilliseconds, so the worker can look at it before deciding to process it.
+// The message is only processed once confirmed with a ConfirmReservationRequest,
+// otherwise it's put back in the queue when the reservation expires.
+type ReserveRequest struct {
+	AvailableCapabilities   []string `protobuf:"bytes,1,rep,name=availableCapabilities,proto3" json:"availableCapabilities,omitempty"`
+	ReservationMilliseconds uint64   `protobuf:"varint,2,opt,name=reservationMilliseconds,proto3" json:"reservationMilliseconds,omitempty"`
+	// The queue to reserve from. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,3,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ReserveRequest) Reset()         { *m = ReserveRequest{} }
+func (m *ReserveRequest) String() string { return proto.CompactTextString(m) }
+func (*ReserveRequest) ProtoMessage()    {}
+func (*ReserveRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{17}
+}
+func (m *ReserveRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ReserveRequest.Unmarshal(m, b)
+}
+func (m *ReserveRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ReserveRequest.Marshal(b, m, deterministic)
+}
+func (dst *ReserveRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ReserveRequest.Merge(dst, src)
+}
+func (m *ReserveRequest) XXX_Size() int {
+	return xxx_messageInfo_ReserveRequest.Size(m)
+}
+func (m *ReserveRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ReserveRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ReserveRequest proto.InternalMessageInfo
+
+func (m *ReserveRequest) GetAvailableCapabilities() []string {
+	if m != nil {
+		return m.AvailableCapabilities
+	}
+	return nil
+}
+
+func (m *ReserveRequest) GetReservationMilliseconds() uint64 {
+	if m != nil {
+		return m.ReservationMilliseconds
+	}
+	return 0
+}
+
+func (m *ReserveRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type ReserveResponse struct {
+	// True if there was a message available
+	HadResult            bool          `protobuf:"varint,1,opt,name=hadResult,proto3" json:"hadResult,omitempty"`
+	Message              *FoundMessage `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}      `json:"-"`
+	XXX_unrecognized     []byte        `json:"-"`
+	XXX_sizecache        int32         `json:"-"`
+}
+
+func (m *ReserveResponse) Reset()         { *m = ReserveResponse{} }
+func (m *ReserveResponse) String() string { return proto.CompactTextString(m) }
+func (*ReserveResponse) ProtoMessage()    {}
+func (*ReserveResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{18}
+}
+func (m *ReserveResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ReserveResponse.Unmarshal(m, b)
+}
+func (m *ReserveResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ReserveResponse.Marshal(b, m, deterministic)
+}
+func (dst *ReserveResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ReserveResponse.Merge(dst, src)
+}
+func (m *ReserveResponse) XXX_Size() int {
+	return xxx_messageInfo_ReserveResponse.Size(m)
+}
+func (m *ReserveResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ReserveResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ReserveResponse proto.InternalMessageInfo
+
+func (m *ReserveResponse) GetHadResult() bool {
+	if m != nil {
+		return m.HadResult
+	}
+	return false
+}
+
+func (m *ReserveResponse) GetMessage() *FoundMessage {
+	if m != nil {
+		return m.Message
+	}
+	return nil
+}
+
+type ConfirmReservationRequest struct {
+	// The id of the reserved message
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// The queue the message was reserved from
+	QueueName            string   `protobuf:"bytes,2,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ConfirmReservationRequest) Reset()         { *m = ConfirmReservationRequest{} }
+func (m *ConfirmReservationRequest) String() string { return proto.CompactTextString(m) }
+func (*ConfirmReservationRequest) ProtoMessage()    {}
+func (*ConfirmReservationRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{19}
+}
+func (m *ConfirmReservationRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ConfirmReservationRequest.Unmarshal(m, b)
+}
+func (m *ConfirmReservationRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ConfirmReservationRequest.Marshal(b, m, deterministic)
+}
+func (dst *ConfirmReservationRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ConfirmReservationRequest.Merge(dst, src)
+}
+func (m *ConfirmReservationRequest) XXX_Size() int {
+	return xxx_messageInfo_ConfirmReservationRequest.Size(m)
+}
+func (m *ConfirmReservationRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ConfirmReservationRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ConfirmReservationRequest proto.InternalMessageInfo
+
+func (m *ConfirmReservationRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *ConfirmReservationRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type ConfirmReservationResponse struct {
+	// True if the message is now being processed by the connection.
+	// False if it wasn't reserved, for example if the reservation expired.
+	Confirmed            bool     `protobuf:"varint,1,opt,name=confirmed,proto3" json:"confirmed,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ConfirmReservationResponse) Reset()         { *m = ConfirmReservationResponse{} }
+func (m *ConfirmReservationResponse) String() string { return proto.CompactTextString(m) }
+func (*ConfirmReservationResponse) ProtoMessage()    {}
+func (*ConfirmReservationResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{20}
+}
+func (m *ConfirmReservationResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ConfirmReservationResponse.Unmarshal(m, b)
+}
+func (m *ConfirmReservationResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ConfirmReservationResponse.Marshal(b, m, deterministic)
+}
+func (dst *ConfirmReservationResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ConfirmReservationResponse.Merge(dst, src)
+}
+func (m *ConfirmReservationResponse) XXX_Size() int {
+	return xxx_messageInfo_ConfirmReservationResponse.Size(m)
+}
+func (m *ConfirmReservationResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ConfirmReservationResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ConfirmReservationResponse proto.InternalMessageInfo
+
+func (m *ConfirmReservationResponse) GetConfirmed() bool {
+	if m != nil {
+		return m.Confirmed
+	}
+	return false
+}
+
+type CancelPopRequest struct {
+	// The session token of the connection doing the pop.
+	// Only pops of the same user can be cancelled, others are reported as NOT_FOUND.
+	SessionToken         string   `protobuf:"bytes,1,opt,name=sessionToken,proto3" json:"sessionToken,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CancelPopRequest) Reset()         { *m = CancelPopRequest{} }
+func (m *CancelPopRequest) String() string { return proto.CompactTextString(m) }
+func (*CancelPopRequest) ProtoMessage()    {}
+func (*CancelPopRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{21}
+}
+func (m *CancelPopRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CancelPopRequest.Unmarshal(m, b)
+}
+func (m *CancelPopRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CancelPopRequest.Marshal(b, m, deterministic)
+}
+func (dst *CancelPopRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CancelPopRequest.Merge(dst, src)
+}
+func (m *CancelPopRequest) XXX_Size() int {
+	return xxx_messageInfo_CancelPopRequest.Size(m)
+}
+func (m *CancelPopRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_CancelPopRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CancelPopRequest proto.InternalMessageInfo
+
+func (m *CancelPopRequest) GetSessionToken() string {
+	if m != nil {
+		return m.SessionToken
+	}
+	return ""
+}
+
+type CancelPopResponse struct {
+	// True if a waiting pop was cancelled
+	Cancelled            bool     `protobuf:"varint,1,opt,name=cancelled,proto3" json:"cancelled,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CancelPopResponse) Reset()         { *m = CancelPopResponse{} }
+func (m *CancelPopResponse) String() string { return proto.CompactTextString(m) }
+func (*CancelPopResponse) ProtoMessage()    {}
+func (*CancelPopResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{22}
+}
+func (m *CancelPopResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CancelPopResponse.Unmarshal(m, b)
+}
+func (m *CancelPopResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CancelPopResponse.Marshal(b, m, deterministic)
+}
+func (dst *CancelPopResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CancelPopResponse.Merge(dst, src)
+}
+func (m *CancelPopResponse) XXX_Size() int {
+	return xxx_messageInfo_CancelPopResponse.Size(m)
+}
+func (m *CancelPopResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_CancelPopResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CancelPopResponse proto.InternalMessageInfo
+
+func (m *CancelPopResponse) GetCancelled() bool {
+	if m != nil {
+		return m.Cancelled
+	}
+	return false
+}
+
+// Removes a message from the queue before it's handed to a worker
+type CancelRequest struct {
+	// The id of the message to cancel
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// The queue the message is in. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,2,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CancelRequest) Reset()         { *m = CancelRequest{} }
+func (m *CancelRequest) String() string { return proto.CompactTextString(m) }
+func (*CancelRequest) ProtoMessage()    {}
+func (*CancelRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{23}
+}
+func (m *CancelRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CancelRequest.Unmarshal(m, b)
+}
+func (m *CancelRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CancelRequest.Marshal(b, m, deterministic)
+}
+func (dst *CancelRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CancelRequest.Merge(dst, src)
+}
+func (m *CancelRequest) XXX_Size() int {
+	return xxx_messageInfo_CancelRequest.Size(m)
+}
+func (m *CancelRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_CancelRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CancelRequest proto.InternalMessageInfo
+
+func (m *CancelRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *CancelRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type CancelResponse struct {
+	// False if the message wasn't waiting in the queue,
+	// e.g. because a worker is already processing it
+	Cancelled            bool     `protobuf:"varint,1,opt,name=cancelled,proto3" json:"cancelled,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *CancelResponse) Reset()         { *m = CancelResponse{} }
+func (m *CancelResponse) String() string { return proto.CompactTextString(m) }
+func (*CancelResponse) ProtoMessage()    {}
+func (*CancelResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{24}
+}
+func (m *CancelResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_CancelResponse.Unmarshal(m, b)
+}
+func (m *CancelResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_CancelResponse.Marshal(b, m, deterministic)
+}
+func (dst *CancelResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_CancelResponse.Merge(dst, src)
+}
+func (m *CancelResponse) XXX_Size() int {
+	return xxx_messageInfo_CancelResponse.Size(m)
+}
+func (m *CancelResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_CancelResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_CancelResponse proto.InternalMessageInfo
+
+func (m *CancelResponse) GetCancelled() bool {
+	if m != nil {
+		return m.Cancelled
+	}
+	return false
+}
+
+type AcknowledgeRequest struct {
+	// The id of the message to acknowledge
+	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *AcknowledgeRequest) Reset()         { *m = AcknowledgeRequest{} }
+func (m *AcknowledgeRequest) String() string { return proto.CompactTextString(m) }
+func (*AcknowledgeRequest) ProtoMessage()    {}
+func (*AcknowledgeRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{25}
+}
+func (m *AcknowledgeRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_AcknowledgeRequest.Unmarshal(m, b)
+}
+func (m *AcknowledgeRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_AcknowledgeRequest.Marshal(b, m, deterministic)
+}
+func (dst *AcknowledgeRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_AcknowledgeRequest.Merge(dst, src)
+}
+func (m *AcknowledgeRequest) XXX_Size() int {
+	return xxx_messageInfo_AcknowledgeRequest.Size(m)
+}
+func (m *AcknowledgeRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_AcknowledgeRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_AcknowledgeRequest proto.InternalMessageInfo
+
+func (m *AcknowledgeRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+// Returned when a message has been acknowledged
+type AcknowledgeResponse struct {
+	// Set if the message had already been acknowledged, in which case nothing was changed
+	AlreadyCompleted     bool     `protobuf:"varint,1,opt,name=alreadyCompleted,proto3" json:"alreadyCompleted,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *AcknowledgeResponse) Reset()         { *m = AcknowledgeResponse{} }
+func (m *AcknowledgeResponse) String() string { return proto.CompactTextString(m) }
+func (*AcknowledgeResponse) ProtoMessage()    {}
+func (*AcknowledgeResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{26}
+}
+func (m *AcknowledgeResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_AcknowledgeResponse.Unmarshal(m, b)
+}
+func (m *AcknowledgeResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_AcknowledgeResponse.Marshal(b, m, deterministic)
+}
+func (dst *AcknowledgeResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_AcknowledgeResponse.Merge(dst, src)
+}
+func (m *AcknowledgeResponse) XXX_Size() int {
+	return xxx_messageInfo_AcknowledgeResponse.Size(m)
+}
+func (m *AcknowledgeResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_AcknowledgeResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_AcknowledgeResponse proto.InternalMessageInfo
+
+func (m *AcknowledgeResponse) GetAlreadyCompleted() bool {
+	if m != nil {
+		return m.AlreadyCompleted
+	}
+	return false
+}
+
+// Marks a message as failed, and puts it back in the queue
+type FailRequest struct {
+	// The id of the message that failed
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// If set the message is put back in the queue with the given priority,
+	// instead of the priority it originally had
+	ChangePriority       bool     `protobuf:"varint,2,opt,name=changePriority,proto3" json:"changePriority,omitempty"`
+	Priority             Priority `protobuf:"varint,3,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FailRequest) Reset()         { *m = FailRequest{} }
+func (m *FailRequest) String() string { return proto.CompactTextString(m) }
+func (*FailRequest) ProtoMessage()    {}
+func (*FailRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{27}
+}
+func (m *FailRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FailRequest.Unmarshal(m, b)
+}
+func (m *FailRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FailRequest.Marshal(b, m, deterministic)
+}
+func (dst *FailRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FailRequest.Merge(dst, src)
+}
+func (m *FailRequest) XXX_Size() int {
+	return xxx_messageInfo_FailRequest.Size(m)
+}
+func (m *FailRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_FailRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FailRequest proto.InternalMessageInfo
+
+func (m *FailRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *FailRequest) GetChangePriority() bool {
+	if m != nil {
+		return m.ChangePriority
+	}
+	return false
+}
+
+func (m *FailRequest) GetPriority() Priority {
+	if m != nil {
+		return m.Priority
+	}
+	return Priority_LOW
+}
+
+type FailResponse struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FailResponse) Reset()         { *m = FailResponse{} }
+func (m *FailResponse) String() string { return proto.CompactTextString(m) }
+func (*FailResponse) ProtoMessage()    {}
+func (*FailResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{28}
+}
+func (m *FailResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FailResponse.Unmarshal(m, b)
+}
+func (m *FailResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FailResponse.Marshal(b, m, deterministic)
+}
+func (dst *FailResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FailResponse.Merge(dst, src)
+}
+func (m *FailResponse) XXX_Size() int {
+	return xxx_messageInfo_FailResponse.Size(m)
+}
+func (m *FailResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_FailResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FailResponse proto.InternalMessageInfo
+
+// Gives a message back without failing it, because the worker can't handle it
+// right now, e.g. because it's missing a dependency.
+// The message is offered to other workers first, and doesn't count as failed.
+type DeclineRequest struct {
+	// The id of the declined message
+	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DeclineRequest) Reset()         { *m = DeclineRequest{} }
+func (m *DeclineRequest) String() string { return proto.CompactTextString(m) }
+func (*DeclineRequest) ProtoMessage()    {}
+func (*DeclineRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{29}
+}
+func (m *DeclineRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_DeclineRequest.Unmarshal(m, b)
+}
+func (m *DeclineRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_DeclineRequest.Marshal(b, m, deterministic)
+}
+func (dst *DeclineRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DeclineRequest.Merge(dst, src)
+}
+func (m *DeclineRequest) XXX_Size() int {
+	return xxx_messageInfo_DeclineRequest.Size(m)
+}
+func (m *DeclineRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_DeclineRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DeclineRequest proto.InternalMessageInfo
+
+func (m *DeclineRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+type DeclineResponse struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DeclineResponse) Reset()         { *m = DeclineResponse{} }
+func (m *DeclineResponse) String() string { return proto.CompactTextString(m) }
+func (*DeclineResponse) ProtoMessage()    {}
+func (*DeclineResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{30}
+}
+func (m *DeclineResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_DeclineResponse.Unmarshal(m, b)
+}
+func (m *DeclineResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_DeclineResponse.Marshal(b, m, deterministic)
+}
+func (dst *DeclineResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DeclineResponse.Merge(dst, src)
+}
+func (m *DeclineResponse) XXX_Size() int {
+	return xxx_messageInfo_DeclineResponse.Size(m)
+}
+func (m *DeclineResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_DeclineResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DeclineResponse proto.InternalMessageInfo
+
+// Marks a message as failed, and puts it back in the queue
+// requiring a different set of capabilities
+type FailWithRetagRequest struct {
+	// The id of the message that failed
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	// The capabilities required to handle the message from now on
+	RequiredCapabilities []string `protobuf:"bytes,2,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FailWithRetagRequest) Reset()         { *m = FailWithRetagRequest{} }
+func (m *FailWithRetagRequest) String() string { return proto.CompactTextString(m) }
+func (*FailWithRetagRequest) ProtoMessage()    {}
+func (*FailWithRetagRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{31}
+}
+func (m *FailWithRetagRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FailWithRetagRequest.Unmarshal(m, b)
+}
+func (m *FailWithRetagRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FailWithRetagRequest.Marshal(b, m, deterministic)
+}
+func (dst *FailWithRetagRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FailWithRetagRequest.Merge(dst, src)
+}
+func (m *FailWithRetagRequest) XXX_Size() int {
+	return xxx_messageInfo_FailWithRetagRequest.Size(m)
+}
+func (m *FailWithRetagRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_FailWithRetagRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FailWithRetagRequest proto.InternalMessageInfo
+
+func (m *FailWithRetagRequest) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *FailWithRetagRequest) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+type FailWithRetagResponse struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FailWithRetagResponse) Reset()         { *m = FailWithRetagResponse{} }
+func (m *FailWithRetagResponse) String() string { return proto.CompactTextString(m) }
+func (*FailWithRetagResponse) ProtoMessage()    {}
+func (*FailWithRetagResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{32}
+}
+func (m *FailWithRetagResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FailWithRetagResponse.Unmarshal(m, b)
+}
+func (m *FailWithRetagResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FailWithRetagResponse.Marshal(b, m, deterministic)
+}
+func (dst *FailWithRetagResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FailWithRetagResponse.Merge(dst, src)
+}
+func (m *FailWithRetagResponse) XXX_Size() int {
+	return xxx_messageInfo_FailWithRetagResponse.Size(m)
+}
+func (m *FailWithRetagResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_FailWithRetagResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FailWithRetagResponse proto.InternalMessageInfo
+
+// Acknowledges every processing message matching the filters.
+// Intended for cleanup tooling, e.g. when a type of worker has been removed.
+// Only available to admins.
+// At least one filter has to be set, all set filters has to match.
+type AcknowledgeMatchingRequest struct {
+	// Only acknowledge messages requiring this capability
+	RequiredCapability string `protobuf:"bytes,1,opt,name=requiredCapability,proto3" json:"requiredCapability,omitempty"`
+	// Only acknowledge messages that has been processing for at least
+	// this many milliseconds
+	MinimumAgeMilliseconds uint64 `protobuf:"varint,2,opt,name=minimumAgeMilliseconds,proto3" json:"minimumAgeMilliseconds,omitempty"`
+	// The queue to acknowledge messages in. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,3,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *AcknowledgeMatchingRequest) Reset()         { *m = AcknowledgeMatchingRequest{} }
+func (m *AcknowledgeMatchingRequest) String() string { return proto.CompactTextString(m) }
+func (*AcknowledgeMatchingRequest) ProtoMessage()    {}
+func (*AcknowledgeMatchingRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{33}
+}
+func (m *AcknowledgeMatchingRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_AcknowledgeMatchingRequest.Unmarshal(m, b)
+}
+func (m *AcknowledgeMatchingRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_AcknowledgeMatchingRequest.Marshal(b, m, deterministic)
+}
+func (dst *AcknowledgeMatchingRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_AcknowledgeMatchingRequest.Merge(dst, src)
+}
+func (m *AcknowledgeMatchingRequest) XXX_Size() int {
+	return xxx_messageInfo_AcknowledgeMatchingRequest.Size(m)
+}
+func (m *AcknowledgeMatchingRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_AcknowledgeMatchingRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_AcknowledgeMatchingRequest proto.InternalMessageInfo
+
+func (m *AcknowledgeMatchingRequest) GetRequiredCapability() string {
+	if m != nil {
+		return m.RequiredCapability
+	}
+	return ""
+}
+
+func (m *AcknowledgeMatchingRequest) GetMinimumAgeMilliseconds() uint64 {
+	if m != nil {
+		return m.MinimumAgeMilliseconds
+	}
+	return 0
+}
+
+func (m *AcknowledgeMatchingRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type AcknowledgeMatchingResponse struct {
+	// How many messages were acknowledged
+	Count                uint64   `protobuf:"varint,1,opt,name=count,proto3" json:"count,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *AcknowledgeMatchingResponse) Reset()         { *m = AcknowledgeMatchingResponse{} }
+func (m *AcknowledgeMatchingResponse) String() string { return proto.CompactTextString(m) }
+func (*AcknowledgeMatchingResponse) ProtoMessage()    {}
+func (*AcknowledgeMatchingResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{34}
+}
+func (m *AcknowledgeMatchingResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_AcknowledgeMatchingResponse.Unmarshal(m, b)
+}
+func (m *AcknowledgeMatchingResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_AcknowledgeMatchingResponse.Marshal(b, m, deterministic)
+}
+func (dst *AcknowledgeMatchingResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_AcknowledgeMatchingResponse.Merge(dst, src)
+}
+func (m *AcknowledgeMatchingResponse) XXX_Size() int {
+	return xxx_messageInfo_AcknowledgeMatchingResponse.Size(m)
+}
+func (m *AcknowledgeMatchingResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_AcknowledgeMatchingResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_AcknowledgeMatchingResponse proto.InternalMessageInfo
+
+func (m *AcknowledgeMatchingResponse) GetCount() uint64 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+// Finds the queued messages requiring the given tags, without removing them.
+// Intended for dashboards, so the messages are matched on the tags they require,
+// and not on whether a worker with the tags could handle them.
+type FindByTagRequest struct {
+	Tags      []string     `protobuf:"bytes,1,rep,name=tags,proto3" json:"tags,omitempty"`
+	MatchMode TagMatchMode `protobuf:"varint,2,opt,name=matchMode,proto3,enum=TagMatchMode" json:"matchMode,omitempty"`
+	// How many matching messages to skip
+	Offset uint64 `protobuf:"varint,3,opt,name=offset,proto3" json:"offset,omitempty"`
+	// The maximum number of messages to return, everything if 0
+	Limit uint64 `protobuf:"varint,4,opt,name=limit,proto3" json:"limit,omitempty"`
+	// The queue to search. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,5,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FindByTagRequest) Reset()         { *m = FindByTagRequest{} }
+func (m *FindByTagRequest) String() string { return proto.CompactTextString(m) }
+func (*FindByTagRequest) ProtoMessage()    {}
+func (*FindByTagRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{35}
+}
+func (m *FindByTagRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FindByTagRequest.Unmarshal(m, b)
+}
+func (m *FindByTagRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FindByTagRequest.Marshal(b, m, deterministic)
+}
+func (dst *FindByTagRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FindByTagRequest.Merge(dst, src)
+}
+func (m *FindByTagRequest) XXX_Size() int {
+	return xxx_messageInfo_FindByTagRequest.Size(m)
+}
+func (m *FindByTagRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_FindByTagRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FindByTagRequest proto.InternalMessageInfo
+
+func (m *FindByTagRequest) GetTags() []string {
+	if m != nil {
+		return m.Tags
+	}
+	return nil
+}
+
+func (m *FindByTagRequest) GetMatchMode() TagMatchMode {
+	if m != nil {
+		return m.MatchMode
+	}
+	return TagMatchMode_ALL
+}
+
+func (m *FindByTagRequest) GetOffset() uint64 {
+	if m != nil {
+		return m.Offset
+	}
+	return 0
+}
+
+func (m *FindByTagRequest) GetLimit() uint64 {
+	if m != nil {
+		return m.Limit
+	}
+	return 0
+}
+
+func (m *FindByTagRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type FoundMessage struct {
+	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Message              []byte   `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	Priority             Priority `protobuf:"varint,3,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
+	RequiredCapabilities []string `protobuf:"bytes,4,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// The user that enqueued the message, empty if unknown
+	EnqueuedBy string `protobuf:"bytes,5,opt,name=enqueuedBy,proto3" json:"enqueuedBy,omitempty"`
+	// True if the message requires more capabilities than the server returns
+	RequiredCapabilitiesTruncated bool `protobuf:"varint,6,opt,name=requiredCapabilitiesTruncated,proto3" json:"requiredCapabilitiesTruncated,omitempty"`
+	// The priority level the message was enqueued with
+	PriorityLevel        uint32   `protobuf:"varint,7,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FoundMessage) Reset()         { *m = FoundMessage{} }
+func (m *FoundMessage) String() string { return proto.CompactTextString(m) }
+func (*FoundMessage) ProtoMessage()    {}
+func (*FoundMessage) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{36}
+}
+func (m *FoundMessage) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FoundMessage.Unmarshal(m, b)
+}
+func (m *FoundMessage) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FoundMessage.Marshal(b, m, deterministic)
+}
+func (dst *FoundMessage) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FoundMessage.Merge(dst, src)
+}
+func (m *FoundMessage) XXX_Size() int {
+	return xxx_messageInfo_FoundMessage.Size(m)
+}
+func (m *FoundMessage) XXX_DiscardUnknown() {
+	xxx_messageInfo_FoundMessage.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FoundMessage proto.InternalMessageInfo
+
+func (m *FoundMessage) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *FoundMessage) GetMessage() []byte {
+	if m != nil {
+		return m.Message
+	}
+	return nil
+}
+
+func (m *FoundMessage) GetPriority() Priority {
+	if m != nil {
+		return m.Priority
+	}
+	return Priority_LOW
+}
+
+func (m *FoundMessage) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+func (m *FoundMessage) GetEnqueuedBy() string {
+	if m != nil {
+		return m.EnqueuedBy
+	}
+	return ""
+}
+
+func (m *FoundMessage) GetRequiredCapabilitiesTruncated() bool {
+	if m != nil {
+		return m.RequiredCapabilitiesTruncated
+	}
+	return false
+}
+
+func (m *FoundMessage) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+type FindByTagResponse struct {
+	// The requested page of matching messages, high priority messages first
+	Messages []*FoundMessage `protobuf:"bytes,1,rep,name=messages,proto3" json:"messages,omitempty"`
+	// The total number of matching messages
+	Total                uint64   `protobuf:"varint,2,opt,name=total,proto3" json:"total,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *FindByTagResponse) Reset()         { *m = FindByTagResponse{} }
+func (m *FindByTagResponse) String() string { return proto.CompactTextString(m) }
+func (*FindByTagResponse) ProtoMessage()    {}
+func (*FindByTagResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{37}
+}
+func (m *FindByTagResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_FindByTagResponse.Unmarshal(m, b)
+}
+func (m *FindByTagResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_FindByTagResponse.Marshal(b, m, deterministic)
+}
+func (dst *FindByTagResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_FindByTagResponse.Merge(dst, src)
+}
+func (m *FindByTagResponse) XXX_Size() int {
+	return xxx_messageInfo_FindByTagResponse.Size(m)
+}
+func (m *FindByTagResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_FindByTagResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_FindByTagResponse proto.InternalMessageInfo
+
+func (m *FindByTagResponse) GetMessages() []*FoundMessage {
+	if m != nil {
+		return m.Messages
+	}
+	return nil
+}
+
+func (m *FindByTagResponse) GetTotal() uint64 {
+	if m != nil {
+		return m.Total
+	}
+	return 0
+}
+
+// Gets the message a worker with the capabilities would get next, without removing it
+type PeekRequest struct {
+	AvailableCapabilities []string `protobuf:"bytes,1,rep,name=availableCapabilities,proto3" json:"availableCapabilities,omitempty"`
+	// If set only messages with the given priority are looked at
+	OnlyPriority bool     `protobuf:"varint,2,opt,name=onlyPriority,proto3" json:"onlyPriority,omitempty"`
+	Priority     Priority `protobuf:"varint,3,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
+	// The queue to look in. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,4,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PeekRequest) Reset()         { *m = PeekRequest{} }
+func (m *PeekRequest) String() string { return proto.CompactTextString(m) }
+func (*PeekRequest) ProtoMessage()    {}
+func (*PeekRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{38}
+}
+func (m *PeekRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PeekRequest.Unmarshal(m, b)
+}
+func (m *PeekRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PeekRequest.Marshal(b, m, deterministic)
+}
+func (dst *PeekRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PeekRequest.Merge(dst, src)
+}
+func (m *PeekRequest) XXX_Size() int {
+	return xxx_messageInfo_PeekRequest.Size(m)
+}
+func (m *PeekRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_PeekRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PeekRequest proto.InternalMessageInfo
+
+func (m *PeekRequest) GetAvailableCapabilities() []string {
+	if m != nil {
+		return m.AvailableCapabilities
+	}
+	return nil
+}
+
+func (m *PeekRequest) GetOnlyPriority() bool {
+	if m != nil {
+		return m.OnlyPriority
+	}
+	return false
+}
+
+func (m *PeekRequest) GetPriority() Priority {
+	if m != nil {
+		return m.Priority
+	}
+	return Priority_LOW
+}
+
+func (m *PeekRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type PeekResponse struct {
+	// True if there was a message available
+	HadResult            bool          `protobuf:"varint,1,opt,name=hadResult,proto3" json:"hadResult,omitempty"`
+	Message              *FoundMessage `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}      `json:"-"`
+	XXX_unrecognized     []byte        `json:"-"`
+	XXX_sizecache        int32         `json:"-"`
+}
+
+func (m *PeekResponse) Reset()         { *m = PeekResponse{} }
+func (m *PeekResponse) String() string { return proto.CompactTextString(m) }
+func (*PeekResponse) ProtoMessage()    {}
+func (*PeekResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{39}
+}
+func (m *PeekResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PeekResponse.Unmarshal(m, b)
+}
+func (m *PeekResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PeekResponse.Marshal(b, m, deterministic)
+}
+func (dst *PeekResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PeekResponse.Merge(dst, src)
+}
+func (m *PeekResponse) XXX_Size() int {
+	return xxx_messageInfo_PeekResponse.Size(m)
+}
+func (m *PeekResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_PeekResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PeekResponse proto.InternalMessageInfo
+
+func (m *PeekResponse) GetHadResult() bool {
+	if m != nil {
+		return m.HadResult
+	}
+	return false
+}
+
+func (m *PeekResponse) GetMessage() *FoundMessage {
+	if m != nil {
+		return m.Message
+	}
+	return nil
+}
+
+// Lists the queued messages a page at a time, in the order they were enqueued, without removing them.
+// Messages popped or enqueued while paging don't make the pages skip or repeat messages.
+type ListQueuedRequest struct {
+	// The nextCursor of the previous page, empty for the first page
+	Cursor string `protobuf:"bytes,1,opt,name=cursor,proto3" json:"cursor,omitempty"`
+	// The maximum number of messages to return, everything if 0
+	Limit uint64 `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+	// The queue to list. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,3,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ListQueuedRequest) Reset()         { *m = ListQueuedRequest{} }
+func (m *ListQueuedRequest) String() string { return proto.CompactTextString(m) }
+func (*ListQueuedRequest) ProtoMessage()    {}
+func (*ListQueuedRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{40}
+}
+func (m *ListQueuedRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ListQueuedRequest.Unmarshal(m, b)
+}
+func (m *ListQueuedRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ListQueuedRequest.Marshal(b, m, deterministic)
+}
+func (dst *ListQueuedRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ListQueuedRequest.Merge(dst, src)
+}
+func (m *ListQueuedRequest) XXX_Size() int {
+	return xxx_messageInfo_ListQueuedRequest.Size(m)
+}
+func (m *ListQueuedRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ListQueuedRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ListQueuedRequest proto.InternalMessageInfo
+
+func (m *ListQueuedRequest) GetCursor() string {
+	if m != nil {
+		return m.Cursor
+	}
+	return ""
+}
+
+func (m *ListQueuedRequest) GetLimit() uint64 {
+	if m != nil {
+		return m.Limit
+	}
+	return 0
+}
+
+func (m *ListQueuedRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type ListQueuedResponse struct {
+	Messages []*FoundMessage `protobuf:"bytes,1,rep,name=messages,proto3" json:"messages,omitempty"`
+	// Passed in the next request to get the next page, empty if there are no more messages
+	NextCursor           string   `protobuf:"bytes,2,opt,name=nextCursor,proto3" json:"nextCursor,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ListQueuedResponse) Reset()         { *m = ListQueuedResponse{} }
+func (m *ListQueuedResponse) String() string { return proto.CompactTextString(m) }
+func (*ListQueuedResponse) ProtoMessage()    {}
+func (*ListQueuedResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{41}
+}
+func (m *ListQueuedResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ListQueuedResponse.Unmarshal(m, b)
+}
+func (m *ListQueuedResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ListQueuedResponse.Marshal(b, m, deterministic)
+}
+func (dst *ListQueuedResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ListQueuedResponse.Merge(dst, src)
+}
+func (m *ListQueuedResponse) XXX_Size() int {
+	return xxx_messageInfo_ListQueuedResponse.Size(m)
+}
+func (m *ListQueuedResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ListQueuedResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ListQueuedResponse proto.InternalMessageInfo
+
+func (m *ListQueuedResponse) GetMessages() []*FoundMessage {
+	if m != nil {
+		return m.Messages
+	}
+	return nil
+}
+
+func (m *ListQueuedResponse) GetNextCursor() string {
+	if m != nil {
+		return m.NextCursor
+	}
+	return ""
+}
+
+// Lists the messages currently being processed, for finding stuck work.
+// Only admins can do this.
+type ListProcessingRequest struct {
+	// The queue to list. Uses the default queue if empty.
+	QueueName            string   `protobuf:"bytes,1,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ListProcessingRequest) Reset()         { *m = ListProcessingRequest{} }
+func (m *ListProcessingRequest) String() string { return proto.CompactTextString(m) }
+func (*ListProcessingRequest) ProtoMessage()    {}
+func (*ListProcessingRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{42}
+}
+func (m *ListProcessingRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ListProcessingRequest.Unmarshal(m, b)
+}
+func (m *ListProcessingRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ListProcessingRequest.Marshal(b, m, deterministic)
+}
+func (dst *ListProcessingRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ListProcessingRequest.Merge(dst, src)
+}
+func (m *ListProcessingRequest) XXX_Size() int {
+	return xxx_messageInfo_ListProcessingRequest.Size(m)
+}
+func (m *ListProcessingRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ListProcessingRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ListProcessingRequest proto.InternalMessageInfo
+
+func (m *ListProcessingRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type ProcessingMessage struct {
+	Id                   string   `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Priority             Priority `protobuf:"varint,2,opt,name=priority,proto3,enum=Priority" json:"priority,omitempty"`
+	RequiredCapabilities []string `protobuf:"bytes,3,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// How long ago the message was popped
+	AgeMilliseconds uint64 `protobuf:"varint,4,opt,name=ageMilliseconds,proto3" json:"ageMilliseconds,omitempty"`
+	// The session of the worker processing the message, empty if unknown
+	Worker string `protobuf:"bytes,5,opt,name=worker,proto3" json:"worker,omitempty"`
+	// The user that enqueued the message, empty if unknown
+	EnqueuedBy string `protobuf:"bytes,6,opt,name=enqueuedBy,proto3" json:"enqueuedBy,omitempty"`
+	// True if the message requires more capabilities than the server returns
+	RequiredCapabilitiesTruncated bool `protobuf:"varint,7,opt,name=requiredCapabilitiesTruncated,proto3" json:"requiredCapabilitiesTruncated,omitempty"`
+	// The priority level the message was enqueued with
+	PriorityLevel        uint32   `protobuf:"varint,8,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ProcessingMessage) Reset()         { *m = ProcessingMessage{} }
+func (m *ProcessingMessage) String() string { return proto.CompactTextString(m) }
+func (*ProcessingMessage) ProtoMessage()    {}
+func (*ProcessingMessage) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{43}
+}
+func (m *ProcessingMessage) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ProcessingMessage.Unmarshal(m, b)
+}
+func (m *ProcessingMessage) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ProcessingMessage.Marshal(b, m, deterministic)
+}
+func (dst *ProcessingMessage) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ProcessingMessage.Merge(dst, src)
+}
+func (m *ProcessingMessage) XXX_Size() int {
+	return xxx_messageInfo_ProcessingMessage.Size(m)
+}
+func (m *ProcessingMessage) XXX_DiscardUnknown() {
+	xxx_messageInfo_ProcessingMessage.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ProcessingMessage proto.InternalMessageInfo
+
+func (m *ProcessingMessage) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *ProcessingMessage) GetPriority() Priority {
+	if m != nil {
+		return m.Priority
+	}
+	return Priority_LOW
+}
+
+func (m *ProcessingMessage) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+func (m *ProcessingMessage) GetAgeMilliseconds() uint64 {
+	if m != nil {
+		return m.AgeMilliseconds
+	}
+	return 0
+}
+
+func (m *ProcessingMessage) GetWorker() string {
+	if m != nil {
+		return m.Worker
+	}
+	return ""
+}
+
+func (m *ProcessingMessage) GetEnqueuedBy() string {
+	if m != nil {
+		return m.EnqueuedBy
+	}
+	return ""
+}
+
+func (m *ProcessingMessage) GetRequiredCapabilitiesTruncated() bool {
+	if m != nil {
+		return m.RequiredCapabilitiesTruncated
+	}
+	return false
+}
+
+func (m *ProcessingMessage) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+type ListProcessingResponse struct {
+	// The messages being processed, the one that has been processing the longest first
+	Messages             []*ProcessingMessage `protobuf:"bytes,1,rep,name=messages,proto3" json:"messages,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}             `json:"-"`
+	XXX_unrecognized     []byte               `json:"-"`
+	XXX_sizecache        int32                `json:"-"`
+}
+
+func (m *ListProcessingResponse) Reset()         { *m = ListProcessingResponse{} }
+func (m *ListProcessingResponse) String() string { return proto.CompactTextString(m) }
+func (*ListProcessingResponse) ProtoMessage()    {}
+func (*ListProcessingResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{44}
+}
+func (m *ListProcessingResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ListProcessingResponse.Unmarshal(m, b)
+}
+func (m *ListProcessingResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ListProcessingResponse.Marshal(b, m, deterministic)
+}
+func (dst *ListProcessingResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ListProcessingResponse.Merge(dst, src)
+}
+func (m *ListProcessingResponse) XXX_Size() int {
+	return xxx_messageInfo_ListProcessingResponse.Size(m)
+}
+func (m *ListProcessingResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ListProcessingResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ListProcessingResponse proto.InternalMessageInfo
+
+func (m *ListProcessingResponse) GetMessages() []*ProcessingMessage {
+	if m != nil {
+		return m.Messages
+	}
+	return nil
+}
+
+// Checks how well the server is doing
+// Stops or resumes handing out tasks that require a capability, while tasks
+// not requiring it are still handed out. Only admins can do this.
+type SetCapabilityPausedRequest struct {
+	Capability           string   `protobuf:"bytes,1,opt,name=capability,proto3" json:"capability,omitempty"`
+	Paused               bool     `protobuf:"varint,2,opt,name=paused,proto3" json:"paused,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *SetCapabilityPausedRequest) Reset()         { *m = SetCapabilityPausedRequest{} }
+func (m *SetCapabilityPausedRequest) String() string { return proto.CompactTextString(m) }
+func (*SetCapabilityPausedRequest) ProtoMessage()    {}
+func (*SetCapabilityPausedRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{45}
+}
+func (m *SetCapabilityPausedRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_SetCapabilityPausedRequest.Unmarshal(m, b)
+}
+func (m *SetCapabilityPausedRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_SetCapabilityPausedRequest.Marshal(b, m, deterministic)
+}
+func (dst *SetCapabilityPausedRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_SetCapabilityPausedRequest.Merge(dst, src)
+}
+func (m *SetCapabilityPausedRequest) XXX_Size() int {
+	return xxx_messageInfo_SetCapabilityPausedRequest.Size(m)
+}
+func (m *SetCapabilityPausedRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_SetCapabilityPausedRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_SetCapabilityPausedRequest proto.InternalMessageInfo
+
+func (m *SetCapabilityPausedRequest) GetCapability() string {
+	if m != nil {
+		return m.Capability
+	}
+	return ""
+}
+
+func (m *SetCapabilityPausedRequest) GetPaused() bool {
+	if m != nil {
+		return m.Paused
+	}
+	return false
+}
+
+type SetCapabilityPausedResponse struct {
+	// Every capability that is paused after the change
+	PausedCapabilities   []string `protobuf:"bytes,1,rep,name=pausedCapabilities,proto3" json:"pausedCapabilities,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *SetCapabilityPausedResponse) Reset()         { *m = SetCapabilityPausedResponse{} }
+func (m *SetCapabilityPausedResponse) String() string { return proto.CompactTextString(m) }
+func (*SetCapabilityPausedResponse) ProtoMessage()    {}
+func (*SetCapabilityPausedResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{46}
+}
+func (m *SetCapabilityPausedResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_SetCapabilityPausedResponse.Unmarshal(m, b)
+}
+func (m *SetCapabilityPausedResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_SetCapabilityPausedResponse.Marshal(b, m, deterministic)
+}
+func (dst *SetCapabilityPausedResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_SetCapabilityPausedResponse.Merge(dst, src)
+}
+func (m *SetCapabilityPausedResponse) XXX_Size() int {
+	return xxx_messageInfo_SetCapabilityPausedResponse.Size(m)
+}
+func (m *SetCapabilityPausedResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_SetCapabilityPausedResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_SetCapabilityPausedResponse proto.InternalMessageInfo
+
+func (m *SetCapabilityPausedResponse) GetPausedCapabilities() []string {
+	if m != nil {
+		return m.PausedCapabilities
+	}
+	return nil
+}
+
+type HealthRequest struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *HealthRequest) Reset()         { *m = HealthRequest{} }
+func (m *HealthRequest) String() string { return proto.CompactTextString(m) }
+func (*HealthRequest) ProtoMessage()    {}
+func (*HealthRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{47}
+}
+func (m *HealthRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_HealthRequest.Unmarshal(m, b)
+}
+func (m *HealthRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_HealthRequest.Marshal(b, m, deterministic)
+}
+func (dst *HealthRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_HealthRequest.Merge(dst, src)
+}
+func (m *HealthRequest) XXX_Size() int {
+	return xxx_messageInfo_HealthRequest.Size(m)
+}
+func (m *HealthRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_HealthRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_HealthRequest proto.InternalMessageInfo
+
+type LevelScanDepth struct {
+	PriorityLevel        uint32   `protobuf:"varint,1,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
+	ScanDepth            float64  `protobuf:"fixed64,2,opt,name=scanDepth,proto3" json:"scanDepth,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *LevelScanDepth) Reset()         { *m = LevelScanDepth{} }
+func (m *LevelScanDepth) String() string { return proto.CompactTextString(m) }
+func (*LevelScanDepth) ProtoMessage()    {}
+func (*LevelScanDepth) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{48}
+}
+func (m *LevelScanDepth) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_LevelScanDepth.Unmarshal(m, b)
+}
+func (m *LevelScanDepth) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_LevelScanDepth.Marshal(b, m, deterministic)
+}
+func (dst *LevelScanDepth) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_LevelScanDepth.Merge(dst, src)
+}
+func (m *LevelScanDepth) XXX_Size() int {
+	return xxx_messageInfo_LevelScanDepth.Size(m)
+}
+func (m *LevelScanDepth) XXX_DiscardUnknown() {
+	xxx_messageInfo_LevelScanDepth.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_LevelScanDepth proto.InternalMessageInfo
+
+func (m *LevelScanDepth) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+func (m *LevelScanDepth) GetScanDepth() float64 {
+	if m != nil {
+		return m.ScanDepth
+	}
+	return 0
+}
+
+type HealthResponse struct {
+	// The average number of distinct sets of required capabilities per pop in the high priority
+	// queue, that the popping worker couldn't handle. A consistently high number indicates
+	// that the queued tasks doesn't match the capabilities of the workers
+	HighPriorityScanDepth float64 `protobuf:"fixed64,1,opt,name=highPriorityScanDepth,proto3" json:"highPriorityScanDepth,omitempty"`
+	// The same for the low priority queue
+	LowPriorityScanDepth float64 `protobuf:"fixed64,2,opt,name=lowPriorityScanDepth,proto3" json:"lowPriorityScanDepth,omitempty"`
+	// How long it will take to empty the queue at the rate tasks has been popped recently.
+	// Negative if nothing has been popped recently, so there is nothing to estimate from.
+	EstimatedDrainSeconds float64 `protobuf:"fixed64,3,opt,name=estimatedDrainSeconds,proto3" json:"estimatedDrainSeconds,omitempty"`
+	// False while the server is loading the stored tasks, during which pops are rejected
+	Ready bool `protobuf:"varint,4,opt,name=ready,proto3" json:"ready,omitempty"`
+	// The scan depth of every priority level, from the highest level down
+	ScanDepthByLevel     []*LevelScanDepth `protobuf:"bytes,5,rep,name=scanDepthByLevel,proto3" json:"scanDepthByLevel,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}          `json:"-"`
+	XXX_unrecognized     []byte            `json:"-"`
+	XXX_sizecache        int32             `json:"-"`
+}
+
+func (m *HealthResponse) Reset()         { *m = HealthResponse{} }
+func (m *HealthResponse) String() string { return proto.CompactTextString(m) }
+func (*HealthResponse) ProtoMessage()    {}
+func (*HealthResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{49}
+}
+func (m *HealthResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_HealthResponse.Unmarshal(m, b)
+}
+func (m *HealthResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_HealthResponse.Marshal(b, m, deterministic)
+}
+func (dst *HealthResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_HealthResponse.Merge(dst, src)
+}
+func (m *HealthResponse) XXX_Size() int {
+	return xxx_messageInfo_HealthResponse.Size(m)
+}
+func (m *HealthResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_HealthResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_HealthResponse proto.InternalMessageInfo
+
+func (m *HealthResponse) GetHighPriorityScanDepth() float64 {
+	if m != nil {
+		return m.HighPriorityScanDepth
+	}
+	return 0
+}
+
+func (m *HealthResponse) GetLowPriorityScanDepth() float64 {
+	if m != nil {
+		return m.LowPriorityScanDepth
+	}
+	return 0
+}
+
+func (m *HealthResponse) GetEstimatedDrainSeconds() float64 {
+	if m != nil {
+		return m.EstimatedDrainSeconds
+	}
+	return 0
+}
+
+func (m *HealthResponse) GetReady() bool {
+	if m != nil {
+		return m.Ready
+	}
+	return false
+}
+
+func (m *HealthResponse) GetScanDepthByLevel() []*LevelScanDepth {
+	if m != nil {
+		return m.ScanDepthByLevel
+	}
+	return nil
+}
+
+// Gets how many messages are waiting and being processed
+type StatsRequest struct {
+	// The queue to get the counts of, the default queue if empty
+	QueueName            string   `protobuf:"bytes,1,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *StatsRequest) Reset()         { *m = StatsRequest{} }
+func (m *StatsRequest) String() string { return proto.CompactTextString(m) }
+func (*StatsRequest) ProtoMessage()    {}
+func (*StatsRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{50}
+}
+func (m *StatsRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_StatsRequest.Unmarshal(m, b)
+}
+func (m *StatsRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_StatsRequest.Marshal(b, m, deterministic)
+}
+func (dst *StatsRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_StatsRequest.Merge(dst, src)
+}
+func (m *StatsRequest) XXX_Size() int {
+	return xxx_messageInfo_StatsRequest.Size(m)
+}
+func (m *StatsRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_StatsRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_StatsRequest proto.InternalMessageInfo
+
+func (m *StatsRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type PendingCount struct {
+	PriorityLevel        uint32   `protobuf:"varint,1,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
+	Count                uint64   `protobuf:"varint,2,opt,name=count,proto3" json:"count,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PendingCount) Reset()         { *m = PendingCount{} }
+func (m *PendingCount) String() string { return proto.CompactTextString(m) }
+func (*PendingCount) ProtoMessage()    {}
+func (*PendingCount) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{51}
+}
+func (m *PendingCount) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PendingCount.Unmarshal(m, b)
+}
+func (m *PendingCount) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PendingCount.Marshal(b, m, deterministic)
+}
+func (dst *PendingCount) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PendingCount.Merge(dst, src)
+}
+func (m *PendingCount) XXX_Size() int {
+	return xxx_messageInfo_PendingCount.Size(m)
+}
+func (m *PendingCount) XXX_DiscardUnknown() {
+	xxx_messageInfo_PendingCount.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PendingCount proto.InternalMessageInfo
+
+func (m *PendingCount) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+func (m *PendingCount) GetCount() uint64 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+type StatsResponse struct {
+	// How many messages are waiting in memory at each priority level, from the highest level down
+	Pending []*PendingCount `protobuf:"bytes,1,rep,name=pending,proto3" json:"pending,omitempty"`
+	// Every waiting message, including the ones only stored on disk because of the memory limit
+	Queued     uint64 `protobuf:"varint,2,opt,name=queued,proto3" json:"queued,omitempty"`
+	Processing uint64 `protobuf:"varint,3,opt,name=processing,proto3" json:"processing,omitempty"`
+	// How many messages are waiting in memory with high priority or above
+	HighPriority uint64 `protobuf:"varint,4,opt,name=highPriority,proto3" json:"highPriority,omitempty"`
+	// How many messages are waiting in memory with low priority
+	LowPriority uint64 `protobuf:"varint,5,opt,name=lowPriority,proto3" json:"lowPriority,omitempty"`
+	// How many messages have been enqueued and acknowledged since the server started
+	Enqueued     uint64 `protobuf:"varint,6,opt,name=enqueued,proto3" json:"enqueued,omitempty"`
+	Acknowledged uint64 `protobuf:"varint,7,opt,name=acknowledged,proto3" json:"acknowledged,omitempty"`
+	// How long it will take to empty the queue at the rate tasks has been popped recently.
+	// Negative if nothing has been popped recently, so there is nothing to estimate from.
+	EstimatedDrainSeconds float64  `protobuf:"fixed64,8,opt,name=estimatedDrainSeconds,proto3" json:"estimatedDrainSeconds,omitempty"`
+	XXX_NoUnkeyedLiteral  struct{} `json:"-"`
+	XXX_unrecognized      []byte   `json:"-"`
+	XXX_sizecache         int32    `json:"-"`
+}
+
+func (m *StatsResponse) Reset()         { *m = StatsResponse{} }
+func (m *StatsResponse) String() string { return proto.CompactTextString(m) }
+func (*StatsResponse) ProtoMessage()    {}
+func (*StatsResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{52}
+}
+func (m *StatsResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_StatsResponse.Unmarshal(m, b)
+}
+func (m *StatsResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_StatsResponse.Marshal(b, m, deterministic)
+}
+func (dst *StatsResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_StatsResponse.Merge(dst, src)
+}
+func (m *StatsResponse) XXX_Size() int {
+	return xxx_messageInfo_StatsResponse.Size(m)
+}
+func (m *StatsResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_StatsResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_StatsResponse proto.InternalMessageInfo
+
+func (m *StatsResponse) GetPending() []*PendingCount {
+	if m != nil {
+		return m.Pending
+	}
+	return nil
+}
+
+func (m *StatsResponse) GetQueued() uint64 {
+	if m != nil {
+		return m.Queued
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetProcessing() uint64 {
+	if m != nil {
+		return m.Processing
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetHighPriority() uint64 {
+	if m != nil {
+		return m.HighPriority
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetLowPriority() uint64 {
+	if m != nil {
+		return m.LowPriority
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetEnqueued() uint64 {
+	if m != nil {
+		return m.Enqueued
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetAcknowledged() uint64 {
+	if m != nil {
+		return m.Acknowledged
+	}
+	return 0
+}
+
+func (m *StatsResponse) GetEstimatedDrainSeconds() float64 {
+	if m != nil {
+		return m.EstimatedDrainSeconds
+	}
+	return 0
+}
+
+// Removes every waiting and processing message of a queue. Only admins can purge.
+type PurgeRequest struct {
+	// The queue to purge, the default queue if empty
+	QueueName            string   `protobuf:"bytes,1,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PurgeRequest) Reset()         { *m = PurgeRequest{} }
+func (m *PurgeRequest) String() string { return proto.CompactTextString(m) }
+func (*PurgeRequest) ProtoMessage()    {}
+func (*PurgeRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{53}
+}
+func (m *PurgeRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PurgeRequest.Unmarshal(m, b)
+}
+func (m *PurgeRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PurgeRequest.Marshal(b, m, deterministic)
+}
+func (dst *PurgeRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PurgeRequest.Merge(dst, src)
+}
+func (m *PurgeRequest) XXX_Size() int {
+	return xxx_messageInfo_PurgeRequest.Size(m)
+}
+func (m *PurgeRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_PurgeRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PurgeRequest proto.InternalMessageInfo
+
+func (m *PurgeRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+type PurgeResponse struct {
+	// How many messages were removed
+	Removed              uint64   `protobuf:"varint,1,opt,name=removed,proto3" json:"removed,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *PurgeResponse) Reset()         { *m = PurgeResponse{} }
+func (m *PurgeResponse) String() string { return proto.CompactTextString(m) }
+func (*PurgeResponse) ProtoMessage()    {}
+func (*PurgeResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{54}
+}
+func (m *PurgeResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_PurgeResponse.Unmarshal(m, b)
+}
+func (m *PurgeResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_PurgeResponse.Marshal(b, m, deterministic)
+}
+func (dst *PurgeResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_PurgeResponse.Merge(dst, src)
+}
+func (m *PurgeResponse) XXX_Size() int {
+	return xxx_messageInfo_PurgeResponse.Size(m)
+}
+func (m *PurgeResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_PurgeResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_PurgeResponse proto.InternalMessageInfo
+
+func (m *PurgeResponse) GetRemoved() uint64 {
+	if m != nil {
+		return m.Removed
+	}
+	return 0
+}
+
+// Exports the dead letters of a queue as json, one message per line, for offline analysis.
+// Only admins can export dead letters.
+type ExportDeadLettersRequest struct {
+	// The queue to export the dead letters of, the default queue if empty
+	QueueName string `protobuf:"bytes,1,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	// If set the exported messages are removed from the dead letters
+	Clear                bool     `protobuf:"varint,2,opt,name=clear,proto3" json:"clear,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ExportDeadLettersRequest) Reset()         { *m = ExportDeadLettersRequest{} }
+func (m *ExportDeadLettersRequest) String() string { return proto.CompactTextString(m) }
+func (*ExportDeadLettersRequest) ProtoMessage()    {}
+func (*ExportDeadLettersRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{55}
+}
+func (m *ExportDeadLettersRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ExportDeadLettersRequest.Unmarshal(m, b)
+}
+func (m *ExportDeadLettersRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ExportDeadLettersRequest.Marshal(b, m, deterministic)
+}
+func (dst *ExportDeadLettersRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ExportDeadLettersRequest.Merge(dst, src)
+}
+func (m *ExportDeadLettersRequest) XXX_Size() int {
+	return xxx_messageInfo_ExportDeadLettersRequest.Size(m)
+}
+func (m *ExportDeadLettersRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ExportDeadLettersRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ExportDeadLettersRequest proto.InternalMessageInfo
+
+func (m *ExportDeadLettersRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+func (m *ExportDeadLettersRequest) GetClear() bool {
+	if m != nil {
+		return m.Clear
+	}
+	return false
+}
+
+type ExportDeadLettersResponse struct {
+	// How many dead letters were exported
+	Exported uint64 `protobuf:"varint,1,opt,name=exported,proto3" json:"exported,omitempty"`
+	// The dead letters with their attempts, as json objects separated by newlines
+	Json                 string   `protobuf:"bytes,2,opt,name=json,proto3" json:"json,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ExportDeadLettersResponse) Reset()         { *m = ExportDeadLettersResponse{} }
+func (m *ExportDeadLettersResponse) String() string { return proto.CompactTextString(m) }
+func (*ExportDeadLettersResponse) ProtoMessage()    {}
+func (*ExportDeadLettersResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{56}
+}
+func (m *ExportDeadLettersResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ExportDeadLettersResponse.Unmarshal(m, b)
+}
+func (m *ExportDeadLettersResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ExportDeadLettersResponse.Marshal(b, m, deterministic)
+}
+func (dst *ExportDeadLettersResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ExportDeadLettersResponse.Merge(dst, src)
+}
+func (m *ExportDeadLettersResponse) XXX_Size() int {
+	return xxx_messageInfo_ExportDeadLettersResponse.Size(m)
+}
+func (m *ExportDeadLettersResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ExportDeadLettersResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ExportDeadLettersResponse proto.InternalMessageInfo
+
+func (m *ExportDeadLettersResponse) GetExported() uint64 {
+	if m != nil {
+		return m.Exported
+	}
+	return 0
+}
+
+func (m *ExportDeadLettersResponse) GetJson() string {
+	if m != nil {
+		return m.Json
+	}
+	return ""
+}
+
+// Streams what happens to the messages of a queue to the connection, for monitoring.
+// The events are sent as event responses with the ref id of the request, until events
+// are subscribed to again with a prefetch of 0, or the connection is closed.
+// Only admins can subscribe to events.
+type EventSubscribeRequest struct {
+	// The queue to watch, the default queue if empty
+	QueueName string `protobuf:"bytes,1,opt,name=queueName,proto3" json:"queueName,omitempty"`
+	// How many events can wait to be sent to the connection. Events past that are dropped,
+	// so a connection that falls behind doesn't hold up the queue.
+	Prefetch             uint32   `protobuf:"varint,2,opt,name=prefetch,proto3" json:"prefetch,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *EventSubscribeRequest) Reset()         { *m = EventSubscribeRequest{} }
+func (m *EventSubscribeRequest) String() string { return proto.CompactTextString(m) }
+func (*EventSubscribeRequest) ProtoMessage()    {}
+func (*EventSubscribeRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{57}
+}
+func (m *EventSubscribeRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EventSubscribeRequest.Unmarshal(m, b)
+}
+func (m *EventSubscribeRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EventSubscribeRequest.Marshal(b, m, deterministic)
+}
+func (dst *EventSubscribeRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EventSubscribeRequest.Merge(dst, src)
+}
+func (m *EventSubscribeRequest) XXX_Size() int {
+	return xxx_messageInfo_EventSubscribeRequest.Size(m)
+}
+func (m *EventSubscribeRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_EventSubscribeRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_EventSubscribeRequest proto.InternalMessageInfo
+
+func (m *EventSubscribeRequest) GetQueueName() string {
+	if m != nil {
+		return m.QueueName
+	}
+	return ""
+}
+
+func (m *EventSubscribeRequest) GetPrefetch() uint32 {
+	if m != nil {
+		return m.Prefetch
+	}
+	return 0
+}
+
+type EventSubscribeResponse struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *EventSubscribeResponse) Reset()         { *m = EventSubscribeResponse{} }
+func (m *EventSubscribeResponse) String() string { return proto.CompactTextString(m) }
+func (*EventSubscribeResponse) ProtoMessage()    {}
+func (*EventSubscribeResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{58}
+}
+func (m *EventSubscribeResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_EventSubscribeResponse.Unmarshal(m, b)
+}
+func (m *EventSubscribeResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_EventSubscribeResponse.Marshal(b, m, deterministic)
+}
+func (dst *EventSubscribeResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_EventSubscribeResponse.Merge(dst, src)
+}
+func (m *EventSubscribeResponse) XXX_Size() int {
+	return xxx_messageInfo_EventSubscribeResponse.Size(m)
+}
+func (m *EventSubscribeResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_EventSubscribeResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_EventSubscribeResponse proto.InternalMessageInfo
+
+type QueueEventResponse struct {
+	Kind EventKind `protobuf:"varint,1,opt,name=kind,proto3,enum=EventKind" json:"kind,omitempty"`
+	// The id of the message the event happened to
+	Id string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`
+	// The priority and capabilities are only known for enqueued, popped and dead lettered events
+	HasPriority          bool     `protobuf:"varint,3,opt,name=hasPriority,proto3" json:"hasPriority,omitempty"`
+	PriorityLevel        uint32   `protobuf:"varint,4,opt,name=priorityLevel,proto3" json:"priorityLevel,omitempty"`
+	RequiredCapabilities []string `protobuf:"bytes,5,rep,name=requiredCapabilities,proto3" json:"requiredCapabilities,omitempty"`
+	// When the event happened, in milliseconds since the unix epoch
+	TimestampMilliseconds uint64 `protobuf:"varint,6,opt,name=timestampMilliseconds,proto3" json:"timestampMilliseconds,omitempty"`
+	// How many events were dropped right before this one, because the connection fell behind
+	MissedEvents         uint64   `protobuf:"varint,7,opt,name=missedEvents,proto3" json:"missedEvents,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *QueueEventResponse) Reset()         { *m = QueueEventResponse{} }
+func (m *QueueEventResponse) String() string { return proto.CompactTextString(m) }
+func (*QueueEventResponse) ProtoMessage()    {}
+func (*QueueEventResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{59}
+}
+func (m *QueueEventResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_QueueEventResponse.Unmarshal(m, b)
+}
+func (m *QueueEventResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_QueueEventResponse.Marshal(b, m, deterministic)
+}
+func (dst *QueueEventResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_QueueEventResponse.Merge(dst, src)
+}
+func (m *QueueEventResponse) XXX_Size() int {
+	return xxx_messageInfo_QueueEventResponse.Size(m)
+}
+func (m *QueueEventResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_QueueEventResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_QueueEventResponse proto.InternalMessageInfo
+
+func (m *QueueEventResponse) GetKind() EventKind {
+	if m != nil {
+		return m.Kind
+	}
+	return EventKind_ENQUEUED
+}
+
+func (m *QueueEventResponse) GetId() string {
+	if m != nil {
+		return m.Id
+	}
+	return ""
+}
+
+func (m *QueueEventResponse) GetHasPriority() bool {
+	if m != nil {
+		return m.HasPriority
+	}
+	return false
+}
+
+func (m *QueueEventResponse) GetPriorityLevel() uint32 {
+	if m != nil {
+		return m.PriorityLevel
+	}
+	return 0
+}
+
+func (m *QueueEventResponse) GetRequiredCapabilities() []string {
+	if m != nil {
+		return m.RequiredCapabilities
+	}
+	return nil
+}
+
+func (m *QueueEventResponse) GetTimestampMilliseconds() uint64 {
+	if m != nil {
+		return m.TimestampMilliseconds
+	}
+	return 0
+}
+
+func (m *QueueEventResponse) GetMissedEvents() uint64 {
+	if m != nil {
+		return m.MissedEvents
+	}
+	return 0
+}
+
+// Gets information about the running server
+type ServerInfoRequest struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ServerInfoRequest) Reset()         { *m = ServerInfoRequest{} }
+func (m *ServerInfoRequest) String() string { return proto.CompactTextString(m) }
+func (*ServerInfoRequest) ProtoMessage()    {}
+func (*ServerInfoRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{60}
+}
+func (m *ServerInfoRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ServerInfoRequest.Unmarshal(m, b)
+}
+func (m *ServerInfoRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ServerInfoRequest.Marshal(b, m, deterministic)
+}
+func (dst *ServerInfoRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ServerInfoRequest.Merge(dst, src)
+}
+func (m *ServerInfoRequest) XXX_Size() int {
+	return xxx_messageInfo_ServerInfoRequest.Size(m)
+}
+func (m *ServerInfoRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_ServerInfoRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ServerInfoRequest proto.InternalMessageInfo
+
+type ServerInfoResponse struct {
+	// How long the server has been running in milliseconds
+	UptimeMilliseconds uint64 `protobuf:"varint,1,opt,name=uptimeMilliseconds,proto3" json:"uptimeMilliseconds,omitempty"`
+	// The version of the server
+	Version string `protobuf:"bytes,2,opt,name=version,proto3" json:"version,omitempty"`
+	// The commit the server was built from, empty if unknown
+	GitCommit string `protobuf:"bytes,3,opt,name=gitCommit,proto3" json:"gitCommit,omitempty"`
+	// The current size of the storage files, for alerting when garbage collection
+	// isn't keeping up. Not set if the sizes couldn't be read.
+	StorageFileSizes *StorageFileSizes `protobuf:"bytes,4,opt,name=storageFileSizes,proto3" json:"storageFileSizes,omitempty"`
+	// The names of the priority levels messages can be enqueued with, from level 0 up.
+	// Enqueuing with a higher level is rejected.
+	PriorityBands []string `protobuf:"bytes,5,rep,name=priorityBands,proto3" json:"priorityBands,omitempty"`
+	// The priority level of messages enqueued without a priority
+	DefaultPriorityLevel uint32   `protobuf:"varint,6,opt,name=defaultPriorityLevel,proto3" json:"defaultPriorityLevel,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ServerInfoResponse) Reset()         { *m = ServerInfoResponse{} }
+func (m *ServerInfoResponse) String() string { return proto.CompactTextString(m) }
+func (*ServerInfoResponse) ProtoMessage()    {}
+func (*ServerInfoResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{61}
+}
+func (m *ServerInfoResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ServerInfoResponse.Unmarshal(m, b)
+}
+func (m *ServerInfoResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ServerInfoResponse.Marshal(b, m, deterministic)
+}
+func (dst *ServerInfoResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ServerInfoResponse.Merge(dst, src)
+}
+func (m *ServerInfoResponse) XXX_Size() int {
+	return xxx_messageInfo_ServerInfoResponse.Size(m)
+}
+func (m *ServerInfoResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ServerInfoResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ServerInfoResponse proto.InternalMessageInfo
+
+func (m *ServerInfoResponse) GetUptimeMilliseconds() uint64 {
+	if m != nil {
+		return m.UptimeMilliseconds
+	}
+	return 0
+}
+
+func (m *ServerInfoResponse) GetVersion() string {
+	if m != nil {
+		return m.Version
+	}
+	return ""
+}
+
+func (m *ServerInfoResponse) GetGitCommit() string {
+	if m != nil {
+		return m.GitCommit
+	}
+	return ""
+}
+
+func (m *ServerInfoResponse) GetStorageFileSizes() *StorageFileSizes {
+	if m != nil {
+		return m.StorageFileSizes
+	}
+	return nil
+}
+
+func (m *ServerInfoResponse) GetPriorityBands() []string {
+	if m != nil {
+		return m.PriorityBands
+	}
+	return nil
+}
+
+func (m *ServerInfoResponse) GetDefaultPriorityLevel() uint32 {
+	if m != nil {
+		return m.DefaultPriorityLevel
+	}
+	return 0
+}
+
+type StorageFileSizes struct {
+	HighPriorityBytes    uint64   `protobuf:"varint,1,opt,name=highPriorityBytes,proto3" json:"highPriorityBytes,omitempty"`
+	LowPriorityBytes     uint64   `protobuf:"varint,2,opt,name=lowPriorityBytes,proto3" json:"lowPriorityBytes,omitempty"`
+	CompletedBytes       uint64   `protobuf:"varint,3,opt,name=completedBytes,proto3" json:"completedBytes,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *StorageFileSizes) Reset()         { *m = StorageFileSizes{} }
+func (m *StorageFileSizes) String() string { return proto.CompactTextString(m) }
+func (*StorageFileSizes) ProtoMessage()    {}
+func (*StorageFileSizes) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{62}
+}
+func (m *StorageFileSizes) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_StorageFileSizes.Unmarshal(m, b)
+}
+func (m *StorageFileSizes) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_StorageFileSizes.Marshal(b, m, deterministic)
+}
+func (dst *StorageFileSizes) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_StorageFileSizes.Merge(dst, src)
+}
+func (m *StorageFileSizes) XXX_Size() int {
+	return xxx_messageInfo_StorageFileSizes.Size(m)
+}
+func (m *StorageFileSizes) XXX_DiscardUnknown() {
+	xxx_messageInfo_StorageFileSizes.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_StorageFileSizes proto.InternalMessageInfo
+
+func (m *StorageFileSizes) GetHighPriorityBytes() uint64 {
+	if m != nil {
+		return m.HighPriorityBytes
+	}
+	return 0
+}
+
+func (m *StorageFileSizes) GetLowPriorityBytes() uint64 {
+	if m != nil {
+		return m.LowPriorityBytes
+	}
+	return 0
+}
+
+func (m *StorageFileSizes) GetCompletedBytes() uint64 {
+	if m != nil {
+		return m.CompletedBytes
+	}
+	return 0
+}
+
+// Gets a snapshot of the complete state of the server, for debugging.
+// Only available to admins.
+type DiagnosticDumpRequest struct {
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DiagnosticDumpRequest) Reset()         { *m = DiagnosticDumpRequest{} }
+func (m *DiagnosticDumpRequest) String() string { return proto.CompactTextString(m) }
+func (*DiagnosticDumpRequest) ProtoMessage()    {}
+func (*DiagnosticDumpRequest) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{63}
+}
+func (m *DiagnosticDumpRequest) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_DiagnosticDumpRequest.Unmarshal(m, b)
+}
+func (m *DiagnosticDumpRequest) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_DiagnosticDumpRequest.Marshal(b, m, deterministic)
+}
+func (dst *DiagnosticDumpRequest) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DiagnosticDumpRequest.Merge(dst, src)
+}
+func (m *DiagnosticDumpRequest) XXX_Size() int {
+	return xxx_messageInfo_DiagnosticDumpRequest.Size(m)
+}
+func (m *DiagnosticDumpRequest) XXX_DiscardUnknown() {
+	xxx_messageInfo_DiagnosticDumpRequest.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DiagnosticDumpRequest proto.InternalMessageInfo
+
+type DiagnosticDumpResponse struct {
+	// The snapshot serialized as json
+	Json                 string   `protobuf:"bytes,1,opt,name=json,proto3" json:"json,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *DiagnosticDumpResponse) Reset()         { *m = DiagnosticDumpResponse{} }
+func (m *DiagnosticDumpResponse) String() string { return proto.CompactTextString(m) }
+func (*DiagnosticDumpResponse) ProtoMessage()    {}
+func (*DiagnosticDumpResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{64}
+}
+func (m *DiagnosticDumpResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_DiagnosticDumpResponse.Unmarshal(m, b)
+}
+func (m *DiagnosticDumpResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_DiagnosticDumpResponse.Marshal(b, m, deterministic)
+}
+func (dst *DiagnosticDumpResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_DiagnosticDumpResponse.Merge(dst, src)
+}
+func (m *DiagnosticDumpResponse) XXX_Size() int {
+	return xxx_messageInfo_DiagnosticDumpResponse.Size(m)
+}
+func (m *DiagnosticDumpResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_DiagnosticDumpResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_DiagnosticDumpResponse proto.InternalMessageInfo
+
+func (m *DiagnosticDumpResponse) GetJson() string {
+	if m != nil {
+		return m.Json
+	}
+	return ""
+}
+
+type ErrorResponse struct {
+	// What went wrong
+	Message              string    `protobuf:"bytes,1,opt,name=message,proto3" json:"message,omitempty"`
+	Code                 ErrorCode `protobuf:"varint,2,opt,name=code,proto3,enum=ErrorCode" json:"code,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}  `json:"-"`
+	XXX_unrecognized     []byte    `json:"-"`
+	XXX_sizecache        int32     `json:"-"`
+}
+
+func (m *ErrorResponse) Reset()         { *m = ErrorResponse{} }
+func (m *ErrorResponse) String() string { return proto.CompactTextString(m) }
+func (*ErrorResponse) ProtoMessage()    {}
+func (*ErrorResponse) Descriptor() ([]byte, []int) {
+	return fileDescriptor_queue_696978ac6113b6a2, []int{65}
+}
+func (m *ErrorResponse) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ErrorResponse.Unmarshal(m, b)
+}
+func (m *ErrorResponse) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ErrorResponse.Marshal(b, m, deterministic)
+}
+func (dst *ErrorResponse) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ErrorResponse.Merge(dst, src)
+}
+func (m *ErrorResponse) XXX_Size() int {
+	return xxx_messageInfo_ErrorResponse.Size(m)
+}
+func (m *ErrorResponse) XXX_DiscardUnknown() {
+	xxx_messageInfo_ErrorResponse.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ErrorResponse proto.InternalMessageInfo
+
+func (m *ErrorResponse) GetMessage() string {
+	if m != nil {
+		return m.Message
+	}
+	return ""
+}
+
+func (m *ErrorResponse) GetCode() ErrorCode {
+	if m != nil {
+		return m.Code
+	}
+	return ErrorCode_UNKNOWN
+}
+
+// Wraps the requests for easier parsing on the socket layer
+type RequestWrapper struct {
+	RefId int32 `protobuf:"varint,10,opt,name=refId,proto3" json:"refId,omitempty"`
+	// Types that are valid to be assigned to Message:
+	//	*RequestWrapper_Enqueue
+	//	*RequestWrapper_Pop
+	//	*RequestWrapper_Acknowledge
 	//	*RequestWrapper_Authenticate
+	//	*RequestWrapper_Health
+	//	*RequestWrapper_AcknowledgeMatching
+	//	*RequestWrapper_FailWithRetag
+	//	*RequestWrapper_ServerInfo
+	//	*RequestWrapper_Fail
+	//	*RequestWrapper_DiagnosticDump
+	//	*RequestWrapper_BeginEnqueue
+	//	*RequestWrapper_EnqueueChunk
+	//	*RequestWrapper_CommitEnqueue
+	//	*RequestWrapper_CancelPop
+	//	*RequestWrapper_Cancel
+	//	*RequestWrapper_FindByTag
+	//	*RequestWrapper_ListProcessing
+	//	*RequestWrapper_SetCapabilityPaused
+	//	*RequestWrapper_Subscribe
+	//	*RequestWrapper_Reserve
+	//	*RequestWrapper_ConfirmReservation
+	//	*RequestWrapper_Decline
+	//	*RequestWrapper_EnqueueBatch
+	//	*RequestWrapper_ListQueued
+	//	*RequestWrapper_Peek
+	//	*RequestWrapper_Stats
+	//	*RequestWrapper_Purge
+	//	*RequestWrapper_EventSubscribe
+	//	*RequestWrapper_ExportDeadLetters
 	Message              isRequestWrapper_Message `protobuf_oneof:"message"`
 	XXX_NoUnkeyedLiteral struct{}                 `json:"-"`
 	XXX_unrecognized     []byte                   `json:"-"`
@@ -460,86 +3703,361 @@ func (m *RequestWrapper) Reset()         { *m = RequestWrapper{} }
 func (m *RequestWrapper) String() string { return proto.CompactTextString(m) }
 func (*RequestWrapper) ProtoMessage()    {}
 func (*RequestWrapper) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{9}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{66}
 }
 func (m *RequestWrapper) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_RequestWrapper.Unmarshal(m, b)
 }
-func (m *RequestWrapper) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_RequestWrapper.Marshal(b, m, deterministic)
+func (m *RequestWrapper) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_RequestWrapper.Marshal(b, m, deterministic)
+}
+func (dst *RequestWrapper) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RequestWrapper.Merge(dst, src)
+}
+func (m *RequestWrapper) XXX_Size() int {
+	return xxx_messageInfo_RequestWrapper.Size(m)
+}
+func (m *RequestWrapper) XXX_DiscardUnknown() {
+	xxx_messageInfo_RequestWrapper.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RequestWrapper proto.InternalMessageInfo
+
+type isRequestWrapper_Message interface {
+	isRequestWrapper_Message()
+}
+
+type RequestWrapper_Enqueue struct {
+	Enqueue *EnqueueRequest `protobuf:"bytes,1,opt,name=enqueue,proto3,oneof"`
+}
+type RequestWrapper_Pop struct {
+	Pop *PopRequest `protobuf:"bytes,2,opt,name=pop,proto3,oneof"`
+}
+type RequestWrapper_Acknowledge struct {
+	Acknowledge *AcknowledgeRequest `protobuf:"bytes,3,opt,name=acknowledge,proto3,oneof"`
+}
+type RequestWrapper_Authenticate struct {
+	Authenticate *AuthenticateRequest `protobuf:"bytes,6,opt,name=authenticate,proto3,oneof"`
+}
+type RequestWrapper_Health struct {
+	Health *HealthRequest `protobuf:"bytes,7,opt,name=health,proto3,oneof"`
+}
+type RequestWrapper_AcknowledgeMatching struct {
+	AcknowledgeMatching *AcknowledgeMatchingRequest `protobuf:"bytes,8,opt,name=acknowledgeMatching,proto3,oneof"`
+}
+type RequestWrapper_FailWithRetag struct {
+	FailWithRetag *FailWithRetagRequest `protobuf:"bytes,9,opt,name=failWithRetag,proto3,oneof"`
+}
+type RequestWrapper_ServerInfo struct {
+	ServerInfo *ServerInfoRequest `protobuf:"bytes,11,opt,name=serverInfo,proto3,oneof"`
+}
+type RequestWrapper_Fail struct {
+	Fail *FailRequest `protobuf:"bytes,12,opt,name=fail,proto3,oneof"`
+}
+type RequestWrapper_DiagnosticDump struct {
+	DiagnosticDump *DiagnosticDumpRequest `protobuf:"bytes,13,opt,name=diagnosticDump,proto3,oneof"`
+}
+type RequestWrapper_BeginEnqueue struct {
+	BeginEnqueue *BeginEnqueueRequest `protobuf:"bytes,14,opt,name=beginEnqueue,proto3,oneof"`
+}
+type RequestWrapper_EnqueueChunk struct {
+	EnqueueChunk *EnqueueChunkRequest `protobuf:"bytes,15,opt,name=enqueueChunk,proto3,oneof"`
+}
+type RequestWrapper_CommitEnqueue struct {
+	CommitEnqueue *CommitEnqueueRequest `protobuf:"bytes,16,opt,name=commitEnqueue,proto3,oneof"`
+}
+type RequestWrapper_CancelPop struct {
+	CancelPop *CancelPopRequest `protobuf:"bytes,17,opt,name=cancelPop,proto3,oneof"`
+}
+type RequestWrapper_Cancel struct {
+	Cancel *CancelRequest `protobuf:"bytes,18,opt,name=cancel,proto3,oneof"`
+}
+type RequestWrapper_FindByTag struct {
+	FindByTag *FindByTagRequest `protobuf:"bytes,19,opt,name=findByTag,proto3,oneof"`
+}
+type RequestWrapper_ListProcessing struct {
+	ListProcessing *ListProcessingRequest `protobuf:"bytes,20,opt,name=listProcessing,proto3,oneof"`
+}
+type RequestWrapper_SetCapabilityPaused struct {
+	SetCapabilityPaused *SetCapabilityPausedRequest `protobuf:"bytes,21,opt,name=setCapabilityPaused,proto3,oneof"`
+}
+type RequestWrapper_Subscribe struct {
+	Subscribe *SubscribeRequest `protobuf:"bytes,22,opt,name=subscribe,proto3,oneof"`
+}
+type RequestWrapper_Reserve struct {
+	Reserve *ReserveRequest `protobuf:"bytes,23,opt,name=reserve,proto3,oneof"`
+}
+type RequestWrapper_ConfirmReservation struct {
+	ConfirmReservation *ConfirmReservationRequest `protobuf:"bytes,24,opt,name=confirmReservation,proto3,oneof"`
+}
+type RequestWrapper_Decline struct {
+	Decline *DeclineRequest `protobuf:"bytes,25,opt,name=decline,proto3,oneof"`
+}
+type RequestWrapper_EnqueueBatch struct {
+	EnqueueBatch *EnqueueBatchRequest `protobuf:"bytes,26,opt,name=enqueueBatch,proto3,oneof"`
+}
+type RequestWrapper_ListQueued struct {
+	ListQueued *ListQueuedRequest `protobuf:"bytes,27,opt,name=listQueued,proto3,oneof"`
+}
+type RequestWrapper_Peek struct {
+	Peek *PeekRequest `protobuf:"bytes,28,opt,name=peek,proto3,oneof"`
+}
+type RequestWrapper_Stats struct {
+	Stats *StatsRequest `protobuf:"bytes,29,opt,name=stats,proto3,oneof"`
+}
+type RequestWrapper_Purge struct {
+	Purge *PurgeRequest `protobuf:"bytes,30,opt,name=purge,proto3,oneof"`
+}
+type RequestWrapper_EventSubscribe struct {
+	EventSubscribe *EventSubscribeRequest `protobuf:"bytes,31,opt,name=eventSubscribe,proto3,oneof"`
+}
+type RequestWrapper_ExportDeadLetters struct {
+	ExportDeadLetters *ExportDeadLettersRequest `protobuf:"bytes,32,opt,name=exportDeadLetters,proto3,oneof"`
+}
+
+func (*RequestWrapper_Enqueue) isRequestWrapper_Message()             {}
+func (*RequestWrapper_Pop) isRequestWrapper_Message()                 {}
+func (*RequestWrapper_Acknowledge) isRequestWrapper_Message()         {}
+func (*RequestWrapper_Authenticate) isRequestWrapper_Message()        {}
+func (*RequestWrapper_Health) isRequestWrapper_Message()              {}
+func (*RequestWrapper_AcknowledgeMatching) isRequestWrapper_Message() {}
+func (*RequestWrapper_FailWithRetag) isRequestWrapper_Message()       {}
+func (*RequestWrapper_ServerInfo) isRequestWrapper_Message()          {}
+func (*RequestWrapper_Fail) isRequestWrapper_Message()                {}
+func (*RequestWrapper_DiagnosticDump) isRequestWrapper_Message()      {}
+func (*RequestWrapper_BeginEnqueue) isRequestWrapper_Message()        {}
+func (*RequestWrapper_EnqueueChunk) isRequestWrapper_Message()        {}
+func (*RequestWrapper_CommitEnqueue) isRequestWrapper_Message()       {}
+func (*RequestWrapper_CancelPop) isRequestWrapper_Message()           {}
+func (*RequestWrapper_Cancel) isRequestWrapper_Message()              {}
+func (*RequestWrapper_FindByTag) isRequestWrapper_Message()           {}
+func (*RequestWrapper_ListProcessing) isRequestWrapper_Message()      {}
+func (*RequestWrapper_SetCapabilityPaused) isRequestWrapper_Message() {}
+func (*RequestWrapper_Subscribe) isRequestWrapper_Message()           {}
+func (*RequestWrapper_Reserve) isRequestWrapper_Message()             {}
+func (*RequestWrapper_ConfirmReservation) isRequestWrapper_Message()  {}
+func (*RequestWrapper_Decline) isRequestWrapper_Message()             {}
+func (*RequestWrapper_EnqueueBatch) isRequestWrapper_Message()        {}
+func (*RequestWrapper_ListQueued) isRequestWrapper_Message()          {}
+func (*RequestWrapper_Peek) isRequestWrapper_Message()                {}
+func (*RequestWrapper_Stats) isRequestWrapper_Message()               {}
+func (*RequestWrapper_Purge) isRequestWrapper_Message()               {}
+func (*RequestWrapper_EventSubscribe) isRequestWrapper_Message()      {}
+func (*RequestWrapper_ExportDeadLetters) isRequestWrapper_Message()   {}
+
+func (m *RequestWrapper) GetMessage() isRequestWrapper_Message {
+	if m != nil {
+		return m.Message
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetRefId() int32 {
+	if m != nil {
+		return m.RefId
+	}
+	return 0
+}
+
+func (m *RequestWrapper) GetEnqueue() *EnqueueRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Enqueue); ok {
+		return x.Enqueue
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetPop() *PopRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Pop); ok {
+		return x.Pop
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetAcknowledge() *AcknowledgeRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Acknowledge); ok {
+		return x.Acknowledge
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetAuthenticate() *AuthenticateRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Authenticate); ok {
+		return x.Authenticate
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetHealth() *HealthRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Health); ok {
+		return x.Health
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetAcknowledgeMatching() *AcknowledgeMatchingRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_AcknowledgeMatching); ok {
+		return x.AcknowledgeMatching
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetFailWithRetag() *FailWithRetagRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_FailWithRetag); ok {
+		return x.FailWithRetag
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetServerInfo() *ServerInfoRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_ServerInfo); ok {
+		return x.ServerInfo
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetFail() *FailRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Fail); ok {
+		return x.Fail
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetDiagnosticDump() *DiagnosticDumpRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_DiagnosticDump); ok {
+		return x.DiagnosticDump
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetBeginEnqueue() *BeginEnqueueRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_BeginEnqueue); ok {
+		return x.BeginEnqueue
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetEnqueueChunk() *EnqueueChunkRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_EnqueueChunk); ok {
+		return x.EnqueueChunk
+	}
+	return nil
+}
+
+func (m *RequestWrapper) GetCommitEnqueue() *CommitEnqueueRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_CommitEnqueue); ok {
+		return x.CommitEnqueue
+	}
+	return nil
 }
-func (dst *RequestWrapper) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_RequestWrapper.Merge(dst, src)
+
+func (m *RequestWrapper) GetCancelPop() *CancelPopRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_CancelPop); ok {
+		return x.CancelPop
+	}
+	return nil
 }
-func (m *RequestWrapper) XXX_Size() int {
-	return xxx_messageInfo_RequestWrapper.Size(m)
+
+func (m *RequestWrapper) GetCancel() *CancelRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Cancel); ok {
+		return x.Cancel
+	}
+	return nil
 }
-func (m *RequestWrapper) XXX_DiscardUnknown() {
-	xxx_messageInfo_RequestWrapper.DiscardUnknown(m)
+
+func (m *RequestWrapper) GetFindByTag() *FindByTagRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_FindByTag); ok {
+		return x.FindByTag
+	}
+	return nil
 }
 
-var xxx_messageInfo_RequestWrapper proto.InternalMessageInfo
+func (m *RequestWrapper) GetListProcessing() *ListProcessingRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_ListProcessing); ok {
+		return x.ListProcessing
+	}
+	return nil
+}
 
-type isRequestWrapper_Message interface {
-	isRequestWrapper_Message()
+func (m *RequestWrapper) GetSetCapabilityPaused() *SetCapabilityPausedRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_SetCapabilityPaused); ok {
+		return x.SetCapabilityPaused
+	}
+	return nil
 }
 
-type RequestWrapper_Enqueue struct {
-	Enqueue *EnqueueRequest `protobuf:"bytes,1,opt,name=enqueue,proto3,oneof"`
+func (m *RequestWrapper) GetSubscribe() *SubscribeRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Subscribe); ok {
+		return x.Subscribe
+	}
+	return nil
 }
-type RequestWrapper_Pop struct {
-	Pop *PopRequest `protobuf:"bytes,2,opt,name=pop,proto3,oneof"`
+
+func (m *RequestWrapper) GetReserve() *ReserveRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Reserve); ok {
+		return x.Reserve
+	}
+	return nil
 }
-type RequestWrapper_Acknowledge struct {
-	Acknowledge *AcknowledgeRequest `protobuf:"bytes,3,opt,name=acknowledge,proto3,oneof"`
+
+func (m *RequestWrapper) GetConfirmReservation() *ConfirmReservationRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_ConfirmReservation); ok {
+		return x.ConfirmReservation
+	}
+	return nil
 }
-type RequestWrapper_Authenticate struct {
-	Authenticate *AuthenticateRequest `protobuf:"bytes,6,opt,name=authenticate,proto3,oneof"`
+
+func (m *RequestWrapper) GetDecline() *DeclineRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Decline); ok {
+		return x.Decline
+	}
+	return nil
 }
 
-func (*RequestWrapper_Enqueue) isRequestWrapper_Message()      {}
-func (*RequestWrapper_Pop) isRequestWrapper_Message()          {}
-func (*RequestWrapper_Acknowledge) isRequestWrapper_Message()  {}
-func (*RequestWrapper_Authenticate) isRequestWrapper_Message() {}
+func (m *RequestWrapper) GetEnqueueBatch() *EnqueueBatchRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_EnqueueBatch); ok {
+		return x.EnqueueBatch
+	}
+	return nil
+}
 
-func (m *RequestWrapper) GetMessage() isRequestWrapper_Message {
-	if m != nil {
-		return m.Message
+func (m *RequestWrapper) GetListQueued() *ListQueuedRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_ListQueued); ok {
+		return x.ListQueued
 	}
 	return nil
 }
 
-func (m *RequestWrapper) GetRefId() int32 {
-	if m != nil {
-		return m.RefId
+func (m *RequestWrapper) GetPeek() *PeekRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Peek); ok {
+		return x.Peek
 	}
-	return 0
+	return nil
 }
 
-func (m *RequestWrapper) GetEnqueue() *EnqueueRequest {
-	if x, ok := m.GetMessage().(*RequestWrapper_Enqueue); ok {
-		return x.Enqueue
+func (m *RequestWrapper) GetStats() *StatsRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Stats); ok {
+		return x.Stats
 	}
 	return nil
 }
 
-func (m *RequestWrapper) GetPop() *PopRequest {
-	if x, ok := m.GetMessage().(*RequestWrapper_Pop); ok {
-		return x.Pop
+func (m *RequestWrapper) GetPurge() *PurgeRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_Purge); ok {
+		return x.Purge
 	}
 	return nil
 }
 
-func (m *RequestWrapper) GetAcknowledge() *AcknowledgeRequest {
-	if x, ok := m.GetMessage().(*RequestWrapper_Acknowledge); ok {
-		return x.Acknowledge
+func (m *RequestWrapper) GetEventSubscribe() *EventSubscribeRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_EventSubscribe); ok {
+		return x.EventSubscribe
 	}
 	return nil
 }
 
-func (m *RequestWrapper) GetAuthenticate() *AuthenticateRequest {
-	if x, ok := m.GetMessage().(*RequestWrapper_Authenticate); ok {
-		return x.Authenticate
+func (m *RequestWrapper) GetExportDeadLetters() *ExportDeadLettersRequest {
+	if x, ok := m.GetMessage().(*RequestWrapper_ExportDeadLetters); ok {
+		return x.ExportDeadLetters
 	}
 	return nil
 }
@@ -551,6 +4069,31 @@ func (*RequestWrapper) XXX_OneofFuncs() (func(msg proto.Message, b *proto.Buffer
 		(*RequestWrapper_Pop)(nil),
 		(*RequestWrapper_Acknowledge)(nil),
 		(*RequestWrapper_Authenticate)(nil),
+		(*RequestWrapper_Health)(nil),
+		(*RequestWrapper_AcknowledgeMatching)(nil),
+		(*RequestWrapper_FailWithRetag)(nil),
+		(*RequestWrapper_ServerInfo)(nil),
+		(*RequestWrapper_Fail)(nil),
+		(*RequestWrapper_DiagnosticDump)(nil),
+		(*RequestWrapper_BeginEnqueue)(nil),
+		(*RequestWrapper_EnqueueChunk)(nil),
+		(*RequestWrapper_CommitEnqueue)(nil),
+		(*RequestWrapper_CancelPop)(nil),
+		(*RequestWrapper_Cancel)(nil),
+		(*RequestWrapper_FindByTag)(nil),
+		(*RequestWrapper_ListProcessing)(nil),
+		(*RequestWrapper_SetCapabilityPaused)(nil),
+		(*RequestWrapper_Subscribe)(nil),
+		(*RequestWrapper_Reserve)(nil),
+		(*RequestWrapper_ConfirmReservation)(nil),
+		(*RequestWrapper_Decline)(nil),
+		(*RequestWrapper_EnqueueBatch)(nil),
+		(*RequestWrapper_ListQueued)(nil),
+		(*RequestWrapper_Peek)(nil),
+		(*RequestWrapper_Stats)(nil),
+		(*RequestWrapper_Purge)(nil),
+		(*RequestWrapper_EventSubscribe)(nil),
+		(*RequestWrapper_ExportDeadLetters)(nil),
 	}
 }
 
@@ -563,62 +4106,387 @@ func _RequestWrapper_OneofMarshaler(msg proto.Message, b *proto.Buffer) error {
 		if err := b.EncodeMessage(x.Enqueue); err != nil {
 			return err
 		}
-	case *RequestWrapper_Pop:
-		b.EncodeVarint(2<<3 | proto.WireBytes)
-		if err := b.EncodeMessage(x.Pop); err != nil {
-			return err
+	case *RequestWrapper_Pop:
+		b.EncodeVarint(2<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Pop); err != nil {
+			return err
+		}
+	case *RequestWrapper_Acknowledge:
+		b.EncodeVarint(3<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Acknowledge); err != nil {
+			return err
+		}
+	case *RequestWrapper_Authenticate:
+		b.EncodeVarint(6<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Authenticate); err != nil {
+			return err
+		}
+	case *RequestWrapper_Health:
+		b.EncodeVarint(7<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Health); err != nil {
+			return err
+		}
+	case *RequestWrapper_AcknowledgeMatching:
+		b.EncodeVarint(8<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.AcknowledgeMatching); err != nil {
+			return err
+		}
+	case *RequestWrapper_FailWithRetag:
+		b.EncodeVarint(9<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.FailWithRetag); err != nil {
+			return err
+		}
+	case *RequestWrapper_ServerInfo:
+		b.EncodeVarint(11<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ServerInfo); err != nil {
+			return err
+		}
+	case *RequestWrapper_Fail:
+		b.EncodeVarint(12<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Fail); err != nil {
+			return err
+		}
+	case *RequestWrapper_DiagnosticDump:
+		b.EncodeVarint(13<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.DiagnosticDump); err != nil {
+			return err
+		}
+	case *RequestWrapper_BeginEnqueue:
+		b.EncodeVarint(14<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.BeginEnqueue); err != nil {
+			return err
+		}
+	case *RequestWrapper_EnqueueChunk:
+		b.EncodeVarint(15<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EnqueueChunk); err != nil {
+			return err
+		}
+	case *RequestWrapper_CommitEnqueue:
+		b.EncodeVarint(16<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.CommitEnqueue); err != nil {
+			return err
+		}
+	case *RequestWrapper_CancelPop:
+		b.EncodeVarint(17<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.CancelPop); err != nil {
+			return err
+		}
+	case *RequestWrapper_Cancel:
+		b.EncodeVarint(18<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Cancel); err != nil {
+			return err
+		}
+	case *RequestWrapper_FindByTag:
+		b.EncodeVarint(19<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.FindByTag); err != nil {
+			return err
+		}
+	case *RequestWrapper_ListProcessing:
+		b.EncodeVarint(20<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ListProcessing); err != nil {
+			return err
+		}
+	case *RequestWrapper_SetCapabilityPaused:
+		b.EncodeVarint(21<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.SetCapabilityPaused); err != nil {
+			return err
+		}
+	case *RequestWrapper_Subscribe:
+		b.EncodeVarint(22<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Subscribe); err != nil {
+			return err
+		}
+	case *RequestWrapper_Reserve:
+		b.EncodeVarint(23<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Reserve); err != nil {
+			return err
+		}
+	case *RequestWrapper_ConfirmReservation:
+		b.EncodeVarint(24<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ConfirmReservation); err != nil {
+			return err
+		}
+	case *RequestWrapper_Decline:
+		b.EncodeVarint(25<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Decline); err != nil {
+			return err
+		}
+	case *RequestWrapper_EnqueueBatch:
+		b.EncodeVarint(26<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EnqueueBatch); err != nil {
+			return err
+		}
+	case *RequestWrapper_ListQueued:
+		b.EncodeVarint(27<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ListQueued); err != nil {
+			return err
+		}
+	case *RequestWrapper_Peek:
+		b.EncodeVarint(28<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Peek); err != nil {
+			return err
+		}
+	case *RequestWrapper_Stats:
+		b.EncodeVarint(29<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Stats); err != nil {
+			return err
+		}
+	case *RequestWrapper_Purge:
+		b.EncodeVarint(30<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Purge); err != nil {
+			return err
+		}
+	case *RequestWrapper_EventSubscribe:
+		b.EncodeVarint(31<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EventSubscribe); err != nil {
+			return err
+		}
+	case *RequestWrapper_ExportDeadLetters:
+		b.EncodeVarint(32<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ExportDeadLetters); err != nil {
+			return err
+		}
+	case nil:
+	default:
+		return fmt.Errorf("RequestWrapper.Message has unexpected type %T", x)
+	}
+	return nil
+}
+
+func _RequestWrapper_OneofUnmarshaler(msg proto.Message, tag, wire int, b *proto.Buffer) (bool, error) {
+	m := msg.(*RequestWrapper)
+	switch tag {
+	case 1: // message.enqueue
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(EnqueueRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Enqueue{msg}
+		return true, err
+	case 2: // message.pop
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(PopRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Pop{msg}
+		return true, err
+	case 3: // message.acknowledge
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(AcknowledgeRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Acknowledge{msg}
+		return true, err
+	case 6: // message.authenticate
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(AuthenticateRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Authenticate{msg}
+		return true, err
+	case 7: // message.health
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(HealthRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Health{msg}
+		return true, err
+	case 8: // message.acknowledgeMatching
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(AcknowledgeMatchingRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_AcknowledgeMatching{msg}
+		return true, err
+	case 9: // message.failWithRetag
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FailWithRetagRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_FailWithRetag{msg}
+		return true, err
+	case 11: // message.serverInfo
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ServerInfoRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_ServerInfo{msg}
+		return true, err
+	case 12: // message.fail
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FailRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Fail{msg}
+		return true, err
+	case 13: // message.diagnosticDump
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(DiagnosticDumpRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_DiagnosticDump{msg}
+		return true, err
+	case 14: // message.beginEnqueue
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(BeginEnqueueRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_BeginEnqueue{msg}
+		return true, err
+	case 15: // message.enqueueChunk
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(EnqueueChunkRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_EnqueueChunk{msg}
+		return true, err
+	case 16: // message.commitEnqueue
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CommitEnqueueRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_CommitEnqueue{msg}
+		return true, err
+	case 17: // message.cancelPop
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CancelPopRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_CancelPop{msg}
+		return true, err
+	case 18: // message.cancel
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CancelRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Cancel{msg}
+		return true, err
+	case 19: // message.findByTag
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FindByTagRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_FindByTag{msg}
+		return true, err
+	case 20: // message.listProcessing
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ListProcessingRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_ListProcessing{msg}
+		return true, err
+	case 21: // message.setCapabilityPaused
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(SetCapabilityPausedRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_SetCapabilityPaused{msg}
+		return true, err
+	case 22: // message.subscribe
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(SubscribeRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Subscribe{msg}
+		return true, err
+	case 23: // message.reserve
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ReserveRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Reserve{msg}
+		return true, err
+	case 24: // message.confirmReservation
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ConfirmReservationRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_ConfirmReservation{msg}
+		return true, err
+	case 25: // message.decline
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
 		}
-	case *RequestWrapper_Acknowledge:
-		b.EncodeVarint(3<<3 | proto.WireBytes)
-		if err := b.EncodeMessage(x.Acknowledge); err != nil {
-			return err
+		msg := new(DeclineRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_Decline{msg}
+		return true, err
+	case 26: // message.enqueueBatch
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
 		}
-	case *RequestWrapper_Authenticate:
-		b.EncodeVarint(6<<3 | proto.WireBytes)
-		if err := b.EncodeMessage(x.Authenticate); err != nil {
-			return err
+		msg := new(EnqueueBatchRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_EnqueueBatch{msg}
+		return true, err
+	case 27: // message.listQueued
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
 		}
-	case nil:
-	default:
-		return fmt.Errorf("RequestWrapper.Message has unexpected type %T", x)
-	}
-	return nil
-}
-
-func _RequestWrapper_OneofUnmarshaler(msg proto.Message, tag, wire int, b *proto.Buffer) (bool, error) {
-	m := msg.(*RequestWrapper)
-	switch tag {
-	case 1: // message.enqueue
+		msg := new(ListQueuedRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_ListQueued{msg}
+		return true, err
+	case 28: // message.peek
 		if wire != proto.WireBytes {
 			return true, proto.ErrInternalBadWireType
 		}
-		msg := new(EnqueueRequest)
+		msg := new(PeekRequest)
 		err := b.DecodeMessage(msg)
-		m.Message = &RequestWrapper_Enqueue{msg}
+		m.Message = &RequestWrapper_Peek{msg}
 		return true, err
-	case 2: // message.pop
+	case 29: // message.stats
 		if wire != proto.WireBytes {
 			return true, proto.ErrInternalBadWireType
 		}
-		msg := new(PopRequest)
+		msg := new(StatsRequest)
 		err := b.DecodeMessage(msg)
-		m.Message = &RequestWrapper_Pop{msg}
+		m.Message = &RequestWrapper_Stats{msg}
 		return true, err
-	case 3: // message.acknowledge
+	case 30: // message.purge
 		if wire != proto.WireBytes {
 			return true, proto.ErrInternalBadWireType
 		}
-		msg := new(AcknowledgeRequest)
+		msg := new(PurgeRequest)
 		err := b.DecodeMessage(msg)
-		m.Message = &RequestWrapper_Acknowledge{msg}
+		m.Message = &RequestWrapper_Purge{msg}
 		return true, err
-	case 6: // message.authenticate
+	case 31: // message.eventSubscribe
 		if wire != proto.WireBytes {
 			return true, proto.ErrInternalBadWireType
 		}
-		msg := new(AuthenticateRequest)
+		msg := new(EventSubscribeRequest)
 		err := b.DecodeMessage(msg)
-		m.Message = &RequestWrapper_Authenticate{msg}
+		m.Message = &RequestWrapper_EventSubscribe{msg}
+		return true, err
+	case 32: // message.exportDeadLetters
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ExportDeadLettersRequest)
+		err := b.DecodeMessage(msg)
+		m.Message = &RequestWrapper_ExportDeadLetters{msg}
 		return true, err
 	default:
 		return false, nil
@@ -649,6 +4517,131 @@ func _RequestWrapper_OneofSizer(msg proto.Message) (n int) {
 		n += 1 // tag and wire
 		n += proto.SizeVarint(uint64(s))
 		n += s
+	case *RequestWrapper_Health:
+		s := proto.Size(x.Health)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_AcknowledgeMatching:
+		s := proto.Size(x.AcknowledgeMatching)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_FailWithRetag:
+		s := proto.Size(x.FailWithRetag)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_ServerInfo:
+		s := proto.Size(x.ServerInfo)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Fail:
+		s := proto.Size(x.Fail)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_DiagnosticDump:
+		s := proto.Size(x.DiagnosticDump)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_BeginEnqueue:
+		s := proto.Size(x.BeginEnqueue)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_EnqueueChunk:
+		s := proto.Size(x.EnqueueChunk)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_CommitEnqueue:
+		s := proto.Size(x.CommitEnqueue)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_CancelPop:
+		s := proto.Size(x.CancelPop)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Cancel:
+		s := proto.Size(x.Cancel)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_FindByTag:
+		s := proto.Size(x.FindByTag)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_ListProcessing:
+		s := proto.Size(x.ListProcessing)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_SetCapabilityPaused:
+		s := proto.Size(x.SetCapabilityPaused)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Subscribe:
+		s := proto.Size(x.Subscribe)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Reserve:
+		s := proto.Size(x.Reserve)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_ConfirmReservation:
+		s := proto.Size(x.ConfirmReservation)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Decline:
+		s := proto.Size(x.Decline)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_EnqueueBatch:
+		s := proto.Size(x.EnqueueBatch)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_ListQueued:
+		s := proto.Size(x.ListQueued)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Peek:
+		s := proto.Size(x.Peek)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Stats:
+		s := proto.Size(x.Stats)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_Purge:
+		s := proto.Size(x.Purge)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_EventSubscribe:
+		s := proto.Size(x.EventSubscribe)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *RequestWrapper_ExportDeadLetters:
+		s := proto.Size(x.ExportDeadLetters)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
 	case nil:
 	default:
 		panic(fmt.Sprintf("proto: unexpected type %T in oneof", x))
@@ -664,6 +4657,32 @@ type ResponseWrapper struct {
 	//	*ResponseWrapper_Acknowledge
 	//	*ResponseWrapper_Error
 	//	*ResponseWrapper_Authenticate
+	//	*ResponseWrapper_Health
+	//	*ResponseWrapper_AcknowledgeMatching
+	//	*ResponseWrapper_FailWithRetag
+	//	*ResponseWrapper_ServerInfo
+	//	*ResponseWrapper_Fail
+	//	*ResponseWrapper_DiagnosticDump
+	//	*ResponseWrapper_BeginEnqueue
+	//	*ResponseWrapper_EnqueueChunk
+	//	*ResponseWrapper_CommitEnqueue
+	//	*ResponseWrapper_CancelPop
+	//	*ResponseWrapper_Cancel
+	//	*ResponseWrapper_FindByTag
+	//	*ResponseWrapper_ListProcessing
+	//	*ResponseWrapper_SetCapabilityPaused
+	//	*ResponseWrapper_Subscribe
+	//	*ResponseWrapper_Reserve
+	//	*ResponseWrapper_ConfirmReservation
+	//	*ResponseWrapper_Decline
+	//	*ResponseWrapper_EnqueueBatch
+	//	*ResponseWrapper_ListQueued
+	//	*ResponseWrapper_Peek
+	//	*ResponseWrapper_Stats
+	//	*ResponseWrapper_Purge
+	//	*ResponseWrapper_EventSubscribe
+	//	*ResponseWrapper_Event
+	//	*ResponseWrapper_ExportDeadLetters
 	Message              isResponseWrapper_Message `protobuf_oneof:"message"`
 	XXX_NoUnkeyedLiteral struct{}                  `json:"-"`
 	XXX_unrecognized     []byte                    `json:"-"`
@@ -674,7 +4693,7 @@ func (m *ResponseWrapper) Reset()         { *m = ResponseWrapper{} }
 func (m *ResponseWrapper) String() string { return proto.CompactTextString(m) }
 func (*ResponseWrapper) ProtoMessage()    {}
 func (*ResponseWrapper) Descriptor() ([]byte, []int) {
-	return fileDescriptor_queue_7ac3e76a0189e465, []int{10}
+	return fileDescriptor_queue_696978ac6113b6a2, []int{67}
 }
 func (m *ResponseWrapper) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_ResponseWrapper.Unmarshal(m, b)
@@ -713,12 +4732,116 @@ type ResponseWrapper_Error struct {
 type ResponseWrapper_Authenticate struct {
 	Authenticate *AuthenticateResponse `protobuf:"bytes,6,opt,name=authenticate,proto3,oneof"`
 }
+type ResponseWrapper_Health struct {
+	Health *HealthResponse `protobuf:"bytes,7,opt,name=health,proto3,oneof"`
+}
+type ResponseWrapper_AcknowledgeMatching struct {
+	AcknowledgeMatching *AcknowledgeMatchingResponse `protobuf:"bytes,8,opt,name=acknowledgeMatching,proto3,oneof"`
+}
+type ResponseWrapper_FailWithRetag struct {
+	FailWithRetag *FailWithRetagResponse `protobuf:"bytes,9,opt,name=failWithRetag,proto3,oneof"`
+}
+type ResponseWrapper_ServerInfo struct {
+	ServerInfo *ServerInfoResponse `protobuf:"bytes,11,opt,name=serverInfo,proto3,oneof"`
+}
+type ResponseWrapper_Fail struct {
+	Fail *FailResponse `protobuf:"bytes,12,opt,name=fail,proto3,oneof"`
+}
+type ResponseWrapper_DiagnosticDump struct {
+	DiagnosticDump *DiagnosticDumpResponse `protobuf:"bytes,13,opt,name=diagnosticDump,proto3,oneof"`
+}
+type ResponseWrapper_BeginEnqueue struct {
+	BeginEnqueue *BeginEnqueueResponse `protobuf:"bytes,14,opt,name=beginEnqueue,proto3,oneof"`
+}
+type ResponseWrapper_EnqueueChunk struct {
+	EnqueueChunk *EnqueueChunkResponse `protobuf:"bytes,15,opt,name=enqueueChunk,proto3,oneof"`
+}
+type ResponseWrapper_CommitEnqueue struct {
+	CommitEnqueue *CommitEnqueueResponse `protobuf:"bytes,16,opt,name=commitEnqueue,proto3,oneof"`
+}
+type ResponseWrapper_CancelPop struct {
+	CancelPop *CancelPopResponse `protobuf:"bytes,17,opt,name=cancelPop,proto3,oneof"`
+}
+type ResponseWrapper_Cancel struct {
+	Cancel *CancelResponse `protobuf:"bytes,18,opt,name=cancel,proto3,oneof"`
+}
+type ResponseWrapper_FindByTag struct {
+	FindByTag *FindByTagResponse `protobuf:"bytes,19,opt,name=findByTag,proto3,oneof"`
+}
+type ResponseWrapper_ListProcessing struct {
+	ListProcessing *ListProcessingResponse `protobuf:"bytes,20,opt,name=listProcessing,proto3,oneof"`
+}
+type ResponseWrapper_SetCapabilityPaused struct {
+	SetCapabilityPaused *SetCapabilityPausedResponse `protobuf:"bytes,21,opt,name=setCapabilityPaused,proto3,oneof"`
+}
+type ResponseWrapper_Subscribe struct {
+	Subscribe *SubscribeResponse `protobuf:"bytes,22,opt,name=subscribe,proto3,oneof"`
+}
+type ResponseWrapper_Reserve struct {
+	Reserve *ReserveResponse `protobuf:"bytes,23,opt,name=reserve,proto3,oneof"`
+}
+type ResponseWrapper_ConfirmReservation struct {
+	ConfirmReservation *ConfirmReservationResponse `protobuf:"bytes,24,opt,name=confirmReservation,proto3,oneof"`
+}
+type ResponseWrapper_Decline struct {
+	Decline *DeclineResponse `protobuf:"bytes,25,opt,name=decline,proto3,oneof"`
+}
+type ResponseWrapper_EnqueueBatch struct {
+	EnqueueBatch *EnqueueBatchResponse `protobuf:"bytes,26,opt,name=enqueueBatch,proto3,oneof"`
+}
+type ResponseWrapper_ListQueued struct {
+	ListQueued *ListQueuedResponse `protobuf:"bytes,27,opt,name=listQueued,proto3,oneof"`
+}
+type ResponseWrapper_Peek struct {
+	Peek *PeekResponse `protobuf:"bytes,28,opt,name=peek,proto3,oneof"`
+}
+type ResponseWrapper_Stats struct {
+	Stats *StatsResponse `protobuf:"bytes,29,opt,name=stats,proto3,oneof"`
+}
+type ResponseWrapper_Purge struct {
+	Purge *PurgeResponse `protobuf:"bytes,30,opt,name=purge,proto3,oneof"`
+}
+type ResponseWrapper_EventSubscribe struct {
+	EventSubscribe *EventSubscribeResponse `protobuf:"bytes,31,opt,name=eventSubscribe,proto3,oneof"`
+}
+type ResponseWrapper_Event struct {
+	Event *QueueEventResponse `protobuf:"bytes,32,opt,name=event,proto3,oneof"`
+}
+type ResponseWrapper_ExportDeadLetters struct {
+	ExportDeadLetters *ExportDeadLettersResponse `protobuf:"bytes,33,opt,name=exportDeadLetters,proto3,oneof"`
+}
 
-func (*ResponseWrapper_Enqueue) isResponseWrapper_Message()      {}
-func (*ResponseWrapper_Pop) isResponseWrapper_Message()          {}
-func (*ResponseWrapper_Acknowledge) isResponseWrapper_Message()  {}
-func (*ResponseWrapper_Error) isResponseWrapper_Message()        {}
-func (*ResponseWrapper_Authenticate) isResponseWrapper_Message() {}
+func (*ResponseWrapper_Enqueue) isResponseWrapper_Message()             {}
+func (*ResponseWrapper_Pop) isResponseWrapper_Message()                 {}
+func (*ResponseWrapper_Acknowledge) isResponseWrapper_Message()         {}
+func (*ResponseWrapper_Error) isResponseWrapper_Message()               {}
+func (*ResponseWrapper_Authenticate) isResponseWrapper_Message()        {}
+func (*ResponseWrapper_Health) isResponseWrapper_Message()              {}
+func (*ResponseWrapper_AcknowledgeMatching) isResponseWrapper_Message() {}
+func (*ResponseWrapper_FailWithRetag) isResponseWrapper_Message()       {}
+func (*ResponseWrapper_ServerInfo) isResponseWrapper_Message()          {}
+func (*ResponseWrapper_Fail) isResponseWrapper_Message()                {}
+func (*ResponseWrapper_DiagnosticDump) isResponseWrapper_Message()      {}
+func (*ResponseWrapper_BeginEnqueue) isResponseWrapper_Message()        {}
+func (*ResponseWrapper_EnqueueChunk) isResponseWrapper_Message()        {}
+func (*ResponseWrapper_CommitEnqueue) isResponseWrapper_Message()       {}
+func (*ResponseWrapper_CancelPop) isResponseWrapper_Message()           {}
+func (*ResponseWrapper_Cancel) isResponseWrapper_Message()              {}
+func (*ResponseWrapper_FindByTag) isResponseWrapper_Message()           {}
+func (*ResponseWrapper_ListProcessing) isResponseWrapper_Message()      {}
+func (*ResponseWrapper_SetCapabilityPaused) isResponseWrapper_Message() {}
+func (*ResponseWrapper_Subscribe) isResponseWrapper_Message()           {}
+func (*ResponseWrapper_Reserve) isResponseWrapper_Message()             {}
+func (*ResponseWrapper_ConfirmReservation) isResponseWrapper_Message()  {}
+func (*ResponseWrapper_Decline) isResponseWrapper_Message()             {}
+func (*ResponseWrapper_EnqueueBatch) isResponseWrapper_Message()        {}
+func (*ResponseWrapper_ListQueued) isResponseWrapper_Message()          {}
+func (*ResponseWrapper_Peek) isResponseWrapper_Message()                {}
+func (*ResponseWrapper_Stats) isResponseWrapper_Message()               {}
+func (*ResponseWrapper_Purge) isResponseWrapper_Message()               {}
+func (*ResponseWrapper_EventSubscribe) isResponseWrapper_Message()      {}
+func (*ResponseWrapper_Event) isResponseWrapper_Message()               {}
+func (*ResponseWrapper_ExportDeadLetters) isResponseWrapper_Message()   {}
 
 func (m *ResponseWrapper) GetMessage() isResponseWrapper_Message {
 	if m != nil {
@@ -741,30 +4864,212 @@ func (m *ResponseWrapper) GetEnqueue() *EnqueueResponse {
 	return nil
 }
 
-func (m *ResponseWrapper) GetPop() *PopResponse {
-	if x, ok := m.GetMessage().(*ResponseWrapper_Pop); ok {
-		return x.Pop
+func (m *ResponseWrapper) GetPop() *PopResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Pop); ok {
+		return x.Pop
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetAcknowledge() *AcknowledgeResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Acknowledge); ok {
+		return x.Acknowledge
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetError() *ErrorResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Error); ok {
+		return x.Error
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetAuthenticate() *AuthenticateResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Authenticate); ok {
+		return x.Authenticate
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetHealth() *HealthResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Health); ok {
+		return x.Health
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetAcknowledgeMatching() *AcknowledgeMatchingResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_AcknowledgeMatching); ok {
+		return x.AcknowledgeMatching
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetFailWithRetag() *FailWithRetagResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_FailWithRetag); ok {
+		return x.FailWithRetag
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetServerInfo() *ServerInfoResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_ServerInfo); ok {
+		return x.ServerInfo
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetFail() *FailResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Fail); ok {
+		return x.Fail
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetDiagnosticDump() *DiagnosticDumpResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_DiagnosticDump); ok {
+		return x.DiagnosticDump
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetBeginEnqueue() *BeginEnqueueResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_BeginEnqueue); ok {
+		return x.BeginEnqueue
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetEnqueueChunk() *EnqueueChunkResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_EnqueueChunk); ok {
+		return x.EnqueueChunk
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetCommitEnqueue() *CommitEnqueueResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_CommitEnqueue); ok {
+		return x.CommitEnqueue
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetCancelPop() *CancelPopResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_CancelPop); ok {
+		return x.CancelPop
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetCancel() *CancelResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Cancel); ok {
+		return x.Cancel
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetFindByTag() *FindByTagResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_FindByTag); ok {
+		return x.FindByTag
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetListProcessing() *ListProcessingResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_ListProcessing); ok {
+		return x.ListProcessing
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetSetCapabilityPaused() *SetCapabilityPausedResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_SetCapabilityPaused); ok {
+		return x.SetCapabilityPaused
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetSubscribe() *SubscribeResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Subscribe); ok {
+		return x.Subscribe
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetReserve() *ReserveResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Reserve); ok {
+		return x.Reserve
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetConfirmReservation() *ConfirmReservationResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_ConfirmReservation); ok {
+		return x.ConfirmReservation
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetDecline() *DeclineResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Decline); ok {
+		return x.Decline
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetEnqueueBatch() *EnqueueBatchResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_EnqueueBatch); ok {
+		return x.EnqueueBatch
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetListQueued() *ListQueuedResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_ListQueued); ok {
+		return x.ListQueued
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetPeek() *PeekResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Peek); ok {
+		return x.Peek
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetStats() *StatsResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Stats); ok {
+		return x.Stats
+	}
+	return nil
+}
+
+func (m *ResponseWrapper) GetPurge() *PurgeResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Purge); ok {
+		return x.Purge
 	}
 	return nil
 }
 
-func (m *ResponseWrapper) GetAcknowledge() *AcknowledgeResponse {
-	if x, ok := m.GetMessage().(*ResponseWrapper_Acknowledge); ok {
-		return x.Acknowledge
+func (m *ResponseWrapper) GetEventSubscribe() *EventSubscribeResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_EventSubscribe); ok {
+		return x.EventSubscribe
 	}
 	return nil
 }
 
-func (m *ResponseWrapper) GetError() *ErrorResponse {
-	if x, ok := m.GetMessage().(*ResponseWrapper_Error); ok {
-		return x.Error
+func (m *ResponseWrapper) GetEvent() *QueueEventResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_Event); ok {
+		return x.Event
 	}
 	return nil
 }
 
-func (m *ResponseWrapper) GetAuthenticate() *AuthenticateResponse {
-	if x, ok := m.GetMessage().(*ResponseWrapper_Authenticate); ok {
-		return x.Authenticate
+func (m *ResponseWrapper) GetExportDeadLetters() *ExportDeadLettersResponse {
+	if x, ok := m.GetMessage().(*ResponseWrapper_ExportDeadLetters); ok {
+		return x.ExportDeadLetters
 	}
 	return nil
 }
@@ -777,6 +5082,32 @@ func (*ResponseWrapper) XXX_OneofFuncs() (func(msg proto.Message, b *proto.Buffe
 		(*ResponseWrapper_Acknowledge)(nil),
 		(*ResponseWrapper_Error)(nil),
 		(*ResponseWrapper_Authenticate)(nil),
+		(*ResponseWrapper_Health)(nil),
+		(*ResponseWrapper_AcknowledgeMatching)(nil),
+		(*ResponseWrapper_FailWithRetag)(nil),
+		(*ResponseWrapper_ServerInfo)(nil),
+		(*ResponseWrapper_Fail)(nil),
+		(*ResponseWrapper_DiagnosticDump)(nil),
+		(*ResponseWrapper_BeginEnqueue)(nil),
+		(*ResponseWrapper_EnqueueChunk)(nil),
+		(*ResponseWrapper_CommitEnqueue)(nil),
+		(*ResponseWrapper_CancelPop)(nil),
+		(*ResponseWrapper_Cancel)(nil),
+		(*ResponseWrapper_FindByTag)(nil),
+		(*ResponseWrapper_ListProcessing)(nil),
+		(*ResponseWrapper_SetCapabilityPaused)(nil),
+		(*ResponseWrapper_Subscribe)(nil),
+		(*ResponseWrapper_Reserve)(nil),
+		(*ResponseWrapper_ConfirmReservation)(nil),
+		(*ResponseWrapper_Decline)(nil),
+		(*ResponseWrapper_EnqueueBatch)(nil),
+		(*ResponseWrapper_ListQueued)(nil),
+		(*ResponseWrapper_Peek)(nil),
+		(*ResponseWrapper_Stats)(nil),
+		(*ResponseWrapper_Purge)(nil),
+		(*ResponseWrapper_EventSubscribe)(nil),
+		(*ResponseWrapper_Event)(nil),
+		(*ResponseWrapper_ExportDeadLetters)(nil),
 	}
 }
 
@@ -809,6 +5140,136 @@ func _ResponseWrapper_OneofMarshaler(msg proto.Message, b *proto.Buffer) error {
 		if err := b.EncodeMessage(x.Authenticate); err != nil {
 			return err
 		}
+	case *ResponseWrapper_Health:
+		b.EncodeVarint(7<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Health); err != nil {
+			return err
+		}
+	case *ResponseWrapper_AcknowledgeMatching:
+		b.EncodeVarint(8<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.AcknowledgeMatching); err != nil {
+			return err
+		}
+	case *ResponseWrapper_FailWithRetag:
+		b.EncodeVarint(9<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.FailWithRetag); err != nil {
+			return err
+		}
+	case *ResponseWrapper_ServerInfo:
+		b.EncodeVarint(11<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ServerInfo); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Fail:
+		b.EncodeVarint(12<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Fail); err != nil {
+			return err
+		}
+	case *ResponseWrapper_DiagnosticDump:
+		b.EncodeVarint(13<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.DiagnosticDump); err != nil {
+			return err
+		}
+	case *ResponseWrapper_BeginEnqueue:
+		b.EncodeVarint(14<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.BeginEnqueue); err != nil {
+			return err
+		}
+	case *ResponseWrapper_EnqueueChunk:
+		b.EncodeVarint(15<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EnqueueChunk); err != nil {
+			return err
+		}
+	case *ResponseWrapper_CommitEnqueue:
+		b.EncodeVarint(16<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.CommitEnqueue); err != nil {
+			return err
+		}
+	case *ResponseWrapper_CancelPop:
+		b.EncodeVarint(17<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.CancelPop); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Cancel:
+		b.EncodeVarint(18<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Cancel); err != nil {
+			return err
+		}
+	case *ResponseWrapper_FindByTag:
+		b.EncodeVarint(19<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.FindByTag); err != nil {
+			return err
+		}
+	case *ResponseWrapper_ListProcessing:
+		b.EncodeVarint(20<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ListProcessing); err != nil {
+			return err
+		}
+	case *ResponseWrapper_SetCapabilityPaused:
+		b.EncodeVarint(21<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.SetCapabilityPaused); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Subscribe:
+		b.EncodeVarint(22<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Subscribe); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Reserve:
+		b.EncodeVarint(23<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Reserve); err != nil {
+			return err
+		}
+	case *ResponseWrapper_ConfirmReservation:
+		b.EncodeVarint(24<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ConfirmReservation); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Decline:
+		b.EncodeVarint(25<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Decline); err != nil {
+			return err
+		}
+	case *ResponseWrapper_EnqueueBatch:
+		b.EncodeVarint(26<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EnqueueBatch); err != nil {
+			return err
+		}
+	case *ResponseWrapper_ListQueued:
+		b.EncodeVarint(27<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ListQueued); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Peek:
+		b.EncodeVarint(28<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Peek); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Stats:
+		b.EncodeVarint(29<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Stats); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Purge:
+		b.EncodeVarint(30<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Purge); err != nil {
+			return err
+		}
+	case *ResponseWrapper_EventSubscribe:
+		b.EncodeVarint(31<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.EventSubscribe); err != nil {
+			return err
+		}
+	case *ResponseWrapper_Event:
+		b.EncodeVarint(32<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Event); err != nil {
+			return err
+		}
+	case *ResponseWrapper_ExportDeadLetters:
+		b.EncodeVarint(33<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.ExportDeadLetters); err != nil {
+			return err
+		}
 	case nil:
 	default:
 		return fmt.Errorf("ResponseWrapper.Message has unexpected type %T", x)
@@ -859,6 +5320,214 @@ func _ResponseWrapper_OneofUnmarshaler(msg proto.Message, tag, wire int, b *prot
 		err := b.DecodeMessage(msg)
 		m.Message = &ResponseWrapper_Authenticate{msg}
 		return true, err
+	case 7: // message.health
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(HealthResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Health{msg}
+		return true, err
+	case 8: // message.acknowledgeMatching
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(AcknowledgeMatchingResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_AcknowledgeMatching{msg}
+		return true, err
+	case 9: // message.failWithRetag
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FailWithRetagResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_FailWithRetag{msg}
+		return true, err
+	case 11: // message.serverInfo
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ServerInfoResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_ServerInfo{msg}
+		return true, err
+	case 12: // message.fail
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FailResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Fail{msg}
+		return true, err
+	case 13: // message.diagnosticDump
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(DiagnosticDumpResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_DiagnosticDump{msg}
+		return true, err
+	case 14: // message.beginEnqueue
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(BeginEnqueueResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_BeginEnqueue{msg}
+		return true, err
+	case 15: // message.enqueueChunk
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(EnqueueChunkResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_EnqueueChunk{msg}
+		return true, err
+	case 16: // message.commitEnqueue
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CommitEnqueueResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_CommitEnqueue{msg}
+		return true, err
+	case 17: // message.cancelPop
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CancelPopResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_CancelPop{msg}
+		return true, err
+	case 18: // message.cancel
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(CancelResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Cancel{msg}
+		return true, err
+	case 19: // message.findByTag
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(FindByTagResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_FindByTag{msg}
+		return true, err
+	case 20: // message.listProcessing
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ListProcessingResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_ListProcessing{msg}
+		return true, err
+	case 21: // message.setCapabilityPaused
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(SetCapabilityPausedResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_SetCapabilityPaused{msg}
+		return true, err
+	case 22: // message.subscribe
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(SubscribeResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Subscribe{msg}
+		return true, err
+	case 23: // message.reserve
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ReserveResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Reserve{msg}
+		return true, err
+	case 24: // message.confirmReservation
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ConfirmReservationResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_ConfirmReservation{msg}
+		return true, err
+	case 25: // message.decline
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(DeclineResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Decline{msg}
+		return true, err
+	case 26: // message.enqueueBatch
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(EnqueueBatchResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_EnqueueBatch{msg}
+		return true, err
+	case 27: // message.listQueued
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ListQueuedResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_ListQueued{msg}
+		return true, err
+	case 28: // message.peek
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(PeekResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Peek{msg}
+		return true, err
+	case 29: // message.stats
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(StatsResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Stats{msg}
+		return true, err
+	case 30: // message.purge
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(PurgeResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Purge{msg}
+		return true, err
+	case 31: // message.eventSubscribe
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(EventSubscribeResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_EventSubscribe{msg}
+		return true, err
+	case 32: // message.event
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(QueueEventResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_Event{msg}
+		return true, err
+	case 33: // message.exportDeadLetters
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(ExportDeadLettersResponse)
+		err := b.DecodeMessage(msg)
+		m.Message = &ResponseWrapper_ExportDeadLetters{msg}
+		return true, err
 	default:
 		return false, nil
 	}
@@ -893,6 +5562,136 @@ func _ResponseWrapper_OneofSizer(msg proto.Message) (n int) {
 		n += 1 // tag and wire
 		n += proto.SizeVarint(uint64(s))
 		n += s
+	case *ResponseWrapper_Health:
+		s := proto.Size(x.Health)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_AcknowledgeMatching:
+		s := proto.Size(x.AcknowledgeMatching)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_FailWithRetag:
+		s := proto.Size(x.FailWithRetag)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_ServerInfo:
+		s := proto.Size(x.ServerInfo)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Fail:
+		s := proto.Size(x.Fail)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_DiagnosticDump:
+		s := proto.Size(x.DiagnosticDump)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_BeginEnqueue:
+		s := proto.Size(x.BeginEnqueue)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_EnqueueChunk:
+		s := proto.Size(x.EnqueueChunk)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_CommitEnqueue:
+		s := proto.Size(x.CommitEnqueue)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_CancelPop:
+		s := proto.Size(x.CancelPop)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Cancel:
+		s := proto.Size(x.Cancel)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_FindByTag:
+		s := proto.Size(x.FindByTag)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_ListProcessing:
+		s := proto.Size(x.ListProcessing)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_SetCapabilityPaused:
+		s := proto.Size(x.SetCapabilityPaused)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Subscribe:
+		s := proto.Size(x.Subscribe)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Reserve:
+		s := proto.Size(x.Reserve)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_ConfirmReservation:
+		s := proto.Size(x.ConfirmReservation)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Decline:
+		s := proto.Size(x.Decline)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_EnqueueBatch:
+		s := proto.Size(x.EnqueueBatch)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_ListQueued:
+		s := proto.Size(x.ListQueued)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Peek:
+		s := proto.Size(x.Peek)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Stats:
+		s := proto.Size(x.Stats)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Purge:
+		s := proto.Size(x.Purge)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_EventSubscribe:
+		s := proto.Size(x.EventSubscribe)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_Event:
+		s := proto.Size(x.Event)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *ResponseWrapper_ExportDeadLetters:
+		s := proto.Size(x.ExportDeadLetters)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
 	case nil:
 	default:
 		panic(fmt.Sprintf("proto: unexpected type %T in oneof", x))
@@ -905,53 +5704,279 @@ func init() {
 	proto.RegisterType((*AuthenticateResponse)(nil), "AuthenticateResponse")
 	proto.RegisterType((*EnqueueRequest)(nil), "EnqueueRequest")
 	proto.RegisterType((*EnqueueResponse)(nil), "EnqueueResponse")
+	proto.RegisterType((*EnqueueBatchRequest)(nil), "EnqueueBatchRequest")
+	proto.RegisterType((*EnqueueBatchResult)(nil), "EnqueueBatchResult")
+	proto.RegisterType((*EnqueueBatchResponse)(nil), "EnqueueBatchResponse")
+	proto.RegisterType((*BeginEnqueueRequest)(nil), "BeginEnqueueRequest")
+	proto.RegisterType((*BeginEnqueueResponse)(nil), "BeginEnqueueResponse")
+	proto.RegisterType((*EnqueueChunkRequest)(nil), "EnqueueChunkRequest")
+	proto.RegisterType((*EnqueueChunkResponse)(nil), "EnqueueChunkResponse")
+	proto.RegisterType((*CommitEnqueueRequest)(nil), "CommitEnqueueRequest")
+	proto.RegisterType((*CommitEnqueueResponse)(nil), "CommitEnqueueResponse")
 	proto.RegisterType((*PopRequest)(nil), "PopRequest")
 	proto.RegisterType((*PopResponse)(nil), "PopResponse")
+	proto.RegisterType((*SubscribeRequest)(nil), "SubscribeRequest")
+	proto.RegisterType((*SubscribeResponse)(nil), "SubscribeResponse")
+	proto.RegisterType((*ReserveRequest)(nil), "ReserveRequest")
+	proto.RegisterType((*ReserveResponse)(nil), "ReserveResponse")
+	proto.RegisterType((*ConfirmReservationRequest)(nil), "ConfirmReservationRequest")
+	proto.RegisterType((*ConfirmReservationResponse)(nil), "ConfirmReservationResponse")
+	proto.RegisterType((*CancelPopRequest)(nil), "CancelPopRequest")
+	proto.RegisterType((*CancelPopResponse)(nil), "CancelPopResponse")
+	proto.RegisterType((*CancelRequest)(nil), "CancelRequest")
+	proto.RegisterType((*CancelResponse)(nil), "CancelResponse")
 	proto.RegisterType((*AcknowledgeRequest)(nil), "AcknowledgeRequest")
 	proto.RegisterType((*AcknowledgeResponse)(nil), "AcknowledgeResponse")
+	proto.RegisterType((*FailRequest)(nil), "FailRequest")
+	proto.RegisterType((*FailResponse)(nil), "FailResponse")
+	proto.RegisterType((*DeclineRequest)(nil), "DeclineRequest")
+	proto.RegisterType((*DeclineResponse)(nil), "DeclineResponse")
+	proto.RegisterType((*FailWithRetagRequest)(nil), "FailWithRetagRequest")
+	proto.RegisterType((*FailWithRetagResponse)(nil), "FailWithRetagResponse")
+	proto.RegisterType((*AcknowledgeMatchingRequest)(nil), "AcknowledgeMatchingRequest")
+	proto.RegisterType((*AcknowledgeMatchingResponse)(nil), "AcknowledgeMatchingResponse")
+	proto.RegisterType((*FindByTagRequest)(nil), "FindByTagRequest")
+	proto.RegisterType((*FoundMessage)(nil), "FoundMessage")
+	proto.RegisterType((*FindByTagResponse)(nil), "FindByTagResponse")
+	proto.RegisterType((*PeekRequest)(nil), "PeekRequest")
+	proto.RegisterType((*PeekResponse)(nil), "PeekResponse")
+	proto.RegisterType((*ListQueuedRequest)(nil), "ListQueuedRequest")
+	proto.RegisterType((*ListQueuedResponse)(nil), "ListQueuedResponse")
+	proto.RegisterType((*ListProcessingRequest)(nil), "ListProcessingRequest")
+	proto.RegisterType((*ProcessingMessage)(nil), "ProcessingMessage")
+	proto.RegisterType((*ListProcessingResponse)(nil), "ListProcessingResponse")
+	proto.RegisterType((*SetCapabilityPausedRequest)(nil), "SetCapabilityPausedRequest")
+	proto.RegisterType((*SetCapabilityPausedResponse)(nil), "SetCapabilityPausedResponse")
+	proto.RegisterType((*HealthRequest)(nil), "HealthRequest")
+	proto.RegisterType((*LevelScanDepth)(nil), "LevelScanDepth")
+	proto.RegisterType((*HealthResponse)(nil), "HealthResponse")
+	proto.RegisterType((*StatsRequest)(nil), "StatsRequest")
+	proto.RegisterType((*PendingCount)(nil), "PendingCount")
+	proto.RegisterType((*StatsResponse)(nil), "StatsResponse")
+	proto.RegisterType((*PurgeRequest)(nil), "PurgeRequest")
+	proto.RegisterType((*PurgeResponse)(nil), "PurgeResponse")
+	proto.RegisterType((*ExportDeadLettersRequest)(nil), "ExportDeadLettersRequest")
+	proto.RegisterType((*ExportDeadLettersResponse)(nil), "ExportDeadLettersResponse")
+	proto.RegisterType((*EventSubscribeRequest)(nil), "EventSubscribeRequest")
+	proto.RegisterType((*EventSubscribeResponse)(nil), "EventSubscribeResponse")
+	proto.RegisterType((*QueueEventResponse)(nil), "QueueEventResponse")
+	proto.RegisterType((*ServerInfoRequest)(nil), "ServerInfoRequest")
+	proto.RegisterType((*ServerInfoResponse)(nil), "ServerInfoResponse")
+	proto.RegisterType((*StorageFileSizes)(nil), "StorageFileSizes")
+	proto.RegisterType((*DiagnosticDumpRequest)(nil), "DiagnosticDumpRequest")
+	proto.RegisterType((*DiagnosticDumpResponse)(nil), "DiagnosticDumpResponse")
 	proto.RegisterType((*ErrorResponse)(nil), "ErrorResponse")
 	proto.RegisterType((*RequestWrapper)(nil), "RequestWrapper")
 	proto.RegisterType((*ResponseWrapper)(nil), "ResponseWrapper")
 	proto.RegisterEnum("Priority", Priority_name, Priority_value)
+	proto.RegisterEnum("EnqueueStatus", EnqueueStatus_name, EnqueueStatus_value)
+	proto.RegisterEnum("TagMatchMode", TagMatchMode_name, TagMatchMode_value)
+	proto.RegisterEnum("EventKind", EventKind_name, EventKind_value)
+	proto.RegisterEnum("ErrorCode", ErrorCode_name, ErrorCode_value)
 }
 
-func init() { proto.RegisterFile("queue.proto", fileDescriptor_queue_7ac3e76a0189e465) }
-
-var fileDescriptor_queue_7ac3e76a0189e465 = []byte{
-	// 547 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x7c, 0x94, 0xdd, 0x6e, 0xd3, 0x4c,
-	0x10, 0x86, 0x6d, 0xa7, 0x6d, 0xe2, 0x71, 0x3e, 0x27, 0xda, 0x24, 0x92, 0xf5, 0x09, 0x44, 0xb0,
-	0xa0, 0x0a, 0x3f, 0x5a, 0xa1, 0x80, 0x04, 0x82, 0xa3, 0x16, 0x15, 0x5c, 0x89, 0x8a, 0x6a, 0x25,
-	0x54, 0x89, 0xb3, 0x4d, 0x3c, 0xb4, 0x0b, 0x69, 0xec, 0xec, 0xda, 0x44, 0x5c, 0x00, 0x17, 0xc2,
-	0x29, 0xd7, 0xc7, 0x05, 0xa0, 0xac, 0x7f, 0x62, 0x07, 0x2b, 0x67, 0x9e, 0x9d, 0xdd, 0x7d, 0xe7,
-	0x79, 0x67, 0xd6, 0xe0, 0xac, 0x52, 0x4c, 0x91, 0xc6, 0x32, 0x4a, 0x22, 0xff, 0x02, 0x06, 0x27,
-	0x69, 0x72, 0x83, 0xcb, 0x44, 0xcc, 0x79, 0x82, 0x0c, 0x57, 0x29, 0xaa, 0x84, 0xfc, 0x0f, 0x9d,
-	0x54, 0xa1, 0x5c, 0xf2, 0x5b, 0xf4, 0xcc, 0xb1, 0x39, 0xb1, 0x59, 0x19, 0x6f, 0x72, 0x31, 0x57,
-	0x6a, 0x1d, 0xc9, 0xd0, 0xb3, 0xb2, 0x5c, 0x11, 0xfb, 0xcf, 0x60, 0x58, 0xbf, 0x4e, 0xc5, 0xd1,
-	0x52, 0x21, 0xf1, 0xa0, 0xad, 0xd2, 0xf9, 0x1c, 0x95, 0xd2, 0xd7, 0x75, 0x58, 0x11, 0xfa, 0x3f,
-	0x4d, 0x70, 0xcf, 0x96, 0xba, 0xa4, 0x42, 0xdc, 0x83, 0xf6, 0x2d, 0x2a, 0xc5, 0xaf, 0x33, 0xed,
-	0x2e, 0x2b, 0x42, 0xf2, 0x10, 0x3a, 0xb1, 0x14, 0x91, 0x14, 0xc9, 0x0f, 0x2d, 0xed, 0x4e, 0x6d,
-	0x7a, 0x99, 0x2f, 0xb0, 0x32, 0x45, 0xa6, 0x30, 0x94, 0xb8, 0x4a, 0x85, 0xc4, 0xf0, 0x2d, 0x8f,
-	0xf9, 0x4c, 0x2c, 0x44, 0x22, 0x50, 0x79, 0xad, 0x71, 0x6b, 0x62, 0xb3, 0xc6, 0x9c, 0x7f, 0x1f,
-	0x7a, 0x65, 0x19, 0x79, 0xd1, 0x2e, 0x58, 0x22, 0xcc, 0xf1, 0x2d, 0x11, 0xfa, 0x5f, 0x01, 0x2e,
-	0xa3, 0xb8, 0xa8, 0xf2, 0x05, 0x8c, 0xf8, 0x77, 0x2e, 0x16, 0x7c, 0xb6, 0xc0, 0x9a, 0x8a, 0xa9,
-	0x55, 0x9a, 0x93, 0xe4, 0x18, 0xdc, 0x35, 0x17, 0xc9, 0xbb, 0x48, 0x5e, 0xe4, 0x88, 0x96, 0xf6,
-	0x63, 0x67, 0xd5, 0xff, 0x04, 0x8e, 0xd6, 0xca, 0x4b, 0xb9, 0x03, 0xf6, 0x0d, 0x0f, 0x19, 0xaa,
-	0x74, 0x91, 0x78, 0x2d, 0x7d, 0x62, 0xbb, 0xb0, 0xc7, 0xb0, 0x0c, 0xc1, 0x2a, 0x11, 0x1e, 0x00,
-	0x39, 0x99, 0x7f, 0x5b, 0x46, 0xeb, 0x05, 0x86, 0xd7, 0xa5, 0xe1, 0xbb, 0xa0, 0x23, 0x18, 0xd4,
-	0x76, 0x65, 0x45, 0xf8, 0x8f, 0xe0, 0xbf, 0x33, 0x29, 0x23, 0x59, 0xed, 0x6a, 0x55, 0xd7, 0x2e,
-	0x75, 0xfd, 0x3f, 0x26, 0xb8, 0xf9, 0xed, 0x57, 0x92, 0xc7, 0x31, 0x4a, 0x32, 0x84, 0x43, 0x89,
-	0x5f, 0xce, 0x43, 0x0f, 0xc6, 0xe6, 0xe4, 0x90, 0x65, 0x01, 0x79, 0x02, 0x6d, 0xcc, 0x6c, 0xd7,
-	0x57, 0x38, 0xd3, 0x1e, 0xad, 0x4f, 0x43, 0x60, 0xb0, 0x62, 0x07, 0xb9, 0x07, 0xad, 0x38, 0x8a,
-	0x35, 0x8e, 0x33, 0x75, 0xe8, 0xb6, 0x19, 0x81, 0xc1, 0x36, 0x19, 0xf2, 0x12, 0x1c, 0xbe, 0x2d,
-	0x5c, 0x1b, 0xe5, 0x4c, 0x07, 0xf4, 0x5f, 0xe4, 0xc0, 0x60, 0xd5, 0x9d, 0xe4, 0x35, 0x74, 0x79,
-	0x65, 0x6e, 0xbd, 0x23, 0x7d, 0x72, 0x48, 0x1b, 0xde, 0x46, 0x60, 0xb0, 0xda, 0xde, 0x53, 0xbb,
-	0x74, 0xc1, 0xff, 0x65, 0x41, 0xaf, 0x70, 0x67, 0x3f, 0xf7, 0xd3, 0x5d, 0xee, 0x3e, 0xdd, 0x19,
-	0xbf, 0x2a, 0xf8, 0xb8, 0x0a, 0xde, 0xa5, 0x95, 0xc9, 0x28, 0xc8, 0x5f, 0x35, 0x91, 0x0f, 0x69,
-	0x43, 0x1b, 0x77, 0xd1, 0x8f, 0xe1, 0x10, 0x37, 0x5d, 0xf5, 0x0e, 0xf4, 0x19, 0x97, 0xd6, 0x7a,
-	0x1c, 0x18, 0x2c, 0x4b, 0x93, 0x37, 0x8d, 0x16, 0x8d, 0x68, 0xd3, 0x7b, 0xdf, 0xe3, 0xd1, 0xe3,
-	0xbb, 0xd0, 0x29, 0x9e, 0x2c, 0x69, 0x43, 0xeb, 0xc3, 0xc7, 0xab, 0xbe, 0x41, 0x3a, 0x70, 0x10,
-	0x9c, 0xbf, 0x0f, 0xfa, 0xe6, 0xe9, 0xe0, 0x73, 0x7b, 0x26, 0x35, 0xf5, 0x6f, 0xab, 0xf8, 0x9a,
-	0x1d, 0xe9, 0x9f, 0xd5, 0xf3, 0xbf, 0x01, 0x00, 0x00, 0xff, 0xff, 0x75, 0xef, 0x1c, 0xb2, 0xbb,
-	0x04, 0x00, 0x00,
+func init() { proto.RegisterFile("queue.proto", fileDescriptor_queue_696978ac6113b6a2) }
+
+var fileDescriptor_queue_696978ac6113b6a2 = []byte{
+	// 3193 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xb5, 0x5a, 0x5b, 0x73, 0xdb, 0xc6,
+	0x15, 0x0e, 0x29, 0x4a, 0xa2, 0x96, 0x17, 0x49, 0xd0, 0xc5, 0xf0, 0x25, 0x8d, 0x8b, 0x24, 0x4e,
+	0xec, 0xb8, 0x98, 0x86, 0x69, 0xd2, 0x4c, 0x3c, 0xc9, 0x94, 0x92, 0xa8, 0x58, 0xb1, 0x2e, 0x0c,
+	0x24, 0xd5, 0x49, 0x5e, 0x3c, 0x10, 0xb9, 0x92, 0x50, 0x91, 0x00, 0x03, 0x80, 0x76, 0xd4, 0xc7,
+	0x76, 0xda, 0x5f, 0xd0, 0xbe, 0xf4, 0xa1, 0x99, 0xe9, 0x63, 0x27, 0x3f, 0xa5, 0xd3, 0xff, 0xd0,
+	0xe9, 0x1f, 0xe8, 0x4f, 0xe8, 0x39, 0xbb, 0x0b, 0x60, 0x17, 0x58, 0x52, 0xb2, 0xd3, 0xbe, 0x71,
+	0xcf, 0x39, 0xbb, 0x67, 0x77, 0xcf, 0xe5, 0x3b, 0x7b, 0x40, 0x52, 0xfb, 0x76, 0x4c, 0xc7, 0xd4,
+	0x1e, 0x85, 0x41, 0x1c, 0x58, 0xdf, 0x92, 0x95, 0xf6, 0x38, 0x3e, 0xa7, 0x7e, 0xec, 0xf5, 0xdc,
+	0x98, 0x3a, 0x14, 0x98, 0x51, 0x6c, 0xdc, 0x22, 0xd5, 0x71, 0x44, 0x43, 0xdf, 0x1d, 0x52, 0xb3,
+	0x74, 0xb7, 0xf4, 0xee, 0x82, 0x93, 0x8e, 0x91, 0x37, 0x72, 0xa3, 0xe8, 0x45, 0x10, 0xf6, 0xcd,
+	0x32, 0xe7, 0x25, 0x63, 0xc3, 0x22, 0xf5, 0x88, 0x46, 0x91, 0x17, 0xf8, 0x47, 0xc1, 0x05, 0xf5,
+	0xcd, 0x19, 0xc6, 0x57, 0x68, 0xd6, 0x11, 0x59, 0x55, 0x55, 0x46, 0xa3, 0xc0, 0x8f, 0xa8, 0x61,
+	0x92, 0xf9, 0x68, 0xdc, 0xeb, 0x81, 0x28, 0x53, 0x59, 0x75, 0x92, 0x61, 0x61, 0xd5, 0xb2, 0x66,
+	0xd5, 0x7f, 0x95, 0x49, 0xb3, 0xe3, 0xb3, 0xa3, 0x25, 0x87, 0x80, 0x05, 0x87, 0x20, 0xe2, 0x9e,
+	0xf1, 0x33, 0xd4, 0x9d, 0x64, 0x68, 0xbc, 0x0d, 0x47, 0x08, 0xbd, 0x20, 0xf4, 0xe2, 0x4b, 0xb6,
+	0x58, 0xb3, 0xb5, 0x60, 0x77, 0x05, 0xc1, 0x49, 0x59, 0x46, 0x8b, 0xac, 0x86, 0xb0, 0x96, 0x17,
+	0xd2, 0xfe, 0xa6, 0x3b, 0x72, 0x4f, 0xbc, 0x81, 0x17, 0x7b, 0x34, 0x82, 0x53, 0xcd, 0x80, 0x7e,
+	0x2d, 0xcf, 0x78, 0x48, 0x96, 0xfb, 0x74, 0xe0, 0x5e, 0xee, 0x79, 0x83, 0x81, 0x17, 0xd1, 0x5e,
+	0xe0, 0xf7, 0x23, 0xb3, 0x02, 0x3a, 0x2a, 0x4e, 0x91, 0x61, 0xdc, 0x25, 0x35, 0xf7, 0xb9, 0xeb,
+	0x0d, 0xdc, 0x93, 0x01, 0x6d, 0xc7, 0xe6, 0x2c, 0x3b, 0x98, 0x4c, 0x32, 0xee, 0x91, 0xa6, 0xd7,
+	0xa7, 0xc3, 0x51, 0x10, 0x53, 0xbf, 0x77, 0xf9, 0x84, 0x5e, 0x9a, 0x73, 0x4c, 0x28, 0x47, 0x35,
+	0xee, 0x90, 0x05, 0x76, 0xf8, 0x7d, 0x34, 0xd9, 0x3c, 0x13, 0xc9, 0x08, 0xc6, 0x4f, 0x08, 0x89,
+	0xe3, 0xc1, 0xa1, 0xd8, 0x4e, 0x95, 0x6d, 0x47, 0xa2, 0x18, 0x6f, 0x91, 0x46, 0x72, 0xea, 0x5d,
+	0xfa, 0x9c, 0x0e, 0xcc, 0x05, 0x10, 0x69, 0x38, 0x2a, 0xd1, 0xda, 0x21, 0x8b, 0xe9, 0x15, 0x0b,
+	0xa3, 0x35, 0x49, 0xd9, 0xeb, 0x0b, 0x17, 0x81, 0x5f, 0xb0, 0xdd, 0xb9, 0x28, 0x76, 0xe3, 0x71,
+	0x24, 0xee, 0xb5, 0x69, 0x8b, 0x19, 0x87, 0x8c, 0xea, 0x08, 0x2e, 0x38, 0xc1, 0x8a, 0x60, 0x6c,
+	0xb8, 0x71, 0xef, 0x3c, 0x31, 0xd9, 0xdb, 0x64, 0xd6, 0x8b, 0xe9, 0x10, 0x3d, 0x60, 0xe6, 0xdd,
+	0x5a, 0x6b, 0xd1, 0x56, 0x4d, 0xea, 0x70, 0xae, 0xb1, 0x4e, 0xe6, 0xdc, 0x38, 0x18, 0x7a, 0x3d,
+	0xa6, 0xa5, 0xea, 0x88, 0x91, 0x75, 0x42, 0x0c, 0x75, 0xd5, 0x68, 0x3c, 0x88, 0x5f, 0x75, 0x8f,
+	0xc6, 0x2a, 0x99, 0xa5, 0x61, 0x18, 0x84, 0xc2, 0x8b, 0xf9, 0xc0, 0xea, 0x90, 0xd5, 0x9c, 0x0e,
+	0x7e, 0x13, 0x3f, 0x23, 0xf3, 0x21, 0xd3, 0x97, 0x6c, 0x7e, 0xc5, 0x2e, 0xee, 0xc5, 0x49, 0x64,
+	0xac, 0x3f, 0x96, 0xc9, 0xca, 0x06, 0x3d, 0xf3, 0xfc, 0x9c, 0xd3, 0xca, 0xae, 0x59, 0x7a, 0x79,
+	0xd7, 0x2c, 0xbf, 0xac, 0x6b, 0xce, 0x5c, 0xd3, 0x35, 0x2b, 0x45, 0xd7, 0x54, 0x9d, 0x6a, 0xf6,
+	0x6a, 0xa7, 0x9a, 0xd3, 0x39, 0xd5, 0x47, 0x64, 0x55, 0xbd, 0x07, 0x71, 0x9f, 0xb8, 0x7a, 0xe8,
+	0xfa, 0xd1, 0x29, 0x0d, 0x77, 0x12, 0xeb, 0x49, 0x14, 0xcb, 0x4d, 0x3d, 0x68, 0xf3, 0x7c, 0xec,
+	0x5f, 0x24, 0xf7, 0x77, 0xc5, 0x34, 0x74, 0x9d, 0xe0, 0xf4, 0x34, 0xa2, 0x31, 0x33, 0x7e, 0xc5,
+	0x11, 0x23, 0xc3, 0x20, 0x95, 0xbe, 0x1b, 0xbb, 0xec, 0x3e, 0xea, 0x0e, 0xfb, 0x6d, 0xb5, 0x52,
+	0x53, 0x0b, 0x15, 0x62, 0x6b, 0x90, 0x01, 0x43, 0xda, 0xa3, 0xde, 0x73, 0xca, 0x35, 0x54, 0x9c,
+	0x74, 0x8c, 0xd9, 0x6d, 0x33, 0x18, 0x0e, 0xbd, 0x38, 0x67, 0xd7, 0xab, 0xf6, 0xa5, 0xc4, 0x6f,
+	0x39, 0x17, 0xbf, 0xd6, 0x3b, 0x64, 0x2d, 0xb7, 0xaa, 0x3e, 0xfe, 0xac, 0x7f, 0x96, 0x08, 0xe9,
+	0x06, 0xa3, 0x44, 0xeb, 0x2f, 0xc8, 0x5a, 0x6a, 0x31, 0xc5, 0x4f, 0x4a, 0xcc, 0x4f, 0xf4, 0x4c,
+	0xcc, 0x39, 0x2f, 0x5c, 0x2f, 0xde, 0x0e, 0xc2, 0x3d, 0x91, 0x3f, 0x79, 0x98, 0xe5, 0xa8, 0xc6,
+	0x16, 0x79, 0xfd, 0xb9, 0x17, 0x79, 0x6c, 0xde, 0xe5, 0x91, 0x37, 0xa4, 0xc1, 0x38, 0xd6, 0x38,
+	0xd7, 0x74, 0x21, 0xf5, 0xe4, 0x95, 0xfc, 0xc9, 0xff, 0x50, 0x21, 0x35, 0x76, 0x20, 0x71, 0x60,
+	0x90, 0x3e, 0x77, 0xfb, 0x3c, 0x9a, 0xd8, 0xfa, 0x55, 0x27, 0x23, 0x4c, 0x49, 0xf9, 0xfc, 0xa2,
+	0xca, 0x69, 0x12, 0x00, 0xdb, 0x9e, 0xbb, 0xd1, 0xb1, 0x3f, 0xc4, 0xe0, 0xa4, 0x7d, 0x71, 0x24,
+	0x9e, 0xaa, 0xab, 0x8e, 0x96, 0x87, 0xba, 0x7b, 0xae, 0xdf, 0xa3, 0x83, 0x01, 0x18, 0x7e, 0x96,
+	0xeb, 0x4e, 0x09, 0x68, 0x61, 0xca, 0xad, 0xd3, 0xdf, 0x48, 0xb2, 0xb4, 0x44, 0x41, 0x14, 0x3b,
+	0x71, 0x7b, 0x17, 0x23, 0xc8, 0x00, 0xd1, 0x38, 0xe4, 0x49, 0xba, 0xea, 0x28, 0x34, 0xf4, 0xac,
+	0x08, 0x4d, 0x07, 0x6b, 0x8a, 0x2c, 0x9d, 0x8e, 0x31, 0x20, 0x43, 0xc0, 0xcb, 0x5d, 0x0f, 0xdc,
+	0x00, 0xf4, 0x2f, 0xb0, 0xe9, 0x32, 0x09, 0x67, 0xfb, 0x41, 0xec, 0x50, 0xb7, 0x7f, 0x69, 0x12,
+	0xc6, 0x4e, 0xc7, 0x57, 0xdb, 0xaa, 0x76, 0x1d, 0x5b, 0x4d, 0x4a, 0x3b, 0xf5, 0x29, 0x69, 0x07,
+	0x34, 0xeb, 0xe8, 0x47, 0xe1, 0xd8, 0x47, 0xfc, 0xef, 0x9b, 0x0d, 0xb6, 0xd5, 0xe9, 0x42, 0xd6,
+	0xef, 0x4a, 0x64, 0xe9, 0x70, 0x7c, 0x12, 0xf5, 0x42, 0xef, 0x84, 0xfe, 0x38, 0xf7, 0xc6, 0x02,
+	0x26, 0xa4, 0xa7, 0x14, 0x8c, 0xcb, 0x1c, 0xa2, 0xe1, 0xa4, 0x63, 0xd5, 0x19, 0x67, 0xf2, 0xce,
+	0xb8, 0x42, 0x96, 0xa5, 0x3d, 0x70, 0x8f, 0xb4, 0xfe, 0x5a, 0x22, 0x4d, 0x18, 0xd0, 0xf0, 0xf9,
+	0x8f, 0xdc, 0xd7, 0xc7, 0xe4, 0x46, 0xc8, 0xd6, 0x71, 0x63, 0x28, 0x6b, 0x14, 0xe3, 0xf0, 0x5c,
+	0x35, 0x89, 0x7d, 0xc5, 0xae, 0xbf, 0x22, 0x8b, 0xe9, 0xfe, 0x74, 0x51, 0x54, 0xca, 0x47, 0xd1,
+	0x3b, 0x59, 0x14, 0xa1, 0xe2, 0x5a, 0xab, 0x61, 0x6f, 0x07, 0x63, 0x3f, 0x09, 0x84, 0x34, 0xa8,
+	0xa0, 0x20, 0xb8, 0xb9, 0x19, 0xf8, 0xa7, 0x5e, 0x38, 0x74, 0xb2, 0x9d, 0x25, 0x97, 0x90, 0x87,
+	0xdd, 0xe9, 0x19, 0xee, 0x13, 0x72, 0x4b, 0xb7, 0x54, 0xb6, 0xdf, 0x1e, 0xe7, 0x8a, 0x94, 0x8b,
+	0x91, 0x97, 0x10, 0x00, 0x42, 0x96, 0x36, 0x59, 0x18, 0x4a, 0x99, 0x2f, 0x5f, 0x33, 0x96, 0x34,
+	0x35, 0xe3, 0xfb, 0x64, 0x59, 0x9a, 0x27, 0xa9, 0x4a, 0x83, 0xbc, 0x94, 0x0b, 0x72, 0xeb, 0x53,
+	0xd2, 0xe0, 0x53, 0x5e, 0xed, 0x94, 0x36, 0x69, 0x26, 0xd3, 0xaf, 0xa5, 0xee, 0x2d, 0x62, 0xb4,
+	0x7b, 0x17, 0x7e, 0xf0, 0x02, 0x06, 0x67, 0x74, 0x82, 0x4e, 0xab, 0x0d, 0x45, 0xbc, 0x2c, 0x25,
+	0x96, 0x7e, 0x40, 0x96, 0xdc, 0x41, 0x88, 0xd1, 0x0f, 0xd8, 0x31, 0x1a, 0xd0, 0x38, 0xd5, 0x50,
+	0xa0, 0x5b, 0x03, 0x52, 0xdb, 0x06, 0x9f, 0x9c, 0x74, 0x2a, 0x40, 0x84, 0xde, 0xb9, 0xeb, 0x9f,
+	0xd1, 0xae, 0x5c, 0x36, 0x03, 0x22, 0xa8, 0x54, 0xa5, 0x7a, 0x99, 0x99, 0x58, 0xbd, 0x58, 0x4d,
+	0x52, 0xe7, 0xda, 0x44, 0x08, 0xdd, 0x25, 0xcd, 0x2d, 0xda, 0x1b, 0x78, 0xfe, 0xc4, 0x23, 0x2e,
+	0x93, 0xc5, 0x54, 0x42, 0x4c, 0xfa, 0x86, 0xac, 0xe2, 0x22, 0x4f, 0xbd, 0x18, 0x8a, 0xab, 0xd8,
+	0x3d, 0x9b, 0xb4, 0xf7, 0x57, 0x28, 0x95, 0xac, 0x1b, 0x64, 0x2d, 0xb7, 0xb6, 0x50, 0xfa, 0xb7,
+	0x12, 0xb9, 0x25, 0xdd, 0xf5, 0x1e, 0x22, 0x84, 0xe7, 0xa7, 0xba, 0x6d, 0x62, 0x14, 0xd6, 0xbb,
+	0x14, 0x7b, 0xd1, 0x70, 0x8c, 0x8f, 0xc8, 0xfa, 0xd0, 0xf3, 0xbd, 0xe1, 0x78, 0xd8, 0x86, 0xc5,
+	0x8a, 0x11, 0x3f, 0x81, 0x7b, 0x45, 0xc0, 0x7f, 0x40, 0x6e, 0x6b, 0xf7, 0x28, 0xfc, 0x02, 0xea,
+	0xda, 0x1e, 0x84, 0x73, 0x2c, 0x6a, 0x17, 0x3e, 0xb0, 0xbe, 0x87, 0x04, 0xbb, 0xed, 0xf9, 0x80,
+	0x54, 0x47, 0xd9, 0x5d, 0x42, 0x55, 0x04, 0xa7, 0x4f, 0xf2, 0x16, 0xfb, 0x6d, 0xbc, 0x47, 0x16,
+	0x18, 0x30, 0xee, 0x05, 0x7d, 0x2a, 0x2a, 0xe8, 0x86, 0x0d, 0x73, 0xf6, 0x12, 0xa2, 0x93, 0xf1,
+	0xa5, 0x72, 0x6b, 0x46, 0x29, 0xb7, 0x60, 0x0f, 0x03, 0x44, 0x2d, 0xf1, 0x34, 0xe2, 0x03, 0xf5,
+	0x58, 0xb3, 0xf9, 0x63, 0x7d, 0x5f, 0x06, 0xb7, 0x91, 0xf2, 0x50, 0xc1, 0xd2, 0xa6, 0x9a, 0xb7,
+	0x26, 0x3c, 0xf8, 0x66, 0x5e, 0xbe, 0xaa, 0xae, 0x4c, 0x81, 0x37, 0x15, 0xf6, 0x67, 0x0b, 0xb0,
+	0x7f, 0x25, 0xfc, 0xcd, 0x5d, 0x03, 0xfe, 0x8a, 0xb5, 0xf4, 0xbc, 0xae, 0x96, 0x3e, 0x22, 0xcb,
+	0x92, 0x09, 0x85, 0xb9, 0xef, 0x93, 0xea, 0x30, 0xa9, 0x6e, 0xf8, 0xcb, 0x24, 0x97, 0xce, 0x53,
+	0x36, 0x5a, 0x25, 0x0e, 0x62, 0x77, 0x20, 0xbc, 0x8f, 0x0f, 0xac, 0x1f, 0x4a, 0x50, 0x82, 0x51,
+	0x7a, 0xf1, 0xe3, 0xd0, 0x0d, 0x12, 0x72, 0xe0, 0x0f, 0x2e, 0x73, 0x09, 0x44, 0xa1, 0x5d, 0xd7,
+	0x4c, 0xd3, 0x2b, 0xc6, 0x63, 0x52, 0xe7, 0xbb, 0xfd, 0xdf, 0x62, 0xdd, 0x33, 0xb2, 0xbc, 0xeb,
+	0x45, 0xf1, 0x97, 0xcc, 0xae, 0xc9, 0x55, 0x80, 0x7b, 0xf7, 0xc6, 0x61, 0x04, 0x6f, 0x44, 0xee,
+	0x85, 0x62, 0x94, 0xb9, 0x77, 0x79, 0xa2, 0x7b, 0x17, 0xa2, 0xf6, 0x19, 0x31, 0x64, 0x05, 0x2f,
+	0x6f, 0x3d, 0xf0, 0x44, 0x9f, 0x7e, 0x17, 0x6f, 0xf2, 0x0d, 0x71, 0xec, 0x91, 0x28, 0xd6, 0x87,
+	0x64, 0x0d, 0x15, 0x74, 0xc3, 0x00, 0xbb, 0x2a, 0x52, 0xd6, 0x52, 0xf6, 0x55, 0xca, 0xef, 0xeb,
+	0x1f, 0x65, 0xb2, 0x9c, 0xcd, 0x99, 0x14, 0x7b, 0xff, 0xc7, 0x96, 0xca, 0xbb, 0x64, 0xd1, 0xcd,
+	0x65, 0x47, 0x9e, 0x35, 0xf2, 0x64, 0x34, 0xc7, 0x8b, 0x20, 0xbc, 0xa0, 0xa1, 0x88, 0x43, 0x31,
+	0xba, 0xb2, 0x34, 0xbf, 0x32, 0x46, 0xe7, 0x5f, 0x29, 0x46, 0xab, 0xba, 0x18, 0x7d, 0x4c, 0xd6,
+	0xf3, 0x56, 0x10, 0xa6, 0xb6, 0x0b, 0xa6, 0x36, 0xec, 0xc2, 0xc5, 0x67, 0xf6, 0x86, 0x68, 0xbf,
+	0x75, 0x48, 0xe3, 0x0c, 0x4d, 0xba, 0xee, 0x38, 0xca, 0x5c, 0x13, 0xce, 0xdc, 0xcb, 0x43, 0x90,
+	0x44, 0xc1, 0xbb, 0x1a, 0xb1, 0x09, 0x49, 0x0f, 0x85, 0x8f, 0xac, 0x3d, 0x72, 0x5b, 0xbb, 0x6a,
+	0xba, 0x49, 0x83, 0x0b, 0x6a, 0x22, 0x5f, 0xc3, 0xb1, 0x16, 0x49, 0xe3, 0x31, 0x75, 0x07, 0x71,
+	0xd2, 0xe2, 0x81, 0x5d, 0x37, 0xd9, 0x45, 0x1c, 0x42, 0x95, 0xb3, 0x45, 0x47, 0xf1, 0x79, 0xf1,
+	0xde, 0x4a, 0x9a, 0x7b, 0x43, 0x27, 0x8d, 0x92, 0x29, 0x6c, 0xcb, 0x25, 0x27, 0x23, 0x58, 0xbf,
+	0x2f, 0x93, 0x66, 0xa2, 0x47, 0xec, 0x14, 0xd2, 0xd4, 0xb9, 0x77, 0x76, 0x9e, 0x38, 0x61, 0xaa,
+	0x8f, 0x2d, 0x5f, 0x72, 0xf4, 0x4c, 0x74, 0xd0, 0x41, 0xf0, 0xa2, 0x38, 0x89, 0x6b, 0xd4, 0xf2,
+	0x50, 0x13, 0x9c, 0xcc, 0x1b, 0xa2, 0x17, 0x6c, 0x85, 0xae, 0xe7, 0x1f, 0x4a, 0xef, 0x5f, 0xd0,
+	0xa4, 0x65, 0x62, 0x8e, 0x60, 0x45, 0x98, 0x78, 0x72, 0xf2, 0x81, 0xf1, 0x88, 0x2c, 0xa5, 0xa7,
+	0xda, 0x10, 0xf7, 0x31, 0x2b, 0x9a, 0x61, 0xea, 0xbd, 0x39, 0x05, 0x41, 0xeb, 0x21, 0xa9, 0x63,
+	0x0f, 0x2b, 0xba, 0x5e, 0x60, 0x7f, 0x81, 0x89, 0xd2, 0xef, 0x83, 0x6f, 0x6d, 0x62, 0x05, 0x70,
+	0x4d, 0x3b, 0xa4, 0xd5, 0x43, 0x59, 0xae, 0x1e, 0x7e, 0x28, 0x93, 0x86, 0x50, 0x2d, 0xae, 0x1f,
+	0x12, 0xeb, 0x88, 0xaf, 0x9e, 0xe6, 0x2d, 0x59, 0x9b, 0x93, 0x70, 0xd1, 0x11, 0x79, 0x20, 0x26,
+	0x1d, 0x19, 0x3e, 0x42, 0x07, 0x1e, 0xa5, 0xde, 0x2f, 0xca, 0x07, 0x89, 0x82, 0x80, 0x22, 0x9b,
+	0x50, 0xe4, 0x04, 0x85, 0x86, 0x6f, 0x66, 0xc9, 0x62, 0xa2, 0x47, 0x25, 0x93, 0xf0, 0x31, 0x98,
+	0x24, 0x02, 0x96, 0x18, 0xe0, 0xc5, 0x9d, 0x8c, 0x51, 0x83, 0x9b, 0xd5, 0x51, 0x3c, 0x0b, 0x80,
+	0x06, 0x99, 0x36, 0xd9, 0xf6, 0xd5, 0x29, 0xb6, 0x47, 0x43, 0x75, 0xc7, 0x61, 0x56, 0xd1, 0x4f,
+	0x37, 0xd4, 0x7d, 0xd2, 0x10, 0xd2, 0x59, 0xab, 0x3c, 0xa4, 0xc3, 0x20, 0xeb, 0x3f, 0x25, 0x43,
+	0x6b, 0x9f, 0x98, 0x9d, 0xef, 0x46, 0x41, 0x18, 0x6f, 0x81, 0x37, 0xed, 0xd2, 0x38, 0xa6, 0xe1,
+	0xf5, 0xbc, 0x81, 0xd9, 0x75, 0x40, 0xdd, 0x50, 0xa4, 0x03, 0x3e, 0xb0, 0x9e, 0x90, 0x9b, 0x9a,
+	0xf5, 0xb2, 0x3e, 0x18, 0x65, 0xcc, 0xac, 0x0f, 0x96, 0x8c, 0xb1, 0x72, 0xfc, 0x4d, 0x14, 0x24,
+	0xbd, 0x7a, 0xf6, 0xdb, 0xfa, 0x92, 0xac, 0x75, 0x9e, 0x53, 0x3f, 0x2e, 0xbc, 0xe3, 0xa7, 0xef,
+	0x6c, 0xca, 0x7b, 0xdd, 0x32, 0xc9, 0x7a, 0x7e, 0x49, 0x51, 0xa9, 0xff, 0xa5, 0x4c, 0x0c, 0x86,
+	0xa5, 0x8c, 0x2f, 0xb5, 0x15, 0x2b, 0x17, 0x50, 0x22, 0x89, 0xde, 0x2a, 0xb1, 0x19, 0xf7, 0x09,
+	0x50, 0x1c, 0x46, 0x2f, 0xf4, 0x89, 0xc0, 0x83, 0xce, 0xdd, 0xa8, 0x2b, 0x57, 0x25, 0x55, 0x47,
+	0x26, 0x15, 0xc3, 0xa6, 0xa2, 0x0b, 0x9b, 0x49, 0xc0, 0x37, 0x3b, 0x05, 0xf8, 0xc0, 0xb7, 0xc0,
+	0x79, 0xe0, 0x82, 0xdc, 0xe1, 0x48, 0x81, 0x3f, 0xee, 0xa8, 0x7a, 0x26, 0x7a, 0xed, 0xd0, 0x8b,
+	0x20, 0x0f, 0xb3, 0xa3, 0x45, 0x89, 0xd7, 0xca, 0x34, 0xd6, 0xc8, 0xc0, 0x86, 0x40, 0xb8, 0xe3,
+	0x9f, 0x06, 0x49, 0x66, 0xfe, 0x33, 0xdc, 0x98, 0x4c, 0xcd, 0x32, 0xfe, 0x78, 0x84, 0xaa, 0x94,
+	0x2d, 0x70, 0x7b, 0x6b, 0x38, 0xe8, 0x9c, 0xb0, 0x04, 0xbe, 0xb2, 0xc5, 0x35, 0x26, 0x43, 0x34,
+	0xf3, 0x99, 0x17, 0xf3, 0x46, 0x66, 0x52, 0xff, 0xa4, 0x04, 0xe3, 0x53, 0xc8, 0x7c, 0x71, 0x10,
+	0x02, 0xb4, 0x6d, 0x7b, 0x03, 0x7a, 0xe8, 0xfd, 0x56, 0x74, 0xe3, 0x6a, 0xad, 0x65, 0xfb, 0x30,
+	0xc7, 0x70, 0x0a, 0xa2, 0xb2, 0x19, 0x36, 0x5c, 0xde, 0x90, 0xc6, 0x9b, 0x55, 0x89, 0x68, 0x86,
+	0x3e, 0x3d, 0x75, 0xa1, 0xf2, 0xeb, 0x6a, 0x5a, 0xd3, 0x5a, 0x9e, 0xf5, 0x27, 0x6c, 0x3d, 0xe5,
+	0xd5, 0x3d, 0x24, 0xcb, 0x72, 0xa6, 0xd9, 0xb8, 0x8c, 0x69, 0x72, 0x29, 0x45, 0x06, 0x3e, 0xc5,
+	0xa5, 0xa4, 0xc3, 0x85, 0x79, 0xb6, 0x2b, 0xd0, 0xd9, 0x5b, 0x3b, 0x79, 0x97, 0x73, 0x49, 0x9e,
+	0xfb, 0x72, 0x54, 0x7c, 0xa3, 0x6e, 0x79, 0xee, 0x99, 0x1f, 0x40, 0x86, 0xe9, 0x6d, 0x8d, 0x87,
+	0x49, 0xeb, 0x03, 0x92, 0xcb, 0x7a, 0x9e, 0x21, 0x4c, 0x99, 0x04, 0x65, 0x49, 0x0a, 0xca, 0x1d,
+	0xd2, 0xe8, 0xe0, 0x87, 0x0d, 0x39, 0xb9, 0xc8, 0x3d, 0xd4, 0x85, 0xec, 0x15, 0x05, 0xb1, 0xd3,
+	0xcb, 0x1e, 0x7d, 0x10, 0x3b, 0x38, 0x6f, 0x13, 0x5f, 0x7c, 0x8c, 0x6e, 0xfd, 0xa7, 0x8e, 0x9d,
+	0x30, 0xb6, 0x89, 0xa7, 0xa1, 0x3b, 0x1a, 0xd1, 0x90, 0x83, 0xdc, 0xe9, 0x4e, 0x9f, 0xf5, 0x23,
+	0x67, 0x1d, 0x3e, 0x80, 0x27, 0xe4, 0xbc, 0x48, 0xb2, 0x4c, 0x45, 0xf1, 0x43, 0xcf, 0xe3, 0xd7,
+	0x9c, 0x44, 0xc2, 0x78, 0x83, 0xcc, 0x8c, 0x82, 0x91, 0xa8, 0xce, 0x6b, 0x76, 0xd6, 0xe3, 0x01,
+	0x21, 0xe4, 0x18, 0xbf, 0x24, 0x35, 0x29, 0x25, 0xb3, 0xdb, 0xc2, 0xaf, 0x2f, 0xc5, 0xc6, 0x09,
+	0x4c, 0x90, 0x25, 0x8d, 0x4f, 0x20, 0xbf, 0x4b, 0x5f, 0x22, 0x99, 0x13, 0xd4, 0x5a, 0xab, 0xb6,
+	0xe6, 0x8b, 0x28, 0x4c, 0x55, 0x64, 0xa1, 0x28, 0x9d, 0x3b, 0x67, 0xf5, 0x06, 0x8b, 0xaf, 0x5a,
+	0xab, 0x69, 0x2b, 0x65, 0x0e, 0xc8, 0x0b, 0xbe, 0x71, 0x40, 0x56, 0xdc, 0xe2, 0x6b, 0x9c, 0xe1,
+	0x43, 0xad, 0x75, 0xdb, 0x9e, 0xdc, 0x4d, 0x80, 0x35, 0x74, 0x33, 0x21, 0x50, 0x1a, 0xa7, 0x72,
+	0x73, 0x82, 0xb5, 0x82, 0x6b, 0xad, 0x35, 0x5b, 0xd7, 0x0e, 0x81, 0x45, 0x54, 0x69, 0xc8, 0x2a,
+	0x24, 0x4a, 0xa3, 0x9c, 0xb5, 0x7d, 0xb1, 0xd0, 0x2c, 0xa4, 0x03, 0x98, 0x28, 0xc9, 0x41, 0x56,
+	0xa9, 0xe0, 0x32, 0x66, 0x9d, 0xc9, 0xd7, 0x6d, 0xa9, 0x5b, 0x04, 0x92, 0x8c, 0x67, 0xfc, 0x8a,
+	0x34, 0xfb, 0x8a, 0xe3, 0xb1, 0xd6, 0x6e, 0xad, 0xb5, 0x6e, 0x6b, 0x1d, 0x15, 0xe6, 0xe5, 0xe4,
+	0xd1, 0x22, 0x27, 0xd2, 0xc7, 0x20, 0xb3, 0x29, 0x2c, 0xa2, 0xf9, 0x52, 0x86, 0x16, 0x91, 0x65,
+	0x71, 0x2e, 0x95, 0xbe, 0xd6, 0x98, 0x8b, 0x62, 0xae, 0xe6, 0x2b, 0x11, 0xce, 0x95, 0x65, 0xf1,
+	0x4a, 0x7b, 0xf2, 0xf7, 0x15, 0x73, 0x49, 0x5c, 0xa9, 0xee, 0x5b, 0x0e, 0x5e, 0xa9, 0x22, 0x6d,
+	0xbc, 0x9f, 0x34, 0xf1, 0xc0, 0x39, 0xcd, 0x65, 0x91, 0xb3, 0xf2, 0x2d, 0x49, 0x98, 0x96, 0x49,
+	0xa1, 0xff, 0xf0, 0x81, 0x69, 0x08, 0xff, 0x51, 0xfa, 0x8a, 0xe8, 0x3f, 0x9c, 0x8f, 0x8b, 0x9f,
+	0x26, 0x8f, 0x7a, 0x73, 0x45, 0x2c, 0x9e, 0xef, 0xd4, 0xe0, 0xe2, 0xa9, 0x14, 0x1a, 0x62, 0xa0,
+	0xbc, 0x31, 0xcc, 0x55, 0x61, 0x08, 0xed, 0x03, 0x10, 0x0d, 0xa1, 0xca, 0xa3, 0xd3, 0x46, 0xc5,
+	0x57, 0x80, 0xb9, 0x26, 0x9c, 0x76, 0xf2, 0xbb, 0x03, 0x9d, 0x56, 0x33, 0x13, 0x4f, 0x11, 0x25,
+	0x18, 0x6d, 0xae, 0x27, 0x69, 0x3d, 0x57, 0x08, 0xe0, 0x29, 0x52, 0x29, 0xcc, 0x12, 0xbc, 0xe1,
+	0x4d, 0xcd, 0x1b, 0x22, 0x4b, 0xa8, 0x7d, 0x76, 0xcc, 0x12, 0x42, 0xc2, 0xd8, 0x25, 0x46, 0xaf,
+	0xd0, 0x3f, 0x36, 0x4d, 0x36, 0xef, 0x96, 0x3d, 0xb1, 0x4b, 0x0d, 0x4b, 0x68, 0xe6, 0xa1, 0xea,
+	0x3e, 0x6f, 0x37, 0x9a, 0x37, 0x85, 0x6a, 0xb5, 0x41, 0x89, 0xaa, 0x85, 0x84, 0xe4, 0x78, 0xec,
+	0x4b, 0xaf, 0x79, 0x4b, 0x75, 0x3c, 0xf9, 0x03, 0xb7, 0xe4, 0x78, 0x8c, 0x8c, 0xc1, 0x38, 0x48,
+	0x1f, 0xfd, 0xe6, 0x6d, 0x11, 0x8c, 0x85, 0x46, 0x03, 0x06, 0x63, 0x26, 0x87, 0xc1, 0x38, 0xa2,
+	0xf4, 0xc2, 0xbc, 0x23, 0x82, 0x51, 0xea, 0xce, 0x60, 0x30, 0x22, 0x0f, 0x3f, 0xa5, 0xe3, 0x77,
+	0xec, 0xc8, 0x7c, 0x5d, 0xb4, 0x35, 0xe4, 0x97, 0x01, 0x48, 0x71, 0x2e, 0x8a, 0x8d, 0xb0, 0xb6,
+	0x34, 0x7f, 0x22, 0xc4, 0xe4, 0xba, 0x14, 0xc5, 0x18, 0x17, 0x3d, 0x8a, 0x2a, 0x75, 0x96, 0xf9,
+	0x86, 0xf0, 0x28, 0x6d, 0x45, 0x87, 0x1e, 0xa5, 0xca, 0x1b, 0x3b, 0x64, 0x99, 0xe6, 0x2b, 0x49,
+	0xf3, 0x2e, 0x5b, 0xe4, 0xa6, 0x3d, 0xa9, 0x66, 0x85, 0x75, 0x8a, 0xb3, 0x36, 0x16, 0x52, 0x84,
+	0xb2, 0xfe, 0xdd, 0x60, 0x1f, 0x37, 0x18, 0x72, 0x4d, 0xc7, 0x9c, 0x87, 0x79, 0xcc, 0x59, 0xb2,
+	0x73, 0x1f, 0x53, 0x65, 0xd0, 0xb9, 0x2b, 0x83, 0x4e, 0xdd, 0x96, 0x3e, 0x10, 0x24, 0xa8, 0xf3,
+	0xb1, 0x0e, 0x75, 0x56, 0x6d, 0x4d, 0x23, 0x3e, 0x0f, 0x3b, 0xf7, 0x92, 0xff, 0x15, 0x54, 0x44,
+	0xe4, 0x2b, 0xf8, 0x8b, 0x77, 0xce, 0xd8, 0xf0, 0x14, 0xd4, 0xc1, 0xd3, 0x9a, 0xad, 0xfb, 0xf7,
+	0x4c, 0x01, 0x9f, 0xee, 0xe7, 0xf0, 0x69, 0xd1, 0x56, 0x9f, 0xc7, 0x12, 0x40, 0x75, 0xa7, 0x01,
+	0xd4, 0x1d, 0x7b, 0x4a, 0x2b, 0x79, 0x12, 0x42, 0x7d, 0xa6, 0x47, 0xa8, 0x75, 0x5b, 0xdb, 0x54,
+	0x2f, 0x42, 0xd4, 0x87, 0x1a, 0x88, 0x5a, 0xb1, 0x8b, 0xb5, 0x69, 0x0e, 0xa3, 0xde, 0x54, 0x30,
+	0xaa, 0x61, 0xcb, 0xdf, 0x18, 0x52, 0x90, 0x6a, 0x4f, 0x00, 0xa9, 0x1b, 0xb6, 0xbe, 0x68, 0xd2,
+	0xa0, 0xd4, 0x23, 0x2d, 0x4a, 0xad, 0xd9, 0xba, 0xff, 0x31, 0x14, 0x60, 0xea, 0x91, 0x16, 0xa6,
+	0xd6, 0x6c, 0xdd, 0x3f, 0x0d, 0x0a, 0x38, 0xf5, 0x99, 0x1e, 0xa7, 0xd6, 0x6d, 0xed, 0xbf, 0x03,
+	0x8a, 0x40, 0xd5, 0x2a, 0x02, 0x95, 0x61, 0x17, 0xbe, 0x81, 0xa9, 0x48, 0x75, 0x3f, 0x87, 0x54,
+	0x8b, 0xb6, 0xfa, 0x09, 0x4b, 0x82, 0xaa, 0x56, 0x11, 0xaa, 0x0c, 0xbb, 0xd0, 0x91, 0x56, 0xb1,
+	0xaa, 0x3d, 0x01, 0xab, 0x6e, 0xd8, 0xfa, 0x36, 0x99, 0x06, 0xac, 0xba, 0xd3, 0xc0, 0xea, 0x8e,
+	0x3d, 0xa5, 0x9d, 0x35, 0x09, 0xad, 0x5a, 0x45, 0xb4, 0x32, 0xec, 0xc2, 0x1b, 0x53, 0x85, 0xab,
+	0x87, 0x79, 0xb8, 0x5a, 0xb2, 0x73, 0x9f, 0x5d, 0x65, 0xbc, 0xda, 0x9b, 0x82, 0x57, 0xb7, 0xed,
+	0xc9, 0x9f, 0x42, 0x27, 0x00, 0xd6, 0xc3, 0x3c, 0x60, 0x2d, 0xd9, 0xb9, 0xef, 0x65, 0x32, 0x62,
+	0x3d, 0xd2, 0x22, 0xd6, 0x9a, 0xad, 0xfb, 0x63, 0x53, 0x01, 0xb2, 0x3e, 0xd4, 0x40, 0xd6, 0x8a,
+	0x5d, 0x6c, 0x5d, 0xe7, 0x30, 0xeb, 0x4d, 0x05, 0xb3, 0x1a, 0xb6, 0xdc, 0xa3, 0x4f, 0x41, 0xeb,
+	0x9e, 0x0a, 0x5a, 0x4d, 0x5b, 0xe9, 0x29, 0x65, 0xa8, 0x75, 0x4f, 0x45, 0xad, 0xa6, 0xad, 0xf4,
+	0x47, 0x32, 0xd8, 0x6a, 0x4f, 0x80, 0xad, 0x1b, 0xb6, 0xbe, 0x6b, 0xa0, 0xc1, 0xad, 0xf7, 0x20,
+	0x5b, 0x23, 0x45, 0x60, 0xd5, 0x8a, 0x5d, 0x6c, 0x2a, 0xb0, 0x94, 0x8d, 0x04, 0xe3, 0x0b, 0x1d,
+	0xc8, 0xfd, 0x54, 0x14, 0x21, 0x13, 0x1b, 0x29, 0x57, 0xa1, 0xdc, 0x83, 0xd7, 0x49, 0x35, 0x6d,
+	0x37, 0xcc, 0x93, 0x99, 0xdd, 0x83, 0xa7, 0x4b, 0xaf, 0x19, 0x55, 0x52, 0x79, 0xbc, 0xf3, 0xf9,
+	0xe3, 0xa5, 0xd2, 0x83, 0x9f, 0xc3, 0x13, 0x4e, 0xfe, 0x07, 0x9b, 0x51, 0x23, 0xf3, 0x9b, 0x4e,
+	0xa7, 0x7d, 0xd4, 0xd9, 0x02, 0x39, 0x83, 0x34, 0xdb, 0xbb, 0x30, 0xda, 0xfa, 0xfa, 0x59, 0xe7,
+	0xab, 0x9d, 0xc3, 0xa3, 0x43, 0x98, 0x71, 0x97, 0xd4, 0xe5, 0x2f, 0x76, 0xb8, 0x68, 0x7b, 0x77,
+	0x17, 0x84, 0xf1, 0xc7, 0xfe, 0xd7, 0x20, 0xf1, 0x6b, 0xb2, 0x90, 0xb6, 0x46, 0x8c, 0x3a, 0xa9,
+	0x76, 0xf6, 0xbf, 0x3c, 0xee, 0x1c, 0xb3, 0x05, 0x09, 0x99, 0xeb, 0x1e, 0x74, 0xbb, 0xf0, 0xbb,
+	0x64, 0x2c, 0x91, 0x7a, 0x7b, 0xf3, 0xc9, 0xfe, 0xc1, 0xd3, 0xdd, 0xce, 0xd6, 0xe7, 0x40, 0x29,
+	0x23, 0x77, 0xbb, 0xbd, 0x03, 0xc3, 0xa5, 0x19, 0x63, 0x99, 0x34, 0xb6, 0x40, 0xf1, 0xb3, 0xdd,
+	0xce, 0xd1, 0x51, 0xc7, 0x01, 0x52, 0xe5, 0xc1, 0x17, 0xb0, 0x6e, 0xf2, 0x6c, 0xc4, 0x7d, 0x1e,
+	0xef, 0xe3, 0xec, 0x7d, 0x58, 0x16, 0x96, 0x3a, 0x3c, 0x3a, 0x70, 0xda, 0x9f, 0x77, 0x9e, 0x6d,
+	0x1f, 0xc3, 0x66, 0x4a, 0xc6, 0x22, 0xa9, 0x6d, 0xc0, 0x6c, 0xa7, 0x03, 0xaa, 0x0f, 0x8f, 0x60,
+	0xed, 0x06, 0x59, 0xd8, 0x3f, 0x38, 0x7a, 0xb6, 0x7d, 0x70, 0xbc, 0x0f, 0xcb, 0x6f, 0xac, 0x7c,
+	0x33, 0x7f, 0x12, 0xb2, 0x73, 0xff, 0xbd, 0x9c, 0xfc, 0x3a, 0x99, 0x63, 0x7f, 0x6c, 0xfd, 0xe0,
+	0xbf, 0x68, 0x40, 0x93, 0x50, 0xe7, 0x2a, 0x00, 0x00,
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::From;
 use std::error::Error as StdError;
 use std::fmt::{Display, Error as FmtError, Formatter};
@@ -9,20 +9,29 @@ use std::io::Error as IOError;
 use std::io::ErrorKind as IOErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::{PoisonError, RwLock};
+use std::sync::{PoisonError, RwLock, Weak};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
 
 use bcrypt;
 use bincode::{deserialize_from, Error as BinCodeError, serialize_into};
 use serde_derive::{Deserialize, Serialize};
 
+use crate::config::DefaultUser;
+
 // In debug builds we use a much smaller number of bcrypt rounds
 // as it's extremely slow, which is really annoying when developing.
 #[cfg(not(debug_assertions))]
-const BCRYPT_ROUNDS: u32 = 13;
+pub const DEFAULT_BCRYPT_COST: u32 = 13;
 
 #[cfg(debug_assertions)]
-const BCRYPT_ROUNDS: u32 = 6;
+pub const DEFAULT_BCRYPT_COST: u32 = 6;
+
+// The costs bcrypt supports
+const MIN_BCRYPT_COST: u32 = 4;
+const MAX_BCRYPT_COST: u32 = 31;
 
 #[derive(Debug)]
 pub enum AuthenticationError {
@@ -31,6 +40,10 @@ pub enum AuthenticationError {
     MutexCorrupted,
     BcryptError(bcrypt::BcryptError),
     UserAlreadyExists,
+    // The users couldn't be loaded, so nobody can log in
+    Locked,
+    // Bcrypt only supports costs from 4 to 31
+    InvalidBcryptCost(u32),
 }
 
 impl Display for AuthenticationError {
@@ -41,6 +54,10 @@ impl Display for AuthenticationError {
             AuthenticationError::MutexCorrupted => write!(f, "Mutex corrupted"),
             AuthenticationError::BcryptError(e) => write!(f, "Bcrypt error: {}", e),
             AuthenticationError::UserAlreadyExists => write!(f, "User already exists"),
+            AuthenticationError::Locked => write!(f, "Authentication is unavailable, the users could not be loaded"),
+            AuthenticationError::InvalidBcryptCost(cost) => {
+                write!(f, "Bcrypt cost {} is not supported, it must be from {} to {}", cost, MIN_BCRYPT_COST, MAX_BCRYPT_COST)
+            }
         }
     }
 }
@@ -97,8 +114,8 @@ impl AuthenticationData {
         }
     }
 
-    fn add_user(&mut self, username: String, pw: String) -> Result<(), bcrypt::BcryptError> {
-        let pw_hash = bcrypt::hash(&pw, BCRYPT_ROUNDS)?;
+    fn add_user(&mut self, username: String, pw: String, cost: u32) -> Result<(), bcrypt::BcryptError> {
+        let pw_hash = bcrypt::hash(&pw, cost)?;
 
         self.users.insert(username.clone(), User::new(username, pw_hash));
 
@@ -106,10 +123,57 @@ impl AuthenticationData {
     }
 }
 
+// Decides who can connect, so users can be managed somewhere else than
+// in the users file, for example in LDAP or an external auth service
+pub trait AuthBackend: Send + Sync {
+    // Returns false if the credentials are wrong
+    fn verify_user(&self, username: &str, password: &str) -> Result<bool, AuthenticationError>;
+
+    // Checks if the user is allowed to use admin operations
+    fn is_admin(&self, username: &str) -> bool;
+}
+
+// Users stored in a file, with bcrypt hashed passwords
 #[derive(Debug, Clone)]
 pub struct Authentication {
     data: Arc<RwLock<AuthenticationData>>,
     data_path: PathBuf,
+    // The users allowed to use admin operations.
+    // If not set no user is an admin.
+    admin_users: Option<HashSet<String>>,
+    // If set changes are only saved once this many has piled up,
+    // or when they are flushed by the background thread
+    save_threshold: Option<usize>,
+    // How many changes hasn't been saved yet
+    pending_changes: Arc<AtomicUsize>,
+    // How many times the file has been written
+    saves: Arc<AtomicUsize>,
+    // Set if the users couldn't be loaded. Every login is rejected,
+    // and nothing is saved, so the file isn't overwritten before it has been fixed.
+    locked: bool,
+    // How many rounds new passwords are hashed with, as a power of two
+    bcrypt_cost: u32,
+}
+
+impl AuthBackend for Authentication {
+    fn verify_user(&self, username: &str, password: &str) -> Result<bool, AuthenticationError> {
+        Authentication::verify_user(self, username, password)
+    }
+
+    fn is_admin(&self, username: &str) -> bool {
+        Authentication::is_admin(self, username)
+    }
+}
+
+// Writes all the users to the file, replacing what was there
+fn save(path: &Path, data: &RwLock<AuthenticationData>) -> Result<(), AuthenticationError> {
+    let writer = BufWriter::new(File::create(path)?);
+
+    let data = data.read()?;
+
+    serialize_into(writer, &*data)?;
+
+    Ok(())
 }
 
 fn load(path: &Path) -> Result<AuthenticationData, AuthenticationError> {
@@ -125,25 +189,131 @@ fn load(path: &Path) -> Result<AuthenticationData, AuthenticationError> {
 
 impl Authentication {
     pub fn new(path: PathBuf) -> Result<Authentication, AuthenticationError> {
+        Authentication::new_with_admin_users(path, None)
+    }
+
+    pub fn new_with_admin_users(path: PathBuf, admin_users: Option<Vec<String>>) -> Result<Authentication, AuthenticationError> {
         let data = load(&path)?;
 
         Ok(Authentication {
             data: Arc::new(RwLock::new(data)),
             data_path: path,
+            admin_users: admin_users.map(|users| users.into_iter().collect()),
+            save_threshold: None,
+            pending_changes: Arc::new(AtomicUsize::new(0)),
+            saves: Arc::new(AtomicUsize::new(0)),
+            locked: false,
+            bcrypt_cost: DEFAULT_BCRYPT_COST,
         })
     }
 
-    fn save_changes(&self) -> Result<(), AuthenticationError> {
-        let writer = BufWriter::new(File::create(&self.data_path)?);
+    // Same as new_with_admin_users, but if the users can't be loaded authentication is
+    // locked instead of failing, so the server can keep running while the file is fixed
+    pub fn new_or_locked(path: PathBuf, admin_users: Option<Vec<String>>) -> Authentication {
+        match Authentication::new_with_admin_users(path.clone(), admin_users.clone()) {
+            Ok(auth) => auth,
+            Err(e) => {
+                eprintln!("ERROR: Failed to load users from {}: {}", path.display(), e);
+                eprintln!("ERROR: Authentication is locked, every login will be rejected until the file is fixed and the server restarted");
+                Authentication {
+                    data: Arc::new(RwLock::new(AuthenticationData::new())),
+                    data_path: path,
+                    admin_users: admin_users.map(|users| users.into_iter().collect()),
+                    save_threshold: None,
+                    pending_changes: Arc::new(AtomicUsize::new(0)),
+                    saves: Arc::new(AtomicUsize::new(0)),
+                    locked: true,
+                    bcrypt_cost: DEFAULT_BCRYPT_COST,
+                }
+            }
+        }
+    }
 
-        let data = self.data.read()?;
+    // Hashes new passwords with the given cost, so the time it takes to check
+    // a password can be tuned to the hardware. Existing passwords keep their cost.
+    pub fn with_bcrypt_cost(mut self, cost: u32) -> Result<Authentication, AuthenticationError> {
+        if !(MIN_BCRYPT_COST..=MAX_BCRYPT_COST).contains(&cost) {
+            return Err(AuthenticationError::InvalidBcryptCost(cost));
+        }
+        self.bcrypt_cost = cost;
+        Ok(self)
+    }
 
-        serialize_into(writer, &*data)?;
+    // True if the users couldn't be loaded, and every login is rejected
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
 
+    // Stops saving every change right away. Instead changes are saved once `threshold`
+    // changes has piled up, and on the given interval. Makes bulk imports of users much faster.
+    // Unsaved changes are lost if the server stops without calling flush.
+    pub fn with_batched_saves(mut self, flush_interval: Duration, threshold: usize) -> Authentication {
+        self.save_threshold = Some(threshold);
+
+        let data: Weak<RwLock<AuthenticationData>> = Arc::downgrade(&self.data);
+        let path = self.data_path.clone();
+        let pending_changes = self.pending_changes.clone();
+        let saves = self.saves.clone();
+        thread::spawn(move || {
+            loop {
+                thread::sleep(flush_interval);
+
+                let data = match data.upgrade() {
+                    Some(data) => data,
+                    None => return,
+                };
+
+                if pending_changes.swap(0, Ordering::SeqCst) > 0 {
+                    saves.fetch_add(1, Ordering::SeqCst);
+                    if let Err(e) = save(&path, &data) {
+                        eprintln!("Failed to save users: {}", e);
+                    }
+                }
+            }
+        });
+
+        self
+    }
+
+    // Saves any changes that hasn't been saved yet
+    pub fn flush(&self) -> Result<(), AuthenticationError> {
+        if self.pending_changes.swap(0, Ordering::SeqCst) > 0 {
+            self.save_changes()?;
+        }
         Ok(())
     }
 
+    // Checks if the user is allowed to use admin operations
+    pub fn is_admin(&self, username: &str) -> bool {
+        match &self.admin_users {
+            Some(admins) => admins.contains(username),
+            None => false,
+        }
+    }
+
+    fn save_changes(&self) -> Result<(), AuthenticationError> {
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        save(&self.data_path, &self.data)
+    }
+
+    // Saves the change, unless changes are batched
+    fn changed(&self) -> Result<(), AuthenticationError> {
+        match self.save_threshold {
+            Some(threshold) => {
+                if self.pending_changes.fetch_add(1, Ordering::SeqCst) + 1 >= threshold {
+                    self.flush()?;
+                }
+                Ok(())
+            }
+            None => self.save_changes(),
+        }
+    }
+
     pub fn verify_user(&self, username: &str, password: &str) -> Result<bool, AuthenticationError> {
+        if self.locked {
+            return Err(AuthenticationError::Locked);
+        }
+
         let guard = self.data.read()?;
 
         let user = match guard.users.get(username) {
@@ -155,17 +325,21 @@ impl Authentication {
     }
 
     pub fn add_user(&mut self, username: String, password: String) -> Result<(), AuthenticationError> {
+        if self.locked {
+            return Err(AuthenticationError::Locked);
+        }
+
         let mut guard = self.data.write()?;
 
         if guard.users.contains_key(&username) {
             return Err(AuthenticationError::UserAlreadyExists);
         }
 
-        guard.add_user(username, password);
+        guard.add_user(username, password, self.bcrypt_cost)?;
 
         drop(guard);
 
-        self.save_changes()?;
+        self.changed()?;
 
         Ok(())
     }
@@ -173,20 +347,41 @@ impl Authentication {
     // Adds the given user, only if no users currently exists
     // Returns true if the user was added, false otherwise
     pub fn add_default_user(&mut self, username: String, password: String) -> Result<bool, AuthenticationError> {
+        if self.locked {
+            return Err(AuthenticationError::Locked);
+        }
+
         let mut guard = self.data.write()?;
 
         if !guard.users.is_empty() {
             return Ok(false);
         }
 
-        guard.add_user(username, password);
+        guard.add_user(username, password, self.bcrypt_cost)?;
 
         drop(guard);
 
-        self.save_changes()?;
+        self.changed()?;
 
         Ok(true)
     }
+
+    // Adds the configured default user, if there is one and no users exists yet
+    pub fn seed_default_user(&mut self, default_user: &Option<DefaultUser>) -> Result<bool, AuthenticationError> {
+        let default_user = match default_user {
+            // The existing users just couldn't be loaded
+            Some(_) if self.locked => return Ok(false),
+            Some(default_user) => default_user,
+            None => return Ok(false),
+        };
+
+        let added = self.add_default_user(default_user.username.clone(), default_user.password.clone())?;
+        if added && default_user.username == "guest" && default_user.password == "guest" {
+            eprintln!("WARNING: Created the default user guest with password guest. Anyone can log in with it, \
+                so set BRQUEUE_CREATE_DEFAULT_USER=false or pick another password in production.");
+        }
+        Ok(added)
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +408,25 @@ mod test {
         assert_eq!(a.verify_user("wrong_user", "pw").unwrap(), false);
     }
 
+    #[test]
+    fn hashes_passwords_with_the_configured_cost() {
+        let path = setup();
+
+        let mut a = Authentication::new(PathBuf::from(path)).unwrap().with_bcrypt_cost(4).unwrap();
+        a.add_user("u1".to_string(), "pw".to_string()).unwrap();
+
+        let hash = a.data.read().unwrap().users["u1"].password.clone();
+        assert_eq!(hash.split('$').nth(2), Some("04"));
+        assert_eq!(a.verify_user("u1", "pw").unwrap(), true);
+
+        for &cost in &[3, 32] {
+            match Authentication::new(PathBuf::from(setup())).unwrap().with_bcrypt_cost(cost) {
+                Err(AuthenticationError::InvalidBcryptCost(invalid)) => assert_eq!(invalid, cost),
+                _ => panic!("Cost {} was accepted", cost),
+            }
+        }
+    }
+
     #[test]
     fn can_load_users() {
         let path = setup();
@@ -230,6 +444,31 @@ mod test {
         assert_eq!(a.verify_user("wrong_user", "pw").unwrap(), false);
     }
 
+    #[test]
+    fn batched_saves_write_the_file_less_often() {
+        let path = setup();
+
+        let mut a = Authentication::new(PathBuf::from(path.clone()))
+            .unwrap()
+            .with_batched_saves(Duration::from_secs(60), 10);
+
+        for i in 0..25 {
+            a.add_user(format!("u{}", i), "pw".to_string()).unwrap();
+        }
+        assert_eq!(a.saves.load(Ordering::SeqCst), 2);
+        // The users that hasn't been saved yet can still log in
+        assert!(a.verify_user("u24", "pw").unwrap());
+
+        // Like when shutting down
+        a.flush().unwrap();
+        assert_eq!(a.saves.load(Ordering::SeqCst), 3);
+        drop(a);
+
+        let a = Authentication::new(PathBuf::from(path)).unwrap();
+        assert!(a.verify_user("u0", "pw").unwrap());
+        assert!(a.verify_user("u24", "pw").unwrap());
+    }
+
     #[test]
     fn add_default_user_when_no_user_exists() {
         let path = setup();
@@ -240,6 +479,62 @@ mod test {
         assert!(a.verify_user("guest", "guest").unwrap());
     }
 
+    #[test]
+    fn no_default_user_is_created_when_disabled() {
+        let path = setup();
+
+        let mut a = Authentication::new(PathBuf::from(path.clone())).unwrap();
+
+        assert!(!a.seed_default_user(&None).unwrap());
+        assert!(!a.verify_user("guest", "guest").unwrap());
+
+        let default_user = DefaultUser {
+            username: "admin".to_string(),
+            password: "secret".to_string(),
+        };
+        assert!(a.seed_default_user(&Some(default_user)).unwrap());
+        assert!(a.verify_user("admin", "secret").unwrap());
+        assert!(!a.verify_user("guest", "guest").unwrap());
+    }
+
+    #[test]
+    fn corrupt_users_file_locks_authentication() {
+        let path = setup();
+        std::fs::write(&path, b"not users").unwrap();
+
+        assert!(Authentication::new(PathBuf::from(path.clone())).is_err());
+
+        let mut a = Authentication::new_or_locked(PathBuf::from(path.clone()), None);
+        assert!(a.is_locked());
+        match a.verify_user("guest", "guest") {
+            Err(AuthenticationError::Locked) => {}
+            _ => panic!("Login was not rejected"),
+        }
+        match a.add_user("u".to_string(), "p".to_string()) {
+            Err(AuthenticationError::Locked) => {}
+            _ => panic!("User was added while locked"),
+        }
+        assert!(!a.seed_default_user(&Some(DefaultUser {
+            username: "guest".to_string(),
+            password: "guest".to_string(),
+        })).unwrap());
+
+        // The broken file is left for an operator to fix
+        assert_eq!(std::fs::read(&path).unwrap(), b"not users");
+    }
+
+    #[test]
+    fn only_configured_users_are_admins() {
+        let path = setup();
+
+        let a = Authentication::new(PathBuf::from(path.clone())).unwrap();
+        assert!(!a.is_admin("anyone"));
+
+        let a = Authentication::new_with_admin_users(PathBuf::from(path), Some(vec!["admin".to_string()])).unwrap();
+        assert!(a.is_admin("admin"));
+        assert!(!a.is_admin("guest"));
+    }
+
     #[test]
     fn _add_default_user_cant_add_when_other_exists() {
         let path = setup();
@@ -1,25 +1,36 @@
 use core::borrow::BorrowMut;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::convert::From;
 use std::io::Cursor;
 use std::io::Error as IOError;
+use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
 use std::net::{TcpListener, TcpStream};
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use crossbeam::channel::{bounded, never, Receiver, Sender};
 use log::debug;
+use tracing::info_span;
 use protobuf::{Message, ProtobufError};
 use uuid::Uuid;
 
-use crate::authentication::Authentication;
+use crate::authentication::AuthBackend;
 use crate::authentication::AuthenticationError;
 use crate::binary::get_size;
 use crate::binary::get_size_array;
+use crate::events::{EventKind, QueueEvent};
 use crate::models;
+use crate::prefetch::PrefetchWindow;
+use crate::queues::{NamedQueues, DEFAULT_QUEUE_NAME};
+use crate::rate_limit::TokenBucket;
+use crate::session::{NotOwner, Sessions};
+use crate::time_helpers::duration_to_millis;
+use crate::transfer::{Error as TransferError, Transfers};
 
 use super::queue_server;
 use super::rpc;
@@ -32,7 +43,11 @@ enum Error {
     ConnectionReset,
     RequestError(String),
     AuthenticationFailed(AuthenticationError),
-    InvalidLogin
+    InvalidLogin,
+    MalformedAuthenticateRequest(String),
+    StorageFull(String),
+    BadRequest(String),
+    NotFound(String),
 }
 
 impl std::fmt::Display for Error {
@@ -45,7 +60,11 @@ impl std::fmt::Display for Error {
             Error::ConnectionReset => write!(f, "Connection reset"),
             Error::RequestError(s) => write!(f, "Request error: {}", s),
             Error::AuthenticationFailed(e) => write!(f, "Authentication Failed: {}", e),
-            Error::InvalidLogin => write!(f, "Invalid login")
+            Error::InvalidLogin => write!(f, "Invalid login"),
+            Error::MalformedAuthenticateRequest(s) => write!(f, "Malformed authenticate request: {}", s),
+            Error::StorageFull(s) => write!(f, "Storage full: {}", s),
+            Error::BadRequest(s) => write!(f, "Bad request: {}", s),
+            Error::NotFound(s) => write!(f, "Not found: {}", s),
         }
     }
 }
@@ -56,20 +75,23 @@ impl From<AuthenticationError> for Error {
     }
 }
 
-fn read_message(s: &mut TcpStream) -> Result<Vec<u8>, Error> {
+// Reads the next length prefixed message.
+// A single read can return less than the whole message, so it keeps reading until it's all there.
+fn read_message<R: Read>(s: &mut R) -> Result<Vec<u8>, Error> {
     let mut size = [0, 0, 0, 0];
 
-    match s.read(&mut size) {
-        Ok(0) => {
-            println!("Read nothing");
-            Err(Error::ConnectionReset)
-        }
-        Ok(read) => match get_size(&size) {
+    match s.read_exact(&mut size) {
+        Ok(()) => match get_size(&size) {
             Ok(message_size) => {
                 let mut data = vec![0u8; message_size as usize];
 
-                match s.read(&mut data) {
-                    Ok(read_size) => Ok(data),
+                match s.read_exact(&mut data) {
+                    Ok(()) => Ok(data),
+                    // The connection was closed in the middle of the message
+                    Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => {
+                        println!("Connection closed while reading message");
+                        Err(Error::ConnectionReset)
+                    }
                     Err(e) => {
                         eprintln!("Failed to read message: {}", e);
                         Err(Error::ReadError(e))
@@ -81,6 +103,10 @@ fn read_message(s: &mut TcpStream) -> Result<Vec<u8>, Error> {
                 Err(Error::ReadError(e))
             }
         },
+        Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => {
+            println!("Read nothing");
+            Err(Error::ConnectionReset)
+        }
         Err(e) => {
             eprintln!("Failed to read size of next message: {}", e);
             Err(Error::ConnectionError(e))
@@ -119,34 +145,405 @@ fn send_reply(s: &mut TcpStream, message: rpc::ResponseWrapper) -> Result<(), Er
     }
 }
 
-fn reply_error(s: &mut TcpStream, message: String, ref_id: i32) {
+// The queue with the given name, the default queue if the name is empty
+fn find_queue(
+    default: &queue_server::QueueServer<Vec<u8>>,
+    named_queues: &Option<NamedQueues<Vec<u8>>>,
+    name: &str,
+) -> Result<queue_server::QueueServer<Vec<u8>>, Error> {
+    if name.is_empty() || name == DEFAULT_QUEUE_NAME {
+        return Ok(default.clone());
+    }
+    match named_queues {
+        Some(named_queues) => named_queues.get(name).map_err(|e| open_queue_error(name, e)),
+        None => Err(Error::BadRequest("Named queues are not enabled".to_string())),
+    }
+}
+
+// Same as find_queue, but creates the queue if it doesn't exist yet
+fn find_or_create_queue(
+    default: &queue_server::QueueServer<Vec<u8>>,
+    named_queues: &Option<NamedQueues<Vec<u8>>>,
+    name: &str,
+) -> Result<queue_server::QueueServer<Vec<u8>>, Error> {
+    if name.is_empty() || name == DEFAULT_QUEUE_NAME {
+        return Ok(default.clone());
+    }
+    match named_queues {
+        Some(named_queues) => named_queues.get_or_create(name).map_err(|e| open_queue_error(name, e)),
+        None => Err(Error::BadRequest("Named queues are not enabled".to_string())),
+    }
+}
+
+fn open_queue_error(name: &str, e: queue_server::Error) -> Error {
+    match e {
+        queue_server::Error::InvalidQueueName(_) | queue_server::Error::TooManyQueues(_) => Error::BadRequest(format!("{}", e)),
+        queue_server::Error::QueueNotFound(_) => Error::NotFound(format!("{}", e)),
+        e => Error::RequestError(format!("Failed to open queue '{}': {}", name, e)),
+    }
+}
+
+// Lets clients tell a full disk apart from other failures, so they know to back off
+fn enqueue_error(e: queue_server::Error) -> Error {
+    eprintln!("Failed to enqueue message: {}", e);
+    let message = format!("Failed to enqueue message: {}", e);
+    match e {
+        queue_server::Error::StorageFull => Error::StorageFull(message),
+        queue_server::Error::InvalidPayload(_) => Error::BadRequest(message),
+        // Retrying won't help, since no worker will ever be allowed to take the task
+        queue_server::Error::Unroutable(_) => Error::BadRequest(message),
+        queue_server::Error::UnknownPriority(_, _) => Error::BadRequest(message),
+        queue_server::Error::BatchItemRejected(_, ref e) => match **e {
+            queue_server::Error::InvalidPayload(_)
+            | queue_server::Error::Unroutable(_)
+            | queue_server::Error::UnknownPriority(_, _) => Error::BadRequest(message),
+            _ => Error::RequestError(message),
+        },
+        _ => Error::RequestError(message),
+    }
+}
+
+fn enqueue_status(created: &queue_server::CreatedMessage) -> rpc::EnqueueStatus {
+    if created.created {
+        rpc::EnqueueStatus::CREATED
+    } else {
+        rpc::EnqueueStatus::ALREADY_EXISTS
+    }
+}
+
+// Negative if nothing has been popped recently, so there is nothing to estimate from
+fn estimated_drain_seconds(qs: &queue_server::QueueServer<Vec<u8>>) -> Result<f64, Error> {
+    match qs.estimate_drain_time() {
+        Ok(Some(drain_time)) => Ok(duration_to_millis(drain_time) as f64 / 1000.0),
+        Ok(None) => Ok(-1.0),
+        Err(e) => {
+            eprintln!("Failed to estimate drain time: {}", e);
+            Err(Error::RequestError(format!("Failed to estimate drain time: {}", e)))
+        }
+    }
+}
+
+// Sends the message through a writer that is shared between threads,
+// so messages from different threads don't get mixed up
+fn send_shared(writer: &Mutex<Option<TcpStream>>, message: rpc::ResponseWrapper) -> Result<(), Error> {
+    match writer.lock() {
+        Ok(mut writer) => match &mut *writer {
+            Some(s) => send_reply(s, message),
+            None => Err(Error::ConnectionReset),
+        },
+        Err(_) => Err(Error::RequestError("Writer mutex corrupted".to_string())),
+    }
+}
+
+fn error_response(message: String, code: rpc::ErrorCode, ref_id: i32) -> rpc::ResponseWrapper {
     let mut response = rpc::ErrorResponse::new();
     response.set_message(message);
+    response.set_code(code);
     let mut wrapper = rpc::ResponseWrapper::new();
     wrapper.set_error(response);
     wrapper.set_refId(ref_id);
+    wrapper
+}
 
-    match send_reply(s, wrapper) {
+fn reply_error(s: &mut TcpStream, message: String, code: rpc::ErrorCode, ref_id: i32) {
+    match send_reply(s, error_response(message, code, ref_id)) {
         Ok(_) => {}
         Err(e) => eprintln!("Failed to write error: {}", e),
     }
 }
 
+fn to_priority(priority: rpc::Priority) -> models::Priority {
+    match priority {
+        rpc::Priority::LOW => models::Priority::Low,
+        rpc::Priority::HIGH => models::Priority::High,
+    }
+}
+
+fn from_priority(priority: &models::Priority) -> rpc::Priority {
+    match priority {
+        models::Priority::Low => rpc::Priority::LOW,
+        models::Priority::High | models::Priority::Level(_) => rpc::Priority::HIGH,
+    }
+}
+
+// The priority of the task to enqueue, the level takes precedence if it's set
+fn request_priority(priority: rpc::Priority, level: u32) -> Result<models::Priority, Error> {
+    match level {
+        0 => Ok(to_priority(priority)),
+        level => models::Priority::from_level(level)
+            .ok_or_else(|| Error::BadRequest(format!("Priority level {} is too high, the highest is 255", level))),
+    }
+}
+
+// Gets when an enqueued task should be available, if it should be delayed
+fn available_at(delay: u64, absolute: &str) -> Result<Option<SystemTime>, Error> {
+    match (delay, absolute) {
+        (0, "") => Ok(None),
+        (delay, "") => Ok(Some(SystemTime::now() + Duration::from_millis(delay))),
+        (0, absolute) => match humantime::parse_rfc3339(absolute) {
+            Ok(time) => Ok(Some(time)),
+            Err(e) => {
+                eprintln!("Invalid availableAt '{}': {}", absolute, e);
+                Err(Error::RequestError(format!("Invalid availableAt '{}': {}", absolute, e)))
+            }
+        },
+        _ => Err(Error::RequestError("Only one of delayMilliseconds and availableAt can be set".to_string())),
+    }
+}
+
+// When the task should be dropped if it hasn't been popped, if it expires at all
+fn expires_at(ttl_seconds: u64) -> Option<SystemTime> {
+    match ttl_seconds {
+        0 => None,
+        ttl => Some(SystemTime::now() + Duration::from_secs(ttl)),
+    }
+}
+
+// Gets the tags to include in a response, limited to max_tags if that's not 0.
+// Also returns if any tags were left out.
+fn response_tags(tags: &models::Tags, max_tags: usize) -> (Vec<String>, bool) {
+    let mut sorted = tags.to_sorted_vec();
+    if max_tags > 0 && sorted.len() > max_tags {
+        sorted.truncate(max_tags);
+        (sorted, true)
+    } else {
+        (sorted, false)
+    }
+}
+
+// The name of the request, for tracing
+fn request_type(message: &rpc::RequestWrapper) -> &'static str {
+    use crate::rpc::RequestWrapper_oneof_message as Request;
+
+    match &message.message {
+        Some(Request::enqueue(_)) => "enqueue",
+        Some(Request::enqueueBatch(_)) => "enqueueBatch",
+        Some(Request::pop(_)) => "pop",
+        Some(Request::acknowledge(_)) => "acknowledge",
+        Some(Request::authenticate(_)) => "authenticate",
+        Some(Request::health(_)) => "health",
+        Some(Request::acknowledgeMatching(_)) => "acknowledgeMatching",
+        Some(Request::failWithRetag(_)) => "failWithRetag",
+        Some(Request::serverInfo(_)) => "serverInfo",
+        Some(Request::fail(_)) => "fail",
+        Some(Request::decline(_)) => "decline",
+        Some(Request::diagnosticDump(_)) => "diagnosticDump",
+        Some(Request::beginEnqueue(_)) => "beginEnqueue",
+        Some(Request::enqueueChunk(_)) => "enqueueChunk",
+        Some(Request::commitEnqueue(_)) => "commitEnqueue",
+        Some(Request::cancelPop(_)) => "cancelPop",
+        Some(Request::cancel(_)) => "cancel",
+        Some(Request::findByTag(_)) => "findByTag",
+        Some(Request::listProcessing(_)) => "listProcessing",
+        Some(Request::listQueued(_)) => "listQueued",
+        Some(Request::peek(_)) => "peek",
+        Some(Request::stats(_)) => "stats",
+        Some(Request::purge(_)) => "purge",
+        Some(Request::eventSubscribe(_)) => "eventSubscribe",
+        Some(Request::exportDeadLetters(_)) => "exportDeadLetters",
+        Some(Request::setCapabilityPaused(_)) => "setCapabilityPaused",
+        Some(Request::subscribe(_)) => "subscribe",
+        Some(Request::reserve(_)) => "reserve",
+        Some(Request::confirmReservation(_)) => "confirmReservation",
+        None => "unknown",
+    }
+}
+
+fn found_message(item: models::QueueItem<Vec<u8>>, max_response_tags: usize) -> rpc::FoundMessage {
+    let mut found = rpc::FoundMessage::new();
+    found.set_id(item.id.to_string());
+    found.set_priority(from_priority(&item.priority));
+    found.set_priorityLevel(item.priority.level());
+    let (capabilities, truncated) = response_tags(&item.required_tags, max_response_tags);
+    found.set_requiredCapabilities(capabilities.into());
+    found.set_requiredCapabilitiesTruncated(truncated);
+    found.set_enqueuedBy(item.enqueued_by.unwrap_or_default());
+    found.set_message(item.data);
+    found
+}
+
+fn popped_response(item: models::QueueItem<Vec<u8>>, visibility_timeout: Option<Duration>, max_response_tags: usize) -> rpc::ResponseWrapper {
+    let mut response = rpc::PopResponse::new();
+    response.set_visibilityTimeoutMilliseconds(visibility_timeout.map(duration_to_millis).unwrap_or(0));
+    response.set_id(item.id.to_string());
+    let (capabilities, truncated) = response_tags(&item.required_tags, max_response_tags);
+    response.set_requiredCapabilities(capabilities.into());
+    response.set_requiredCapabilitiesTruncated(truncated);
+    response.set_message(item.data);
+    response.set_hadResult(true);
+    response.set_enqueuedBy(item.enqueued_by.unwrap_or_default());
+    response.set_sequence(item.sequence);
+    let mut wrapper = rpc::ResponseWrapper::new();
+    wrapper.set_pop(response);
+    wrapper
+}
+
+// How long a subscription waits before trying again, if too many tasks are processing
+const SUBSCRIPTION_BACKOFF: Duration = Duration::from_millis(100);
+
+// Tasks are pushed to a subscribed connection, as long as it has room for them
+#[derive(Clone)]
+struct Subscription {
+    window: PrefetchWindow,
+    cancel: Sender<()>,
+}
+
+// The events of a queue are sent to the connection until unsubscribed
+struct EventSubscription {
+    queue_server: queue_server::QueueServer<Vec<u8>>,
+    id: u64,
+}
+
+fn event_response(event: QueueEvent) -> rpc::QueueEventResponse {
+    let mut response = rpc::QueueEventResponse::new();
+    response.set_kind(match event.kind {
+        EventKind::Enqueued => rpc::EventKind::ENQUEUED,
+        EventKind::Popped => rpc::EventKind::POPPED,
+        EventKind::Acknowledged => rpc::EventKind::ACKNOWLEDGED,
+        EventKind::Failed => rpc::EventKind::FAILED,
+        EventKind::DeadLettered => rpc::EventKind::DEAD_LETTERED,
+    });
+    response.set_id(event.id.to_string());
+    if let Some(priority) = event.priority {
+        response.set_hasPriority(true);
+        response.set_priorityLevel(priority.level());
+    }
+    response.set_requiredCapabilities(event.required_tags.into());
+    response.set_timestampMilliseconds(event.at.duration_since(UNIX_EPOCH).map(duration_to_millis).unwrap_or(0));
+    response.set_missedEvents(event.missed as u64);
+    response
+}
+
+// How often waiting pops are cancelled while a closed connection
+// waits for the requests it was handling to finish
+const DISCONNECT_CANCEL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Used to tell connections apart when tracing
+static NEXT_CONNECTION_ID: AtomicUsize = AtomicUsize::new(0);
+
 // One client corresponds to exactly one connection
 // to the server
 #[derive(Clone)]
 pub struct Client {
+    connection_id: usize,
     queue_server: queue_server::QueueServer<Vec<u8>>,
-    outstanding_tasks: Arc<Mutex<HashSet<Uuid>>>,
-    auth: Authentication
+    // The tasks the connection is processing, with the name of the queue each was popped from
+    outstanding_tasks: Arc<Mutex<HashMap<Uuid, String>>>,
+    auth: Arc<dyn AuthBackend>,
+    sessions: Sessions,
+    session_token: String,
+    // The user the connection is authenticated as
+    username: Option<String>,
+    transfers: Transfers,
+    // The maximum number of tags to include per message in responses, unlimited if 0
+    max_response_tags: usize,
+    // Replies, and tasks pushed to the subscription, are written through this.
+    // Not set until the connection is handled.
+    writer: Arc<Mutex<Option<TcpStream>>>,
+    // Shared between the threads handling requests for the connection
+    subscription: Arc<Mutex<Option<Subscription>>>,
+    event_subscription: Arc<Mutex<Option<EventSubscription>>>,
+    // How many requests from the connection are handled at once
+    concurrency: usize,
+    // Limits how often the connection can pop, if set
+    pop_limit: Option<Arc<Mutex<TokenBucket>>>,
+    // Only the default queue can be used if not set
+    named_queues: Option<NamedQueues<Vec<u8>>>,
 }
 
 impl Client {
-    pub fn new(queue_server: queue_server::QueueServer<Vec<u8>>, auth: Authentication) -> Client {
+    pub fn new(queue_server: queue_server::QueueServer<Vec<u8>>, auth: Arc<dyn AuthBackend>, sessions: Sessions, transfers: Transfers) -> Client {
         Client {
+            connection_id: NEXT_CONNECTION_ID.fetch_add(1, Ordering::SeqCst),
             queue_server,
-            outstanding_tasks: Arc::new(Mutex::new(HashSet::new())),
-            auth
+            outstanding_tasks: Arc::new(Mutex::new(HashMap::new())),
+            auth,
+            sessions,
+            session_token: Sessions::new_token(),
+            username: None,
+            transfers,
+            max_response_tags: 0,
+            writer: Arc::new(Mutex::new(None)),
+            subscription: Arc::new(Mutex::new(None)),
+            event_subscription: Arc::new(Mutex::new(None)),
+            concurrency: 1,
+            pop_limit: None,
+            named_queues: None,
+        }
+    }
+
+    // Lets requests use other queues than the default one
+    pub fn with_named_queues(mut self, named_queues: NamedQueues<Vec<u8>>) -> Client {
+        self.named_queues = Some(named_queues);
+        self
+    }
+
+    // The queue with the given name, the default queue if the name is empty
+    fn queue(&self, name: &str) -> Result<queue_server::QueueServer<Vec<u8>>, Error> {
+        find_queue(&self.queue_server, &self.named_queues, name)
+    }
+
+    // The queue to enqueue tasks in, created if it's the first task in the queue
+    fn queue_to_enqueue_in(&self, name: &str) -> Result<queue_server::QueueServer<Vec<u8>>, Error> {
+        find_or_create_queue(&self.queue_server, &self.named_queues, name)
+    }
+
+    // The queue the task was popped from
+    fn queue_of_task(&self, id: &Uuid) -> Result<queue_server::QueueServer<Vec<u8>>, Error> {
+        let name = match self.outstanding_tasks.lock() {
+            Ok(tasks) => tasks.get(id).cloned(),
+            Err(_) => None,
+        };
+        match name {
+            Some(name) => self.queue(&name),
+            None => Ok(self.queue_server.clone()),
+        }
+    }
+
+    // Remembers that the connection is processing the task, until task_done is called
+    fn track_task(&self, id: Uuid, queue_name: &str) {
+        if let Ok(mut tasks) = self.outstanding_tasks.lock() {
+            tasks.insert(id, queue_name.to_string());
+        }
+    }
+
+    pub fn with_max_response_tags(mut self, max_response_tags: usize) -> Client {
+        self.max_response_tags = max_response_tags;
+        self
+    }
+
+    // Handles up to this many requests from the connection at once, so for example
+    // a waiting pop doesn't hold up acknowledgements sent on the same connection.
+    // The replies are sent as the requests finish, so they might not be in the same
+    // order as the requests, and have to be matched to the requests by their ref id.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Client {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    // Limits how often the connection can pop, so a worker popping as fast as it can
+    // doesn't take all the tasks from the other workers.
+    // Pops past the limit get a rate limited response, without a task.
+    pub fn with_pop_rate_limit(mut self, rate_per_second: f64, burst: usize) -> Client {
+        self.pop_limit = Some(Arc::new(Mutex::new(TokenBucket::new(rate_per_second, burst, Instant::now()))));
+        self
+    }
+
+    // Takes a token from the pop rate limit. Returns false if the connection pops too often.
+    fn allow_pop(&self) -> bool {
+        match &self.pop_limit {
+            Some(pop_limit) => match pop_limit.lock() {
+                Ok(mut bucket) => bucket.try_take(Instant::now()),
+                Err(_) => true,
+            },
+            None => true,
+        }
+    }
+
+    fn ensure_admin(&self) -> Result<(), Error> {
+        match &self.username {
+            Some(username) if self.auth.is_admin(username) => Ok(()),
+            _ => Err(Error::RequestError("Only admins can do this".to_string())),
         }
     }
 
@@ -154,25 +551,78 @@ impl Client {
         let capabilities = request.get_availableCapabilities();
         let wait_for_messages = request.get_waitForMessage();
 
-        let mut qs = &mut self.queue_server.to_owned();
+        if !self.allow_pop() {
+            let mut response = rpc::PopResponse::new();
+            response.set_hadResult(false);
+            response.set_rateLimited(true);
+            let mut wrapper = rpc::ResponseWrapper::new();
+            wrapper.set_pop(response);
+            return Ok(wrapper);
+        }
+
+        let queue_name = request.get_queueName();
+        let mut qs = self.queue(queue_name)?;
+
+        let worker = Some(self.session_token.as_str());
+        let visibility_timeout = match request.get_visibilityTimeoutMilliseconds() {
+            0 => None,
+            milliseconds => Some(Duration::from_millis(milliseconds)),
+        };
+        let mut pop = |cancel: &Receiver<()>| match visibility_timeout {
+            Some(timeout) => qs.pop_with_visibility_timeout(worker, capabilities.to_vec(), wait_for_messages, cancel, Some(timeout)),
+            None => qs.pop_as_worker(worker, capabilities.to_vec(), wait_for_messages, cancel),
+        };
+        let result = if wait_for_messages {
+            let cancel = self.sessions.begin_waiting_pop(&self.session_token, self.username.clone());
+            let result = pop(&cancel);
+            self.sessions.end_waiting_pop(&self.session_token);
+            result
+        } else {
+            pop(&never())
+        };
 
-        match qs.pop(capabilities.to_vec(), wait_for_messages) {
+        match result {
             Ok(Some(item)) => {
-                if let Ok(mut tasks) = self.outstanding_tasks.lock() {
-                    tasks.insert(item.id.clone());
-                }
+                self.track_task(item.id, queue_name);
+                Ok(popped_response(item, visibility_timeout.or(qs.visibility_timeout()), self.max_response_tags))
+            }
+            Ok(None) => {
+                // Nothing matched, so anything still in the queue requires other capabilities
+                let has_unmatched = match qs.queued_len() {
+                    Ok(queued) => queued > 0,
+                    Err(e) => {
+                        eprintln!("Failed to check for unmatched messages: {}", e);
+                        false
+                    }
+                };
 
                 let mut response = rpc::PopResponse::new();
-                response.set_id(item.id.to_string());
-                response.set_message(item.data);
-                response.set_hadResult(true);
+                response.set_hadResult(false);
+                response.set_hasUnmatchedMessages(has_unmatched);
                 let mut wrapper = rpc::ResponseWrapper::new();
                 wrapper.set_pop(response);
                 Ok(wrapper)
             }
-            Ok(None) => {
+            Err(queue_server::Error::PopCancelled) => {
+                let mut response = rpc::PopResponse::new();
+                response.set_hadResult(false);
+                response.set_cancelled(true);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_pop(response);
+                Ok(wrapper)
+            }
+            Err(queue_server::Error::TooManyProcessing) => {
+                let mut response = rpc::PopResponse::new();
+                response.set_hadResult(false);
+                response.set_backpressure(true);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_pop(response);
+                Ok(wrapper)
+            }
+            Err(queue_server::Error::NotReady) => {
                 let mut response = rpc::PopResponse::new();
                 response.set_hadResult(false);
+                response.set_notReady(true);
                 let mut wrapper = rpc::ResponseWrapper::new();
                 wrapper.set_pop(response);
                 Ok(wrapper)
@@ -184,6 +634,233 @@ impl Client {
         }
     }
 
+    // Starts pushing tasks to the connection, instead of waiting for it to pop them.
+    // The pushed tasks are sent as pop responses with the ref id of the subscribe request.
+    fn subscribe(&mut self, request: &rpc::SubscribeRequest, ref_id: i32) -> Result<rpc::ResponseWrapper, Error> {
+        self.end_subscription();
+
+        let prefetch = request.get_prefetch() as usize;
+        if prefetch > 0 {
+            let window = PrefetchWindow::new(prefetch);
+            let (cancel_sender, cancel) = bounded(1);
+
+            let mut qs = self.queue(request.get_queueName())?;
+            let queue_name = request.get_queueName().to_string();
+            let capabilities = request.get_availableCapabilities().to_vec();
+            let worker = self.session_token.clone();
+            let outstanding_tasks = self.outstanding_tasks.clone();
+            let writer = self.writer.clone();
+            let max_response_tags = self.max_response_tags;
+            let w = window.clone();
+            thread::spawn(move || {
+                while w.wait_for_room() {
+                    match qs.pop_as_worker(Some(&worker), capabilities.clone(), true, &cancel) {
+                        Ok(Some(item)) => {
+                            if let Ok(mut tasks) = outstanding_tasks.lock() {
+                                tasks.insert(item.id, queue_name.clone());
+                            }
+                            w.add(item.id.clone());
+
+                            let mut wrapper = popped_response(item, qs.visibility_timeout(), max_response_tags);
+                            wrapper.set_refId(ref_id);
+                            if let Err(e) = send_shared(&writer, wrapper) {
+                                eprintln!("Failed to push message to subscription: {}", e);
+                                return;
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(queue_server::Error::PopCancelled) => return,
+                        Err(queue_server::Error::TooManyProcessing) | Err(queue_server::Error::NotReady) => thread::sleep(SUBSCRIPTION_BACKOFF),
+                        Err(e) => {
+                            eprintln!("Failed to pop message for subscription: {}", e);
+                            return;
+                        }
+                    }
+                }
+            });
+
+            if let Ok(mut subscription) = self.subscription.lock() {
+                *subscription = Some(Subscription {
+                    window,
+                    cancel: cancel_sender,
+                });
+            }
+        }
+
+        let response = rpc::SubscribeResponse::new();
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_subscribe(response);
+        Ok(wrapper)
+    }
+
+    fn end_subscription(&mut self) {
+        let subscription = match self.subscription.lock() {
+            Ok(mut subscription) => subscription.take(),
+            Err(_) => None,
+        };
+        if let Some(subscription) = subscription {
+            subscription.window.close();
+            let _ = subscription.cancel.try_send(());
+        }
+    }
+
+    // Starts sending what happens to the messages of the queue to the connection.
+    // The events are sent as event responses with the ref id of the request.
+    fn event_subscribe(&mut self, request: &rpc::EventSubscribeRequest, ref_id: i32) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+        self.end_event_subscription();
+
+        let prefetch = request.get_prefetch() as usize;
+        if prefetch > 0 {
+            let qs = self.queue(request.get_queueName())?;
+            let (id, events) = match qs.subscribe_events(prefetch) {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    eprintln!("Failed to subscribe to events: {}", e);
+                    return Err(Error::RequestError(format!("Failed to subscribe to events: {}", e)));
+                }
+            };
+
+            let writer = self.writer.clone();
+            let events_of = qs.clone();
+            thread::spawn(move || {
+                // Ends once unsubscribed
+                for event in events.iter() {
+                    let mut wrapper = rpc::ResponseWrapper::new();
+                    wrapper.set_event(event_response(event));
+                    wrapper.set_refId(ref_id);
+                    if let Err(e) = send_shared(&writer, wrapper) {
+                        eprintln!("Failed to send event to subscription: {}", e);
+                        let _ = events_of.unsubscribe_events(id);
+                        return;
+                    }
+                }
+            });
+
+            if let Ok(mut subscription) = self.event_subscription.lock() {
+                *subscription = Some(EventSubscription { queue_server: qs, id });
+            }
+        }
+
+        let response = rpc::EventSubscribeResponse::new();
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_eventSubscribe(response);
+        Ok(wrapper)
+    }
+
+    fn end_event_subscription(&mut self) {
+        let subscription = match self.event_subscription.lock() {
+            Ok(mut subscription) => subscription.take(),
+            Err(_) => None,
+        };
+        if let Some(subscription) = subscription {
+            let _ = subscription.queue_server.unsubscribe_events(subscription.id);
+        }
+    }
+
+    // Must be called when the connection is done with a task,
+    // so the subscription can push another one
+    fn task_done(&self, id: &Uuid) {
+        if let Ok(mut tasks) = self.outstanding_tasks.lock() {
+            tasks.remove(id);
+        }
+        if let Ok(subscription) = self.subscription.lock() {
+            if let Some(subscription) = &*subscription {
+                subscription.window.remove(id);
+            }
+        }
+    }
+
+    fn reserve(&mut self, request: &rpc::ReserveRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let capabilities = request.get_availableCapabilities().to_vec();
+        let reservation_ttl = Duration::from_millis(request.get_reservationMilliseconds());
+
+        match self.queue(request.get_queueName())?.reserve(capabilities, reservation_ttl, Some(self.session_token.as_str())) {
+            Ok(item) => {
+                let mut response = rpc::ReserveResponse::new();
+                if let Some(item) = item {
+                    let message = found_message(item, self.max_response_tags);
+                    response.set_hadResult(true);
+                    response.set_message(message);
+                }
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_reserve(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to reserve message: {}", e);
+                Err(Error::RequestError(format!("Failed to reserve message: {}", e)))
+            }
+        }
+    }
+
+    fn confirm_reservation(&mut self, request: &rpc::ConfirmReservationRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let id = match Uuid::parse_str(request.get_id()) {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("Failed to parse message id to UUID: {}", e);
+                return Err(Error::RequestError(format!("Failed to parse message id to UUID: {}", e)));
+            }
+        };
+
+        match self.queue(request.get_queueName())?.confirm_reservation(id, Some(self.session_token.as_str())) {
+            Ok(confirmed) => {
+                if confirmed {
+                    self.track_task(id, request.get_queueName());
+                }
+                let mut response = rpc::ConfirmReservationResponse::new();
+                response.set_confirmed(confirmed);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_confirmReservation(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to confirm reservation: {}", e);
+                Err(Error::RequestError(format!("Failed to confirm reservation: {}", e)))
+            }
+        }
+    }
+
+    fn cancel_pop(&mut self, request: &rpc::CancelPopRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let cancelled = match self.sessions.cancel_waiting_pop(request.get_sessionToken(), self.username.as_deref()) {
+            Ok(cancelled) => cancelled,
+            Err(NotOwner) => return Err(Error::NotFound("The session has no waiting pop".to_string())),
+        };
+
+        let mut response = rpc::CancelPopResponse::new();
+        response.set_cancelled(cancelled);
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_cancelPop(response);
+        Ok(wrapper)
+    }
+
+    fn cancel(&mut self, request: &rpc::CancelRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let uuid = match Uuid::parse_str(request.get_id()) {
+            Ok(uuid) => uuid,
+            Err(e) => {
+                eprintln!("Failed to parse id to UUID: {}", e);
+                return Err(Error::RequestError(format!(
+                    "Failed to parse id to UUID: {}",
+                    e
+                )));
+            }
+        };
+
+        match self.queue(request.get_queueName())?.cancel(uuid) {
+            Ok(cancelled) => {
+                let mut response = rpc::CancelResponse::new();
+                response.set_cancelled(cancelled);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_cancel(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to cancel message: {}", e);
+                Err(Error::RequestError(format!("Failed to cancel message: {}", e)))
+            }
+        }
+    }
+
     fn acknowledge(
         &mut self,
         request: &rpc::AcknowledgeRequest,
@@ -192,14 +869,21 @@ impl Client {
 
         match Uuid::parse_str(id) {
             Ok(uuid) => {
-                let mut qs = &mut self.queue_server.to_owned();
-                match qs.acknowledge(uuid) {
-                    Ok(()) => {
-                        if let Ok(mut tasks) = self.outstanding_tasks.lock() {
-                            tasks.remove(&uuid);
-                        }
+                let mut qs = self.queue_of_task(&uuid)?;
+                match qs.acknowledge_as_worker(uuid, Some(self.session_token.as_str())) {
+                    Ok(queue_server::Acknowledgement::Requeued) => {
+                        self.task_done(&uuid);
+                        Err(Error::RequestError("The message was put back in the queue when the server was paused".to_string()))
+                    }
+                    Ok(queue_server::Acknowledgement::NotProcessing) => {
+                        self.task_done(&uuid);
+                        Err(Error::RequestError("The message is not being processed by this worker, it might have timed out".to_string()))
+                    }
+                    Ok(acknowledgement) => {
+                        self.task_done(&uuid);
 
                         let mut response = rpc::AcknowledgeResponse::new();
+                        response.set_alreadyCompleted(acknowledgement == queue_server::Acknowledgement::AlreadyCompleted);
                         let mut wrapper = rpc::ResponseWrapper::new();
                         wrapper.set_acknowledge(response);
                         Ok(wrapper)
@@ -223,62 +907,664 @@ impl Client {
         }
     }
 
-    fn enqueue(&mut self, request: &rpc::EnqueueRequest) -> Result<rpc::ResponseWrapper, Error> {
-        let priority = request.get_priority();
-        let message = request.get_message();
-        let required_capabilities = request.get_requiredCapabilities();
+    fn fail(&mut self, request: &rpc::FailRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let id = request.get_id();
 
-        let prio = match priority {
-            rpc::Priority::LOW => models::Priority::Low,
-            rpc::Priority::HIGH => models::Priority::High,
+        let uuid = match Uuid::parse_str(id) {
+            Ok(uuid) => uuid,
+            Err(e) => {
+                eprintln!("Failed to parse id to UUID: {}", e);
+                return Err(Error::RequestError(format!(
+                    "Failed to parse id to UUID: {}",
+                    e
+                )));
+            }
         };
 
-        let mut qs = &mut self.queue_server.to_owned();
+        let mut qs = self.queue_of_task(&uuid)?;
+        let result = if request.get_changePriority() {
+            qs.fail_with_priority(uuid, to_priority(request.get_priority()), Some(self.session_token.as_str()))
+        } else {
+            qs.fail_for_worker(uuid, &self.session_token)
+        };
 
-        match qs.enqueue(message.to_vec(), prio, required_capabilities.to_vec()) {
-            Ok(created) => {
-                let mut response = rpc::EnqueueResponse::new();
-                response.set_id(created.id.to_string());
+        match result {
+            Ok(()) => {
+                self.task_done(&uuid);
+
+                let response = rpc::FailResponse::new();
                 let mut wrapper = rpc::ResponseWrapper::new();
-                wrapper.set_enqueue(response);
+                wrapper.set_fail(response);
                 Ok(wrapper)
             }
             Err(e) => {
-                eprintln!("Failed to enqueue message: {}", e);
-                Err(Error::RequestError(format!(
-                    "Failed to enqueue message: {}",
-                    e
-                )))
+                eprintln!("Failed to fail message: {}", e);
+                Err(Error::RequestError(format!("Failed to fail message: {}", e)))
             }
         }
     }
 
-    fn drop_connection(mut self) {
-        if let Ok(mut tasks) = self.outstanding_tasks.lock() {
-            for id in tasks.iter() {
-                match self.queue_server.fail(*id) {
-                    Err(e) => eprintln!("Failed to fail task: {}", e),
-                    _ => {}
-                };
+    fn decline(&mut self, request: &rpc::DeclineRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let uuid = match Uuid::parse_str(request.get_id()) {
+            Ok(uuid) => uuid,
+            Err(e) => {
+                eprintln!("Failed to parse id to UUID: {}", e);
+                return Err(Error::RequestError(format!("Failed to parse id to UUID: {}", e)));
             }
-        }
-    }
-
-    fn ensure_auth(&self, s: &mut TcpStream) -> Result<(), Error> {
-        let data = read_message(s)?;
+        };
 
-        let message = parse_request(data)?;
+        match self.queue_of_task(&uuid)?.decline(uuid, &self.session_token) {
+            Ok(()) => {
+                self.task_done(&uuid);
 
-        if !message.has_authenticate() {
-            return Err(Error::RequestError("Invalid request".to_string()));
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_decline(rpc::DeclineResponse::new());
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to decline message: {}", e);
+                Err(Error::RequestError(format!("Failed to decline message: {}", e)))
+            }
         }
+    }
 
-        let request = message.get_authenticate();
+    fn fail_with_retag(
+        &mut self,
+        request: &rpc::FailWithRetagRequest,
+    ) -> Result<rpc::ResponseWrapper, Error> {
+        let id = request.get_id();
+        let required_capabilities = request.get_requiredCapabilities().to_vec();
+
+        let uuid = match Uuid::parse_str(id) {
+            Ok(uuid) => uuid,
+            Err(e) => {
+                eprintln!("Failed to parse id to UUID: {}", e);
+                return Err(Error::RequestError(format!(
+                    "Failed to parse id to UUID: {}",
+                    e
+                )));
+            }
+        };
+
+        match self.queue_of_task(&uuid)?.fail_with_capabilities(uuid, required_capabilities, Some(self.session_token.as_str())) {
+            Ok(()) => {
+                self.task_done(&uuid);
+
+                let response = rpc::FailWithRetagResponse::new();
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_failWithRetag(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to fail message: {}", e);
+                Err(Error::RequestError(format!("Failed to fail message: {}", e)))
+            }
+        }
+    }
+
+    fn acknowledge_matching(
+        &mut self,
+        request: &rpc::AcknowledgeMatchingRequest,
+    ) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        let capability = request.get_requiredCapability().to_string();
+        let minimum_age = Duration::from_millis(request.get_minimumAgeMilliseconds());
+
+        if capability.is_empty() && minimum_age == Duration::from_millis(0) {
+            return Err(Error::RequestError(
+                "At least one filter is required to acknowledge matching messages".to_string(),
+            ));
+        }
+
+        let result = self.queue(request.get_queueName())?.acknowledge_matching(|in_flight| {
+            (capability.is_empty() || in_flight.item.required_tags.contains(&capability))
+                && in_flight.age() >= minimum_age
+        });
+
+        match result {
+            Ok(count) => {
+                let mut response = rpc::AcknowledgeMatchingResponse::new();
+                response.set_count(count as u64);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_acknowledgeMatching(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to acknowledge matching messages: {}", e);
+                Err(Error::RequestError(format!(
+                    "Failed to acknowledge matching messages: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    fn enqueue(&mut self, request: &rpc::EnqueueRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let created = self.enqueue_item(request)?;
+
+        let mut response = rpc::EnqueueResponse::new();
+        response.set_id(created.id.to_string());
+        response.set_status(enqueue_status(&created));
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_enqueue(response);
+        Ok(wrapper)
+    }
+
+    fn enqueue_item(&mut self, request: &rpc::EnqueueRequest) -> Result<queue_server::CreatedMessage, Error> {
+        let message = request.get_message();
+        let required_capabilities = request.get_requiredCapabilities();
+
+        let prio = request_priority(request.get_priority(), request.get_priorityLevel())?;
+        let available_at = available_at(request.get_delayMilliseconds(), request.get_availableAt())?;
+        let expires_at = expires_at(request.get_ttlSeconds());
+
+        let mut qs = self.queue_to_enqueue_in(request.get_queueName())?;
+
+        let result = if request.get_idempotencyKey().is_empty() {
+            qs.enqueue_expiring(self.username.clone(), message.to_vec(), prio, required_capabilities.to_vec(), available_at, expires_at)
+        } else {
+            qs.enqueue_idempotent(
+                request.get_idempotencyKey().to_string(),
+                self.username.clone(),
+                queue_server::BatchItem {
+                    message: message.to_vec(),
+                    priority: prio,
+                    required_capabilities: required_capabilities.to_vec(),
+                    available_at,
+                    expires_at,
+                },
+            )
+        };
+
+        result.map_err(enqueue_error)
+    }
+
+    fn enqueue_batch(&mut self, request: &rpc::EnqueueBatchRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let mut results = Vec::new();
+        if request.get_atomic() {
+            let queue_name = match request.get_items().first() {
+                Some(item) => item.get_queueName(),
+                None => "",
+            };
+            let mut batch = Vec::new();
+            for item in request.get_items() {
+                if !item.get_idempotencyKey().is_empty() {
+                    return Err(Error::BadRequest("Idempotency keys can't be used in atomic batches".to_string()));
+                }
+                if item.get_queueName() != queue_name {
+                    return Err(Error::BadRequest("Every task in an atomic batch has to go in the same queue".to_string()));
+                }
+                batch.push(queue_server::BatchItem {
+                    message: item.get_message().to_vec(),
+                    priority: request_priority(item.get_priority(), item.get_priorityLevel())?,
+                    required_capabilities: item.get_requiredCapabilities().to_vec(),
+                    available_at: available_at(item.get_delayMilliseconds(), item.get_availableAt())?,
+                    expires_at: expires_at(item.get_ttlSeconds()),
+                });
+            }
+
+            let created = self.queue_to_enqueue_in(queue_name)?.enqueue_atomically(self.username.clone(), batch).map_err(enqueue_error)?;
+            for created in created {
+                let mut result = rpc::EnqueueBatchResult::new();
+                result.set_id(created.id.to_string());
+                result.set_status(enqueue_status(&created));
+                results.push(result);
+            }
+        } else {
+            for item in request.get_items() {
+                let mut result = rpc::EnqueueBatchResult::new();
+                match self.enqueue_item(item) {
+                    Ok(created) => {
+                        result.set_id(created.id.to_string());
+                        result.set_status(enqueue_status(&created));
+                    }
+                    Err(e) => result.set_error(format!("{}", e)),
+                }
+                results.push(result);
+            }
+        }
+
+        let mut response = rpc::EnqueueBatchResponse::new();
+        response.set_results(results.into());
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_enqueueBatch(response);
+        Ok(wrapper)
+    }
+
+    fn begin_enqueue(&mut self, request: &rpc::BeginEnqueueRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let prio = request_priority(request.get_priority(), request.get_priorityLevel())?;
+        let required_capabilities = request.get_requiredCapabilities().to_vec();
+        let available_at = available_at(request.get_delayMilliseconds(), request.get_availableAt())?;
+        let expires_at = expires_at(request.get_ttlSeconds());
+        // Checked before the payload is sent, instead of once it has all been received.
+        // Every queue has the same rules, so the default queue can check it.
+        self.queue_server.validate_task(&prio, &required_capabilities).map_err(enqueue_error)?;
+
+        match self.transfers.begin(self.username.clone(), prio, required_capabilities, available_at, expires_at) {
+            Ok(id) => {
+                let mut response = rpc::BeginEnqueueResponse::new();
+                response.set_transferId(id.to_string());
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_beginEnqueue(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to begin enqueue: {}", e);
+                Err(Error::RequestError(format!("Failed to begin enqueue: {}", e)))
+            }
+        }
+    }
+
+    fn enqueue_chunk(&mut self, request: &rpc::EnqueueChunkRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let id = match Uuid::parse_str(request.get_transferId()) {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("Failed to parse transfer id to UUID: {}", e);
+                return Err(Error::RequestError(format!("Failed to parse transfer id to UUID: {}", e)));
+            }
+        };
+
+        match self.transfers.append(&id, self.username.as_deref(), request.get_offset(), request.get_data()) {
+            Ok(received) => {
+                let mut response = rpc::EnqueueChunkResponse::new();
+                response.set_received(received);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_enqueueChunk(response);
+                Ok(wrapper)
+            }
+            Err(e @ TransferError::TooLarge(_)) => Err(Error::BadRequest(format!("Failed to receive enqueue chunk: {}", e))),
+            Err(e) => {
+                eprintln!("Failed to receive enqueue chunk: {}", e);
+                Err(Error::RequestError(format!("Failed to receive enqueue chunk: {}", e)))
+            }
+        }
+    }
+
+    fn commit_enqueue(&mut self, request: &rpc::CommitEnqueueRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let id = match Uuid::parse_str(request.get_transferId()) {
+            Ok(id) => id,
+            Err(e) => {
+                eprintln!("Failed to parse transfer id to UUID: {}", e);
+                return Err(Error::RequestError(format!("Failed to parse transfer id to UUID: {}", e)));
+            }
+        };
+
+        // Checked first, so the transfer isn't lost if the queue can't be used
+        let mut qs = self.queue_to_enqueue_in(request.get_queueName())?;
+        let completed = match self.transfers.commit(&id, self.username.as_deref()) {
+            Ok(completed) => completed,
+            Err(e) => {
+                eprintln!("Failed to commit enqueue: {}", e);
+                return Err(Error::RequestError(format!("Failed to commit enqueue: {}", e)));
+            }
+        };
+
+        match qs.enqueue_expiring(
+            self.username.clone(),
+            completed.data,
+            completed.priority,
+            completed.required_capabilities,
+            completed.available_at,
+            completed.expires_at,
+        ) {
+            Ok(created) => {
+                let mut response = rpc::CommitEnqueueResponse::new();
+                response.set_id(created.id.to_string());
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_commitEnqueue(response);
+                Ok(wrapper)
+            }
+            Err(e) => Err(enqueue_error(e)),
+        }
+    }
+
+    fn find_by_tag(&mut self, request: &rpc::FindByTagRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let tags = models::Tags::from(request.get_tags().to_vec());
+        let mode = match request.get_matchMode() {
+            rpc::TagMatchMode::ALL => models::TagMatch::All,
+            rpc::TagMatchMode::ANY => models::TagMatch::Any,
+        };
+        let limit = match request.get_limit() {
+            0 => usize::max_value(),
+            limit => limit as usize,
+        };
+
+        match self.queue(request.get_queueName())?.find_by_tags(&tags, &mode, request.get_offset() as usize, limit) {
+            Ok((items, total)) => {
+                let mut response = rpc::FindByTagResponse::new();
+                for item in items {
+                    let found = found_message(item, self.max_response_tags);
+                    response.mut_messages().push(found);
+                }
+                response.set_total(total as u64);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_findByTag(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to find messages: {}", e);
+                Err(Error::RequestError(format!("Failed to find messages: {}", e)))
+            }
+        }
+    }
+
+    fn peek(&mut self, request: &rpc::PeekRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let capabilities = request.get_availableCapabilities().to_vec();
+        let priority = if request.get_onlyPriority() {
+            Some(to_priority(request.get_priority()))
+        } else {
+            None
+        };
+
+        match self.queue(request.get_queueName())?.peek(capabilities, priority) {
+            Ok(item) => {
+                let mut response = rpc::PeekResponse::new();
+                if let Some(item) = item {
+                    response.set_hadResult(true);
+                    response.set_message(found_message(item, self.max_response_tags));
+                }
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_peek(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to peek message: {}", e);
+                Err(Error::RequestError(format!("Failed to peek message: {}", e)))
+            }
+        }
+    }
+
+    fn list_queued(&mut self, request: &rpc::ListQueuedRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let cursor = match request.get_cursor() {
+            "" => None,
+            cursor => Some(cursor.parse::<queue_server::PageCursor>().map_err(Error::BadRequest)?),
+        };
+        let limit = match request.get_limit() {
+            0 => usize::max_value(),
+            limit => limit as usize,
+        };
+
+        match self.queue(request.get_queueName())?.list_page(cursor.as_ref(), limit) {
+            Ok((items, next)) => {
+                let mut response = rpc::ListQueuedResponse::new();
+                for item in items {
+                    let found = found_message(item, self.max_response_tags);
+                    response.mut_messages().push(found);
+                }
+                if let Some(next) = next {
+                    response.set_nextCursor(next.to_string());
+                }
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_listQueued(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to list messages: {}", e);
+                Err(Error::RequestError(format!("Failed to list messages: {}", e)))
+            }
+        }
+    }
+
+    fn set_capability_paused(&mut self, request: &rpc::SetCapabilityPausedRequest) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        match self.queue_server.set_capability_paused(request.get_capability().to_string(), request.get_paused()) {
+            Ok(paused) => {
+                let mut response = rpc::SetCapabilityPausedResponse::new();
+                response.set_pausedCapabilities(paused.into());
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_setCapabilityPaused(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to pause capability: {}", e);
+                Err(Error::RequestError(format!("Failed to pause capability: {}", e)))
+            }
+        }
+    }
+
+    fn list_processing(&mut self, request: &rpc::ListProcessingRequest) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        match self.queue(request.get_queueName())?.list_processing() {
+            Ok(processing) => {
+                let mut response = rpc::ListProcessingResponse::new();
+                for in_flight in processing {
+                    let mut message = rpc::ProcessingMessage::new();
+                    message.set_id(in_flight.item.id.to_string());
+                    message.set_priority(from_priority(&in_flight.item.priority));
+                    message.set_priorityLevel(in_flight.item.priority.level());
+                    let (capabilities, truncated) = response_tags(&in_flight.item.required_tags, self.max_response_tags);
+                    message.set_requiredCapabilities(capabilities.into());
+                    message.set_requiredCapabilitiesTruncated(truncated);
+                    message.set_ageMilliseconds(duration_to_millis(in_flight.age()));
+                    message.set_worker(in_flight.worker.unwrap_or_default());
+                    message.set_enqueuedBy(in_flight.item.enqueued_by.unwrap_or_default());
+                    response.mut_messages().push(message);
+                }
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_listProcessing(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to list processing messages: {}", e);
+                Err(Error::RequestError(format!("Failed to list processing messages: {}", e)))
+            }
+        }
+    }
+
+    fn health(&mut self, _request: &rpc::HealthRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let scan_depth = self.queue_server.scan_depth();
+
+        let mut response = rpc::HealthResponse::new();
+        response.set_highPriorityScanDepth(scan_depth.high_priority);
+        response.set_lowPriorityScanDepth(scan_depth.low_priority);
+        for (level, depth) in scan_depth.by_level {
+            let mut level_depth = rpc::LevelScanDepth::new();
+            level_depth.set_priorityLevel(level);
+            level_depth.set_scanDepth(depth);
+            response.mut_scanDepthByLevel().push(level_depth);
+        }
+        response.set_ready(self.queue_server.is_ready());
+        response.set_estimatedDrainSeconds(estimated_drain_seconds(&self.queue_server)?);
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_health(response);
+        Ok(wrapper)
+    }
+
+    fn diagnostic_dump(&mut self, _request: &rpc::DiagnosticDumpRequest) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        let dump = match self.queue_server.diagnostic_dump() {
+            Ok(dump) => dump,
+            Err(e) => {
+                eprintln!("Failed to create diagnostic dump: {}", e);
+                return Err(Error::RequestError(format!("Failed to create diagnostic dump: {}", e)));
+            }
+        };
+
+        let json = match serde_json::to_string(&dump) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to serialize diagnostic dump: {}", e);
+                return Err(Error::RequestError(format!("Failed to serialize diagnostic dump: {}", e)));
+            }
+        };
+
+        let mut response = rpc::DiagnosticDumpResponse::new();
+        response.set_json(json);
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_diagnosticDump(response);
+        Ok(wrapper)
+    }
+
+    fn stats(&mut self, request: &rpc::StatsRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let qs = self.queue(request.get_queueName())?;
+        match qs.stats() {
+            Ok(stats) => {
+                let mut response = rpc::StatsResponse::new();
+                for (level, count) in stats.pending_by_level {
+                    let mut pending = rpc::PendingCount::new();
+                    pending.set_priorityLevel(level);
+                    pending.set_count(count as u64);
+                    response.mut_pending().push(pending);
+                }
+                response.set_queued(stats.queued as u64);
+                response.set_processing(stats.processing as u64);
+                response.set_highPriority(stats.high_priority as u64);
+                response.set_lowPriority(stats.low_priority as u64);
+                response.set_enqueued(stats.enqueued as u64);
+                response.set_acknowledged(stats.acknowledged as u64);
+                response.set_estimatedDrainSeconds(estimated_drain_seconds(&qs)?);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_stats(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to get stats: {}", e);
+                Err(Error::RequestError(format!("Failed to get stats: {}", e)))
+            }
+        }
+    }
+
+    fn purge(&mut self, request: &rpc::PurgeRequest) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        match self.queue(request.get_queueName())?.purge() {
+            Ok(removed) => {
+                let mut response = rpc::PurgeResponse::new();
+                response.set_removed(removed as u64);
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_purge(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to purge queue: {}", e);
+                Err(Error::RequestError(format!("Failed to purge queue: {}", e)))
+            }
+        }
+    }
+
+    fn export_dead_letters(&mut self, request: &rpc::ExportDeadLettersRequest) -> Result<rpc::ResponseWrapper, Error> {
+        self.ensure_admin()?;
+
+        let mut json = Vec::new();
+        match self.queue(request.get_queueName())?.export_dead_letters_to(&mut json, request.get_clear()) {
+            Ok(exported) => {
+                let mut response = rpc::ExportDeadLettersResponse::new();
+                response.set_exported(exported as u64);
+                response.set_json(String::from_utf8_lossy(&json).into_owned());
+                let mut wrapper = rpc::ResponseWrapper::new();
+                wrapper.set_exportDeadLetters(response);
+                Ok(wrapper)
+            }
+            Err(e) => {
+                eprintln!("Failed to export dead letters: {}", e);
+                Err(Error::RequestError(format!("Failed to export dead letters: {}", e)))
+            }
+        }
+    }
+
+    fn server_info(&mut self, _request: &rpc::ServerInfoRequest) -> Result<rpc::ResponseWrapper, Error> {
+        let info = self.queue_server.server_info();
+
+        let mut response = rpc::ServerInfoResponse::new();
+        response.set_uptimeMilliseconds(duration_to_millis(info.uptime));
+        response.set_version(info.version.to_string());
+        response.set_gitCommit(info.git_commit.unwrap_or("").to_string());
+        if let Some(sizes) = info.storage_file_sizes {
+            let mut storage_file_sizes = rpc::StorageFileSizes::new();
+            storage_file_sizes.set_highPriorityBytes(sizes.high_priority);
+            storage_file_sizes.set_lowPriorityBytes(sizes.low_priority);
+            storage_file_sizes.set_completedBytes(sizes.completed);
+            response.set_storageFileSizes(storage_file_sizes);
+        }
+        response.set_priorityBands(info.priority_bands.into());
+        response.set_defaultPriorityLevel(info.default_priority.level());
+        let mut wrapper = rpc::ResponseWrapper::new();
+        wrapper.set_serverInfo(response);
+        Ok(wrapper)
+    }
+
+    // Hands the outstanding tasks over to the session registry, which
+    // will fail them unless the worker reconnects within the grace period
+    fn drop_connection(mut self) {
+        self.end_subscription();
+        self.end_event_subscription();
+        if let Ok(mut tasks) = self.outstanding_tasks.lock() {
+            let tasks = std::mem::replace(&mut *tasks, HashMap::new());
+            let default = self.queue_server.clone();
+            let named_queues = self.named_queues.clone();
+            self.sessions.disconnect(self.session_token.clone(), self.username.clone(), tasks, move |name: &str| {
+                find_queue(&default, &named_queues, name).ok()
+            });
+        }
+    }
+
+    // Takes back the tasks of a previous connection from the same worker.
+    // A session started by another user isn't resumed, and the connection gets a new session instead.
+    fn resume_session(&mut self, token: &str) {
+        if let Some(resumed) = self.sessions.reconnect(token, self.username.as_deref()) {
+            debug!("Resumed session with {} outstanding tasks", resumed.len());
+            if let Ok(mut tasks) = self.outstanding_tasks.lock() {
+                tasks.extend(resumed);
+            }
+            self.session_token = token.to_string();
+        }
+    }
+
+    // Verifies the credentials, and takes over the session if requested.
+    // Returns false if the credentials are wrong.
+    fn authenticate(&mut self, request: &rpc::AuthenticateRequest) -> Result<bool, Error> {
+        if request.username.is_empty() {
+            return Err(Error::MalformedAuthenticateRequest("username is missing".to_string()));
+        }
+        if request.password.is_empty() {
+            return Err(Error::MalformedAuthenticateRequest("password is missing".to_string()));
+        }
 
         let success = self.auth.verify_user(&request.username, &request.password)?;
 
+        if success {
+            self.username = Some(request.username.clone());
+            if !request.sessionToken.is_empty() {
+                self.resume_session(&request.sessionToken);
+            }
+        }
+
+        Ok(success)
+    }
+
+    fn ensure_auth(&mut self, s: &mut TcpStream) -> Result<(), Error> {
+        let data = read_message(s)?;
+
+        let message = parse_request(data)?;
+
+        if !message.has_authenticate() {
+            return Err(Error::RequestError("Invalid request".to_string()));
+        }
+
+        let success = match self.authenticate(message.get_authenticate()) {
+            Ok(success) => success,
+            Err(e @ Error::MalformedAuthenticateRequest(_)) => {
+                // Let the client know it's a bug in the client, and not wrong credentials
+                reply_error(s, format!("{}", e), rpc::ErrorCode::UNKNOWN, message.refId);
+                return Err(e);
+            }
+            Err(e @ Error::AuthenticationFailed(AuthenticationError::Locked)) => {
+                // Let the client know it's not the credentials that are wrong
+                reply_error(s, format!("{}", e), rpc::ErrorCode::UNKNOWN, message.refId);
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
         let mut response = rpc::AuthenticateResponse::new();
         response.set_success(success);
+        if success {
+            response.set_sessionToken(self.session_token.clone());
+        }
         let mut wrapper = rpc::ResponseWrapper::new();
         wrapper.set_authenticate(response);
         wrapper.set_refId(message.refId);
@@ -291,6 +1577,131 @@ impl Client {
         }
     }
 
+    // Handles a single request, and sends the reply
+    fn handle_request(&mut self, message: rpc::RequestWrapper) {
+        let ref_id = message.get_refId();
+        let span = info_span!(
+            "request",
+            connection = self.connection_id,
+            ref_id = ref_id,
+            request = request_type(&message),
+        );
+        let _entered = span.enter();
+
+        let result = if message.has_enqueue() {
+            let enqueue_request = message.get_enqueue();
+            self.enqueue(enqueue_request)
+        } else if message.has_enqueueBatch() {
+            let enqueue_batch_request = message.get_enqueueBatch();
+            self.enqueue_batch(enqueue_batch_request)
+        } else if message.has_beginEnqueue() {
+            let begin_enqueue_request = message.get_beginEnqueue();
+            self.begin_enqueue(begin_enqueue_request)
+        } else if message.has_enqueueChunk() {
+            let enqueue_chunk_request = message.get_enqueueChunk();
+            self.enqueue_chunk(enqueue_chunk_request)
+        } else if message.has_commitEnqueue() {
+            let commit_enqueue_request = message.get_commitEnqueue();
+            self.commit_enqueue(commit_enqueue_request)
+        } else if message.has_acknowledge() {
+            let acknowledge_request = message.get_acknowledge();
+            self.acknowledge(acknowledge_request)
+        } else if message.has_pop() {
+            let pop_request = message.get_pop();
+            self.pop(pop_request)
+        } else if message.has_cancel() {
+            let cancel_request = message.get_cancel();
+            self.cancel(cancel_request)
+        } else if message.has_subscribe() {
+            let subscribe_request = message.get_subscribe();
+            self.subscribe(subscribe_request, ref_id)
+        } else if message.has_eventSubscribe() {
+            let event_subscribe_request = message.get_eventSubscribe();
+            self.event_subscribe(event_subscribe_request, ref_id)
+        } else if message.has_reserve() {
+            let reserve_request = message.get_reserve();
+            self.reserve(reserve_request)
+        } else if message.has_confirmReservation() {
+            let confirm_reservation_request = message.get_confirmReservation();
+            self.confirm_reservation(confirm_reservation_request)
+        } else if message.has_cancelPop() {
+            let cancel_pop_request = message.get_cancelPop();
+            self.cancel_pop(cancel_pop_request)
+        } else if message.has_fail() {
+            let fail_request = message.get_fail();
+            self.fail(fail_request)
+        } else if message.has_decline() {
+            let decline_request = message.get_decline();
+            self.decline(decline_request)
+        } else if message.has_failWithRetag() {
+            let fail_with_retag_request = message.get_failWithRetag();
+            self.fail_with_retag(fail_with_retag_request)
+        } else if message.has_acknowledgeMatching() {
+            let acknowledge_matching_request = message.get_acknowledgeMatching();
+            self.acknowledge_matching(acknowledge_matching_request)
+        } else if message.has_findByTag() {
+            let find_by_tag_request = message.get_findByTag();
+            self.find_by_tag(find_by_tag_request)
+        } else if message.has_peek() {
+            let peek_request = message.get_peek();
+            self.peek(peek_request)
+        } else if message.has_listQueued() {
+            let list_queued_request = message.get_listQueued();
+            self.list_queued(list_queued_request)
+        } else if message.has_listProcessing() {
+            let list_processing_request = message.get_listProcessing();
+            self.list_processing(list_processing_request)
+        } else if message.has_setCapabilityPaused() {
+            let set_capability_paused_request = message.get_setCapabilityPaused();
+            self.set_capability_paused(set_capability_paused_request)
+        } else if message.has_diagnosticDump() {
+            let diagnostic_dump_request = message.get_diagnosticDump();
+            self.diagnostic_dump(diagnostic_dump_request)
+        } else if message.has_serverInfo() {
+            let server_info_request = message.get_serverInfo();
+            self.server_info(server_info_request)
+        } else if message.has_stats() {
+            let stats_request = message.get_stats();
+            self.stats(stats_request)
+        } else if message.has_purge() {
+            let purge_request = message.get_purge();
+            self.purge(purge_request)
+        } else if message.has_exportDeadLetters() {
+            let export_dead_letters_request = message.get_exportDeadLetters();
+            self.export_dead_letters(export_dead_letters_request)
+        } else if message.has_health() {
+            let health_request = message.get_health();
+            self.health(health_request)
+        } else {
+            Err(Error::RequestError("Unknown request".to_string()))
+        };
+
+        match result {
+            Ok(mut wrapper) => {
+                wrapper.set_refId(ref_id);
+                match send_shared(&self.writer, wrapper) {
+                    Err(e) => eprintln!("Failed to send reply: {}", e),
+                    _ => debug!("Response send without issue for ref_id '{}'", ref_id),
+                };
+            }
+            Err(Error::RequestError(error_message)) => {
+                let _ = send_shared(&self.writer, error_response(error_message, rpc::ErrorCode::UNKNOWN, ref_id));
+            }
+            Err(Error::StorageFull(error_message)) => {
+                let _ = send_shared(&self.writer, error_response(error_message, rpc::ErrorCode::STORAGE_FULL, ref_id));
+            }
+            Err(Error::BadRequest(error_message)) => {
+                let _ = send_shared(&self.writer, error_response(error_message, rpc::ErrorCode::BAD_REQUEST, ref_id));
+            }
+            Err(Error::NotFound(error_message)) => {
+                let _ = send_shared(&self.writer, error_response(error_message, rpc::ErrorCode::NOT_FOUND, ref_id));
+            }
+            Err(e) => {
+                eprintln!("Unexpected error {}", e);
+            }
+        }
+    }
+
     pub fn handle_connection(mut self, mut s: TcpStream) {
         match self.ensure_auth(&mut s) {
             Err(e) => {
@@ -300,56 +1711,1149 @@ impl Client {
             Ok(()) => {},
         }
 
-
+        match s.try_clone() {
+            Ok(writer) => {
+                if let Ok(mut w) = self.writer.lock() {
+                    *w = Some(writer);
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to set up connection: {}", e);
+                return;
+            }
+        }
+
+        if self.concurrency > 1 {
+            return self.handle_requests_concurrently(s);
+        }
+
         loop {
-            match read_message(&mut s) {
-                Ok(data) => {
-                    let message = match parse_request(data) {
-                        Ok(message) => message,
-                        Err(e) => {
-                            eprintln!("Failed to parse message: {}", e);
-                            return;
-                        }
-                    };
-
-                    let ref_id = message.get_refId();
-
-                    let result = if message.has_enqueue() {
-                        let enqueue_request = message.get_enqueue();
-                        self.enqueue(enqueue_request)
-                    } else if message.has_acknowledge() {
-                        let acknowledge_request = message.get_acknowledge();
-                        self.acknowledge(acknowledge_request)
-                    } else if message.has_pop() {
-                        let pop_request = message.get_pop();
-                        self.pop(pop_request)
-                    } else {
-                        Err(Error::RequestError("Unknown request".to_string()))
-                    };
-
-                    match result {
-                        Ok(mut wrapper) => {
-                            wrapper.set_refId(ref_id);
-                            match send_reply(&mut s, wrapper) {
-                                Err(e) => eprintln!("Failed to send reply: {}", e),
-                                _ => debug!("Response send without issue for ref_id '{}'", ref_id),
-                            };
-                        }
-                        Err(Error::RequestError(error_message)) => {
-                            reply_error(&mut s, error_message, ref_id);
-                        }
-                        Err(e) => {
-                            eprintln!("Unexpected error {}", e);
-                        }
+            let message = match read_message(&mut s) {
+                Ok(data) => match parse_request(data) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        eprintln!("Failed to parse message: {}", e);
+                        break;
                     }
+                },
+                Err(e) => {
+                    println!("Failed to read new message from client: {}", e);
+                    break;
+                }
+            };
+
+            self.handle_request(message);
+        }
+        drop(s);
+        self.drop_connection();
+    }
+
+    // Reads requests from the connection, and hands them to a thread for each
+    // request that can be handled at once
+    fn handle_requests_concurrently(self, mut s: TcpStream) {
+        let (requests, received) = bounded::<rpc::RequestWrapper>(0);
+        let (finished, done) = bounded::<()>(self.concurrency);
+        for _ in 0..self.concurrency {
+            let mut worker = self.clone();
+            let received = received.clone();
+            let finished = finished.clone();
+            thread::spawn(move || {
+                for message in received.iter() {
+                    worker.handle_request(message);
                 }
+                let _ = finished.send(());
+            });
+        }
+
+        loop {
+            let message = match read_message(&mut s) {
+                Ok(data) => match parse_request(data) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        eprintln!("Failed to parse message: {}", e);
+                        break;
+                    }
+                },
                 Err(e) => {
                     println!("Failed to read new message from client: {}", e);
-                    drop(s);
-                    self.drop_connection();
-                    return;
+                    break;
+                }
+            };
+            if requests.send(message).is_err() {
+                break;
+            }
+        }
+        drop(requests);
+        drop(s);
+
+        // The tasks popped by the requests still running has to be handed over with the
+        // rest of the outstanding tasks, so wait for them, cancelling any waiting pop
+        let mut running = self.concurrency;
+        while running > 0 {
+            select! {
+                recv(done) -> _ => running -= 1,
+                default(DISCONNECT_CANCEL_INTERVAL) => {
+                    let _ = self.sessions.cancel_waiting_pop(&self.session_token, self.username.as_deref());
                 }
             }
         }
+        self.drop_connection();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use crate::authentication::Authentication;
+    use crate::config::Config;
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    // Hands out the data in the given chunks, one chunk per read
+    struct ChunkedStream {
+        chunks: Vec<Vec<u8>>,
+    }
+
+    impl Read for ChunkedStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.chunks.is_empty() {
+                return Ok(0);
+            }
+            let chunk = &mut self.chunks[0];
+            let read = chunk.len().min(buf.len());
+            buf[..read].copy_from_slice(&chunk[..read]);
+            chunk.drain(..read);
+            if chunk.is_empty() {
+                self.chunks.remove(0);
+            }
+            Ok(read)
+        }
+    }
+
+    #[test]
+    fn reassembles_messages_split_over_several_reads() {
+        let payload: Vec<u8> = (0..10000).map(|i| (i % 251) as u8).collect();
+        let mut data = get_size_array(payload.len() as i32).unwrap();
+        data.extend(&payload);
+        let rest = data.split_off(6000);
+        let mut stream = ChunkedStream { chunks: vec![data[..2].to_vec(), data[2..].to_vec(), rest] };
+
+        assert_eq!(read_message(&mut stream).ok().expect("Failed to read message"), payload);
+        match read_message(&mut stream) {
+            Err(Error::ConnectionReset) => {}
+            _ => panic!("Expected the connection to be closed"),
+        }
+    }
+
+    #[test]
+    fn connection_closed_in_the_middle_of_a_message_is_a_reset() {
+        let mut data = get_size_array(100).unwrap();
+        data.extend(vec![1u8; 40]);
+        let mut stream = ChunkedStream { chunks: vec![data[..10].to_vec(), data[10..].to_vec()] };
+
+        match read_message(&mut stream) {
+            Err(Error::ConnectionReset) => {}
+            _ => panic!("Expected the connection to be reset"),
+        }
+    }
+
+    #[test]
+    fn found_messages_have_the_priority_level() {
+        let item = models::QueueItem::new(b"foo".to_vec(), models::Tags::new(), models::Priority::Level(5));
+        let found = found_message(item, 0);
+        assert_eq!(found.get_priority(), rpc::Priority::HIGH);
+        assert_eq!(found.get_priorityLevel(), 5);
+    }
+
+    fn setup() -> Client {
+        setup_with_user(None)
+    }
+
+    // Sets up a client using the file backend, with the given username and password added.
+    // The user called admin is allowed to use admin operations.
+    fn setup_with_user(user: Option<(&str, &str)>) -> Client {
+        let root = setup_test_storage().unwrap();
+        let mut auth = Authentication::new_with_admin_users(
+            PathBuf::from(format!("{}users", root)),
+            Some(vec!["admin".to_string()]),
+        ).expect("Failed to create authentication");
+        if let Some((username, password)) = user {
+            auth.add_user(username.to_string(), password.to_string()).unwrap();
+        }
+
+        setup_with_backend(&root, Arc::new(auth))
+    }
+
+    fn setup_with_backend(root: &str, auth: Arc<dyn AuthBackend>) -> Client {
+        let qs = queue_server::QueueServer::new_with_filename(format!("{}test", root))
+            .expect("Failed to create queue server");
+        let transfers = Transfers::new(PathBuf::from(format!("{}transfers", root)))
+            .expect("Failed to create transfers");
+
+        Client::new(qs, auth, Sessions::new(Duration::from_millis(0)), transfers)
+    }
+
+    #[test]
+    fn diagnostic_dump_requires_admin() {
+        let root = setup_test_storage().unwrap();
+        let qs = queue_server::QueueServer::new_with_filename(format!("{}test", root))
+            .expect("Failed to create queue server");
+        let auth = Authentication::new_with_admin_users(
+            PathBuf::from(format!("{}users", root)),
+            Some(vec!["admin".to_string()]),
+        ).expect("Failed to create authentication");
+        let transfers = Transfers::new(PathBuf::from(format!("{}transfers", root)))
+            .expect("Failed to create transfers");
+        let mut client = Client::new(qs, Arc::new(auth), Sessions::new(Duration::from_millis(0)), transfers);
+
+        client.username = Some("guest".to_string());
+        assert!(client.diagnostic_dump(&rpc::DiagnosticDumpRequest::new()).is_err());
+
+        client.username = Some("admin".to_string());
+        let response = client.diagnostic_dump(&rpc::DiagnosticDumpRequest::new()).ok().expect("Failed to get dump");
+        assert!(response.get_diagnosticDump().get_json().contains("\"processing\":0"));
+    }
+
+    fn authenticate_request(username: &str, password: &str) -> rpc::AuthenticateRequest {
+        let mut request = rpc::AuthenticateRequest::new();
+        request.set_username(username.to_string());
+        request.set_password(password.to_string());
+        request
+    }
+
+    #[test]
+    fn authenticate_rejects_missing_fields() {
+        let mut client = setup_with_user(Some(("guest", "guest")));
+
+        match client.authenticate(&authenticate_request("", "guest")) {
+            Err(Error::MalformedAuthenticateRequest(_)) => {}
+            _ => panic!("Empty username was not rejected as malformed"),
+        }
+        match client.authenticate(&authenticate_request("guest", "")) {
+            Err(Error::MalformedAuthenticateRequest(_)) => {}
+            _ => panic!("Empty password was not rejected as malformed"),
+        }
+
+        // Wrong credentials are still just a failed login
+        match client.authenticate(&authenticate_request("guest", "wrong")) {
+            Ok(false) => {}
+            _ => panic!("Wrong password was not a failed login"),
+        }
+        match client.authenticate(&authenticate_request("guest", "guest")) {
+            Ok(true) => {}
+            _ => panic!("Valid login failed"),
+        }
+        assert_eq!(client.username, Some("guest".to_string()));
+    }
+
+    struct MockBackend {
+        accept: bool,
+        verified: Mutex<Vec<String>>,
+    }
+
+    impl AuthBackend for MockBackend {
+        fn verify_user(&self, username: &str, _password: &str) -> Result<bool, AuthenticationError> {
+            self.verified.lock().unwrap().push(username.to_string());
+            Ok(self.accept)
+        }
+
+        fn is_admin(&self, _username: &str) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn ensure_auth_uses_the_backend_decision() {
+        for &accept in &[true, false] {
+            let backend = Arc::new(MockBackend { accept, verified: Mutex::new(Vec::new()) });
+            let root = setup_test_storage().unwrap();
+            let mut client = setup_with_backend(&root, backend.clone());
+
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let mut worker = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let (mut server_side, _) = listener.accept().unwrap();
+
+            let mut request = rpc::RequestWrapper::new();
+            request.set_authenticate(authenticate_request("someone", "pw"));
+            send_request(&mut worker, request);
+
+            match client.ensure_auth(&mut server_side) {
+                Ok(()) => assert!(accept, "Login was accepted although the backend rejected it"),
+                Err(Error::InvalidLogin) => assert!(!accept, "Login was rejected although the backend accepted it"),
+                Err(e) => panic!("Unexpected error: {}", e),
+            }
+            assert_eq!(read_response(&mut worker).get_authenticate().get_success(), accept);
+            assert_eq!(*backend.verified.lock().unwrap(), vec!["someone".to_string()]);
+        }
+    }
+
+    fn pop_request(capabilities: Vec<&str>) -> rpc::PopRequest {
+        let mut request = rpc::PopRequest::new();
+        request.set_availableCapabilities(capabilities.iter().map(|s| s.to_string()).collect());
+        request
+    }
+
+    #[test]
+    fn pop_reports_unmatched_messages() {
+        let mut client = setup();
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_requiredCapabilities(vec!["x".to_string()].into());
+        client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+
+        let response = client.pop(&pop_request(vec!["y"])).ok().expect("Failed to pop");
+        assert!(!response.get_pop().get_hadResult());
+        assert!(response.get_pop().get_hasUnmatchedMessages());
+
+        let response = client.pop(&pop_request(vec!["x"])).ok().expect("Failed to pop");
+        assert!(response.get_pop().get_hadResult());
+        assert!(!response.get_pop().get_hasUnmatchedMessages());
+    }
+
+    #[test]
+    fn pop_reports_who_enqueued_the_message() {
+        let mut producer = setup();
+        producer.username = Some("a".to_string());
+        let mut worker = producer.clone();
+        worker.username = Some("b".to_string());
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        producer.enqueue(&enqueue).ok().expect("Failed to enqueue");
+
+        let response = worker.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert!(response.get_pop().get_hadResult());
+        assert_eq!(response.get_pop().get_enqueuedBy(), "a");
+    }
+
+    #[test]
+    fn named_queue_tasks_are_acknowledged_in_their_queue() {
+        let root = setup_test_storage().unwrap();
+        let client = setup_with_user(None);
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues.clone());
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_queueName("emails".to_string());
+        client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+
+        assert!(!client.pop(&pop_request(vec![])).ok().expect("Failed to pop").get_pop().get_hadResult());
+        let mut request = pop_request(vec![]);
+        request.set_queueName("emails".to_string());
+        let response = client.pop(&request).ok().expect("Failed to pop");
+        assert!(response.get_pop().get_hadResult());
+
+        let mut acknowledge = rpc::AcknowledgeRequest::new();
+        acknowledge.set_id(response.get_pop().get_id().to_string());
+        client.acknowledge(&acknowledge).ok().expect("Failed to acknowledge");
+        assert!(named_queues.get("emails").unwrap().list_processing().unwrap().is_empty());
+    }
+
+    #[test]
+    fn pop_tells_the_worker_how_long_it_has_to_acknowledge() {
+        let mut client = setup();
+        for _ in 0..3 {
+            let mut enqueue = rpc::EnqueueRequest::new();
+            enqueue.set_message(b"foo".to_vec());
+            client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        }
+
+        let response = client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert_eq!(response.get_pop().get_visibilityTimeoutMilliseconds(), 0);
+
+        client.queue_server = client.queue_server.clone().with_visibility_timeout(Duration::from_secs(30));
+        let response = client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert_eq!(response.get_pop().get_visibilityTimeoutMilliseconds(), 30000);
+
+        let mut pop = pop_request(vec![]);
+        pop.set_visibilityTimeoutMilliseconds(5000);
+        let response = client.pop(&pop).ok().expect("Failed to pop");
+        assert_eq!(response.get_pop().get_visibilityTimeoutMilliseconds(), 5000);
+    }
+
+    #[test]
+    fn requests_use_the_requested_queue() {
+        let root = setup_test_storage().unwrap();
+        let client = setup_with_user(None);
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues.clone());
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_requiredCapabilities(vec!["x".to_string()].into());
+        enqueue.set_queueName("emails".to_string());
+        let id = client.enqueue(&enqueue).ok().expect("Failed to enqueue").get_enqueue().get_id().to_string();
+
+        let mut peek = rpc::PeekRequest::new();
+        peek.set_availableCapabilities(vec!["x".to_string()].into());
+        assert!(!client.peek(&peek).ok().expect("Failed to peek").get_peek().get_hadResult());
+        peek.set_queueName("emails".to_string());
+        assert!(client.peek(&peek).ok().expect("Failed to peek").get_peek().get_hadResult());
+
+        let mut find = rpc::FindByTagRequest::new();
+        find.set_tags(vec!["x".to_string()].into());
+        find.set_queueName("emails".to_string());
+        assert_eq!(client.find_by_tag(&find).ok().expect("Failed to find").get_findByTag().get_total(), 1);
+
+        let mut list = rpc::ListQueuedRequest::new();
+        list.set_queueName("emails".to_string());
+        assert_eq!(client.list_queued(&list).ok().expect("Failed to list").get_listQueued().get_messages().len(), 1);
+
+        let mut cancel = rpc::CancelRequest::new();
+        cancel.set_id(id);
+        assert!(!client.cancel(&cancel).ok().expect("Failed to cancel").get_cancel().get_cancelled());
+        cancel.set_queueName("emails".to_string());
+        assert!(client.cancel(&cancel).ok().expect("Failed to cancel").get_cancel().get_cancelled());
+        assert_eq!(named_queues.get("emails").unwrap().queued_len().unwrap(), 0);
+    }
+
+    #[test]
+    fn named_queue_tasks_are_kept_during_the_grace_period() {
+        let root = setup_test_storage().unwrap();
+        let mut client = setup_with_user(None);
+        client.sessions = Sessions::new(Duration::from_secs(60));
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues.clone());
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_queueName("emails".to_string());
+        client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        let mut pop = pop_request(vec![]);
+        pop.set_queueName("emails".to_string());
+        let id = client.pop(&pop).ok().expect("Failed to pop").get_pop().get_id().to_string();
+
+        let token = client.session_token.clone();
+        let mut resumed = Client::new(client.queue_server.clone(), client.auth.clone(), client.sessions.clone(), client.transfers.clone())
+            .with_named_queues(named_queues.clone());
+        client.drop_connection();
+        assert_eq!(named_queues.get("emails").unwrap().list_processing().unwrap().len(), 1);
+
+        resumed.resume_session(&token);
+        let mut acknowledge = rpc::AcknowledgeRequest::new();
+        acknowledge.set_id(id);
+        assert!(!resumed.acknowledge(&acknowledge).ok().expect("Failed to acknowledge").get_acknowledge().get_alreadyCompleted());
+        assert!(named_queues.get("emails").unwrap().list_processing().unwrap().is_empty());
+    }
+
+    #[test]
+    fn sessions_can_only_be_resumed_by_the_same_user() {
+        let mut client = setup();
+        client.sessions = Sessions::new(Duration::from_secs(60));
+        client.username = Some("alice".to_string());
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        assert!(client.pop(&pop_request(vec![])).ok().expect("Failed to pop").get_pop().get_hadResult());
+
+        let token = client.session_token.clone();
+        let mut other = Client::new(client.queue_server.clone(), client.auth.clone(), client.sessions.clone(), client.transfers.clone());
+        other.username = Some("mallory".to_string());
+        let mut resumed = Client::new(client.queue_server.clone(), client.auth.clone(), client.sessions.clone(), client.transfers.clone());
+        resumed.username = Some("alice".to_string());
+        client.drop_connection();
+
+        // Another user gets a session of its own
+        other.resume_session(&token);
+        assert_ne!(other.session_token, token);
+        assert!(other.outstanding_tasks.lock().unwrap().is_empty());
+
+        resumed.resume_session(&token);
+        assert_eq!(resumed.session_token, token);
+        assert_eq!(resumed.outstanding_tasks.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn stats_are_reported_for_the_requested_queue() {
+        let root = setup_test_storage().unwrap();
+        let client = setup_with_user(None);
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues);
+
+        for _ in 0..3 {
+            let mut enqueue = rpc::EnqueueRequest::new();
+            enqueue.set_message(b"foo".to_vec());
+            enqueue.set_queueName("emails".to_string());
+            client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        }
+        let mut pop = pop_request(vec![]);
+        pop.set_queueName("emails".to_string());
+        assert!(client.pop(&pop).ok().expect("Failed to pop").get_pop().get_hadResult());
+
+        let mut request = rpc::StatsRequest::new();
+        request.set_queueName("emails".to_string());
+        let response = client.stats(&request).ok().expect("Failed to get stats");
+        let stats = response.get_stats();
+        assert_eq!(stats.get_queued(), 2);
+        assert_eq!(stats.get_lowPriority(), 2);
+        assert_eq!(stats.get_processing(), 1);
+        assert_eq!(stats.get_enqueued(), 3);
+
+        let response = client.stats(&rpc::StatsRequest::new()).ok().expect("Failed to get stats");
+        assert_eq!(response.get_stats().get_queued(), 0);
+        // Nothing has been popped from the default queue
+        assert_eq!(response.get_stats().get_estimatedDrainSeconds(), -1.0);
+    }
+
+    #[test]
+    fn read_only_requests_dont_create_queues() {
+        let root = setup_test_storage().unwrap();
+        let client = setup_with_user(None);
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues.clone());
+
+        let mut request = rpc::StatsRequest::new();
+        request.set_queueName("emails".to_string());
+        match client.stats(&request) {
+            Err(Error::NotFound(_)) => {}
+            _ => panic!("Stats were reported for a queue that doesn't exist"),
+        }
+        let mut pop = pop_request(vec![]);
+        pop.set_queueName("emails".to_string());
+        match client.pop(&pop) {
+            Err(Error::NotFound(_)) => {}
+            _ => panic!("Popped from a queue that doesn't exist"),
+        }
+        assert!(named_queues.names().unwrap().is_empty());
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_queueName("emails".to_string());
+        client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        let response = client.stats(&request).ok().expect("Failed to get stats");
+        assert_eq!(response.get_stats().get_queued(), 1);
+    }
+
+    #[test]
+    fn purge_only_empties_the_requested_queue() {
+        let root = setup_test_storage().unwrap();
+        let client = setup_with_user(None);
+        let named_queues = NamedQueues::new(client.queue_server.clone(), format!("{}queues", root), Default::default());
+        let mut client = client.with_named_queues(named_queues.clone());
+
+        for queue_name in &["emails", ""] {
+            let mut enqueue = rpc::EnqueueRequest::new();
+            enqueue.set_message(b"foo".to_vec());
+            enqueue.set_queueName(queue_name.to_string());
+            client.enqueue(&enqueue).ok().expect("Failed to enqueue");
+        }
+
+        let mut request = rpc::PurgeRequest::new();
+        request.set_queueName("emails".to_string());
+        assert!(client.purge(&request).is_err());
+
+        client.username = Some("admin".to_string());
+        let response = client.purge(&request).ok().expect("Failed to purge");
+        assert_eq!(response.get_purge().get_removed(), 1);
+        assert_eq!(named_queues.get("emails").unwrap().queued_len().unwrap(), 0);
+        assert_eq!(client.queue_server.queued_len().unwrap(), 1);
+    }
+
+    #[test]
+    fn dead_letters_are_exported_for_admins() {
+        let root = setup_test_storage().unwrap();
+        let mut client = setup();
+        let mut config = Config::default();
+        config.max_attempts = 1;
+        client.queue_server = queue_server::QueueServer::new_with_config(format!("{}dead_letters", root), &config)
+            .expect("Failed to create queue server");
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"poison".to_vec());
+        let id = client.enqueue(&enqueue).ok().expect("Failed to enqueue").get_enqueue().get_id().to_string();
+        client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        let mut fail = rpc::FailRequest::new();
+        fail.set_id(id.clone());
+        client.fail(&fail).ok().expect("Failed to fail");
+
+        let mut request = rpc::ExportDeadLettersRequest::new();
+        request.set_clear(true);
+        assert!(client.export_dead_letters(&request).is_err());
+
+        client.username = Some("admin".to_string());
+        let response = client.export_dead_letters(&request).ok().expect("Failed to export");
+        let exported = response.get_exportDeadLetters();
+        assert_eq!(exported.get_exported(), 1);
+        assert!(exported.get_json().contains(&format!("\"id\":\"{}\"", id)));
+        assert!(exported.get_json().contains("\"attempts\":1"));
+        assert!(client.queue_server.dead_letters().unwrap().is_empty());
+    }
+
+    #[test]
+    fn enqueue_reports_full_storage() {
+        let mut client = setup();
+        client.queue_server.simulate_full_disk();
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        match client.enqueue(&enqueue) {
+            Err(Error::StorageFull(_)) => {}
+            _ => panic!("Expected the storage to be full"),
+        }
+    }
+
+    #[test]
+    fn pop_on_empty_queue_has_no_unmatched_messages() {
+        let mut client = setup();
+
+        let response = client.pop(&pop_request(vec!["y"])).ok().expect("Failed to pop");
+        assert!(!response.get_pop().get_hadResult());
+        assert!(!response.get_pop().get_hasUnmatchedMessages());
+    }
+
+    #[test]
+    fn waiting_pop_can_be_cancelled_from_another_connection() {
+        let client = setup();
+        let mut control = client.clone();
+        control.session_token = Sessions::new_token();
+        let token = client.session_token.clone();
+
+        let mut c = client.clone();
+        let handle = thread::spawn(move || {
+            let mut request = pop_request(vec![]);
+            request.set_waitForMessage(true);
+            c.pop(&request).ok().expect("Failed to pop")
+        });
+
+        let mut request = rpc::CancelPopRequest::new();
+        request.set_sessionToken(token);
+        // The pop might not have started waiting yet
+        let mut cancelled = false;
+        for _ in 0..100 {
+            let response = control.cancel_pop(&request).ok().expect("Failed to cancel pop");
+            if response.get_cancelPop().get_cancelled() {
+                cancelled = true;
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(cancelled);
+
+        let response = handle.join().expect("Failed to join thread");
+        assert!(!response.get_pop().get_hadResult());
+        assert!(response.get_pop().get_cancelled());
+    }
+
+    #[test]
+    fn waiting_pop_of_another_user_cant_be_cancelled() {
+        let mut client = setup();
+        client.username = Some("worker".to_string());
+        let mut control = client.clone();
+        control.session_token = Sessions::new_token();
+        control.username = Some("intruder".to_string());
+
+        let _cancel = client.sessions.begin_waiting_pop(&client.session_token, client.username.clone());
+        let mut request = rpc::CancelPopRequest::new();
+        request.set_sessionToken(client.session_token.clone());
+        match control.cancel_pop(&request) {
+            Err(Error::NotFound(_)) => {}
+            _ => panic!("Pop of another user was cancelled"),
+        }
+
+        control.username = Some("worker".to_string());
+        let response = control.cancel_pop(&request).ok().expect("Failed to cancel pop");
+        assert!(response.get_cancelPop().get_cancelled());
+    }
+
+    #[test]
+    fn can_enqueue_in_chunks() {
+        let mut client = setup();
+        let payload: Vec<u8> = (0..100000).map(|i| (i % 251) as u8).collect();
+
+        let mut begin = rpc::BeginEnqueueRequest::new();
+        begin.set_priority(rpc::Priority::HIGH);
+        let response = client.begin_enqueue(&begin).ok().expect("Failed to begin enqueue");
+        let transfer_id = response.get_beginEnqueue().get_transferId().to_string();
+
+        let mut offset = 0;
+        for chunk in payload.chunks(30000) {
+            let mut request = rpc::EnqueueChunkRequest::new();
+            request.set_transferId(transfer_id.clone());
+            request.set_offset(offset);
+            request.set_data(chunk.to_vec());
+            let response = client.enqueue_chunk(&request).ok().expect("Failed to send chunk");
+            offset += chunk.len() as u64;
+            assert_eq!(response.get_enqueueChunk().get_received(), offset);
+        }
+
+        let mut commit = rpc::CommitEnqueueRequest::new();
+        commit.set_transferId(transfer_id);
+        let response = client.commit_enqueue(&commit).ok().expect("Failed to commit enqueue");
+        let id = response.get_commitEnqueue().get_id().to_string();
+
+        let response = client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert!(response.get_pop().get_hadResult());
+        assert_eq!(response.get_pop().get_id(), id);
+        assert_eq!(response.get_pop().get_message(), &payload[..]);
+    }
+
+    #[test]
+    fn chunked_enqueue_uses_the_same_options_as_enqueue() {
+        let mut client = setup();
+
+        let mut begin = rpc::BeginEnqueueRequest::new();
+        begin.set_priorityLevel(5);
+        match client.begin_enqueue(&begin) {
+            Err(Error::BadRequest(_)) => {}
+            _ => panic!("Unknown priority level was accepted before the payload was sent"),
+        }
+
+        begin.set_priorityLevel(1);
+        begin.set_delayMilliseconds(60000);
+        let response = client.begin_enqueue(&begin).ok().expect("Failed to begin enqueue");
+        let mut commit = rpc::CommitEnqueueRequest::new();
+        commit.set_transferId(response.get_beginEnqueue().get_transferId().to_string());
+        client.commit_enqueue(&commit).ok().expect("Failed to commit enqueue");
+
+        // Delayed, so it can't be popped yet
+        assert!(!client.pop(&pop_request(vec![])).ok().expect("Failed to pop").get_pop().get_hadResult());
+        assert_eq!(client.queue_server.queued_len().unwrap(), 1);
+    }
+
+    #[test]
+    fn chunked_enqueue_can_only_be_finished_by_the_same_user() {
+        let mut client = setup();
+        client.username = Some("alice".to_string());
+        let response = client.begin_enqueue(&rpc::BeginEnqueueRequest::new()).ok().expect("Failed to begin enqueue");
+        let transfer_id = response.get_beginEnqueue().get_transferId().to_string();
+
+        client.username = Some("bob".to_string());
+        let mut chunk = rpc::EnqueueChunkRequest::new();
+        chunk.set_transferId(transfer_id.clone());
+        chunk.set_data(b"foo".to_vec());
+        assert!(client.enqueue_chunk(&chunk).is_err());
+        let mut commit = rpc::CommitEnqueueRequest::new();
+        commit.set_transferId(transfer_id);
+        assert!(client.commit_enqueue(&commit).is_err());
+
+        client.username = Some("alice".to_string());
+        client.enqueue_chunk(&chunk).ok().expect("Failed to send chunk");
+        client.commit_enqueue(&commit).ok().expect("Failed to commit enqueue");
+        assert_eq!(client.queue_server.queued_len().unwrap(), 1);
+    }
+
+    #[test]
+    fn truncates_tags_in_responses() {
+        let mut client = setup().with_max_response_tags(2);
+        let tags = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        client.queue_server.enqueue(b"many".to_vec(), models::Priority::High, tags.clone())
+            .expect("Failed to enqueue");
+        client.queue_server.enqueue(b"few".to_vec(), models::Priority::Low, vec!["a".to_string()])
+            .expect("Failed to enqueue");
+
+        let mut request = rpc::FindByTagRequest::new();
+        request.set_tags(vec!["a".to_string()].into());
+        let response = client.find_by_tag(&request).ok().expect("Failed to find messages");
+        let messages = response.get_findByTag().get_messages();
+        assert_eq!(messages.len(), 2);
+
+        assert_eq!(messages[0].get_requiredCapabilities(), &["a".to_string(), "b".to_string()]);
+        assert!(messages[0].get_requiredCapabilitiesTruncated());
+        assert_eq!(messages[1].get_requiredCapabilities(), &["a".to_string()]);
+        assert!(!messages[1].get_requiredCapabilitiesTruncated());
+
+        let response = client.pop(&pop_request(vec!["a", "b", "c"])).ok().expect("Failed to pop");
+        assert_eq!(response.get_pop().get_requiredCapabilities(), &["a".to_string(), "b".to_string()]);
+        assert!(response.get_pop().get_requiredCapabilitiesTruncated());
+        let response = client.pop(&pop_request(vec!["a"])).ok().expect("Failed to pop");
+        assert_eq!(response.get_pop().get_requiredCapabilities(), &["a".to_string()]);
+        assert!(!response.get_pop().get_requiredCapabilitiesTruncated());
+    }
+
+    #[test]
+    fn lists_queued_messages_a_page_at_a_time() {
+        let mut client = setup();
+        for i in 0..5 {
+            client.queue_server.enqueue(format!("{}", i).into_bytes(), models::Priority::Low, vec![])
+                .expect("Failed to enqueue");
+        }
+
+        let mut messages = Vec::new();
+        let mut request = rpc::ListQueuedRequest::new();
+        request.set_limit(2);
+        loop {
+            let response = client.list_queued(&request).ok().expect("Failed to list messages");
+            let page = response.get_listQueued();
+            messages.extend(page.get_messages().iter().map(|message| message.get_message().to_vec()));
+            if page.get_nextCursor().is_empty() {
+                break;
+            }
+            request.set_cursor(page.get_nextCursor().to_string());
+        }
+        assert_eq!(messages, vec![b"0".to_vec(), b"1".to_vec(), b"2".to_vec(), b"3".to_vec(), b"4".to_vec()]);
+
+        request.set_cursor("not a cursor".to_string());
+        match client.list_queued(&request) {
+            Err(Error::BadRequest(_)) => {}
+            _ => panic!("An invalid cursor was accepted"),
+        }
+    }
+
+    #[test]
+    fn enqueue_at_absolute_time() {
+        let mut client = setup();
+        let available_at = SystemTime::now() + Duration::from_millis(300);
+
+        let mut request = rpc::EnqueueRequest::new();
+        request.set_message(b"later".to_vec());
+        request.set_availableAt(humantime::format_rfc3339(available_at).to_string());
+        client.enqueue(&request).ok().expect("Failed to enqueue");
+
+        let response = client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert!(!response.get_pop().get_hadResult());
+
+        thread::sleep(Duration::from_millis(350));
+        let response = client.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+        assert!(response.get_pop().get_hadResult());
+        assert_eq!(response.get_pop().get_message(), b"later");
+    }
+
+    #[test]
+    fn enqueue_with_idempotency_key_reports_existing_task() {
+        let mut client = setup();
+
+        let mut request = rpc::EnqueueRequest::new();
+        request.set_message(b"foo".to_vec());
+        request.set_idempotencyKey("key".to_string());
+
+        let first = client.enqueue(&request).ok().expect("Failed to enqueue");
+        let second = client.enqueue(&request).ok().expect("Failed to enqueue");
+        assert_eq!(first.get_enqueue().get_status(), rpc::EnqueueStatus::CREATED);
+        assert_eq!(second.get_enqueue().get_status(), rpc::EnqueueStatus::ALREADY_EXISTS);
+        assert_eq!(first.get_enqueue().get_id(), second.get_enqueue().get_id());
+    }
+
+    #[test]
+    fn enqueue_rejects_both_delay_and_absolute_time() {
+        let mut client = setup();
+
+        let mut request = rpc::EnqueueRequest::new();
+        request.set_message(b"foo".to_vec());
+        request.set_delayMilliseconds(1000);
+        request.set_availableAt("2018-12-24T18:00:00Z".to_string());
+        assert!(client.enqueue(&request).is_err());
+
+        let mut request = rpc::EnqueueRequest::new();
+        request.set_availableAt("tomorrow".to_string());
+        assert!(client.enqueue(&request).is_err());
+    }
+
+    #[test]
+    fn subscription_respects_prefetch_window() {
+        let mut client = setup();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut worker = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server_side, _) = listener.accept().unwrap();
+        *client.writer.lock().unwrap() = Some(server_side);
+
+        let mut request = rpc::SubscribeRequest::new();
+        request.set_prefetch(2);
+        client.subscribe(&request, 7).ok().expect("Failed to subscribe");
+
+        for i in 0..5 {
+            client.queue_server.enqueue(vec![i], models::Priority::High, vec![]).unwrap();
+        }
+
+        let mut pushed = Vec::new();
+        for _ in 0..2 {
+            let message: rpc::ResponseWrapper = protobuf::parse_from_bytes(&read_message(&mut worker).ok().unwrap()).unwrap();
+            assert_eq!(message.get_refId(), 7);
+            pushed.push(message.get_pop().get_id().to_string());
+        }
+
+        // Nothing more is pushed until something is acknowledged
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(client.queue_server.list_processing().unwrap().len(), 2);
+        assert_eq!(client.queue_server.queued_len().unwrap(), 3);
+
+        let mut acknowledge = rpc::AcknowledgeRequest::new();
+        acknowledge.set_id(pushed[0].clone());
+        client.acknowledge(&acknowledge).ok().expect("Failed to acknowledge");
+
+        let message: rpc::ResponseWrapper = protobuf::parse_from_bytes(&read_message(&mut worker).ok().unwrap()).unwrap();
+        assert!(message.get_pop().get_hadResult());
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(client.queue_server.list_processing().unwrap().len(), 2);
+        assert_eq!(client.queue_server.queued_len().unwrap(), 2);
+
+        client.end_subscription();
+    }
+
+    #[test]
+    fn event_subscription_streams_events_in_order() {
+        let mut client = setup();
+        client.username = Some("admin".to_string());
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut watcher = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server_side, _) = listener.accept().unwrap();
+        *client.writer.lock().unwrap() = Some(server_side);
+
+        let mut request = rpc::EventSubscribeRequest::new();
+        request.set_prefetch(10);
+        client.event_subscribe(&request, 9).ok().expect("Failed to subscribe to events");
+
+        let mut enqueue = rpc::EnqueueRequest::new();
+        enqueue.set_message(b"foo".to_vec());
+        enqueue.set_requiredCapabilities(vec!["a".to_string()].into());
+        let id = client.enqueue(&enqueue).ok().expect("Failed to enqueue").get_enqueue().get_id().to_string();
+        client.pop(&pop_request(vec!["a"])).ok().expect("Failed to pop");
+        let mut acknowledge = rpc::AcknowledgeRequest::new();
+        acknowledge.set_id(id.clone());
+        client.acknowledge(&acknowledge).ok().expect("Failed to acknowledge");
+
+        let mut kinds = Vec::new();
+        for _ in 0..3 {
+            let message: rpc::ResponseWrapper = protobuf::parse_from_bytes(&read_message(&mut watcher).ok().unwrap()).unwrap();
+            assert_eq!(message.get_refId(), 9);
+            assert_eq!(message.get_event().get_id(), id);
+            kinds.push(message.get_event().get_kind());
+        }
+        assert_eq!(kinds, vec![rpc::EventKind::ENQUEUED, rpc::EventKind::POPPED, rpc::EventKind::ACKNOWLEDGED]);
+
+        client.end_event_subscription();
+    }
+
+    #[test]
+    fn reserved_message_can_be_confirmed() {
+        let mut client = setup();
+        let created = client.queue_server.enqueue(b"foo".to_vec(), models::Priority::High, vec![]).unwrap();
+
+        let mut request = rpc::ReserveRequest::new();
+        request.set_reservationMilliseconds(10000);
+        let response = client.reserve(&request).ok().expect("Failed to reserve");
+        assert!(response.get_reserve().get_hadResult());
+        assert_eq!(response.get_reserve().get_message().get_message(), b"foo");
+        assert!(!client.reserve(&request).ok().expect("Failed to reserve").get_reserve().get_hadResult());
+
+        let mut confirm = rpc::ConfirmReservationRequest::new();
+        confirm.set_id(created.id.to_string());
+        let response = client.confirm_reservation(&confirm).ok().expect("Failed to confirm");
+        assert!(response.get_confirmReservation().get_confirmed());
+        assert!(client.outstanding_tasks.lock().unwrap().contains_key(&created.id));
+    }
+
+    #[test]
+    fn atomic_batch_with_an_invalid_item_enqueues_nothing() {
+        let mut client = setup();
+        client.queue_server = client.queue_server.clone().with_payload_validator(Arc::new(|payload: &Vec<u8>| crate::validation::json_payload(payload)));
+
+        let mut valid = rpc::EnqueueRequest::new();
+        valid.set_message(b"{}".to_vec());
+        let mut invalid = rpc::EnqueueRequest::new();
+        invalid.set_message(b"{".to_vec());
+        let mut request = rpc::EnqueueBatchRequest::new();
+        request.set_items(vec![valid, invalid].into());
+
+        let response = client.enqueue_batch(&request).ok().expect("Failed to enqueue batch");
+        let results = response.get_enqueueBatch().get_results();
+        assert!(results[0].get_error().is_empty());
+        assert!(!results[1].get_error().is_empty());
+        assert_eq!(client.queue_server.queued_len().unwrap(), 1);
+
+        request.set_atomic(true);
+        match client.enqueue_batch(&request) {
+            Err(Error::BadRequest(_)) => {}
+            _ => panic!("Expected the batch to be rejected"),
+        }
+        assert_eq!(client.queue_server.queued_len().unwrap(), 1);
+    }
+
+    #[test]
+    fn fast_popping_connection_is_rate_limited() {
+        let mut fast = setup().with_pop_rate_limit(10.0, 1);
+        let mut moderate = Client::new(fast.queue_server.clone(), fast.auth.clone(), fast.sessions.clone(), fast.transfers.clone())
+            .with_pop_rate_limit(10.0, 1);
+        for i in 0..10 {
+            fast.queue_server.enqueue(vec![i], models::Priority::High, vec![]).unwrap();
+        }
+
+        let limited = (0..5)
+            .filter(|_| fast.pop(&pop_request(vec![])).ok().expect("Failed to pop").get_pop().get_rateLimited())
+            .count();
+        assert_eq!(limited, 4);
+
+        for _ in 0..3 {
+            let response = moderate.pop(&pop_request(vec![])).ok().expect("Failed to pop");
+            assert!(!response.get_pop().get_rateLimited());
+            assert!(response.get_pop().get_hadResult());
+            thread::sleep(Duration::from_millis(150));
+        }
+    }
+
+    fn send_request(s: &mut TcpStream, message: rpc::RequestWrapper) {
+        let mut data = message.write_to_bytes().unwrap();
+        let mut size = get_size_array(data.len() as i32).unwrap();
+        size.append(&mut data);
+        s.write_all(&size).unwrap();
+    }
+
+    fn read_response(s: &mut TcpStream) -> rpc::ResponseWrapper {
+        protobuf::parse_from_bytes(&read_message(s).ok().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn acknowledge_is_handled_while_pop_waits_on_the_same_connection() {
+        let client = setup_with_user(Some(("worker", "pw"))).with_concurrency(2);
+        let mut qs = client.queue_server.clone();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut worker = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server_side, _) = listener.accept().unwrap();
+        let handle = thread::spawn(move || client.handle_connection(server_side));
+
+        let mut request = rpc::RequestWrapper::new();
+        request.set_authenticate(authenticate_request("worker", "pw"));
+        send_request(&mut worker, request);
+        assert!(read_response(&mut worker).get_authenticate().get_success());
+
+        let first = qs.enqueue(b"first".to_vec(), models::Priority::High, vec![]).unwrap();
+        let mut request = rpc::RequestWrapper::new();
+        request.set_pop(pop_request(vec![]));
+        request.set_refId(1);
+        send_request(&mut worker, request);
+        assert_eq!(read_response(&mut worker).get_pop().get_id(), first.id.to_string());
+
+        // Waits, since the queue is empty
+        let mut pop = pop_request(vec![]);
+        pop.set_waitForMessage(true);
+        let mut request = rpc::RequestWrapper::new();
+        request.set_pop(pop);
+        request.set_refId(2);
+        send_request(&mut worker, request);
+
+        let mut acknowledge = rpc::AcknowledgeRequest::new();
+        acknowledge.set_id(first.id.to_string());
+        let mut request = rpc::RequestWrapper::new();
+        request.set_acknowledge(acknowledge);
+        request.set_refId(3);
+        send_request(&mut worker, request);
+
+        let response = read_response(&mut worker);
+        assert_eq!(response.get_refId(), 3);
+        assert!(response.has_acknowledge());
+        assert!(qs.list_processing().unwrap().is_empty());
+
+        let second = qs.enqueue(b"second".to_vec(), models::Priority::High, vec![]).unwrap();
+        let response = read_response(&mut worker);
+        assert_eq!(response.get_refId(), 2);
+        assert_eq!(response.get_pop().get_id(), second.id.to_string());
+
+        drop(worker);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn malformed_request_hands_back_the_outstanding_tasks() {
+        let client = setup_with_user(Some(("worker", "pw")));
+        let mut qs = client.queue_server.clone();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut worker = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server_side, _) = listener.accept().unwrap();
+        let handle = thread::spawn(move || client.handle_connection(server_side));
+
+        let mut request = rpc::RequestWrapper::new();
+        request.set_authenticate(authenticate_request("worker", "pw"));
+        send_request(&mut worker, request);
+        assert!(read_response(&mut worker).get_authenticate().get_success());
+
+        let created = qs.enqueue(b"foo".to_vec(), models::Priority::High, vec![]).unwrap();
+        let mut request = rpc::RequestWrapper::new();
+        request.set_pop(pop_request(vec![]));
+        send_request(&mut worker, request);
+        assert_eq!(read_response(&mut worker).get_pop().get_id(), created.id.to_string());
+
+        // Not a valid request, which ends the connection
+        let mut garbage = get_size_array(3).unwrap();
+        garbage.extend_from_slice(&[0xff, 0xff, 0xff]);
+        worker.write_all(&garbage).unwrap();
+        handle.join().unwrap();
+
+        // Without a grace period the task is put back in the queue right away
+        assert!(qs.list_processing().unwrap().is_empty());
+        assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, created.id);
+    }
+
+    // Records the spans that are created while it's the default subscriber
+    struct SpanCapture {
+        spans: Arc<Mutex<Vec<(String, std::collections::HashMap<String, String>)>>>,
+        next_id: AtomicUsize,
+    }
+
+    struct FieldCapture(std::collections::HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldCapture {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl tracing::Subscriber for SpanCapture {
+        fn enabled(&self, _metadata: &tracing::Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes) -> tracing::span::Id {
+            let mut fields = FieldCapture(std::collections::HashMap::new());
+            span.record(&mut fields);
+            self.spans.lock().unwrap().push((span.metadata().name().to_string(), fields.0));
+            tracing::span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) as u64 + 1)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event) {}
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn traces_every_request() {
+        let mut client = setup();
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        let capture = SpanCapture {
+            spans: spans.clone(),
+            next_id: AtomicUsize::new(0),
+        };
+
+        tracing::subscriber::with_default(capture, || {
+            let mut request = rpc::RequestWrapper::new();
+            request.set_refId(1);
+            request.set_enqueue(rpc::EnqueueRequest::new());
+            client.handle_request(request);
+
+            let mut request = rpc::RequestWrapper::new();
+            request.set_refId(2);
+            request.set_pop(pop_request(vec![]));
+            client.handle_request(request);
+        });
+
+        let spans = spans.lock().unwrap();
+        let requests: Vec<_> = spans.iter().filter(|(name, _)| name == "request").map(|(_, fields)| fields).collect();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["request"], "enqueue");
+        assert_eq!(requests[0]["ref_id"], "1");
+        assert_eq!(requests[0]["connection"], client.connection_id.to_string());
+        assert_eq!(requests[1]["request"], "pop");
+        assert_eq!(requests[1]["ref_id"], "2");
+
+        // The queue operations are traced too
+        assert!(spans.iter().any(|(name, _)| name == "enqueue"));
+        assert!(spans.iter().any(|(name, _)| name == "pop"));
     }
 }
@@ -0,0 +1,456 @@
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::authentication::DEFAULT_BCRYPT_COST;
+use crate::models::Priority;
+use crate::time_helpers::duration_to_millis;
+
+// How hard the server tries to make sure data has reached the disk
+#[derive(Clone, Debug, PartialEq)]
+pub enum Durability {
+    // Data is handed to the operating system after every write.
+    // Survives the server crashing, but not the machine crashing.
+    Flush,
+    // Files and directory entries are also synced to the disk
+    // after files are created and renamed.
+    // Survives the machine crashing, at the cost of slower garbage collection.
+    Fsync,
+}
+
+impl FromStr for Durability {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Durability, String> {
+        match s {
+            "flush" => Ok(Durability::Flush),
+            "fsync" => Ok(Durability::Fsync),
+            _ => Err("expected 'flush' or 'fsync'".to_string()),
+        }
+    }
+}
+
+// Which task is popped first among tasks with the same priority
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueueOrder {
+    // The task that was enqueued first is popped first
+    Fifo,
+    // The task that was enqueued last is popped first,
+    // for workloads where the newest data is the most important
+    Lifo,
+}
+
+impl FromStr for QueueOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<QueueOrder, String> {
+        match s {
+            "fifo" => Ok(QueueOrder::Fifo),
+            "lifo" => Ok(QueueOrder::Lifo),
+            _ => Err("expected 'fifo' or 'lifo'".to_string()),
+        }
+    }
+}
+
+// What to do with tasks requiring a capability no worker is allowed to have
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnroutablePolicy {
+    // The task is rejected, since it would never be processed
+    Reject,
+    // The task is enqueued anyway, but a warning is logged
+    Warn,
+}
+
+impl FromStr for UnroutablePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<UnroutablePolicy, String> {
+        match s {
+            "reject" => Ok(UnroutablePolicy::Reject),
+            "warn" => Ok(UnroutablePolicy::Warn),
+            _ => Err("expected 'reject' or 'warn'".to_string()),
+        }
+    }
+}
+
+// Raises the priority of tasks the longer they wait, so a steady stream of
+// high priority tasks can't keep low priority tasks waiting forever
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PriorityAging {
+    // The priority of a task is raised by one level for every step it has waited
+    pub step: Duration,
+    // Tasks are never raised above this level
+    pub max_level: u32,
+}
+
+impl PriorityAging {
+    // The level a task enqueued with the given level is treated as, after waiting this long.
+    // Tasks already above the max level keep their level.
+    pub fn effective_level(&self, level: u32, waited: Duration) -> u32 {
+        if level >= self.max_level || self.step == Duration::from_millis(0) {
+            return level;
+        }
+        let steps = waited.as_nanos() / self.step.as_nanos();
+        (level as u128 + steps).min(self.max_level as u128) as u32
+    }
+}
+
+// The user created when the server starts without any users
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefaultUser {
+    pub username: String,
+    pub password: String,
+}
+
+// Runtime configuration of the server.
+// Every value has a sensible default, and can be overridden
+// with an environment variable when starting the server.
+#[derive(Clone, Debug)]
+pub struct Config {
+    // How long to wait after a connection is dropped before the tasks
+    // it was working on are put back into the queue.
+    // If the same worker reconnects within this period it keeps its tasks.
+    pub disconnect_grace_period: Duration,
+    // The capabilities tasks are allowed to require.
+    // If not set any capability is allowed.
+    pub allowed_capabilities: Option<Vec<String>>,
+    // The users allowed to use admin operations.
+    // If not set no user is an admin, so admin operations, like purging
+    // every queue, have to be enabled explicitly.
+    pub admin_users: Option<Vec<String>>,
+    pub durability: Durability,
+    // How long a failed task is kept for the worker that failed it.
+    // The worker often still has state for the task, so retrying it there is cheaper.
+    // If the worker doesn't pop it again within this period any worker can take it.
+    // Disabled if zero.
+    pub failed_affinity_window: Duration,
+    // How often acknowledgements are flushed to disk.
+    // If zero every acknowledgement is flushed right away. Otherwise acknowledgements
+    // are batched, and a few acknowledged tasks might be processed again after a crash.
+    pub acknowledge_flush_interval: Duration,
+    // Roughly how many bytes of queued tasks to keep in memory.
+    // Tasks enqueued past this are only kept on disk, and loaded again when there is room.
+    // Unlimited if zero.
+    pub memory_limit_bytes: usize,
+    // How many new connections per second are accepted from a single address,
+    // after the burst has been used. Connections past that are closed right away.
+    // Disabled if zero.
+    pub connection_rate_per_second: f64,
+    // How many connections a single address can open at once before being limited
+    pub connection_burst: usize,
+    // How many times garbage collection retries renaming or removing a storage file.
+    // Some filesystems fail these now and then, for example if another process has the file open.
+    pub gc_retry_limit: u32,
+    // How often a waiting pop looks through the queue on its own, in case it missed being
+    // woken up when a task was enqueued. Shorter means tasks are picked up faster
+    // in that case, at the cost of more work while the workers are idle.
+    pub wait_tick: Duration,
+    // How many wake ups are kept for waiting pops that are about to start waiting.
+    // Without room a task enqueued right before a pop starts waiting is only found
+    // on the next wait tick, instead of right away.
+    pub wake_buffer: usize,
+    // If set every capability tasks require, and every capability workers has,
+    // must match this pattern. Use ^ and $ to match the entire capability.
+    pub tag_pattern: Option<Regex>,
+    // How often changes to the users are saved.
+    // If zero every change is saved right away, which gets slow when adding many users.
+    pub auth_flush_interval: Duration,
+    // How many changes to the users can pile up before they are saved,
+    // if they are not saved right away
+    pub auth_flush_threshold: usize,
+    // How expensive new passwords are to hash, from 4 to 31. Every step doubles the time
+    // it takes to check a password, both for logins and for anyone guessing passwords.
+    pub bcrypt_cost: u32,
+    // How many tasks can be processing at once across all workers.
+    // Once reached nothing more is popped until some of them are acknowledged,
+    // which protects the server from workers that pop tasks without acknowledging them.
+    // Unlimited if zero.
+    pub max_processing: usize,
+    // How many capabilities of a task are included when listing tasks.
+    // Keeps responses small for tasks requiring many capabilities.
+    // Unlimited if zero.
+    pub max_response_tags: usize,
+    pub queue_order: QueueOrder,
+    // Tasks requiring any of these capabilities are not handed out,
+    // until the capability is resumed by an admin
+    pub paused_capabilities: Vec<String>,
+    // Capabilities workers are never allowed to have. Pops leave them out,
+    // so tasks requiring any of them can never be processed.
+    pub denied_capabilities: Vec<String>,
+    pub unroutable_policy: UnroutablePolicy,
+    // If set the stored tasks are loaded in exactly the order they were enqueued in.
+    // Otherwise tasks enqueued while garbage collection is running can end up
+    // slightly out of order, which saves sorting the tasks when they are loaded.
+    pub preserve_order_after_gc: bool,
+    // Created if the server has no users when it starts, so it can be used right away.
+    // Only created by default in debug builds, since it's easy to guess.
+    pub default_user: Option<DefaultUser>,
+    // If set the server still starts if the users can't be loaded, but rejects every login
+    // until the file has been fixed. Keeps the server up for health checks in the meantime.
+    pub lock_auth_on_load_failure: bool,
+    // If set tasks are rejected when they are enqueued, unless their payload is valid JSON
+    pub require_json_payloads: bool,
+    // How many requests from a single connection are handled at once.
+    // If more than one, replies might be sent in another order than the requests.
+    pub connection_concurrency: usize,
+    // How many pops per second each connection can do, after the burst has been used.
+    // Keeps a single worker popping as fast as it can from starving the other workers.
+    // Disabled if zero.
+    pub pop_rate_per_second: f64,
+    // How many pops a connection can do at once before being limited
+    pub pop_burst: usize,
+    // How many pops can wait for a task at once. Every waiting pop takes up a thread,
+    // so this bounds the threads used by a large number of idle workers.
+    // Pops past this return right away without a task, and the worker has to poll instead.
+    // Unlimited if zero.
+    pub max_waiting_pops: usize,
+    // If set the server starts accepting connections while the stored tasks are being loaded,
+    // and rejects pops until they are. Otherwise connections are only accepted once loaded.
+    pub reject_pops_until_loaded: bool,
+    // Popped tasks that aren't acknowledged or failed within this long are put back
+    // in the queue, so tasks held by a stuck worker are eventually processed.
+    // Workers can ask for another timeout when popping. Disabled if zero.
+    pub visibility_timeout: Duration,
+    // The names of the priority levels tasks can be enqueued with, from level 0 up.
+    // Tasks with a higher level than the last band are rejected.
+    pub priority_bands: Vec<String>,
+    // How many times a task can fail before it's moved to the dead letter queue,
+    // instead of being put back in the queue. Unlimited if zero.
+    pub max_attempts: u32,
+    // Dead letters older than this are deleted for good. Kept until drained if zero.
+    pub dead_letter_retention: Duration,
+    // How many dead letters are kept. Past this the oldest are deleted for good. Unlimited if zero.
+    pub max_dead_letters: usize,
+    // How many named queues can be created. Every queue has its own files and threads,
+    // so this keeps clients from using up the disk and threads by enqueuing to new names.
+    // Unlimited if zero.
+    pub max_named_queues: usize,
+    // How large a payload sent in chunks can be. Unlimited if zero.
+    pub max_transfer_bytes: u64,
+    // Payloads sent in chunks are deleted if nothing is received for this long,
+    // so uploads abandoned by their client don't fill up the disk. Kept until restart if zero.
+    pub transfer_idle_timeout: Duration,
+    // If set tasks are popped by their aged priority instead of the priority they were enqueued with
+    pub priority_aging: Option<PriorityAging>,
+    // How long a shutdown waits for the requests being handled to finish,
+    // before the queues are saved anyway
+    pub shutdown_timeout: Duration,
+    // The address and port the server accepts connections on
+    pub listen_address: String,
+    // The folder the tasks, transfers and named queues are stored in
+    pub storage_folder: String,
+    // Where the users are stored. Kept in the storage folder if not set.
+    pub auth_path: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            disconnect_grace_period: Duration::from_millis(0),
+            allowed_capabilities: None,
+            admin_users: None,
+            durability: Durability::Flush,
+            failed_affinity_window: Duration::from_millis(0),
+            acknowledge_flush_interval: Duration::from_millis(0),
+            memory_limit_bytes: 0,
+            connection_rate_per_second: 0.0,
+            connection_burst: 10,
+            gc_retry_limit: 3,
+            wait_tick: Duration::from_secs(1),
+            tag_pattern: None,
+            auth_flush_interval: Duration::from_millis(0),
+            auth_flush_threshold: 100,
+            bcrypt_cost: DEFAULT_BCRYPT_COST,
+            max_processing: 0,
+            max_response_tags: 0,
+            queue_order: QueueOrder::Fifo,
+            paused_capabilities: Vec::new(),
+            denied_capabilities: Vec::new(),
+            unroutable_policy: UnroutablePolicy::Reject,
+            preserve_order_after_gc: false,
+            default_user: if cfg!(debug_assertions) {
+                Some(DefaultUser {
+                    username: "guest".to_string(),
+                    password: "guest".to_string(),
+                })
+            } else {
+                None
+            },
+            lock_auth_on_load_failure: false,
+            require_json_payloads: false,
+            connection_concurrency: 1,
+            pop_rate_per_second: 0.0,
+            pop_burst: 10,
+            max_waiting_pops: 0,
+            reject_pops_until_loaded: false,
+            visibility_timeout: Duration::from_millis(0),
+            priority_bands: vec!["low".to_string(), "high".to_string()],
+            max_attempts: 0,
+            dead_letter_retention: Duration::from_millis(0),
+            max_dead_letters: 0,
+            max_named_queues: 100,
+            max_transfer_bytes: 256 * 1024 * 1024,
+            transfer_idle_timeout: Duration::from_secs(60 * 60),
+            priority_aging: None,
+            wake_buffer: 8,
+            shutdown_timeout: Duration::from_secs(10),
+            listen_address: "0.0.0.0:6431".to_string(),
+            storage_folder: "./storage".to_string(),
+            auth_path: None,
+        }
+    }
+}
+
+// Reads the given environment variable, falling back to the default
+// if it's not set, or can't be parsed
+fn read_env<T: FromStr>(name: &str, default: T) -> T
+    where T::Err: Display {
+    match env::var(name) {
+        Ok(value) => match value.parse() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Invalid value '{}' for {}: {}", value, name, e);
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+// Reads the given environment variable, if it's set and can be parsed
+fn read_optional_env<T: FromStr>(name: &str) -> Option<T>
+    where T::Err: Display {
+    match env::var(name) {
+        Ok(value) => match value.parse() {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                eprintln!("Invalid value '{}' for {}: {}", value, name, e);
+                None
+            }
+        },
+        Err(_) => None,
+    }
+}
+
+// Reads a comma separated list from the given environment variable
+fn read_list_env(name: &str) -> Option<Vec<String>> {
+    match env::var(name) {
+        Ok(value) => Some(
+            value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+        ),
+        Err(_) => None,
+    }
+}
+
+impl Config {
+    pub fn from_env() -> Config {
+        let default = Config::default();
+
+        Config {
+            disconnect_grace_period: Duration::from_millis(read_env(
+                "BRQUEUE_DISCONNECT_GRACE_PERIOD_MS",
+                duration_to_millis(default.disconnect_grace_period),
+            )),
+            allowed_capabilities: read_list_env("BRQUEUE_ALLOWED_CAPABILITIES")
+                .or(default.allowed_capabilities),
+            admin_users: read_list_env("BRQUEUE_ADMIN_USERS").or(default.admin_users),
+            durability: read_env("BRQUEUE_DURABILITY", default.durability),
+            failed_affinity_window: Duration::from_millis(read_env(
+                "BRQUEUE_FAILED_AFFINITY_WINDOW_MS",
+                duration_to_millis(default.failed_affinity_window),
+            )),
+            acknowledge_flush_interval: Duration::from_millis(read_env(
+                "BRQUEUE_ACKNOWLEDGE_FLUSH_INTERVAL_MS",
+                duration_to_millis(default.acknowledge_flush_interval),
+            )),
+            memory_limit_bytes: read_env("BRQUEUE_MEMORY_LIMIT_BYTES", default.memory_limit_bytes),
+            connection_rate_per_second: read_env("BRQUEUE_CONNECTION_RATE_PER_SECOND", default.connection_rate_per_second),
+            connection_burst: read_env("BRQUEUE_CONNECTION_BURST", default.connection_burst),
+            gc_retry_limit: read_env("BRQUEUE_GC_RETRY_LIMIT", default.gc_retry_limit),
+            wait_tick: Duration::from_millis(read_env(
+                "BRQUEUE_WAIT_TICK_MS",
+                duration_to_millis(default.wait_tick),
+            )),
+            tag_pattern: read_optional_env("BRQUEUE_TAG_PATTERN").or(default.tag_pattern),
+            auth_flush_interval: Duration::from_millis(read_env(
+                "BRQUEUE_AUTH_FLUSH_INTERVAL_MS",
+                duration_to_millis(default.auth_flush_interval),
+            )),
+            auth_flush_threshold: read_env("BRQUEUE_AUTH_FLUSH_THRESHOLD", default.auth_flush_threshold),
+            bcrypt_cost: read_env("BRQUEUE_BCRYPT_COST", default.bcrypt_cost),
+            max_processing: read_env("BRQUEUE_MAX_PROCESSING", default.max_processing),
+            max_response_tags: read_env("BRQUEUE_MAX_RESPONSE_TAGS", default.max_response_tags),
+            queue_order: read_env("BRQUEUE_QUEUE_ORDER", default.queue_order),
+            paused_capabilities: read_list_env("BRQUEUE_PAUSED_CAPABILITIES")
+                .unwrap_or(default.paused_capabilities),
+            denied_capabilities: read_list_env("BRQUEUE_DENIED_CAPABILITIES")
+                .unwrap_or(default.denied_capabilities),
+            unroutable_policy: read_env("BRQUEUE_UNROUTABLE_POLICY", default.unroutable_policy),
+            preserve_order_after_gc: read_env("BRQUEUE_PRESERVE_ORDER_AFTER_GC", default.preserve_order_after_gc),
+            default_user: if read_env("BRQUEUE_CREATE_DEFAULT_USER", default.default_user.is_some()) {
+                Some(DefaultUser {
+                    username: read_env("BRQUEUE_DEFAULT_USERNAME", "guest".to_string()),
+                    password: read_env("BRQUEUE_DEFAULT_PASSWORD", "guest".to_string()),
+                })
+            } else {
+                None
+            },
+            lock_auth_on_load_failure: read_env("BRQUEUE_LOCK_AUTH_ON_LOAD_FAILURE", default.lock_auth_on_load_failure),
+            require_json_payloads: read_env("BRQUEUE_REQUIRE_JSON_PAYLOADS", default.require_json_payloads),
+            connection_concurrency: read_env("BRQUEUE_CONNECTION_CONCURRENCY", default.connection_concurrency),
+            pop_rate_per_second: read_env("BRQUEUE_POP_RATE_PER_SECOND", default.pop_rate_per_second),
+            pop_burst: read_env("BRQUEUE_POP_BURST", default.pop_burst),
+            max_waiting_pops: read_env("BRQUEUE_MAX_WAITING_POPS", default.max_waiting_pops),
+            reject_pops_until_loaded: read_env("BRQUEUE_REJECT_POPS_UNTIL_LOADED", default.reject_pops_until_loaded),
+            visibility_timeout: Duration::from_millis(read_env(
+                "BRQUEUE_VISIBILITY_TIMEOUT_MS",
+                duration_to_millis(default.visibility_timeout),
+            )),
+            priority_bands: read_list_env("BRQUEUE_PRIORITY_BANDS")
+                .filter(|bands| !bands.is_empty())
+                .unwrap_or(default.priority_bands),
+            max_attempts: read_env("BRQUEUE_MAX_ATTEMPTS", default.max_attempts),
+            dead_letter_retention: Duration::from_millis(read_env(
+                "BRQUEUE_DEAD_LETTER_RETENTION_MS",
+                duration_to_millis(default.dead_letter_retention),
+            )),
+            max_dead_letters: read_env("BRQUEUE_MAX_DEAD_LETTERS", default.max_dead_letters),
+            max_named_queues: read_env("BRQUEUE_MAX_NAMED_QUEUES", default.max_named_queues),
+            max_transfer_bytes: read_env("BRQUEUE_MAX_TRANSFER_BYTES", default.max_transfer_bytes),
+            transfer_idle_timeout: Duration::from_millis(read_env(
+                "BRQUEUE_TRANSFER_IDLE_TIMEOUT_MS",
+                duration_to_millis(default.transfer_idle_timeout),
+            )),
+            priority_aging: match read_env("BRQUEUE_PRIORITY_AGING_STEP_MS", 0) {
+                0 => default.priority_aging,
+                step => Some(PriorityAging {
+                    step: Duration::from_millis(step),
+                    max_level: read_env("BRQUEUE_PRIORITY_AGING_MAX_LEVEL", Priority::High.level()),
+                }),
+            },
+            wake_buffer: read_env("BRQUEUE_WAKE_BUFFER", default.wake_buffer),
+            shutdown_timeout: Duration::from_millis(read_env(
+                "BRQUEUE_SHUTDOWN_TIMEOUT_MS",
+                duration_to_millis(default.shutdown_timeout),
+            )),
+            listen_address: read_env("BRQUEUE_LISTEN", default.listen_address),
+            storage_folder: read_env("BRQUEUE_STORAGE_FOLDER", default.storage_folder),
+            auth_path: read_optional_env("BRQUEUE_AUTH_PATH").or(default.auth_path),
+        }
+    }
+
+    // Where the users are stored
+    pub fn auth_path(&self) -> String {
+        match &self.auth_path {
+            Some(path) => path.clone(),
+            None => format!("{}/auth", self.storage_folder),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crossbeam::channel::{bounded, Receiver, Sender, TrySendError};
+use uuid::Uuid;
+
+use crate::hooks::EventHook;
+use crate::models::{Priority, QueueItem};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EventKind {
+    Enqueued,
+    Popped,
+    Acknowledged,
+    Failed,
+    DeadLettered,
+}
+
+// Something that happened to a task, as sent to the event subscribers.
+// The payload is left out, since the subscribers are usually dashboards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueEvent {
+    pub kind: EventKind,
+    pub id: Uuid,
+    // Only known when the whole task is at hand, acknowledgements and failures only know the id
+    pub priority: Option<Priority>,
+    pub required_tags: Vec<String>,
+    pub at: SystemTime,
+    // How many events were dropped before this one, because the subscriber fell behind
+    pub missed: usize,
+}
+
+impl QueueEvent {
+    fn for_item<T: Send + Clone>(kind: EventKind, item: &QueueItem<T>) -> QueueEvent {
+        QueueEvent {
+            kind,
+            id: item.id,
+            priority: Some(item.priority.clone()),
+            required_tags: item.required_tags.to_sorted_vec(),
+            at: SystemTime::now(),
+            missed: 0,
+        }
+    }
+
+    fn for_id(kind: EventKind, id: &Uuid) -> QueueEvent {
+        QueueEvent {
+            kind,
+            id: *id,
+            priority: None,
+            required_tags: Vec::new(),
+            at: SystemTime::now(),
+            missed: 0,
+        }
+    }
+}
+
+struct Subscriber {
+    id: u64,
+    sender: Sender<QueueEvent>,
+    // Events dropped since the last one the subscriber received
+    missed: usize,
+}
+
+struct Registry {
+    next_id: u64,
+    subscribers: Vec<Subscriber>,
+}
+
+// Forwards what happens to the tasks to everyone subscribed, for real time monitoring.
+// Events are never waited on, so a slow subscriber can't hold up the queue.
+// Instead every subscriber has room for a number of events, and events past that are dropped.
+#[derive(Clone)]
+pub struct EventSubscribers {
+    registry: Arc<Mutex<Registry>>,
+}
+
+// Error returned if the registry mutex has been poisoned
+#[derive(Debug)]
+pub struct RegistryCorrupted;
+
+impl EventSubscribers {
+    pub fn new() -> EventSubscribers {
+        EventSubscribers {
+            registry: Arc::new(Mutex::new(Registry {
+                next_id: 0,
+                subscribers: Vec::new(),
+            })),
+        }
+    }
+
+    // Starts sending events to the returned channel, which holds up to buffer events
+    pub fn subscribe(&self, buffer: usize) -> Result<(u64, Receiver<QueueEvent>), RegistryCorrupted> {
+        let mut registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        let (sender, receiver) = bounded(buffer.max(1));
+        let id = registry.next_id;
+        registry.next_id += 1;
+        registry.subscribers.push(Subscriber { id, sender, missed: 0 });
+        Ok((id, receiver))
+    }
+
+    // Stops sending events to the subscriber. The channel is closed once the queued events are read.
+    pub fn unsubscribe(&self, id: u64) -> Result<(), RegistryCorrupted> {
+        let mut registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        registry.subscribers.retain(|subscriber| subscriber.id != id);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        match self.registry.lock() {
+            Ok(registry) => registry.subscribers.len(),
+            Err(_) => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn publish(&self, event: QueueEvent) {
+        let mut registry = match self.registry.lock() {
+            Ok(registry) => registry,
+            Err(_) => return,
+        };
+        // Subscribers that dropped their channel are removed
+        registry.subscribers.retain_mut(|subscriber| {
+            let mut event = event.clone();
+            event.missed = subscriber.missed;
+            match subscriber.sender.try_send(event) {
+                Ok(()) => {
+                    subscriber.missed = 0;
+                    true
+                }
+                Err(TrySendError::Full(_)) => {
+                    subscriber.missed += 1;
+                    true
+                }
+                Err(TrySendError::Disconnected(_)) => false,
+            }
+        });
+    }
+}
+
+impl Default for EventSubscribers {
+    fn default() -> EventSubscribers {
+        EventSubscribers::new()
+    }
+}
+
+impl<T: Send + Clone> EventHook<T> for EventSubscribers {
+    fn on_enqueue(&self, item: &QueueItem<T>) {
+        self.publish(QueueEvent::for_item(EventKind::Enqueued, item));
+    }
+
+    fn on_pop(&self, item: &QueueItem<T>) {
+        self.publish(QueueEvent::for_item(EventKind::Popped, item));
+    }
+
+    fn on_acknowledge(&self, id: &Uuid) {
+        self.publish(QueueEvent::for_id(EventKind::Acknowledged, id));
+    }
+
+    fn on_fail(&self, id: &Uuid) {
+        self.publish(QueueEvent::for_id(EventKind::Failed, id));
+    }
+
+    fn on_dead_letter(&self, item: &QueueItem<T>) {
+        self.publish(QueueEvent::for_item(EventKind::DeadLettered, item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::Tags;
+
+    use super::*;
+
+    #[test]
+    fn drops_events_past_the_buffer_and_counts_them() {
+        let subscribers = EventSubscribers::new();
+        let (_, receiver) = subscribers.subscribe(1).unwrap();
+        let item = QueueItem::new("foo".to_string(), Tags::from(vec!["a".to_string()]), Priority::High);
+
+        subscribers.on_enqueue(&item);
+        EventHook::<String>::on_acknowledge(&subscribers, &item.id);
+        EventHook::<String>::on_fail(&subscribers, &item.id);
+
+        let first = receiver.try_recv().unwrap();
+        assert_eq!(first.kind, EventKind::Enqueued);
+        assert_eq!(first.required_tags, vec!["a".to_string()]);
+        assert!(receiver.try_recv().is_err());
+
+        subscribers.on_pop(&item);
+        let next = receiver.try_recv().unwrap();
+        assert_eq!(next.kind, EventKind::Popped);
+        assert_eq!(next.missed, 2);
+    }
+
+    #[test]
+    fn closed_subscriptions_are_removed() {
+        let subscribers = EventSubscribers::new();
+        let (id, receiver) = subscribers.subscribe(1).unwrap();
+        let (_, other) = subscribers.subscribe(1).unwrap();
+        drop(other);
+
+        EventHook::<String>::on_acknowledge(&subscribers, &Uuid::new_v4());
+        assert_eq!(subscribers.len(), 1);
+
+        subscribers.unsubscribe(id).unwrap();
+        assert!(subscribers.is_empty());
+        assert!(receiver.try_recv().is_ok());
+    }
+}
@@ -16,12 +16,17 @@ pub struct FileItemReader<T: Serialize + DeserializeOwned + Send + Clone, R: Rea
 
 impl<T: Serialize + DeserializeOwned + Send + Clone> FileItemReader<T, File> {
     pub fn new_from_file(path: &Path) -> Result<FileItemReader<T, File>, IOError> {
-        let mut reader = BufReader::new(File::open(path)?);
+        Ok(FileItemReader::new(File::open(path)?))
+    }
+}
 
-        Ok(FileItemReader {
-            reader,
+impl<T: Serialize + DeserializeOwned + Send + Clone, R: Read> FileItemReader<T, R> {
+    // Reads items from the current position of the reader
+    pub fn new(reader: R) -> FileItemReader<T, R> {
+        FileItemReader {
+            reader: BufReader::new(reader),
             _pd: PhantomData,
-        })
+        }
     }
 }
 
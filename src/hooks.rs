@@ -0,0 +1,30 @@
+use uuid::Uuid;
+
+use crate::models::QueueItem;
+
+// Lets custom logic run when something happens to the tasks in the queue,
+// for example for auditing or notifying other systems.
+// Hooks are called on the thread doing the operation, while the operation
+// is being done, so they should return quickly, and hand off anything slow
+// to another thread.
+pub trait EventHook<T: Send + Clone>: Send + Sync {
+    // Called when a task has been saved and put in the queue
+    fn on_enqueue(&self, _item: &QueueItem<T>) {}
+
+    // Called when a task has been handed to a worker
+    fn on_pop(&self, _item: &QueueItem<T>) {}
+
+    // Called when a processing task has been completed
+    fn on_acknowledge(&self, _id: &Uuid) {}
+
+    // Called when a processing task has failed, and is going back in the queue
+    fn on_fail(&self, _id: &Uuid) {}
+
+    // Called when a task has failed too many times, and is moved to the dead letter queue
+    fn on_dead_letter(&self, _item: &QueueItem<T>) {}
+}
+
+// Does nothing, used when no hook is configured
+pub struct NoopHook;
+
+impl<T: Send + Clone> EventHook<T> for NoopHook {}
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crossbeam::channel::{bounded, Receiver, Sender};
+
+use crate::models::{QueueItem, Tags};
+
+struct IdleWorker<T: Send + Clone> {
+    id: u64,
+    capabilities: Tags,
+    // Has room for exactly one item, so handing over an item never blocks
+    sender: Sender<QueueItem<T>>,
+}
+
+struct Registry<T: Send + Clone> {
+    next_id: u64,
+    // In the order the workers started waiting, so the worker
+    // that has waited the longest gets the next item
+    workers: Vec<IdleWorker<T>>,
+}
+
+// Keeps track of the workers waiting in a blocking pop, and what they can handle,
+// so new items can be handed directly to a worker that can handle them,
+// instead of waking up every waiting worker to look through the queue
+#[derive(Clone)]
+pub struct IdleWorkers<T: Send + Clone> {
+    registry: Arc<Mutex<Registry<T>>>,
+}
+
+// Error returned if the registry mutex has been poisoned
+#[derive(Debug)]
+pub struct RegistryCorrupted;
+
+impl<T: Send + Clone> IdleWorkers<T> {
+    pub fn new() -> IdleWorkers<T> {
+        IdleWorkers {
+            registry: Arc::new(Mutex::new(Registry {
+                next_id: 0,
+                workers: Vec::new(),
+            })),
+        }
+    }
+
+    // Registers a worker that is about to wait for an item.
+    // Items handed to the worker are received on the returned channel.
+    pub fn register(&self, capabilities: Tags) -> Result<(u64, Receiver<QueueItem<T>>), RegistryCorrupted> {
+        let mut registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        let (sender, receiver) = bounded(1);
+        let id = registry.next_id;
+        registry.next_id += 1;
+        registry.workers.push(IdleWorker { id, capabilities, sender });
+        Ok((id, receiver))
+    }
+
+    // Removes the worker once it stops waiting.
+    // Returns false if the worker had already been handed an item,
+    // in which case the item is waiting in its channel.
+    pub fn unregister(&self, id: u64) -> Result<bool, RegistryCorrupted> {
+        let mut registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        match registry.workers.iter().position(|worker| worker.id == id) {
+            Some(position) => {
+                registry.workers.remove(position);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // Hands the item to the idle worker that has waited the longest and can handle it.
+    // The worker is no longer idle afterwards.
+    // Items requiring a paused capability are never handed out.
+    // Workers for which has_better_queued returns true are passed over, since they
+    // should take a queued item of the same or a higher priority first.
+    // Returns the item if no worker could take it.
+    pub fn hand_over<F>(&self, item: QueueItem<T>, paused: &HashSet<String>, has_better_queued: F) -> Result<Option<QueueItem<T>>, RegistryCorrupted>
+        where F: Fn(&Tags) -> bool {
+        if item.required_tags.iter().any(|tag| paused.contains(tag)) {
+            return Ok(Some(item));
+        }
+
+        let mut registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        let position = registry
+            .workers
+            .iter()
+            .position(|worker| item.can_be_handled_by(&worker.capabilities) && !has_better_queued(&worker.capabilities));
+        let position = match position {
+            Some(position) => position,
+            None => return Ok(Some(item)),
+        };
+
+        // Sent while holding the lock, so the item is in the channel
+        // before the worker can see that it's no longer registered
+        let worker = registry.workers.remove(position);
+        match worker.sender.try_send(item) {
+            Ok(()) => Ok(None),
+            Err(e) => Ok(Some(e.into_inner())),
+        }
+    }
+
+    // The capabilities of the workers currently waiting for an item
+    pub fn capabilities(&self) -> Result<Vec<Tags>, RegistryCorrupted> {
+        let registry = self.registry.lock().map_err(|_| RegistryCorrupted)?;
+        Ok(registry.workers.iter().map(|worker| worker.capabilities.clone()).collect())
+    }
+}
+
+impl<T: Send + Clone> Default for IdleWorkers<T> {
+    fn default() -> IdleWorkers<T> {
+        IdleWorkers::new()
+    }
+}
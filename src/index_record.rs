@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, ErrorKind as IOErrorKind, Read, Write};
+use std::io::Error as IOError;
+use std::path::Path;
+
+use bincode::{deserialize, Error as BinCodeError, serialize, serialize_into};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::file_item_reader::FileItemReader;
+use crate::models::{Priority, QueueItem};
+use crate::time_helpers::unix_timestamp_millis;
+
+// Index files only refer to tasks, and never contains the actual payload
+// of the task, so they stay small and fast to scan, no matter how big the tasks are.
+// All index files should be read and written through the functions in here,
+// so the readers and writers always agree on the format.
+
+// A compact reference to a task, with the metadata needed to find it again
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ItemRecord {
+    pub id: Uuid,
+    pub priority: Priority,
+    // Milliseconds since the unix epoch when the record was created
+    pub recorded_at: u64,
+}
+
+impl ItemRecord {
+    pub fn from_item<T: Send + Clone>(item: &QueueItem<T>) -> ItemRecord {
+        ItemRecord {
+            id: item.id,
+            priority: item.priority.clone(),
+            recorded_at: unix_timestamp_millis(),
+        }
+    }
+}
+
+// Appends a single record to an index file
+pub fn write_record<W: Write, R: Serialize>(writer: &mut W, record: &R) -> Result<(), BinCodeError> {
+    serialize_into(writer, record)
+}
+
+// Reads all the records in an index file
+pub fn read_records<R>(path: &Path) -> Result<FileItemReader<R, File>, IOError>
+    where R: Serialize + DeserializeOwned + Send + Clone {
+    FileItemReader::new_from_file(path)
+}
+
+// The bincode encoding of a uuid is always a length prefix of 16, followed by the 16 bytes
+// of the uuid. Since every record in the completed index has the same size, a corrupted
+// or partially written record can be skipped, and the reader can resync on the next one.
+const COMPLETED_RECORD_PREFIX: [u8; 8] = [16, 0, 0, 0, 0, 0, 0, 0];
+const COMPLETED_RECORD_SIZE: usize = 24;
+
+// Appends the id of a completed task to the completed index.
+// The record is written in one go, so a concurrent reader never sees half a record.
+pub fn write_completed_id<W: Write>(writer: &mut W, id: &Uuid) -> Result<(), BinCodeError> {
+    let encoded = serialize(id)?;
+    debug_assert_eq!(encoded.len(), COMPLETED_RECORD_SIZE);
+    writer.write_all(&encoded)?;
+    Ok(())
+}
+
+// Reads the ids of all the tasks in the completed index.
+// Any corrupted records are skipped.
+pub fn read_completed_ids(path: &Path) -> Result<HashSet<Uuid>, IOError> {
+    let mut data = Vec::new();
+    BufReader::new(File::open(path)?).read_to_end(&mut data)?;
+
+    let (ids, _) = scan_completed_ids(&data);
+    Ok(ids)
+}
+
+// Removes a partially written record from the end of the completed index, like the one
+// left behind if the server crashed while writing it. Otherwise the next record would be
+// appended right after it, and the two would be hard to tell apart when reading.
+pub fn truncate_torn_completed_tail(path: &Path) -> Result<(), IOError> {
+    let mut data = Vec::new();
+    match File::open(path) {
+        Ok(file) => BufReader::new(file).read_to_end(&mut data)?,
+        Err(ref e) if e.kind() == IOErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    let (_, end) = scan_completed_ids(&data);
+    if end < data.len() {
+        OpenOptions::new().write(true).open(path)?.set_len(end as u64)?;
+    }
+    Ok(())
+}
+
+// Finds all the valid records in the completed index, and where the last one ends
+fn scan_completed_ids(data: &[u8]) -> (HashSet<Uuid>, usize) {
+    let mut ids = HashSet::new();
+    let mut end = 0;
+    let mut position = 0;
+    while position + COMPLETED_RECORD_SIZE <= data.len() {
+        let record = &data[position..position + COMPLETED_RECORD_SIZE];
+        if record.starts_with(&COMPLETED_RECORD_PREFIX) && !record_starts_within(data, position) {
+            if let Ok(id) = deserialize::<Uuid>(record) {
+                ids.insert(id);
+                position += COMPLETED_RECORD_SIZE;
+                end = position;
+                continue;
+            }
+        }
+        // Not a valid record, look for the start of the next one
+        position += 1;
+    }
+
+    (ids, end)
+}
+
+// Checks if another complete record starts inside the record at the given position.
+// That is what a partially written record followed by a complete one looks like,
+// and reading it as a record would skip the start of the complete one.
+fn record_starts_within(data: &[u8], position: usize) -> bool {
+    (1..COMPLETED_RECORD_SIZE)
+        .map(|offset| position + offset)
+        .filter(|start| start + COMPLETED_RECORD_SIZE <= data.len())
+        .any(|start| data[start..].starts_with(&COMPLETED_RECORD_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::metadata;
+    use std::io::BufWriter;
+
+    use crate::models::Tags;
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    fn write_records(path: &str, payload_size: usize, count: usize) -> Vec<ItemRecord> {
+        let mut writer = BufWriter::new(File::create(path).unwrap());
+        let mut records = Vec::new();
+
+        for _ in 0..count {
+            let item = QueueItem::new(vec![0u8; payload_size], Tags::from(vec!["foo"]), Priority::Low);
+            let record = ItemRecord::from_item(&item);
+            write_record(&mut writer, &record).unwrap();
+            records.push(record);
+        }
+
+        writer.flush().unwrap();
+        records
+    }
+
+    #[test]
+    fn can_round_trip_records() {
+        let root = setup_test_storage().unwrap();
+        let path = format!("{}index", root);
+
+        let written = write_records(&path, 10, 100);
+        let read: Vec<ItemRecord> = read_records(Path::new(&path)).unwrap().collect();
+
+        assert_eq!(read, written);
+    }
+
+    #[test]
+    fn size_does_not_depend_on_payload() {
+        let root = setup_test_storage().unwrap();
+        let small_path = format!("{}small", root);
+        let large_path = format!("{}large", root);
+
+        write_records(&small_path, 1, 100);
+        write_records(&large_path, 100000, 100);
+
+        let small_size = metadata(&small_path).unwrap().len();
+        let large_size = metadata(&large_path).unwrap().len();
+
+        assert_eq!(small_size, large_size);
+        assert_eq!(small_size % 100, 0);
+    }
+
+    #[test]
+    fn can_read_completed_ids() {
+        let root = setup_test_storage().unwrap();
+        let path = format!("{}completed", root);
+
+        let ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let mut writer = BufWriter::new(File::create(&path).unwrap());
+        for id in &ids {
+            write_completed_id(&mut writer, id).unwrap();
+        }
+        drop(writer);
+
+        let completed = read_completed_ids(Path::new(&path)).unwrap();
+        assert_eq!(completed.len(), 2);
+        assert!(ids.iter().all(|id| completed.contains(id)));
+    }
+
+    #[test]
+    fn skips_corrupted_completed_records() {
+        let root = setup_test_storage().unwrap();
+        let path = format!("{}completed", root);
+
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let mut writer = BufWriter::new(File::create(&path).unwrap());
+        write_completed_id(&mut writer, &first).unwrap();
+        // Half a record, like a write that was interrupted
+        writer.write_all(&serialize(&Uuid::new_v4()).unwrap()[..10]).unwrap();
+        write_completed_id(&mut writer, &second).unwrap();
+        // And a record that has been partially written at the end
+        writer.write_all(&serialize(&Uuid::new_v4()).unwrap()[..20]).unwrap();
+        drop(writer);
+
+        let completed = read_completed_ids(Path::new(&path)).unwrap();
+        assert_eq!(completed.len(), 2);
+        assert!(completed.contains(&first));
+        assert!(completed.contains(&second));
+    }
+
+    #[test]
+    fn keeps_completed_records_next_to_a_short_partial_record() {
+        let root = setup_test_storage().unwrap();
+        let path = format!("{}completed", root);
+
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let mut writer = BufWriter::new(File::create(&path).unwrap());
+        write_completed_id(&mut writer, &first).unwrap();
+        // Less than the prefix of a record, so it can't be told apart by the prefix alone
+        writer.write_all(&serialize(&Uuid::new_v4()).unwrap()[..3]).unwrap();
+        write_completed_id(&mut writer, &second).unwrap();
+        drop(writer);
+
+        let completed = read_completed_ids(Path::new(&path)).unwrap();
+        assert_eq!(completed.len(), 2);
+        assert!(completed.contains(&first));
+        assert!(completed.contains(&second));
+    }
+
+    #[test]
+    fn truncates_a_torn_completed_tail() {
+        let root = setup_test_storage().unwrap();
+        let path = format!("{}completed", root);
+
+        let first = Uuid::new_v4();
+        let mut writer = BufWriter::new(File::create(&path).unwrap());
+        write_completed_id(&mut writer, &first).unwrap();
+        writer.write_all(&serialize(&Uuid::new_v4()).unwrap()[..3]).unwrap();
+        drop(writer);
+
+        truncate_torn_completed_tail(Path::new(&path)).unwrap();
+        assert_eq!(metadata(&path).unwrap().len(), COMPLETED_RECORD_SIZE as u64);
+
+        let second = Uuid::new_v4();
+        let mut writer = OpenOptions::new().append(true).open(&path).unwrap();
+        write_completed_id(&mut writer, &second).unwrap();
+        drop(writer);
+
+        let completed = read_completed_ids(Path::new(&path)).unwrap();
+        assert_eq!(completed.len(), 2);
+        assert!(completed.contains(&first));
+        assert!(completed.contains(&second));
+    }
+}
@@ -1,16 +1,19 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::convert;
 use std::fmt;
-use std::fs::{create_dir_all, File, OpenOptions, remove_file, rename};
+use std::fs::{copy, create_dir_all, File, metadata, OpenOptions, remove_file, rename};
 use std::io::{BufReader, BufWriter};
-use std::io::{Read, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
 use std::io::Error as IOError;
+use std::io::ErrorKind as IOErrorKind;
 use std::marker::PhantomData;
 use std::mem;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, TryLockError};
 use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
 
 use bincode::{deserialize, deserialize_from, Error as BinCodeError, serialize, serialize_into};
 use serde::de::DeserializeOwned;
@@ -19,7 +22,9 @@ use serde_derive::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::binary::get_size_array;
+use crate::config::Durability;
 use crate::file_item_reader::FileItemReader;
+use crate::index_record::{read_completed_ids, truncate_torn_completed_tail, write_completed_id};
 use crate::models::{Priority, QueueItem, Tags};
 
 #[derive(Debug)]
@@ -27,7 +32,12 @@ pub enum Error {
     IOError(IOError),
     FailedToSerializeWorkItem(BinCodeError),
     MutexCorrupted,
-    GarbageCollectionFailed
+    GarbageCollectionFailed,
+    ReadOnly,
+    // The storage was written by a newer version of the server
+    UnsupportedFormatVersion(u8),
+    // Files can't be created in the storage folder
+    NotWritable(PathBuf, IOError),
 }
 
 impl convert::From<IOError> for Error {
@@ -52,7 +62,15 @@ impl fmt::Display for Error {
             Error::FailedToSerializeWorkItem(e) => {
                 write!(f, "Failed to serialize work item: {}", e)
             },
-            Error::GarbageCollectionFailed => write!(f, "Garbage collection failed")
+            Error::GarbageCollectionFailed => write!(f, "Garbage collection failed"),
+            Error::ReadOnly => write!(f, "Storage is opened read-only"),
+            Error::UnsupportedFormatVersion(version) => {
+                write!(f, "Storage format version {} is not supported, the newest supported is {}", version, ITEM_FILE_VERSION)
+            }
+            Error::NotWritable(path, e) => {
+                write!(f, "Storage folder {} is not writable: {}. Make sure the server is allowed to write to it, \
+                    and that it's not on a read-only mount", path.display(), e)
+            }
         }
     }
 }
@@ -70,9 +88,87 @@ struct FileReferences {
 pub struct InternalQueueFileManager<T> where T: Send + Clone + Serialize + DeserializeOwned {
     file_prefix: PathBuf,
     open_files: Arc<RwLock<FileReferences>>,
-    _pd: PhantomData<T>,
+    // No items are kept in the manager, so it can be shared between threads even if T can't
+    _pd: PhantomData<fn() -> T>,
     gc_lock: Arc<Mutex<()>>,
+    // The file sizes when the running garbage collection started,
+    // reported while the files are being swapped
+    sizes_before_gc: Arc<Mutex<Option<StorageFileSizes>>>,
     require_flush: bool,
+    durability: Durability,
+    // If set acknowledgements are not flushed right away,
+    // and flush_completed has to be called regularly instead
+    batch_acknowledgements: bool,
+    // If set the files are only opened for reading, and nothing can be written
+    read_only: bool,
+    // How many times garbage collection retries a failed rename or remove before giving up
+    gc_retry_limit: u32,
+    // If set the loaded items are sorted by their sequence number, so they are in the order
+    // they were enqueued in, even if garbage collection moved some of them around
+    preserve_order: bool,
+    // Set if the storage was opened read-only before the names of the item files were fixed,
+    // in which case high priority items are read from the low priority file and the other way around
+    swapped_file_names: bool,
+    file_operations: FileOperations,
+}
+
+// The filesystem operations used to save items, and to swap the storage files during
+// garbage collection. Only replaced in tests, to simulate misbehaving filesystems.
+#[derive(Clone, Copy)]
+struct FileOperations {
+    rename: fn(&Path, &Path) -> Result<(), IOError>,
+    remove: fn(&Path) -> Result<(), IOError>,
+    write_item: fn(&mut BufWriter<File>, &[u8], bool) -> Result<(), IOError>,
+}
+
+fn rename_file(from: &Path, to: &Path) -> Result<(), IOError> {
+    rename(from, to)
+}
+
+fn remove_path(path: &Path) -> Result<(), IOError> {
+    remove_file(path)
+}
+
+fn write_item(file: &mut BufWriter<File>, encoded: &[u8], flush: bool) -> Result<(), IOError> {
+    file.write_all(encoded)?;
+    if flush {
+        file.flush()?;
+    }
+    Ok(())
+}
+
+impl Default for FileOperations {
+    fn default() -> FileOperations {
+        FileOperations {
+            rename: rename_file,
+            remove: remove_path,
+            write_item,
+        }
+    }
+}
+
+// The os error codes for running out of disk space
+#[cfg(unix)]
+const OUT_OF_SPACE_ERRORS: &[i32] = &[28]; // ENOSPC
+#[cfg(not(unix))]
+const OUT_OF_SPACE_ERRORS: &[i32] = &[39, 112]; // ERROR_HANDLE_DISK_FULL, ERROR_DISK_FULL
+
+// Checks if the error is caused by the disk being full
+pub fn is_storage_full(e: &IOError) -> bool {
+    e.kind() == IOErrorKind::WriteZero
+        || e.raw_os_error().map(|code| OUT_OF_SPACE_ERRORS.contains(&code)).unwrap_or(false)
+}
+
+// How long to wait before retrying a failed filesystem operation.
+// Multiplied by the number of attempts so far.
+const GC_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+// The size in bytes of each of the storage files
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StorageFileSizes {
+    pub high_priority: u64,
+    pub low_priority: u64,
+    pub completed: u64,
 }
 
 pub struct StoredItems<T: Send + Clone> {
@@ -81,15 +177,331 @@ pub struct StoredItems<T: Send + Clone> {
 }
 
 const COMPLETED_EXTENSION: &'static str = "_completed.dat";
-const HIGH_PRIORITY_EXTENSION: &'static str = "_low_priority.dat";
-const LOW_PRIORITY_EXTENSION: &'static str = "_high_priority.dat";
+const HIGH_PRIORITY_EXTENSION: &'static str = "_high_priority.dat";
+const LOW_PRIORITY_EXTENSION: &'static str = "_low_priority.dat";
+// Tasks that failed too many times, kept until they are drained
+const DEAD_LETTER_EXTENSION: &'static str = "_dead_letter.dat";
+// Used while swapping the names of item files written in the original format
+const SWAP_EXTENSION: &'static str = ".swap";
+
+// Item files start with this, followed by a byte with the version of the format.
+// Files without it are in the original format, which is version 0.
+// The original format starts with the length of the payload, which is never this big.
+const ITEM_FILE_MAGIC: &[u8; 7] = b"BRQUEUE";
+// The magic bytes followed by the version
+const ITEM_FILE_HEADER_LENGTH: u64 = ITEM_FILE_MAGIC.len() as u64 + 1;
+
+// The version of the format item files are written in.
+// Files in the original format are migrated to this when the storage is opened.
+// Versions:
+// 0: The original format without a header. High priority items were saved in _low_priority.dat
+//    and the other way around, see migrate_item_files.
+// 1: Items are saved as QueueItem, and the files are named after the priority of their items
+const ITEM_FILE_VERSION: u8 = 1;
+
+// An item as it was stored in the original format
+#[derive(Clone, Serialize, Deserialize)]
+struct LegacyQueueItem<T: Send + Clone> {
+    data: T,
+    required_tags: Tags,
+    id: Uuid,
+    priority: Priority,
+}
+
+impl<T: Send + Clone> convert::From<LegacyQueueItem<T>> for QueueItem<T> {
+    fn from(item: LegacyQueueItem<T>) -> QueueItem<T> {
+        QueueItem {
+            data: item.data,
+            required_tags: item.required_tags,
+            id: item.id,
+            priority: item.priority,
+            enqueued_by: None,
+            available_at: None,
+            sequence: 0,
+            expires_at: None,
+            attempts: 0,
+            enqueued_at: None,
+            idempotency_key: None,
+            dead_lettered_at: None,
+        }
+    }
+}
+
+// Reads the items of an item file, no matter which format it's in
+enum ItemFileReader<T: Send + Clone + Serialize + DeserializeOwned> {
+    Current(FileItemReader<QueueItem<T>, File>),
+    Legacy(FileItemReader<LegacyQueueItem<T>, File>),
+}
+
+impl<T: Send + Clone + Serialize + DeserializeOwned> Iterator for ItemFileReader<T> {
+    type Item = QueueItem<T>;
+
+    fn next(&mut self) -> Option<QueueItem<T>> {
+        match self {
+            ItemFileReader::Current(reader) => reader.next(),
+            ItemFileReader::Legacy(reader) => reader.next().map(QueueItem::from),
+        }
+    }
+}
+
+fn write_item_file_header<W: Write>(writer: &mut W) -> Result<(), Error> {
+    writer.write_all(ITEM_FILE_MAGIC)?;
+    writer.write_all(&[ITEM_FILE_VERSION])?;
+    Ok(())
+}
+
+// Reads the format version of an item file, leaving the file positioned at the first item
+fn read_item_file_version(file: &mut File) -> Result<u8, Error> {
+    let mut header = [0u8; 8];
+    match file.read_exact(&mut header) {
+        Ok(()) if header.starts_with(ITEM_FILE_MAGIC) => return Ok(header[7]),
+        Ok(()) => {}
+        Err(ref e) if e.kind() == IOErrorKind::UnexpectedEof => {}
+        Err(e) => return Err(Error::IOError(e)),
+    };
+
+    file.seek(SeekFrom::Start(0))?;
+    Ok(0)
+}
+
+// Reads the items of an item file in any of the supported format versions
+fn read_items<T>(path: &Path) -> Result<ItemFileReader<T>, Error>
+    where T: Send + Clone + Serialize + DeserializeOwned {
+    let mut file = File::open(path)?;
+
+    match read_item_file_version(&mut file)? {
+        0 => Ok(ItemFileReader::Legacy(FileItemReader::new(file))),
+        ITEM_FILE_VERSION => Ok(ItemFileReader::Current(FileItemReader::new(file))),
+        version => Err(Error::UnsupportedFormatVersion(version)),
+    }
+}
+
+// Fails if the item file was written by a newer version of the server,
+// so nothing is appended to a file that can't be read
+fn ensure_supported_version(path: &Path) -> Result<(), Error> {
+    if file_size(path)? == 0 {
+        return Ok(());
+    }
+    match read_item_file_version(&mut File::open(path)?)? {
+        version if version > ITEM_FILE_VERSION => Err(Error::UnsupportedFormatVersion(version)),
+        _ => Ok(()),
+    }
+}
+
+// True if the item file is in the original format, when the names of the item files were swapped
+fn has_swapped_name(path: &Path) -> Result<bool, Error> {
+    if file_size(path)? == 0 {
+        return Ok(false);
+    }
+    Ok(read_item_file_version(&mut File::open(path)?)? == 0)
+}
+
+fn has_current_version(path: &Path) -> Result<bool, Error> {
+    if file_size(path)? == 0 {
+        return Ok(false);
+    }
+    Ok(read_item_file_version(&mut File::open(path)?)? == ITEM_FILE_VERSION)
+}
+
+// True if the storage is in the original format, and the item files
+// still have the name of the other priority
+fn uses_swapped_file_names(prefix: &Path) -> Result<bool, Error> {
+    Ok(has_swapped_name(&get_file_path(prefix, HIGH_PRIORITY_EXTENSION))?
+        || has_swapped_name(&get_file_path(prefix, LOW_PRIORITY_EXTENSION))?)
+}
+
+// Migrates item files in the original format to the current format, and gives them the name
+// of the priority of their items. The content of each file is first written to a copy in the
+// current format under the name of the other file, and the copies are then renamed into place.
+// The originals are kept next to them, with the version they had as extension.
+// If this is interrupted before any copy has been renamed, the copies are started over,
+// otherwise the renames are finished.
+fn migrate_item_files<T>(prefix: &Path) -> Result<(), Error>
+    where T: Send + Clone + Serialize + DeserializeOwned {
+    for extension in &[HIGH_PRIORITY_EXTENSION, LOW_PRIORITY_EXTENSION, DEAD_LETTER_EXTENSION] {
+        ensure_supported_version(&get_file_path(prefix, extension))?;
+    }
+
+    let high_priority_file = get_file_path(prefix, HIGH_PRIORITY_EXTENSION);
+    let low_priority_file = get_file_path(prefix, LOW_PRIORITY_EXTENSION);
+    let high_priority_copy = get_file_path(&high_priority_file, SWAP_EXTENSION);
+    let low_priority_copy = get_file_path(&low_priority_file, SWAP_EXTENSION);
+
+    let copies_complete = (high_priority_copy.exists() || low_priority_copy.exists())
+        && (has_current_version(&high_priority_file)? || has_current_version(&low_priority_file)?);
+    if !copies_complete {
+        if !uses_swapped_file_names(prefix)? {
+            return Ok(());
+        }
+
+        for (from, to) in &[(&low_priority_file, &high_priority_copy), (&high_priority_file, &low_priority_copy)] {
+            let mut target = BufWriter::new(create_item_file(to)?);
+            if file_size(from)? > 0 {
+                for item in read_items::<T>(from)? {
+                    serialize_into(&mut target, &item)?;
+                }
+                let version = read_item_file_version(&mut File::open(from)?)?;
+                copy(from, get_file_path(from, &format!(".v{}", version)))?;
+            }
+            target.flush()?;
+            target.get_ref().sync_all()?;
+        }
+    }
+
+    for (copy, file) in &[(&high_priority_copy, &high_priority_file), (&low_priority_copy, &low_priority_file)] {
+        if copy.exists() {
+            // Not every platform allows renaming on top of an existing file
+            if file.exists() {
+                remove_file(file)?;
+            }
+            rename(copy, file)?;
+        }
+    }
+    Ok(())
+}
+
+// Puts the storage files back in order if garbage collection failed, or the server
+// stopped while it was running. A failed garbage collection can leave behind:
+// - Backups of the original item files, which are complete, while the
+//   garbage collected files might only be partially written.
+// - The files items were written to while garbage collection was running.
+fn recover_interrupted_gc<T>(prefix: &Path) -> Result<(), Error>
+    where T: Send + Clone + Serialize + DeserializeOwned {
+    for extension in &[HIGH_PRIORITY_EXTENSION, LOW_PRIORITY_EXTENSION] {
+        let file = get_file_path(prefix, extension);
+        let backup = get_file_path(prefix, &format!("{}.bak", extension));
+        if backup.exists() {
+            // Not every platform allows renaming on top of an existing file
+            if file.exists() {
+                remove_file(&file)?;
+            }
+            rename(&backup, &file)?;
+        }
+    }
+
+    let gc_prefix = Path::new(&format!("{}_gc", prefix.to_string_lossy())).to_path_buf();
+    for extension in &[HIGH_PRIORITY_EXTENSION, LOW_PRIORITY_EXTENSION] {
+        let gc_file = get_file_path(&gc_prefix, extension);
+        if gc_file.exists() {
+            // Items can be appended more than once if this is interrupted,
+            // but only the latest version of each item is loaded anyway
+            let mut target = BufWriter::new(open_item_file(&get_file_path(prefix, extension))?);
+            for item in read_items::<T>(&gc_file)? {
+                serialize_into(&mut target, &item)?;
+            }
+            target.flush()?;
+            drop(target);
+            remove_file(&gc_file)?;
+        }
+    }
+
+    let completed_gc_file = get_file_path(&gc_prefix, COMPLETED_EXTENSION);
+    if completed_gc_file.exists() {
+        let completed_file = get_file_path(prefix, COMPLETED_EXTENSION);
+        truncate_torn_completed_tail(&completed_file)?;
+        let mut target = BufWriter::new(OpenOptions::new().append(true).create(true).open(completed_file)?);
+        for id in read_completed_ids(&completed_gc_file)? {
+            write_completed_id(&mut target, &id)?;
+        }
+        target.flush()?;
+        drop(target);
+        remove_file(&completed_gc_file)?;
+    }
+
+    Ok(())
+}
+
+// Opens an item file for appending, writing the header if the file is new
+fn open_item_file(path: &Path) -> Result<File, Error> {
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        write_item_file_header(&mut file)?;
+    }
+    Ok(file)
+}
+
+// Creates an empty item file, replacing any existing file
+fn create_item_file(path: &Path) -> Result<File, Error> {
+    let mut file = File::create(path)?;
+    write_item_file_header(&mut file)?;
+    Ok(file)
+}
+
+// Makes sure files can be created in the storage folder, so the server fails with
+// a clear error when it starts, instead of when the first task is saved
+fn probe_writable(prefix: &Path) -> Result<(), Error> {
+    let folder = prefix.parent().expect("No parent for path").to_path_buf();
+    if let Err(e) = create_dir_all(&folder) {
+        return Err(Error::NotWritable(folder, e));
+    }
+
+    let probe = get_file_path(prefix, "_write_probe");
+    match File::create(&probe).and_then(|_| remove_file(&probe)) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(Error::NotWritable(folder, e)),
+    }
+}
 
 fn get_file_path(base: &Path, extension: &str) -> PathBuf {
     Path::new(&format!("{}{}", base.to_string_lossy(), extension)).to_path_buf()
 }
 
+// Gets the size of the file, a file that doesn't exist is empty
+fn file_size(path: &Path) -> Result<u64, Error> {
+    match metadata(path) {
+        Ok(metadata) => Ok(metadata.len()),
+        Err(ref e) if e.kind() == IOErrorKind::NotFound => Ok(0),
+        Err(e) => Err(Error::IOError(e)),
+    }
+}
+
 fn open_for_append(filename: &PathBuf) -> Result<FileReferences, Error> {
     let options = OpenOptions::new().append(true).create(true).clone();
+    let high_prio_file = open_item_file(&get_file_path(filename, HIGH_PRIORITY_EXTENSION))?;
+    let low_prio_file = open_item_file(&get_file_path(filename, LOW_PRIORITY_EXTENSION))?;
+    let completed_path = get_file_path(filename, COMPLETED_EXTENSION);
+    truncate_torn_completed_tail(&completed_path)?;
+    let completed_file = options.open(completed_path)?;
+
+    Ok(FileReferences {
+        high_priority_file: Arc::new(Mutex::new(BufWriter::new(high_prio_file))),
+        low_priority_file: Arc::new(Mutex::new(BufWriter::new(low_prio_file))),
+        completed_file_index_file: Arc::new(Mutex::new(BufWriter::new(completed_file))),
+    })
+}
+
+// Syncs the directory entries of the given directory to disk.
+// Creating, renaming and removing a file only changes the directory, so without
+// this the change might be lost if the machine crashes, even if the file content was synced.
+#[cfg(unix)]
+fn sync_directory(path: &Path) -> Result<(), Error> {
+    File::open(path)?.sync_all()?;
+    Ok(())
+}
+
+// Directories can't be opened as files on other platforms,
+// and their directory entries are durable once the file handles are synced.
+#[cfg(not(unix))]
+fn sync_directory(_path: &Path) -> Result<(), Error> {
+    Ok(())
+}
+
+fn flush_completed(open_files: &RwLock<FileReferences>) -> Result<(), Error> {
+    if let Ok(references) = open_files.read() {
+        if let Ok(mut completed) = references.completed_file_index_file.lock() {
+            completed.flush()?;
+            Ok(())
+        } else {
+            Err(Error::MutexCorrupted)
+        }
+    } else {
+        Err(Error::MutexCorrupted)
+    }
+}
+
+// Opens the storage files without being able to write to them
+fn open_for_reading(filename: &PathBuf) -> Result<FileReferences, Error> {
+    let options = OpenOptions::new().read(true).clone();
     let high_prio_file = options.open(get_file_path(filename, HIGH_PRIORITY_EXTENSION))?;
     let low_prio_file = options.open(get_file_path(filename, LOW_PRIORITY_EXTENSION))?;
     let completed_file = options.open(get_file_path(filename, COMPLETED_EXTENSION))?;
@@ -101,46 +513,352 @@ fn open_for_append(filename: &PathBuf) -> Result<FileReferences, Error> {
     })
 }
 
+// Items are saved again when they are changed, so only the
+// latest version of each item should be kept when loading
+fn latest_versions<T: Send + Clone>(items: Vec<QueueItem<T>>) -> Vec<QueueItem<T>> {
+    let mut last_position = HashMap::new();
+    for (position, item) in items.iter().enumerate() {
+        last_position.insert(item.id, position);
+    }
+
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(position, item)| last_position.get(&item.id) == Some(position))
+        .map(|(_, item)| item)
+        .collect()
+}
+
 impl<T> InternalQueueFileManager<T> where T: Send + Clone + Serialize + DeserializeOwned {
     pub fn new(filename_prefix: String, require_flush: bool) -> Result<InternalQueueFileManager<T>, Error> {
+        InternalQueueFileManager::new_with_durability(filename_prefix, require_flush, Durability::Flush)
+    }
+
+    pub fn new_with_durability(filename_prefix: String, require_flush: bool, durability: Durability) -> Result<InternalQueueFileManager<T>, Error> {
         let p = Path::new(&filename_prefix.clone()).to_owned();
-        let parent_folder = p.parent().expect("No parent for path");
-        create_dir_all(parent_folder)?;
+        probe_writable(&p)?;
+
+        recover_interrupted_gc::<T>(&p)?;
+        migrate_item_files::<T>(&p)?;
 
         let file_references = open_for_append(&p)?;
 
+        let manager = InternalQueueFileManager {
+            file_prefix: p,
+            open_files: Arc::new(RwLock::new(file_references)),
+            _pd: PhantomData,
+            gc_lock: Arc::new(Mutex::new(())),
+            sizes_before_gc: Arc::new(Mutex::new(None)),
+            require_flush,
+            durability,
+            batch_acknowledgements: false,
+            read_only: false,
+            gc_retry_limit: 0,
+            preserve_order: false,
+            swapped_file_names: false,
+            file_operations: FileOperations::default(),
+        };
+        // The storage files might just have been created
+        manager.sync_storage_directory()?;
+
+        Ok(manager)
+    }
+
+    // Opens existing storage, without ever writing to it.
+    // Useful for inspecting the storage of a running server.
+    pub fn open_read_only(filename_prefix: String) -> Result<InternalQueueFileManager<T>, Error> {
+        let p = Path::new(&filename_prefix).to_owned();
+        let file_references = open_for_reading(&p)?;
+        let swapped_file_names = uses_swapped_file_names(&p)?;
+
         Ok(InternalQueueFileManager {
             file_prefix: p,
             open_files: Arc::new(RwLock::new(file_references)),
             _pd: PhantomData,
             gc_lock: Arc::new(Mutex::new(())),
-            require_flush
+            sizes_before_gc: Arc::new(Mutex::new(None)),
+            require_flush: false,
+            durability: Durability::Flush,
+            batch_acknowledgements: false,
+            read_only: true,
+            gc_retry_limit: 0,
+            preserve_order: false,
+            swapped_file_names,
+            file_operations: FileOperations::default(),
         })
     }
 
+    fn ensure_writable(&self) -> Result<(), Error> {
+        if self.read_only {
+            Err(Error::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Stops flushing acknowledgements right away, and flushes them on the given interval instead.
+    // The flushing stops once the manager is dropped.
+    pub fn with_batched_acknowledgements(mut self, flush_interval: Duration) -> InternalQueueFileManager<T> {
+        self.batch_acknowledgements = true;
+
+        let open_files = Arc::downgrade(&self.open_files);
+        thread::spawn(move || {
+            loop {
+                thread::sleep(flush_interval);
+
+                let open_files = match open_files.upgrade() {
+                    Some(open_files) => open_files,
+                    None => return,
+                };
+
+                if let Err(e) = flush_completed(&open_files) {
+                    eprintln!("Failed to flush acknowledgements: {}", e);
+                }
+            }
+        });
+
+        self
+    }
+
+    // Retries renames and removes during garbage collection up to the given number of times,
+    // since they can fail temporarily on some filesystems, such as Windows while another
+    // process has the file open, or network filesystems.
+    pub fn with_gc_retry_limit(mut self, gc_retry_limit: u32) -> InternalQueueFileManager<T> {
+        self.gc_retry_limit = gc_retry_limit;
+        self
+    }
+
+    // Sorts loaded items by the order they were enqueued in.
+    // Items saved while garbage collection is running are otherwise loaded after items
+    // saved right after it, which matters if the exact order is important.
+    pub fn with_preserved_order(mut self) -> InternalQueueFileManager<T> {
+        self.preserve_order = true;
+        self
+    }
+
+    // Runs a filesystem operation, retrying it with a short backoff if it fails
+    fn retry_file_operation<F>(&self, mut operation: F) -> Result<(), Error>
+        where F: FnMut() -> Result<(), IOError> {
+        let mut attempts = 0;
+        loop {
+            match operation() {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    // Retrying won't make a missing file appear
+                    if attempts >= self.gc_retry_limit || e.kind() == IOErrorKind::NotFound {
+                        return Err(Error::IOError(e));
+                    }
+                    attempts += 1;
+                    eprintln!("Filesystem operation failed during garbage collection, retrying: {}", e);
+                    thread::sleep(GC_RETRY_BACKOFF * attempts);
+                }
+            }
+        }
+    }
+
+    // Makes every save fail, like they do when the disk is full
+    #[cfg(test)]
+    pub fn simulate_full_disk(&mut self) {
+        fn full_disk(_file: &mut BufWriter<File>, _encoded: &[u8], _flush: bool) -> Result<(), IOError> {
+            Err(IOError::from_raw_os_error(OUT_OF_SPACE_ERRORS[0]))
+        }
+        self.file_operations.write_item = full_disk;
+    }
+
+    fn rename_with_retries(&self, from: &Path, to: &Path) -> Result<(), Error> {
+        let rename = self.file_operations.rename;
+        self.retry_file_operation(|| rename(from, to))
+    }
+
+    fn remove_with_retries(&self, path: &Path) -> Result<(), Error> {
+        let remove = self.file_operations.remove;
+        self.retry_file_operation(|| remove(path))
+    }
+
     fn get_file_path(&self, extension: &str) -> PathBuf {
         get_file_path(&self.file_prefix, extension)
     }
 
+    // The file the items of the priority are read from
+    fn item_file_path(&self, priority: &Priority) -> PathBuf {
+        match (priority, self.swapped_file_names) {
+            (Priority::High, false) | (Priority::Level(_), false) | (Priority::Low, true) => self.get_file_path(HIGH_PRIORITY_EXTENSION),
+            (Priority::Low, false) | (Priority::High, true) | (Priority::Level(_), true) => self.get_file_path(LOW_PRIORITY_EXTENSION),
+        }
+    }
+
+    // Makes files created, renamed or removed in the storage directory durable,
+    // if the durability policy requires it
+    fn sync_storage_directory(&self) -> Result<(), Error> {
+        if self.durability != Durability::Fsync {
+            return Ok(());
+        }
+
+        match self.file_prefix.parent() {
+            // A relative prefix without a folder lives in the current directory
+            Some(parent) if parent.as_os_str().is_empty() => sync_directory(Path::new(".")),
+            Some(parent) => sync_directory(parent),
+            None => Ok(()),
+        }
+    }
+
+    // Makes the content of a newly written file durable,
+    // if the durability policy requires it
+    fn sync_file(&self, file: &File) -> Result<(), Error> {
+        if self.durability == Durability::Fsync {
+            file.sync_all()?;
+        }
+        Ok(())
+    }
+
+    // Also used to save changes to items that are already stored. The changed item
+    // is appended as a new version, and the older versions are skipped when loading.
     pub fn save_item(&self, item: &QueueItem<T>) -> Result<(), Error> {
-        if let Ok(mut references) = self.open_files.read() {
-            let mut file_ref = match item.priority {
+        self.save_item_to(item, &item.priority)
+    }
+
+    // Saves a new version of an item that has been changed.
+    // If the priority of the item has changed, the new version is also written to
+    // the file of the previous priority, so the old version is shadowed when loading.
+    pub fn save_changed_item(&self, item: &QueueItem<T>, previous_priority: &Priority) -> Result<(), Error> {
+        self.save_item(item)?;
+        if *previous_priority != item.priority {
+            self.save_item_to(item, previous_priority)?;
+        }
+        Ok(())
+    }
+
+    // Appends the item to the file of the given priority
+    fn save_item_to(&self, item: &QueueItem<T>, priority: &Priority) -> Result<(), Error> {
+        self.ensure_writable()?;
+
+        let encoded = match serialize(item) {
+            Err(e) => return Err(Error::FailedToSerializeWorkItem(e)),
+            Ok(encoded) => encoded,
+        };
+        self.write_encoded(&encoded, priority)
+    }
+
+    // Appends an item that failed too many times to the dead letter file.
+    // Rarely used, so the file is only opened while writing to it.
+    pub fn save_dead_letter(&self, item: &QueueItem<T>) -> Result<(), Error> {
+        self.ensure_writable()?;
+
+        let mut file = open_item_file(&self.get_file_path(DEAD_LETTER_EXTENSION))?;
+        serialize_into(&mut file, item)?;
+        self.sync_file(&file)
+    }
+
+    // Reads the items in the dead letter file, in the order they were saved
+    pub fn load_dead_letters(&self) -> Result<Vec<QueueItem<T>>, Error> {
+        let path = self.get_file_path(DEAD_LETTER_EXTENSION);
+        if file_size(&path)? == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(read_items(&path)?.collect())
+    }
+
+    // Removes every item from the dead letter file
+    pub fn clear_dead_letters(&self) -> Result<(), Error> {
+        self.replace_dead_letters(&[])
+    }
+
+    // Replaces the items in the dead letter file, for example to remove the oldest
+    pub fn replace_dead_letters(&self, items: &[QueueItem<T>]) -> Result<(), Error> {
+        self.ensure_writable()?;
+
+        let mut file = create_item_file(&self.get_file_path(DEAD_LETTER_EXTENSION))?;
+        for item in items {
+            serialize_into(&mut file, item)?;
+        }
+        self.sync_file(&file)
+    }
+
+    // Removes every saved item and acknowledgement, leaving empty storage files behind.
+    // The dead letters are kept.
+    pub fn truncate_all(&self) -> Result<(), Error> {
+        self.ensure_writable()?;
+
+        // Garbage collection would otherwise copy the removed items back
+        let _gc_guard = match self.gc_lock.lock() {
+            Ok(guard) => guard,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        let mut references = match self.open_files.write() {
+            Ok(references) => references,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+
+        // Anything still buffered would otherwise be written after the files are truncated
+        for file in &[&references.high_priority_file, &references.low_priority_file, &references.completed_file_index_file] {
+            match file.lock() {
+                Ok(mut file) => file.flush()?,
+                Err(_) => return Err(Error::MutexCorrupted),
+            }
+        }
+
+        let options = OpenOptions::new().write(true).truncate(true).create(true).clone();
+        for extension in &[HIGH_PRIORITY_EXTENSION, LOW_PRIORITY_EXTENSION] {
+            let mut file = options.open(self.get_file_path(extension))?;
+            write_item_file_header(&mut file)?;
+            self.sync_file(&file)?;
+        }
+        let completed = options.open(self.get_file_path(COMPLETED_EXTENSION))?;
+        self.sync_file(&completed)?;
+
+        *references = open_for_append(&self.file_prefix)?;
+        Ok(())
+    }
+
+    // Saves either all of the items, or none of them.
+    // Every item is serialized before anything is written, so an item that can't be
+    // serialized stops the batch. If writing fails part way through, the items are
+    // marked as completed, so the ones that were written are never loaded.
+    pub fn save_items(&self, items: &[QueueItem<T>]) -> Result<(), Error> {
+        self.ensure_writable()?;
+
+        let mut high_priority = Vec::new();
+        let mut low_priority = Vec::new();
+        for item in items {
+            let encoded = match serialize(item) {
+                Err(e) => return Err(Error::FailedToSerializeWorkItem(e)),
+                Ok(encoded) => encoded,
+            };
+            match item.priority {
+                Priority::High | Priority::Level(_) => high_priority.extend(encoded),
+                Priority::Low => low_priority.extend(encoded),
+            }
+        }
+
+        let result = self
+            .write_encoded(&high_priority, &Priority::High)
+            .and_then(|()| self.write_encoded(&low_priority, &Priority::Low));
+        if result.is_err() {
+            for item in items {
+                self.mark_as_completed(&item.id)?;
+            }
+        }
+        result
+    }
+
+    // Appends already serialized items to the file of the given priority
+    fn write_encoded(&self, encoded: &[u8], priority: &Priority) -> Result<(), Error> {
+        if encoded.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(references) = self.open_files.read() {
+            // Items with a level above high priority are saved with the high priority items,
+            // their level is stored in the item
+            let file_ref = match priority {
                 Priority::Low => &references.low_priority_file,
-                Priority::High => &references.high_priority_file,
+                Priority::High | Priority::Level(_) => &references.high_priority_file,
             };
 
             if let Ok(mut file) = file_ref.lock() {
-                let mut encoded = match serialize(item) {
-                    Err(e) => return Err(Error::FailedToSerializeWorkItem(e)),
-                    Ok(encoded) => encoded,
-                };
-
                 // Write the data to the disk, and ensure the
                 // content has been flushed to disk.
-                file.write(&encoded)?;
-                if self.require_flush {
-                    file.flush()?;
-                }
+                (self.file_operations.write_item)(&mut *file, encoded, self.require_flush)?;
 
                 Ok(())
             } else {
@@ -155,18 +873,31 @@ impl<T> InternalQueueFileManager<T> where T: Send + Clone + Serialize + Deserial
     {
         if let Ok(mut guard) = self.open_files.read() {
             // Load the completed ids
-            let completed_ids: HashSet<Uuid> =
-                FileItemReader::new_from_file(&self.get_file_path(COMPLETED_EXTENSION))?.collect();
+            let completed_ids = read_completed_ids(&self.get_file_path(COMPLETED_EXTENSION))?;
 
-            let high_priority: Vec<QueueItem<T>> =
-                FileItemReader::new_from_file(&self.get_file_path(HIGH_PRIORITY_EXTENSION))?
+            // Items that has been moved to another priority are kept in the new file
+            let mut high_priority: Vec<QueueItem<T>> = latest_versions(
+                read_items(&self.item_file_path(&Priority::High))?
                     .filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id))
-                    .collect();
-
-            let low_priority: Vec<QueueItem<T>> =
-                FileItemReader::new_from_file(&self.get_file_path(LOW_PRIORITY_EXTENSION))?
+                    .collect())
+                .into_iter()
+                // Every level above high priority is saved in the high priority file
+                .filter(|item| item.priority >= Priority::High)
+                .collect();
+
+            let mut low_priority: Vec<QueueItem<T>> = latest_versions(
+                read_items(&self.item_file_path(&Priority::Low))?
                     .filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id))
-                    .collect();
+                    .collect())
+                .into_iter()
+                .filter(|item| item.priority == Priority::Low)
+                .collect();
+
+            if self.preserve_order {
+                // The sort is stable, so items saved before sequence numbers were recorded keep their order
+                high_priority.sort_by_key(|item| item.sequence);
+                low_priority.sort_by_key(|item| item.sequence);
+            }
 
             Ok(StoredItems { low_priority, high_priority })
         } else {
@@ -174,16 +905,47 @@ impl<T> InternalQueueFileManager<T> where T: Send + Clone + Serialize + Deserial
         }
     }
 
-    pub fn mark_as_completed(&mut self, id: &Uuid) -> Result<(), Error> {
+    // Finds the highest sequence number of the saved items, so new items can continue from it
+    pub fn highest_sequence(&self) -> Result<u64, Error> {
+        if let Ok(_guard) = self.open_files.read() {
+            let mut highest = 0;
+            for extension in &[HIGH_PRIORITY_EXTENSION, LOW_PRIORITY_EXTENSION] {
+                for item in read_items::<T>(&self.get_file_path(extension))? {
+                    highest = highest.max(item.sequence);
+                }
+            }
+            Ok(highest)
+        } else {
+            Err(Error::MutexCorrupted)
+        }
+    }
+
+    // Visits the items saved with the given priority, in the order they were saved.
+    // Stops when the visitor returns false.
+    pub fn visit_items<F>(&self, priority: &Priority, mut visit: F) -> Result<(), Error>
+        where F: FnMut(QueueItem<T>) -> bool {
+        // Hold the lock, so GC doesn't move the files while we read them
+        if let Ok(_guard) = self.open_files.read() {
+            for item in read_items(&self.item_file_path(priority))? {
+                if !visit(item) {
+                    break;
+                }
+            }
+            Ok(())
+        } else {
+            Err(Error::MutexCorrupted)
+        }
+    }
+
+    pub fn mark_as_completed(&self, id: &Uuid) -> Result<(), Error> {
+        self.ensure_writable()?;
+
         if let Ok(mut references) = self.open_files.read() {
             if let Ok(mut completed) = references.completed_file_index_file.lock() {
-                let encoded = match serialize(id) {
-                    Err(e) => return Err(Error::FailedToSerializeWorkItem(e)),
-                    Ok(encoded) => encoded,
-                };
-
-                completed.write(&encoded)?;
-                completed.flush()?;
+                write_completed_id(&mut *completed, id)?;
+                if !self.batch_acknowledgements {
+                    completed.flush()?;
+                }
 
                 Ok(())
             } else {
@@ -194,106 +956,203 @@ impl<T> InternalQueueFileManager<T> where T: Send + Clone + Serialize + Deserial
         }
     }
 
+    // Writes any batched acknowledgements to disk
+    pub fn flush_completed(&self) -> Result<(), Error> {
+        flush_completed(&self.open_files)
+    }
+
     pub fn run_garbage_collection(&mut self) -> Result<(), Error> {
-        if let Ok(lck) = self.gc_lock.lock() {
-            let gc_files_path = Path::new(&format!("{}_gc", self.file_prefix.to_string_lossy())).to_path_buf();
-
-            // Ensure we don't bite ourselves while running parallel
-            if let Ok(mut guard) = self.open_files.write() {
-                let mut temp_target = open_for_append(&gc_files_path)?;
-                *guard = temp_target;
-                // Automatically drop the existing target and the lock
-                // When this happen it will allow the queue to continue accepting items
-                // additionally it will close the normal target files so we can clean them up
+        self.ensure_writable()?;
+
+        let _gc_guard = match self.gc_lock.lock() {
+            Ok(guard) => guard,
+            // An earlier garbage collection panicked part way through. The files are put
+            // back in order the same way as when the server stopped during garbage collection,
+            // after which garbage collection can run again.
+            Err(poisoned) => {
+                let guard = poisoned.into_inner();
+                self.recover_panicked_gc()?;
+                self.gc_lock.clear_poison();
+                guard
             }
+        };
 
+        let gc_files_path = Path::new(&format!("{}_gc", self.file_prefix.to_string_lossy())).to_path_buf();
 
-            let high_priority_file = self.get_file_path(HIGH_PRIORITY_EXTENSION);
-            let high_priority_backup = self.get_file_path(&format!("{}.bak", HIGH_PRIORITY_EXTENSION));
-            let low_priority_file = self.get_file_path(LOW_PRIORITY_EXTENSION);
-            let low_priority_backup = self.get_file_path(&format!("{}.bak", LOW_PRIORITY_EXTENSION));
-            let completed_file = self.get_file_path(COMPLETED_EXTENSION);
-
-            // Create a backup of the original files, so we don't risk losing data
-            rename(&high_priority_file, &high_priority_backup)?;
-            rename(&low_priority_file, &low_priority_backup)?;
-
-            // Read the completed ids, so we know which items we can remove as garbage
-            let completed_ids: HashSet<Uuid> = FileItemReader::new_from_file(&completed_file)?.collect();
+        let sizes = self.measure_file_sizes(&self.file_prefix)?;
+        match self.sizes_before_gc.lock() {
+            Ok(mut sizes_before_gc) => *sizes_before_gc = Some(sizes),
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
 
-            // Actually write out the new items
-            // First for high priority
-            let mut target = BufWriter::new(File::create(high_priority_file)?);
-            for item in FileItemReader::new_from_file(&high_priority_backup)?.filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id)) {
-                serialize_into(&mut target, &item)?;
+        // Ensure we don't bite ourselves while running parallel
+        if let Ok(mut guard) = self.open_files.write() {
+            let temp_target = open_for_append(&gc_files_path)?;
+            *guard = temp_target;
+            // Automatically drop the existing target and the lock
+            // When this happen it will allow the queue to continue accepting items
+            // additionally it will close the normal target files so we can clean them up
+        }
+        self.sync_storage_directory()?;
+
+
+        let high_priority_file = self.get_file_path(HIGH_PRIORITY_EXTENSION);
+        let high_priority_backup = self.get_file_path(&format!("{}.bak", HIGH_PRIORITY_EXTENSION));
+        let low_priority_file = self.get_file_path(LOW_PRIORITY_EXTENSION);
+        let low_priority_backup = self.get_file_path(&format!("{}.bak", LOW_PRIORITY_EXTENSION));
+        let completed_file = self.get_file_path(COMPLETED_EXTENSION);
+
+        // Create a backup of the original files, so we don't risk losing data
+        self.rename_with_retries(&high_priority_file, &high_priority_backup)?;
+        self.rename_with_retries(&low_priority_file, &low_priority_backup)?;
+        self.sync_storage_directory()?;
+
+        // Read the completed ids, so we know which items we can remove as garbage
+        let completed_ids = read_completed_ids(&completed_file)?;
+
+        // Actually write out the new items
+        // First for high priority
+        let mut target = BufWriter::new(create_item_file(&high_priority_file)?);
+        for item in read_items(&high_priority_backup)?.filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id)) {
+            serialize_into(&mut target, &item)?;
+        };
+        target.flush()?;
+        self.sync_file(target.get_ref())?;
+
+        // And then for low priority
+        target = BufWriter::new(create_item_file(&low_priority_file)?);
+        for item in read_items(&low_priority_backup)?.filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id)) {
+            serialize_into(&mut target, &item)?;
+        };
+        target.flush()?;
+        self.sync_file(target.get_ref())?;
+        drop(target);
+        drop(completed_ids);
+        // The garbage collected files has to be durable before the backups are removed
+        self.sync_storage_directory()?;
+
+        // Remove the backup files, since the garbage collected files have now been saved.
+        self.remove_with_retries(&high_priority_backup)?;
+        self.remove_with_retries(&low_priority_backup)?;
+        self.remove_with_retries(&completed_file)?;
+
+        // Change back to writing to the normal files
+        if let Ok(mut guard) = self.open_files.write() {
+            let normal_target = open_for_append(&self.file_prefix)?;
+            *guard = normal_target;
+        }
+        self.sync_storage_directory()?;
+
+        let completed_gc_file = get_file_path(&gc_files_path, COMPLETED_EXTENSION);
+        let high_priority_gc_file = get_file_path(&gc_files_path, HIGH_PRIORITY_EXTENSION);
+        let low_priority_gc_file = get_file_path(&gc_files_path, LOW_PRIORITY_EXTENSION);
+
+        // Copy the data we got while we were garbage collecting into the normal files
+        // This will offset the order slightly, but it's the best we can do to stay active
+        // while GC is running
+        // And the best solution i could find that made rust compile the code...
+        if let Ok(guard) = self.open_files.read() {
+            // I know it's slightly in-efficient to deserialize and serialize, but i can't be bother to
+            // do the binary copy right now, in a way that doesn't break the target
+            // TODO: Binary copy this
+            if let Ok(mut completed) = guard.completed_file_index_file.lock() {
+                for id in read_completed_ids(&completed_gc_file)? {
+                    write_completed_id(&mut *completed, &id)?;
+                };
+                completed.flush()?;
+            } else {
+                return Err(Error::MutexCorrupted);
             };
-            target.flush()?;
-
-            // And then for low priority
-            target = BufWriter::new(File::create(low_priority_file)?);
-            for item in FileItemReader::new_from_file(&low_priority_backup)?.filter(|item: &QueueItem<T>| !completed_ids.contains(&item.id)) {
-                serialize_into(&mut target, &item)?;
+            if let Ok(mut high_priority) = guard.high_priority_file.lock() {
+                for item in read_items::<T>(&high_priority_gc_file)? {
+                    serialize_into(&mut *high_priority, &item)?;
+                };
+                high_priority.flush()?;
+            } else {
+                return Err(Error::MutexCorrupted);
             };
-            target.flush()?;
-            drop(target);
-            drop(completed_ids);
+            if let Ok(mut low_priority) = guard.low_priority_file.lock() {
+                for item in read_items::<T>(&low_priority_gc_file)? {
+                    serialize_into(&mut *low_priority, &item)?;
+                };
+                low_priority.flush()?;
+            } else {
+                return Err(Error::MutexCorrupted);
+            };
+        }
 
-            // Remove the backup files, since the garbage collected files have now been saved.
-            remove_file(high_priority_backup)?;
-            remove_file(low_priority_backup)?;
-            remove_file(completed_file)?;
+        // Lastly remove the temporary gc files
+        self.remove_with_retries(&completed_gc_file)?;
+        self.remove_with_retries(&high_priority_gc_file)?;
+        self.remove_with_retries(&low_priority_gc_file)?;
+        self.sync_storage_directory()?;
 
-            // Change back to writing to the normal files
-            if let Ok(mut guard) = self.open_files.write() {
-                let mut normal_target = open_for_append(&self.file_prefix)?;
-                *guard = normal_target;
+        // If we have come this far without failure it's apparently a miracle
+        Ok(())
+    }
+
+    // Puts the storage files back in order after garbage collection panicked.
+    // Saves are blocked while the files are moved around.
+    fn recover_panicked_gc(&self) -> Result<(), Error> {
+        let mut references = match self.open_files.write() {
+            Ok(references) => references,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // Everything written so far has to be in the files before they are moved
+        for file in &[&references.high_priority_file, &references.low_priority_file, &references.completed_file_index_file] {
+            match file.lock() {
+                Ok(mut file) => file.flush()?,
+                Err(poisoned) => poisoned.into_inner().flush()?,
             }
+        }
 
-            let completed_gc_file = get_file_path(&gc_files_path, COMPLETED_EXTENSION);
-            let high_priority_gc_file = get_file_path(&gc_files_path, HIGH_PRIORITY_EXTENSION);
-            let low_priority_gc_file = get_file_path(&gc_files_path, LOW_PRIORITY_EXTENSION);
-
-            // Copy the data we got while we were garbage collecting into the normal files
-            // This will offset the order slightly, but it's the best we can do to stay active
-            // while GC is running
-            // And the best solution i could find that made rust compile the code...
-            if let Ok(mut guard) = self.open_files.read() {
-                // I know it's slightly in-efficient to deserialize and serialize, but i can't be bother to
-                // do the binary copy right now, in a way that doesn't break the target
-                // TODO: Binary copy this
-                if let Ok(mut completed) = guard.completed_file_index_file.lock() {
-                    for item in FileItemReader::<Uuid, File>::new_from_file(&completed_gc_file)? {
-                        serialize_into(&mut *completed, &item)?;
-                    };
-                    completed.flush()?;
-                } else {
-                    return Err(Error::MutexCorrupted);
-                };
-                if let Ok(mut high_priority) = guard.high_priority_file.lock() {
-                    for item in FileItemReader::<QueueItem<T>, File>::new_from_file(&high_priority_gc_file)? {
-                        serialize_into(&mut *high_priority, &item)?;
-                    };
-                    high_priority.flush()?;
-                } else {
-                    return Err(Error::MutexCorrupted);
-                };
-                if let Ok(mut low_priority) = guard.low_priority_file.lock() {
-                    for item in FileItemReader::<QueueItem<T>, File>::new_from_file(&low_priority_gc_file)? {
-                        serialize_into(&mut *low_priority, &item)?;
-                    };
-                    low_priority.flush()?;
-                } else {
-                    return Err(Error::MutexCorrupted);
-                };
+        recover_interrupted_gc::<T>(&self.file_prefix)?;
+        *references = open_for_append(&self.file_prefix)?;
+        drop(references);
+        self.sync_storage_directory()
+    }
+
+    // True if garbage collection is currently running
+    pub fn is_garbage_collecting(&self) -> bool {
+        match self.gc_lock.try_lock() {
+            Err(TryLockError::WouldBlock) => true,
+            _ => false,
+        }
+    }
+
+    // Gets the current size of the storage files.
+    // Data that hasn't been flushed yet is not included.
+    // While garbage collection is running the files are being swapped, so the sizes from
+    // before it started are reported instead, plus what has been saved since then.
+    pub fn file_sizes(&self) -> Result<StorageFileSizes, Error> {
+        if self.is_garbage_collecting() {
+            let sizes_before_gc = match self.sizes_before_gc.lock() {
+                Ok(sizes) => sizes.clone(),
+                Err(_) => return Err(Error::MutexCorrupted),
+            };
+            if let Some(sizes) = sizes_before_gc {
+                let gc_files_path = Path::new(&format!("{}_gc", self.file_prefix.to_string_lossy())).to_path_buf();
+                let added = self.measure_file_sizes(&gc_files_path)?;
+                // Only the items are copied over when garbage collection is done, not the headers
+                return Ok(StorageFileSizes {
+                    high_priority: sizes.high_priority + added.high_priority.saturating_sub(ITEM_FILE_HEADER_LENGTH),
+                    low_priority: sizes.low_priority + added.low_priority.saturating_sub(ITEM_FILE_HEADER_LENGTH),
+                    completed: sizes.completed + added.completed,
+                });
             }
+        }
 
-            // Lastly remove the temporary gc files
-            remove_file(&completed_gc_file)?;
-            remove_file(&high_priority_gc_file)?;
-            remove_file(&low_priority_gc_file)?;
+        self.measure_file_sizes(&self.file_prefix)
+    }
 
-            // If we have come this far without failure it's apparently a miracle
-            Ok(())
+    fn measure_file_sizes(&self, prefix: &Path) -> Result<StorageFileSizes, Error> {
+        // Make sure we don't read the sizes while the files being written to are replaced
+        if let Ok(_guard) = self.open_files.read() {
+            Ok(StorageFileSizes {
+                high_priority: file_size(&get_file_path(prefix, HIGH_PRIORITY_EXTENSION))?,
+                low_priority: file_size(&get_file_path(prefix, LOW_PRIORITY_EXTENSION))?,
+                completed: file_size(&get_file_path(prefix, COMPLETED_EXTENSION))?,
+            })
         } else {
             Err(Error::MutexCorrupted)
         }
@@ -325,6 +1184,9 @@ impl<T> InternalQueueFileManager<T> where T: Send + Clone + Serialize + Deserial
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use crate::models::{QueueItem, Tags};
     use crate::test_helpers::setup_test_storage;
 
@@ -413,6 +1275,411 @@ mod tests {
         assert_eq!(high_priority.get(0).unwrap().data, "bar".to_string());
     }
 
+    #[test]
+    fn loads_latest_version_of_changed_items() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+
+        let mut item = QueueItem::new("foo".to_string(), Tags::from(vec!["foo"]), Priority::High);
+        manager.save_item(&item).unwrap();
+        manager.save_item(&QueueItem::new("bar".to_string(), Tags::new(), Priority::High)).unwrap();
+
+        item.required_tags = Tags::from(vec!["bar"]);
+        manager.save_item(&item).unwrap();
+
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+
+        assert_eq!(high_priority.len(), 2);
+        assert_eq!(high_priority.get(0).unwrap().data, "bar".to_string());
+        assert_eq!(high_priority.get(1).unwrap(), &item);
+    }
+
+    #[test]
+    fn loads_items_with_changed_priority_once() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+
+        let mut item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&item).unwrap();
+
+        item.priority = Priority::Low;
+        manager.save_changed_item(&item, &Priority::High).unwrap();
+
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority.len(), 0);
+        assert_eq!(low_priority, vec![item.clone()]);
+
+        // And back again
+        item.priority = Priority::High;
+        manager.save_changed_item(&item, &Priority::Low).unwrap();
+
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![item]);
+        assert_eq!(low_priority.len(), 0);
+    }
+
+    #[test]
+    fn loads_items_saved_in_the_original_format() {
+        let storage_path = setup();
+
+        let legacy = LegacyQueueItem {
+            data: "foo".to_string(),
+            required_tags: Tags::from(vec!["foo"]),
+            id: Uuid::new_v4(),
+            priority: Priority::High,
+        };
+        // High priority items were saved in the low priority file in the original format
+        let legacy_path = get_file_path(Path::new(&storage_path), LOW_PRIORITY_EXTENSION);
+        let mut file = File::create(&legacy_path).unwrap();
+        file.write_all(&serialize(&legacy).unwrap()).unwrap();
+        drop(file);
+
+        let path = get_file_path(Path::new(&storage_path), HIGH_PRIORITY_EXTENSION);
+        let original = std::fs::read(&legacy_path).unwrap();
+
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+
+        // The file is migrated, and the original is kept as a backup
+        assert_eq!(read_item_file_version(&mut File::open(&path).unwrap()).unwrap(), ITEM_FILE_VERSION);
+        assert_eq!(std::fs::read(get_file_path(&legacy_path, ".v0")).unwrap(), original);
+
+        let mut item = QueueItem::new("bar".to_string(), Tags::new(), Priority::High);
+        item.enqueued_by = Some("foo".to_string());
+        manager.save_item(&item).unwrap();
+
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+        assert_eq!(high_priority.len(), 2);
+        assert_eq!(high_priority[0], QueueItem::from(legacy));
+        assert_eq!(high_priority[0].enqueued_by, None);
+        assert_eq!(high_priority[1], item);
+    }
+
+    #[test]
+    fn dead_letters_are_kept_until_cleared() {
+        let storage_path = setup();
+        let manager = InternalQueueFileManager::new(storage_path.clone(), true).unwrap();
+        assert!(manager.load_dead_letters().unwrap().is_empty());
+
+        let mut first = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        first.attempts = 3;
+        let second = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        manager.save_dead_letter(&first).unwrap();
+        manager.save_dead_letter(&second).unwrap();
+        drop(manager);
+
+        let manager = InternalQueueFileManager::<String>::new(storage_path.clone(), true).unwrap();
+        assert_eq!(manager.load_dead_letters().unwrap(), vec![first, second]);
+
+        manager.clear_dead_letters().unwrap();
+        assert!(manager.load_dead_letters().unwrap().is_empty());
+        let third = QueueItem::new("baz".to_string(), Tags::new(), Priority::Low);
+        manager.save_dead_letter(&third).unwrap();
+        assert_eq!(manager.load_dead_letters().unwrap(), vec![third]);
+    }
+
+    #[test]
+    fn items_are_saved_in_the_file_named_after_their_priority() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path.clone(), true).unwrap();
+
+        let high = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let low = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        manager.save_item(&high).unwrap();
+        manager.save_item(&low).unwrap();
+
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high.clone()]);
+        assert_eq!(low_priority, vec![low.clone()]);
+
+        let high_file: Vec<QueueItem<String>> = read_items(Path::new(&format!("{}_high_priority.dat", storage_path))).unwrap().collect();
+        let low_file: Vec<QueueItem<String>> = read_items(Path::new(&format!("{}_low_priority.dat", storage_path))).unwrap().collect();
+        assert_eq!(high_file, vec![high]);
+        assert_eq!(low_file, vec![low]);
+    }
+
+    // The item as it was saved in the original format
+    fn as_legacy(item: &QueueItem<String>) -> LegacyQueueItem<String> {
+        LegacyQueueItem {
+            data: item.data.clone(),
+            required_tags: item.required_tags.clone(),
+            id: item.id,
+            priority: item.priority.clone(),
+        }
+    }
+
+    // Writes items the way they were saved in the original format, in the file named after the other priority
+    fn write_original_files(storage_path: &str, high: &QueueItem<String>, low: &QueueItem<String>) {
+        for (extension, item) in &[(LOW_PRIORITY_EXTENSION, high), (HIGH_PRIORITY_EXTENSION, low)] {
+            let mut file = File::create(get_file_path(Path::new(storage_path), extension)).unwrap();
+            file.write_all(&serialize(&as_legacy(item)).unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn renames_item_files_saved_with_swapped_names() {
+        let storage_path = setup();
+        let high = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let low = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        write_original_files(&storage_path, &high, &low);
+
+        let mut manager = InternalQueueFileManager::<String>::new(storage_path.clone(), true).unwrap();
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high.clone()]);
+        assert_eq!(low_priority, vec![low.clone()]);
+
+        let high_path = get_file_path(Path::new(&storage_path), HIGH_PRIORITY_EXTENSION);
+        let high_file: Vec<QueueItem<String>> = read_items(&high_path).unwrap().collect();
+        assert_eq!(high_file, vec![high.clone()]);
+        assert!(!get_file_path(&high_path, SWAP_EXTENSION).exists());
+        drop(manager);
+
+        // Opening it again doesn't swap the files back
+        let mut manager = InternalQueueFileManager::<String>::new(storage_path, true).unwrap();
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high]);
+    }
+
+    #[test]
+    fn finishes_renaming_interrupted_by_a_crash() {
+        let storage_path = setup();
+        let high = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let low = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        write_original_files(&storage_path, &high, &low);
+        migrate_item_files::<String>(Path::new(&storage_path)).unwrap();
+
+        // As if the server stopped after renaming the first copy
+        let low_path = get_file_path(Path::new(&storage_path), LOW_PRIORITY_EXTENSION);
+        let mut file = File::create(get_file_path(&low_path, SWAP_EXTENSION)).unwrap();
+        file.write_all(&std::fs::read(&low_path).unwrap()).unwrap();
+        drop(file);
+        let mut file = File::create(&low_path).unwrap();
+        file.write_all(&serialize(&as_legacy(&high)).unwrap()).unwrap();
+        drop(file);
+
+        let mut manager = InternalQueueFileManager::<String>::new(storage_path, true).unwrap();
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high]);
+        assert_eq!(low_priority, vec![low]);
+    }
+
+    #[test]
+    fn read_only_storage_with_swapped_names_is_loaded_correctly() {
+        let storage_path = setup();
+        let high = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let low = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        write_original_files(&storage_path, &high, &low);
+        File::create(get_file_path(Path::new(&storage_path), COMPLETED_EXTENSION)).unwrap();
+
+        let mut manager = InternalQueueFileManager::<String>::open_read_only(storage_path).unwrap();
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high]);
+        assert_eq!(low_priority, vec![low]);
+    }
+
+    #[test]
+    fn refuses_storage_from_newer_versions() {
+        let storage_path = setup();
+
+        let mut file = File::create(get_file_path(Path::new(&storage_path), LOW_PRIORITY_EXTENSION)).unwrap();
+        file.write_all(ITEM_FILE_MAGIC).unwrap();
+        file.write_all(&[ITEM_FILE_VERSION + 1]).unwrap();
+        drop(file);
+
+        match InternalQueueFileManager::<String>::new(storage_path, true) {
+            Err(Error::UnsupportedFormatVersion(version)) => assert_eq!(version, ITEM_FILE_VERSION + 1),
+            _ => panic!("Storage from a newer version was opened"),
+        }
+    }
+
+    #[test]
+    fn can_mark_items_as_completed_across_threads() {
+        let storage_path = setup();
+        let manager: InternalQueueFileManager<String> = InternalQueueFileManager::new(storage_path, true).unwrap();
+
+        let mut threads = Vec::new();
+        for _ in 0..20 {
+            let m = manager.clone();
+            threads.push(std::thread::spawn(move || {
+                let mut ids = Vec::new();
+                for _ in 0..100 {
+                    let id = Uuid::new_v4();
+                    m.mark_as_completed(&id).unwrap();
+                    ids.push(id);
+                }
+                ids
+            }));
+        }
+
+        let mut ids = HashSet::new();
+        for thread in threads {
+            ids.extend(thread.join().unwrap());
+        }
+
+        let completed = read_completed_ids(&manager.get_file_path(COMPLETED_EXTENSION)).unwrap();
+        assert_eq!(completed.len(), 2000);
+        assert_eq!(completed, ids);
+    }
+
+    #[test]
+    fn batched_acknowledgements_are_written_when_flushed() {
+        let storage_path = setup();
+        let manager: InternalQueueFileManager<String> = InternalQueueFileManager::new(storage_path, true)
+            .unwrap()
+            .with_batched_acknowledgements(Duration::from_millis(50));
+
+        manager.mark_as_completed(&Uuid::new_v4()).unwrap();
+        assert_eq!(manager.file_sizes().unwrap().completed, 0);
+
+        // Eventually the acknowledgement is flushed in the background
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(manager.file_sizes().unwrap().completed, 24);
+
+        manager.mark_as_completed(&Uuid::new_v4()).unwrap();
+        manager.flush_completed().unwrap();
+        assert_eq!(manager.file_sizes().unwrap().completed, 48);
+    }
+
+    #[test]
+    #[ignore]
+    fn batched_acknowledgements_are_faster() {
+        use std::time::Instant;
+
+        fn time_acknowledgements(manager: InternalQueueFileManager<String>) -> Duration {
+            let start = Instant::now();
+            for _ in 0..100000 {
+                manager.mark_as_completed(&Uuid::new_v4()).unwrap();
+            }
+            manager.flush_completed().unwrap();
+            start.elapsed()
+        }
+
+        let immediate = time_acknowledgements(InternalQueueFileManager::new(setup(), true).unwrap());
+        let batched = time_acknowledgements(InternalQueueFileManager::new(setup(), true).unwrap()
+            .with_batched_acknowledgements(Duration::from_millis(100)));
+        println!("Immediate: {:?}, batched: {:?}", immediate, batched);
+
+        assert!(batched < immediate);
+    }
+
+    #[test]
+    fn read_only_storage_can_be_loaded_but_not_written() {
+        let storage_path = setup();
+        let item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+
+        let manager = InternalQueueFileManager::new(storage_path.clone(), true).unwrap();
+        manager.save_item(&item).unwrap();
+        drop(manager);
+
+        let mut manager = InternalQueueFileManager::open_read_only(storage_path).unwrap();
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![item.clone()]);
+
+        match manager.save_item(&item) {
+            Err(Error::ReadOnly) => {}
+            _ => panic!("Saved item to read-only storage"),
+        }
+        match manager.mark_as_completed(&item.id) {
+            Err(Error::ReadOnly) => {}
+            _ => panic!("Completed item in read-only storage"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn refuses_storage_folder_that_is_not_writable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let folder = format!("{}read_only/", setup_test_storage().unwrap());
+        create_dir_all(&folder).unwrap();
+        std::fs::set_permissions(&folder, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+        // Permissions don't apply to root, so there is nothing to test
+        let probe = format!("{}probe", folder);
+        if File::create(&probe).is_ok() {
+            remove_file(&probe).unwrap();
+            return;
+        }
+
+        match InternalQueueFileManager::<String>::new(format!("{}tasks", folder), true) {
+            Err(Error::NotWritable(path, _)) => assert_eq!(path, PathBuf::from(&folder[..folder.len() - 1])),
+            Err(e) => panic!("Unexpected error: {}", e),
+            Ok(_) => panic!("Read-only storage was opened for writing"),
+        }
+        std::fs::set_permissions(&folder, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn can_get_file_sizes() {
+        let storage_path = setup();
+        let manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+
+        // Only the headers has been written
+        assert_eq!(manager.file_sizes().unwrap(), StorageFileSizes { high_priority: 8, low_priority: 8, completed: 0 });
+
+        let item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&item).unwrap();
+        manager.mark_as_completed(&item.id).unwrap();
+
+        let sizes = manager.file_sizes().unwrap();
+        assert_eq!(sizes.high_priority, 8 + serialize(&item).unwrap().len() as u64);
+        assert_eq!(sizes.low_priority, 8);
+        assert_eq!(sizes.completed, 24);
+        assert!(!manager.is_garbage_collecting());
+    }
+
+    #[test]
+    fn file_sizes_shrink_after_garbage_collection() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+        let empty = manager.file_sizes().unwrap().high_priority;
+
+        let items: Vec<QueueItem<String>> = (0..10)
+            .map(|i| QueueItem::new(format!("item {}", i), Tags::new(), Priority::High))
+            .collect();
+        for item in &items {
+            manager.save_item(item).unwrap();
+            manager.mark_as_completed(&item.id).unwrap();
+        }
+        let grown = manager.file_sizes().unwrap();
+        let items_size: u64 = items.iter().map(|item| serialize(item).unwrap().len() as u64).sum();
+        assert_eq!(grown.high_priority, empty + items_size);
+
+        manager.run_garbage_collection().unwrap();
+        let collected = manager.file_sizes().unwrap();
+        assert_eq!(collected.high_priority, empty);
+        assert_eq!(collected.completed, 0);
+    }
+
+    // Renames the file, and then waits a while, so the files can be looked at during garbage collection
+    fn slow_rename(from: &Path, to: &Path) -> Result<(), IOError> {
+        rename(from, to)?;
+        thread::sleep(Duration::from_millis(100));
+        Ok(())
+    }
+
+    #[test]
+    fn file_sizes_are_kept_while_garbage_collecting() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+        manager.file_operations.rename = slow_rename;
+
+        let item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&item).unwrap();
+        let before = manager.file_sizes().unwrap();
+
+        let mut gc_manager = manager.clone();
+        let handle = thread::spawn(move || gc_manager.run_garbage_collection());
+        while !manager.is_garbage_collecting() {
+            thread::yield_now();
+        }
+        // The high priority file has been moved away at this point
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(manager.file_sizes().unwrap(), before);
+
+        handle.join().unwrap().unwrap();
+        assert_eq!(manager.file_sizes().unwrap(), before);
+    }
+
     #[test]
     fn can_run_garbage_collection() {
         let storage_path = setup();
@@ -483,6 +1750,199 @@ mod tests {
         assert_eq!(lp_set, lp_items_set);
     }
 
+    #[test]
+    fn preserves_enqueue_order_when_items_are_added_during_gc() {
+        let storage_path = setup();
+
+        let mut manager = InternalQueueFileManager::new(storage_path.clone(), true)
+            .unwrap()
+            .with_preserved_order();
+
+        // Give garbage collection something to do, so it's still running while items are added
+        for _ in 0..20000 {
+            let item = QueueItem::new("trash".to_string(), Tags::new(), Priority::High);
+            manager.save_item(&item).expect("Failed to save trash item");
+            manager.mark_as_completed(&item.id).expect("Failed to complete trash item");
+        }
+
+        let mut m = manager.clone();
+        let handle = std::thread::spawn(move || {
+            m.run_garbage_collection().expect("Garbage collection failed");
+        });
+
+        // Keep adding items until garbage collection is done, so they might be added right after
+        // it switches back to the normal files, before the items added during it are copied over
+        let mut enqueued = Vec::new();
+        while !manager.is_garbage_collecting() {}
+        while manager.is_garbage_collecting() {
+            let mut item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+            item.sequence = enqueued.len() as u64 + 1;
+            manager.save_item(&item).unwrap();
+            enqueued.push(item.id);
+        }
+
+        handle.join().unwrap();
+
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+        let loaded: Vec<Uuid> = high_priority.iter().map(|item| item.id).collect();
+        assert_eq!(loaded, enqueued);
+        assert_eq!(manager.highest_sequence().unwrap(), enqueued.len() as u64);
+    }
+
+    #[test]
+    fn can_run_garbage_collection_with_fsync() {
+        let storage_path = setup();
+
+        let mut manager = InternalQueueFileManager::new_with_durability(storage_path.clone(), true, Durability::Fsync).unwrap();
+
+        let item1 = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let item2 = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+
+        manager.save_item(&item1).unwrap();
+        manager.save_item(&item2).unwrap();
+        manager.mark_as_completed(&item1.id).unwrap();
+
+        manager.run_garbage_collection().unwrap();
+
+        drop(manager);
+
+        manager = InternalQueueFileManager::new_with_durability(storage_path, true, Durability::Fsync).unwrap();
+
+        let StoredItems { low_priority, high_priority } = manager.load_items().unwrap();
+
+        assert_eq!(high_priority.len(), 0);
+        assert_eq!(low_priority, vec![item2]);
+    }
+
+    static ITEM_WRITES: AtomicUsize = AtomicUsize::new(0);
+
+    fn counted_write_item(file: &mut BufWriter<File>, encoded: &[u8], flush: bool) -> Result<(), IOError> {
+        ITEM_WRITES.fetch_add(1, Ordering::SeqCst);
+        write_item(file, encoded, flush)
+    }
+
+    #[test]
+    fn saves_a_batch_with_one_write_per_file() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, true).unwrap();
+        manager.file_operations.write_item = counted_write_item;
+
+        let items: Vec<QueueItem<String>> = (0..1000)
+            .map(|i| {
+                let priority = if i % 2 == 0 { Priority::High } else { Priority::Low };
+                QueueItem::new(format!("item {}", i), Tags::new(), priority)
+            })
+            .collect();
+        manager.save_items(&items).unwrap();
+        assert_eq!(ITEM_WRITES.load(Ordering::SeqCst), 2);
+
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        let expected_high: Vec<_> = items.iter().filter(|item| item.priority == Priority::High).cloned().collect();
+        let expected_low: Vec<_> = items.iter().filter(|item| item.priority == Priority::Low).cloned().collect();
+        assert_eq!(high_priority, expected_high);
+        assert_eq!(low_priority, expected_low);
+    }
+
+    static FAILING_RENAMES: AtomicUsize = AtomicUsize::new(0);
+
+    // Fails the number of times set in FAILING_RENAMES before renaming
+    fn flaky_rename(from: &Path, to: &Path) -> Result<(), IOError> {
+        if FAILING_RENAMES.load(Ordering::SeqCst) > 0 {
+            FAILING_RENAMES.fetch_sub(1, Ordering::SeqCst);
+            return Err(IOError::new(IOErrorKind::Other, "File is in use"));
+        }
+        rename(from, to)
+    }
+
+    fn broken_remove(_path: &Path) -> Result<(), IOError> {
+        Err(IOError::new(IOErrorKind::PermissionDenied, "File is in use"))
+    }
+
+    #[test]
+    fn gc_retries_failing_renames() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, false)
+            .unwrap()
+            .with_gc_retry_limit(3);
+        manager.file_operations.rename = flaky_rename;
+
+        let kept = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let completed = QueueItem::new("bar".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&kept).unwrap();
+        manager.save_item(&completed).unwrap();
+        manager.mark_as_completed(&completed.id).unwrap();
+
+        FAILING_RENAMES.store(3, Ordering::SeqCst);
+        manager.run_garbage_collection().unwrap();
+        assert_eq!(FAILING_RENAMES.load(Ordering::SeqCst), 0);
+
+        let StoredItems { high_priority, .. } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![kept]);
+    }
+
+    #[test]
+    fn recovers_from_failed_gc() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path.clone(), false)
+            .unwrap()
+            .with_gc_retry_limit(1);
+        manager.file_operations.remove = broken_remove;
+
+        let high = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let low = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        manager.save_item(&high).unwrap();
+        manager.save_item(&low).unwrap();
+
+        assert!(manager.run_garbage_collection().is_err());
+        // Written to the temporary gc files, since the gc never finished
+        let late = QueueItem::new("baz".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&late).unwrap();
+        manager.mark_as_completed(&low.id).unwrap();
+        manager.flush_data().unwrap();
+        drop(manager);
+
+        let mut manager: InternalQueueFileManager<String> = InternalQueueFileManager::new(storage_path.clone(), false).unwrap();
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![high, late]);
+        assert_eq!(low_priority, vec![]);
+
+        assert!(!Path::new(&format!("{}{}.bak", storage_path, HIGH_PRIORITY_EXTENSION)).exists());
+        assert!(!Path::new(&format!("{}_gc{}", storage_path, HIGH_PRIORITY_EXTENSION)).exists());
+    }
+
+    fn panicking_rename(_from: &Path, _to: &Path) -> Result<(), IOError> {
+        panic!("Simulated panic during garbage collection");
+    }
+
+    #[test]
+    fn gc_runs_again_after_a_gc_panicked() {
+        let storage_path = setup();
+        let mut manager = InternalQueueFileManager::new(storage_path, false).unwrap();
+
+        let kept = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+        let completed = QueueItem::new("bar".to_string(), Tags::new(), Priority::Low);
+        manager.save_item(&kept).unwrap();
+        manager.save_item(&completed).unwrap();
+        manager.mark_as_completed(&completed.id).unwrap();
+
+        let mut panicking = manager.clone();
+        panicking.file_operations.rename = panicking_rename;
+        assert!(thread::spawn(move || panicking.run_garbage_collection()).join().is_err());
+
+        // Saved while the files are still in the state the panic left them in
+        let late = QueueItem::new("baz".to_string(), Tags::new(), Priority::High);
+        manager.save_item(&late).unwrap();
+
+        manager.run_garbage_collection().expect("Garbage collection failed after the panic");
+        assert!(!manager.is_garbage_collecting());
+        let StoredItems { high_priority, low_priority } = manager.load_items().unwrap();
+        assert_eq!(high_priority, vec![kept, late]);
+        assert_eq!(low_priority, vec![]);
+
+        // The lock is no longer poisoned
+        manager.run_garbage_collection().unwrap();
+    }
+
     #[test]
     #[ignore]
     fn can_gc_many_items() {
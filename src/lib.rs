@@ -0,0 +1,31 @@
+extern crate bincode;
+#[macro_use]
+extern crate crossbeam;
+extern crate protobuf;
+extern crate uuid;
+
+pub mod binary;
+pub mod client;
+pub mod config;
+pub mod events;
+pub mod file_item_reader;
+pub mod hooks;
+pub mod idle_workers;
+pub mod index_record;
+pub mod internal_queue_file_manager;
+pub mod metrics;
+pub mod models;
+pub mod prefetch;
+pub mod queue;
+pub mod queue_server;
+pub mod queues;
+pub mod rate_limit;
+pub mod rpc;
+pub mod server;
+pub mod session;
+pub mod shutdown;
+mod test_helpers;
+pub mod time_helpers;
+pub mod transfer;
+pub mod validation;
+pub mod authentication;
@@ -1,49 +1,87 @@
-extern crate bincode;
-#[macro_use]
-extern crate crossbeam;
 extern crate env_logger;
-extern crate protobuf;
-extern crate uuid;
 
-use std::net::{TcpListener, TcpStream};
+use std::net::TcpListener;
 use std::path::PathBuf;
+use std::process;
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
-mod binary;
-mod client;
-mod file_item_reader;
-mod internal_queue_file_manager;
-mod models;
-mod queue;
-mod queue_server;
-mod rpc;
-mod test_helpers;
-mod authentication;
-
-fn handle_connection(mut s: TcpStream, qs: queue_server::QueueServer<Vec<u8>>, auth: authentication::Authentication) {
-    thread::spawn(move || {
-        let mut c = client::Client::new(qs, auth);
-        c.handle_connection(s);
-    });
-}
+use brqueue::{authentication, config, queue_server, queues, server, session, shutdown, transfer, validation};
+
+// Exit code used when the storage folder can't be written to
+const EXIT_STORAGE_NOT_WRITABLE: i32 = 3;
 
 fn main() {
-    let mut qs = queue_server::QueueServer::new().expect("Failed to create underlying queue");
-    let mut auth = authentication::Authentication::new(PathBuf::from("storage/auth")).expect("Failed to initialize authentication");
+    let config = config::Config::from_env();
 
-    auth.add_default_user("guest".to_string(), "guest".to_string()).expect("Failed to add default user");
+    let mut qs = match queue_server::QueueServer::new(&config) {
+        Ok(qs) => qs,
+        Err(e @ queue_server::Error::StorageNotWritable(..)) => {
+            eprintln!("{}", e);
+            process::exit(EXIT_STORAGE_NOT_WRITABLE);
+        }
+        Err(e) => panic!("Failed to create underlying queue: {}", e),
+    };
+    if config.require_json_payloads {
+        qs = qs.with_payload_validator(Arc::new(|payload: &Vec<u8>| validation::json_payload(payload)));
+    }
+    if config.reject_pops_until_loaded {
+        qs = qs.until_loaded();
+        let mut loader = qs.clone();
+        thread::spawn(move || {
+            match loader.load_stored_items() {
+                Ok(loaded) => println!("Loaded {} stored tasks", loaded),
+                Err(e) => {
+                    eprintln!("Failed to load stored tasks: {}", e);
+                    process::exit(1);
+                }
+            }
+        });
+    } else {
+        let loaded = qs.load_stored_items().expect("Failed to load stored tasks");
+        println!("Loaded {} stored tasks", loaded);
+    }
+    let mut auth = if config.lock_auth_on_load_failure {
+        authentication::Authentication::new_or_locked(PathBuf::from(config.auth_path()), config.admin_users.clone())
+    } else {
+        authentication::Authentication::new_with_admin_users(PathBuf::from(config.auth_path()), config.admin_users.clone()).expect("Failed to initialize authentication")
+    };
+    auth = auth.with_bcrypt_cost(config.bcrypt_cost).expect("Invalid bcrypt cost");
 
-    let listener = TcpListener::bind("0.0.0.0:6431").expect("Failed to bind to socket");
+    if config.auth_flush_interval > Duration::from_millis(0) {
+        auth = auth.with_batched_saves(config.auth_flush_interval, config.auth_flush_threshold);
+    }
 
-    println!("Listening on localhost:6431");
+    auth.seed_default_user(&config.default_user).expect("Failed to add default user");
+    // The default user should be there right away, even if changes are batched
+    auth.flush().expect("Failed to save default user");
 
-    for stream_result in listener.incoming() {
-        let q = qs.clone();
-        let a = auth.clone();
-        match stream_result {
-            Ok(mut stream) => handle_connection(stream, q, a),
-            Err(e) => eprintln!("Stream failed: {}", e),
-        }
+    let sessions = session::Sessions::new(config.disconnect_grace_period);
+    let mut transfers = transfer::Transfers::new(PathBuf::from(format!("{}/transfers", config.storage_folder)))
+        .expect("Failed to initialize transfers")
+        .with_max_bytes(config.max_transfer_bytes);
+    if config.transfer_idle_timeout > Duration::from_millis(0) {
+        transfers = transfers.with_idle_timeout(config.transfer_idle_timeout);
     }
+
+    let listener = TcpListener::bind(&config.listen_address).expect("Failed to bind to socket");
+
+    println!("Listening on {}", config.listen_address);
+
+    let mut named_queues = queues::NamedQueues::new(qs.clone(), format!("{}/queues", config.storage_folder), config.clone());
+    if config.require_json_payloads {
+        named_queues = named_queues.with_payload_validator(Arc::new(|payload: &Vec<u8>| validation::json_payload(payload)));
+    }
+
+    let auth = Arc::new(auth);
+    let server = server::Server::new(qs, auth.clone(), sessions, transfers, config)
+        .with_named_queues(named_queues.clone());
+    // Only returns once the connections are done, so nothing changes the queues while they are saved
+    server.run(listener, shutdown::on_signal()).expect("Failed to accept connections");
+
+    println!("Shutting down");
+    let requeued = named_queues.shutdown().expect("Failed to save the queues");
+    println!("Put {} tasks that were being processed back in the queue", requeued);
+    auth.flush().expect("Failed to save users");
 }
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::time_helpers::duration_to_millis;
+
+// How much weight a new sample gets compared to the history
+const SMOOTHING_FACTOR: f64 = 0.1;
+// How long events are counted for, before the rate is added to the average
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+// An exponential moving average that can be shared between threads
+#[derive(Clone, Debug)]
+pub struct MovingAverage {
+    inner: Arc<Mutex<Option<f64>>>,
+}
+
+impl MovingAverage {
+    pub fn new() -> MovingAverage {
+        MovingAverage {
+            inner: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn add_sample(&self, sample: f64) {
+        if let Ok(mut value) = self.inner.lock() {
+            *value = match *value {
+                // The first sample is the best guess we have
+                None => Some(sample),
+                Some(current) => Some(current + SMOOTHING_FACTOR * (sample - current)),
+            };
+        }
+    }
+
+    // Gets the current average, 0 if no samples has been added yet
+    pub fn get(&self) -> f64 {
+        match self.inner.lock() {
+            Ok(value) => value.unwrap_or(0.0),
+            Err(_) => 0.0,
+        }
+    }
+}
+
+impl Default for MovingAverage {
+    fn default() -> MovingAverage {
+        MovingAverage::new()
+    }
+}
+
+// A counter that can be shared between threads
+#[derive(Clone, Debug)]
+pub struct Counter {
+    inner: Arc<AtomicUsize>,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter {
+            inner: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn increment(&self) {
+        self.add(1);
+    }
+
+    pub fn add(&self, count: usize) {
+        self.inner.fetch_add(count, Ordering::SeqCst);
+    }
+
+    pub fn get(&self) -> usize {
+        self.inner.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Counter {
+        Counter::new()
+    }
+}
+
+struct RateWindow {
+    started: Instant,
+    count: usize,
+}
+
+// Measures how many times per second something happens, as a moving average
+// of the rate in each window. Every method takes the current time,
+// so the meter can be tested without waiting.
+#[derive(Clone)]
+pub struct RateMeter {
+    window: Arc<Mutex<RateWindow>>,
+    average: MovingAverage,
+}
+
+impl RateMeter {
+    pub fn new(now: Instant) -> RateMeter {
+        RateMeter {
+            window: Arc::new(Mutex::new(RateWindow { started: now, count: 0 })),
+            average: MovingAverage::new(),
+        }
+    }
+
+    // Adds the rate of the current window to the average, if the window is over
+    fn roll(&self, window: &mut RateWindow, now: Instant) {
+        if now < window.started + RATE_WINDOW {
+            return;
+        }
+        let elapsed = duration_to_millis(now - window.started) as f64 / 1000.0;
+        self.average.add_sample(window.count as f64 / elapsed);
+        window.started = now;
+        window.count = 0;
+    }
+
+    pub fn record(&self, now: Instant) {
+        if let Ok(mut window) = self.window.lock() {
+            self.roll(&mut window, now);
+            window.count += 1;
+        }
+    }
+
+    // Gets the average number of events per second, 0 if nothing has been measured yet
+    pub fn per_second(&self, now: Instant) -> f64 {
+        if let Ok(mut window) = self.window.lock() {
+            self.roll(&mut window, now);
+        }
+        self.average.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_moves_towards_samples() {
+        let average = MovingAverage::new();
+        assert_eq!(average.get(), 0.0);
+
+        average.add_sample(10.0);
+        assert_eq!(average.get(), 10.0);
+
+        for _ in 0..100 {
+            average.add_sample(1.0);
+        }
+        assert!(average.get() < 1.1);
+    }
+
+    #[test]
+    fn rate_is_measured_per_second() {
+        let start = Instant::now();
+        let meter = RateMeter::new(start);
+        assert_eq!(meter.per_second(start), 0.0);
+
+        // 5 events per second for 3 seconds
+        for i in 0..15 {
+            meter.record(start + Duration::from_millis(i * 200));
+        }
+        let rate = meter.per_second(start + Duration::from_millis(3000));
+        assert!((rate - 5.0).abs() < 0.01, "Rate was {}", rate);
+    }
+}
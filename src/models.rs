@@ -1,12 +1,78 @@
+use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::iter::FromIterator;
+use std::time::SystemTime;
 
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
+// Serialized as its level, not as its position in the enum,
+// so variants can be added or reordered without breaking saved items.
+// Items with a higher level are popped first.
+#[derive(Clone, Debug)]
 pub enum Priority {
     Low,
     High,
+    // Levels above High, from 2 and up. Use from_level to create it,
+    // so Low and High are never represented as a Level.
+    Level(u8),
+}
+
+impl Priority {
+    // The level stored on disk and never changed for an existing priority.
+    // Matches the variant index the priorities were stored with before,
+    // so items saved by older versions still load.
+    pub fn level(&self) -> u32 {
+        match self {
+            Priority::Low => 0,
+            Priority::High => 1,
+            Priority::Level(level) => *level as u32,
+        }
+    }
+
+    pub fn from_level(level: u32) -> Option<Priority> {
+        match level {
+            0 => Some(Priority::Low),
+            1 => Some(Priority::High),
+            level if level <= u8::max_value() as u32 => Some(Priority::Level(level as u8)),
+            _ => None,
+        }
+    }
+}
+
+// Compared by level, so a Level with the level of Low or High is the same priority
+impl PartialEq for Priority {
+    fn eq(&self, other: &Priority) -> bool {
+        self.level() == other.level()
+    }
+}
+
+impl Eq for Priority {}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Priority) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Priority) -> Ordering {
+        self.level().cmp(&other.level())
+    }
+}
+
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.level())
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Priority, D::Error> {
+        let level = u32::deserialize(deserializer)?;
+        Priority::from_level(level).ok_or_else(|| de::Error::custom(format!("Unknown priority level {}", level)))
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -15,6 +81,23 @@ pub struct QueueItem<T: Send + Clone> {
     pub required_tags: Tags,
     pub id: uuid::Uuid,
     pub priority: Priority,
+    // The user that enqueued the item, if known
+    pub enqueued_by: Option<String>,
+    // The item is not handed out before this time, if set
+    pub available_at: Option<SystemTime>,
+    // Increases with every enqueued item, so the order items were enqueued in can be restored
+    pub sequence: u64,
+    // The item is dropped instead of handed out after this time, if set
+    pub expires_at: Option<SystemTime>,
+    // How many times handling the item has failed
+    pub attempts: u32,
+    // When the item was enqueued, if known. Items saved by older versions don't have it.
+    pub enqueued_at: Option<SystemTime>,
+    // The idempotency key the item was enqueued with, if any.
+    // Saved so the key still finds the item after a restart.
+    pub idempotency_key: Option<String>,
+    // When the item was moved to the dead letter queue, if it has been
+    pub dead_lettered_at: Option<SystemTime>,
 }
 
 impl<T: Send + Clone> QueueItem<T> {
@@ -26,6 +109,22 @@ impl<T: Send + Clone> QueueItem<T> {
             required_tags: tags,
             priority,
             id,
+            enqueued_by: None,
+            available_at: None,
+            sequence: 0,
+            expires_at: None,
+            attempts: 0,
+            enqueued_at: None,
+            idempotency_key: None,
+            dead_lettered_at: None,
+        }
+    }
+
+    // True if the item is too old to be handed out
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        match self.expires_at {
+            Some(expires_at) => expires_at <= now,
+            None => false,
         }
     }
 
@@ -34,6 +133,15 @@ impl<T: Send + Clone> QueueItem<T> {
     }
 }
 
+// How a set of tags is matched against the tags of an item
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TagMatch {
+    // The item has all of the tags
+    All,
+    // The item has at least one of the tags
+    Any,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct Tags {
     inner: HashSet<String>,
@@ -57,6 +165,38 @@ impl Tags {
     pub fn is_superset(&self, other: &Tags) -> bool {
         return self.inner.is_superset(&other.inner);
     }
+
+    pub fn contains(&self, tag: &str) -> bool {
+        self.inner.contains(tag)
+    }
+
+    // Iterates the tags in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.inner.iter().map(|tag| tag.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    // Checks if these tags matches the queried tags
+    pub fn matches(&self, query: &Tags, mode: &TagMatch) -> bool {
+        match mode {
+            TagMatch::All => self.is_superset(query),
+            TagMatch::Any => !self.inner.is_disjoint(&query.inner),
+        }
+    }
+
+    // Gets the tags in a stable order, so equal tag sets can be compared and hashed
+    pub fn to_sorted_vec(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.inner.iter().cloned().collect();
+        tags.sort();
+        tags
+    }
 }
 
 impl std::convert::From<Vec<String>> for Tags {
@@ -74,3 +214,82 @@ impl std::convert::From<Vec<&str>> for Tags {
         }
     }
 }
+
+impl IntoIterator for Tags {
+    type Item = String;
+    type IntoIter = std::collections::hash_set::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode::{deserialize, serialize};
+
+    use super::*;
+
+    #[test]
+    fn priority_levels_survive_adding_priorities() {
+        // How the priorities were stored before, and how they would be
+        // stored if a new priority was added between the existing ones
+        #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+        enum OriginalPriority {
+            Low,
+            High,
+        }
+        #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+        enum ExtendedPriority {
+            Low,
+            Medium,
+            High,
+        }
+
+        let original = serialize(&OriginalPriority::High).unwrap();
+        assert_eq!(serialize(&Priority::High).unwrap(), original);
+        assert_eq!(serialize(&Priority::Low).unwrap(), serialize(&OriginalPriority::Low).unwrap());
+
+        // Deriving the serialization would turn saved high priority items into medium ones
+        assert_eq!(deserialize::<ExtendedPriority>(&original).unwrap(), ExtendedPriority::Medium);
+        assert_eq!(deserialize::<Priority>(&original).unwrap(), Priority::High);
+
+        assert!(deserialize::<Priority>(&serialize(&256u32).unwrap()).is_err());
+    }
+
+    #[test]
+    fn priority_levels_are_ordered_and_stored_as_numbers() {
+        let seven = Priority::from_level(7).unwrap();
+        assert_eq!(deserialize::<Priority>(&serialize(&seven).unwrap()).unwrap(), seven);
+        assert_eq!(serialize(&seven).unwrap(), serialize(&7u32).unwrap());
+
+        assert_eq!(Priority::from_level(1), Some(Priority::High));
+        assert_eq!(Priority::Level(1), Priority::High);
+        assert!(seven > Priority::High);
+        assert!(Priority::High > Priority::Low);
+    }
+
+    #[test]
+    fn can_iterate_tags() {
+        let tags = Tags::from(vec!["foo", "bar"]);
+
+        let mut iterated: Vec<&str> = tags.iter().collect();
+        iterated.sort();
+        assert_eq!(iterated, vec!["bar", "foo"]);
+        assert_eq!(tags.len(), 2);
+
+        let mut owned: Vec<String> = tags.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec!["bar".to_string(), "foo".to_string()]);
+    }
+
+    #[test]
+    fn can_check_membership() {
+        let tags = Tags::from(vec!["foo"]);
+
+        assert!(tags.contains("foo"));
+        assert!(!tags.contains("bar"));
+        assert!(!tags.is_empty());
+        assert!(Tags::new().is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Condvar, Mutex};
+
+use uuid::Uuid;
+
+struct WindowState {
+    in_flight: HashSet<Uuid>,
+    closed: bool,
+}
+
+// Keeps track of the tasks pushed to a subscribed worker that it hasn't
+// acknowledged yet, so it's never sent more than it has asked to hold at once
+#[derive(Clone)]
+pub struct PrefetchWindow {
+    size: usize,
+    state: Arc<(Mutex<WindowState>, Condvar)>,
+}
+
+impl PrefetchWindow {
+    pub fn new(size: usize) -> PrefetchWindow {
+        PrefetchWindow {
+            size,
+            state: Arc::new((
+                Mutex::new(WindowState {
+                    in_flight: HashSet::new(),
+                    closed: false,
+                }),
+                Condvar::new(),
+            )),
+        }
+    }
+
+    // Blocks until there is room for another task.
+    // Returns false if the window is closed.
+    pub fn wait_for_room(&self) -> bool {
+        let (lock, condvar) = &*self.state;
+        let mut state = match lock.lock() {
+            Ok(state) => state,
+            Err(_) => return false,
+        };
+        while !state.closed && state.in_flight.len() >= self.size {
+            state = match condvar.wait(state) {
+                Ok(state) => state,
+                Err(_) => return false,
+            };
+        }
+        !state.closed
+    }
+
+    pub fn add(&self, id: Uuid) {
+        if let Ok(mut state) = self.state.0.lock() {
+            state.in_flight.insert(id);
+        }
+    }
+
+    // Makes room for another task, if the task was in the window.
+    // Returns false if it wasn't.
+    pub fn remove(&self, id: &Uuid) -> bool {
+        let (lock, condvar) = &*self.state;
+        match lock.lock() {
+            Ok(mut state) => {
+                let removed = state.in_flight.remove(id);
+                if removed {
+                    condvar.notify_all();
+                }
+                removed
+            }
+            Err(_) => false,
+        }
+    }
+
+    // Stops anyone waiting for room
+    pub fn close(&self) {
+        let (lock, condvar) = &*self.state;
+        if let Ok(mut state) = lock.lock() {
+            state.closed = true;
+        }
+        condvar.notify_all();
+    }
+
+    pub fn len(&self) -> usize {
+        match self.state.0.lock() {
+            Ok(state) => state.in_flight.len(),
+            Err(_) => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn waits_until_tasks_are_removed() {
+        let window = PrefetchWindow::new(2);
+        let first = Uuid::new_v4();
+        assert!(window.is_empty());
+
+        assert!(window.wait_for_room());
+        window.add(first);
+        assert!(window.wait_for_room());
+        window.add(Uuid::new_v4());
+        assert_eq!(window.len(), 2);
+
+        let w = window.clone();
+        let handle = thread::spawn(move || w.wait_for_room());
+        thread::sleep(Duration::from_millis(50));
+        assert!(!window.remove(&Uuid::new_v4()));
+        assert!(window.remove(&first));
+        assert!(handle.join().unwrap());
+    }
+
+    #[test]
+    fn closing_stops_waiting() {
+        let window = PrefetchWindow::new(1);
+        window.add(Uuid::new_v4());
+
+        let w = window.clone();
+        let handle = thread::spawn(move || w.wait_for_room());
+        thread::sleep(Duration::from_millis(50));
+        window.close();
+        assert!(!handle.join().unwrap());
+    }
+}
@@ -1,21 +1,37 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::fmt;
-use std::iter::{FromIterator, Iterator};
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::thread::{JoinHandle, spawn};
 
-use crossbeam::channel::{Receiver, Sender, unbounded};
+use uuid::Uuid;
 
+use crate::config::QueueOrder;
+use crate::metrics::MovingAverage;
 use crate::models::{QueueItem, Tags};
 
-// Tags for messages
+// All the queued items that require exactly the same tags,
+// in the order they were enqueued
+struct TagGroup<T: Send + Clone> {
+    tags: Tags,
+    items: VecDeque<(u64, QueueItem<T>)>,
+}
+
+struct InnerQueue<T: Send + Clone> {
+    // Incremented for every enqueued item, so items can be ordered across groups
+    next_sequence: u64,
+    // The queued items, indexed by the tags they require.
+    // This way pop only has to check each distinct tag set once, instead
+    // of checking every single item in the queue
+    groups: HashMap<Vec<String>, TagGroup<T>>,
+}
 
 #[derive(Clone)]
 pub struct Queue<T: Send + Clone> {
-    sender: Sender<QueueItem<T>>,
-    receiver: Receiver<QueueItem<T>>,
+    inner: Arc<Mutex<InnerQueue<T>>>,
+    // How many tag groups pop looks at that the worker can't handle
+    scan_depth: MovingAverage,
+    order: QueueOrder,
 }
 
 #[derive(Debug)]
@@ -33,46 +49,240 @@ impl fmt::Display for Error {
 
 impl<T: Send + Clone> Queue<T> {
     pub fn new() -> Queue<T> {
-        let (sender, receiver) = unbounded();
+        Queue::with_order(QueueOrder::Fifo)
+    }
 
-        Queue { sender, receiver }
+    pub fn with_order(order: QueueOrder) -> Queue<T> {
+        Queue {
+            inner: Arc::new(Mutex::new(InnerQueue {
+                next_sequence: 0,
+                groups: HashMap::new(),
+            })),
+            scan_depth: MovingAverage::new(),
+            order,
+        }
     }
 
     pub fn enqueue(&mut self, item: QueueItem<T>) -> Result<(), Error> {
-        match self.sender.send(item) {
-            Err(e) => Err(Error::QueueCorrupted),
-            Ok(()) => Ok(()),
-        }
+        let mut inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let sequence = inner.next_sequence;
+        inner.next_sequence += 1;
+
+        let group = inner
+            .groups
+            .entry(item.required_tags.to_sorted_vec())
+            .or_insert_with(|| TagGroup {
+                tags: item.required_tags.clone(),
+                items: VecDeque::new(),
+            });
+        group.items.push_back((sequence, item));
+
+        Ok(())
     }
 
     pub fn pop(&mut self, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
-        let mut failures = Vec::new();
-
-        while let Ok(q) = self.receiver.try_recv() {
-            if q.can_be_handled_by(capabilities) {
-                if !failures.is_empty() {
-                    // If there were any failures, put them in the back of the queue for now
-                    for failure in failures {
-                        self.sender.send(failure);
-                    }
+        let mut inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let order = self.order;
+        let (key, mismatched) = next_group(&inner, capabilities, order);
+        self.scan_depth.add_sample(mismatched as f64);
+
+        let key = match key {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+
+        let (item, is_empty) = match inner.groups.get_mut(&key) {
+            Some(group) => {
+                let item = match order {
+                    QueueOrder::Fifo => group.items.pop_front(),
+                    QueueOrder::Lifo => group.items.pop_back(),
+                };
+                (item.map(|(_, item)| item), group.items.is_empty())
+            }
+            None => return Err(Error::QueueCorrupted),
+        };
+        if is_empty {
+            inner.groups.remove(&key);
+        }
+
+        Ok(item)
+    }
+
+    // The item pop would take next, without taking it out of the queue
+    pub fn peek(&self, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
+        let inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let key = match next_group(&inner, capabilities, self.order).0 {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        let item = inner.groups.get(&key).and_then(|group| match self.order {
+            QueueOrder::Fifo => group.items.front(),
+            QueueOrder::Lifo => group.items.back(),
+        });
+        Ok(item.map(|(_, item)| item.clone()))
+    }
+
+    // The oldest item that can be handled, whatever order the queue pops in
+    pub fn peek_oldest(&self, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
+        let inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let key = match next_group(&inner, capabilities, QueueOrder::Fifo).0 {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+        let item = inner.groups.get(&key).and_then(|group| group.items.front());
+        Ok(item.map(|(_, item)| item.clone()))
+    }
+
+    // Removes a specific item from the queue, wherever it is.
+    // The order of the remaining items is kept.
+    pub fn remove_by_id(&mut self, id: &Uuid) -> Result<Option<QueueItem<T>>, Error> {
+        let mut inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let mut found = None;
+        for (key, group) in inner.groups.iter_mut() {
+            if let Some(position) = group.items.iter().position(|(_, item)| item.id == *id) {
+                let item = group.items.remove(position).map(|(_, item)| item);
+                found = Some((key.clone(), item, group.items.is_empty()));
+                break;
+            }
+        }
+
+        match found {
+            Some((key, item, is_empty)) => {
+                if is_empty {
+                    inner.groups.remove(&key);
                 }
-                return Ok(Some(q));
-            } else {
-                failures.push(q);
+                Ok(item)
+            }
+            None => Ok(None),
+        }
+    }
+
+    // Changes a specific item without moving it in the queue.
+    // The change must not touch the required tags, since that would move the item to another group.
+    // Returns the changed item, or None if the item isn't in the queue.
+    pub fn update_by_id<F>(&mut self, id: &Uuid, change: F) -> Result<Option<QueueItem<T>>, Error>
+        where F: FnOnce(&mut QueueItem<T>) {
+        let mut inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for group in inner.groups.values_mut() {
+            if let Some((_, item)) = group.items.iter_mut().find(|(_, item)| item.id == *id) {
+                change(item);
+                return Ok(Some(item.clone()));
             }
         }
 
         Ok(None)
     }
 
-    pub fn get_content(&mut self) -> Result<Vec<QueueItem<T>>, Error> {
-        let items: Vec<QueueItem<T>> = self.receiver.try_iter().collect();
-        // Add all the items back again
-        for item in &items {
-            self.sender.send(item.to_owned());
+    // Removes every item, returning how many there were
+    pub fn clear(&mut self) -> Result<usize, Error> {
+        match self.inner.lock() {
+            Ok(mut inner) => Ok(inner.groups.drain().map(|(_, group)| group.items.len()).sum()),
+            Err(_) => Err(Error::QueueCorrupted),
         }
-        Ok(items)
     }
+
+    pub fn len(&self) -> Result<usize, Error> {
+        match self.inner.lock() {
+            Ok(inner) => Ok(inner.groups.values().map(|group| group.items.len()).sum()),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // How many items requiring exactly the given tags are queued
+    pub fn len_with_tags(&self, tags: &Tags) -> Result<usize, Error> {
+        match self.inner.lock() {
+            Ok(inner) => Ok(inner.groups.get(&tags.to_sorted_vec()).map(|group| group.items.len()).unwrap_or(0)),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // How many queued items a worker with the given capabilities could be handed,
+    // using the same rule as when popping
+    pub fn len_handled_by(&self, capabilities: &Tags) -> Result<usize, Error> {
+        match self.inner.lock() {
+            Ok(inner) => Ok(inner
+                .groups
+                .values()
+                .filter(|group| capabilities.is_superset(&group.tags))
+                .map(|group| group.items.len())
+                .sum()),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // The average number of distinct tag sets per pop that the worker couldn't handle.
+    // A queue with many different tag sets is fine as long as the workers can handle them,
+    // but a consistently high number means the items in the queue doesn't
+    // match the capabilities of the workers asking for them
+    pub fn average_scan_depth(&self) -> f64 {
+        self.scan_depth.get()
+    }
+
+    pub fn get_content(&self) -> Result<Vec<QueueItem<T>>, Error> {
+        let inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        let mut items: Vec<&(u64, QueueItem<T>)> = inner
+            .groups
+            .values()
+            .flat_map(|group| group.items.iter())
+            .collect();
+        items.sort_by_key(|(sequence, _)| *sequence);
+
+        Ok(items.into_iter().map(|(_, item)| item.clone()).collect())
+    }
+}
+
+// Finds the group with the oldest item that can be handled,
+// or the newest if the queue is last in first out.
+// Returns the key of the group, and how many groups couldn't be handled.
+fn next_group<T: Send + Clone>(inner: &InnerQueue<T>, capabilities: &Tags, order: QueueOrder) -> (Option<Vec<String>>, usize) {
+    let mut best: Option<(u64, &Vec<String>)> = None;
+    let mut mismatched = 0;
+    for (key, group) in inner.groups.iter() {
+        if !capabilities.is_superset(&group.tags) {
+            mismatched += 1;
+            continue;
+        }
+        let candidate = match order {
+            QueueOrder::Fifo => group.items.front(),
+            QueueOrder::Lifo => group.items.back(),
+        };
+        if let Some((sequence, _)) = candidate {
+            match (best, order) {
+                (Some((best_sequence, _)), QueueOrder::Fifo) if best_sequence < *sequence => {}
+                (Some((best_sequence, _)), QueueOrder::Lifo) if best_sequence > *sequence => {}
+                _ => best = Some((*sequence, key)),
+            }
+        }
+    }
+    (best.map(|(_, key)| key.clone()), mismatched)
 }
 
 #[cfg(test)]
@@ -147,6 +357,170 @@ mod tests {
         assert_eq!(q.pop(&Tags::new()).unwrap().unwrap().data, "baz");
     }
 
+    #[test]
+    fn tracks_scan_depth() {
+        let mut q = Queue::new();
+
+        for i in 0..100 {
+            q.enqueue(QueueItem::new("foo", Tags::from(vec![format!("foo{}", i)]), Priority::High)).unwrap();
+        }
+        q.enqueue(QueueItem::new("bar", Tags::from(vec!["bar"]), Priority::High)).unwrap();
+
+        assert_eq!(q.pop(&Tags::from(vec!["bar"])).unwrap().unwrap().data, "bar");
+        assert!(q.pop(&Tags::from(vec!["bar"])).unwrap().is_none());
+
+        assert_eq!(q.average_scan_depth(), 100.0);
+        assert_eq!(q.get_content().unwrap().len(), 100);
+    }
+
+    #[test]
+    fn scan_depth_ignores_tag_sets_the_worker_can_handle() {
+        let mut q = Queue::new();
+
+        for i in 0..100 {
+            q.enqueue(QueueItem::new("foo", Tags::from(vec![format!("foo{}", i % 10)]), Priority::High)).unwrap();
+        }
+
+        let capabilities = Tags::from((0..10).map(|i| format!("foo{}", i)).collect::<Vec<String>>());
+        while q.pop(&capabilities).unwrap().is_some() {}
+
+        assert_eq!(q.average_scan_depth(), 0.0);
+    }
+
+    #[test]
+    fn remove_by_id_keeps_the_order() {
+        let mut q = Queue::new();
+
+        let items: Vec<QueueItem<&str>> = vec!["foo", "bar", "baz", "qux"]
+            .into_iter()
+            .map(|data| QueueItem::new(data, Tags::new(), Priority::High))
+            .collect();
+        for item in &items {
+            q.enqueue(item.clone()).unwrap();
+        }
+
+        assert_eq!(q.remove_by_id(&items[1].id).unwrap(), Some(items[1].clone()));
+        assert_eq!(q.remove_by_id(&items[1].id).unwrap(), None);
+
+        let remaining: Vec<&str> = q.get_content().unwrap().iter().map(|item| item.data).collect();
+        assert_eq!(remaining, vec!["foo", "baz", "qux"]);
+    }
+
+    #[test]
+    fn update_by_id_keeps_the_position() {
+        let mut q = Queue::new();
+        let first = QueueItem::new("foo", Tags::from(vec!["a"]), Priority::Low);
+        let second = QueueItem::new("bar", Tags::from(vec!["a"]), Priority::Low);
+        let id = first.id;
+        q.enqueue(first).unwrap();
+        q.enqueue(second).unwrap();
+
+        let updated = q.update_by_id(&id, |item| item.data = "baz").unwrap().unwrap();
+        assert_eq!(updated.data, "baz");
+        assert!(q.update_by_id(&Uuid::new_v4(), |item| item.data = "qux").unwrap().is_none());
+
+        let capabilities = Tags::from(vec!["a"]);
+        assert_eq!(q.pop(&capabilities).unwrap().unwrap().data, "baz");
+        assert_eq!(q.pop(&capabilities).unwrap().unwrap().data, "bar");
+    }
+
+    #[test]
+    fn remove_by_id_removes_empty_groups() {
+        let mut q = Queue::new();
+
+        let item = QueueItem::new("foo", Tags::from(vec!["foo"]), Priority::High);
+        q.enqueue(item.clone()).unwrap();
+        q.enqueue(QueueItem::new("bar", Tags::from(vec!["bar"]), Priority::High)).unwrap();
+
+        q.remove_by_id(&item.id).unwrap();
+        assert!(q.pop(&Tags::from(vec!["foo"])).unwrap().is_none());
+        assert_eq!(q.pop(&Tags::from(vec!["bar"])).unwrap().unwrap().data, "bar");
+    }
+
+    // Finds the first item that can be handled the slow way, by checking every single item
+    fn linear_pop(items: &mut Vec<QueueItem<String>>, capabilities: &Tags) -> Option<QueueItem<String>> {
+        let position = items.iter().position(|item| item.can_be_handled_by(capabilities))?;
+        Some(items.remove(position))
+    }
+
+    #[test]
+    fn indexed_pop_matches_linear_scan() {
+        let mut q = Queue::new();
+        let mut reference = Vec::new();
+
+        let tag_sets: Vec<Vec<&str>> = vec![vec![], vec!["a"], vec!["b"], vec!["a", "b"], vec!["c"], vec!["a", "c"]];
+
+        // A simple deterministic pseudo random sequence
+        let mut seed: u64 = 42;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+
+        for i in 0..500 {
+            let tags = Tags::from(tag_sets[next() % tag_sets.len()].clone());
+            let item = QueueItem::new(format!("item{}", i), tags, Priority::High);
+            reference.push(item.clone());
+            q.enqueue(item).unwrap();
+        }
+
+        for _ in 0..600 {
+            let capabilities = Tags::from(tag_sets[next() % tag_sets.len()].clone());
+            let expected = linear_pop(&mut reference, &capabilities).map(|item| item.id);
+            let actual = q.pop(&capabilities).unwrap().map(|item| item.id);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn peek_returns_the_item_pop_takes_without_removing_it() {
+        for order in vec![QueueOrder::Fifo, QueueOrder::Lifo] {
+            let mut q = Queue::with_order(order);
+            q.enqueue(QueueItem::new("foo1", Tags::from(vec!["a"]), Priority::High)).unwrap();
+            q.enqueue(QueueItem::new("foo2", Tags::new(), Priority::High)).unwrap();
+            q.enqueue(QueueItem::new("foo3", Tags::from(vec!["a"]), Priority::High)).unwrap();
+
+            for capabilities in vec![Tags::new(), Tags::from(vec!["a"])] {
+                while let Some(peeked) = q.peek(&capabilities).unwrap() {
+                    let len = q.len().unwrap();
+                    assert_eq!(q.peek(&capabilities).unwrap().unwrap().id, peeked.id);
+                    assert_eq!(q.len().unwrap(), len);
+                    assert_eq!(q.pop(&capabilities).unwrap().unwrap().id, peeked.id);
+                }
+                assert!(q.pop(&capabilities).unwrap().is_none());
+            }
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn pop_time_does_not_depend_on_non_matching_items() {
+        use std::time::{Duration, Instant};
+
+        fn time_pops(non_matching: usize) -> Duration {
+            let mut q = Queue::new();
+            for _ in 0..non_matching {
+                q.enqueue(QueueItem::new("foo", Tags::from(vec!["foo"]), Priority::High)).unwrap();
+            }
+            for _ in 0..1000 {
+                q.enqueue(QueueItem::new("bar", Tags::from(vec!["bar"]), Priority::High)).unwrap();
+            }
+
+            let capabilities = Tags::from(vec!["bar"]);
+            let start = Instant::now();
+            for _ in 0..1000 {
+                assert_eq!(q.pop(&capabilities).unwrap().unwrap().data, "bar");
+            }
+            start.elapsed()
+        }
+
+        let shallow = time_pops(10);
+        let deep = time_pops(100000);
+        println!("Shallow: {:?}, deep: {:?}", shallow, deep);
+
+        assert!(deep < shallow * 10);
+    }
+
     pub fn can_iterate_in_order() {
         let mut q = Queue::new();
 
@@ -161,4 +535,25 @@ mod tests {
         assert_eq!(content.get(1).unwrap().data, "foo2");
         assert_eq!(content.get(2).unwrap().data, "foo3");
     }
+
+    fn pop_three(order: QueueOrder) -> Vec<&'static str> {
+        let mut q = Queue::with_order(order);
+        q.enqueue(QueueItem::new("foo1", Tags::new(), Priority::High)).unwrap();
+        // Items in other tag groups are ordered the same way
+        q.enqueue(QueueItem::new("foo2", Tags::from(vec!["bar"]), Priority::High)).unwrap();
+        q.enqueue(QueueItem::new("foo3", Tags::new(), Priority::High)).unwrap();
+
+        let capabilities = Tags::from(vec!["bar"]);
+        (0..3).map(|_| q.pop(&capabilities).unwrap().unwrap().data).collect()
+    }
+
+    #[test]
+    fn pops_first_in_first_out() {
+        assert_eq!(pop_three(QueueOrder::Fifo), vec!["foo1", "foo2", "foo3"]);
+    }
+
+    #[test]
+    fn pops_last_in_first_out() {
+        assert_eq!(pop_three(QueueOrder::Lifo), vec!["foo3", "foo2", "foo1"]);
+    }
 }
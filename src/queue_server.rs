@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::collections::VecDeque;
 use std::convert;
 use std::fmt;
@@ -6,25 +7,38 @@ use std::fs::{create_dir_all, File, OpenOptions};
 use std::io::BufWriter;
 use std::io::Error as IOError;
 use std::io::Write;
+use std::mem;
 use std::path;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::Condvar;
 use std::sync::Mutex;
 use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use bincode::{deserialize, Error as BinCodeError, serialize};
-use crossbeam::channel::{bounded, Receiver, Sender, TrySendError};
-use log::{debug, error};
+use bincode::{Error as BinCodeError, serialized_size};
+use crossbeam::channel::{bounded, never, Receiver, Sender, TrySendError};
+use log::{debug, error, info, warn};
+use regex::Regex;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_derive::{Deserialize, Serialize};
+use tracing::debug_span;
 use uuid::Uuid;
 
-use crate::internal_queue_file_manager::{Error as InternalQueueFileManagerError, InternalQueueFileManager};
+use crate::config::{Config, PriorityAging, QueueOrder, UnroutablePolicy};
+use crate::events::{EventSubscribers, QueueEvent};
+use crate::hooks::{EventHook, NoopHook};
+use crate::idle_workers::{IdleWorkers, RegistryCorrupted};
+use crate::internal_queue_file_manager::{Error as InternalQueueFileManagerError, InternalQueueFileManager, is_storage_full, StorageFileSizes};
+use crate::metrics::{Counter, MovingAverage, RateMeter};
 use crate::models::Priority;
 use crate::models::QueueItem;
-use crate::models::Tags;
+use crate::models::{TagMatch, Tags};
+use crate::time_helpers::duration_to_millis;
+use crate::validation::PayloadValidator;
 
 use super::queue;
 
@@ -34,7 +48,37 @@ pub enum Error {
     IOError(IOError),
     MutexCorrupted,
     FailedToSerializeWorkItem(BinCodeError),
-    GarbageCollectionFailed
+    GarbageCollectionFailed,
+    CapabilityNotAllowed(String),
+    // The capabilities doesn't match the configured tag pattern
+    MalformedCapabilities(Vec<String>),
+    EmptyCapability,
+    // The payload was rejected by the payload validator
+    InvalidPayload(String),
+    // The disk is full, so nothing can be saved
+    StorageFull,
+    PopCancelled,
+    // Nothing can be popped until some of the processing tasks are acknowledged
+    TooManyProcessing,
+    // The stored tasks are still being loaded, so nothing can be popped yet
+    NotReady,
+    ReadOnly,
+    UnsupportedStorageVersion(u8),
+    // Files can't be created in the storage folder
+    StorageNotWritable(path::PathBuf, IOError),
+    // An item in an atomic batch was rejected, so nothing in the batch was enqueued
+    BatchItemRejected(usize, Box<Error>),
+    // Queue names can only contain letters, digits, dashes and underscores
+    InvalidQueueName(String),
+    // No queue with the name has been created
+    QueueNotFound(String),
+    // The queue can't be created, since the maximum number of named queues exist already
+    TooManyQueues(usize),
+    // The priority level is above the configured priority bands. Has the level and the number of bands.
+    UnknownPriority(u32, usize),
+    // The task requires a capability no worker is allowed to have, so it would never be processed.
+    // Unlike a task no connected worker can handle, this won't change by waiting.
+    Unroutable(String),
 }
 
 impl convert::From<IOError> for Error {
@@ -50,10 +94,19 @@ impl convert::From<InternalQueueFileManagerError> for Error {
             InternalQueueFileManagerError::FailedToSerializeWorkItem(e) => Error::FailedToSerializeWorkItem(e),
             InternalQueueFileManagerError::MutexCorrupted => Error::MutexCorrupted,
             InternalQueueFileManagerError::GarbageCollectionFailed => Error::GarbageCollectionFailed,
+            InternalQueueFileManagerError::ReadOnly => Error::ReadOnly,
+            InternalQueueFileManagerError::UnsupportedFormatVersion(version) => Error::UnsupportedStorageVersion(version),
+            InternalQueueFileManagerError::NotWritable(path, e) => Error::StorageNotWritable(path, e),
         }
     }
 }
 
+impl convert::From<RegistryCorrupted> for Error {
+    fn from(_: RegistryCorrupted) -> Self {
+        Error::QueueCorrupted
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -65,30 +118,96 @@ impl fmt::Display for Error {
             Error::FailedToSerializeWorkItem(e) => {
                 write!(f, "Failed to serialize work item: {}", e)
             },
-            Error::GarbageCollectionFailed => write!(f, "Garbage collection failed")
+            Error::GarbageCollectionFailed => write!(f, "Garbage collection failed"),
+            Error::CapabilityNotAllowed(capability) => {
+                write!(f, "Capability '{}' is not allowed", capability)
+            }
+            Error::MalformedCapabilities(capabilities) => {
+                write!(f, "Capabilities doesn't match the required pattern: {}", capabilities.join(", "))
+            }
+            Error::EmptyCapability => write!(f, "Capabilities can't be empty"),
+            Error::InvalidPayload(reason) => write!(f, "Invalid payload: {}", reason),
+            Error::StorageFull => write!(f, "Storage is full, try again later"),
+            Error::PopCancelled => write!(f, "Pop was cancelled"),
+            Error::TooManyProcessing => write!(f, "Too many tasks are being processed"),
+            Error::NotReady => write!(f, "The stored tasks are still being loaded"),
+            Error::ReadOnly => write!(f, "Queue is opened read-only"),
+            Error::StorageNotWritable(path, e) => {
+                write!(f, "Storage folder {} is not writable: {}. Make sure the server is allowed to write to it, \
+                    and that it's not on a read-only mount", path.display(), e)
+            }
+            Error::BatchItemRejected(index, e) => {
+                write!(f, "Item {} in the batch was rejected, so nothing was enqueued: {}", index, e)
+            }
+            Error::Unroutable(capability) => {
+                write!(f, "Capability '{}' is denied for all workers, so the task can never be processed", capability)
+            }
+            Error::InvalidQueueName(name) => {
+                write!(f, "Invalid queue name '{}', only letters, digits, '-' and '_' are allowed", name)
+            }
+            Error::QueueNotFound(name) => write!(f, "Queue '{}' doesn't exist", name),
+            Error::TooManyQueues(max) => {
+                write!(f, "Can't create another queue, the server is limited to {} named queues", max)
+            }
+            Error::UnknownPriority(level, bands) => {
+                write!(f, "Priority level {} doesn't exist, the server has {} priority bands", level, bands)
+            }
+            Error::UnsupportedStorageVersion(version) => {
+                write!(f, "Storage was written in format version {}, which this version doesn't support", version)
+            }
         }
     }
 }
 
 #[derive(Clone)]
 struct InternalQueueManager<T: Send + Clone> {
-    high_priority_queue: queue::Queue<T>,
-    low_priority_queue: queue::Queue<T>,
+    // One queue per priority level, popped from the highest level down.
+    // Shared between clones, so levels created later are seen by all of them.
+    queues: Arc<RwLock<BTreeMap<u32, queue::Queue<T>>>>,
+    order: QueueOrder,
+    // If set items are popped by their aged priority
+    priority_aging: Option<PriorityAging>,
 }
 
 impl<T: Send + Clone> InternalQueueManager<T> {
-    fn new() -> InternalQueueManager<T> {
+    fn new(order: QueueOrder, priority_aging: Option<PriorityAging>) -> InternalQueueManager<T> {
+        let mut queues = BTreeMap::new();
+        queues.insert(Priority::Low.level(), queue::Queue::with_order(order));
+        queues.insert(Priority::High.level(), queue::Queue::with_order(order));
         InternalQueueManager {
-            high_priority_queue: queue::Queue::new(),
-            low_priority_queue: queue::Queue::new(),
+            queues: Arc::new(RwLock::new(queues)),
+            order,
+            priority_aging,
+        }
+    }
+
+    // The queue of the priority level, created if it's the first item with that level
+    fn queue_for(&self, priority: &Priority) -> Result<queue::Queue<T>, Error> {
+        let level = priority.level();
+        match self.queues.read() {
+            Ok(queues) => {
+                if let Some(queue) = queues.get(&level) {
+                    return Ok(queue.clone());
+                }
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+        match self.queues.write() {
+            Ok(mut queues) => Ok(queues.entry(level).or_insert_with(|| queue::Queue::with_order(self.order)).clone()),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Every queue, from the highest priority level down
+    fn queues(&self) -> Result<Vec<queue::Queue<T>>, Error> {
+        match self.queues.read() {
+            Ok(queues) => Ok(queues.values().rev().cloned().collect()),
+            Err(_) => Err(Error::QueueCorrupted),
         }
     }
 
     fn enqueue(&mut self, item: QueueItem<T>) -> Result<(), Error> {
-        let result = match item.priority {
-            Priority::Low => self.low_priority_queue.enqueue(item),
-            Priority::High => self.high_priority_queue.enqueue(item),
-        };
+        let result = self.queue_for(&item.priority)?.enqueue(item);
 
         if result.is_err() {
             error!("Error when inserting into queue {}", result.unwrap_err());
@@ -98,349 +217,5413 @@ impl<T: Send + Clone> InternalQueueManager<T> {
         }
     }
 
-    fn pop(&mut self, capabilities: Vec<String>) -> Result<Option<QueueItem<T>>, Error> {
+    fn pop(&mut self, capabilities: Vec<String>, now: SystemTime) -> Result<Option<QueueItem<T>>, Error> {
         let tags = Tags::from(capabilities);
+        if let Some(aging) = self.priority_aging {
+            return self.pop_aged(&tags, &aging, now);
+        }
 
         // Try the queues in order
-        match self.high_priority_queue.pop(&tags) {
-            Err(e) => Err(Error::QueueCorrupted),
-            Ok(Some(entry)) => Ok(Some(entry)),
-            Ok(None) => match self.low_priority_queue.pop(&tags) {
-                Err(e) => Err(Error::QueueCorrupted),
-                Ok(Some(entry)) => Ok(Some(entry)),
-                Ok(None) => Ok(None),
+        for mut queue in self.queues()? {
+            if let Some(entry) = queue.pop(&tags).map_err(|_| Error::QueueCorrupted)? {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+
+    // Pops the item with the highest aged priority among the next item of every level.
+    // Items with the same aged priority are popped in queue order.
+    // When the queue is last in first out the oldest item of every level is a candidate too,
+    // otherwise the items that have waited the longest would never be looked at.
+    fn pop_aged(&mut self, tags: &Tags, aging: &PriorityAging, now: SystemTime) -> Result<Option<QueueItem<T>>, Error> {
+        loop {
+            let mut next: Option<(u32, u64, Uuid, queue::Queue<T>)> = None;
+            for queue in self.queues()? {
+                let mut candidates = vec![queue.peek(tags).map_err(|_| Error::QueueCorrupted)?];
+                if self.order == QueueOrder::Lifo {
+                    candidates.push(queue.peek_oldest(tags).map_err(|_| Error::QueueCorrupted)?);
+                }
+                for item in candidates.into_iter().flatten() {
+                    let waited = item.enqueued_at
+                        .and_then(|enqueued_at| now.duration_since(enqueued_at).ok())
+                        .unwrap_or_default();
+                    let level = aging.effective_level(item.priority.level(), waited);
+                    let better = match &next {
+                        Some((best_level, best_sequence, _, _)) => level > *best_level || (level == *best_level && match self.order {
+                            QueueOrder::Fifo => item.sequence < *best_sequence,
+                            QueueOrder::Lifo => item.sequence > *best_sequence,
+                        }),
+                        None => true,
+                    };
+                    if better {
+                        next = Some((level, item.sequence, item.id, queue.clone()));
+                    }
+                }
+            }
+
+            let (_, _, id, mut queue) = match next {
+                Some(next) => next,
+                None => return Ok(None),
+            };
+            // Another pop might have taken the item since it was looked at, in which case look again
+            if let Some(item) = queue.remove_by_id(&id).map_err(|_| Error::QueueCorrupted)? {
+                return Ok(Some(item));
+            }
+        }
+    }
+
+    // The item pop would take next, only looking at the given priority if set
+    fn peek(&self, tags: &Tags, priority: Option<&Priority>) -> Result<Option<QueueItem<T>>, Error> {
+        let queues = match priority {
+            Some(priority) => match self.queues.read() {
+                Ok(queues) => queues.get(&priority.level()).cloned().into_iter().collect(),
+                Err(_) => return Err(Error::QueueCorrupted),
             },
+            None => self.queues()?,
+        };
+        for queue in queues {
+            if let Some(item) = queue.peek(tags).map_err(|_| Error::QueueCorrupted)? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
+    fn remove_by_id(&mut self, id: &Uuid) -> Result<Option<QueueItem<T>>, Error> {
+        for mut queue in self.queues()? {
+            if let Some(item) = queue.remove_by_id(id).map_err(|_| Error::QueueCorrupted)? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
+    fn update_by_id<F>(&mut self, id: &Uuid, change: F) -> Result<Option<QueueItem<T>>, Error>
+        where F: FnOnce(&mut QueueItem<T>) {
+        // The change is only applied to the queue that has the item
+        let mut change = Some(change);
+        let mut apply = |item: &mut QueueItem<T>| {
+            if let Some(change) = change.take() {
+                change(item);
+            }
+        };
+
+        for mut queue in self.queues()? {
+            if let Some(item) = queue.update_by_id(id, &mut apply).map_err(|_| Error::QueueCorrupted)? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
+    fn len_with_tags(&self, tags: &Tags) -> Result<usize, Error> {
+        let mut len = 0;
+        for queue in self.queues()? {
+            len += queue.len_with_tags(tags).map_err(|_| Error::QueueCorrupted)?;
+        }
+        Ok(len)
+    }
+
+    fn len_handled_by(&self, capabilities: &Tags) -> Result<usize, Error> {
+        let mut len = 0;
+        for queue in self.queues()? {
+            len += queue.len_handled_by(capabilities).map_err(|_| Error::QueueCorrupted)?;
+        }
+        Ok(len)
+    }
+
+    fn get_all(&self) -> Result<Vec<QueueItem<T>>, Error> {
+        let mut all = Vec::new();
+        for queue in self.queues()? {
+            all.extend(queue.get_content().map_err(|_| Error::QueueCorrupted)?);
+        }
+        Ok(all)
+    }
+
+    // Removes every item from every level, returning how many there were
+    fn clear(&mut self) -> Result<usize, Error> {
+        let mut removed = 0;
+        for mut queue in self.queues()? {
+            removed += queue.clear().map_err(|_| Error::QueueCorrupted)?;
+        }
+        Ok(removed)
+    }
+
+    fn len(&self) -> Result<usize, Error> {
+        let mut len = 0;
+        for queue in self.queues()? {
+            len += queue.len().map_err(|_| Error::QueueCorrupted)?;
+        }
+        Ok(len)
+    }
+
+    // How many items are queued with at least the given priority
+    fn len_from(&self, priority: &Priority) -> Result<usize, Error> {
+        let queues = match self.queues.read() {
+            Ok(queues) => queues.range(priority.level()..).map(|(_, queue)| queue.clone()).collect::<Vec<_>>(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let mut len = 0;
+        for queue in queues {
+            len += queue.len().map_err(|_| Error::QueueCorrupted)?;
+        }
+        Ok(len)
+    }
+
+    // How many items are in each of the queues, from the highest priority level down
+    fn len_by_level(&self) -> Result<Vec<(u32, usize)>, Error> {
+        let queues = match self.queues.read() {
+            Ok(queues) => queues.iter().rev().map(|(level, queue)| (*level, queue.clone())).collect::<Vec<_>>(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let mut lens = Vec::new();
+        for (level, queue) in queues {
+            lens.push((level, queue.len().map_err(|_| Error::QueueCorrupted)?));
+        }
+        Ok(lens)
+    }
+
+    fn scan_depth(&self) -> ScanDepth {
+        let by_level: Vec<(u32, f64)> = match self.queues.read() {
+            Ok(queues) => queues.iter().rev().map(|(level, queue)| (*level, queue.average_scan_depth())).collect(),
+            Err(_) => Vec::new(),
+        };
+        let depth = |priority: Priority| {
+            by_level
+                .iter()
+                .find(|(level, _)| *level == priority.level())
+                .map_or(0.0, |(_, depth)| *depth)
+        };
+        ScanDepth {
+            high_priority: depth(Priority::High),
+            low_priority: depth(Priority::Low),
+            by_level,
+        }
+    }
+}
+
+// The average number of tag sets per pop in each of the queues that the worker couldn't handle.
+// Used as a signal for how well the queued items match the workers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScanDepth {
+    // The scan depth of each priority level, from the highest level down
+    pub by_level: Vec<(u32, f64)>,
+    pub high_priority: f64,
+    pub low_priority: f64,
+}
+
+// Where a page of queued tasks ended, so the next page can continue from there.
+// Tasks are paged in the order they were enqueued, and the id breaks the tie
+// between tasks saved before sequence numbers were recorded, which all have 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PageCursor {
+    sequence: u64,
+    id: Uuid,
+}
+
+impl PageCursor {
+    fn of<T: Send + Clone>(item: &QueueItem<T>) -> PageCursor {
+        PageCursor {
+            sequence: item.sequence,
+            id: item.id,
+        }
+    }
+}
+
+impl fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.sequence, self.id)
+    }
+}
+
+impl FromStr for PageCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<PageCursor, String> {
+        let invalid = || format!("'{}' is not a valid cursor", s);
+        let mut parts = s.splitn(2, ':');
+        let sequence = parts.next().and_then(|sequence| sequence.parse().ok()).ok_or_else(invalid)?;
+        let id = parts.next().and_then(|id| Uuid::parse_str(id).ok()).ok_or_else(invalid)?;
+        Ok(PageCursor { sequence, id })
+    }
+}
+
+// What happens to the tasks being processed when the server is paused
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProcessingOnPause {
+    // The workers can still acknowledge or fail the tasks they are processing
+    Leave,
+    // The tasks are put back in the queue, so nothing is lost if the workers are stopped too
+    RequeueAll,
+}
+
+// An item that has been handed to a worker, but not yet acknowledged
+#[derive(Clone, Debug)]
+pub struct InFlightItem<T: Send + Clone> {
+    pub item: QueueItem<T>,
+    pub popped_at: Instant,
+    // The worker processing the item, if known
+    pub worker: Option<String>,
+    // The item is put back in the queue if it's still processing after this long
+    pub visibility_timeout: Option<Duration>,
+}
+
+impl<T: Send + Clone> InFlightItem<T> {
+    // How long the item has been processing
+    pub fn age(&self) -> Duration {
+        self.popped_at.elapsed()
+    }
+
+    fn is_timed_out(&self, now: Instant) -> bool {
+        match self.visibility_timeout {
+            Some(timeout) => now.duration_since(self.popped_at) >= timeout,
+            None => false,
+        }
+    }
+}
+
+// A failed task kept back for the worker that failed it
+#[derive(Clone, Debug)]
+struct ReservedItem<T: Send + Clone> {
+    item: QueueItem<T>,
+    worker: String,
+    until: Instant,
+}
+
+// A task a worker has declined, kept back for the other workers
+#[derive(Clone, Debug)]
+struct DeclinedItem<T: Send + Clone> {
+    item: QueueItem<T>,
+    worker: String,
+    until: Instant,
+}
+
+// A task a worker is deciding whether to take.
+// Hidden from other workers until the reservation expires.
+#[derive(Clone, Debug)]
+struct Reservation<T: Send + Clone> {
+    item: QueueItem<T>,
+    // Only the worker that reserved the task can confirm it
+    worker: Option<String>,
+    until: Instant,
+}
+
+// Tasks that are only stored on disk, because the memory limit was reached,
+// in the order they were enqueued
+struct SpilledItems {
+    high_priority: VecDeque<SpilledItem>,
+    low_priority: VecDeque<SpilledItem>,
+}
+
+// The tags are kept, so a pop can find a spilled task it can take without reading the others
+struct SpilledItem {
+    id: Uuid,
+    required_tags: Tags,
+}
+
+impl SpilledItems {
+    fn for_priority(&mut self, priority: &Priority) -> &mut VecDeque<SpilledItem> {
+        match priority {
+            Priority::High | Priority::Level(_) => &mut self.high_priority,
+            Priority::Low => &mut self.low_priority,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.high_priority.len() + self.low_priority.len()
+    }
+}
+
+// How fast tasks requiring a specific set of tags are being processed
+struct ProcessingRate {
+    last_acknowledged: Instant,
+    // The average milliseconds between acknowledgements while there is a backlog
+    interval: MovingAverage,
+}
+
+// How long to turn away new tasks after running out of disk space,
+// before trying to save them again
+const STORAGE_FULL_BACKOFF: Duration = Duration::from_secs(5);
+
+// How long a declined task is kept from the worker that declined it,
+// before it's put back in the queue for anyone
+const DECLINED_REDELIVERY_WINDOW: Duration = Duration::from_secs(1);
+
+// How often processing tasks are checked for having passed their visibility timeout
+const VISIBILITY_SCAN_INTERVAL: Duration = Duration::from_millis(100);
+
+// How often the dead letters are checked for being past the retention limits
+const DEAD_LETTER_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+// How many completed ids to remember, to recognize late acknowledgements
+const RECENTLY_COMPLETED_LIMIT: usize = 10000;
+
+// The ids of the most recently completed tasks.
+// Needed since the completed index is cleared by garbage collection.
+struct RecentlyCompleted {
+    ids: HashSet<Uuid>,
+    order: VecDeque<Uuid>,
+}
+
+impl RecentlyCompleted {
+    fn new() -> RecentlyCompleted {
+        RecentlyCompleted {
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn insert(&mut self, id: Uuid) {
+        if self.ids.insert(id) {
+            self.order.push_back(id);
+        }
+        while self.order.len() > RECENTLY_COMPLETED_LIMIT {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+    }
+
+    fn contains(&self, id: &Uuid) -> bool {
+        self.ids.contains(id)
+    }
+}
+
+// The idempotency keys of the tasks that hasn't been completed yet,
+// so enqueuing with the same key again returns the existing task
+struct IdempotencyKeys {
+    ids: HashMap<String, Uuid>,
+    keys: HashMap<Uuid, String>,
+}
+
+impl IdempotencyKeys {
+    fn new() -> IdempotencyKeys {
+        IdempotencyKeys {
+            ids: HashMap::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, key: String, id: Uuid) {
+        self.keys.insert(id, key.clone());
+        self.ids.insert(key, id);
+    }
+
+    fn get(&self, key: &str) -> Option<Uuid> {
+        self.ids.get(key).cloned()
+    }
+
+    // Frees the key of the task, so it can be used again
+    fn remove(&mut self, id: &Uuid) {
+        if let Some(key) = self.keys.remove(id) {
+            self.ids.remove(&key);
+        }
+    }
+}
+
+// The outcome of acknowledging a task
+#[derive(Debug, Clone, PartialEq)]
+pub enum Acknowledgement {
+    Completed,
+    // The task was completed earlier, so nothing was changed
+    AlreadyCompleted,
+    // The task was put back in the queue when the server was paused,
+    // so it will be handed out again instead
+    Requeued,
+    // The task isn't being processed, and hasn't been completed recently
+    NotProcessing,
+}
+
+// Totals of everything that has happened since the server was started
+#[derive(Clone)]
+struct Counters {
+    enqueued: Counter,
+    popped: Counter,
+    acknowledged: Counter,
+    failed: Counter,
+    // Tasks dropped because they expired before they were popped
+    expired: Counter,
+    // Tasks moved to the dead letter queue because they failed too many times
+    dead_lettered: Counter,
+    garbage_collections: Counter,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CounterTotals {
+    pub enqueued: usize,
+    pub popped: usize,
+    pub acknowledged: usize,
+    pub failed: usize,
+    pub expired: usize,
+    pub dead_lettered: usize,
+    pub garbage_collections: usize,
+}
+
+impl Counters {
+    fn new() -> Counters {
+        Counters {
+            enqueued: Counter::new(),
+            popped: Counter::new(),
+            acknowledged: Counter::new(),
+            failed: Counter::new(),
+            expired: Counter::new(),
+            dead_lettered: Counter::new(),
+            garbage_collections: Counter::new(),
+        }
+    }
+
+    fn totals(&self) -> CounterTotals {
+        CounterTotals {
+            enqueued: self.enqueued.get(),
+            popped: self.popped.get(),
+            acknowledged: self.acknowledged.get(),
+            failed: self.failed.get(),
+            expired: self.expired.get(),
+            dead_lettered: self.dead_lettered.get(),
+            garbage_collections: self.garbage_collections.get(),
+        }
+    }
+}
+
+// How many tasks are waiting and being processed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    // How many tasks are waiting in memory at each priority level, from the highest level down
+    pub pending_by_level: Vec<(u32, usize)>,
+    // How many tasks are waiting in memory with high priority or above
+    pub high_priority: usize,
+    // How many tasks are waiting in memory with low priority
+    pub low_priority: usize,
+    // Every waiting task, including the ones that are only stored on disk,
+    // reserved, or on their way back to the queue
+    pub queued: usize,
+    pub processing: usize,
+    // Totals since the server was started
+    pub enqueued: usize,
+    pub acknowledged: usize,
+}
+
+// A dead letter as it's exported for analysis, see QueueServer::export_dead_letters
+#[derive(Debug, Serialize)]
+struct ExportedDeadLetter<'a, T: Serialize> {
+    id: String,
+    // How many times handling the task failed
+    attempts: u32,
+    priority_level: u32,
+    required_capabilities: Vec<String>,
+    enqueued_by: Option<&'a str>,
+    // Milliseconds since the unix epoch, if known
+    enqueued_at: Option<u64>,
+    dead_lettered_at: Option<u64>,
+    data: &'a T,
+}
+
+impl<'a, T: Send + Clone + Serialize> From<&'a QueueItem<T>> for ExportedDeadLetter<'a, T> {
+    fn from(item: &'a QueueItem<T>) -> ExportedDeadLetter<'a, T> {
+        let unix_millis = |time: SystemTime| time.duration_since(UNIX_EPOCH).ok().map(duration_to_millis);
+        ExportedDeadLetter {
+            id: item.id.to_string(),
+            attempts: item.attempts,
+            priority_level: item.priority.level(),
+            required_capabilities: item.required_tags.to_sorted_vec(),
+            enqueued_by: item.enqueued_by.as_deref(),
+            enqueued_at: item.enqueued_at.and_then(unix_millis),
+            dead_lettered_at: item.dead_lettered_at.and_then(unix_millis),
+            data: &item.data,
         }
     }
 }
 
+// A snapshot of the complete state of the server, for debugging incidents
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticDump {
+    pub uptime_milliseconds: u64,
+    pub high_priority_queued: usize,
+    pub low_priority_queued: usize,
+    pub processing: usize,
+    // How long the task that has been processing the longest has been processing
+    pub oldest_processing_milliseconds: Option<u64>,
+    pub garbage_collection_running: bool,
+    pub storage_file_sizes: StorageFileSizes,
+    pub counters: CounterTotals,
+    pub scan_depth: ScanDepth,
+    // The estimated size of the tasks queued in memory
+    pub queued_bytes: usize,
+    // How many tasks are only stored on disk because of the memory limit
+    pub spilled: usize,
+    // How many tasks can be processing at once, 0 if unlimited
+    pub max_processing: usize,
+    // How many pops are waiting for a task
+    pub waiting_pops: usize,
+}
+
 #[derive(Clone)]
 pub struct QueueServer<T: Send + Clone + Serialize + DeserializeOwned> {
     queue: InternalQueueManager<T>,
     file_manager: Arc<RwLock<InternalQueueFileManager<T>>>,
-    // Try writing to this to see if something can be send
-    waiting: Sender<QueueItem<T>>,
-    // Wait on this for push like queuing
-    wait_receive: Receiver<QueueItem<T>>,
-    processing: Arc<Mutex<HashMap<Uuid, QueueItem<T>>>>,
+    // Try writing to this to wake up a waiting pop
+    waiting: Sender<()>,
+    // Wait on this for push like queuing.
+    // Waking up only signals that something has been added, the waiter
+    // has to scan the queues itself, so it gets the highest priority item
+    wait_receive: Receiver<()>,
+    // The workers waiting for an item. New items are handed directly
+    // to one of them if it can handle the item.
+    idle_workers: IdleWorkers<T>,
+    // How many pops are currently waiting for a task
+    waiting_pops: Arc<AtomicUsize>,
+    // How many pops can wait for a task at once, unlimited if zero.
+    // Pops past that return right away, as if they weren't waiting.
+    max_waiting_pops: usize,
+    processing: Arc<Mutex<HashMap<Uuid, InFlightItem<T>>>>,
+    // The capabilities tasks may require, anything is allowed if not set
+    allowed_capabilities: Option<Tags>,
+    started_at: Instant,
+    // Signaled whenever a task is completed, so anyone waiting
+    // for the queue to become empty can check again
+    completed_signal: Arc<(Mutex<()>, Condvar)>,
+    counters: Counters,
+    // Failed tasks waiting for the worker that failed them to pop again
+    reserved: Arc<Mutex<Vec<ReservedItem<T>>>>,
+    failed_affinity_window: Duration,
+    // Declined tasks waiting for another worker to pop
+    declined: Arc<Mutex<Vec<DeclinedItem<T>>>>,
+    // Tasks reserved with reserve, waiting to be confirmed
+    reservations: Arc<Mutex<HashMap<Uuid, Reservation<T>>>>,
+    // Tasks that are not available yet, kept out of the queue until they are
+    scheduled: Arc<Mutex<Vec<QueueItem<T>>>>,
+    // Tasks requiring any of these are left in the queue
+    paused_capabilities: Arc<RwLock<HashSet<String>>>,
+    // Workers can't have these, so they are left out of the capabilities of every pop
+    denied_capabilities: HashSet<String>,
+    unroutable_policy: UnroutablePolicy,
+    hook: Arc<dyn EventHook<T>>,
+    // Everyone watching what happens to the tasks
+    events: EventSubscribers,
+    payload_validator: Option<PayloadValidator<T>>,
+    // How many tasks are popped per second, recently
+    pop_rate: RateMeter,
+    // The sequence number the next enqueued task gets
+    next_sequence: Arc<AtomicU64>,
+    // The estimated size of the tasks in the in-memory queue
+    queued_bytes: Arc<AtomicUsize>,
+    memory_limit_bytes: usize,
+    spilled: Arc<Mutex<SpilledItems>>,
+    // Set if the storage is opened read-only, in which case nothing can be changed
+    read_only: bool,
+    // Keyed by the sorted tags the tasks require
+    processing_rates: Arc<Mutex<HashMap<Vec<String>, ProcessingRate>>>,
+    recently_completed: Arc<Mutex<RecentlyCompleted>>,
+    idempotency_keys: Arc<Mutex<IdempotencyKeys>>,
+    // How often waiting pops look for tasks without being woken up
+    wait_tick: Duration,
+    tag_pattern: Option<Regex>,
+    // Set when the disk runs full. Nothing is saved until then,
+    // so clients trying again right away doesn't hammer the disk.
+    storage_full_until: Arc<Mutex<Option<Instant>>>,
+    // How many tasks can be processing at once, unlimited if zero
+    max_processing: usize,
+    // Cleared while the stored tasks are being loaded, pops are rejected until then
+    ready: Arc<AtomicBool>,
+    // Tasks with a sequence number from this were enqueued after the server started,
+    // so they are already in the queue, and are skipped when loading the stored tasks
+    first_new_sequence: u64,
+    // How long popped tasks can be processing before they are put back in the queue,
+    // unless the pop asks for another timeout
+    visibility_timeout: Option<Duration>,
+    // Set once the thread looking for timed out tasks is running
+    visibility_scanner_started: Arc<AtomicBool>,
+    // Tasks taken out of processing by the visibility scanner, waiting to be put back in the queue
+    timed_out: Arc<Mutex<Vec<QueueItem<T>>>>,
+    // The names of the priority levels tasks can be enqueued with, from level 0 up
+    priority_bands: Vec<String>,
+    // How many times a task can fail before it's moved to the dead letter queue, unlimited if zero
+    max_attempts: u32,
+    // The tasks that failed too many times, in the order they were moved there
+    dead_letters: Arc<Mutex<Vec<QueueItem<T>>>>,
+    dead_letter_retention: DeadLetterRetention,
+    // Set while the server is paused, in which case no tasks are handed out
+    paused: Arc<AtomicBool>,
+    // The tasks taken from the workers when the server was paused, until it's resumed
+    requeued_on_pause: Arc<Mutex<HashSet<Uuid>>>,
+    // The current time, replaced in tests to see how tasks age
+    clock: fn() -> SystemTime,
+}
+
+// Information about the running server, for support and debugging
+#[derive(Debug, Clone)]
+pub struct ServerInfo {
+    pub uptime: Duration,
+    pub version: &'static str,
+    // The commit the server was built from, if it was built from a git checkout
+    pub git_commit: Option<&'static str>,
+    // None if the sizes couldn't be read
+    pub storage_file_sizes: Option<StorageFileSizes>,
+    // The names of the priority levels, from level 0 up
+    pub priority_bands: Vec<String>,
+    // The priority level of tasks enqueued without a priority
+    pub default_priority: Priority,
+}
+
+// A task to enqueue, on its own or as part of a batch
+pub struct BatchItem<T: Send + Clone> {
+    pub message: T,
+    pub priority: Priority,
+    pub required_capabilities: Vec<String>,
+    pub available_at: Option<SystemTime>,
+    pub expires_at: Option<SystemTime>,
 }
 
 pub struct CreatedMessage {
     pub id: Uuid,
+    // False if the message was enqueued earlier with the same idempotency key,
+    // in which case id is the id of that message
+    pub created: bool,
 }
 
-impl<T: Send + Clone + Serialize + DeserializeOwned> QueueServer<T> {
-    pub fn new_with_filename(filename: String) -> Result<QueueServer<T>, Error> {
-        let file_manager = InternalQueueFileManager::new(filename, true)?;
-        let (sender, receiver) = bounded(0);
+// Empties a collection shared between the clones of the server, returning how many entries it had
+fn clear_shared<C: Default + IntoIterator>(collection: &Mutex<C>) -> Result<usize, Error> {
+    match collection.lock() {
+        Ok(mut collection) => Ok(mem::take(&mut *collection).into_iter().count()),
+        Err(_) => Err(Error::QueueCorrupted),
+    }
+}
 
-        return Ok(QueueServer {
-            queue: InternalQueueManager::new(),
-            file_manager: Arc::new(RwLock::new(file_manager)),
-            waiting: sender,
-            wait_receive: receiver,
-            processing: Arc::new(Mutex::new(HashMap::new())),
-        });
+// How long dead letters are kept, and how many of them
+#[derive(Debug, Clone, Copy)]
+struct DeadLetterRetention {
+    // Kept until drained if not set
+    max_age: Option<Duration>,
+    // Unlimited if not set
+    max_count: Option<usize>,
+}
+
+impl DeadLetterRetention {
+    fn from_config(config: &Config) -> DeadLetterRetention {
+        DeadLetterRetention {
+            max_age: Some(config.dead_letter_retention).filter(|age| *age > Duration::from_millis(0)),
+            max_count: Some(config.max_dead_letters).filter(|count| *count > 0),
+        }
     }
 
-    pub fn new() -> Result<QueueServer<T>, Error> {
-        QueueServer::new_with_filename("./storage/tasks".to_string())
+    fn is_enabled(&self) -> bool {
+        self.max_age.is_some() || self.max_count.is_some()
     }
+}
 
-    fn add_item_to_queue(&mut self, item: QueueItem<T>) -> Result<(), Error> {
-        match self.waiting.try_send(item) {
-            Ok(()) => Ok(()),
-            Err(TrySendError::Full(item)) => self.queue.enqueue(item),
-            Err(_) => Err(Error::QueueCorrupted),
+// Deletes the dead letters that are older than the retention allows, and the oldest ones
+// past the max count. Returns how many were deleted.
+fn purge_dead_letters<T>(
+    dead_letters: &Mutex<Vec<QueueItem<T>>>,
+    file_manager: &RwLock<InternalQueueFileManager<T>>,
+    retention: DeadLetterRetention,
+    now: SystemTime,
+) -> Result<usize, Error>
+    where T: Send + Clone + Serialize + DeserializeOwned {
+    let mut dead_letters = match dead_letters.lock() {
+        Ok(dead_letters) => dead_letters,
+        Err(_) => return Err(Error::QueueCorrupted),
+    };
+
+    let mut kept: Vec<QueueItem<T>> = match retention.max_age {
+        // Dead letters saved by older versions don't know when they were dead lettered,
+        // so they are aged by when they were enqueued, if that is known
+        Some(max_age) => dead_letters
+            .iter()
+            .filter(|item| match item.dead_lettered_at.or(item.enqueued_at) {
+                Some(at) => at + max_age > now,
+                None => true,
+            })
+            .cloned()
+            .collect(),
+        None => dead_letters.clone(),
+    };
+    if let Some(max_count) = retention.max_count {
+        if kept.len() > max_count {
+            let excess = kept.len() - max_count;
+            kept.drain(..excess);
         }
     }
 
-    // Enqueues another item in the queue.
-    // The generated id of the enqueued item is returned
-    pub fn enqueue(
-        &mut self,
-        message: T,
-        priority: Priority,
-        required_capabilities: Vec<String>,
-    ) -> Result<CreatedMessage, Error> {
-        let item = QueueItem::new(message, Tags::from(required_capabilities), priority);
+    let purged = dead_letters.len() - kept.len();
+    if purged == 0 {
+        return Ok(0);
+    }
+    match file_manager.read() {
+        Ok(manager) => manager.replace_dead_letters(&kept)?,
+        Err(_) => return Err(Error::MutexCorrupted),
+    }
+    *dead_letters = kept;
+    info!("Purged {} dead letters past the retention limits", purged);
+    Ok(purged)
+}
 
-        if let Ok(mut manager) = self.file_manager.read() {
-            match manager.save_item(&item) {
-                Err(e) => return Err(e.into()),
-                _ => debug!("Item saved to disk without issues"),
-            }
-        } else {
-            return Err(Error::MutexCorrupted);
-        }
+// Estimates how much memory an item takes up
+fn estimate_size<T: Send + Clone + Serialize>(item: &QueueItem<T>) -> usize {
+    serialized_size(item).unwrap_or(0) as usize
+}
 
-        let id = item.id.clone();
-        let result = self.add_item_to_queue(item);
-        match result {
-            Err(e) => return Err(e),
-            _ => debug!("Item added to queue without issues. "),
+impl<T: Send + Clone + Serialize + DeserializeOwned + 'static> QueueServer<T> {
+    pub fn new_with_config(filename: String, config: &Config) -> Result<QueueServer<T>, Error> {
+        let mut file_manager = InternalQueueFileManager::new_with_durability(filename, true, config.durability.clone())?
+            .with_gc_retry_limit(config.gc_retry_limit);
+        if config.preserve_order_after_gc {
+            file_manager = file_manager.with_preserved_order();
+        }
+        if config.acknowledge_flush_interval > Duration::from_millis(0) {
+            file_manager = file_manager.with_batched_acknowledgements(config.acknowledge_flush_interval);
         }
 
-        Ok(CreatedMessage { id })
+        let queue_server = QueueServer::from_file_manager(file_manager, config, false)?;
+        if queue_server.dead_letter_retention.is_enabled() {
+            queue_server.start_dead_letter_sweeper();
+        }
+        if config.visibility_timeout > Duration::from_millis(0) {
+            return Ok(queue_server.with_visibility_timeout(config.visibility_timeout));
+        }
+        Ok(queue_server)
     }
 
-    fn pop_item(
-        &mut self,
-        capabilities: Vec<String>,
-        wait_for_message: bool,
-    ) -> Result<Option<QueueItem<T>>, Error> {
-        match self.queue.pop(capabilities.clone()) {
-            Err(e) => Err(e),
-            Ok(Some(entry)) => Ok(Some(entry)),
-            Ok(None) => {
-                if wait_for_message {
-                    loop {
-                        select! {
-                            recv(self.wait_receive) -> msg => {
-                                match msg {
-                                    Ok(item) => return Ok(Some(item)),
-                                    Err(_) => return Err(Error::QueueCorrupted),
-                                }
-                            },
-                            default(Duration::from_secs(1)) => {
-                                // Try to receive something from the queue again
-                                match self.queue.pop(capabilities.clone()) {
-                                    Err(e) => return Err(e),
-                                    Ok(Some(item)) => return Ok(Some(item)),
-                                    Ok(None) => {},
-                                }
-                            }
-                        }
-                    }
-                } else {
-                    Ok(None)
-                }
-            }
+    // Opens the storage of another server read-only, so the tasks can be inspected
+    // without any risk of changing them. Anything that would change the queue fails with Error::ReadOnly.
+    pub fn open_read_only(filename: String) -> Result<QueueServer<T>, Error> {
+        let mut file_manager = InternalQueueFileManager::open_read_only(filename)?;
+        let stored = file_manager.load_items()?;
+
+        let mut queue_server = QueueServer::from_file_manager(file_manager, &Config::default(), true)?;
+        for item in stored.high_priority.into_iter().chain(stored.low_priority) {
+            queue_server.add_item_to_queue(item)?;
         }
+
+        Ok(queue_server)
     }
 
-    pub fn pop(
-        &mut self,
-        capabilities: Vec<String>,
-        wait_for_message: bool,
-    ) -> Result<Option<QueueItem<T>>, Error> {
-        match self.pop_item(capabilities, wait_for_message) {
-            Err(e) => Err(e),
-            Ok(None) => Ok(None),
-            Ok(Some(item)) => {
-                if let Ok(mut waiting) = self.processing.lock() {
-                    waiting.insert(item.id.clone(), item.clone());
-                } else {
-                    return Err(Error::QueueCorrupted);
-                };
-                Ok(Some(item))
+    fn from_file_manager(file_manager: InternalQueueFileManager<T>, config: &Config, read_only: bool) -> Result<QueueServer<T>, Error> {
+        let (sender, receiver) = bounded(config.wake_buffer);
+        let next_sequence = file_manager.highest_sequence()? + 1;
+        let dead_letters = file_manager.load_dead_letters()?;
+
+        Ok(QueueServer {
+            queue: InternalQueueManager::new(config.queue_order, config.priority_aging),
+            file_manager: Arc::new(RwLock::new(file_manager)),
+            waiting: sender,
+            wait_receive: receiver,
+            idle_workers: IdleWorkers::new(),
+            waiting_pops: Arc::new(AtomicUsize::new(0)),
+            max_waiting_pops: config.max_waiting_pops,
+            processing: Arc::new(Mutex::new(HashMap::new())),
+            allowed_capabilities: config.allowed_capabilities.clone().map(Tags::from),
+            started_at: Instant::now(),
+            completed_signal: Arc::new((Mutex::new(()), Condvar::new())),
+            counters: Counters::new(),
+            reserved: Arc::new(Mutex::new(Vec::new())),
+            failed_affinity_window: config.failed_affinity_window,
+            declined: Arc::new(Mutex::new(Vec::new())),
+            reservations: Arc::new(Mutex::new(HashMap::new())),
+            scheduled: Arc::new(Mutex::new(Vec::new())),
+            paused_capabilities: Arc::new(RwLock::new(config.paused_capabilities.iter().cloned().collect())),
+            denied_capabilities: config.denied_capabilities.iter().cloned().collect(),
+            unroutable_policy: config.unroutable_policy,
+            hook: Arc::new(NoopHook),
+            events: EventSubscribers::new(),
+            payload_validator: None,
+            pop_rate: RateMeter::new(Instant::now()),
+            next_sequence: Arc::new(AtomicU64::new(next_sequence)),
+            queued_bytes: Arc::new(AtomicUsize::new(0)),
+            memory_limit_bytes: config.memory_limit_bytes,
+            spilled: Arc::new(Mutex::new(SpilledItems {
+                high_priority: VecDeque::new(),
+                low_priority: VecDeque::new(),
+            })),
+            read_only,
+            processing_rates: Arc::new(Mutex::new(HashMap::new())),
+            recently_completed: Arc::new(Mutex::new(RecentlyCompleted::new())),
+            idempotency_keys: Arc::new(Mutex::new(IdempotencyKeys::new())),
+            wait_tick: config.wait_tick,
+            tag_pattern: config.tag_pattern.clone(),
+            storage_full_until: Arc::new(Mutex::new(None)),
+            max_processing: config.max_processing,
+            ready: Arc::new(AtomicBool::new(true)),
+            first_new_sequence: next_sequence,
+            visibility_timeout: None,
+            visibility_scanner_started: Arc::new(AtomicBool::new(false)),
+            timed_out: Arc::new(Mutex::new(Vec::new())),
+            priority_bands: config.priority_bands.clone(),
+            max_attempts: config.max_attempts,
+            dead_letters: Arc::new(Mutex::new(dead_letters)),
+            dead_letter_retention: DeadLetterRetention::from_config(config),
+            paused: Arc::new(AtomicBool::new(false)),
+            requeued_on_pause: Arc::new(Mutex::new(HashSet::new())),
+            clock: SystemTime::now,
+        })
+    }
+
+    // Runs the hook when something happens to the tasks
+    pub fn with_hook(mut self, hook: Arc<dyn EventHook<T>>) -> QueueServer<T> {
+        self.hook = hook;
+        self
+    }
+
+    // Tells the hook and the event subscribers that something happened to a task
+    fn emit<F: Fn(&dyn EventHook<T>)>(&self, event: F) {
+        event(&*self.hook);
+        event(&self.events);
+    }
+
+    // Starts sending what happens to the tasks to the returned channel, until unsubscribed.
+    // Up to buffer events are kept for the subscriber, events past that are dropped.
+    pub fn subscribe_events(&self, buffer: usize) -> Result<(u64, Receiver<QueueEvent>), Error> {
+        self.events.subscribe(buffer).map_err(|_| Error::QueueCorrupted)
+    }
+
+    pub fn unsubscribe_events(&self, id: u64) -> Result<(), Error> {
+        self.events.unsubscribe(id).map_err(|_| Error::QueueCorrupted)
+    }
+
+    // Puts popped tasks back in the queue if they haven't been acknowledged or failed
+    // within the timeout, unless the pop asks for another timeout
+    pub fn with_visibility_timeout(mut self, timeout: Duration) -> QueueServer<T> {
+        self.visibility_timeout = Some(timeout);
+        self.start_visibility_scanner();
+        self
+    }
+
+    // How long popped tasks can be processing by default, None if they never time out
+    pub fn visibility_timeout(&self) -> Option<Duration> {
+        self.visibility_timeout
+    }
+
+    // Starts the thread moving timed out tasks out of processing, unless it's already running.
+    // The thread stops once the queue server has been dropped.
+    fn start_visibility_scanner(&self) {
+        if self.visibility_scanner_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let processing = Arc::downgrade(&self.processing);
+        let timed_out = Arc::downgrade(&self.timed_out);
+        let waiting = self.waiting.clone();
+        thread::spawn(move || {
+            loop {
+                thread::sleep(VISIBILITY_SCAN_INTERVAL);
+
+                let (processing, timed_out) = match (processing.upgrade(), timed_out.upgrade()) {
+                    (Some(processing), Some(timed_out)) => (processing, timed_out),
+                    _ => return,
+                };
+
+                let expired: Vec<QueueItem<T>> = match processing.lock() {
+                    Ok(mut processing) => {
+                        let now = Instant::now();
+                        let ids: Vec<Uuid> = processing
+                            .values()
+                            .filter(|in_flight| in_flight.is_timed_out(now))
+                            .map(|in_flight| in_flight.item.id)
+                            .collect();
+                        ids.iter().filter_map(|id| processing.remove(id)).map(|in_flight| in_flight.item).collect()
+                    }
+                    Err(_) => return,
+                };
+                if expired.is_empty() {
+                    continue;
+                }
+
+                match timed_out.lock() {
+                    Ok(mut timed_out) => timed_out.extend(expired),
+                    Err(_) => return,
+                }
+                // Wakes up a waiting pop, so the tasks are handed out right away
+                let _ = waiting.try_send(());
+            }
+        });
+    }
+
+    // Starts the thread deleting the dead letters past the retention limits.
+    // The thread stops once the queue server has been dropped.
+    fn start_dead_letter_sweeper(&self) {
+        let dead_letters = Arc::downgrade(&self.dead_letters);
+        let file_manager = Arc::downgrade(&self.file_manager);
+        let retention = self.dead_letter_retention;
+        let clock = self.clock;
+        thread::spawn(move || {
+            loop {
+                thread::sleep(DEAD_LETTER_SWEEP_INTERVAL);
+
+                let (dead_letters, file_manager) = match (dead_letters.upgrade(), file_manager.upgrade()) {
+                    (Some(dead_letters), Some(file_manager)) => (dead_letters, file_manager),
+                    _ => return,
+                };
+                if let Err(e) = purge_dead_letters(&dead_letters, &file_manager, retention, clock()) {
+                    error!("Failed to purge dead letters: {}", e);
+                }
+            }
+        });
+    }
+
+    // Rejects pops with Error::NotReady until load_stored_items has finished,
+    // so the stored tasks can be loaded while the server starts accepting connections
+    pub fn until_loaded(self) -> QueueServer<T> {
+        self.ready.store(false, Ordering::SeqCst);
+        self
+    }
+
+    // Puts the tasks saved by earlier runs of the server in the queue, and lets pops through
+    pub fn load_stored_items(&mut self) -> Result<usize, Error> {
+        let stored = match self.file_manager.write() {
+            Ok(mut manager) => manager.load_items()?,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+
+        let mut loaded = 0;
+        for item in stored.high_priority.into_iter().chain(stored.low_priority) {
+            // Items saved before sequence numbers were recorded all have 0
+            if item.sequence >= self.first_new_sequence {
+                continue;
+            }
+            if item.is_expired(SystemTime::now()) {
+                self.drop_expired(item)?;
+                continue;
+            }
+            if let Some(key) = &item.idempotency_key {
+                match self.idempotency_keys.lock() {
+                    Ok(mut keys) => keys.insert(key.clone(), item.id),
+                    Err(_) => return Err(Error::QueueCorrupted),
+                }
+            }
+            match item.available_at {
+                Some(available_at) if available_at > SystemTime::now() => self.schedule(item)?,
+                _ => self.add_or_spill_item(item)?,
+            }
+            loaded += 1;
+        }
+
+        self.ready.store(true, Ordering::SeqCst);
+        Ok(loaded)
+    }
+
+    // False while the stored tasks are being loaded
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::SeqCst)
+    }
+
+    // Rejects enqueued tasks the validator doesn't accept the payload of
+    pub fn with_payload_validator(mut self, validator: PayloadValidator<T>) -> QueueServer<T> {
+        self.payload_validator = Some(validator);
+        self
+    }
+
+    fn ensure_writable(&self) -> Result<(), Error> {
+        if self.read_only {
+            Err(Error::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn new_with_filename(filename: String) -> Result<QueueServer<T>, Error> {
+        QueueServer::new_with_config(filename, &Config::default())
+    }
+
+    pub fn new(config: &Config) -> Result<QueueServer<T>, Error> {
+        QueueServer::new_with_config(format!("{}/tasks", config.storage_folder), config)
+    }
+
+    // Ensures tasks only require capabilities the server allows
+    fn validate_capabilities(&self, capabilities: &[String]) -> Result<(), Error> {
+        self.validate_capability_format(capabilities)?;
+        if let Some(allowed) = &self.allowed_capabilities {
+            for capability in capabilities {
+                if !allowed.contains(capability) {
+                    return Err(Error::CapabilityNotAllowed(capability.clone()));
+                }
+            }
+        }
+        self.ensure_routable(capabilities)
+    }
+
+    // Checks that workers are allowed to have the capabilities, so a task requiring them can be processed
+    fn ensure_routable(&self, capabilities: &[String]) -> Result<(), Error> {
+        let denied = match capabilities.iter().find(|capability| self.denied_capabilities.contains(*capability)) {
+            Some(denied) => denied,
+            None => return Ok(()),
+        };
+        match self.unroutable_policy {
+            UnroutablePolicy::Reject => Err(Error::Unroutable(denied.clone())),
+            UnroutablePolicy::Warn => {
+                warn!("Task requires the denied capability '{}', and will never be processed", denied);
+                Ok(())
+            }
+        }
+    }
+
+    // Leaves out the capabilities workers aren't allowed to have
+    fn without_denied(&self, capabilities: Vec<String>) -> Vec<String> {
+        if self.denied_capabilities.is_empty() {
+            return capabilities;
+        }
+        capabilities.into_iter().filter(|capability| !self.denied_capabilities.contains(capability)).collect()
+    }
+
+    // Ensures capabilities are not empty, and matches the configured tag pattern if there is one
+    fn validate_capability_format(&self, capabilities: &[String]) -> Result<(), Error> {
+        // An empty capability is almost always a bug in the client.
+        // No capabilities at all is fine, that just means no requirements.
+        if capabilities.iter().any(|capability| capability.is_empty()) {
+            return Err(Error::EmptyCapability);
+        }
+        if let Some(pattern) = &self.tag_pattern {
+            let malformed: Vec<String> = capabilities
+                .iter()
+                .filter(|capability| !pattern.is_match(capability))
+                .cloned()
+                .collect();
+            if !malformed.is_empty() {
+                return Err(Error::MalformedCapabilities(malformed));
+            }
+        }
+        Ok(())
+    }
+
+    fn save_item(&self, item: &QueueItem<T>) -> Result<(), Error> {
+        match self.storage_full_until.lock() {
+            Ok(until) => match *until {
+                Some(until) if Instant::now() < until => return Err(Error::StorageFull),
+                _ => {}
+            },
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+
+        match self.write_item(item) {
+            Err(Error::IOError(ref e)) if is_storage_full(e) => {
+                error!("Storage is full: {}", e);
+                // Garbage collection might free up enough space
+                if self.run_garbage_collection().is_ok() && self.write_item(item).is_ok() {
+                    return Ok(());
+                }
+
+                match self.storage_full_until.lock() {
+                    Ok(mut until) => *until = Some(Instant::now() + STORAGE_FULL_BACKOFF),
+                    Err(_) => return Err(Error::QueueCorrupted),
+                }
+                Err(Error::StorageFull)
+            }
+            result => result,
+        }
+    }
+
+    // Saves every item, or none of them.
+    // Unlike save_item it's not retried after garbage collection, as the items
+    // written before running out of space have been discarded.
+    fn save_batch(&self, items: &[QueueItem<T>]) -> Result<(), Error> {
+        match self.storage_full_until.lock() {
+            Ok(until) => match *until {
+                Some(until) if Instant::now() < until => return Err(Error::StorageFull),
+                _ => {}
+            },
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+
+        let result = match self.file_manager.read() {
+            Ok(manager) => manager.save_items(items).map_err(Error::from),
+            Err(_) => Err(Error::MutexCorrupted),
+        };
+        match result {
+            Err(Error::IOError(ref e)) if is_storage_full(e) => {
+                error!("Storage is full: {}", e);
+                match self.storage_full_until.lock() {
+                    Ok(mut until) => *until = Some(Instant::now() + STORAGE_FULL_BACKOFF),
+                    Err(_) => return Err(Error::QueueCorrupted),
+                }
+                Err(Error::StorageFull)
+            }
+            result => result,
+        }
+    }
+
+    fn write_item(&self, item: &QueueItem<T>) -> Result<(), Error> {
+        if let Ok(manager) = self.file_manager.read() {
+            match manager.save_item(item) {
+                Err(e) => Err(e.into()),
+                _ => {
+                    debug!("Item saved to disk without issues");
+                    Ok(())
+                }
+            }
+        } else {
+            Err(Error::MutexCorrupted)
+        }
+    }
+
+    fn add_item_to_queue(&mut self, item: QueueItem<T>) -> Result<(), Error> {
+        self.queued_bytes.fetch_add(estimate_size(&item), Ordering::SeqCst);
+        self.queue.enqueue(item)?;
+        self.wake_waiter()
+    }
+
+    // Must be called for every item taken out of the in-memory queue
+    fn removed_from_queue(&self, item: &QueueItem<T>) {
+        self.queued_bytes.fetch_sub(estimate_size(item), Ordering::SeqCst);
+    }
+
+    // Adds the item to the in-memory queue, unless the memory limit has been reached,
+    // in which case it's only kept on disk until there is room for it.
+    // The item must already have been saved.
+    fn add_or_spill_item(&mut self, item: QueueItem<T>) -> Result<(), Error> {
+        if self.memory_limit_bytes > 0 {
+            let is_spilled = match self.spilled.lock() {
+                Ok(mut spilled) => {
+                    let spilled = spilled.for_priority(&item.priority);
+                    // Once anything has been spilled, newer items has to be spilled too, to keep the order
+                    let over_limit = self.queued_bytes.load(Ordering::SeqCst) + estimate_size(&item) > self.memory_limit_bytes;
+                    if over_limit || !spilled.is_empty() {
+                        spilled.push_back(SpilledItem {
+                            id: item.id,
+                            required_tags: item.required_tags.clone(),
+                        });
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Err(_) => return Err(Error::QueueCorrupted),
+            };
+            if is_spilled {
+                return self.wake_waiter();
+            }
+        }
+
+        self.add_item_to_queue(item)
+    }
+
+    // Loads spilled items back into memory, until the memory limit is reached again.
+    // If force is set at least one item is loaded, even if the limit has been reached.
+    // Returns true if anything was loaded.
+    fn load_spilled(&mut self, priority: &Priority, force: bool) -> Result<bool, Error> {
+        let mut spilled = match self.spilled.lock() {
+            Ok(spilled) => spilled,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let spilled = spilled.for_priority(priority);
+        if spilled.is_empty() {
+            return Ok(false);
+        }
+
+        let mut budget = self.memory_limit_bytes.saturating_sub(self.queued_bytes.load(Ordering::SeqCst));
+        let mut loaded = Vec::new();
+        if let Ok(manager) = self.file_manager.read() {
+            // The spilled items are in the same order as in the file
+            manager.visit_items(priority, |item| {
+                if spilled.front().map(|spilled| spilled.id) != Some(item.id) {
+                    return true;
+                }
+                let size = estimate_size(&item);
+                if size > budget && !(force && loaded.is_empty()) {
+                    return false;
+                }
+                budget = budget.saturating_sub(size);
+                spilled.pop_front();
+                loaded.push(item);
+                !spilled.is_empty()
+            })?;
+        } else {
+            return Err(Error::MutexCorrupted);
+        }
+
+        if loaded.is_empty() && force {
+            // The item can't be found on disk, so there is no way to get it back
+            if let Some(missing) = spilled.pop_front() {
+                error!("Spilled item {} is missing from disk", missing.id);
+            }
+        }
+
+        let any_loaded = !loaded.is_empty();
+        for item in loaded {
+            self.queued_bytes.fetch_add(estimate_size(&item), Ordering::SeqCst);
+            self.queue.enqueue(item)?;
+        }
+        Ok(any_loaded)
+    }
+
+    // Takes the first spilled item the capabilities can handle straight from disk,
+    // without loading any of the other spilled items into memory
+    fn take_spilled_match(&mut self, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
+        let mut spilled = match self.spilled.lock() {
+            Ok(spilled) => spilled,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for priority in &[Priority::High, Priority::Low] {
+            let spilled = spilled.for_priority(priority);
+            while let Some(position) = spilled.iter().position(|spilled| capabilities.is_superset(&spilled.required_tags)) {
+                let id = match spilled.remove(position) {
+                    Some(spilled) => spilled.id,
+                    None => return Err(Error::QueueCorrupted),
+                };
+
+                let mut found = None;
+                if let Ok(manager) = self.file_manager.read() {
+                    manager.visit_items(priority, |item| {
+                        if item.id == id {
+                            found = Some(item);
+                            false
+                        } else {
+                            true
+                        }
+                    })?;
+                } else {
+                    return Err(Error::MutexCorrupted);
+                }
+
+                match found {
+                    Some(item) => return Ok(Some(item)),
+                    // The item can't be found on disk, so there is no way to get it back
+                    None => error!("Spilled item {} is missing from disk", id),
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    // Wakes up a waiting pop, if there is any, so it can look for new items
+    fn wake_waiter(&mut self) -> Result<(), Error> {
+        match self.waiting.try_send(()) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(())) => Ok(()),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Enqueues another item in the queue.
+    // The generated id of the enqueued item is returned
+    pub fn enqueue(
+        &mut self,
+        message: T,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+    ) -> Result<CreatedMessage, Error> {
+        self.enqueue_by(None, message, priority, required_capabilities)
+    }
+
+    // Same as enqueue, but records who enqueued the item, so workers can trace where it came from
+    pub fn enqueue_by(
+        &mut self,
+        enqueued_by: Option<String>,
+        message: T,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+    ) -> Result<CreatedMessage, Error> {
+        self.enqueue_scheduled(enqueued_by, message, priority, required_capabilities, None)
+    }
+
+    // Same as enqueue_by, but the item isn't handed out before available_at, if set
+    pub fn enqueue_scheduled(
+        &mut self,
+        enqueued_by: Option<String>,
+        message: T,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+        available_at: Option<SystemTime>,
+    ) -> Result<CreatedMessage, Error> {
+        self.enqueue_expiring(enqueued_by, message, priority, required_capabilities, available_at, None)
+    }
+
+    // Enqueues a task that is only worth handling within the given time after being enqueued.
+    // The task is dropped instead of handed out if it hasn't been popped by then.
+    pub fn enqueue_with_ttl(&mut self, message: T, priority: Priority, required_capabilities: Vec<String>, ttl: Duration) -> Result<CreatedMessage, Error> {
+        self.enqueue_expiring(None, message, priority, required_capabilities, None, Some(SystemTime::now() + ttl))
+    }
+
+    // Same as enqueue_scheduled, but the task is dropped instead of handed out
+    // if it hasn't been popped before it expires
+    pub fn enqueue_expiring(
+        &mut self,
+        enqueued_by: Option<String>,
+        message: T,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+        available_at: Option<SystemTime>,
+        expires_at: Option<SystemTime>,
+    ) -> Result<CreatedMessage, Error> {
+        let span = debug_span!("enqueue", priority = ?priority);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let item = self.new_item(enqueued_by, message, priority, required_capabilities, available_at, expires_at)?;
+
+        self.enqueue_new_item(item)
+    }
+
+    fn enqueue_new_item(&mut self, item: QueueItem<T>) -> Result<CreatedMessage, Error> {
+        self.save_item(&item)?;
+
+        let id = item.id.clone();
+        self.queue_saved_item(item)?;
+
+        Ok(CreatedMessage { id, created: true })
+    }
+
+    // Enqueues either every item in the batch, or none of them.
+    // If any item is rejected, Error::BatchItemRejected tells which one.
+    // The ids of the enqueued items are returned in the order of the batch.
+    pub fn enqueue_atomically(&mut self, enqueued_by: Option<String>, batch: Vec<BatchItem<T>>) -> Result<Vec<CreatedMessage>, Error> {
+        let span = debug_span!("enqueue_atomically", size = batch.len());
+        let _entered = span.enter();
+        self.ensure_writable()?;
+
+        let mut items = Vec::with_capacity(batch.len());
+        for (index, batch_item) in batch.into_iter().enumerate() {
+            let item = self
+                .new_item(
+                    enqueued_by.clone(),
+                    batch_item.message,
+                    batch_item.priority,
+                    batch_item.required_capabilities,
+                    batch_item.available_at,
+                    batch_item.expires_at,
+                )
+                .map_err(|e| Error::BatchItemRejected(index, Box::new(e)))?;
+            items.push(item);
+        }
+
+        self.save_batch(&items)?;
+
+        let created = items.iter().map(|item| CreatedMessage { id: item.id, created: true }).collect();
+        // Made available from the highest priority down, so a waiting worker
+        // isn't handed a low priority item from the batch ahead of a high priority one
+        items.sort_by_key(|item| Reverse(item.priority.level()));
+        for item in items {
+            self.queue_saved_item(item)?;
+        }
+        Ok(created)
+    }
+
+    // Enqueues the messages with their priority and required capabilities, all or nothing.
+    // Every item is written to disk at once, so bulk producers only pay for a single flush.
+    pub fn enqueue_batch(&mut self, messages: Vec<(T, Priority, Vec<String>)>) -> Result<Vec<CreatedMessage>, Error> {
+        let batch = messages
+            .into_iter()
+            .map(|(message, priority, required_capabilities)| BatchItem {
+                message,
+                priority,
+                required_capabilities,
+                available_at: None,
+                expires_at: None,
+            })
+            .collect();
+        self.enqueue_atomically(None, batch)
+    }
+
+    // Checks that a task with the priority and capabilities can be enqueued,
+    // for when the task is enqueued later, for example once its payload has been received
+    pub fn validate_task(&self, priority: &Priority, required_capabilities: &[String]) -> Result<(), Error> {
+        self.validate_priority(priority)?;
+        self.validate_capabilities(required_capabilities)
+    }
+
+    // Only the configured priority bands can be used
+    fn validate_priority(&self, priority: &Priority) -> Result<(), Error> {
+        if priority.level() as usize >= self.priority_bands.len() {
+            return Err(Error::UnknownPriority(priority.level(), self.priority_bands.len()));
+        }
+        Ok(())
+    }
+
+    fn validate_payload(&self, data: &T) -> Result<(), Error> {
+        match &self.payload_validator {
+            Some(validator) => validator(data).map_err(Error::InvalidPayload),
+            None => Ok(()),
+        }
+    }
+
+    // Validates a new task, and creates the item for it
+    fn new_item(
+        &self,
+        enqueued_by: Option<String>,
+        message: T,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+        available_at: Option<SystemTime>,
+        expires_at: Option<SystemTime>,
+    ) -> Result<QueueItem<T>, Error> {
+        self.validate_priority(&priority)?;
+        self.validate_capabilities(&required_capabilities)?;
+        self.validate_payload(&message)?;
+
+        let mut item = QueueItem::new(message, Tags::from(required_capabilities), priority);
+        item.enqueued_by = enqueued_by;
+        item.available_at = available_at;
+        item.expires_at = expires_at;
+        item.sequence = self.next_sequence.fetch_add(1, Ordering::SeqCst);
+        item.enqueued_at = Some((self.clock)());
+        Ok(item)
+    }
+
+    // Makes a saved item available to the workers
+    fn queue_saved_item(&mut self, item: QueueItem<T>) -> Result<(), Error> {
+        self.emit(|hook| hook.on_enqueue(&item));
+        let result = match item.available_at {
+            Some(available_at) if available_at > SystemTime::now() => self.schedule(item),
+            _ => match self.hand_to_idle_worker(item)? {
+                Some(item) => self.add_or_spill_item(item),
+                None => Ok(()),
+            },
+        };
+        match result {
+            Err(e) => return Err(e),
+            _ => debug!("Item added to queue without issues. "),
+        }
+        self.counters.enqueued.increment();
+        Ok(())
+    }
+
+    // Same as enqueue_expiring, but only enqueues the item if no task with the same key is
+    // queued or being processed. The key can be used again once the task has been completed.
+    pub fn enqueue_idempotent(&mut self, idempotency_key: String, enqueued_by: Option<String>, task: BatchItem<T>) -> Result<CreatedMessage, Error> {
+        if let Some(id) = self.idempotent_task(&idempotency_key)? {
+            return Ok(CreatedMessage { id, created: false });
+        }
+
+        self.ensure_writable()?;
+        let mut item = self.new_item(enqueued_by, task.message, task.priority, task.required_capabilities, task.available_at, task.expires_at)?;
+        // Saved with the item, so the key is known again after a restart
+        item.idempotency_key = Some(idempotency_key.clone());
+
+        // The key is reserved before the item is saved, so two enqueues with the same key
+        // can't both create a task, without holding the lock while saving
+        match self.idempotency_keys.lock() {
+            Ok(mut keys) => {
+                if let Some(id) = keys.get(&idempotency_key) {
+                    return Ok(CreatedMessage { id, created: false });
+                }
+                keys.insert(idempotency_key, item.id);
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+
+        if let Err(e) = self.save_item(&item) {
+            match self.idempotency_keys.lock() {
+                Ok(mut keys) => keys.remove(&item.id),
+                Err(_) => return Err(Error::QueueCorrupted),
+            }
+            return Err(e);
+        }
+
+        let id = item.id;
+        self.queue_saved_item(item)?;
+        Ok(CreatedMessage { id, created: true })
+    }
+
+    // The task enqueued with the idempotency key, if it hasn't been completed yet
+    fn idempotent_task(&self, idempotency_key: &str) -> Result<Option<Uuid>, Error> {
+        match self.idempotency_keys.lock() {
+            Ok(keys) => Ok(keys.get(idempotency_key)),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Hands the item directly to a waiting worker that can handle it.
+    // Returns the item if no waiting worker can handle it.
+    fn hand_to_idle_worker(&self, item: QueueItem<T>) -> Result<Option<QueueItem<T>>, Error> {
+        if self.is_paused() {
+            return Ok(Some(item));
+        }
+        let level = item.priority.level();
+        // A worker that could take a queued item with the same or a higher priority must get
+        // that one first, so the new item goes in the queue, and the woken worker picks the best
+        let has_better_queued = |capabilities: &Tags| match self.queue.peek(capabilities, None) {
+            Ok(Some(queued)) => queued.priority.level() >= level,
+            Ok(None) => false,
+            Err(_) => true,
+        };
+        match self.paused_capabilities.read() {
+            Ok(paused) => Ok(self.idle_workers.hand_over(item, &paused, has_better_queued)?),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Swaps an item handed to a waiting worker for a queued item with a higher priority,
+    // if one has arrived since, so the worker still gets the best item there is once it wakes up
+    // Returns None if other workers took both items in the meantime.
+    fn prefer_queued(&mut self, item: QueueItem<T>, capabilities: &[String], worker: Option<&str>) -> Result<Option<QueueItem<T>>, Error> {
+        let better = match self.queue.peek(&Tags::from(capabilities.to_vec()), None)? {
+            Some(queued) => queued.priority.level() > item.priority.level(),
+            None => false,
+        };
+        if !better {
+            return Ok(Some(item));
+        }
+        self.add_or_spill_item(item)?;
+        self.try_pop(capabilities, worker)
+    }
+
+    // The capabilities of the workers currently waiting in a blocking pop
+    pub fn idle_workers(&self) -> Result<Vec<Tags>, Error> {
+        Ok(self.idle_workers.capabilities()?)
+    }
+
+    // Puts reserved tasks back in the queue once their worker has had its chance
+    fn release_expired_reservations(&mut self) -> Result<(), Error> {
+        let expired: Vec<ReservedItem<T>> = match self.reserved.lock() {
+            Ok(mut reserved) => {
+                if reserved.is_empty() {
+                    return Ok(());
+                }
+                let now = Instant::now();
+                let (expired, kept) = reserved.drain(..).partition(|reservation| reservation.until <= now);
+                *reserved = kept;
+                expired
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for reservation in expired {
+            self.add_item_to_queue(reservation.item)?;
+        }
+        Ok(())
+    }
+
+    // Puts declined tasks back in the queue once the other workers have had their chance
+    fn release_expired_declines(&mut self) -> Result<(), Error> {
+        let expired: Vec<DeclinedItem<T>> = match self.declined.lock() {
+            Ok(mut declined) => {
+                if declined.is_empty() {
+                    return Ok(());
+                }
+                let now = Instant::now();
+                let (expired, kept) = declined.drain(..).partition(|declined| declined.until <= now);
+                *declined = kept;
+                expired
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for declined in expired {
+            self.add_item_to_queue(declined.item)?;
+        }
+        Ok(())
+    }
+
+    // Puts tasks that passed their visibility timeout back in the queue
+    fn release_timed_out(&mut self) -> Result<(), Error> {
+        let timed_out: Vec<QueueItem<T>> = match self.timed_out.lock() {
+            Ok(mut timed_out) => timed_out.drain(..).collect(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for item in timed_out {
+            self.counters.failed.increment();
+            self.emit(|hook| hook.on_fail(&item.id));
+            if let Some(item) = self.record_failure(item, true)? {
+                self.add_item_to_queue(item)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Takes the oldest declined task the worker can handle, and didn't decline itself
+    fn take_declined(&mut self, worker: Option<&str>, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
+        match self.declined.lock() {
+            Ok(mut declined) => {
+                let position = declined.iter().position(|declined| {
+                    worker != Some(declined.worker.as_str()) && declined.item.can_be_handled_by(capabilities)
+                });
+                Ok(position.map(|position| declined.remove(position).item))
+            }
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Keeps the item out of the queue until it's available
+    fn schedule(&mut self, item: QueueItem<T>) -> Result<(), Error> {
+        match self.scheduled.lock() {
+            Ok(mut scheduled) => {
+                scheduled.push(item);
+                Ok(())
+            }
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Puts scheduled tasks in the queue once they are available
+    fn release_scheduled(&mut self) -> Result<(), Error> {
+        let available: Vec<QueueItem<T>> = match self.scheduled.lock() {
+            Ok(mut scheduled) => {
+                if scheduled.is_empty() {
+                    return Ok(());
+                }
+                let now = SystemTime::now();
+                let (available, kept) = scheduled
+                    .drain(..)
+                    .partition(|item| item.available_at.map_or(true, |available_at| available_at <= now));
+                *scheduled = kept;
+                available
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for item in available {
+            self.add_or_spill_item(item)?;
+        }
+        Ok(())
+    }
+
+    // Takes the oldest task reserved for the worker, that the worker can handle
+    fn take_reserved(&mut self, worker: &str, capabilities: &Tags) -> Result<Option<QueueItem<T>>, Error> {
+        match self.reserved.lock() {
+            Ok(mut reserved) => {
+                let position = reserved.iter().position(|reservation| {
+                    reservation.worker == worker && reservation.item.can_be_handled_by(capabilities)
+                });
+                Ok(position.map(|position| reserved.remove(position).item))
+            }
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Takes the next task the worker can handle, dropping any expired tasks on the way
+    fn try_pop(&mut self, capabilities: &[String], worker: Option<&str>) -> Result<Option<QueueItem<T>>, Error> {
+        loop {
+            match self.try_pop_any(capabilities, worker)? {
+                Some(item) if item.is_expired(SystemTime::now()) => self.drop_expired(item)?,
+                item => return Ok(item),
+            }
+        }
+    }
+
+    // Marks an expired task as completed, so it's never handed out, and garbage collection removes it
+    fn drop_expired(&mut self, item: QueueItem<T>) -> Result<(), Error> {
+        debug!("Dropping expired task {}", item.id);
+        self.counters.expired.increment();
+        let result = self.mark_as_completed(&item.id);
+        self.signal_completed();
+        result
+    }
+
+    fn try_pop_any(&mut self, capabilities: &[String], worker: Option<&str>) -> Result<Option<QueueItem<T>>, Error> {
+        if self.is_paused() {
+            return Ok(None);
+        }
+        self.release_timed_out()?;
+        self.release_expired_reservations()?;
+        self.release_expired_declines()?;
+        self.release_expired_peek_reservations()?;
+        self.release_scheduled()?;
+
+        // A worker can't take tasks requiring a capability it doesn't have,
+        // so leaving out the paused capabilities leaves those tasks in the queue
+        let capabilities: Vec<String> = match self.paused_capabilities.read() {
+            Ok(paused) => capabilities.iter().filter(|capability| !paused.contains(*capability)).cloned().collect(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let capabilities = &capabilities[..];
+
+        if let Some(worker) = worker {
+            if let Some(item) = self.take_reserved(worker, &Tags::from(capabilities.to_vec()))? {
+                return Ok(Some(item));
+            }
+        }
+        if let Some(item) = self.take_declined(worker, &Tags::from(capabilities.to_vec()))? {
+            return Ok(Some(item));
+        }
+
+        if self.memory_limit_bytes == 0 {
+            let item = self.queue.pop(capabilities.to_vec(), (self.clock)())?;
+            if let Some(item) = &item {
+                self.removed_from_queue(item);
+            }
+            return Ok(item);
+        }
+
+        // Make use of any room freed up since last time
+        self.load_spilled(&Priority::High, false)?;
+        self.load_spilled(&Priority::Low, false)?;
+        // Low priority items shouldn't be handed out just because the high priority ones are on disk
+        if self.queue.len_from(&Priority::High)? == 0 {
+            self.load_spilled(&Priority::High, true)?;
+        }
+
+        if let Some(item) = self.queue.pop(capabilities.to_vec(), (self.clock)())? {
+            self.removed_from_queue(&item);
+            return Ok(Some(item));
+        }
+
+        // Nothing in memory matches, so look for a spilled item that does
+        self.take_spilled_match(&Tags::from(capabilities.to_vec()))
+    }
+
+    fn pop_item(
+        &mut self,
+        capabilities: Vec<String>,
+        wait_for_message: bool,
+        worker: Option<&str>,
+        cancel: &Receiver<()>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        let capabilities = self.without_denied(capabilities);
+        match self.try_pop(&capabilities, worker) {
+            Err(e) => Err(e),
+            Ok(Some(entry)) => Ok(Some(entry)),
+            Ok(None) => {
+                if wait_for_message && self.start_waiting() {
+                    let (idle_id, handed_over) = match self.idle_workers.register(Tags::from(capabilities.clone())) {
+                        Ok(registered) => registered,
+                        Err(e) => {
+                            self.waiting_pops.fetch_sub(1, Ordering::SeqCst);
+                            return Err(Error::from(e));
+                        }
+                    };
+                    let result = self.wait_for_item(&capabilities, worker, cancel, &handed_over);
+                    let result = self.stop_idling(idle_id, &handed_over, result);
+                    self.waiting_pops.fetch_sub(1, Ordering::SeqCst);
+                    result
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    // Counts another waiting pop, unless too many are waiting already.
+    // Returns false if the pop can't wait.
+    fn start_waiting(&self) -> bool {
+        let waiting = self.waiting_pops.fetch_add(1, Ordering::SeqCst);
+        if self.max_waiting_pops > 0 && waiting >= self.max_waiting_pops {
+            self.waiting_pops.fetch_sub(1, Ordering::SeqCst);
+            false
+        } else {
+            true
+        }
+    }
+
+    // How many pops are currently waiting for a task
+    pub fn waiting_pops(&self) -> usize {
+        self.waiting_pops.load(Ordering::SeqCst)
+    }
+
+    fn wait_for_item(
+        &mut self,
+        capabilities: &[String],
+        worker: Option<&str>,
+        cancel: &Receiver<()>,
+        handed_over: &Receiver<QueueItem<T>>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        loop {
+            select! {
+                recv(cancel) -> _ => return Err(Error::PopCancelled),
+                recv(handed_over) -> item => {
+                    return match item {
+                        // Keeps waiting for another task, now without being handed tasks directly
+                        Ok(item) if item.is_expired(SystemTime::now()) => {
+                            self.drop_expired(item)?;
+                            continue;
+                        }
+                        Ok(item) => match self.prefer_queued(item, capabilities, worker)? {
+                            Some(item) => Ok(Some(item)),
+                            None => continue,
+                        },
+                        Err(_) => Err(Error::QueueCorrupted),
+                    };
+                },
+                recv(self.wait_receive) -> msg => {
+                    if msg.is_err() {
+                        return Err(Error::QueueCorrupted);
+                    }
+                    match self.try_pop(capabilities, worker) {
+                        Err(e) => return Err(e),
+                        Ok(Some(item)) => return Ok(Some(item)),
+                        Ok(None) => {},
+                    }
+                },
+                default(self.wait_tick) => {
+                    // Try to receive something from the queue again
+                    match self.try_pop(capabilities, worker) {
+                        Err(e) => return Err(e),
+                        Ok(Some(item)) => return Ok(Some(item)),
+                        Ok(None) => {},
+                    }
+                }
+            }
+        }
+    }
+
+    // Removes the worker from the idle workers once it's done waiting.
+    // If an item was handed to it after it found something else, or gave up,
+    // the item is put in the queue for someone else.
+    fn stop_idling(
+        &mut self,
+        idle_id: u64,
+        handed_over: &Receiver<QueueItem<T>>,
+        result: Result<Option<QueueItem<T>>, Error>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        if !self.idle_workers.unregister(idle_id)? {
+            if let Ok(item) = handed_over.try_recv() {
+                self.add_or_spill_item(item)?;
+            }
+        }
+        result
+    }
+
+    pub fn pop(
+        &mut self,
+        capabilities: Vec<String>,
+        wait_for_message: bool,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        self.pop_cancellable(capabilities, wait_for_message, &never())
+    }
+
+    // Same as pop, but a waiting pop can be aborted by sending on the cancel channel,
+    // in which case Error::PopCancelled is returned
+    pub fn pop_cancellable(
+        &mut self,
+        capabilities: Vec<String>,
+        wait_for_message: bool,
+        cancel: &Receiver<()>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        self.pop_as_worker(None, capabilities, wait_for_message, cancel)
+    }
+
+    // Pops on behalf of the given worker, which gets the tasks
+    // it has recently failed itself before anything else
+    pub fn pop_as_worker(
+        &mut self,
+        worker: Option<&str>,
+        capabilities: Vec<String>,
+        wait_for_message: bool,
+        cancel: &Receiver<()>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        let visibility_timeout = self.visibility_timeout;
+        self.pop_with_visibility_timeout(worker, capabilities, wait_for_message, cancel, visibility_timeout)
+    }
+
+    // Pops like pop_as_worker, but puts the task back in the queue if it hasn't been
+    // acknowledged or failed within the given timeout, instead of the configured one.
+    // The task is kept until it's acknowledged or failed if the timeout is None.
+    pub fn pop_with_visibility_timeout(
+        &mut self,
+        worker: Option<&str>,
+        capabilities: Vec<String>,
+        wait_for_message: bool,
+        cancel: &Receiver<()>,
+        visibility_timeout: Option<Duration>,
+    ) -> Result<Option<QueueItem<T>>, Error> {
+        let span = debug_span!("pop", wait = wait_for_message);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        if !self.is_ready() {
+            return Err(Error::NotReady);
+        }
+        self.validate_capability_format(&capabilities)?;
+        self.ensure_below_processing_limit()?;
+        match self.pop_item(capabilities, wait_for_message, worker, cancel) {
+            Err(e) => Err(e),
+            Ok(None) => Ok(None),
+            Ok(Some(item)) => {
+                self.start_processing(&item, worker, visibility_timeout)?;
+                Ok(Some(item))
+            }
+        }
+    }
+
+    // Refuses to hand out more tasks once max_processing tasks are being processed.
+    // Reserved tasks count too, since they are processed once the reservations are confirmed.
+    fn ensure_below_processing_limit(&self) -> Result<(), Error> {
+        if self.max_processing == 0 {
+            return Ok(());
+        }
+        let processing = match self.processing.lock() {
+            Ok(processing) => processing.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let reserved = match self.reservations.lock() {
+            Ok(reservations) => reservations.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        if processing + reserved >= self.max_processing {
+            Err(Error::TooManyProcessing)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Records that the popped item is being processed
+    fn start_processing(&mut self, item: &QueueItem<T>, worker: Option<&str>, visibility_timeout: Option<Duration>) -> Result<(), Error> {
+        if visibility_timeout.is_some() {
+            self.start_visibility_scanner();
+        }
+        if let Ok(mut waiting) = self.processing.lock() {
+            let in_flight = InFlightItem {
+                item: item.clone(),
+                popped_at: Instant::now(),
+                worker: worker.map(|worker| worker.to_string()),
+                visibility_timeout,
+            };
+            waiting.insert(item.id.clone(), in_flight);
+        } else {
+            return Err(Error::QueueCorrupted);
+        };
+        self.counters.popped.increment();
+        self.pop_rate.record(Instant::now());
+        self.emit(|hook| hook.on_pop(&item));
+        Ok(())
+    }
+
+    // Takes the next task matching the capabilities, and hides it from other workers
+    // for the given time, so the worker can look at it before deciding to take it.
+    // The task is only processed once the same worker confirms it with confirm_reservation,
+    // otherwise it's put back in the queue when the reservation expires.
+    pub fn reserve(&mut self, capabilities: Vec<String>, reservation_ttl: Duration, worker: Option<&str>) -> Result<Option<QueueItem<T>>, Error> {
+        self.ensure_writable()?;
+        if !self.is_ready() {
+            return Err(Error::NotReady);
+        }
+        self.validate_capability_format(&capabilities)?;
+        self.ensure_below_processing_limit()?;
+        let capabilities = self.without_denied(capabilities);
+
+        let item = match self.try_pop(&capabilities, worker)? {
+            Some(item) => item,
+            None => return Ok(None),
+        };
+        match self.reservations.lock() {
+            Ok(mut reservations) => {
+                reservations.insert(item.id, Reservation {
+                    item: item.clone(),
+                    worker: worker.map(|worker| worker.to_string()),
+                    until: Instant::now() + reservation_ttl,
+                });
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+        Ok(Some(item))
+    }
+
+    // Starts processing a reserved task.
+    // Returns false if the task isn't reserved by the worker, for example if the reservation has expired.
+    pub fn confirm_reservation(&mut self, id: Uuid, worker: Option<&str>) -> Result<bool, Error> {
+        self.ensure_writable()?;
+        let reservation = match self.reservations.lock() {
+            Ok(mut reservations) => match reservations.get(&id) {
+                Some(reservation) if reservation.worker.as_deref() == worker => reservations.remove(&id),
+                _ => None,
+            },
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        match reservation {
+            Some(reservation) if reservation.until > Instant::now() => {
+                let visibility_timeout = self.visibility_timeout;
+                self.start_processing(&reservation.item, worker, visibility_timeout)?;
+                Ok(true)
+            }
+            Some(reservation) => {
+                self.add_item_to_queue(reservation.item)?;
+                Ok(false)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // Puts tasks back in the queue once their reservation expires
+    fn release_expired_peek_reservations(&mut self) -> Result<(), Error> {
+        let expired: Vec<QueueItem<T>> = match self.reservations.lock() {
+            Ok(mut reservations) => {
+                if reservations.is_empty() {
+                    return Ok(());
+                }
+                let now = Instant::now();
+                let expired: Vec<Uuid> = reservations
+                    .values()
+                    .filter(|reservation| reservation.until <= now)
+                    .map(|reservation| reservation.item.id)
+                    .collect();
+                expired.iter().filter_map(|id| reservations.remove(id)).map(|reservation| reservation.item).collect()
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for item in expired {
+            self.add_item_to_queue(item)?;
+        }
+        Ok(())
+    }
+
+    pub fn scan_depth(&self) -> ScanDepth {
+        self.queue.scan_depth()
+    }
+
+    pub fn stats(&self) -> Result<Stats, Error> {
+        let processing = match self.processing.lock() {
+            Ok(processing) => processing.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let pending_by_level = self.queue.len_by_level()?;
+        let high_priority = pending_by_level
+            .iter()
+            .filter(|(level, _)| *level >= Priority::High.level())
+            .map(|(_, count)| count)
+            .sum();
+        let low_priority = pending_by_level
+            .iter()
+            .filter(|(level, _)| *level == Priority::Low.level())
+            .map(|(_, count)| count)
+            .sum();
+        Ok(Stats {
+            pending_by_level,
+            high_priority,
+            low_priority,
+            queued: self.queued_len()?,
+            processing,
+            enqueued: self.counters.enqueued.get(),
+            acknowledged: self.counters.acknowledged.get(),
+        })
+    }
+
+    pub fn diagnostic_dump(&self) -> Result<DiagnosticDump, Error> {
+        let (processing, oldest_processing) = match self.processing.lock() {
+            Ok(waiting) => (
+                waiting.len(),
+                waiting.values().map(|in_flight| in_flight.age()).max(),
+            ),
+            _ => return Err(Error::QueueCorrupted),
+        };
+
+        let (garbage_collection_running, storage_file_sizes) = match self.file_manager.read() {
+            Ok(manager) => (manager.is_garbage_collecting(), manager.file_sizes()?),
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+
+        Ok(DiagnosticDump {
+            uptime_milliseconds: duration_to_millis(self.started_at.elapsed()),
+            high_priority_queued: self.queue.len_from(&Priority::High)?,
+            low_priority_queued: self.queue.len()? - self.queue.len_from(&Priority::High)?,
+            processing,
+            oldest_processing_milliseconds: oldest_processing.map(duration_to_millis),
+            garbage_collection_running,
+            storage_file_sizes,
+            counters: self.counters.totals(),
+            scan_depth: self.queue.scan_depth(),
+            queued_bytes: self.queued_bytes(),
+            spilled: match self.spilled.lock() {
+                Ok(spilled) => spilled.len(),
+                Err(_) => return Err(Error::QueueCorrupted),
+            },
+            max_processing: self.max_processing,
+            waiting_pops: self.waiting_pops(),
+        })
+    }
+
+    // Gets the tasks currently being processed, the one that has been processing the longest first
+    pub fn list_processing(&self) -> Result<Vec<InFlightItem<T>>, Error> {
+        let mut processing: Vec<InFlightItem<T>> = match self.processing.lock() {
+            Ok(processing) => processing.values().cloned().collect(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        processing.sort_by_key(|in_flight| in_flight.popped_at);
+        Ok(processing)
+    }
+
+    // Gets all the tasks waiting in memory, high priority tasks first
+    pub fn get_all(&self) -> Result<Vec<QueueItem<T>>, Error> {
+        self.queue.get_all()
+    }
+
+    // Gets the task waiting in memory that a worker with the capabilities would get next,
+    // without handing it out. Only the tasks with the priority are looked at, if given.
+    pub fn peek(&self, capabilities: Vec<String>, priority: Option<Priority>) -> Result<Option<QueueItem<T>>, Error> {
+        self.validate_capability_format(&capabilities)?;
+        let capabilities = self.without_denied(capabilities);
+        self.queue.peek(&Tags::from(capabilities), priority.as_ref())
+    }
+
+    // Gets a page of the tasks waiting in memory, in the order they were enqueued.
+    // The page starts after the task the cursor points at, so paging through the queue
+    // neither skips nor repeats tasks when tasks are popped or enqueued in between.
+    // Returns the page, and the cursor for the next page if there might be more tasks.
+    pub fn list_page(&self, after: Option<&PageCursor>, limit: usize) -> Result<(Vec<QueueItem<T>>, Option<PageCursor>), Error> {
+        let mut items: Vec<QueueItem<T>> = self
+            .get_all()?
+            .into_iter()
+            .filter(|item| after.map_or(true, |after| PageCursor::of(item) > *after))
+            .collect();
+        items.sort_by_key(PageCursor::of);
+
+        let more = items.len() > limit;
+        items.truncate(limit);
+        let next = match items.last() {
+            Some(last) if more => Some(PageCursor::of(last)),
+            _ => None,
+        };
+        Ok((items, next))
+    }
+
+    // Finds the tasks waiting in memory that requires the given tags, high priority tasks first.
+    // Unlike pop this matches on the required tags, and not on what a worker can handle.
+    // Returns a page of the tasks, along with the total number of matching tasks.
+    pub fn find_by_tags(&self, tags: &Tags, mode: &TagMatch, offset: usize, limit: usize) -> Result<(Vec<QueueItem<T>>, usize), Error> {
+        let matching: Vec<QueueItem<T>> = self
+            .get_all()?
+            .into_iter()
+            .filter(|item| item.required_tags.matches(tags, mode))
+            .collect();
+
+        let total = matching.len();
+        let page = matching.into_iter().skip(offset).take(limit).collect();
+        Ok((page, total))
+    }
+
+    // How many tasks are waiting in the queue, not counting tasks being processed
+    pub fn queued_len(&self) -> Result<usize, Error> {
+        let reserved = match self.reserved.lock() {
+            Ok(reserved) => reserved.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let declined = match self.declined.lock() {
+            Ok(declined) => declined.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let timed_out = match self.timed_out.lock() {
+            Ok(timed_out) => timed_out.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let spilled = match self.spilled.lock() {
+            Ok(spilled) => spilled.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        // Delayed tasks are still waiting to be processed, even if they can't be popped yet
+        let scheduled = match self.scheduled.lock() {
+            Ok(scheduled) => scheduled.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        // Reserved tasks aren't processed until the reservation is confirmed
+        let reservations = match self.reservations.lock() {
+            Ok(reservations) => reservations.len(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        Ok(self.queue.len()? + reserved + declined + timed_out + spilled + scheduled + reservations)
+    }
+
+    // Removes every waiting and processing task, both from memory and from disk,
+    // so the queue starts over without restarting the server. The dead letters are kept.
+    // Tasks that were processing when purged are no longer processing when acknowledged.
+    // Returns how many tasks were removed.
+    pub fn purge(&mut self) -> Result<usize, Error> {
+        self.ensure_writable()?;
+
+        let mut removed = self.queue.clear()?;
+        removed += clear_shared(&self.reserved)?;
+        removed += clear_shared(&self.declined)?;
+        removed += clear_shared(&self.timed_out)?;
+        removed += clear_shared(&self.scheduled)?;
+        removed += clear_shared(&self.reservations)?;
+        removed += clear_shared(&self.processing)?;
+        removed += match self.spilled.lock() {
+            Ok(mut spilled) => {
+                let len = spilled.len();
+                spilled.high_priority.clear();
+                spilled.low_priority.clear();
+                len
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        clear_shared(&self.requeued_on_pause)?;
+        match self.idempotency_keys.lock() {
+            Ok(mut keys) => *keys = IdempotencyKeys::new(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+        self.queued_bytes.store(0, Ordering::SeqCst);
+
+        match self.file_manager.read() {
+            Ok(manager) => manager.truncate_all()?,
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+
+        self.signal_completed();
+        Ok(removed)
+    }
+
+    // The estimated number of bytes used by the tasks queued in memory
+    pub fn queued_bytes(&self) -> usize {
+        self.queued_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn server_info(&self) -> ServerInfo {
+        ServerInfo {
+            uptime: self.started_at.elapsed(),
+            version: env!("CARGO_PKG_VERSION"),
+            git_commit: option_env!("BRQUEUE_GIT_COMMIT"),
+            storage_file_sizes: match self.file_manager.read() {
+                Ok(manager) => manager.file_sizes().ok(),
+                Err(_) => None,
+            },
+            priority_bands: self.priority_bands.clone(),
+            default_priority: Priority::Low,
+        }
+    }
+
+    // Records on disk that the task is done, so it's not loaded again
+    fn mark_as_completed(&self, id: &Uuid) -> Result<(), Error> {
+        match self.recently_completed.lock() {
+            Ok(mut recently_completed) => recently_completed.insert(*id),
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+        match self.idempotency_keys.lock() {
+            Ok(mut keys) => keys.remove(id),
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+
+        if let Ok(manager) = self.file_manager.read() {
+            manager.mark_as_completed(id)?;
+            Ok(())
+        } else {
+            Err(Error::MutexCorrupted)
+        }
+    }
+
+    // Removes completed tasks from the storage files
+    pub fn run_garbage_collection(&self) -> Result<(), Error> {
+        // The manager is cloned, so items can still be saved while garbage collection is running
+        let mut manager = match self.file_manager.read() {
+            Ok(manager) => manager.clone(),
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        self.counters.garbage_collections.increment();
+        manager.run_garbage_collection()?;
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub fn simulate_clock(&mut self, clock: fn() -> SystemTime) {
+        self.clock = clock;
+    }
+
+    #[cfg(test)]
+    pub fn simulate_full_disk(&self) {
+        if let Ok(mut manager) = self.file_manager.write() {
+            manager.simulate_full_disk();
+        }
+    }
+
+    // Wakes up everyone waiting for the queue to become empty
+    fn signal_completed(&self) {
+        let (lock, condvar) = &*self.completed_signal;
+        // Take the lock, so we can't signal between a waiter checking
+        // if the queue is empty and starting to wait
+        let _guard = lock.lock();
+        condvar.notify_all();
+    }
+
+    fn is_empty(&self) -> Result<bool, Error> {
+        let processing = match self.processing.lock() {
+            Ok(waiting) => waiting.len(),
+            _ => return Err(Error::QueueCorrupted),
+        };
+
+        Ok(processing == 0 && self.queued_len()? == 0)
+    }
+
+    // Blocks until there is nothing queued and nothing processing, or until the timeout runs out.
+    // Returns true if the queue became empty
+    pub fn wait_until_empty(&self, timeout: Option<Duration>) -> Result<bool, Error> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let (lock, condvar) = &*self.completed_signal;
+
+        let mut guard = match lock.lock() {
+            Ok(guard) => guard,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+
+        loop {
+            if self.is_empty()? {
+                return Ok(true);
+            }
+
+            guard = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(false);
+                    }
+                    match condvar.wait_timeout(guard, deadline - now) {
+                        Ok((guard, _)) => guard,
+                        Err(_) => return Err(Error::MutexCorrupted),
+                    }
+                }
+                None => match condvar.wait(guard) {
+                    Ok(guard) => guard,
+                    Err(_) => return Err(Error::MutexCorrupted),
+                },
+            };
+        }
+    }
+
+    // Removes a task that hasn't been handed to a worker yet, so it's never processed.
+    // Returns false if the task isn't waiting in the queue.
+    pub fn cancel(&mut self, id: Uuid) -> Result<bool, Error> {
+        let span = debug_span!("cancel", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let mut found = match self.queue.remove_by_id(&id)? {
+            Some(item) => {
+                self.removed_from_queue(&item);
+                true
+            }
+            None => false,
+        };
+
+        if !found {
+            found = match self.spilled.lock() {
+                Ok(mut spilled) => {
+                    let SpilledItems { high_priority, low_priority } = &mut *spilled;
+                    [high_priority, low_priority].iter_mut().any(|ids| {
+                        match ids.iter().position(|spilled| spilled.id == id) {
+                            Some(position) => {
+                                ids.remove(position);
+                                true
+                            }
+                            None => false,
+                        }
+                    })
+                }
+                Err(_) => return Err(Error::QueueCorrupted),
+            };
+        }
+
+        let mut item = None;
+
+        if !found {
+            item = match self.reserved.lock() {
+                Ok(mut reserved) => match reserved.iter().position(|reservation| reservation.item.id == id) {
+                    Some(position) => Some(reserved.remove(position).item),
+                    None => None,
+                },
+                Err(_) => return Err(Error::QueueCorrupted),
+            };
+        }
+
+        if !found && item.is_none() {
+            item = match self.reservations.lock() {
+                Ok(mut reservations) => reservations.remove(&id).map(|reservation| reservation.item),
+                Err(_) => return Err(Error::QueueCorrupted),
+            };
+        }
+
+        if !found && item.is_none() {
+            item = match self.scheduled.lock() {
+                Ok(mut scheduled) => match scheduled.iter().position(|scheduled| scheduled.id == id) {
+                    Some(position) => Some(scheduled.remove(position)),
+                    None => None,
+                },
+                Err(_) => return Err(Error::QueueCorrupted),
+            };
+        }
+
+        if found || item.is_some() {
+            self.mark_as_completed(&id)?;
+            self.signal_completed();
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    // Replaces the data of a queued task, without changing its position in the queue.
+    // Returns false if the task isn't queued, either because it's already being processed
+    // or completed, or because it's only kept on disk due to the memory limit.
+    // After a restart the task is ordered by when it was last changed.
+    pub fn update_payload(&mut self, id: Uuid, data: T) -> Result<bool, Error> {
+        self.ensure_writable()?;
+        self.validate_payload(&data)?;
+        let mut previous_size = 0;
+        let updated = self.queue.update_by_id(&id, |item| {
+            previous_size = estimate_size(item);
+            item.data = data;
+        })?;
+
+        match updated {
+            Some(item) => {
+                self.queued_bytes.fetch_sub(previous_size, Ordering::SeqCst);
+                self.queued_bytes.fetch_add(estimate_size(&item), Ordering::SeqCst);
+
+                if let Ok(manager) = self.file_manager.read() {
+                    manager.save_item(&item)?;
+                } else {
+                    return Err(Error::MutexCorrupted);
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // Stops handing out tasks, for example during maintenance. Tasks can still be enqueued.
+    // Returns how many tasks being processed were put back in the queue.
+    pub fn pause(&mut self, on_processing: ProcessingOnPause) -> Result<usize, Error> {
+        self.paused.store(true, Ordering::SeqCst);
+        if on_processing == ProcessingOnPause::Leave {
+            return Ok(0);
+        }
+
+        let taken: Vec<QueueItem<T>> = match self.processing.lock() {
+            Ok(mut processing) => processing.drain().map(|(_, in_flight)| in_flight.item).collect(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        match self.requeued_on_pause.lock() {
+            Ok(mut requeued) => requeued.extend(taken.iter().map(|item| item.id)),
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+
+        let count = taken.len();
+        for item in taken {
+            self.add_item_to_queue(item)?;
+        }
+        Ok(count)
+    }
+
+    // Starts handing out tasks again after the server has been paused
+    pub fn resume(&mut self) -> Result<(), Error> {
+        self.paused.store(false, Ordering::SeqCst);
+        match self.requeued_on_pause.lock() {
+            Ok(mut requeued) => requeued.clear(),
+            Err(_) => return Err(Error::QueueCorrupted),
+        }
+        self.wake_waiter()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    // Gets ready for the process to exit. Nothing more is handed out, the tasks being
+    // processed are put back in the queue, and everything buffered is written to disk.
+    // The tasks being processed were never marked completed, so they are loaded
+    // again on the next start. Returns how many tasks were being processed.
+    pub fn shutdown(&mut self) -> Result<usize, Error> {
+        let requeued = self.pause(ProcessingOnPause::RequeueAll)?;
+        if self.read_only {
+            return Ok(requeued);
+        }
+
+        match self.file_manager.write() {
+            Ok(mut manager) => {
+                manager.flush_completed()?;
+                manager.flush_data()?;
+            }
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+        Ok(requeued)
+    }
+
+    // Stops or resumes handing out tasks that require the capability.
+    // Returns every paused capability after the change.
+    pub fn set_capability_paused(&mut self, capability: String, paused: bool) -> Result<Vec<String>, Error> {
+        let all_paused = match self.paused_capabilities.write() {
+            Ok(mut paused_capabilities) => {
+                if paused {
+                    paused_capabilities.insert(capability);
+                } else {
+                    paused_capabilities.remove(&capability);
+                }
+                let mut all_paused: Vec<String> = paused_capabilities.iter().cloned().collect();
+                all_paused.sort();
+                all_paused
+            }
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        if !paused {
+            // The resumed tasks might be what a waiting worker is looking for
+            self.wake_waiter()?;
+        }
+        Ok(all_paused)
+    }
+
+    // Moves a queued task to another priority. The task is put at the back of
+    // the queue for the new priority, as if it had just been enqueued there.
+    // Returns false if the task isn't queued, either because it's already being processed
+    // or completed, or because it's only kept on disk due to the memory limit.
+    pub fn set_item_priority(&mut self, id: Uuid, priority: Priority) -> Result<bool, Error> {
+        self.ensure_writable()?;
+        self.validate_priority(&priority)?;
+        let mut item = match self.queue.remove_by_id(&id)? {
+            Some(item) => item,
+            None => return Ok(false),
+        };
+        self.removed_from_queue(&item);
+
+        let previous_priority = item.priority.clone();
+        item.priority = priority;
+
+        let saved = match self.file_manager.read() {
+            Ok(manager) => manager.save_changed_item(&item, &previous_priority).map_err(Error::from),
+            Err(_) => Err(Error::MutexCorrupted),
+        };
+        if let Err(e) = saved {
+            // Put the task back as it was, so it isn't lost from the queue
+            item.priority = previous_priority;
+            self.add_item_to_queue(item)?;
+            return Err(e);
+        }
+
+        self.add_item_to_queue(item)?;
+        Ok(true)
+    }
+
+    // Moves a queued task to another queue, keeping its id. The task is saved in the other
+    // queue before it's removed from this one, so a crash in between can't lose it,
+    // although the task might then be in both queues after a restart.
+    // Returns false if the task isn't queued, either because it's already being processed
+    // or completed, or because it's only kept on disk due to the memory limit.
+    pub fn move_item_to(&mut self, id: Uuid, target: &mut QueueServer<T>) -> Result<bool, Error> {
+        self.ensure_writable()?;
+        target.ensure_writable()?;
+        let item = match self.queue.remove_by_id(&id)? {
+            Some(item) => item,
+            None => return Ok(false),
+        };
+        self.removed_from_queue(&item);
+
+        if let Err(e) = target.enqueue_moved(item.clone()) {
+            // Put the task back as it was, so it isn't lost from either queue
+            self.add_item_to_queue(item)?;
+            return Err(e);
+        }
+
+        self.mark_as_completed(&id)?;
+        self.signal_completed();
+        Ok(true)
+    }
+
+    // Adds a task moved from another queue. It's validated like a new task,
+    // and put at the back of the queue for its priority.
+    fn enqueue_moved(&mut self, mut item: QueueItem<T>) -> Result<(), Error> {
+        self.validate_priority(&item.priority)?;
+        self.validate_capabilities(&item.required_tags.to_sorted_vec())?;
+        self.validate_payload(&item.data)?;
+        item.sequence = self.next_sequence.fetch_add(1, Ordering::SeqCst);
+
+        self.save_item(&item)?;
+        if let Some(key) = &item.idempotency_key {
+            match self.idempotency_keys.lock() {
+                Ok(mut keys) => keys.insert(key.clone(), item.id),
+                Err(_) => return Err(Error::QueueCorrupted),
+            }
+        }
+        self.queue_saved_item(item)
+    }
+
+    // Tracks how often tasks requiring the tags are completed
+    fn record_acknowledged(&self, tags: &Tags) -> Result<(), Error> {
+        // The time between acknowledgements only says something about
+        // the processing speed if the workers had something else to do
+        let has_backlog = self.queue.len_with_tags(tags)? > 0;
+
+        let mut rates = match self.processing_rates.lock() {
+            Ok(rates) => rates,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        let now = Instant::now();
+        let rate = rates.entry(tags.to_sorted_vec()).or_insert_with(|| ProcessingRate {
+            last_acknowledged: now,
+            interval: MovingAverage::new(),
+        });
+        if has_backlog && rate.last_acknowledged < now {
+            rate.interval.add_sample(duration_to_millis(now - rate.last_acknowledged) as f64);
+        }
+        rate.last_acknowledged = now;
+
+        Ok(())
+    }
+
+    // Estimates how long it will take to empty the queue, if tasks keep being popped as fast
+    // as they have been recently. None if nothing has been popped recently.
+    pub fn estimate_drain_time(&self) -> Result<Option<Duration>, Error> {
+        self.estimate_drain_time_at(Instant::now())
+    }
+
+    fn estimate_drain_time_at(&self, now: Instant) -> Result<Option<Duration>, Error> {
+        let queued = self.queued_len()?;
+        let per_second = self.pop_rate.per_second(now);
+        if per_second <= 0.0 {
+            return Ok(None);
+        }
+        Ok(Some(Duration::from_millis((queued as f64 / per_second * 1000.0) as u64)))
+    }
+
+    // Estimates how long a new task requiring the given capabilities would wait before
+    // being handed to a worker, based on how many tasks the workers able to handle it
+    // would be handed first, and how fast such tasks have been processed recently.
+    // Zero if a worker able to handle the task is idle, or nothing is ahead of it,
+    // since the task would be taken right away, or if there isn't enough history to make an estimate.
+    pub fn estimated_wait(&self, capabilities: Vec<String>) -> Result<Duration, Error> {
+        let tags = Tags::from(capabilities);
+        if self.idle_workers()?.iter().any(|worker| worker.is_superset(&tags)) {
+            return Ok(Duration::from_millis(0));
+        }
+        let pending = self.queue.len_handled_by(&tags)?;
+        if pending == 0 {
+            return Ok(Duration::from_millis(0));
+        }
+
+        let interval = match self.processing_rates.lock() {
+            Ok(rates) => rates.get(&tags.to_sorted_vec()).map(|rate| rate.interval.get()).unwrap_or(0.0),
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        Ok(Duration::from_millis((pending as f64 * interval) as u64))
+    }
+
+    // Takes the task out of processing, if it was handed to the given worker.
+    // Once the visibility timeout of a task runs out it can be handed to another worker,
+    // and the worker it timed out for must not be able to finish it for them.
+    fn take_processing(&self, id: &Uuid, worker: Option<&str>) -> Result<Option<InFlightItem<T>>, Error> {
+        match self.processing.lock() {
+            Ok(mut processing) => {
+                let owned = match processing.get(id) {
+                    Some(in_flight) => in_flight.worker.as_deref() == worker,
+                    None => false,
+                };
+                Ok(if owned { processing.remove(id) } else { None })
+            }
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Marks a task as completed
+    pub fn acknowledge(&mut self, id: Uuid) -> Result<Acknowledgement, Error> {
+        self.acknowledge_as_worker(id, None)
+    }
+
+    // Marks a task as completed, if it's being processed by the given worker.
+    // Tasks popped by another worker are not processing as far as the caller is concerned.
+    pub fn acknowledge_as_worker(&mut self, id: Uuid, worker: Option<&str>) -> Result<Acknowledgement, Error> {
+        let span = debug_span!("acknowledge", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let item = self.take_processing(&id, worker)?;
+
+        match item {
+            Some(in_flight) => {
+                self.counters.acknowledged.increment();
+                self.emit(|hook| hook.on_acknowledge(&id));
+                self.record_acknowledged(&in_flight.item.required_tags)?;
+                let result = self.mark_as_completed(&id);
+                self.signal_completed();
+                result.map(|()| Acknowledgement::Completed)
+            }
+            // Already on the completed index, or removed by garbage collection,
+            // so there is no need to write it again
+            None => match self.recently_completed.lock() {
+                Ok(recently_completed) if recently_completed.contains(&id) => Ok(Acknowledgement::AlreadyCompleted),
+                Ok(_) => match self.requeued_on_pause.lock() {
+                    Ok(requeued) if requeued.contains(&id) => Ok(Acknowledgement::Requeued),
+                    Ok(_) => Ok(Acknowledgement::NotProcessing),
+                    Err(_) => Err(Error::QueueCorrupted),
+                },
+                Err(_) => Err(Error::QueueCorrupted),
+            },
+        }
+    }
+
+    // Marks every task currently processing that matches the predicate as completed.
+    // Returns how many tasks were acknowledged.
+    pub fn acknowledge_matching<F>(&mut self, predicate: F) -> Result<usize, Error>
+        where F: Fn(&InFlightItem<T>) -> bool {
+        self.ensure_writable()?;
+        let matching: Vec<Uuid> = match self.processing.lock() {
+            Ok(mut waiting) => {
+                let ids: Vec<Uuid> = waiting
+                    .values()
+                    .filter(|in_flight| predicate(in_flight))
+                    .map(|in_flight| in_flight.item.id)
+                    .collect();
+                for id in &ids {
+                    waiting.remove(id);
+                }
+                ids
+            }
+            _ => return Err(Error::QueueCorrupted),
+        };
+
+        self.counters.acknowledged.add(matching.len());
+        for id in &matching {
+            self.emit(|hook| hook.on_acknowledge(id));
+        }
+        let result: Result<(), Error> = matching.iter().map(|id| self.mark_as_completed(id)).collect();
+        self.signal_completed();
+        result.map(|()| matching.len())
+    }
+
+    // Marks tasks as failed, and puts them back in the queue
+    pub fn fail(&mut self, id: Uuid) -> Result<(), Error> {
+        self.fail_as_worker(id, None)
+    }
+
+    // Fails the task like fail, if it's being processed by the given worker.
+    // Nothing is changed if another worker is processing it.
+    pub fn fail_as_worker(&mut self, id: Uuid, worker: Option<&str>) -> Result<(), Error> {
+        let span = debug_span!("fail", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let item = self.take_processing(&id, worker)?;
+
+        match item {
+            Some(in_flight) => {
+                self.counters.failed.increment();
+                self.emit(|hook| hook.on_fail(&id));
+                match self.record_failure(in_flight.item, true)? {
+                    Some(item) => self.add_item_to_queue(item),
+                    None => Ok(()),
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Marks a task as failed. If failed task affinity is enabled the task is kept
+    // for the worker for a while, otherwise it's put back in the queue right away.
+    pub fn fail_for_worker(&mut self, id: Uuid, worker: &str) -> Result<(), Error> {
+        let span = debug_span!("fail", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        if self.failed_affinity_window == Duration::from_millis(0) {
+            return self.fail_as_worker(id, Some(worker));
+        }
+
+        let item = self.take_processing(&id, Some(worker))?;
+
+        match item {
+            Some(in_flight) => {
+                self.counters.failed.increment();
+                self.emit(|hook| hook.on_fail(&id));
+                let item = match self.record_failure(in_flight.item, true)? {
+                    Some(item) => item,
+                    None => return Ok(()),
+                };
+                match self.reserved.lock() {
+                    Ok(mut reserved) => reserved.push(ReservedItem {
+                        item,
+                        worker: worker.to_string(),
+                        until: Instant::now() + self.failed_affinity_window,
+                    }),
+                    Err(_) => return Err(Error::QueueCorrupted),
+                }
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Gives a processing task back without failing it, because the worker can't handle it
+    // right now, e.g. because it's missing a dependency. The task is offered to
+    // the other workers first, and isn't counted as failed.
+    pub fn decline(&mut self, id: Uuid, worker: &str) -> Result<(), Error> {
+        let span = debug_span!("decline", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let item = self.take_processing(&id, Some(worker))?;
+
+        match item {
+            Some(in_flight) => {
+                match self.declined.lock() {
+                    Ok(mut declined) => declined.push(DeclinedItem {
+                        item: in_flight.item,
+                        worker: worker.to_string(),
+                        until: Instant::now() + DECLINED_REDELIVERY_WINDOW,
+                    }),
+                    Err(_) => return Err(Error::QueueCorrupted),
+                }
+                // Let a waiting worker pick it up
+                self.wake_waiter()
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Counts a failed attempt at handling the task. Returns the task if it should be tried again,
+    // or None if it has failed too many times, in which case it's moved to the dead letter queue.
+    // If save is set the attempts are saved, so they are remembered after a restart.
+    fn record_failure(&mut self, mut item: QueueItem<T>, save: bool) -> Result<Option<QueueItem<T>>, Error> {
+        item.attempts += 1;
+        if self.max_attempts == 0 {
+            return Ok(Some(item));
+        }
+        if item.attempts >= self.max_attempts {
+            self.move_to_dead_letters(item)?;
+            return Ok(None);
+        }
+
+        if save {
+            match self.file_manager.read() {
+                Ok(manager) => manager.save_item(&item)?,
+                Err(_) => return Err(Error::MutexCorrupted),
+            }
+        }
+        Ok(Some(item))
+    }
+
+    // Takes a task that failed too many times out of the queue, and keeps it until it's drained
+    fn move_to_dead_letters(&mut self, mut item: QueueItem<T>) -> Result<(), Error> {
+        item.dead_lettered_at = Some((self.clock)());
+        debug!("Moving task {} to the dead letter queue after {} attempts", item.id, item.attempts);
+        let mut dead_letters = match self.dead_letters.lock() {
+            Ok(dead_letters) => dead_letters,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        match self.file_manager.read() {
+            Ok(manager) => manager.save_dead_letter(&item)?,
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+
+        self.counters.dead_lettered.increment();
+        self.emit(|hook| hook.on_dead_letter(&item));
+        let result = self.mark_as_completed(&item.id);
+        self.signal_completed();
+        dead_letters.push(item);
+        result
+    }
+
+    // The tasks that failed too many times, in the order they failed for the last time
+    pub fn dead_letters(&self) -> Result<Vec<QueueItem<T>>, Error> {
+        match self.dead_letters.lock() {
+            Ok(dead_letters) => Ok(dead_letters.clone()),
+            Err(_) => Err(Error::QueueCorrupted),
+        }
+    }
+
+    // Takes every task out of the dead letter queue, for example to handle them manually
+    pub fn drain_dead_letters(&mut self) -> Result<Vec<QueueItem<T>>, Error> {
+        self.ensure_writable()?;
+        let mut dead_letters = match self.dead_letters.lock() {
+            Ok(dead_letters) => dead_letters,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+        match self.file_manager.read() {
+            Ok(manager) => manager.clear_dead_letters()?,
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+        Ok(dead_letters.drain(..).collect())
+    }
+
+    // Writes every dead letter to the file as json, one per line, so they can be analysed
+    // without the server. If clear is set the exported dead letters are taken out of the
+    // dead letter queue. Returns how many were exported.
+    pub fn export_dead_letters(&mut self, out: &path::Path, clear: bool) -> Result<usize, Error> {
+        self.export_dead_letters_to(BufWriter::new(File::create(out)?), clear)
+    }
+
+    // Same as export_dead_letters, but writes to any writer.
+    // The dead letters are only cleared once they have all been written.
+    pub fn export_dead_letters_to<W: Write>(&mut self, mut writer: W, clear: bool) -> Result<usize, Error> {
+        if clear {
+            self.ensure_writable()?;
+        }
+        let mut dead_letters = match self.dead_letters.lock() {
+            Ok(dead_letters) => dead_letters,
+            Err(_) => return Err(Error::QueueCorrupted),
+        };
+
+        for item in dead_letters.iter() {
+            serde_json::to_writer(&mut writer, &ExportedDeadLetter::from(item)).map_err(IOError::from)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+
+        let exported = dead_letters.len();
+        if clear {
+            match self.file_manager.read() {
+                Ok(manager) => manager.clear_dead_letters()?,
+                Err(_) => return Err(Error::MutexCorrupted),
+            }
+            dead_letters.clear();
+        }
+        Ok(exported)
+    }
+
+    // Deletes the dead letters past the retention limits right away, instead of waiting
+    // for the background sweep. Returns how many were deleted.
+    pub fn purge_old_dead_letters(&mut self) -> Result<usize, Error> {
+        self.ensure_writable()?;
+        purge_dead_letters(&self.dead_letters, &self.file_manager, self.dead_letter_retention, (self.clock)())
+    }
+
+    // Marks a task as failed, and puts a changed version of it back in the queue
+    fn fail_with_change<F>(&mut self, id: Uuid, worker: Option<&str>, change: F) -> Result<(), Error>
+        where F: FnOnce(&mut QueueItem<T>) {
+        let span = debug_span!("fail", id = %id);
+        let _entered = span.enter();
+        self.ensure_writable()?;
+        let item = self.take_processing(&id, worker)?;
+
+        match item {
+            Some(in_flight) => {
+                self.counters.failed.increment();
+                self.emit(|hook| hook.on_fail(&id));
+                let mut item = in_flight.item;
+                let previous_priority = item.priority.clone();
+                change(&mut item);
+                // Saved with the change below
+                let item = match self.record_failure(item, false)? {
+                    Some(item) => item,
+                    None => return Ok(()),
+                };
+
+                if let Ok(manager) = self.file_manager.read() {
+                    manager.save_changed_item(&item, &previous_priority)?;
+                } else {
+                    return Err(Error::MutexCorrupted);
+                }
+
+                self.add_item_to_queue(item)
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Marks a task as failed, and puts it back in the queue requiring
+    // a new set of capabilities, so it can be picked up by other workers
+    pub fn fail_with_capabilities(&mut self, id: Uuid, required_capabilities: Vec<String>, worker: Option<&str>) -> Result<(), Error> {
+        self.validate_capabilities(&required_capabilities)?;
+
+        self.fail_with_change(id, worker, |item| item.required_tags = Tags::from(required_capabilities))
+    }
+
+    // Marks a task as failed, and puts it back in the queue with another priority.
+    // Useful to stop tasks that keeps failing from jumping the line.
+    pub fn fail_with_priority(&mut self, id: Uuid, priority: Priority, worker: Option<&str>) -> Result<(), Error> {
+        self.validate_priority(&priority)?;
+
+        self.fail_with_change(id, worker, |item| item.priority = priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::spawn;
+
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    fn setup() -> String {
+        format!("{}test", setup_test_storage().unwrap())
+    }
+
+    mod enqueue_and_pop {
+        use super::*;
+
+        #[test]
+        fn enqueue_and_pop_with_wait_for_message() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
+            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]);
+
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "foo"
+            );
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "bar"
+            );
+
+            let mut q = qs.clone();
+
+            let h1 = spawn(move || {
+                thread::sleep_ms(50);
+                q.enqueue("baz".to_string(), Priority::High, vec!["foo".to_string()]);
+            });
+
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "baz"
+            );
+
+            h1.join().expect("Failed to join thread");
+        }
+
+        #[test]
+        #[ignore]
+        fn enqueue_and_pop_with_long_wait() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let mut q = qs.clone();
+
+            let h1 = spawn(move || {
+                thread::sleep_ms(3000);
+                q.enqueue("baz".to_string(), Priority::High, vec!["foo".to_string()]);
+            });
+
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "baz"
+            );
+
+            h1.join().expect("Failed to join thread");
+        }
+
+        #[test]
+        fn enqueue_and_pop_without_wait_for_message() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
+            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]);
+
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], false)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "foo"
+            );
+            assert_eq!(
+                qs.pop(vec!["foo".to_string(), "bar".to_string()], false)
+                    .unwrap()
+                    .unwrap()
+                    .data,
+                "bar"
+            );
+
+            assert!(qs
+                .pop(vec!["foo".to_string(), "bar".to_string()], false)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        #[ignore]
+        fn rough_benchmark() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            let mut handles = Vec::new();
+            for i in 0..100 {
+                let mut q = qs.clone();
+                let handle = spawn(move || {
+                    for j in 0..10000 {
+                        q.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
+                    }
+                });
+                handles.push(handle);
+            }
+            for h in handles {
+                h.join();
+            }
+        }
+    }
+
+    mod waiting_pop {
+        use super::*;
+
+        #[test]
+        fn waiting_pop_gets_highest_priority_item() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let mut q = qs.clone();
+
+            let h1 = spawn(move || {
+                while q.idle_workers().unwrap().is_empty() {
+                    thread::sleep(Duration::from_millis(5));
+                }
+                // Both items arrive together, to make sure the waiter
+                // isn't just handed the first item that came in
+                q.enqueue_batch(vec![
+                    ("low".to_string(), Priority::Low, vec![]),
+                    ("high".to_string(), Priority::High, vec![]),
+                ]).expect("Failed to enqueue");
+            });
+
+            assert_eq!(qs.pop(vec![], true).unwrap().unwrap().data, "high");
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "low");
+
+            h1.join().expect("Failed to join thread");
+        }
+
+        #[test]
+        fn waiting_pop_finds_items_on_the_configured_tick() {
+            let storage_path = setup();
+            let config = Config {
+                wait_tick: Duration::from_millis(20),
+                ..Config::default()
+            };
+            let mut qs = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+
+            let mut q = qs.clone();
+            let h1 = spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                // Without waking the waiter, so it has to find the item on its own
+                q.queue.enqueue(QueueItem::new("foo".to_string(), Tags::new(), Priority::High))
+                    .expect("Failed to enqueue");
+                Instant::now()
+            });
+
+            assert_eq!(qs.pop(vec![], true).unwrap().unwrap().data, "foo");
+            let enqueued_at = h1.join().expect("Failed to join thread");
+            assert!(enqueued_at.elapsed() < Duration::from_millis(500));
+        }
+
+        // How long a waiting pop takes to find a task enqueued after it looked through the queue,
+        // but before it started waiting
+        fn missed_wake_up_latency(wake_buffer: usize, wait_tick: Duration) -> Duration {
+            let config = Config {
+                wait_tick,
+                wake_buffer,
+                ..Config::default()
+            };
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(setup(), &config)
+                .expect("Failed to create queue server");
+
+            qs.queue.enqueue(QueueItem::new("foo".to_string(), Tags::new(), Priority::High))
+                .expect("Failed to enqueue");
+            qs.wake_waiter().expect("Failed to wake waiter");
+
+            let started = Instant::now();
+            let item = qs.wait_for_item(&[], None, &never(), &never()).unwrap().unwrap();
+            assert_eq!(item.data, "foo");
+            started.elapsed()
+        }
+
+        #[test]
+        fn buffered_wake_ups_are_not_missed() {
+            let wait_tick = Duration::from_millis(300);
+            assert!(missed_wake_up_latency(0, wait_tick) >= wait_tick);
+            assert!(missed_wake_up_latency(4, wait_tick) < Duration::from_millis(100));
+        }
+
+        #[test]
+        fn waiting_pop_can_be_cancelled() {
+            let storage_path = setup();
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let (cancel, cancelled) = bounded(1);
+            let h1 = spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                cancel.send(()).expect("Failed to cancel");
+            });
+
+            match qs.pop_cancellable(vec![], true, &cancelled) {
+                Err(Error::PopCancelled) => {}
+                _ => panic!("Pop was not cancelled"),
+            }
+
+            h1.join().expect("Failed to join thread");
+        }
+
+        #[test]
+        fn waiting_pop_does_not_receive_items_it_cannot_handle() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let mut q = qs.clone();
+
+            let h1 = spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                q.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()])
+                    .expect("Failed to enqueue");
+                q.enqueue("foo".to_string(), Priority::Low, vec!["foo".to_string()])
+                    .expect("Failed to enqueue");
+            });
+
+            assert_eq!(qs.pop(vec!["foo".to_string()], true).unwrap().unwrap().data, "foo");
+
+            h1.join().expect("Failed to join thread");
+        }
+    }
+
+    mod acknowledge_and_fail {
+        use super::*;
+
+        #[test]
+        fn acknowledge_will_remove() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let id = qs
+                .enqueue("foo".to_string(), Priority::High, vec![])
+                .expect("Failed to enqueue task");
+
+            let item = qs
+                .pop(vec![], false)
+                .expect("Failed to pop item")
+                .expect("Not item received");
+
+            assert_eq!(item.id, id.id);
+
+            qs.acknowledge(id.id).expect("Failed to acknowledge task");
+
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+        }
+
+        #[test]
+        fn fail_will_re_enqueue() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let id = qs
+                .enqueue("foo".to_string(), Priority::High, vec![])
+                .expect("Failed to enqueue task");
+
+            let item = qs
+                .pop(vec![], false)
+                .expect("Failed to pop item")
+                .expect("Not item received");
+
+            assert_eq!(item.id, id.id);
+
+            qs.fail(id.id).expect("Failed to fail task");
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, item.id);
+        }
+    }
+
+    mod startup_load {
+        use super::*;
+
+        #[test]
+        fn pops_are_rejected_until_the_stored_tasks_are_loaded() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone()).expect("Failed to create queue server");
+            let stored = qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            drop(qs);
+
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to reopen queue server")
+                .until_loaded();
+            assert!(!qs.is_ready());
+            match qs.pop(vec![], false) {
+                Err(Error::NotReady) => {}
+                _ => panic!("Expected the pop to be rejected"),
+            }
+            // Tasks enqueued while loading are not loaded twice
+            let new = qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+
+            assert_eq!(qs.load_stored_items().unwrap(), 1);
+            assert!(qs.is_ready());
+            assert_eq!(qs.queued_len().unwrap(), 2);
+            let mut popped = vec![qs.pop(vec![], false).unwrap().unwrap().id, qs.pop(vec![], false).unwrap().unwrap().id];
+            popped.sort();
+            let mut expected = vec![stored.id, new.id];
+            expected.sort();
+            assert_eq!(popped, expected);
+        }
+
+        // Processing tasks don't need a file of their own: a task stays in the item file
+        // until it's acknowledged, and only then is it marked as completed.
+        // So load_items puts tasks that were processing when the server stopped back in the queue.
+        #[test]
+        fn processing_tasks_are_queued_again_after_a_restart() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone()).expect("Failed to create queue server");
+            let processing = qs.enqueue("processing".to_string(), Priority::High, vec![]).unwrap();
+            let acknowledged = qs.enqueue("acknowledged".to_string(), Priority::High, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(acknowledged.id).unwrap();
+            qs.run_garbage_collection().unwrap();
+            drop(qs);
+
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to reopen queue server");
+            assert_eq!(qs.load_stored_items().unwrap(), 1);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, processing.id);
+        }
+    }
+
+    mod atomic_batch {
+        use super::*;
+
+        fn batch_item(message: &str) -> BatchItem<String> {
+            BatchItem {
+                message: message.to_string(),
+                priority: Priority::High,
+                required_capabilities: vec![],
+                available_at: None,
+                expires_at: None,
+            }
+        }
+
+        #[test]
+        fn batch_ids_are_returned_in_input_order() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let created = qs.enqueue_batch(vec![
+                ("low".to_string(), Priority::Low, vec![]),
+                ("high".to_string(), Priority::High, vec!["a".to_string()]),
+            ]).unwrap();
+
+            assert_eq!(qs.pop(vec!["a".to_string()], false).unwrap().unwrap().id, created[1].id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, created[0].id);
+        }
+
+        #[test]
+        fn nothing_is_enqueued_if_an_item_is_rejected() {
+            let storage_path = setup();
+            let validator: PayloadValidator<String> = Arc::new(|payload: &String| {
+                if payload.is_empty() { Err("empty".to_string()) } else { Ok(()) }
+            });
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server")
+                .with_payload_validator(validator);
+
+            match qs.enqueue_atomically(None, vec![batch_item("foo"), batch_item(""), batch_item("bar")]) {
+                Err(Error::BatchItemRejected(1, _)) => {}
+                _ => panic!("Expected the second item to be rejected"),
+            }
+            assert_eq!(qs.queued_len().unwrap(), 0);
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.enqueued, 0);
+
+            let stored: QueueServer<String> = QueueServer::open_read_only(storage_path).unwrap();
+            assert!(stored.get_all().unwrap().is_empty());
+        }
+
+        #[test]
+        fn every_item_is_enqueued_in_order() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone()).expect("Failed to create queue server");
+
+            let mut low = batch_item("low");
+            low.priority = Priority::Low;
+            let created = qs.enqueue_atomically(None, vec![batch_item("foo"), low, batch_item("bar")]).unwrap();
+            assert_eq!(created.len(), 3);
+
+            let popped: Vec<Uuid> = (0..3).map(|_| qs.pop(vec![], false).unwrap().unwrap().id).collect();
+            assert_eq!(popped, vec![created[0].id, created[2].id, created[1].id]);
+
+            let stored: QueueServer<String> = QueueServer::open_read_only(storage_path).unwrap();
+            assert_eq!(stored.get_all().unwrap().len(), 3);
+        }
+    }
+
+    mod decline {
+        use super::*;
+
+        fn pop(qs: &mut QueueServer<String>, worker: &str) -> Option<QueueItem<String>> {
+            qs.pop_as_worker(Some(worker), vec![], false, &never()).unwrap()
+        }
+
+        #[test]
+        fn declined_task_goes_to_another_worker_without_failing() {
+            let mut qs = QueueServer::new_with_filename(setup()).expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = pop(&mut qs, "a").unwrap();
+            qs.decline(item.id, "a").unwrap();
+
+            assert!(pop(&mut qs, "a").is_none());
+            assert_eq!(pop(&mut qs, "b").unwrap().id, item.id);
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.failed, 0);
+        }
+
+        #[test]
+        fn declining_worker_gets_the_task_after_the_window() {
+            let mut qs = QueueServer::new_with_filename(setup()).expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = pop(&mut qs, "a").unwrap();
+            qs.decline(item.id, "a").unwrap();
+            assert_eq!(qs.queued_len().unwrap(), 1);
+
+            thread::sleep(DECLINED_REDELIVERY_WINDOW + Duration::from_millis(50));
+            assert_eq!(pop(&mut qs, "a").unwrap().id, item.id);
+        }
+    }
+
+    mod visibility_timeout {
+        use super::*;
+
+        #[test]
+        fn unacknowledged_task_is_put_back_after_the_timeout() {
+            let mut qs = QueueServer::new_with_filename(setup()).expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop_with_visibility_timeout(None, vec![], false, &never(), Some(Duration::from_millis(50))).unwrap().unwrap();
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+
+            thread::sleep(Duration::from_millis(50) + VISIBILITY_SCAN_INTERVAL * 2);
+            assert_eq!(qs.queued_len().unwrap(), 1);
+            assert!(qs.list_processing().unwrap().is_empty());
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, item.id);
+        }
+
+        #[test]
+        fn timeout_set_on_the_server_applies_to_plain_pops() {
+            let mut qs = QueueServer::new_with_filename(setup())
+                .expect("Failed to create queue server")
+                .with_visibility_timeout(Duration::from_millis(50));
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+
+            thread::sleep(Duration::from_millis(50) + VISIBILITY_SCAN_INTERVAL * 2);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, item.id);
+        }
+
+        #[test]
+        fn configured_timeout_is_used_by_default() {
+            let mut config = Config::default();
+            config.visibility_timeout = Duration::from_millis(50);
+            let mut qs = QueueServer::new_with_config(setup(), &config).expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let acknowledged = qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(acknowledged.id).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+
+            thread::sleep(Duration::from_millis(50) + VISIBILITY_SCAN_INTERVAL * 2);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, item.id);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+        }
+
+        #[test]
+        fn late_worker_cannot_finish_a_task_handed_to_another_worker() {
+            let mut qs = QueueServer::new_with_filename(setup()).expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop_with_visibility_timeout(Some("a"), vec![], false, &never(), Some(Duration::from_millis(50))).unwrap().unwrap();
+            thread::sleep(Duration::from_millis(50) + VISIBILITY_SCAN_INTERVAL * 2);
+            assert_eq!(qs.pop_as_worker(Some("b"), vec![], false, &never()).unwrap().unwrap().id, item.id);
+
+            assert_eq!(qs.acknowledge_as_worker(item.id, Some("a")).unwrap(), Acknowledgement::NotProcessing);
+            qs.fail_as_worker(item.id, Some("a")).unwrap();
+            qs.fail_with_priority(item.id, Priority::Low, Some("a")).unwrap();
+            qs.decline(item.id, "a").unwrap();
+            assert_eq!(qs.list_processing().unwrap()[0].worker, Some("b".to_string()));
+            assert_eq!(qs.queued_len().unwrap(), 0);
+
+            assert_eq!(qs.acknowledge_as_worker(item.id, Some("b")).unwrap(), Acknowledgement::Completed);
+        }
+    }
+
+    mod failed_affinity {
+        use super::*;
+
+        fn setup_with_window(window: Duration) -> QueueServer<String> {
+            let mut config = Config::default();
+            config.failed_affinity_window = window;
+            QueueServer::new_with_config(setup(), &config).expect("Failed to create queue server")
+        }
+
+        fn pop(qs: &mut QueueServer<String>, worker: &str) -> Option<QueueItem<String>> {
+            qs.pop_as_worker(Some(worker), vec![], false, &never()).unwrap()
+        }
+
+        #[test]
+        fn failing_worker_gets_the_item_back_first() {
+            let mut qs = setup_with_window(Duration::from_secs(60));
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+
+            let item = pop(&mut qs, "a").unwrap();
+            assert_eq!(item.data, "foo");
+            qs.fail_for_worker(item.id, "a").unwrap();
+
+            // Other workers don't get it while it's reserved
+            assert_eq!(pop(&mut qs, "b").unwrap().data, "bar");
+            assert!(pop(&mut qs, "b").is_none());
+            assert_eq!(qs.queued_len().unwrap(), 1);
+
+            assert_eq!(pop(&mut qs, "a").unwrap().id, item.id);
+        }
+
+        #[test]
+        fn other_workers_get_the_item_after_the_window() {
+            let mut qs = setup_with_window(Duration::from_millis(50));
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = pop(&mut qs, "a").unwrap();
+            qs.fail_for_worker(item.id, "a").unwrap();
+
+            assert!(pop(&mut qs, "b").is_none());
+            thread::sleep(Duration::from_millis(100));
+            assert_eq!(pop(&mut qs, "b").unwrap().id, item.id);
+        }
+
+        #[test]
+        fn disabled_affinity_requeues_right_away() {
+            let mut qs = setup_with_window(Duration::from_millis(0));
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = pop(&mut qs, "a").unwrap();
+            qs.fail_for_worker(item.id, "a").unwrap();
+
+            assert_eq!(pop(&mut qs, "b").unwrap().id, item.id);
+        }
+    }
+
+    mod estimated_wait {
+        use super::*;
+
+        #[test]
+        fn estimates_from_backlog_and_processing_rate() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            for _ in 0..10 {
+                qs.enqueue("foo".to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+            }
+            qs.enqueue("bar".to_string(), Priority::High, vec!["y".to_string()]).unwrap();
+
+            // No history yet
+            assert_eq!(qs.estimated_wait(vec!["x".to_string()]).unwrap(), Duration::from_millis(0));
+
+            // Process a task every 20ms
+            for _ in 0..5 {
+                let item = qs.pop(vec!["x".to_string()], false).unwrap().unwrap();
+                thread::sleep(Duration::from_millis(20));
+                qs.acknowledge(item.id).unwrap();
+            }
+
+            // 5 tasks left at around 20ms each
+            let estimate = qs.estimated_wait(vec!["x".to_string()]).unwrap();
+            assert!(estimate >= Duration::from_millis(80), "Estimate too low: {:?}", estimate);
+            assert!(estimate <= Duration::from_millis(300), "Estimate too high: {:?}", estimate);
+
+            // Nothing is known about how fast the other tasks are processed
+            assert_eq!(qs.estimated_wait(vec!["y".to_string()]).unwrap(), Duration::from_millis(0));
+            // And nothing waits for tasks nothing else is requiring
+            assert_eq!(qs.estimated_wait(vec!["z".to_string()]).unwrap(), Duration::from_millis(0));
+        }
+
+        #[test]
+        fn counts_tasks_requiring_fewer_capabilities() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            for _ in 0..6 {
+                qs.enqueue("foo".to_string(), Priority::High, vec!["x".to_string(), "y".to_string()]).unwrap();
+            }
+            for _ in 0..3 {
+                let item = qs.pop(vec!["x".to_string(), "y".to_string()], false).unwrap().unwrap();
+                thread::sleep(Duration::from_millis(20));
+                qs.acknowledge(item.id).unwrap();
+            }
+            let with_own_tags = qs.estimated_wait(vec!["x".to_string(), "y".to_string()]).unwrap();
+
+            // Workers able to handle x and y pop these as well
+            for _ in 0..10 {
+                qs.enqueue("bar".to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+            }
+            let estimate = qs.estimated_wait(vec!["x".to_string(), "y".to_string()]).unwrap();
+            assert!(estimate > with_own_tags * 3, "{:?} is not above {:?}", estimate, with_own_tags * 3);
+        }
+
+        #[test]
+        fn zero_when_a_matching_worker_is_idle() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            for _ in 0..4 {
+                qs.enqueue("foo".to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+            }
+            for _ in 0..3 {
+                let item = qs.pop(vec!["x".to_string()], false).unwrap().unwrap();
+                thread::sleep(Duration::from_millis(20));
+                qs.acknowledge(item.id).unwrap();
+            }
+            qs.enqueue("foo".to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+            assert!(qs.estimated_wait(vec!["x".to_string()]).unwrap() > Duration::from_millis(0));
+
+            // Only workers able to handle the task matter
+            let mut worker = qs.clone();
+            let handle = thread::spawn(move || worker.pop(vec!["y".to_string()], true));
+            while qs.idle_workers().unwrap().is_empty() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            assert!(qs.estimated_wait(vec!["x".to_string()]).unwrap() > Duration::from_millis(0));
+            assert_eq!(qs.estimated_wait(vec!["y".to_string()]).unwrap(), Duration::from_millis(0));
+
+            qs.enqueue("bar".to_string(), Priority::High, vec!["y".to_string()]).unwrap();
+            handle.join().unwrap().unwrap().unwrap();
+        }
+    }
+
+    mod find_by_tags {
+        use super::*;
+
+        #[test]
+        fn finds_items_requiring_the_tags() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("a".to_string(), Priority::Low, vec!["x".to_string()]).unwrap();
+            qs.enqueue("b".to_string(), Priority::Low, vec!["x".to_string(), "y".to_string()]).unwrap();
+            qs.enqueue("c".to_string(), Priority::Low, vec!["y".to_string()]).unwrap();
+            qs.enqueue("d".to_string(), Priority::High, vec!["x".to_string(), "z".to_string()]).unwrap();
+            qs.enqueue("e".to_string(), Priority::Low, vec![]).unwrap();
+
+            let find = |tags: Vec<&str>, mode: TagMatch, offset: usize, limit: usize| {
+                let (items, total) = qs.find_by_tags(&Tags::from(tags), &mode, offset, limit).unwrap();
+                (items.into_iter().map(|item| item.data).collect::<Vec<String>>(), total)
+            };
+
+            assert_eq!(find(vec!["x"], TagMatch::All, 0, 10), (vec!["d".to_string(), "a".to_string(), "b".to_string()], 3));
+            assert_eq!(find(vec!["x", "y"], TagMatch::All, 0, 10), (vec!["b".to_string()], 1));
+            assert_eq!(find(vec!["y", "z"], TagMatch::Any, 0, 10), (vec!["d".to_string(), "b".to_string(), "c".to_string()], 3));
+
+            // Paging
+            assert_eq!(find(vec!["x"], TagMatch::All, 0, 2), (vec!["d".to_string(), "a".to_string()], 3));
+            assert_eq!(find(vec!["x"], TagMatch::All, 2, 2), (vec!["b".to_string()], 3));
+            assert_eq!(find(vec!["x"], TagMatch::All, 4, 2), (vec![], 3));
+
+            // Nothing was removed from the queue
+            assert_eq!(qs.queued_len().unwrap(), 5);
+        }
+    }
+
+    mod peek {
+        use super::*;
+
+        #[test]
+        fn peeks_without_removing() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let low = qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            let high = qs.enqueue("high".to_string(), Priority::High, vec!["a".to_string()]).unwrap();
+
+            assert_eq!(qs.peek(vec!["a".to_string()], None).unwrap().unwrap().id, high.id);
+            assert_eq!(qs.peek(vec![], None).unwrap().unwrap().id, low.id);
+            assert_eq!(qs.peek(vec!["a".to_string()], Some(Priority::Low)).unwrap().unwrap().id, low.id);
+            assert!(qs.peek(vec![], Some(Priority::Level(5))).unwrap().is_none());
+            assert_eq!(qs.queued_len().unwrap(), 2);
+
+            assert_eq!(qs.pop(vec!["a".to_string()], false).unwrap().unwrap().id, high.id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, low.id);
+        }
+    }
+
+    mod purge {
+        use super::*;
+
+        #[test]
+        fn removes_waiting_and_processing_tasks_from_memory_and_disk() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            let processing = qs.enqueue("processing".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+
+            assert_eq!(qs.purge().unwrap(), 3);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert!(qs.list_processing().unwrap().is_empty());
+            assert_eq!(qs.acknowledge(processing.id).unwrap(), Acknowledgement::NotProcessing);
+
+            let stored = qs.file_manager.write().unwrap().load_items().unwrap();
+            assert!(stored.high_priority.is_empty());
+            assert!(stored.low_priority.is_empty());
+
+            // The storage can still be used afterwards
+            let created = qs.enqueue("after".to_string(), Priority::Low, vec![]).unwrap();
+            drop(qs);
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            assert_eq!(qs.load_stored_items().unwrap(), 1);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, created.id);
+        }
+    }
+
+    mod pause {
+        use super::*;
+
+        #[test]
+        fn leaves_processing_tasks_with_the_workers() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let processing = qs.enqueue("processing".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("queued".to_string(), Priority::High, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+
+            assert_eq!(qs.pause(ProcessingOnPause::Leave).unwrap(), 0);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert_eq!(qs.acknowledge(processing.id).unwrap(), Acknowledgement::Completed);
+
+            qs.resume().unwrap();
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "queued");
+        }
+
+        #[test]
+        fn requeues_processing_tasks() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let first = qs.enqueue("first".to_string(), Priority::High, vec![]).unwrap();
+            let second = qs.enqueue("second".to_string(), Priority::Low, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+
+            assert_eq!(qs.pause(ProcessingOnPause::RequeueAll).unwrap(), 2);
+            assert!(qs.list_processing().unwrap().is_empty());
+            assert_eq!(qs.queued_len().unwrap(), 2);
+            assert_eq!(qs.acknowledge(first.id).unwrap(), Acknowledgement::Requeued);
+            assert_eq!(qs.acknowledge(second.id).unwrap(), Acknowledgement::Requeued);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+
+            qs.resume().unwrap();
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, first.id);
+            assert_eq!(qs.acknowledge(first.id).unwrap(), Acknowledgement::Completed);
+        }
+    }
+
+    mod shutdown {
+        use super::*;
+
+        #[test]
+        fn saves_acknowledgements_and_keeps_processing_tasks() {
+            let storage_path = setup();
+            let mut config = Config::default();
+            config.acknowledge_flush_interval = Duration::from_secs(60 * 60);
+            let mut qs = QueueServer::new_with_config(storage_path.clone(), &config).expect("Failed to create queue server");
+
+            let acknowledged = qs.enqueue("acknowledged".to_string(), Priority::High, vec![]).unwrap();
+            let processing = qs.enqueue("processing".to_string(), Priority::High, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(qs.acknowledge(acknowledged.id).unwrap(), Acknowledgement::Completed);
+
+            assert_eq!(qs.shutdown().unwrap(), 1);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            drop(qs);
+
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path).expect("Failed to reopen queue server");
+            assert_eq!(qs.load_stored_items().unwrap(), 1);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, processing.id);
+        }
+    }
+
+    mod priority_aging {
+        use super::*;
+
+        fn an_hour_from_now() -> SystemTime {
+            SystemTime::now() + Duration::from_secs(60 * 60)
+        }
+
+        fn setup_with_aging(storage_path: String) -> QueueServer<String> {
+            setup_with_aging_and_order(storage_path, QueueOrder::Fifo)
+        }
+
+        fn setup_with_aging_and_order(storage_path: String, order: QueueOrder) -> QueueServer<String> {
+            let mut config = Config::default();
+            config.priority_bands = (0..6).map(|level| level.to_string()).collect();
+            config.priority_aging = Some(PriorityAging {
+                step: Duration::from_secs(10 * 60),
+                max_level: Priority::High.level(),
+            });
+            config.queue_order = order;
+            QueueServer::new_with_config(storage_path, &config).expect("Failed to create queue server")
+        }
+
+        #[test]
+        fn low_priority_tasks_that_waited_long_enough_are_popped_first() {
+            let storage_path = setup();
+            let mut qs = setup_with_aging(storage_path);
+
+            let waited = qs.enqueue("waited".to_string(), Priority::Low, vec![]).unwrap();
+            qs.simulate_clock(an_hour_from_now);
+            let high = qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+            let low = qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, waited.id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, high.id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, low.id);
+        }
+
+        #[test]
+        fn tasks_are_not_aged_past_the_max_level() {
+            let storage_path = setup();
+            let mut qs = setup_with_aging(storage_path);
+
+            qs.enqueue("waited".to_string(), Priority::Low, vec![]).unwrap();
+            qs.simulate_clock(an_hour_from_now);
+            let urgent = qs.enqueue("urgent".to_string(), Priority::Level(5), vec![]).unwrap();
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, urgent.id);
+        }
+
+        #[test]
+        fn tasks_that_have_not_waited_long_enough_keep_their_priority() {
+            let storage_path = setup();
+            let mut qs = setup_with_aging(storage_path);
+
+            qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            let high = qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, high.id);
+        }
+
+        #[test]
+        fn old_tasks_are_aged_when_the_queue_is_last_in_first_out() {
+            let storage_path = setup();
+            let mut qs = setup_with_aging_and_order(storage_path, QueueOrder::Lifo);
+
+            let waited = qs.enqueue("waited".to_string(), Priority::Low, vec![]).unwrap();
+            qs.simulate_clock(an_hour_from_now);
+            let low = qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            let high = qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+
+            // The aged task has the same priority as the high priority one, which is newer
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, high.id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, waited.id);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, low.id);
+        }
+    }
+
+    mod list_page {
+        use super::*;
+
+        #[test]
+        fn pages_through_every_item_once() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            for i in 0..25 {
+                let priority = if i % 3 == 0 { Priority::High } else { Priority::Low };
+                qs.enqueue(format!("item {}", i), priority, vec![]).unwrap();
+            }
+
+            let mut seen = Vec::new();
+            let mut cursor: Option<PageCursor> = None;
+            loop {
+                let (page, next) = qs.list_page(cursor.as_ref(), 10).unwrap();
+                assert!(page.len() <= 10);
+                seen.extend(page.into_iter().map(|item| item.data));
+
+                // Popping between pages doesn't make the pages skip or repeat items
+                qs.pop(vec![], false).unwrap();
+
+                // The cursor survives being sent to a client and back
+                match next {
+                    Some(next) => cursor = Some(next.to_string().parse().unwrap()),
+                    None => break,
+                }
+            }
+
+            let expected: Vec<String> = (0..25).map(|i| format!("item {}", i)).collect();
+            assert_eq!(seen, expected);
+        }
+
+        #[test]
+        fn rejects_invalid_cursors() {
+            assert!("".parse::<PageCursor>().is_err());
+            assert!("12".parse::<PageCursor>().is_err());
+            assert!("x:4c5e0e6a-6d5e-4f3b-9b1a-1f6b9a0c2d3e".parse::<PageCursor>().is_err());
+        }
+    }
+
+    mod read_only {
+        use super::*;
+
+        #[test]
+        fn read_only_queue_can_be_listed_but_not_changed() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+            qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+
+            let mut read_only: QueueServer<String> = QueueServer::open_read_only(storage_path)
+                .expect("Failed to open read-only");
+
+            let all: Vec<String> = read_only.get_all().unwrap().into_iter().map(|item| item.data).collect();
+            assert_eq!(all, vec!["high".to_string(), "low".to_string()]);
+
+            match read_only.enqueue("foo".to_string(), Priority::High, vec![]) {
+                Err(Error::ReadOnly) => {}
+                _ => panic!("Enqueued to a read-only queue"),
+            }
+            match read_only.pop(vec![], false) {
+                Err(Error::ReadOnly) => {}
+                _ => panic!("Popped from a read-only queue"),
+            }
+            assert_eq!(read_only.queued_len().unwrap(), 2);
+        }
+    }
+
+    mod memory_limit {
+        use super::*;
+
+        fn setup_with_limit(limit: usize) -> QueueServer<String> {
+            let mut config = Config::default();
+            config.memory_limit_bytes = limit;
+            QueueServer::new_with_config(setup(), &config).expect("Failed to create queue server")
+        }
+
+        #[test]
+        fn spilled_items_can_be_popped_in_order() {
+            let item_size = estimate_size(&QueueItem::new("item00".to_string(), Tags::new(), Priority::Low));
+            let limit = item_size * 3;
+            let mut qs = setup_with_limit(limit);
+
+            for i in 0..50 {
+                qs.enqueue(format!("item{:02}", i), Priority::Low, vec![]).unwrap();
+                assert!(qs.queued_bytes() <= limit);
+            }
+            assert_eq!(qs.queued_len().unwrap(), 50);
+
+            for i in 0..50 {
+                let item = qs.pop(vec![], false).unwrap().expect("Spilled item was not loaded");
+                assert_eq!(item.data, format!("item{:02}", i));
+                assert!(qs.queued_bytes() <= limit);
+            }
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert_eq!(qs.queued_bytes(), 0);
+        }
+
+        #[test]
+        fn spilled_high_priority_items_come_first() {
+            let item_size = estimate_size(&QueueItem::new("low0".to_string(), Tags::new(), Priority::Low));
+            let mut qs = setup_with_limit(item_size * 2);
+
+            qs.enqueue("low0".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("low1".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("high".to_string(), Priority::High, vec![]).unwrap();
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "high");
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "low0");
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "low1");
+        }
+
+        #[test]
+        fn spilled_items_can_be_found_without_loading_the_others() {
+            let item_size = estimate_size(&QueueItem::new("foo".to_string(), Tags::from(vec!["foo"]), Priority::Low));
+            let limit = item_size * 2;
+            let mut qs = setup_with_limit(limit);
+
+            for _ in 0..5 {
+                qs.enqueue("foo".to_string(), Priority::Low, vec!["foo".to_string()]).unwrap();
+            }
+            let bar = qs.enqueue("bar".to_string(), Priority::Low, vec!["bar".to_string()]).unwrap();
+
+            assert_eq!(qs.pop(vec!["bar".to_string()], false).unwrap().unwrap().id, bar.id);
+            assert!(qs.queued_bytes() <= limit);
+
+            assert!(qs.pop(vec!["baz".to_string()], false).unwrap().is_none());
+            assert!(qs.queued_bytes() <= limit);
+            assert_eq!(qs.queued_len().unwrap(), 5);
+
+            for _ in 0..5 {
+                assert_eq!(qs.pop(vec!["foo".to_string()], false).unwrap().unwrap().data, "foo");
+                assert!(qs.queued_bytes() <= limit);
+            }
+        }
+
+        #[test]
+        fn spilled_items_can_be_cancelled() {
+            let mut qs = setup_with_limit(1);
+
+            let foo = qs.enqueue("foo".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::Low, vec![]).unwrap();
+
+            assert!(qs.cancel(foo.id).unwrap());
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "bar");
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+        }
+    }
+
+    mod cancel {
+        use super::*;
+
+        #[test]
+        fn cancelled_tasks_are_never_popped() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let bar = qs.enqueue("bar".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("baz".to_string(), Priority::Low, vec![]).unwrap();
+
+            assert!(qs.cancel(bar.id).unwrap());
+            assert!(!qs.cancel(bar.id).unwrap());
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "foo");
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "baz");
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+        }
+
+        #[test]
+        fn processing_tasks_cannot_be_cancelled() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let foo = qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+
+            assert!(!qs.cancel(foo.id).unwrap());
+        }
+    }
+
+    mod acknowledge {
+        use super::*;
+
+        #[test]
+        fn reports_tasks_that_are_already_completed() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let queued = qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            assert_eq!(qs.acknowledge(queued.id).unwrap(), Acknowledgement::NotProcessing);
+
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(qs.acknowledge(item.id).unwrap(), Acknowledgement::Completed);
+            qs.run_garbage_collection().unwrap();
+            let sizes = qs.diagnostic_dump().unwrap().storage_file_sizes;
+
+            assert_eq!(qs.acknowledge(item.id).unwrap(), Acknowledgement::AlreadyCompleted);
+            assert_eq!(qs.diagnostic_dump().unwrap().storage_file_sizes, sizes);
+        }
+    }
+
+    mod update_payload {
+        use super::*;
+
+        #[test]
+        fn keeps_the_position_in_the_queue() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            let foo = qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+
+            assert!(qs.update_payload(foo.id, "baz".to_string()).unwrap());
+
+            let popped = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(popped.id, foo.id);
+            assert_eq!(popped.data, "baz");
+
+            // Can't be changed while it's being processed
+            assert!(!qs.update_payload(foo.id, "qux".to_string()).unwrap());
+            qs.acknowledge(foo.id).unwrap();
+            assert!(!qs.update_payload(foo.id, "qux".to_string()).unwrap());
+        }
+
+        #[test]
+        fn changed_payload_is_persisted() {
+            let storage_path = setup();
+            let foo = {
+                let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path.clone())
+                    .expect("Failed to create queue server");
+                let foo = qs.enqueue("foo".to_string(), Priority::Low, vec![]).unwrap();
+                qs.update_payload(foo.id, "bar".to_string()).unwrap();
+                foo
+            };
+
+            let stored: QueueServer<String> = QueueServer::open_read_only(storage_path)
+                .expect("Failed to open storage");
+            let items = stored.get_all().unwrap();
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].id, foo.id);
+            assert_eq!(items[0].data, "bar");
+        }
+    }
+
+    mod fail_with_capabilities {
+        use super::*;
+
+        #[test]
+        fn is_only_popped_by_workers_with_new_capabilities() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]).unwrap();
+            let item = qs.pop(vec!["foo".to_string()], false).unwrap().unwrap();
+
+            qs.fail_with_capabilities(item.id, vec!["bar".to_string()], None)
+                .expect("Failed to fail task");
+
+            assert!(qs.pop(vec!["foo".to_string()], false).unwrap().is_none());
+            let retagged = qs.pop(vec!["bar".to_string()], false).unwrap().unwrap();
+            assert_eq!(retagged.id, item.id);
+            assert_eq!(retagged.required_tags, Tags::from(vec!["bar"]));
+        }
+
+        #[test]
+        fn rejects_capabilities_that_are_not_allowed() {
+            let storage_path = setup();
+            let config = Config {
+                allowed_capabilities: Some(vec!["foo".to_string(), "bar".to_string()]),
+                ..Config::default()
+            };
+            let mut qs = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+
+            assert!(qs.enqueue("foo".to_string(), Priority::High, vec!["baz".to_string()]).is_err());
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]).unwrap();
+            let item = qs.pop(vec!["foo".to_string()], false).unwrap().unwrap();
+
+            match qs.fail_with_capabilities(item.id, vec!["baz".to_string()], None) {
+                Err(Error::CapabilityNotAllowed(capability)) => assert_eq!(capability, "baz"),
+                _ => panic!("Expected the capability to be rejected"),
+            }
+        }
+    }
+
+    mod list_processing {
+        use super::*;
+
+        #[test]
+        fn lists_tasks_that_are_not_acknowledged() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            for _ in 0..3 {
+                qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            }
+
+            let first = qs.pop_as_worker(Some("a"), vec![], false, &never()).unwrap().unwrap();
+            thread::sleep(Duration::from_millis(20));
+            let second = qs.pop_as_worker(Some("b"), vec![], false, &never()).unwrap().unwrap();
+            let acknowledged = qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(acknowledged.id).unwrap();
+
+            let processing = qs.list_processing().unwrap();
+            assert_eq!(processing.len(), 2);
+            assert_eq!(processing[0].item.id, first.id);
+            assert_eq!(processing[0].worker, Some("a".to_string()));
+            assert_eq!(processing[1].item.id, second.id);
+            assert_eq!(processing[1].worker, Some("b".to_string()));
+            assert!(processing[0].age() >= Duration::from_millis(20));
+            assert!(processing[0].age() < Duration::from_secs(5));
+            assert!(processing[1].age() < processing[0].age());
+        }
+    }
+
+    mod max_processing {
+        use super::*;
+
+        #[test]
+        fn refuses_pops_until_tasks_are_acknowledged() {
+            let storage_path = setup();
+            let config = Config {
+                max_processing: 2,
+                ..Config::default()
+            };
+            let mut qs = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+
+            for _ in 0..3 {
+                qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            }
+
+            let first = qs.pop(vec![], false).unwrap().unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            match qs.pop(vec![], false) {
+                Err(Error::TooManyProcessing) => {}
+                _ => panic!("Expected the pop to be refused"),
+            }
+
+            qs.acknowledge(first.id).unwrap();
+            assert!(qs.pop(vec![], false).unwrap().is_some());
+            assert_eq!(qs.diagnostic_dump().unwrap().max_processing, 2);
+        }
+    }
+
+    mod set_item_priority {
+        use super::*;
+
+        #[test]
+        fn bumped_task_is_popped_before_low_priority_tasks() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            qs.enqueue("first".to_string(), Priority::Low, vec![]).unwrap();
+            let bumped = qs.enqueue("bumped".to_string(), Priority::Low, vec![]).unwrap();
+
+            assert!(qs.set_item_priority(bumped.id, Priority::High).unwrap());
+            assert!(!qs.set_item_priority(Uuid::new_v4(), Priority::High).unwrap());
+
+            let popped = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(popped.data, "bumped");
+            assert_eq!(popped.priority, Priority::High);
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "first");
+
+            // The new priority is saved
+            let stored: QueueServer<String> = QueueServer::open_read_only(storage_path).unwrap();
+            let all = stored.get_all().unwrap();
+            assert_eq!(all.len(), 2);
+            assert_eq!(all[0].data, "bumped");
+            assert_eq!(all[0].priority, Priority::High);
+        }
+
+        #[test]
+        fn levels_above_the_priority_bands_are_rejected() {
+            let mut qs = QueueServer::new_with_filename(setup())
+                .expect("Failed to create queue server");
+
+            let item = qs.enqueue("foo".to_string(), Priority::Low, vec![]).unwrap();
+            match qs.set_item_priority(item.id, Priority::Level(5)) {
+                Err(Error::UnknownPriority(5, 2)) => {}
+                _ => panic!("Expected the priority to be rejected"),
+            }
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().priority, Priority::Low);
+        }
+    }
+
+    mod idempotent_enqueue {
+        use super::*;
+
+        fn task(message: String) -> BatchItem<String> {
+            BatchItem {
+                message,
+                priority: Priority::High,
+                required_capabilities: vec![],
+                available_at: None,
+                expires_at: None,
+            }
+        }
+
+        #[test]
+        fn enqueuing_with_the_same_key_returns_the_existing_task() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let first = qs.enqueue_idempotent("key".to_string(), None, task("foo".to_string())).unwrap();
+            let second = qs.enqueue_idempotent("key".to_string(), None, task("bar".to_string())).unwrap();
+            assert!(first.created);
+            assert!(!second.created);
+            assert_eq!(first.id, second.id);
+            assert_eq!(qs.queued_len().unwrap(), 1);
+
+            // The key is freed once the task is completed
+            let popped = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(popped.data, "foo");
+            qs.acknowledge(popped.id).unwrap();
+            let third = qs.enqueue_idempotent("key".to_string(), None, task("baz".to_string())).unwrap();
+            assert!(third.created);
+            assert_ne!(third.id, first.id);
+        }
+
+        #[test]
+        fn keys_are_remembered_after_a_restart() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+            let first = qs.enqueue_idempotent("key".to_string(), None, task("foo".to_string())).unwrap();
+            drop(qs);
+
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.load_stored_items().unwrap();
+            let retried = qs.enqueue_idempotent("key".to_string(), None, task("foo".to_string())).unwrap();
+            assert!(!retried.created);
+            assert_eq!(retried.id, first.id);
+            assert_eq!(qs.queued_len().unwrap(), 1);
+        }
+
+        #[test]
+        fn key_is_freed_if_the_task_cannot_be_saved() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.simulate_full_disk();
+
+            match qs.enqueue_idempotent("key".to_string(), None, task("foo".to_string())) {
+                Err(Error::StorageFull) => {}
+                _ => panic!("Expected the storage to be full"),
+            }
+            assert_eq!(qs.idempotent_task("key").unwrap(), None);
+        }
+
+        // Enqueues another task with a different key, from inside the hook
+        struct EnqueuingHook {
+            qs: Mutex<QueueServer<String>>,
+        }
+
+        impl EventHook<String> for EnqueuingHook {
+            fn on_enqueue(&self, item: &QueueItem<String>) {
+                if item.data == "foo" {
+                    let mut qs = self.qs.lock().unwrap();
+                    qs.enqueue_idempotent("other".to_string(), None, task("bar".to_string())).unwrap();
+                }
+            }
+        }
+
+        #[test]
+        fn hooks_can_enqueue_while_a_task_is_enqueued() {
+            let storage_path = setup();
+            let qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            let hook = Arc::new(EnqueuingHook { qs: Mutex::new(qs.clone()) });
+            let mut qs = qs.with_hook(hook);
+
+            assert!(qs.enqueue_idempotent("key".to_string(), None, task("foo".to_string())).unwrap().created);
+            assert_eq!(qs.queued_len().unwrap(), 2);
+        }
+    }
+
+    mod reserve {
+        use super::*;
+
+        #[test]
+        fn reserved_task_is_hidden_until_the_reservation_expires() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+
+            let reserved = qs.reserve(vec![], Duration::from_millis(100), None).unwrap().unwrap();
+            assert_eq!(reserved.data, "foo");
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert!(qs.list_processing().unwrap().is_empty());
+
+            thread::sleep(Duration::from_millis(150));
+            assert!(!qs.confirm_reservation(reserved.id, None).unwrap());
+            let popped = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(popped.id, reserved.id);
+        }
+
+        #[test]
+        fn confirmed_task_is_processed() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+
+            let reserved = qs.reserve(vec![], Duration::from_secs(10), None).unwrap().unwrap();
+            assert!(qs.confirm_reservation(reserved.id, None).unwrap());
+            assert!(!qs.confirm_reservation(reserved.id, None).unwrap());
+            assert_eq!(qs.list_processing().unwrap().len(), 1);
+            assert_eq!(qs.acknowledge(reserved.id).unwrap(), Acknowledgement::Completed);
+        }
+
+        #[test]
+        fn only_the_reserving_worker_can_confirm() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+
+            let reserved = qs.reserve(vec![], Duration::from_secs(10), Some("a")).unwrap().unwrap();
+            assert_eq!(qs.queued_len().unwrap(), 1);
+            assert!(!qs.confirm_reservation(reserved.id, Some("b")).unwrap());
+            assert!(!qs.confirm_reservation(reserved.id, None).unwrap());
+            assert!(qs.confirm_reservation(reserved.id, Some("a")).unwrap());
+            assert_eq!(qs.list_processing().unwrap()[0].worker, Some("a".to_string()));
+        }
+
+        #[test]
+        fn reservations_count_towards_the_processing_limit() {
+            let storage_path = setup();
+            let config = Config {
+                max_processing: 1,
+                ..Config::default()
+            };
+            let mut qs = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+
+            qs.reserve(vec![], Duration::from_secs(10), None).unwrap().unwrap();
+            match qs.reserve(vec![], Duration::from_secs(10), None) {
+                Err(Error::TooManyProcessing) => {}
+                _ => panic!("Expected the reservation to be refused"),
+            }
+            match qs.pop(vec![], false) {
+                Err(Error::TooManyProcessing) => {}
+                _ => panic!("Expected the pop to be refused"),
+            }
+        }
+
+        #[test]
+        fn reserved_task_can_be_cancelled() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+
+            let reserved = qs.reserve(vec![], Duration::from_secs(10), None).unwrap().unwrap();
+            assert!(qs.cancel(reserved.id).unwrap());
+            assert_eq!(qs.queued_len().unwrap(), 0);
+            assert!(!qs.confirm_reservation(reserved.id, None).unwrap());
+            assert_eq!(qs.acknowledge(reserved.id).unwrap(), Acknowledgement::AlreadyCompleted);
+        }
+    }
+
+    mod max_waiting_pops {
+        use super::*;
+
+        #[test]
+        fn pops_past_the_limit_do_not_wait() {
+            let storage_path = setup();
+            let config = Config {
+                max_waiting_pops: 1,
+                wait_tick: Duration::from_secs(10),
+                ..Config::default()
+            };
+            let mut qs = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+
+            let mut waiter = qs.clone();
+            let handle = thread::spawn(move || waiter.pop(vec![], true));
+            while qs.waiting_pops() == 0 {
+                thread::sleep(Duration::from_millis(1));
+            }
+
+            let started = Instant::now();
+            assert!(qs.pop(vec![], true).unwrap().is_none());
+            assert!(started.elapsed() < Duration::from_secs(1));
+            assert_eq!(qs.diagnostic_dump().unwrap().waiting_pops, 1);
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            assert_eq!(handle.join().unwrap().unwrap().unwrap().data, "foo");
+            assert_eq!(qs.waiting_pops(), 0);
+        }
+    }
+
+    mod payload_validator {
+        use super::*;
+
+        #[test]
+        fn rejects_payloads_the_validator_does_not_accept() {
+            let storage_path = setup();
+            // Payloads must be JSON objects with a name
+            let validator: PayloadValidator<String> = Arc::new(|payload: &String| {
+                match serde_json::from_str::<serde_json::Value>(payload) {
+                    Ok(ref value) if value["name"].is_string() => Ok(()),
+                    Ok(_) => Err("name is missing".to_string()),
+                    Err(e) => Err(format!("{}", e)),
+                }
+            });
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server")
+                .with_payload_validator(validator);
+
+            qs.enqueue("{\"name\": \"foo\"}".to_string(), Priority::High, vec![]).unwrap();
+            match qs.enqueue("{\"id\": 1}".to_string(), Priority::High, vec![]) {
+                Err(Error::InvalidPayload(reason)) => assert_eq!(reason, "name is missing"),
+                _ => panic!("Invalid payload was enqueued"),
+            }
+            assert_eq!(qs.queued_len().unwrap(), 1);
+        }
+
+        #[test]
+        fn rejects_invalid_payload_updates() {
+            let validator: PayloadValidator<String> = Arc::new(|payload: &String| {
+                if payload.is_empty() {
+                    Err("payload is empty".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+            let mut qs = QueueServer::new_with_filename(setup())
+                .expect("Failed to create queue server")
+                .with_payload_validator(validator);
+
+            let item = qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            match qs.update_payload(item.id, String::new()) {
+                Err(Error::InvalidPayload(reason)) => assert_eq!(reason, "payload is empty"),
+                _ => panic!("Invalid payload was saved"),
+            }
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "foo");
+        }
+    }
+
+    mod idle_workers {
+        use super::*;
+
+        #[test]
+        fn enqueued_item_is_handed_directly_to_waiting_worker() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let mut worker = qs.clone();
+            let handle = thread::spawn(move || worker.pop(vec!["foo".to_string()], true));
+
+            while qs.idle_workers().unwrap().is_empty() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            assert_eq!(qs.idle_workers().unwrap(), vec![Tags::from(vec!["foo"])]);
+
+            // Nobody is waiting for this one
+            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]).unwrap();
+            assert_eq!(qs.idle_workers().unwrap().len(), 1);
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]).unwrap();
+            // The worker is busy right away, and the item never went through the queue
+            assert!(qs.idle_workers().unwrap().is_empty());
+            assert_eq!(qs.queued_len().unwrap(), 1);
+
+            let popped = handle.join().unwrap().unwrap().unwrap();
+            assert_eq!(popped.data, "foo");
+            assert_eq!(qs.list_processing().unwrap().len(), 1);
+        }
+
+        #[test]
+        fn handed_over_item_is_requeued_if_the_pop_is_cancelled() {
+            let storage_path = setup();
+            let qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let mut worker = qs.clone();
+            let (idle_id, handed_over) = qs.idle_workers.register(Tags::new()).unwrap();
+            let item = QueueItem::new("foo".to_string(), Tags::new(), Priority::High);
+            assert!(qs.idle_workers.hand_over(item, &HashSet::new(), |_| false).unwrap().is_none());
+
+            match worker.stop_idling(idle_id, &handed_over, Err(Error::PopCancelled)) {
+                Err(Error::PopCancelled) => {}
+                _ => panic!("Pop wasn't cancelled"),
+            }
+            assert_eq!(worker.queued_len().unwrap(), 1);
+        }
+    }
+
+    mod sequence {
+        use super::*;
+
+        #[test]
+        fn sequence_numbers_increase_and_are_saved() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            qs.enqueue("first".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("second".to_string(), Priority::High, vec![]).unwrap();
+            let third = qs.enqueue("third".to_string(), Priority::Low, vec![]).unwrap();
+            // Changing the priority keeps the sequence number
+            qs.set_item_priority(third.id, Priority::High).unwrap();
+
+            let mut popped = Vec::new();
+            while let Some(item) = qs.pop(vec![], false).unwrap() {
+                popped.push(item);
+            }
+            let mut sequences: Vec<u64> = popped.iter().map(|item| item.sequence).collect();
+            sequences.sort();
+            assert_eq!(sequences.len(), 3);
+            assert!(sequences.windows(2).all(|pair| pair[0] < pair[1]));
+
+            let stored: QueueServer<String> = QueueServer::open_read_only(storage_path.clone()).unwrap();
+            for item in stored.get_all().unwrap() {
+                let original = popped.iter().find(|p| p.id == item.id).unwrap();
+                assert_eq!(item.sequence, original.sequence);
+            }
+
+            // A new server continues after the saved sequence numbers
+            let mut reopened = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to reopen queue server");
+            reopened.enqueue("fourth".to_string(), Priority::Low, vec![]).unwrap();
+            let fourth = reopened.pop(vec![], false).unwrap().unwrap();
+            assert!(fourth.sequence > sequences[2]);
+        }
+    }
+
+    mod paused_capabilities {
+        use super::*;
+
+        #[test]
+        fn paused_tasks_stay_queued_until_resumed() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            assert_eq!(qs.set_capability_paused("x".to_string(), true).unwrap(), vec!["x".to_string()]);
+
+            qs.enqueue("x1".to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+            qs.enqueue("y1".to_string(), Priority::Low, vec!["y".to_string()]).unwrap();
+            qs.enqueue("x2".to_string(), Priority::High, vec!["x".to_string(), "y".to_string()]).unwrap();
+            qs.enqueue("y2".to_string(), Priority::Low, vec!["y".to_string()]).unwrap();
+
+            let capabilities = vec!["x".to_string(), "y".to_string()];
+            assert_eq!(qs.pop(capabilities.clone(), false).unwrap().unwrap().data, "y1");
+            assert_eq!(qs.pop(capabilities.clone(), false).unwrap().unwrap().data, "y2");
+            assert!(qs.pop(capabilities.clone(), false).unwrap().is_none());
+
+            assert!(qs.set_capability_paused("x".to_string(), false).unwrap().is_empty());
+            assert_eq!(qs.pop(capabilities.clone(), false).unwrap().unwrap().data, "x1");
+            assert_eq!(qs.pop(capabilities.clone(), false).unwrap().unwrap().data, "x2");
+        }
+    }
+
+    mod denied_capabilities {
+        use super::*;
+
+        fn setup_with_policy(policy: UnroutablePolicy) -> QueueServer<String> {
+            let mut config = Config::default();
+            config.denied_capabilities = vec!["gpu".to_string()];
+            config.unroutable_policy = policy;
+            QueueServer::new_with_config(setup(), &config).expect("Failed to create queue server")
+        }
+
+        #[test]
+        fn task_requiring_a_denied_capability_is_rejected_as_unroutable() {
+            let mut qs = setup_with_policy(UnroutablePolicy::Reject);
+
+            match qs.enqueue("foo".to_string(), Priority::High, vec!["gpu".to_string()]) {
+                Err(Error::Unroutable(capability)) => assert_eq!(capability, "gpu"),
+                _ => panic!("Expected the task to be rejected as unroutable"),
+            }
+            assert_eq!(qs.queued_len().unwrap(), 0);
+
+            // No worker currently having the capability is fine, one could connect later
+            qs.enqueue("bar".to_string(), Priority::High, vec!["cpu".to_string()]).unwrap();
+        }
+
+        #[test]
+        fn workers_never_get_tasks_requiring_a_denied_capability() {
+            let mut qs = setup_with_policy(UnroutablePolicy::Warn);
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["gpu".to_string()]).unwrap();
+            assert!(qs.pop(vec!["gpu".to_string()], false).unwrap().is_none());
+            assert_eq!(qs.queued_len().unwrap(), 1);
+        }
+    }
+
+    mod drain_time {
+        use super::*;
+
+        #[test]
+        fn estimates_drain_time_from_pop_rate() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            let start = Instant::now();
+            qs.pop_rate = RateMeter::new(start);
+
+            for _ in 0..50 {
+                qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            }
+            assert_eq!(qs.estimate_drain_time_at(start).unwrap(), None);
+
+            // 10 pops per second for 2 seconds
+            for i in 0..20 {
+                qs.pop_rate.record(start + Duration::from_millis(i * 100));
+            }
+
+            let estimate = qs.estimate_drain_time_at(start + Duration::from_secs(2)).unwrap().unwrap();
+            assert!(estimate > Duration::from_millis(4500) && estimate < Duration::from_millis(5500), "Estimate was {:?}", estimate);
+        }
+    }
+
+    mod hooks {
+        use super::*;
+
+        struct RecordingHook {
+            events: Mutex<Vec<String>>,
+        }
+
+        impl EventHook<String> for RecordingHook {
+            fn on_enqueue(&self, item: &QueueItem<String>) {
+                self.events.lock().unwrap().push(format!("enqueue {}", item.data));
+            }
+
+            fn on_pop(&self, item: &QueueItem<String>) {
+                self.events.lock().unwrap().push(format!("pop {}", item.data));
+            }
+
+            fn on_acknowledge(&self, _id: &Uuid) {
+                self.events.lock().unwrap().push("acknowledge".to_string());
+            }
+
+            fn on_fail(&self, _id: &Uuid) {
+                self.events.lock().unwrap().push("fail".to_string());
+            }
+        }
+
+        #[test]
+        fn hook_sees_every_event() {
+            let storage_path = setup();
+            let hook = Arc::new(RecordingHook { events: Mutex::new(Vec::new()) });
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server")
+                .with_hook(hook.clone());
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            qs.fail(item.id).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(item.id).unwrap();
+
+            assert_eq!(*hook.events.lock().unwrap(), vec!["enqueue foo", "pop foo", "fail", "pop foo", "acknowledge"]);
+        }
+    }
+
+    mod empty_capabilities {
+        use super::*;
+
+        #[test]
+        fn rejects_empty_capabilities() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            match qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string(), "".to_string()]) {
+                Err(Error::EmptyCapability) => {}
+                _ => panic!("Expected the empty capability to be rejected"),
+            }
+            match qs.pop(vec!["".to_string()], false) {
+                Err(Error::EmptyCapability) => {}
+                _ => panic!("Expected the empty capability to be rejected"),
+            }
+
+            // No capabilities at all is fine
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "foo");
+        }
+    }
+
+    mod scheduled {
+        use super::*;
+
+        #[test]
+        fn tasks_are_not_popped_before_they_are_available() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let available_at = SystemTime::now() + Duration::from_millis(200);
+            qs.enqueue_scheduled(None, "later".to_string(), Priority::High, vec![], Some(available_at))
+                .expect("Failed to enqueue");
+            qs.enqueue("now".to_string(), Priority::Low, vec![])
+                .expect("Failed to enqueue");
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "now");
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+
+            thread::sleep(Duration::from_millis(250));
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "later");
+        }
+
+        #[test]
+        fn delayed_tasks_are_counted_as_queued() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let available_at = SystemTime::now() + Duration::from_secs(60);
+            qs.enqueue_scheduled(None, "later".to_string(), Priority::High, vec![], Some(available_at))
+                .expect("Failed to enqueue");
+
+            assert_eq!(qs.queued_len().unwrap(), 1);
+            assert_eq!(qs.stats().unwrap().queued, 1);
+            assert!(!qs.wait_until_empty(Some(Duration::from_millis(10))).unwrap());
+        }
+
+        #[test]
+        fn delayed_tasks_can_be_cancelled() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            let available_at = SystemTime::now() + Duration::from_millis(100);
+            let created = qs.enqueue_scheduled(None, "later".to_string(), Priority::High, vec![], Some(available_at))
+                .expect("Failed to enqueue");
+
+            assert!(qs.cancel(created.id).unwrap());
+            assert_eq!(qs.queued_len().unwrap(), 0);
+            thread::sleep(Duration::from_millis(150));
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            drop(qs);
+
+            // Not loaded again after a restart either
+            let reopened: QueueServer<String> = QueueServer::open_read_only(storage_path).expect("Failed to open storage");
+            assert_eq!(reopened.queued_len().unwrap(), 0);
+        }
+    }
+
+    mod expiry {
+        use super::*;
+
+        #[test]
+        fn task_expiring_before_it_is_popped_is_dropped() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path.clone())
+                .expect("Failed to create queue server");
+
+            let expires_at = SystemTime::now() + Duration::from_millis(50);
+            let expired = qs.enqueue_expiring(None, "stale".to_string(), Priority::High, vec![], None, Some(expires_at))
+                .expect("Failed to enqueue");
+            thread::sleep(Duration::from_millis(100));
+
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.expired, 1);
+            assert_eq!(qs.acknowledge(expired.id).unwrap(), Acknowledgement::AlreadyCompleted);
+            drop(qs);
+
+            // Marked as completed, so it's not loaded again
+            let reopened: QueueServer<String> = QueueServer::open_read_only(storage_path).expect("Failed to open storage");
+            assert_eq!(reopened.queued_len().unwrap(), 0);
+        }
+
+        #[test]
+        fn task_is_dropped_when_its_ttl_has_passed() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue_with_ttl("stale".to_string(), Priority::Low, vec![], Duration::from_millis(10))
+                .expect("Failed to enqueue");
+            thread::sleep(Duration::from_millis(50));
+
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert_eq!(qs.queued_len().unwrap(), 0);
+        }
+
+        #[test]
+        fn task_is_popped_before_it_expires() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            let expires_at = SystemTime::now() + Duration::from_secs(60);
+            qs.enqueue_expiring(None, "stale".to_string(), Priority::High, vec![], None, Some(SystemTime::now()))
+                .expect("Failed to enqueue");
+            let valid = qs.enqueue_expiring(None, "fresh".to_string(), Priority::High, vec![], None, Some(expires_at))
+                .expect("Failed to enqueue");
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, valid.id);
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+        }
+    }
+
+    mod storage_full {
+        use super::*;
+
+        #[test]
+        fn rejects_tasks_when_the_disk_is_full() {
+            let storage_path = setup();
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+            qs.simulate_full_disk();
+
+            match qs.enqueue("foo".to_string(), Priority::High, vec![]) {
+                Err(Error::StorageFull) => {}
+                _ => panic!("Expected the storage to be full"),
+            }
+            // Garbage collection was tried, in case it could free up space
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.garbage_collections, 1);
+
+            // Tasks are turned away for a while, without trying again
+            match qs.enqueue("foo".to_string(), Priority::High, vec![]) {
+                Err(Error::StorageFull) => {}
+                _ => panic!("Expected the storage to be full"),
             }
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.garbage_collections, 1);
+            assert_eq!(qs.queued_len().unwrap(), 0);
         }
     }
 
-    // Marks a task as completed
-    pub fn acknowledge(&mut self, id: Uuid) -> Result<(), Error> {
-        if let Ok(mut waiting) = self.processing.lock() {
-            waiting.remove(&id);
-            Ok(())
-        } else {
-            Err(Error::QueueCorrupted)
+    mod tag_pattern {
+        use super::*;
+
+        fn create_queue_server() -> QueueServer<String> {
+            let config = Config {
+                tag_pattern: Some(Regex::new("^[a-z]+:[a-z]+$").unwrap()),
+                ..Config::default()
+            };
+            QueueServer::new_with_config(setup(), &config).expect("Failed to create queue server")
         }
-    }
 
-    // Marks tasks as failed, and puts them back in the queue
-    pub fn fail(&mut self, id: Uuid) -> Result<(), Error> {
-        let item = match self.processing.lock() {
-            Ok(mut waiting) => waiting.remove(&id),
-            _ => return Err(Error::QueueCorrupted),
-        };
+        #[test]
+        fn rejects_malformed_capabilities_on_enqueue() {
+            let mut qs = create_queue_server();
 
-        match item {
-            Some(item) => self.add_item_to_queue(item),
-            None => Ok(()),
+            match qs.enqueue("foo".to_string(), Priority::High, vec!["os:linux".to_string(), "linux".to_string(), "Os:Linux".to_string()]) {
+                Err(Error::MalformedCapabilities(capabilities)) => {
+                    assert_eq!(capabilities, vec!["linux".to_string(), "Os:Linux".to_string()])
+                }
+                _ => panic!("Expected the capabilities to be rejected"),
+            }
+
+            qs.enqueue("foo".to_string(), Priority::High, vec!["os:linux".to_string()]).unwrap();
+        }
+
+        #[test]
+        fn rejects_malformed_capabilities_on_pop() {
+            let mut qs = create_queue_server();
+            qs.enqueue("foo".to_string(), Priority::High, vec!["os:linux".to_string()]).unwrap();
+
+            match qs.pop(vec!["os:linux".to_string(), "gpu".to_string()], false) {
+                Err(Error::MalformedCapabilities(capabilities)) => assert_eq!(capabilities, vec!["gpu".to_string()]),
+                _ => panic!("Expected the capabilities to be rejected"),
+            }
+
+            assert_eq!(qs.pop(vec!["os:linux".to_string()], false).unwrap().unwrap().data, "foo");
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::thread::spawn;
+    mod fail_with_priority {
+        use super::*;
 
-    use crate::test_helpers::setup_test_storage;
+        #[test]
+        fn downgraded_task_is_popped_after_high_priority_tasks() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
 
-    use super::*;
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
 
-    fn setup() -> String {
-        format!("{}test", setup_test_storage().unwrap())
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+            qs.fail_with_priority(item.id, Priority::Low, None).expect("Failed to fail task");
+            qs.enqueue("baz".to_string(), Priority::High, vec![]).unwrap();
+
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "bar");
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "baz");
+
+            let downgraded = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(downgraded.id, item.id);
+            assert_eq!(downgraded.priority, Priority::Low);
+        }
+
+        #[test]
+        fn levels_above_the_priority_bands_are_rejected() {
+            let mut qs = QueueServer::new_with_filename(setup())
+                .expect("Failed to create queue server");
+
+            qs.enqueue("foo".to_string(), Priority::Low, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            match qs.fail_with_priority(item.id, Priority::Level(5), None) {
+                Err(Error::UnknownPriority(5, 2)) => {}
+                _ => panic!("Expected the priority to be rejected"),
+            }
+            assert_eq!(qs.list_processing().unwrap().len(), 1);
+        }
     }
 
-    mod enqueue_and_pop {
+    mod acknowledge_matching {
         use super::*;
 
         #[test]
-        fn enqueue_and_pop_with_wait_for_message() {
+        fn only_acknowledges_matching_items() {
             let storage_path = setup();
             let mut qs = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
 
-            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
-            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]);
+            qs.enqueue("foo1".to_string(), Priority::High, vec!["foo".to_string()]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]).unwrap();
+            qs.enqueue("foo2".to_string(), Priority::Low, vec!["foo".to_string()]).unwrap();
 
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "foo"
-            );
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "bar"
-            );
+            let capabilities = vec!["foo".to_string(), "bar".to_string()];
+            let mut ids = Vec::new();
+            while let Some(item) = qs.pop(capabilities.clone(), false).unwrap() {
+                ids.push(item.id);
+            }
+            assert_eq!(ids.len(), 3);
 
-            let mut q = qs.clone();
+            let count = qs
+                .acknowledge_matching(|in_flight| in_flight.item.required_tags.contains("foo"))
+                .expect("Failed to acknowledge");
+            assert_eq!(count, 2);
 
-            let h1 = spawn(move || {
-                thread::sleep_ms(50);
-                q.enqueue("baz".to_string(), Priority::High, vec!["foo".to_string()]);
-            });
+            // Simulate the worker disconnecting, which fails all of its tasks
+            for id in ids {
+                qs.fail(id).expect("Failed to fail task");
+            }
 
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "baz"
-            );
+            assert_eq!(qs.pop(capabilities.clone(), false).unwrap().unwrap().data, "bar");
+            assert!(qs.pop(capabilities, false).unwrap().is_none());
+        }
 
-            h1.join().expect("Failed to join thread");
+        #[test]
+        fn can_acknowledge_by_age() {
+            let storage_path = setup();
+            let mut qs = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("old".to_string(), Priority::High, vec![]).unwrap();
+            let old = qs.pop(vec![], false).unwrap().unwrap();
+            thread::sleep(Duration::from_millis(100));
+            qs.enqueue("new".to_string(), Priority::High, vec![]).unwrap();
+            let new = qs.pop(vec![], false).unwrap().unwrap();
+
+            let count = qs
+                .acknowledge_matching(|in_flight| in_flight.age() >= Duration::from_millis(100))
+                .expect("Failed to acknowledge");
+            assert_eq!(count, 1);
+
+            qs.fail(old.id).unwrap();
+            qs.fail(new.id).unwrap();
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "new");
         }
+    }
+
+    mod wait_until_empty {
+        use super::*;
 
         #[test]
-        #[ignore]
-        fn enqueue_and_pop_with_long_wait() {
+        fn returns_when_all_tasks_are_processed() {
             let storage_path = setup();
             let mut qs = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
 
-            let mut q = qs.clone();
+            for i in 0..10 {
+                qs.enqueue(format!("foo{}", i), Priority::High, vec![]).unwrap();
+            }
 
-            let h1 = spawn(move || {
-                thread::sleep_ms(3000);
-                q.enqueue("baz".to_string(), Priority::High, vec!["foo".to_string()]);
+            let mut worker = qs.clone();
+            let handle = spawn(move || {
+                while let Some(item) = worker.pop(vec![], false).unwrap() {
+                    thread::sleep(Duration::from_millis(5));
+                    worker.acknowledge(item.id).unwrap();
+                }
             });
 
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], true)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "baz"
-            );
+            assert!(qs.wait_until_empty(Some(Duration::from_secs(5))).unwrap());
 
-            h1.join().expect("Failed to join thread");
+            handle.join().expect("Failed to join thread");
         }
 
         #[test]
-        fn enqueue_and_pop_without_wait_for_message() {
+        fn times_out_when_tasks_are_not_processed() {
             let storage_path = setup();
             let mut qs = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
 
-            qs.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
-            qs.enqueue("bar".to_string(), Priority::High, vec!["bar".to_string()]);
-
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], false)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "foo"
-            );
-            assert_eq!(
-                qs.pop(vec!["foo".to_string(), "bar".to_string()], false)
-                    .unwrap()
-                    .unwrap()
-                    .data,
-                "bar"
-            );
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
 
-            assert!(qs
-                .pop(vec!["foo".to_string(), "bar".to_string()], false)
-                .unwrap()
-                .is_none());
+            assert!(!qs.wait_until_empty(Some(Duration::from_millis(50))).unwrap());
         }
+    }
+
+    mod diagnostic_dump {
+        use super::*;
 
         #[test]
-        #[ignore]
-        fn rough_benchmark() {
+        fn reflects_server_state() {
             let storage_path = setup();
             let mut qs = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
-            let mut handles = Vec::new();
-            for i in 0..100 {
-                let mut q = qs.clone();
-                let handle = spawn(move || {
-                    for j in 0..10000 {
-                        q.enqueue("foo".to_string(), Priority::High, vec!["foo".to_string()]);
-                    }
-                });
-                handles.push(handle);
+
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("bar".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("baz".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("qux".to_string(), Priority::Low, vec![]).unwrap();
+
+            let foo = qs.pop(vec![], false).unwrap().unwrap();
+            let bar = qs.pop(vec![], false).unwrap().unwrap();
+            let baz = qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(foo.id).unwrap();
+            qs.fail(bar.id).unwrap();
+            thread::sleep(Duration::from_millis(20));
+
+            let dump = qs.diagnostic_dump().expect("Failed to get dump");
+
+            assert_eq!(dump.high_priority_queued, 1);
+            assert_eq!(dump.low_priority_queued, 1);
+            assert_eq!(dump.processing, 1);
+            assert!(dump.oldest_processing_milliseconds.unwrap() >= 20);
+            assert!(!dump.garbage_collection_running);
+            assert!(dump.storage_file_sizes.high_priority > 0);
+            assert!(dump.storage_file_sizes.low_priority > 0);
+            assert!(dump.storage_file_sizes.completed > 0);
+            assert_eq!(dump.counters, CounterTotals { enqueued: 4, popped: 3, acknowledged: 1, failed: 1, expired: 0, dead_lettered: 0, garbage_collections: 0 });
+
+            let json = serde_json::to_string(&dump).expect("Failed to serialize dump");
+            assert!(json.contains("\"processing\":1"));
+            drop(baz);
+        }
+    }
+
+    mod dead_letters {
+        use super::*;
+
+        fn setup_with_max_attempts(storage_path: String, max_attempts: u32) -> QueueServer<String> {
+            let mut config = Config::default();
+            config.max_attempts = max_attempts;
+            QueueServer::new_with_config(storage_path, &config).expect("Failed to create queue server")
+        }
+
+        #[test]
+        fn task_failing_too_many_times_is_moved_to_the_dead_letters() {
+            let storage_path = setup();
+            let mut qs = setup_with_max_attempts(storage_path.clone(), 3);
+
+            let poison = qs.enqueue("poison".to_string(), Priority::High, vec![]).unwrap();
+            for attempt in 1..3 {
+                let item = qs.pop(vec![], false).unwrap().unwrap();
+                qs.fail(item.id).unwrap();
+                assert_eq!(qs.get_all().unwrap()[0].attempts, attempt);
             }
-            for h in handles {
-                h.join();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            qs.fail(item.id).unwrap();
+
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            let dead_letters = qs.dead_letters().unwrap();
+            assert_eq!(dead_letters.len(), 1);
+            assert_eq!(dead_letters[0].id, poison.id);
+            assert_eq!(dead_letters[0].attempts, 3);
+            assert_eq!(qs.diagnostic_dump().unwrap().counters.dead_lettered, 1);
+            drop(qs);
+
+            // Kept after a restart, and not put back in the queue
+            let mut qs = setup_with_max_attempts(storage_path.clone(), 3);
+            qs.load_stored_items().unwrap();
+            assert!(qs.pop(vec![], false).unwrap().is_none());
+            assert_eq!(qs.drain_dead_letters().unwrap()[0].id, poison.id);
+            assert!(qs.dead_letters().unwrap().is_empty());
+            drop(qs);
+
+            let qs = setup_with_max_attempts(storage_path, 3);
+            assert!(qs.dead_letters().unwrap().is_empty());
+        }
+
+        #[test]
+        fn attempts_are_remembered_after_a_restart() {
+            let storage_path = setup();
+            let mut qs = setup_with_max_attempts(storage_path.clone(), 2);
+
+            qs.enqueue("poison".to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            qs.fail(item.id).unwrap();
+            drop(qs);
+
+            let mut qs = setup_with_max_attempts(storage_path, 2);
+            qs.load_stored_items().unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            assert_eq!(item.attempts, 1);
+            qs.fail(item.id).unwrap();
+            assert_eq!(qs.dead_letters().unwrap().len(), 1);
+        }
+
+        fn dead_letter(qs: &mut QueueServer<String>, message: &str) -> Uuid {
+            qs.enqueue(message.to_string(), Priority::High, vec![]).unwrap();
+            let item = qs.pop(vec![], false).unwrap().unwrap();
+            qs.fail(item.id).unwrap();
+            item.id
+        }
+
+        fn two_hours_from_now() -> SystemTime {
+            SystemTime::now() + Duration::from_secs(2 * 60 * 60)
+        }
+
+        #[test]
+        fn dead_letters_older_than_the_retention_are_purged() {
+            let storage_path = setup();
+            let mut config = Config::default();
+            config.max_attempts = 1;
+            config.dead_letter_retention = Duration::from_secs(60 * 60);
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(storage_path.clone(), &config)
+                .expect("Failed to create queue server");
+
+            dead_letter(&mut qs, "old");
+            dead_letter(&mut qs, "also old");
+            qs.simulate_clock(two_hours_from_now);
+            let newer = dead_letter(&mut qs, "newer");
+            assert_eq!(qs.dead_letters().unwrap().len(), 3);
+
+            assert_eq!(qs.purge_old_dead_letters().unwrap(), 2);
+            let kept: Vec<Uuid> = qs.dead_letters().unwrap().iter().map(|item| item.id).collect();
+            assert_eq!(kept, vec![newer]);
+            drop(qs);
+
+            // The purged dead letters are deleted from disk as well
+            let qs: QueueServer<String> = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+            assert_eq!(qs.dead_letters().unwrap().len(), 1);
+        }
+
+        #[test]
+        fn oldest_dead_letters_past_the_max_count_are_purged() {
+            let mut config = Config::default();
+            config.max_attempts = 1;
+            config.max_dead_letters = 2;
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(setup(), &config)
+                .expect("Failed to create queue server");
+
+            dead_letter(&mut qs, "first");
+            let second = dead_letter(&mut qs, "second");
+            let third = dead_letter(&mut qs, "third");
+
+            assert_eq!(qs.purge_old_dead_letters().unwrap(), 1);
+            let kept: Vec<Uuid> = qs.dead_letters().unwrap().iter().map(|item| item.id).collect();
+            assert_eq!(kept, vec![second, third]);
+            assert_eq!(qs.purge_old_dead_letters().unwrap(), 0);
+        }
+
+        #[test]
+        fn dead_letters_are_exported_with_their_attempts() {
+            let storage_path = setup();
+            let mut qs = setup_with_max_attempts(storage_path.clone(), 2);
+
+            let mut ids = Vec::new();
+            for message in &["first", "second"] {
+                qs.enqueue(message.to_string(), Priority::High, vec!["x".to_string()]).unwrap();
+                for _ in 0..2 {
+                    let item = qs.pop(vec!["x".to_string()], false).unwrap().unwrap();
+                    qs.fail(item.id).unwrap();
+                }
+                ids.push(qs.dead_letters().unwrap().last().unwrap().id);
+            }
+
+            let out = path::PathBuf::from(format!("{}.dead_letters.json", storage_path));
+            assert_eq!(qs.export_dead_letters(&out, false).unwrap(), 2);
+            let exported: Vec<serde_json::Value> = std::fs::read_to_string(&out).unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+            assert_eq!(exported.len(), 2);
+            for (exported, id) in exported.iter().zip(&ids) {
+                assert_eq!(exported["id"], id.to_string());
+                assert_eq!(exported["attempts"], 2);
+                assert_eq!(exported["required_capabilities"], serde_json::json!(["x"]));
+                assert!(exported["dead_lettered_at"].is_u64());
+            }
+            assert_eq!(exported[0]["data"], "first");
+            assert_eq!(qs.dead_letters().unwrap().len(), 2);
+
+            // Clearing after the export takes them out of the dead letter queue
+            assert_eq!(qs.export_dead_letters(&out, true).unwrap(), 2);
+            assert!(qs.dead_letters().unwrap().is_empty());
+            drop(qs);
+            assert!(setup_with_max_attempts(storage_path, 2).dead_letters().unwrap().is_empty());
+        }
+
+        #[test]
+        fn failing_tasks_are_retried_forever_without_a_limit() {
+            let mut qs = setup_with_max_attempts(setup(), 0);
+
+            qs.enqueue("poison".to_string(), Priority::High, vec![]).unwrap();
+            for _ in 0..10 {
+                let item = qs.pop(vec![], false).unwrap().unwrap();
+                qs.fail(item.id).unwrap();
             }
+            assert!(qs.dead_letters().unwrap().is_empty());
+            assert_eq!(qs.queued_len().unwrap(), 1);
         }
     }
 
-    mod acknowledge_and_fail {
+    mod stats {
         use super::*;
 
         #[test]
-        fn acknowledge_will_remove() {
+        fn counts_pending_tasks_per_level_and_processing_tasks() {
             let storage_path = setup();
             let mut qs = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
 
-            let id = qs
-                .enqueue("foo".to_string(), Priority::High, vec![])
-                .expect("Failed to enqueue task");
+            qs.enqueue("a".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("b".to_string(), Priority::Low, vec![]).unwrap();
+            qs.enqueue("c".to_string(), Priority::High, vec![]).unwrap();
+            qs.enqueue("d".to_string(), Priority::High, vec![]).unwrap();
+            let popped = qs.pop(vec![], false).unwrap().unwrap();
+            qs.pop(vec![], false).unwrap().unwrap();
+            qs.acknowledge(popped.id).unwrap();
 
-            let item = qs
-                .pop(vec![], false)
-                .expect("Failed to pop item")
-                .expect("Not item received");
+            assert_eq!(qs.stats().unwrap(), Stats {
+                pending_by_level: vec![(1, 0), (0, 2)],
+                high_priority: 0,
+                low_priority: 2,
+                queued: 2,
+                processing: 1,
+                enqueued: 4,
+                acknowledged: 1,
+            });
+        }
+    }
 
-            assert_eq!(item.id, id.id);
+    mod server_info {
+        use super::*;
 
-            qs.acknowledge(id.id).expect("Failed to acknowledge task");
+        #[test]
+        fn reports_uptime_and_version() {
+            let storage_path = setup();
+            let qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
+                .expect("Failed to create queue server");
 
-            assert!(qs.pop(vec![], false).unwrap().is_none());
+            let first = qs.server_info();
+            thread::sleep(Duration::from_millis(10));
+            let second = qs.server_info();
+
+            assert!(second.uptime > first.uptime);
+            assert_eq!(first.version, env!("CARGO_PKG_VERSION"));
         }
 
         #[test]
-        fn fail_will_re_enqueue() {
+        fn reports_storage_file_sizes() {
             let storage_path = setup();
-            let mut qs = QueueServer::new_with_filename(storage_path)
+            let mut qs: QueueServer<String> = QueueServer::new_with_filename(storage_path)
                 .expect("Failed to create queue server");
 
-            let id = qs
-                .enqueue("foo".to_string(), Priority::High, vec![])
-                .expect("Failed to enqueue task");
+            let before = qs.server_info().storage_file_sizes.unwrap();
+            qs.enqueue("foo".to_string(), Priority::High, vec![]).unwrap();
+            let after = qs.server_info().storage_file_sizes.unwrap();
 
-            let item = qs
-                .pop(vec![], false)
-                .expect("Failed to pop item")
-                .expect("Not item received");
+            assert!(after.high_priority > before.high_priority);
+            assert_eq!(after.low_priority, before.low_priority);
+        }
 
-            assert_eq!(item.id, id.id);
+        #[test]
+        fn reports_priority_bands_and_rejects_levels_above_them() {
+            let mut config = Config::default();
+            config.priority_bands = vec!["low".to_string(), "normal".to_string(), "urgent".to_string()];
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(setup(), &config)
+                .expect("Failed to create queue server");
 
-            qs.fail(id.id).expect("Failed to fail task");
+            let info = qs.server_info();
+            assert_eq!(info.priority_bands, config.priority_bands);
+            assert_eq!(info.default_priority, Priority::Low);
 
-            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().id, item.id);
+            qs.enqueue("urgent".to_string(), Priority::Level(2), vec![]).unwrap();
+            match qs.enqueue("unknown".to_string(), Priority::Level(3), vec![]) {
+                Err(Error::UnknownPriority(3, 3)) => {}
+                _ => panic!("Expected the priority to be rejected"),
+            }
+            assert_eq!(qs.queued_len().unwrap(), 1);
+        }
+
+        #[test]
+        fn reports_the_scan_depth_of_every_level() {
+            let mut config = Config::default();
+            config.priority_bands = vec!["low".to_string(), "normal".to_string(), "urgent".to_string()];
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(setup(), &config)
+                .expect("Failed to create queue server");
+
+            qs.enqueue("urgent".to_string(), Priority::Level(2), vec!["foo".to_string()]).unwrap();
+            qs.enqueue("low".to_string(), Priority::Low, vec![]).unwrap();
+            assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "low");
+
+            let scan_depth = qs.scan_depth();
+            assert_eq!(scan_depth.by_level, vec![(2, 1.0), (1, 0.0), (0, 0.0)]);
+            assert_eq!(scan_depth.low_priority, 0.0);
         }
     }
 
@@ -464,5 +5647,26 @@ mod tests {
             assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "baz");
             assert_eq!(qs.pop(vec![], false).unwrap().unwrap().data, "bar");
         }
+
+        #[test]
+        fn higher_levels_are_popped_first_also_after_restart() {
+            let storage_path = setup();
+            let mut config = Config::default();
+            config.priority_bands = (0..10).map(|level| format!("level {}", level)).collect();
+            let mut qs = QueueServer::new_with_config(storage_path.clone(), &config)
+                .expect("Failed to create queue server");
+
+            for (message, level) in &[("one", 1), ("nine", 9), ("zero", 0), ("five", 5), ("nine again", 9)] {
+                qs.enqueue(message.to_string(), Priority::from_level(*level).unwrap(), vec![])
+                    .expect("Failed to enqueue");
+            }
+            drop(qs);
+
+            let mut qs: QueueServer<String> = QueueServer::new_with_config(storage_path, &config)
+                .expect("Failed to create queue server");
+            qs.load_stored_items().unwrap();
+            let popped: Vec<String> = (0..5).map(|_| qs.pop(vec![], false).unwrap().unwrap().data).collect();
+            assert_eq!(popped, vec!["nine", "nine again", "five", "one", "zero"]);
+        }
     }
 }
@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::queue_server::{Error, QueueServer};
+use crate::validation::PayloadValidator;
+
+// The queue used when no queue name is given, so existing clients keep working
+pub const DEFAULT_QUEUE_NAME: &str = "default";
+
+// How long a queue name can be
+const MAX_QUEUE_NAME_LENGTH: usize = 64;
+
+// Separate queues identified by name, so different kinds of work can be kept apart.
+// Every queue has its own storage and ordering. Queues are created the first time
+// something is enqueued to them, and the tasks stored by earlier runs are loaded
+// the first time the queue is used.
+#[derive(Clone)]
+pub struct NamedQueues<T: Send + Clone + Serialize + DeserializeOwned> {
+    default: QueueServer<T>,
+    queues: Arc<RwLock<HashMap<String, QueueServer<T>>>>,
+    // Held while a queue is being opened, so it's only opened once,
+    // without holding up the queues that are open already
+    opening: Arc<Mutex<()>>,
+    // The folder the storage of the named queues is put in, one subfolder per queue
+    storage_folder: String,
+    config: Config,
+    payload_validator: Option<PayloadValidator<T>>,
+}
+
+impl<T: Send + Clone + Serialize + DeserializeOwned + 'static> NamedQueues<T> {
+    pub fn new(default: QueueServer<T>, storage_folder: String, config: Config) -> NamedQueues<T> {
+        NamedQueues {
+            default,
+            queues: Arc::new(RwLock::new(HashMap::new())),
+            opening: Arc::new(Mutex::new(())),
+            storage_folder,
+            config,
+            payload_validator: None,
+        }
+    }
+
+    // Rejects tasks enqueued in the named queues the validator doesn't accept the payload of
+    pub fn with_payload_validator(mut self, validator: PayloadValidator<T>) -> NamedQueues<T> {
+        self.payload_validator = Some(validator);
+        self
+    }
+
+    // The queue with the given name. An empty name is the default queue.
+    // Returns Error::QueueNotFound if nothing has been enqueued to the queue yet.
+    pub fn get(&self, name: &str) -> Result<QueueServer<T>, Error> {
+        self.open(name, false)
+    }
+
+    // Same as get, but creates the queue if it doesn't exist yet.
+    // Returns Error::TooManyQueues if the maximum number of named queues exist already.
+    pub fn get_or_create(&self, name: &str) -> Result<QueueServer<T>, Error> {
+        self.open(name, true)
+    }
+
+    fn open(&self, name: &str, create: bool) -> Result<QueueServer<T>, Error> {
+        if name.is_empty() || name == DEFAULT_QUEUE_NAME {
+            return Ok(self.default.clone());
+        }
+        validate_queue_name(name)?;
+
+        if let Some(queue) = self.opened(name)? {
+            return Ok(queue);
+        }
+
+        let _opening = match self.opening.lock() {
+            Ok(opening) => opening,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        // Another thread might have opened it while waiting for the lock
+        if let Some(queue) = self.opened(name)? {
+            return Ok(queue);
+        }
+
+        // Queues created by earlier runs only exist on disk until they are used
+        let folder = format!("{}/{}", self.storage_folder, name);
+        if !Path::new(&folder).is_dir() {
+            if !create {
+                return Err(Error::QueueNotFound(name.to_string()));
+            }
+            let max = self.config.max_named_queues;
+            if max > 0 && self.stored_queue_count()? >= max {
+                return Err(Error::TooManyQueues(max));
+            }
+        }
+
+        // Loaded without holding the lock, so the other queues can be used in the meantime
+        let mut queue = QueueServer::new_with_config(format!("{}/tasks", folder), &self.config)?;
+        if let Some(validator) = &self.payload_validator {
+            queue = queue.with_payload_validator(validator.clone());
+        }
+        queue.load_stored_items()?;
+
+        match self.queues.write() {
+            Ok(mut queues) => {
+                queues.insert(name.to_string(), queue.clone());
+            }
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+        Ok(queue)
+    }
+
+    // The queue, if it has been opened already
+    fn opened(&self, name: &str) -> Result<Option<QueueServer<T>>, Error> {
+        match self.queues.read() {
+            Ok(queues) => Ok(queues.get(name).cloned()),
+            Err(_) => Err(Error::MutexCorrupted),
+        }
+    }
+
+    // How many named queues exist on disk, including the ones not opened since the server started
+    fn stored_queue_count(&self) -> Result<usize, Error> {
+        match fs::read_dir(&self.storage_folder) {
+            Ok(entries) => Ok(entries.filter_map(Result::ok).filter(|entry| entry.path().is_dir()).count()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(Error::IOError(e)),
+        }
+    }
+
+    // Moves a queued task from one queue to another, for example to escalate it to a queue
+    // that is handled first. See QueueServer::move_item_to.
+    // Returns false if the task isn't queued in the first queue.
+    pub fn move_item(&self, id: Uuid, from: &str, to: &str) -> Result<bool, Error> {
+        // The task would be removed again right after being put back
+        if canonical_name(from) == canonical_name(to) {
+            return Ok(false);
+        }
+
+        let mut source = self.get(from)?;
+        let mut target = self.get_or_create(to)?;
+        source.move_item_to(id, &mut target)
+    }
+
+    // The names of the queues that have been used since the server started, not including the default queue
+    pub fn names(&self) -> Result<Vec<String>, Error> {
+        match self.queues.read() {
+            Ok(queues) => {
+                let mut names: Vec<String> = queues.keys().cloned().collect();
+                names.sort();
+                Ok(names)
+            }
+            Err(_) => Err(Error::MutexCorrupted),
+        }
+    }
+
+    // Shuts down the default queue and every named queue, see QueueServer::shutdown.
+    // Returns how many tasks were being processed across all of them.
+    pub fn shutdown(&self) -> Result<usize, Error> {
+        let mut queues: Vec<QueueServer<T>> = match self.queues.read() {
+            Ok(queues) => queues.values().cloned().collect(),
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        queues.push(self.default.clone());
+
+        let mut requeued = 0;
+        for mut queue in queues {
+            requeued += queue.shutdown()?;
+        }
+        Ok(requeued)
+    }
+}
+
+// The name of the queue, with the empty name meaning the default queue
+fn canonical_name(name: &str) -> &str {
+    if name.is_empty() {
+        DEFAULT_QUEUE_NAME
+    } else {
+        name
+    }
+}
+
+// Queue names are used as folder names, so only simple names are allowed
+fn validate_queue_name(name: &str) -> Result<(), Error> {
+    let valid = name.len() <= MAX_QUEUE_NAME_LENGTH
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidQueueName(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossbeam::channel::never;
+
+    use crate::models::Priority;
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    fn setup() -> (String, NamedQueues<String>) {
+        let root = setup_test_storage().unwrap();
+        let default = QueueServer::new_with_filename(format!("{}tasks", root)).expect("Failed to create queue server");
+        let queues = NamedQueues::new(default, format!("{}queues", root), Config::default());
+        (root, queues)
+    }
+
+    #[test]
+    fn named_queues_are_kept_apart() {
+        let (_root, queues) = setup();
+
+        let emails = queues.get_or_create("emails").unwrap().enqueue("email".to_string(), Priority::High, vec![]).unwrap();
+        queues.get("").unwrap().enqueue("default".to_string(), Priority::High, vec![]).unwrap();
+
+        assert!(queues.get_or_create("thumbnails").unwrap().pop(vec![], false).unwrap().is_none());
+        assert_eq!(queues.get("emails").unwrap().pop(vec![], false).unwrap().unwrap().id, emails.id);
+        assert_eq!(queues.get(DEFAULT_QUEUE_NAME).unwrap().pop(vec![], false).unwrap().unwrap().data, "default");
+        assert_eq!(queues.names().unwrap(), vec!["emails".to_string(), "thumbnails".to_string()]);
+    }
+
+    #[test]
+    fn named_queue_tasks_are_loaded_after_restart() {
+        let (root, queues) = setup();
+        let created = queues.get_or_create("emails").unwrap().enqueue("email".to_string(), Priority::High, vec![]).unwrap();
+        drop(queues);
+
+        let default: QueueServer<String> = QueueServer::new_with_filename(format!("{}tasks", root)).expect("Failed to create queue server");
+        let queues = NamedQueues::new(default, format!("{}queues", root), Config::default());
+        let popped = queues.get("emails").unwrap().pop_cancellable(vec![], false, &never()).unwrap().unwrap();
+        assert_eq!(popped.id, created.id);
+    }
+
+    #[test]
+    fn moved_task_is_only_popped_from_the_target_queue() {
+        let (root, queues) = setup();
+        let normal = queues.get_or_create("normal").unwrap().enqueue("escalated".to_string(), Priority::Low, vec![]).unwrap();
+        queues.get("normal").unwrap().enqueue("waiting".to_string(), Priority::Low, vec![]).unwrap();
+
+        assert!(queues.move_item(normal.id, "normal", "urgent").unwrap());
+        assert!(!queues.move_item(normal.id, "normal", "urgent").unwrap());
+        assert!(!queues.move_item(normal.id, "urgent", "urgent").unwrap());
+        assert_eq!(queues.get("normal").unwrap().queued_len().unwrap(), 1);
+        drop(queues);
+
+        // Both changes are saved
+        let default: QueueServer<String> = QueueServer::new_with_filename(format!("{}tasks", root)).expect("Failed to create queue server");
+        let queues = NamedQueues::new(default, format!("{}queues", root), Config::default());
+        assert_eq!(queues.get("urgent").unwrap().pop(vec![], false).unwrap().unwrap().id, normal.id);
+        assert_eq!(queues.get("normal").unwrap().pop(vec![], false).unwrap().unwrap().data, "waiting");
+        assert!(queues.get("normal").unwrap().pop(vec![], false).unwrap().is_none());
+    }
+
+    #[test]
+    fn unknown_queues_are_not_created_by_get() {
+        let (root, queues) = setup();
+
+        match queues.get("emails") {
+            Err(Error::QueueNotFound(name)) => assert_eq!(name, "emails"),
+            _ => panic!("Unknown queue was found"),
+        }
+        assert!(queues.names().unwrap().is_empty());
+        assert!(!Path::new(&format!("{}queues/emails", root)).exists());
+    }
+
+    #[test]
+    fn limits_how_many_queues_can_be_created() {
+        let root = setup_test_storage().unwrap();
+        let default = QueueServer::new_with_filename(format!("{}tasks", root)).expect("Failed to create queue server");
+        let config = Config {
+            max_named_queues: 2,
+            ..Config::default()
+        };
+        let queues: NamedQueues<String> = NamedQueues::new(default, format!("{}queues", root), config.clone());
+        queues.get_or_create("first").unwrap();
+        queues.get_or_create("second").unwrap();
+
+        match queues.get_or_create("third") {
+            Err(Error::TooManyQueues(2)) => {}
+            _ => panic!("Queue was created past the limit"),
+        }
+        // Existing queues can still be used
+        assert!(queues.get_or_create("first").is_ok());
+        drop(queues);
+
+        // Queues created by earlier runs count too
+        let default = QueueServer::new_with_filename(format!("{}tasks", root)).expect("Failed to create queue server");
+        let queues: NamedQueues<String> = NamedQueues::new(default, format!("{}queues", root), config);
+        assert!(queues.get_or_create("third").is_err());
+        assert!(queues.get("second").is_ok());
+    }
+
+    #[test]
+    fn rejects_names_that_are_not_folder_safe() {
+        let (_root, queues) = setup();
+
+        for name in &["../escape", "a/b", "with space"] {
+            match queues.get(name) {
+                Err(Error::InvalidQueueName(_)) => {}
+                _ => panic!("{} was accepted as a queue name", name),
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Instant;
+
+use crate::time_helpers::duration_to_millis;
+
+// How many sources to track before forgetting the ones that are back at full burst
+const MAX_TRACKED_SOURCES: usize = 1024;
+
+// Allows a burst of events, and then a steady rate of events after that
+#[derive(Clone, Debug)]
+pub struct TokenBucket {
+    rate_per_second: f64,
+    burst: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate_per_second: f64, burst: usize, now: Instant) -> TokenBucket {
+        TokenBucket {
+            rate_per_second,
+            burst: burst as f64,
+            tokens: burst as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        if now > self.last_refill {
+            let elapsed = duration_to_millis(now - self.last_refill) as f64 / 1000.0;
+            self.tokens = (self.tokens + elapsed * self.rate_per_second).min(self.burst);
+            self.last_refill = now;
+        }
+    }
+
+    // Takes a token if there is any left
+    pub fn try_take(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.burst
+    }
+}
+
+// Limits how often new connections are accepted from each source address,
+// so a crash looping fleet of workers can't hammer the server with reconnects
+pub struct ConnectionLimiter {
+    rate_per_second: f64,
+    burst: usize,
+    buckets: HashMap<IpAddr, TokenBucket>,
+}
+
+impl ConnectionLimiter {
+    pub fn new(rate_per_second: f64, burst: usize) -> ConnectionLimiter {
+        ConnectionLimiter {
+            rate_per_second,
+            burst,
+            buckets: HashMap::new(),
+        }
+    }
+
+    // Checks if a new connection from the address should be accepted
+    pub fn allow(&mut self, address: IpAddr, now: Instant) -> bool {
+        if self.buckets.len() >= MAX_TRACKED_SOURCES && !self.buckets.contains_key(&address) {
+            // A full bucket behaves exactly like a new one, so it's safe to forget
+            self.buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        let rate_per_second = self.rate_per_second;
+        let burst = self.burst;
+        self.buckets
+            .entry(address)
+            .or_insert_with(|| TokenBucket::new(rate_per_second, burst, now))
+            .try_take(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn refuses_connections_past_the_limit() {
+        let mut limiter = ConnectionLimiter::new(2.0, 3);
+        let worker: IpAddr = "10.0.0.1".parse().unwrap();
+        let other: IpAddr = "10.0.0.2".parse().unwrap();
+        let now = Instant::now();
+
+        // The burst is allowed
+        for _ in 0..3 {
+            assert!(limiter.allow(worker, now));
+        }
+        // But not anything past that
+        assert!(!limiter.allow(worker, now));
+        assert!(!limiter.allow(worker, now + Duration::from_millis(100)));
+
+        // Other sources are not affected
+        assert!(limiter.allow(other, now));
+
+        // New connections are allowed at the configured rate
+        assert!(limiter.allow(worker, now + Duration::from_millis(600)));
+        assert!(!limiter.allow(worker, now + Duration::from_millis(700)));
+    }
+
+    #[test]
+    fn forgets_sources_that_are_back_at_full_burst() {
+        let mut limiter = ConnectionLimiter::new(1.0, 1);
+        let now = Instant::now();
+
+        for i in 0..MAX_TRACKED_SOURCES {
+            let address = IpAddr::from([10, 0, (i / 256) as u8, (i % 256) as u8]);
+            assert!(limiter.allow(address, now));
+        }
+
+        let later = now + Duration::from_secs(2);
+        assert!(limiter.allow("10.1.0.0".parse().unwrap(), later));
+        assert_eq!(limiter.buckets.len(), 1);
+    }
+}
@@ -21,11 +21,12 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
-#[derive(PartialEq, Clone, Default)]
+#[derive(PartialEq,Clone,Default)]
 pub struct AuthenticateRequest {
     // message fields
     pub username: ::std::string::String,
     pub password: ::std::string::String,
+    pub sessionToken: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -87,6 +88,32 @@ impl AuthenticateRequest {
     pub fn get_password(&self) -> &str {
         &self.password
     }
+
+    // string sessionToken = 3;
+
+    pub fn clear_sessionToken(&mut self) {
+        self.sessionToken.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessionToken(&mut self, v: ::std::string::String) {
+        self.sessionToken = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sessionToken(&mut self) -> &mut ::std::string::String {
+        &mut self.sessionToken
+    }
+
+    // Take field
+    pub fn take_sessionToken(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sessionToken, ::std::string::String::new())
+    }
+
+    pub fn get_sessionToken(&self) -> &str {
+        &self.sessionToken
+    }
 }
 
 impl ::protobuf::Message for AuthenticateRequest {
@@ -104,6 +131,9 @@ impl ::protobuf::Message for AuthenticateRequest {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.password)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sessionToken)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -122,6 +152,9 @@ impl ::protobuf::Message for AuthenticateRequest {
         if !self.password.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.password);
         }
+        if !self.sessionToken.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.sessionToken);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -134,6 +167,9 @@ impl ::protobuf::Message for AuthenticateRequest {
         if !self.password.is_empty() {
             os.write_string(2, &self.password)?;
         }
+        if !self.sessionToken.is_empty() {
+            os.write_string(3, &self.sessionToken)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -186,6 +222,11 @@ impl ::protobuf::Message for AuthenticateRequest {
                     |m: &AuthenticateRequest| { &m.password },
                     |m: &mut AuthenticateRequest| { &mut m.password },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "sessionToken",
+                    |m: &AuthenticateRequest| { &m.sessionToken },
+                    |m: &mut AuthenticateRequest| { &mut m.sessionToken },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<AuthenticateRequest>(
                     "AuthenticateRequest",
                     fields,
@@ -210,6 +251,7 @@ impl ::protobuf::Clear for AuthenticateRequest {
     fn clear(&mut self) {
         self.clear_username();
         self.clear_password();
+        self.clear_sessionToken();
         self.unknown_fields.clear();
     }
 }
@@ -226,10 +268,11 @@ impl ::protobuf::reflect::ProtobufValue for AuthenticateRequest {
     }
 }
 
-#[derive(PartialEq, Clone, Default)]
+#[derive(PartialEq,Clone,Default)]
 pub struct AuthenticateResponse {
     // message fields
     pub success: bool,
+    pub sessionToken: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -254,6 +297,32 @@ impl AuthenticateResponse {
     pub fn get_success(&self) -> bool {
         self.success
     }
+
+    // string sessionToken = 2;
+
+    pub fn clear_sessionToken(&mut self) {
+        self.sessionToken.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessionToken(&mut self, v: ::std::string::String) {
+        self.sessionToken = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sessionToken(&mut self) -> &mut ::std::string::String {
+        &mut self.sessionToken
+    }
+
+    // Take field
+    pub fn take_sessionToken(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sessionToken, ::std::string::String::new())
+    }
+
+    pub fn get_sessionToken(&self) -> &str {
+        &self.sessionToken
+    }
 }
 
 impl ::protobuf::Message for AuthenticateResponse {
@@ -272,6 +341,9 @@ impl ::protobuf::Message for AuthenticateResponse {
                     let tmp = is.read_bool()?;
                     self.success = tmp;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sessionToken)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -287,6 +359,9 @@ impl ::protobuf::Message for AuthenticateResponse {
         if self.success != false {
             my_size += 2;
         }
+        if !self.sessionToken.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.sessionToken);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -296,6 +371,9 @@ impl ::protobuf::Message for AuthenticateResponse {
         if self.success != false {
             os.write_bool(1, self.success)?;
         }
+        if !self.sessionToken.is_empty() {
+            os.write_string(2, &self.sessionToken)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -343,6 +421,11 @@ impl ::protobuf::Message for AuthenticateResponse {
                     |m: &AuthenticateResponse| { &m.success },
                     |m: &mut AuthenticateResponse| { &mut m.success },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "sessionToken",
+                    |m: &AuthenticateResponse| { &m.sessionToken },
+                    |m: &mut AuthenticateResponse| { &mut m.sessionToken },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<AuthenticateResponse>(
                     "AuthenticateResponse",
                     fields,
@@ -366,6 +449,7 @@ impl ::protobuf::Message for AuthenticateResponse {
 impl ::protobuf::Clear for AuthenticateResponse {
     fn clear(&mut self) {
         self.clear_success();
+        self.clear_sessionToken();
         self.unknown_fields.clear();
     }
 }
@@ -388,6 +472,12 @@ pub struct EnqueueRequest {
     pub message: ::std::vec::Vec<u8>,
     pub priority: Priority,
     pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub delayMilliseconds: u64,
+    pub availableAt: ::std::string::String,
+    pub idempotencyKey: ::std::string::String,
+    pub queueName: ::std::string::String,
+    pub ttlSeconds: u64,
+    pub priorityLevel: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -463,6 +553,129 @@ impl EnqueueRequest {
     pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
         &self.requiredCapabilities
     }
+
+    // uint64 delayMilliseconds = 4;
+
+    pub fn clear_delayMilliseconds(&mut self) {
+        self.delayMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delayMilliseconds(&mut self, v: u64) {
+        self.delayMilliseconds = v;
+    }
+
+    pub fn get_delayMilliseconds(&self) -> u64 {
+        self.delayMilliseconds
+    }
+
+    // string availableAt = 5;
+
+    pub fn clear_availableAt(&mut self) {
+        self.availableAt.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableAt(&mut self, v: ::std::string::String) {
+        self.availableAt = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_availableAt(&mut self) -> &mut ::std::string::String {
+        &mut self.availableAt
+    }
+
+    // Take field
+    pub fn take_availableAt(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.availableAt, ::std::string::String::new())
+    }
+
+    pub fn get_availableAt(&self) -> &str {
+        &self.availableAt
+    }
+
+    // string idempotencyKey = 6;
+
+    pub fn clear_idempotencyKey(&mut self) {
+        self.idempotencyKey.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idempotencyKey(&mut self, v: ::std::string::String) {
+        self.idempotencyKey = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_idempotencyKey(&mut self) -> &mut ::std::string::String {
+        &mut self.idempotencyKey
+    }
+
+    // Take field
+    pub fn take_idempotencyKey(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.idempotencyKey, ::std::string::String::new())
+    }
+
+    pub fn get_idempotencyKey(&self) -> &str {
+        &self.idempotencyKey
+    }
+
+    // string queueName = 7;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+
+    // uint64 ttlSeconds = 8;
+
+    pub fn clear_ttlSeconds(&mut self) {
+        self.ttlSeconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttlSeconds(&mut self, v: u64) {
+        self.ttlSeconds = v;
+    }
+
+    pub fn get_ttlSeconds(&self) -> u64 {
+        self.ttlSeconds
+    }
+
+    // uint32 priorityLevel = 9;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
 }
 
 impl ::protobuf::Message for EnqueueRequest {
@@ -483,6 +696,36 @@ impl ::protobuf::Message for EnqueueRequest {
                 3 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.delayMilliseconds = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.availableAt)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.idempotencyKey)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttlSeconds = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -504,6 +747,24 @@ impl ::protobuf::Message for EnqueueRequest {
         for value in &self.requiredCapabilities {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
+        if self.delayMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.delayMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.availableAt.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.availableAt);
+        }
+        if !self.idempotencyKey.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.idempotencyKey);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.queueName);
+        }
+        if self.ttlSeconds != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.ttlSeconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -519,6 +780,24 @@ impl ::protobuf::Message for EnqueueRequest {
         for v in &self.requiredCapabilities {
             os.write_string(3, &v)?;
         };
+        if self.delayMilliseconds != 0 {
+            os.write_uint64(4, self.delayMilliseconds)?;
+        }
+        if !self.availableAt.is_empty() {
+            os.write_string(5, &self.availableAt)?;
+        }
+        if !self.idempotencyKey.is_empty() {
+            os.write_string(6, &self.idempotencyKey)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(7, &self.queueName)?;
+        }
+        if self.ttlSeconds != 0 {
+            os.write_uint64(8, self.ttlSeconds)?;
+        }
+        if self.priorityLevel != 0 {
+            os.write_uint32(9, self.priorityLevel)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -576,6 +855,36 @@ impl ::protobuf::Message for EnqueueRequest {
                     |m: &EnqueueRequest| { &m.requiredCapabilities },
                     |m: &mut EnqueueRequest| { &mut m.requiredCapabilities },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "delayMilliseconds",
+                    |m: &EnqueueRequest| { &m.delayMilliseconds },
+                    |m: &mut EnqueueRequest| { &mut m.delayMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableAt",
+                    |m: &EnqueueRequest| { &m.availableAt },
+                    |m: &mut EnqueueRequest| { &mut m.availableAt },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "idempotencyKey",
+                    |m: &EnqueueRequest| { &m.idempotencyKey },
+                    |m: &mut EnqueueRequest| { &mut m.idempotencyKey },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &EnqueueRequest| { &m.queueName },
+                    |m: &mut EnqueueRequest| { &mut m.queueName },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttlSeconds",
+                    |m: &EnqueueRequest| { &m.ttlSeconds },
+                    |m: &mut EnqueueRequest| { &mut m.ttlSeconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &EnqueueRequest| { &m.priorityLevel },
+                    |m: &mut EnqueueRequest| { &mut m.priorityLevel },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<EnqueueRequest>(
                     "EnqueueRequest",
                     fields,
@@ -601,6 +910,12 @@ impl ::protobuf::Clear for EnqueueRequest {
         self.clear_message();
         self.clear_priority();
         self.clear_requiredCapabilities();
+        self.clear_delayMilliseconds();
+        self.clear_availableAt();
+        self.clear_idempotencyKey();
+        self.clear_queueName();
+        self.clear_ttlSeconds();
+        self.clear_priorityLevel();
         self.unknown_fields.clear();
     }
 }
@@ -621,6 +936,7 @@ impl ::protobuf::reflect::ProtobufValue for EnqueueRequest {
 pub struct EnqueueResponse {
     // message fields
     pub id: ::std::string::String,
+    pub status: EnqueueStatus,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -656,6 +972,21 @@ impl EnqueueResponse {
     pub fn get_id(&self) -> &str {
         &self.id
     }
+
+    // .EnqueueStatus status = 2;
+
+    pub fn clear_status(&mut self) {
+        self.status = EnqueueStatus::CREATED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: EnqueueStatus) {
+        self.status = v;
+    }
+
+    pub fn get_status(&self) -> EnqueueStatus {
+        self.status
+    }
 }
 
 impl ::protobuf::Message for EnqueueResponse {
@@ -670,6 +1001,9 @@ impl ::protobuf::Message for EnqueueResponse {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -685,6 +1019,9 @@ impl ::protobuf::Message for EnqueueResponse {
         if !self.id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.id);
         }
+        if self.status != EnqueueStatus::CREATED {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -694,6 +1031,9 @@ impl ::protobuf::Message for EnqueueResponse {
         if !self.id.is_empty() {
             os.write_string(1, &self.id)?;
         }
+        if self.status != EnqueueStatus::CREATED {
+            os.write_enum(2, self.status.value())?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -741,6 +1081,11 @@ impl ::protobuf::Message for EnqueueResponse {
                     |m: &EnqueueResponse| { &m.id },
                     |m: &mut EnqueueResponse| { &mut m.id },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<EnqueueStatus>>(
+                    "status",
+                    |m: &EnqueueResponse| { &m.status },
+                    |m: &mut EnqueueResponse| { &mut m.status },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<EnqueueResponse>(
                     "EnqueueResponse",
                     fields,
@@ -764,6 +1109,7 @@ impl ::protobuf::Message for EnqueueResponse {
 impl ::protobuf::Clear for EnqueueResponse {
     fn clear(&mut self) {
         self.clear_id();
+        self.clear_status();
         self.unknown_fields.clear();
     }
 }
@@ -781,63 +1127,68 @@ impl ::protobuf::reflect::ProtobufValue for EnqueueResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PopRequest {
+pub struct EnqueueBatchRequest {
     // message fields
-    pub availableCapabilities: ::protobuf::RepeatedField<::std::string::String>,
-    pub waitForMessage: bool,
+    pub items: ::protobuf::RepeatedField<EnqueueRequest>,
+    pub atomic: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl PopRequest {
-    pub fn new() -> PopRequest {
+impl EnqueueBatchRequest {
+    pub fn new() -> EnqueueBatchRequest {
         ::std::default::Default::default()
     }
 
-    // repeated string availableCapabilities = 1;
+    // repeated .EnqueueRequest items = 1;
 
-    pub fn clear_availableCapabilities(&mut self) {
-        self.availableCapabilities.clear();
+    pub fn clear_items(&mut self) {
+        self.items.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_availableCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.availableCapabilities = v;
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<EnqueueRequest>) {
+        self.items = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_availableCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.availableCapabilities
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<EnqueueRequest> {
+        &mut self.items
     }
 
     // Take field
-    pub fn take_availableCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.availableCapabilities, ::protobuf::RepeatedField::new())
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<EnqueueRequest> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_availableCapabilities(&self) -> &[::std::string::String] {
-        &self.availableCapabilities
+    pub fn get_items(&self) -> &[EnqueueRequest] {
+        &self.items
     }
 
-    // bool waitForMessage = 2;
+    // bool atomic = 2;
 
-    pub fn clear_waitForMessage(&mut self) {
-        self.waitForMessage = false;
+    pub fn clear_atomic(&mut self) {
+        self.atomic = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_waitForMessage(&mut self, v: bool) {
-        self.waitForMessage = v;
+    pub fn set_atomic(&mut self, v: bool) {
+        self.atomic = v;
     }
 
-    pub fn get_waitForMessage(&self) -> bool {
-        self.waitForMessage
+    pub fn get_atomic(&self) -> bool {
+        self.atomic
     }
 }
 
-impl ::protobuf::Message for PopRequest {
+impl ::protobuf::Message for EnqueueBatchRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -846,14 +1197,14 @@ impl ::protobuf::Message for PopRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.availableCapabilities)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.waitForMessage = tmp;
+                    self.atomic = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -867,10 +1218,11 @@ impl ::protobuf::Message for PopRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.availableCapabilities {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        if self.waitForMessage != false {
+        if self.atomic != false {
             my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -879,11 +1231,13 @@ impl ::protobuf::Message for PopRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.availableCapabilities {
-            os.write_string(1, &v)?;
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         };
-        if self.waitForMessage != false {
-            os.write_bool(2, self.waitForMessage)?;
+        if self.atomic != false {
+            os.write_bool(2, self.atomic)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -915,8 +1269,8 @@ impl ::protobuf::Message for PopRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> PopRequest {
-        PopRequest::new()
+    fn new() -> EnqueueBatchRequest {
+        EnqueueBatchRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -927,18 +1281,18 @@ impl ::protobuf::Message for PopRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "availableCapabilities",
-                    |m: &PopRequest| { &m.availableCapabilities },
-                    |m: &mut PopRequest| { &mut m.availableCapabilities },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnqueueRequest>>(
+                    "items",
+                    |m: &EnqueueBatchRequest| { &m.items },
+                    |m: &mut EnqueueBatchRequest| { &mut m.items },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "waitForMessage",
-                    |m: &PopRequest| { &m.waitForMessage },
-                    |m: &mut PopRequest| { &mut m.waitForMessage },
+                    "atomic",
+                    |m: &EnqueueBatchRequest| { &m.atomic },
+                    |m: &mut EnqueueBatchRequest| { &mut m.atomic },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PopRequest>(
-                    "PopRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<EnqueueBatchRequest>(
+                    "EnqueueBatchRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -946,122 +1300,122 @@ impl ::protobuf::Message for PopRequest {
         }
     }
 
-    fn default_instance() -> &'static PopRequest {
-        static mut instance: ::protobuf::lazy::Lazy<PopRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static EnqueueBatchRequest {
+        static mut instance: ::protobuf::lazy::Lazy<EnqueueBatchRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PopRequest,
+            ptr: 0 as *const EnqueueBatchRequest,
         };
         unsafe {
-            instance.get(PopRequest::new)
+            instance.get(EnqueueBatchRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PopRequest {
+impl ::protobuf::Clear for EnqueueBatchRequest {
     fn clear(&mut self) {
-        self.clear_availableCapabilities();
-        self.clear_waitForMessage();
+        self.clear_items();
+        self.clear_atomic();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PopRequest {
+impl ::std::fmt::Debug for EnqueueBatchRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PopRequest {
+impl ::protobuf::reflect::ProtobufValue for EnqueueBatchRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PopResponse {
+pub struct EnqueueBatchResult {
     // message fields
-    pub hadResult: bool,
-    pub message: ::std::vec::Vec<u8>,
     pub id: ::std::string::String,
+    pub status: EnqueueStatus,
+    pub error: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl PopResponse {
-    pub fn new() -> PopResponse {
+impl EnqueueBatchResult {
+    pub fn new() -> EnqueueBatchResult {
         ::std::default::Default::default()
     }
 
-    // bool hadResult = 3;
+    // string id = 1;
 
-    pub fn clear_hadResult(&mut self) {
-        self.hadResult = false;
+    pub fn clear_id(&mut self) {
+        self.id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_hadResult(&mut self, v: bool) {
-        self.hadResult = v;
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
     }
 
-    pub fn get_hadResult(&self) -> bool {
-        self.hadResult
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
     }
 
-    // bytes message = 1;
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
 
-    pub fn clear_message(&mut self) {
-        self.message.clear();
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // .EnqueueStatus status = 2;
+
+    pub fn clear_status(&mut self) {
+        self.status = EnqueueStatus::CREATED;
     }
 
     // Param is passed by value, moved
-    pub fn set_message(&mut self, v: ::std::vec::Vec<u8>) {
-        self.message = v;
+    pub fn set_status(&mut self, v: EnqueueStatus) {
+        self.status = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_message(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.message
-    }
-
-    // Take field
-    pub fn take_message(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.message, ::std::vec::Vec::new())
-    }
-
-    pub fn get_message(&self) -> &[u8] {
-        &self.message
+    pub fn get_status(&self) -> EnqueueStatus {
+        self.status
     }
 
-    // string id = 2;
+    // string error = 3;
 
-    pub fn clear_id(&mut self) {
-        self.id.clear();
+    pub fn clear_error(&mut self) {
+        self.error.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = v;
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        &mut self.id
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
     }
 
     // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
     }
 
-    pub fn get_id(&self) -> &str {
-        &self.id
+    pub fn get_error(&self) -> &str {
+        &self.error
     }
 }
 
-impl ::protobuf::Message for PopResponse {
+impl ::protobuf::Message for EnqueueBatchResult {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1070,18 +1424,14 @@ impl ::protobuf::Message for PopResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.hadResult = tmp;
-                },
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.message)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1095,14 +1445,14 @@ impl ::protobuf::Message for PopResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.hadResult != false {
-            my_size += 2;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
         }
-        if !self.message.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.message);
+        if self.status != EnqueueStatus::CREATED {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
         }
-        if !self.id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.id);
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1110,14 +1460,14 @@ impl ::protobuf::Message for PopResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.hadResult != false {
-            os.write_bool(3, self.hadResult)?;
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
         }
-        if !self.message.is_empty() {
-            os.write_bytes(1, &self.message)?;
+        if self.status != EnqueueStatus::CREATED {
+            os.write_enum(2, self.status.value())?;
         }
-        if !self.id.is_empty() {
-            os.write_string(2, &self.id)?;
+        if !self.error.is_empty() {
+            os.write_string(3, &self.error)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1149,8 +1499,8 @@ impl ::protobuf::Message for PopResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> PopResponse {
-        PopResponse::new()
+    fn new() -> EnqueueBatchResult {
+        EnqueueBatchResult::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1161,23 +1511,23 @@ impl ::protobuf::Message for PopResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "hadResult",
-                    |m: &PopResponse| { &m.hadResult },
-                    |m: &mut PopResponse| { &mut m.hadResult },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &EnqueueBatchResult| { &m.id },
+                    |m: &mut EnqueueBatchResult| { &mut m.id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "message",
-                    |m: &PopResponse| { &m.message },
-                    |m: &mut PopResponse| { &mut m.message },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<EnqueueStatus>>(
+                    "status",
+                    |m: &EnqueueBatchResult| { &m.status },
+                    |m: &mut EnqueueBatchResult| { &mut m.status },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "id",
-                    |m: &PopResponse| { &m.id },
-                    |m: &mut PopResponse| { &mut m.id },
+                    "error",
+                    |m: &EnqueueBatchResult| { &m.error },
+                    |m: &mut EnqueueBatchResult| { &mut m.error },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PopResponse>(
-                    "PopResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<EnqueueBatchResult>(
+                    "EnqueueBatchResult",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1185,81 +1535,85 @@ impl ::protobuf::Message for PopResponse {
         }
     }
 
-    fn default_instance() -> &'static PopResponse {
-        static mut instance: ::protobuf::lazy::Lazy<PopResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static EnqueueBatchResult {
+        static mut instance: ::protobuf::lazy::Lazy<EnqueueBatchResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PopResponse,
+            ptr: 0 as *const EnqueueBatchResult,
         };
         unsafe {
-            instance.get(PopResponse::new)
+            instance.get(EnqueueBatchResult::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PopResponse {
+impl ::protobuf::Clear for EnqueueBatchResult {
     fn clear(&mut self) {
-        self.clear_hadResult();
-        self.clear_message();
         self.clear_id();
+        self.clear_status();
+        self.clear_error();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PopResponse {
+impl ::std::fmt::Debug for EnqueueBatchResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PopResponse {
+impl ::protobuf::reflect::ProtobufValue for EnqueueBatchResult {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AcknowledgeRequest {
+pub struct EnqueueBatchResponse {
     // message fields
-    pub id: ::std::string::String,
+    pub results: ::protobuf::RepeatedField<EnqueueBatchResult>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl AcknowledgeRequest {
-    pub fn new() -> AcknowledgeRequest {
+impl EnqueueBatchResponse {
+    pub fn new() -> EnqueueBatchResponse {
         ::std::default::Default::default()
     }
 
-    // string id = 1;
+    // repeated .EnqueueBatchResult results = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id.clear();
+    pub fn clear_results(&mut self) {
+        self.results.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = v;
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<EnqueueBatchResult>) {
+        self.results = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        &mut self.id
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<EnqueueBatchResult> {
+        &mut self.results
     }
 
     // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<EnqueueBatchResult> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_id(&self) -> &str {
-        &self.id
+    pub fn get_results(&self) -> &[EnqueueBatchResult] {
+        &self.results
     }
 }
 
-impl ::protobuf::Message for AcknowledgeRequest {
+impl ::protobuf::Message for EnqueueBatchResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1268,7 +1622,7 @@ impl ::protobuf::Message for AcknowledgeRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1282,18 +1636,21 @@ impl ::protobuf::Message for AcknowledgeRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.id);
-        }
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.id.is_empty() {
-            os.write_string(1, &self.id)?;
-        }
+        for v in &self.results {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1324,8 +1681,8 @@ impl ::protobuf::Message for AcknowledgeRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> AcknowledgeRequest {
-        AcknowledgeRequest::new()
+    fn new() -> EnqueueBatchResponse {
+        EnqueueBatchResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1336,13 +1693,13 @@ impl ::protobuf::Message for AcknowledgeRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "id",
-                    |m: &AcknowledgeRequest| { &m.id },
-                    |m: &mut AcknowledgeRequest| { &mut m.id },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnqueueBatchResult>>(
+                    "results",
+                    |m: &EnqueueBatchResponse| { &m.results },
+                    |m: &mut EnqueueBatchResponse| { &mut m.results },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeRequest>(
-                    "AcknowledgeRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<EnqueueBatchResponse>(
+                    "EnqueueBatchResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1350,50 +1707,168 @@ impl ::protobuf::Message for AcknowledgeRequest {
         }
     }
 
-    fn default_instance() -> &'static AcknowledgeRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static EnqueueBatchResponse {
+        static mut instance: ::protobuf::lazy::Lazy<EnqueueBatchResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AcknowledgeRequest,
+            ptr: 0 as *const EnqueueBatchResponse,
         };
         unsafe {
-            instance.get(AcknowledgeRequest::new)
+            instance.get(EnqueueBatchResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for AcknowledgeRequest {
+impl ::protobuf::Clear for EnqueueBatchResponse {
     fn clear(&mut self) {
-        self.clear_id();
+        self.clear_results();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AcknowledgeRequest {
+impl ::std::fmt::Debug for EnqueueBatchResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AcknowledgeRequest {
+impl ::protobuf::reflect::ProtobufValue for EnqueueBatchResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(PartialEq, Clone, Default)]
-pub struct AcknowledgeResponse {
+#[derive(PartialEq,Clone,Default)]
+pub struct BeginEnqueueRequest {
+    // message fields
+    pub priority: Priority,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub delayMilliseconds: u64,
+    pub availableAt: ::std::string::String,
+    pub ttlSeconds: u64,
+    pub priorityLevel: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl AcknowledgeResponse {
-    pub fn new() -> AcknowledgeResponse {
+impl BeginEnqueueRequest {
+    pub fn new() -> BeginEnqueueRequest {
         ::std::default::Default::default()
     }
+
+    // .Priority priority = 1;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = Priority::LOW;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: Priority) {
+        self.priority = v;
+    }
+
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+
+    // repeated string requiredCapabilities = 2;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+
+    // uint64 delayMilliseconds = 3;
+
+    pub fn clear_delayMilliseconds(&mut self) {
+        self.delayMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delayMilliseconds(&mut self, v: u64) {
+        self.delayMilliseconds = v;
+    }
+
+    pub fn get_delayMilliseconds(&self) -> u64 {
+        self.delayMilliseconds
+    }
+
+    // string availableAt = 4;
+
+    pub fn clear_availableAt(&mut self) {
+        self.availableAt.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableAt(&mut self, v: ::std::string::String) {
+        self.availableAt = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_availableAt(&mut self) -> &mut ::std::string::String {
+        &mut self.availableAt
+    }
+
+    // Take field
+    pub fn take_availableAt(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.availableAt, ::std::string::String::new())
+    }
+
+    pub fn get_availableAt(&self) -> &str {
+        &self.availableAt
+    }
+
+    // uint64 ttlSeconds = 5;
+
+    pub fn clear_ttlSeconds(&mut self) {
+        self.ttlSeconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttlSeconds(&mut self, v: u64) {
+        self.ttlSeconds = v;
+    }
+
+    pub fn get_ttlSeconds(&self) -> u64 {
+        self.ttlSeconds
+    }
+
+    // uint32 priorityLevel = 6;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
 }
 
-impl ::protobuf::Message for AcknowledgeResponse {
+impl ::protobuf::Message for BeginEnqueueRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1402,6 +1877,36 @@ impl ::protobuf::Message for AcknowledgeResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.priority, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.delayMilliseconds = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.availableAt)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttlSeconds = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1414,12 +1919,48 @@ impl ::protobuf::Message for AcknowledgeResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if self.priority != Priority::LOW {
+            my_size += ::protobuf::rt::enum_size(1, self.priority);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.delayMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.delayMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.availableAt.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.availableAt);
+        }
+        if self.ttlSeconds != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.ttlSeconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.priority != Priority::LOW {
+            os.write_enum(1, self.priority.value())?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(2, &v)?;
+        };
+        if self.delayMilliseconds != 0 {
+            os.write_uint64(3, self.delayMilliseconds)?;
+        }
+        if !self.availableAt.is_empty() {
+            os.write_string(4, &self.availableAt)?;
+        }
+        if self.ttlSeconds != 0 {
+            os.write_uint64(5, self.ttlSeconds)?;
+        }
+        if self.priorityLevel != 0 {
+            os.write_uint32(6, self.priorityLevel)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1450,8 +1991,8 @@ impl ::protobuf::Message for AcknowledgeResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> AcknowledgeResponse {
-        AcknowledgeResponse::new()
+    fn new() -> BeginEnqueueRequest {
+        BeginEnqueueRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1461,87 +2002,123 @@ impl ::protobuf::Message for AcknowledgeResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeResponse>(
-                    "AcknowledgeResponse",
-                    fields,
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Priority>>(
+                    "priority",
+                    |m: &BeginEnqueueRequest| { &m.priority },
+                    |m: &mut BeginEnqueueRequest| { &mut m.priority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &BeginEnqueueRequest| { &m.requiredCapabilities },
+                    |m: &mut BeginEnqueueRequest| { &mut m.requiredCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "delayMilliseconds",
+                    |m: &BeginEnqueueRequest| { &m.delayMilliseconds },
+                    |m: &mut BeginEnqueueRequest| { &mut m.delayMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableAt",
+                    |m: &BeginEnqueueRequest| { &m.availableAt },
+                    |m: &mut BeginEnqueueRequest| { &mut m.availableAt },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttlSeconds",
+                    |m: &BeginEnqueueRequest| { &m.ttlSeconds },
+                    |m: &mut BeginEnqueueRequest| { &mut m.ttlSeconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &BeginEnqueueRequest| { &m.priorityLevel },
+                    |m: &mut BeginEnqueueRequest| { &mut m.priorityLevel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BeginEnqueueRequest>(
+                    "BeginEnqueueRequest",
+                    fields,
                     file_descriptor_proto()
                 )
             })
         }
     }
 
-    fn default_instance() -> &'static AcknowledgeResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static BeginEnqueueRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BeginEnqueueRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AcknowledgeResponse,
+            ptr: 0 as *const BeginEnqueueRequest,
         };
         unsafe {
-            instance.get(AcknowledgeResponse::new)
+            instance.get(BeginEnqueueRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for AcknowledgeResponse {
+impl ::protobuf::Clear for BeginEnqueueRequest {
     fn clear(&mut self) {
+        self.clear_priority();
+        self.clear_requiredCapabilities();
+        self.clear_delayMilliseconds();
+        self.clear_availableAt();
+        self.clear_ttlSeconds();
+        self.clear_priorityLevel();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AcknowledgeResponse {
+impl ::std::fmt::Debug for BeginEnqueueRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AcknowledgeResponse {
+impl ::protobuf::reflect::ProtobufValue for BeginEnqueueRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ErrorResponse {
+pub struct BeginEnqueueResponse {
     // message fields
-    pub message: ::std::string::String,
+    pub transferId: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl ErrorResponse {
-    pub fn new() -> ErrorResponse {
+impl BeginEnqueueResponse {
+    pub fn new() -> BeginEnqueueResponse {
         ::std::default::Default::default()
     }
 
-    // string message = 1;
+    // string transferId = 1;
 
-    pub fn clear_message(&mut self) {
-        self.message.clear();
+    pub fn clear_transferId(&mut self) {
+        self.transferId.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_message(&mut self, v: ::std::string::String) {
-        self.message = v;
+    pub fn set_transferId(&mut self, v: ::std::string::String) {
+        self.transferId = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_message(&mut self) -> &mut ::std::string::String {
-        &mut self.message
+    pub fn mut_transferId(&mut self) -> &mut ::std::string::String {
+        &mut self.transferId
     }
 
     // Take field
-    pub fn take_message(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    pub fn take_transferId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.transferId, ::std::string::String::new())
     }
 
-    pub fn get_message(&self) -> &str {
-        &self.message
+    pub fn get_transferId(&self) -> &str {
+        &self.transferId
     }
 }
 
-impl ::protobuf::Message for ErrorResponse {
+impl ::protobuf::Message for BeginEnqueueResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1551,7 +2128,7 @@ impl ::protobuf::Message for ErrorResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.transferId)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1565,8 +2142,8 @@ impl ::protobuf::Message for ErrorResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.message.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.message);
+        if !self.transferId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.transferId);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1574,8 +2151,8 @@ impl ::protobuf::Message for ErrorResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.message.is_empty() {
-            os.write_string(1, &self.message)?;
+        if !self.transferId.is_empty() {
+            os.write_string(1, &self.transferId)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1607,8 +2184,8 @@ impl ::protobuf::Message for ErrorResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> ErrorResponse {
-        ErrorResponse::new()
+    fn new() -> BeginEnqueueResponse {
+        BeginEnqueueResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1620,12 +2197,12 @@ impl ::protobuf::Message for ErrorResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "message",
-                    |m: &ErrorResponse| { &m.message },
-                    |m: &mut ErrorResponse| { &mut m.message },
+                    "transferId",
+                    |m: &BeginEnqueueResponse| { &m.transferId },
+                    |m: &mut BeginEnqueueResponse| { &mut m.transferId },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ErrorResponse>(
-                    "ErrorResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<BeginEnqueueResponse>(
+                    "BeginEnqueueResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1633,293 +2210,308 @@ impl ::protobuf::Message for ErrorResponse {
         }
     }
 
-    fn default_instance() -> &'static ErrorResponse {
-        static mut instance: ::protobuf::lazy::Lazy<ErrorResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static BeginEnqueueResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BeginEnqueueResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ErrorResponse,
+            ptr: 0 as *const BeginEnqueueResponse,
         };
         unsafe {
-            instance.get(ErrorResponse::new)
+            instance.get(BeginEnqueueResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ErrorResponse {
+impl ::protobuf::Clear for BeginEnqueueResponse {
     fn clear(&mut self) {
-        self.clear_message();
+        self.clear_transferId();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ErrorResponse {
+impl ::std::fmt::Debug for BeginEnqueueResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
+impl ::protobuf::reflect::ProtobufValue for BeginEnqueueResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RequestWrapper {
+pub struct EnqueueChunkRequest {
     // message fields
-    pub refId: i32,
-    // message oneof groups
-    pub message: ::std::option::Option<RequestWrapper_oneof_message>,
+    pub transferId: ::std::string::String,
+    pub offset: u64,
+    pub data: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum RequestWrapper_oneof_message {
-    enqueue(EnqueueRequest),
-    pop(PopRequest),
-    acknowledge(AcknowledgeRequest),
-    authenticate(AuthenticateRequest),
-}
-
-impl RequestWrapper {
-    pub fn new() -> RequestWrapper {
+impl EnqueueChunkRequest {
+    pub fn new() -> EnqueueChunkRequest {
         ::std::default::Default::default()
     }
 
-    // int32 refId = 10;
+    // string transferId = 1;
 
-    pub fn clear_refId(&mut self) {
-        self.refId = 0;
+    pub fn clear_transferId(&mut self) {
+        self.transferId.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_refId(&mut self, v: i32) {
-        self.refId = v;
+    pub fn set_transferId(&mut self, v: ::std::string::String) {
+        self.transferId = v;
     }
 
-    pub fn get_refId(&self) -> i32 {
-        self.refId
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transferId(&mut self) -> &mut ::std::string::String {
+        &mut self.transferId
     }
 
-    // .EnqueueRequest enqueue = 1;
-
-    pub fn clear_enqueue(&mut self) {
-        self.message = ::std::option::Option::None;
+    // Take field
+    pub fn take_transferId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.transferId, ::std::string::String::new())
     }
 
-    pub fn has_enqueue(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(..)) => true,
-            _ => false,
-        }
+    pub fn get_transferId(&self) -> &str {
+        &self.transferId
     }
 
-    // Param is passed by value, moved
-    pub fn set_enqueue(&mut self, v: EnqueueRequest) {
-        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(v))
-    }
+    // uint64 offset = 2;
 
-    // Mutable pointer to the field.
-    pub fn mut_enqueue(&mut self) -> &mut EnqueueRequest {
-        if let ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(EnqueueRequest::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
     }
 
-    // Take field
-    pub fn take_enqueue(&mut self) -> EnqueueRequest {
-        if self.has_enqueue() {
-            match self.message.take() {
-                ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            EnqueueRequest::new()
-        }
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = v;
     }
 
-    pub fn get_enqueue(&self) -> &EnqueueRequest {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(ref v)) => v,
-            _ => EnqueueRequest::default_instance(),
-        }
+    pub fn get_offset(&self) -> u64 {
+        self.offset
     }
 
-    // .PopRequest pop = 2;
-
-    pub fn clear_pop(&mut self) {
-        self.message = ::std::option::Option::None;
-    }
+    // bytes data = 3;
 
-    pub fn has_pop(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(..)) => true,
-            _ => false,
-        }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_pop(&mut self, v: PopRequest) {
-        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(v))
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_pop(&mut self) -> &mut PopRequest {
-        if let ::std::option::Option::Some(RequestWrapper_oneof_message::pop(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(PopRequest::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
     // Take field
-    pub fn take_pop(&mut self) -> PopRequest {
-        if self.has_pop() {
-            match self.message.take() {
-                ::std::option::Option::Some(RequestWrapper_oneof_message::pop(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            PopRequest::new()
-        }
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
 
-    pub fn get_pop(&self) -> &PopRequest {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(ref v)) => v,
-            _ => PopRequest::default_instance(),
-        }
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
+}
 
-    // .AcknowledgeRequest acknowledge = 3;
-
-    pub fn clear_acknowledge(&mut self) {
-        self.message = ::std::option::Option::None;
+impl ::protobuf::Message for EnqueueChunkRequest {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_acknowledge(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(..)) => true,
-            _ => false,
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.transferId)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_acknowledge(&mut self, v: AcknowledgeRequest) {
-        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(v))
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.transferId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.transferId);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_acknowledge(&mut self) -> &mut AcknowledgeRequest {
-        if let ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(AcknowledgeRequest::new()));
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.transferId.is_empty() {
+            os.write_string(1, &self.transferId)?;
         }
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(ref mut v)) => v,
-            _ => panic!(),
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
         }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_acknowledge(&mut self) -> AcknowledgeRequest {
-        if self.has_acknowledge() {
-            match self.message.take() {
-                ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AcknowledgeRequest::new()
-        }
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    pub fn get_acknowledge(&self) -> &AcknowledgeRequest {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(ref v)) => v,
-            _ => AcknowledgeRequest::default_instance(),
-        }
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // .AuthenticateRequest authenticate = 6;
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn clear_authenticate(&mut self) {
-        self.message = ::std::option::Option::None;
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    pub fn has_authenticate(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(..)) => true,
-            _ => false,
-        }
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // Param is passed by value, moved
-    pub fn set_authenticate(&mut self, v: AuthenticateRequest) {
-        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(v))
+    fn new() -> EnqueueChunkRequest {
+        EnqueueChunkRequest::new()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_authenticate(&mut self) -> &mut AuthenticateRequest {
-        if let ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(_)) = self.message {} else {
-            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(AuthenticateRequest::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(ref mut v)) => v,
-            _ => panic!(),
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "transferId",
+                    |m: &EnqueueChunkRequest| { &m.transferId },
+                    |m: &mut EnqueueChunkRequest| { &mut m.transferId },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "offset",
+                    |m: &EnqueueChunkRequest| { &m.offset },
+                    |m: &mut EnqueueChunkRequest| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &EnqueueChunkRequest| { &m.data },
+                    |m: &mut EnqueueChunkRequest| { &mut m.data },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<EnqueueChunkRequest>(
+                    "EnqueueChunkRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
 
-    // Take field
-    pub fn take_authenticate(&mut self) -> AuthenticateRequest {
-        if self.has_authenticate() {
-            match self.message.take() {
-                ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AuthenticateRequest::new()
+    fn default_instance() -> &'static EnqueueChunkRequest {
+        static mut instance: ::protobuf::lazy::Lazy<EnqueueChunkRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EnqueueChunkRequest,
+        };
+        unsafe {
+            instance.get(EnqueueChunkRequest::new)
         }
     }
+}
 
-    pub fn get_authenticate(&self) -> &AuthenticateRequest {
-        match self.message {
-            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(ref v)) => v,
-            _ => AuthenticateRequest::default_instance(),
-        }
+impl ::protobuf::Clear for EnqueueChunkRequest {
+    fn clear(&mut self) {
+        self.clear_transferId();
+        self.clear_offset();
+        self.clear_data();
+        self.unknown_fields.clear();
     }
 }
 
-impl ::protobuf::Message for RequestWrapper {
+impl ::std::fmt::Debug for EnqueueChunkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EnqueueChunkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EnqueueChunkResponse {
+    // message fields
+    pub received: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl EnqueueChunkResponse {
+    pub fn new() -> EnqueueChunkResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 received = 1;
+
+    pub fn clear_received(&mut self) {
+        self.received = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_received(&mut self, v: u64) {
+        self.received = v;
+    }
+
+    pub fn get_received(&self) -> u64 {
+        self.received
+    }
+}
+
+impl ::protobuf::Message for EnqueueChunkResponse {
     fn is_initialized(&self) -> bool {
-        if let Some(RequestWrapper_oneof_message::enqueue(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(RequestWrapper_oneof_message::pop(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(RequestWrapper_oneof_message::acknowledge(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(RequestWrapper_oneof_message::authenticate(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -1927,36 +2519,12 @@ impl ::protobuf::Message for RequestWrapper {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                10 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.refId = tmp;
-                },
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(is.read_message()?));
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(is.read_message()?));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(is.read_message()?));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(is.read_message()?));
+                    let tmp = is.read_uint64()?;
+                    self.received = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1970,28 +2538,8 @@ impl ::protobuf::Message for RequestWrapper {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.refId != 0 {
-            my_size += ::protobuf::rt::value_size(10, self.refId, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &RequestWrapper_oneof_message::enqueue(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &RequestWrapper_oneof_message::pop(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &RequestWrapper_oneof_message::acknowledge(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &RequestWrapper_oneof_message::authenticate(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.received != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.received, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1999,32 +2547,8 @@ impl ::protobuf::Message for RequestWrapper {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.refId != 0 {
-            os.write_int32(10, self.refId)?;
-        }
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &RequestWrapper_oneof_message::enqueue(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &RequestWrapper_oneof_message::pop(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &RequestWrapper_oneof_message::acknowledge(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &RequestWrapper_oneof_message::authenticate(ref v) => {
-                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.received != 0 {
+            os.write_uint64(1, self.received)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2056,8 +2580,8 @@ impl ::protobuf::Message for RequestWrapper {
         Self::descriptor_static()
     }
 
-    fn new() -> RequestWrapper {
-        RequestWrapper::new()
+    fn new() -> EnqueueChunkResponse {
+        EnqueueChunkResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2068,33 +2592,13 @@ impl ::protobuf::Message for RequestWrapper {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "refId",
-                    |m: &RequestWrapper| { &m.refId },
-                    |m: &mut RequestWrapper| { &mut m.refId },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueRequest>(
-                    "enqueue",
-                    RequestWrapper::has_enqueue,
-                    RequestWrapper::get_enqueue,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PopRequest>(
-                    "pop",
-                    RequestWrapper::has_pop,
-                    RequestWrapper::get_pop,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AcknowledgeRequest>(
-                    "acknowledge",
-                    RequestWrapper::has_acknowledge,
-                    RequestWrapper::get_acknowledge,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AuthenticateRequest>(
-                    "authenticate",
-                    RequestWrapper::has_authenticate,
-                    RequestWrapper::get_authenticate,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "received",
+                    |m: &EnqueueChunkResponse| { &m.received },
+                    |m: &mut EnqueueChunkResponse| { &mut m.received },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<RequestWrapper>(
-                    "RequestWrapper",
+                ::protobuf::reflect::MessageDescriptor::new::<EnqueueChunkResponse>(
+                    "EnqueueChunkResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2102,338 +2606,15724 @@ impl ::protobuf::Message for RequestWrapper {
         }
     }
 
-    fn default_instance() -> &'static RequestWrapper {
-        static mut instance: ::protobuf::lazy::Lazy<RequestWrapper> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static EnqueueChunkResponse {
+        static mut instance: ::protobuf::lazy::Lazy<EnqueueChunkResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const RequestWrapper,
+            ptr: 0 as *const EnqueueChunkResponse,
         };
         unsafe {
-            instance.get(RequestWrapper::new)
+            instance.get(EnqueueChunkResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for RequestWrapper {
+impl ::protobuf::Clear for EnqueueChunkResponse {
     fn clear(&mut self) {
-        self.clear_refId();
-        self.clear_enqueue();
-        self.clear_pop();
-        self.clear_acknowledge();
-        self.clear_authenticate();
+        self.clear_received();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RequestWrapper {
+impl ::std::fmt::Debug for EnqueueChunkResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RequestWrapper {
+impl ::protobuf::reflect::ProtobufValue for EnqueueChunkResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ResponseWrapper {
+pub struct CommitEnqueueRequest {
     // message fields
-    pub refId: i32,
-    // message oneof groups
-    pub message: ::std::option::Option<ResponseWrapper_oneof_message>,
+    pub transferId: ::std::string::String,
+    pub queueName: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum ResponseWrapper_oneof_message {
-    enqueue(EnqueueResponse),
-    pop(PopResponse),
-    acknowledge(AcknowledgeResponse),
-    error(ErrorResponse),
-    authenticate(AuthenticateResponse),
-}
-
-impl ResponseWrapper {
-    pub fn new() -> ResponseWrapper {
+impl CommitEnqueueRequest {
+    pub fn new() -> CommitEnqueueRequest {
         ::std::default::Default::default()
     }
 
-    // int32 refId = 10;
+    // string transferId = 1;
 
-    pub fn clear_refId(&mut self) {
-        self.refId = 0;
+    pub fn clear_transferId(&mut self) {
+        self.transferId.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_refId(&mut self, v: i32) {
-        self.refId = v;
+    pub fn set_transferId(&mut self, v: ::std::string::String) {
+        self.transferId = v;
     }
 
-    pub fn get_refId(&self) -> i32 {
-        self.refId
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transferId(&mut self) -> &mut ::std::string::String {
+        &mut self.transferId
     }
 
-    // .EnqueueResponse enqueue = 1;
+    // Take field
+    pub fn take_transferId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.transferId, ::std::string::String::new())
+    }
 
-    pub fn clear_enqueue(&mut self) {
-        self.message = ::std::option::Option::None;
+    pub fn get_transferId(&self) -> &str {
+        &self.transferId
     }
 
-    pub fn has_enqueue(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(..)) => true,
-            _ => false,
-        }
+    // string queueName = 2;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_enqueue(&mut self, v: EnqueueResponse) {
-        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(v))
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_enqueue(&mut self) -> &mut EnqueueResponse {
-        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(EnqueueResponse::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
     }
 
     // Take field
-    pub fn take_enqueue(&mut self) -> EnqueueResponse {
-        if self.has_enqueue() {
-            match self.message.take() {
-                ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            EnqueueResponse::new()
-        }
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
     }
 
-    pub fn get_enqueue(&self) -> &EnqueueResponse {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(ref v)) => v,
-            _ => EnqueueResponse::default_instance(),
-        }
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
     }
+}
 
-    // .PopResponse pop = 2;
-
-    pub fn clear_pop(&mut self) {
-        self.message = ::std::option::Option::None;
+impl ::protobuf::Message for CommitEnqueueRequest {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_pop(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(..)) => true,
-            _ => false,
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.transferId)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_pop(&mut self, v: PopResponse) {
-        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_pop(&mut self) -> &mut PopResponse {
-        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(PopResponse::new()));
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.transferId.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.transferId);
         }
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(ref mut v)) => v,
-            _ => panic!(),
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.queueName);
         }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Take field
-    pub fn take_pop(&mut self) -> PopResponse {
-        if self.has_pop() {
-            match self.message.take() {
-                ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            PopResponse::new()
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.transferId.is_empty() {
+            os.write_string(1, &self.transferId)?;
         }
-    }
-
-    pub fn get_pop(&self) -> &PopResponse {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(ref v)) => v,
-            _ => PopResponse::default_instance(),
+        if !self.queueName.is_empty() {
+            os.write_string(2, &self.queueName)?;
         }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // .AcknowledgeResponse acknowledge = 3;
-
-    pub fn clear_acknowledge(&mut self) {
-        self.message = ::std::option::Option::None;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    pub fn has_acknowledge(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(..)) => true,
-            _ => false,
-        }
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_acknowledge(&mut self, v: AcknowledgeResponse) {
-        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(v))
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_acknowledge(&mut self) -> &mut AcknowledgeResponse {
-        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(_)) = self.message {} else {
-            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(AcknowledgeResponse::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(ref mut v)) => v,
-            _ => panic!(),
-        }
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    // Take field
-    pub fn take_acknowledge(&mut self) -> AcknowledgeResponse {
-        if self.has_acknowledge() {
-            match self.message.take() {
-                ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AcknowledgeResponse::new()
-        }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
     }
-
-    pub fn get_acknowledge(&self) -> &AcknowledgeResponse {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(ref v)) => v,
-            _ => AcknowledgeResponse::default_instance(),
-        }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // .ErrorResponse error = 4;
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
 
-    pub fn clear_error(&mut self) {
-        self.message = ::std::option::Option::None;
+    fn new() -> CommitEnqueueRequest {
+        CommitEnqueueRequest::new()
     }
 
-    pub fn has_error(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(..)) => true,
-            _ => false,
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "transferId",
+                    |m: &CommitEnqueueRequest| { &m.transferId },
+                    |m: &mut CommitEnqueueRequest| { &mut m.transferId },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &CommitEnqueueRequest| { &m.queueName },
+                    |m: &mut CommitEnqueueRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitEnqueueRequest>(
+                    "CommitEnqueueRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
 
-    // Param is passed by value, moved
-    pub fn set_error(&mut self, v: ErrorResponse) {
-        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(v))
+    fn default_instance() -> &'static CommitEnqueueRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CommitEnqueueRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitEnqueueRequest,
+        };
+        unsafe {
+            instance.get(CommitEnqueueRequest::new)
+        }
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_error(&mut self) -> &mut ErrorResponse {
-        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::error(_)) = self.message {} else {
-            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ErrorResponse::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ref mut v)) => v,
-            _ => panic!(),
-        }
+impl ::protobuf::Clear for CommitEnqueueRequest {
+    fn clear(&mut self) {
+        self.clear_transferId();
+        self.clear_queueName();
+        self.unknown_fields.clear();
     }
+}
 
-    // Take field
-    pub fn take_error(&mut self) -> ErrorResponse {
-        if self.has_error() {
-            match self.message.take() {
-                ::std::option::Option::Some(ResponseWrapper_oneof_message::error(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ErrorResponse::new()
-        }
+impl ::std::fmt::Debug for CommitEnqueueRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn get_error(&self) -> &ErrorResponse {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ref v)) => v,
-            _ => ErrorResponse::default_instance(),
-        }
+impl ::protobuf::reflect::ProtobufValue for CommitEnqueueRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // .AuthenticateResponse authenticate = 6;
+#[derive(PartialEq,Clone,Default)]
+pub struct CommitEnqueueResponse {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn clear_authenticate(&mut self) {
-        self.message = ::std::option::Option::None;
+impl CommitEnqueueResponse {
+    pub fn new() -> CommitEnqueueResponse {
+        ::std::default::Default::default()
     }
 
-    pub fn has_authenticate(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(..)) => true,
-            _ => false,
-        }
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_authenticate(&mut self, v: AuthenticateResponse) {
-        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(v))
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_authenticate(&mut self) -> &mut AuthenticateResponse {
-        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(_)) = self.message {} else {
-            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(AuthenticateResponse::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
     }
 
     // Take field
-    pub fn take_authenticate(&mut self) -> AuthenticateResponse {
-        if self.has_authenticate() {
-            match self.message.take() {
-                ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            AuthenticateResponse::new()
-        }
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
     }
 
-    pub fn get_authenticate(&self) -> &AuthenticateResponse {
-        match self.message {
-            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(ref v)) => v,
-            _ => AuthenticateResponse::default_instance(),
-        }
+    pub fn get_id(&self) -> &str {
+        &self.id
     }
 }
 
-impl ::protobuf::Message for ResponseWrapper {
+impl ::protobuf::Message for CommitEnqueueResponse {
     fn is_initialized(&self) -> bool {
-        if let Some(ResponseWrapper_oneof_message::enqueue(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(ResponseWrapper_oneof_message::pop(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(ResponseWrapper_oneof_message::acknowledge(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitEnqueueResponse {
+        CommitEnqueueResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &CommitEnqueueResponse| { &m.id },
+                    |m: &mut CommitEnqueueResponse| { &mut m.id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitEnqueueResponse>(
+                    "CommitEnqueueResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitEnqueueResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CommitEnqueueResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitEnqueueResponse,
+        };
+        unsafe {
+            instance.get(CommitEnqueueResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitEnqueueResponse {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitEnqueueResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitEnqueueResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PopRequest {
+    // message fields
+    pub availableCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub waitForMessage: bool,
+    pub visibilityTimeoutMilliseconds: u64,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PopRequest {
+    pub fn new() -> PopRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string availableCapabilities = 1;
+
+    pub fn clear_availableCapabilities(&mut self) {
+        self.availableCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.availableCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_availableCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.availableCapabilities
+    }
+
+    // Take field
+    pub fn take_availableCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.availableCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_availableCapabilities(&self) -> &[::std::string::String] {
+        &self.availableCapabilities
+    }
+
+    // bool waitForMessage = 2;
+
+    pub fn clear_waitForMessage(&mut self) {
+        self.waitForMessage = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_waitForMessage(&mut self, v: bool) {
+        self.waitForMessage = v;
+    }
+
+    pub fn get_waitForMessage(&self) -> bool {
+        self.waitForMessage
+    }
+
+    // uint64 visibilityTimeoutMilliseconds = 3;
+
+    pub fn clear_visibilityTimeoutMilliseconds(&mut self) {
+        self.visibilityTimeoutMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visibilityTimeoutMilliseconds(&mut self, v: u64) {
+        self.visibilityTimeoutMilliseconds = v;
+    }
+
+    pub fn get_visibilityTimeoutMilliseconds(&self) -> u64 {
+        self.visibilityTimeoutMilliseconds
+    }
+
+    // string queueName = 4;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for PopRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.availableCapabilities)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.waitForMessage = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.visibilityTimeoutMilliseconds = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.availableCapabilities {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.waitForMessage != false {
+            my_size += 2;
+        }
+        if self.visibilityTimeoutMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.visibilityTimeoutMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.availableCapabilities {
+            os.write_string(1, &v)?;
+        };
+        if self.waitForMessage != false {
+            os.write_bool(2, self.waitForMessage)?;
+        }
+        if self.visibilityTimeoutMilliseconds != 0 {
+            os.write_uint64(3, self.visibilityTimeoutMilliseconds)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(4, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PopRequest {
+        PopRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableCapabilities",
+                    |m: &PopRequest| { &m.availableCapabilities },
+                    |m: &mut PopRequest| { &mut m.availableCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "waitForMessage",
+                    |m: &PopRequest| { &m.waitForMessage },
+                    |m: &mut PopRequest| { &mut m.waitForMessage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "visibilityTimeoutMilliseconds",
+                    |m: &PopRequest| { &m.visibilityTimeoutMilliseconds },
+                    |m: &mut PopRequest| { &mut m.visibilityTimeoutMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &PopRequest| { &m.queueName },
+                    |m: &mut PopRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PopRequest>(
+                    "PopRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PopRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PopRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PopRequest,
+        };
+        unsafe {
+            instance.get(PopRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PopRequest {
+    fn clear(&mut self) {
+        self.clear_availableCapabilities();
+        self.clear_waitForMessage();
+        self.clear_visibilityTimeoutMilliseconds();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PopRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PopRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PopResponse {
+    // message fields
+    pub hadResult: bool,
+    pub message: ::std::vec::Vec<u8>,
+    pub id: ::std::string::String,
+    pub hasUnmatchedMessages: bool,
+    pub cancelled: bool,
+    pub enqueuedBy: ::std::string::String,
+    pub backpressure: bool,
+    pub sequence: u64,
+    pub rateLimited: bool,
+    pub notReady: bool,
+    pub visibilityTimeoutMilliseconds: u64,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub requiredCapabilitiesTruncated: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PopResponse {
+    pub fn new() -> PopResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool hadResult = 3;
+
+    pub fn clear_hadResult(&mut self) {
+        self.hadResult = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hadResult(&mut self, v: bool) {
+        self.hadResult = v;
+    }
+
+    pub fn get_hadResult(&self) -> bool {
+        self.hadResult
+    }
+
+    // bytes message = 1;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::vec::Vec<u8>) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.message, ::std::vec::Vec::new())
+    }
+
+    pub fn get_message(&self) -> &[u8] {
+        &self.message
+    }
+
+    // string id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // bool hasUnmatchedMessages = 4;
+
+    pub fn clear_hasUnmatchedMessages(&mut self) {
+        self.hasUnmatchedMessages = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hasUnmatchedMessages(&mut self, v: bool) {
+        self.hasUnmatchedMessages = v;
+    }
+
+    pub fn get_hasUnmatchedMessages(&self) -> bool {
+        self.hasUnmatchedMessages
+    }
+
+    // bool cancelled = 5;
+
+    pub fn clear_cancelled(&mut self) {
+        self.cancelled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancelled(&mut self, v: bool) {
+        self.cancelled = v;
+    }
+
+    pub fn get_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    // string enqueuedBy = 6;
+
+    pub fn clear_enqueuedBy(&mut self) {
+        self.enqueuedBy.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueuedBy(&mut self, v: ::std::string::String) {
+        self.enqueuedBy = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_enqueuedBy(&mut self) -> &mut ::std::string::String {
+        &mut self.enqueuedBy
+    }
+
+    // Take field
+    pub fn take_enqueuedBy(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.enqueuedBy, ::std::string::String::new())
+    }
+
+    pub fn get_enqueuedBy(&self) -> &str {
+        &self.enqueuedBy
+    }
+
+    // bool backpressure = 7;
+
+    pub fn clear_backpressure(&mut self) {
+        self.backpressure = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_backpressure(&mut self, v: bool) {
+        self.backpressure = v;
+    }
+
+    pub fn get_backpressure(&self) -> bool {
+        self.backpressure
+    }
+
+    // uint64 sequence = 8;
+
+    pub fn clear_sequence(&mut self) {
+        self.sequence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: u64) {
+        self.sequence = v;
+    }
+
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    // bool rateLimited = 9;
+
+    pub fn clear_rateLimited(&mut self) {
+        self.rateLimited = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rateLimited(&mut self, v: bool) {
+        self.rateLimited = v;
+    }
+
+    pub fn get_rateLimited(&self) -> bool {
+        self.rateLimited
+    }
+
+    // bool notReady = 10;
+
+    pub fn clear_notReady(&mut self) {
+        self.notReady = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notReady(&mut self, v: bool) {
+        self.notReady = v;
+    }
+
+    pub fn get_notReady(&self) -> bool {
+        self.notReady
+    }
+
+    // uint64 visibilityTimeoutMilliseconds = 11;
+
+    pub fn clear_visibilityTimeoutMilliseconds(&mut self) {
+        self.visibilityTimeoutMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visibilityTimeoutMilliseconds(&mut self, v: u64) {
+        self.visibilityTimeoutMilliseconds = v;
+    }
+
+    pub fn get_visibilityTimeoutMilliseconds(&self) -> u64 {
+        self.visibilityTimeoutMilliseconds
+    }
+
+    // repeated string requiredCapabilities = 12;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+
+    // bool requiredCapabilitiesTruncated = 13;
+
+    pub fn clear_requiredCapabilitiesTruncated(&mut self) {
+        self.requiredCapabilitiesTruncated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilitiesTruncated(&mut self, v: bool) {
+        self.requiredCapabilitiesTruncated = v;
+    }
+
+    pub fn get_requiredCapabilitiesTruncated(&self) -> bool {
+        self.requiredCapabilitiesTruncated
+    }
+}
+
+impl ::protobuf::Message for PopResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.hadResult = tmp;
+                },
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.message)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.hasUnmatchedMessages = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cancelled = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.enqueuedBy)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.backpressure = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sequence = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rateLimited = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.notReady = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.visibilityTimeoutMilliseconds = tmp;
+                },
+                12 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.requiredCapabilitiesTruncated = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.hadResult != false {
+            my_size += 2;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.message);
+        }
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.id);
+        }
+        if self.hasUnmatchedMessages != false {
+            my_size += 2;
+        }
+        if self.cancelled != false {
+            my_size += 2;
+        }
+        if !self.enqueuedBy.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.enqueuedBy);
+        }
+        if self.backpressure != false {
+            my_size += 2;
+        }
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.sequence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rateLimited != false {
+            my_size += 2;
+        }
+        if self.notReady != false {
+            my_size += 2;
+        }
+        if self.visibilityTimeoutMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.visibilityTimeoutMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(12, &value);
+        };
+        if self.requiredCapabilitiesTruncated != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.hadResult != false {
+            os.write_bool(3, self.hadResult)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(1, &self.message)?;
+        }
+        if !self.id.is_empty() {
+            os.write_string(2, &self.id)?;
+        }
+        if self.hasUnmatchedMessages != false {
+            os.write_bool(4, self.hasUnmatchedMessages)?;
+        }
+        if self.cancelled != false {
+            os.write_bool(5, self.cancelled)?;
+        }
+        if !self.enqueuedBy.is_empty() {
+            os.write_string(6, &self.enqueuedBy)?;
+        }
+        if self.backpressure != false {
+            os.write_bool(7, self.backpressure)?;
+        }
+        if self.sequence != 0 {
+            os.write_uint64(8, self.sequence)?;
+        }
+        if self.rateLimited != false {
+            os.write_bool(9, self.rateLimited)?;
+        }
+        if self.notReady != false {
+            os.write_bool(10, self.notReady)?;
+        }
+        if self.visibilityTimeoutMilliseconds != 0 {
+            os.write_uint64(11, self.visibilityTimeoutMilliseconds)?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(12, &v)?;
+        };
+        if self.requiredCapabilitiesTruncated != false {
+            os.write_bool(13, self.requiredCapabilitiesTruncated)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PopResponse {
+        PopResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "hadResult",
+                    |m: &PopResponse| { &m.hadResult },
+                    |m: &mut PopResponse| { &mut m.hadResult },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "message",
+                    |m: &PopResponse| { &m.message },
+                    |m: &mut PopResponse| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &PopResponse| { &m.id },
+                    |m: &mut PopResponse| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "hasUnmatchedMessages",
+                    |m: &PopResponse| { &m.hasUnmatchedMessages },
+                    |m: &mut PopResponse| { &mut m.hasUnmatchedMessages },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "cancelled",
+                    |m: &PopResponse| { &m.cancelled },
+                    |m: &mut PopResponse| { &mut m.cancelled },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "enqueuedBy",
+                    |m: &PopResponse| { &m.enqueuedBy },
+                    |m: &mut PopResponse| { &mut m.enqueuedBy },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "backpressure",
+                    |m: &PopResponse| { &m.backpressure },
+                    |m: &mut PopResponse| { &mut m.backpressure },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "sequence",
+                    |m: &PopResponse| { &m.sequence },
+                    |m: &mut PopResponse| { &mut m.sequence },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "rateLimited",
+                    |m: &PopResponse| { &m.rateLimited },
+                    |m: &mut PopResponse| { &mut m.rateLimited },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "notReady",
+                    |m: &PopResponse| { &m.notReady },
+                    |m: &mut PopResponse| { &mut m.notReady },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "visibilityTimeoutMilliseconds",
+                    |m: &PopResponse| { &m.visibilityTimeoutMilliseconds },
+                    |m: &mut PopResponse| { &mut m.visibilityTimeoutMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &PopResponse| { &m.requiredCapabilities },
+                    |m: &mut PopResponse| { &mut m.requiredCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "requiredCapabilitiesTruncated",
+                    |m: &PopResponse| { &m.requiredCapabilitiesTruncated },
+                    |m: &mut PopResponse| { &mut m.requiredCapabilitiesTruncated },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PopResponse>(
+                    "PopResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PopResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PopResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PopResponse,
+        };
+        unsafe {
+            instance.get(PopResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PopResponse {
+    fn clear(&mut self) {
+        self.clear_hadResult();
+        self.clear_message();
+        self.clear_id();
+        self.clear_hasUnmatchedMessages();
+        self.clear_cancelled();
+        self.clear_enqueuedBy();
+        self.clear_backpressure();
+        self.clear_sequence();
+        self.clear_rateLimited();
+        self.clear_notReady();
+        self.clear_visibilityTimeoutMilliseconds();
+        self.clear_requiredCapabilities();
+        self.clear_requiredCapabilitiesTruncated();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PopResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PopResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubscribeRequest {
+    // message fields
+    pub availableCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub prefetch: u32,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl SubscribeRequest {
+    pub fn new() -> SubscribeRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string availableCapabilities = 1;
+
+    pub fn clear_availableCapabilities(&mut self) {
+        self.availableCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.availableCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_availableCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.availableCapabilities
+    }
+
+    // Take field
+    pub fn take_availableCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.availableCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_availableCapabilities(&self) -> &[::std::string::String] {
+        &self.availableCapabilities
+    }
+
+    // uint32 prefetch = 2;
+
+    pub fn clear_prefetch(&mut self) {
+        self.prefetch = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefetch(&mut self, v: u32) {
+        self.prefetch = v;
+    }
+
+    pub fn get_prefetch(&self) -> u32 {
+        self.prefetch
+    }
+
+    // string queueName = 3;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for SubscribeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.availableCapabilities)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.prefetch = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.availableCapabilities {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.prefetch != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.prefetch, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.availableCapabilities {
+            os.write_string(1, &v)?;
+        };
+        if self.prefetch != 0 {
+            os.write_uint32(2, self.prefetch)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(3, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubscribeRequest {
+        SubscribeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableCapabilities",
+                    |m: &SubscribeRequest| { &m.availableCapabilities },
+                    |m: &mut SubscribeRequest| { &mut m.availableCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "prefetch",
+                    |m: &SubscribeRequest| { &m.prefetch },
+                    |m: &mut SubscribeRequest| { &mut m.prefetch },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &SubscribeRequest| { &m.queueName },
+                    |m: &mut SubscribeRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SubscribeRequest>(
+                    "SubscribeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SubscribeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SubscribeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SubscribeRequest,
+        };
+        unsafe {
+            instance.get(SubscribeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SubscribeRequest {
+    fn clear(&mut self) {
+        self.clear_availableCapabilities();
+        self.clear_prefetch();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubscribeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubscribeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubscribeResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl SubscribeResponse {
+    pub fn new() -> SubscribeResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for SubscribeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SubscribeResponse {
+        SubscribeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<SubscribeResponse>(
+                    "SubscribeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SubscribeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<SubscribeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SubscribeResponse,
+        };
+        unsafe {
+            instance.get(SubscribeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SubscribeResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubscribeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubscribeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReserveRequest {
+    // message fields
+    pub availableCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub reservationMilliseconds: u64,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ReserveRequest {
+    pub fn new() -> ReserveRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string availableCapabilities = 1;
+
+    pub fn clear_availableCapabilities(&mut self) {
+        self.availableCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.availableCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_availableCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.availableCapabilities
+    }
+
+    // Take field
+    pub fn take_availableCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.availableCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_availableCapabilities(&self) -> &[::std::string::String] {
+        &self.availableCapabilities
+    }
+
+    // uint64 reservationMilliseconds = 2;
+
+    pub fn clear_reservationMilliseconds(&mut self) {
+        self.reservationMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reservationMilliseconds(&mut self, v: u64) {
+        self.reservationMilliseconds = v;
+    }
+
+    pub fn get_reservationMilliseconds(&self) -> u64 {
+        self.reservationMilliseconds
+    }
+
+    // string queueName = 3;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for ReserveRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.availableCapabilities)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.reservationMilliseconds = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.availableCapabilities {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.reservationMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.reservationMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.availableCapabilities {
+            os.write_string(1, &v)?;
+        };
+        if self.reservationMilliseconds != 0 {
+            os.write_uint64(2, self.reservationMilliseconds)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(3, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReserveRequest {
+        ReserveRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableCapabilities",
+                    |m: &ReserveRequest| { &m.availableCapabilities },
+                    |m: &mut ReserveRequest| { &mut m.availableCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "reservationMilliseconds",
+                    |m: &ReserveRequest| { &m.reservationMilliseconds },
+                    |m: &mut ReserveRequest| { &mut m.reservationMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &ReserveRequest| { &m.queueName },
+                    |m: &mut ReserveRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReserveRequest>(
+                    "ReserveRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ReserveRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ReserveRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ReserveRequest,
+        };
+        unsafe {
+            instance.get(ReserveRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ReserveRequest {
+    fn clear(&mut self) {
+        self.clear_availableCapabilities();
+        self.clear_reservationMilliseconds();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReserveRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReserveRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReserveResponse {
+    // message fields
+    pub hadResult: bool,
+    pub message: ::protobuf::SingularPtrField<FoundMessage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ReserveResponse {
+    pub fn new() -> ReserveResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool hadResult = 1;
+
+    pub fn clear_hadResult(&mut self) {
+        self.hadResult = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hadResult(&mut self, v: bool) {
+        self.hadResult = v;
+    }
+
+    pub fn get_hadResult(&self) -> bool {
+        self.hadResult
+    }
+
+    // .FoundMessage message = 2;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: FoundMessage) {
+        self.message = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut FoundMessage {
+        if self.message.is_none() {
+            self.message.set_default();
+        }
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> FoundMessage {
+        self.message.take().unwrap_or_else(|| FoundMessage::new())
+    }
+
+    pub fn get_message(&self) -> &FoundMessage {
+        self.message.as_ref().unwrap_or_else(|| FoundMessage::default_instance())
+    }
+}
+
+impl ::protobuf::Message for ReserveResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.hadResult = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.hadResult != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.hadResult != false {
+            os.write_bool(1, self.hadResult)?;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReserveResponse {
+        ReserveResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "hadResult",
+                    |m: &ReserveResponse| { &m.hadResult },
+                    |m: &mut ReserveResponse| { &mut m.hadResult },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FoundMessage>>(
+                    "message",
+                    |m: &ReserveResponse| { &m.message },
+                    |m: &mut ReserveResponse| { &mut m.message },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReserveResponse>(
+                    "ReserveResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ReserveResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ReserveResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ReserveResponse,
+        };
+        unsafe {
+            instance.get(ReserveResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ReserveResponse {
+    fn clear(&mut self) {
+        self.clear_hadResult();
+        self.clear_message();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReserveResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReserveResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmReservationRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ConfirmReservationRequest {
+    pub fn new() -> ConfirmReservationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // string queueName = 2;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for ConfirmReservationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(2, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmReservationRequest {
+        ConfirmReservationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &ConfirmReservationRequest| { &m.id },
+                    |m: &mut ConfirmReservationRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &ConfirmReservationRequest| { &m.queueName },
+                    |m: &mut ConfirmReservationRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ConfirmReservationRequest>(
+                    "ConfirmReservationRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ConfirmReservationRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ConfirmReservationRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ConfirmReservationRequest,
+        };
+        unsafe {
+            instance.get(ConfirmReservationRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ConfirmReservationRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmReservationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmReservationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmReservationResponse {
+    // message fields
+    pub confirmed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ConfirmReservationResponse {
+    pub fn new() -> ConfirmReservationResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool confirmed = 1;
+
+    pub fn clear_confirmed(&mut self) {
+        self.confirmed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_confirmed(&mut self, v: bool) {
+        self.confirmed = v;
+    }
+
+    pub fn get_confirmed(&self) -> bool {
+        self.confirmed
+    }
+}
+
+impl ::protobuf::Message for ConfirmReservationResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.confirmed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.confirmed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.confirmed != false {
+            os.write_bool(1, self.confirmed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmReservationResponse {
+        ConfirmReservationResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "confirmed",
+                    |m: &ConfirmReservationResponse| { &m.confirmed },
+                    |m: &mut ConfirmReservationResponse| { &mut m.confirmed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ConfirmReservationResponse>(
+                    "ConfirmReservationResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ConfirmReservationResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ConfirmReservationResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ConfirmReservationResponse,
+        };
+        unsafe {
+            instance.get(ConfirmReservationResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ConfirmReservationResponse {
+    fn clear(&mut self) {
+        self.clear_confirmed();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmReservationResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmReservationResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CancelPopRequest {
+    // message fields
+    pub sessionToken: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl CancelPopRequest {
+    pub fn new() -> CancelPopRequest {
+        ::std::default::Default::default()
+    }
+
+    // string sessionToken = 1;
+
+    pub fn clear_sessionToken(&mut self) {
+        self.sessionToken.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessionToken(&mut self, v: ::std::string::String) {
+        self.sessionToken = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sessionToken(&mut self) -> &mut ::std::string::String {
+        &mut self.sessionToken
+    }
+
+    // Take field
+    pub fn take_sessionToken(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sessionToken, ::std::string::String::new())
+    }
+
+    pub fn get_sessionToken(&self) -> &str {
+        &self.sessionToken
+    }
+}
+
+impl ::protobuf::Message for CancelPopRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sessionToken)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.sessionToken.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.sessionToken);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.sessionToken.is_empty() {
+            os.write_string(1, &self.sessionToken)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CancelPopRequest {
+        CancelPopRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "sessionToken",
+                    |m: &CancelPopRequest| { &m.sessionToken },
+                    |m: &mut CancelPopRequest| { &mut m.sessionToken },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CancelPopRequest>(
+                    "CancelPopRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CancelPopRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CancelPopRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CancelPopRequest,
+        };
+        unsafe {
+            instance.get(CancelPopRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CancelPopRequest {
+    fn clear(&mut self) {
+        self.clear_sessionToken();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CancelPopRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CancelPopRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CancelPopResponse {
+    // message fields
+    pub cancelled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl CancelPopResponse {
+    pub fn new() -> CancelPopResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool cancelled = 1;
+
+    pub fn clear_cancelled(&mut self) {
+        self.cancelled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancelled(&mut self, v: bool) {
+        self.cancelled = v;
+    }
+
+    pub fn get_cancelled(&self) -> bool {
+        self.cancelled
+    }
+}
+
+impl ::protobuf::Message for CancelPopResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cancelled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cancelled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.cancelled != false {
+            os.write_bool(1, self.cancelled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CancelPopResponse {
+        CancelPopResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "cancelled",
+                    |m: &CancelPopResponse| { &m.cancelled },
+                    |m: &mut CancelPopResponse| { &mut m.cancelled },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CancelPopResponse>(
+                    "CancelPopResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CancelPopResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CancelPopResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CancelPopResponse,
+        };
+        unsafe {
+            instance.get(CancelPopResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CancelPopResponse {
+    fn clear(&mut self) {
+        self.clear_cancelled();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CancelPopResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CancelPopResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CancelRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl CancelRequest {
+    pub fn new() -> CancelRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // string queueName = 2;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for CancelRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(2, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CancelRequest {
+        CancelRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &CancelRequest| { &m.id },
+                    |m: &mut CancelRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &CancelRequest| { &m.queueName },
+                    |m: &mut CancelRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CancelRequest>(
+                    "CancelRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CancelRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CancelRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CancelRequest,
+        };
+        unsafe {
+            instance.get(CancelRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CancelRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CancelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CancelRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CancelResponse {
+    // message fields
+    pub cancelled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl CancelResponse {
+    pub fn new() -> CancelResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool cancelled = 1;
+
+    pub fn clear_cancelled(&mut self) {
+        self.cancelled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancelled(&mut self, v: bool) {
+        self.cancelled = v;
+    }
+
+    pub fn get_cancelled(&self) -> bool {
+        self.cancelled
+    }
+}
+
+impl ::protobuf::Message for CancelResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cancelled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cancelled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.cancelled != false {
+            os.write_bool(1, self.cancelled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CancelResponse {
+        CancelResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "cancelled",
+                    |m: &CancelResponse| { &m.cancelled },
+                    |m: &mut CancelResponse| { &mut m.cancelled },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CancelResponse>(
+                    "CancelResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CancelResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CancelResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CancelResponse,
+        };
+        unsafe {
+            instance.get(CancelResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CancelResponse {
+    fn clear(&mut self) {
+        self.clear_cancelled();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CancelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CancelResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcknowledgeRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl AcknowledgeRequest {
+    pub fn new() -> AcknowledgeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl ::protobuf::Message for AcknowledgeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcknowledgeRequest {
+        AcknowledgeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &AcknowledgeRequest| { &m.id },
+                    |m: &mut AcknowledgeRequest| { &mut m.id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeRequest>(
+                    "AcknowledgeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AcknowledgeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AcknowledgeRequest,
+        };
+        unsafe {
+            instance.get(AcknowledgeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AcknowledgeRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcknowledgeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcknowledgeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcknowledgeResponse {
+    // message fields
+    pub alreadyCompleted: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl AcknowledgeResponse {
+    pub fn new() -> AcknowledgeResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool alreadyCompleted = 1;
+
+    pub fn clear_alreadyCompleted(&mut self) {
+        self.alreadyCompleted = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_alreadyCompleted(&mut self, v: bool) {
+        self.alreadyCompleted = v;
+    }
+
+    pub fn get_alreadyCompleted(&self) -> bool {
+        self.alreadyCompleted
+    }
+}
+
+impl ::protobuf::Message for AcknowledgeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.alreadyCompleted = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.alreadyCompleted != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.alreadyCompleted != false {
+            os.write_bool(1, self.alreadyCompleted)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcknowledgeResponse {
+        AcknowledgeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "alreadyCompleted",
+                    |m: &AcknowledgeResponse| { &m.alreadyCompleted },
+                    |m: &mut AcknowledgeResponse| { &mut m.alreadyCompleted },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeResponse>(
+                    "AcknowledgeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AcknowledgeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AcknowledgeResponse,
+        };
+        unsafe {
+            instance.get(AcknowledgeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AcknowledgeResponse {
+    fn clear(&mut self) {
+        self.clear_alreadyCompleted();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcknowledgeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcknowledgeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FailRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub changePriority: bool,
+    pub priority: Priority,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FailRequest {
+    pub fn new() -> FailRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // bool changePriority = 2;
+
+    pub fn clear_changePriority(&mut self) {
+        self.changePriority = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_changePriority(&mut self, v: bool) {
+        self.changePriority = v;
+    }
+
+    pub fn get_changePriority(&self) -> bool {
+        self.changePriority
+    }
+
+    // .Priority priority = 3;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = Priority::LOW;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: Priority) {
+        self.priority = v;
+    }
+
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+}
+
+impl ::protobuf::Message for FailRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.changePriority = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.priority, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.changePriority != false {
+            my_size += 2;
+        }
+        if self.priority != Priority::LOW {
+            my_size += ::protobuf::rt::enum_size(3, self.priority);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.changePriority != false {
+            os.write_bool(2, self.changePriority)?;
+        }
+        if self.priority != Priority::LOW {
+            os.write_enum(3, self.priority.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FailRequest {
+        FailRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &FailRequest| { &m.id },
+                    |m: &mut FailRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "changePriority",
+                    |m: &FailRequest| { &m.changePriority },
+                    |m: &mut FailRequest| { &mut m.changePriority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Priority>>(
+                    "priority",
+                    |m: &FailRequest| { &m.priority },
+                    |m: &mut FailRequest| { &mut m.priority },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FailRequest>(
+                    "FailRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FailRequest {
+        static mut instance: ::protobuf::lazy::Lazy<FailRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FailRequest,
+        };
+        unsafe {
+            instance.get(FailRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FailRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_changePriority();
+        self.clear_priority();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FailRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FailRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FailResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FailResponse {
+    pub fn new() -> FailResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for FailResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FailResponse {
+        FailResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<FailResponse>(
+                    "FailResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FailResponse {
+        static mut instance: ::protobuf::lazy::Lazy<FailResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FailResponse,
+        };
+        unsafe {
+            instance.get(FailResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FailResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FailResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FailResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeclineRequest {
+    // message fields
+    pub id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl DeclineRequest {
+    pub fn new() -> DeclineRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl ::protobuf::Message for DeclineRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeclineRequest {
+        DeclineRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &DeclineRequest| { &m.id },
+                    |m: &mut DeclineRequest| { &mut m.id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeclineRequest>(
+                    "DeclineRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeclineRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DeclineRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeclineRequest,
+        };
+        unsafe {
+            instance.get(DeclineRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeclineRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeclineRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeclineRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeclineResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl DeclineResponse {
+    pub fn new() -> DeclineResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for DeclineResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeclineResponse {
+        DeclineResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<DeclineResponse>(
+                    "DeclineResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeclineResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DeclineResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeclineResponse,
+        };
+        unsafe {
+            instance.get(DeclineResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeclineResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeclineResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeclineResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FailWithRetagRequest {
+    // message fields
+    pub id: ::std::string::String,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FailWithRetagRequest {
+    pub fn new() -> FailWithRetagRequest {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // repeated string requiredCapabilities = 2;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+}
+
+impl ::protobuf::Message for FailWithRetagRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FailWithRetagRequest {
+        FailWithRetagRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &FailWithRetagRequest| { &m.id },
+                    |m: &mut FailWithRetagRequest| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &FailWithRetagRequest| { &m.requiredCapabilities },
+                    |m: &mut FailWithRetagRequest| { &mut m.requiredCapabilities },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FailWithRetagRequest>(
+                    "FailWithRetagRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FailWithRetagRequest {
+        static mut instance: ::protobuf::lazy::Lazy<FailWithRetagRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FailWithRetagRequest,
+        };
+        unsafe {
+            instance.get(FailWithRetagRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FailWithRetagRequest {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_requiredCapabilities();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FailWithRetagRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FailWithRetagRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FailWithRetagResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FailWithRetagResponse {
+    pub fn new() -> FailWithRetagResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for FailWithRetagResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FailWithRetagResponse {
+        FailWithRetagResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<FailWithRetagResponse>(
+                    "FailWithRetagResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FailWithRetagResponse {
+        static mut instance: ::protobuf::lazy::Lazy<FailWithRetagResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FailWithRetagResponse,
+        };
+        unsafe {
+            instance.get(FailWithRetagResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FailWithRetagResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FailWithRetagResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FailWithRetagResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcknowledgeMatchingRequest {
+    // message fields
+    pub requiredCapability: ::std::string::String,
+    pub minimumAgeMilliseconds: u64,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl AcknowledgeMatchingRequest {
+    pub fn new() -> AcknowledgeMatchingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string requiredCapability = 1;
+
+    pub fn clear_requiredCapability(&mut self) {
+        self.requiredCapability.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapability(&mut self, v: ::std::string::String) {
+        self.requiredCapability = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requiredCapability(&mut self) -> &mut ::std::string::String {
+        &mut self.requiredCapability
+    }
+
+    // Take field
+    pub fn take_requiredCapability(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.requiredCapability, ::std::string::String::new())
+    }
+
+    pub fn get_requiredCapability(&self) -> &str {
+        &self.requiredCapability
+    }
+
+    // uint64 minimumAgeMilliseconds = 2;
+
+    pub fn clear_minimumAgeMilliseconds(&mut self) {
+        self.minimumAgeMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_minimumAgeMilliseconds(&mut self, v: u64) {
+        self.minimumAgeMilliseconds = v;
+    }
+
+    pub fn get_minimumAgeMilliseconds(&self) -> u64 {
+        self.minimumAgeMilliseconds
+    }
+
+    // string queueName = 3;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for AcknowledgeMatchingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.requiredCapability)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.minimumAgeMilliseconds = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.requiredCapability.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.requiredCapability);
+        }
+        if self.minimumAgeMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.minimumAgeMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.requiredCapability.is_empty() {
+            os.write_string(1, &self.requiredCapability)?;
+        }
+        if self.minimumAgeMilliseconds != 0 {
+            os.write_uint64(2, self.minimumAgeMilliseconds)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(3, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcknowledgeMatchingRequest {
+        AcknowledgeMatchingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapability",
+                    |m: &AcknowledgeMatchingRequest| { &m.requiredCapability },
+                    |m: &mut AcknowledgeMatchingRequest| { &mut m.requiredCapability },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "minimumAgeMilliseconds",
+                    |m: &AcknowledgeMatchingRequest| { &m.minimumAgeMilliseconds },
+                    |m: &mut AcknowledgeMatchingRequest| { &mut m.minimumAgeMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &AcknowledgeMatchingRequest| { &m.queueName },
+                    |m: &mut AcknowledgeMatchingRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeMatchingRequest>(
+                    "AcknowledgeMatchingRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AcknowledgeMatchingRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeMatchingRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AcknowledgeMatchingRequest,
+        };
+        unsafe {
+            instance.get(AcknowledgeMatchingRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AcknowledgeMatchingRequest {
+    fn clear(&mut self) {
+        self.clear_requiredCapability();
+        self.clear_minimumAgeMilliseconds();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcknowledgeMatchingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcknowledgeMatchingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcknowledgeMatchingResponse {
+    // message fields
+    pub count: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl AcknowledgeMatchingResponse {
+    pub fn new() -> AcknowledgeMatchingResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 count = 1;
+
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = v;
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl ::protobuf::Message for AcknowledgeMatchingResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.count != 0 {
+            os.write_uint64(1, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcknowledgeMatchingResponse {
+        AcknowledgeMatchingResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    |m: &AcknowledgeMatchingResponse| { &m.count },
+                    |m: &mut AcknowledgeMatchingResponse| { &mut m.count },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AcknowledgeMatchingResponse>(
+                    "AcknowledgeMatchingResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AcknowledgeMatchingResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AcknowledgeMatchingResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AcknowledgeMatchingResponse,
+        };
+        unsafe {
+            instance.get(AcknowledgeMatchingResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AcknowledgeMatchingResponse {
+    fn clear(&mut self) {
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcknowledgeMatchingResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcknowledgeMatchingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindByTagRequest {
+    // message fields
+    pub tags: ::protobuf::RepeatedField<::std::string::String>,
+    pub matchMode: TagMatchMode,
+    pub offset: u64,
+    pub limit: u64,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FindByTagRequest {
+    pub fn new() -> FindByTagRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tags = 1;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+
+    // .TagMatchMode matchMode = 2;
+
+    pub fn clear_matchMode(&mut self) {
+        self.matchMode = TagMatchMode::ALL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matchMode(&mut self, v: TagMatchMode) {
+        self.matchMode = v;
+    }
+
+    pub fn get_matchMode(&self) -> TagMatchMode {
+        self.matchMode
+    }
+
+    // uint64 offset = 3;
+
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = v;
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+
+    // uint64 limit = 4;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = v;
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    // string queueName = 5;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for FindByTagRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.matchMode, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.offset = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.limit = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.matchMode != TagMatchMode::ALL {
+            my_size += ::protobuf::rt::enum_size(2, self.matchMode);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tags {
+            os.write_string(1, &v)?;
+        };
+        if self.matchMode != TagMatchMode::ALL {
+            os.write_enum(2, self.matchMode.value())?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(3, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(4, self.limit)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(5, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FindByTagRequest {
+        FindByTagRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    |m: &FindByTagRequest| { &m.tags },
+                    |m: &mut FindByTagRequest| { &mut m.tags },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TagMatchMode>>(
+                    "matchMode",
+                    |m: &FindByTagRequest| { &m.matchMode },
+                    |m: &mut FindByTagRequest| { &mut m.matchMode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "offset",
+                    |m: &FindByTagRequest| { &m.offset },
+                    |m: &mut FindByTagRequest| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "limit",
+                    |m: &FindByTagRequest| { &m.limit },
+                    |m: &mut FindByTagRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &FindByTagRequest| { &m.queueName },
+                    |m: &mut FindByTagRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FindByTagRequest>(
+                    "FindByTagRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FindByTagRequest {
+        static mut instance: ::protobuf::lazy::Lazy<FindByTagRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FindByTagRequest,
+        };
+        unsafe {
+            instance.get(FindByTagRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FindByTagRequest {
+    fn clear(&mut self) {
+        self.clear_tags();
+        self.clear_matchMode();
+        self.clear_offset();
+        self.clear_limit();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindByTagRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindByTagRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FoundMessage {
+    // message fields
+    pub id: ::std::string::String,
+    pub message: ::std::vec::Vec<u8>,
+    pub priority: Priority,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub enqueuedBy: ::std::string::String,
+    pub requiredCapabilitiesTruncated: bool,
+    pub priorityLevel: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FoundMessage {
+    pub fn new() -> FoundMessage {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // bytes message = 2;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::vec::Vec<u8>) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.message, ::std::vec::Vec::new())
+    }
+
+    pub fn get_message(&self) -> &[u8] {
+        &self.message
+    }
+
+    // .Priority priority = 3;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = Priority::LOW;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: Priority) {
+        self.priority = v;
+    }
+
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+
+    // repeated string requiredCapabilities = 4;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+
+    // string enqueuedBy = 5;
+
+    pub fn clear_enqueuedBy(&mut self) {
+        self.enqueuedBy.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueuedBy(&mut self, v: ::std::string::String) {
+        self.enqueuedBy = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_enqueuedBy(&mut self) -> &mut ::std::string::String {
+        &mut self.enqueuedBy
+    }
+
+    // Take field
+    pub fn take_enqueuedBy(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.enqueuedBy, ::std::string::String::new())
+    }
+
+    pub fn get_enqueuedBy(&self) -> &str {
+        &self.enqueuedBy
+    }
+
+    // bool requiredCapabilitiesTruncated = 6;
+
+    pub fn clear_requiredCapabilitiesTruncated(&mut self) {
+        self.requiredCapabilitiesTruncated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilitiesTruncated(&mut self, v: bool) {
+        self.requiredCapabilitiesTruncated = v;
+    }
+
+    pub fn get_requiredCapabilitiesTruncated(&self) -> bool {
+        self.requiredCapabilitiesTruncated
+    }
+
+    // uint32 priorityLevel = 7;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
+}
+
+impl ::protobuf::Message for FoundMessage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.message)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.priority, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.enqueuedBy)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.requiredCapabilitiesTruncated = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.message);
+        }
+        if self.priority != Priority::LOW {
+            my_size += ::protobuf::rt::enum_size(3, self.priority);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if !self.enqueuedBy.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.enqueuedBy);
+        }
+        if self.requiredCapabilitiesTruncated != false {
+            my_size += 2;
+        }
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(2, &self.message)?;
+        }
+        if self.priority != Priority::LOW {
+            os.write_enum(3, self.priority.value())?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(4, &v)?;
+        };
+        if !self.enqueuedBy.is_empty() {
+            os.write_string(5, &self.enqueuedBy)?;
+        }
+        if self.requiredCapabilitiesTruncated != false {
+            os.write_bool(6, self.requiredCapabilitiesTruncated)?;
+        }
+        if self.priorityLevel != 0 {
+            os.write_uint32(7, self.priorityLevel)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FoundMessage {
+        FoundMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &FoundMessage| { &m.id },
+                    |m: &mut FoundMessage| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "message",
+                    |m: &FoundMessage| { &m.message },
+                    |m: &mut FoundMessage| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Priority>>(
+                    "priority",
+                    |m: &FoundMessage| { &m.priority },
+                    |m: &mut FoundMessage| { &mut m.priority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &FoundMessage| { &m.requiredCapabilities },
+                    |m: &mut FoundMessage| { &mut m.requiredCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "enqueuedBy",
+                    |m: &FoundMessage| { &m.enqueuedBy },
+                    |m: &mut FoundMessage| { &mut m.enqueuedBy },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "requiredCapabilitiesTruncated",
+                    |m: &FoundMessage| { &m.requiredCapabilitiesTruncated },
+                    |m: &mut FoundMessage| { &mut m.requiredCapabilitiesTruncated },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &FoundMessage| { &m.priorityLevel },
+                    |m: &mut FoundMessage| { &mut m.priorityLevel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FoundMessage>(
+                    "FoundMessage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FoundMessage {
+        static mut instance: ::protobuf::lazy::Lazy<FoundMessage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FoundMessage,
+        };
+        unsafe {
+            instance.get(FoundMessage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FoundMessage {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_message();
+        self.clear_priority();
+        self.clear_requiredCapabilities();
+        self.clear_enqueuedBy();
+        self.clear_requiredCapabilitiesTruncated();
+        self.clear_priorityLevel();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FoundMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FoundMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindByTagResponse {
+    // message fields
+    pub messages: ::protobuf::RepeatedField<FoundMessage>,
+    pub total: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl FindByTagResponse {
+    pub fn new() -> FindByTagResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .FoundMessage messages = 1;
+
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_messages(&mut self, v: ::protobuf::RepeatedField<FoundMessage>) {
+        self.messages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_messages(&mut self) -> &mut ::protobuf::RepeatedField<FoundMessage> {
+        &mut self.messages
+    }
+
+    // Take field
+    pub fn take_messages(&mut self) -> ::protobuf::RepeatedField<FoundMessage> {
+        ::std::mem::replace(&mut self.messages, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_messages(&self) -> &[FoundMessage] {
+        &self.messages
+    }
+
+    // uint64 total = 2;
+
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = v;
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total
+    }
+}
+
+impl ::protobuf::Message for FindByTagResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.messages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.messages)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.total = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.messages {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.total != 0 {
+            os.write_uint64(2, self.total)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FindByTagResponse {
+        FindByTagResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FoundMessage>>(
+                    "messages",
+                    |m: &FindByTagResponse| { &m.messages },
+                    |m: &mut FindByTagResponse| { &mut m.messages },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "total",
+                    |m: &FindByTagResponse| { &m.total },
+                    |m: &mut FindByTagResponse| { &mut m.total },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FindByTagResponse>(
+                    "FindByTagResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FindByTagResponse {
+        static mut instance: ::protobuf::lazy::Lazy<FindByTagResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FindByTagResponse,
+        };
+        unsafe {
+            instance.get(FindByTagResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FindByTagResponse {
+    fn clear(&mut self) {
+        self.clear_messages();
+        self.clear_total();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindByTagResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindByTagResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PeekRequest {
+    // message fields
+    pub availableCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub onlyPriority: bool,
+    pub priority: Priority,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PeekRequest {
+    pub fn new() -> PeekRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string availableCapabilities = 1;
+
+    pub fn clear_availableCapabilities(&mut self) {
+        self.availableCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_availableCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.availableCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_availableCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.availableCapabilities
+    }
+
+    // Take field
+    pub fn take_availableCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.availableCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_availableCapabilities(&self) -> &[::std::string::String] {
+        &self.availableCapabilities
+    }
+
+    // bool onlyPriority = 2;
+
+    pub fn clear_onlyPriority(&mut self) {
+        self.onlyPriority = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_onlyPriority(&mut self, v: bool) {
+        self.onlyPriority = v;
+    }
+
+    pub fn get_onlyPriority(&self) -> bool {
+        self.onlyPriority
+    }
+
+    // .Priority priority = 3;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = Priority::LOW;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: Priority) {
+        self.priority = v;
+    }
+
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+
+    // string queueName = 4;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for PeekRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.availableCapabilities)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.onlyPriority = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.priority, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.availableCapabilities {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.onlyPriority != false {
+            my_size += 2;
+        }
+        if self.priority != Priority::LOW {
+            my_size += ::protobuf::rt::enum_size(3, self.priority);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.availableCapabilities {
+            os.write_string(1, &v)?;
+        };
+        if self.onlyPriority != false {
+            os.write_bool(2, self.onlyPriority)?;
+        }
+        if self.priority != Priority::LOW {
+            os.write_enum(3, self.priority.value())?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(4, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PeekRequest {
+        PeekRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "availableCapabilities",
+                    |m: &PeekRequest| { &m.availableCapabilities },
+                    |m: &mut PeekRequest| { &mut m.availableCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "onlyPriority",
+                    |m: &PeekRequest| { &m.onlyPriority },
+                    |m: &mut PeekRequest| { &mut m.onlyPriority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Priority>>(
+                    "priority",
+                    |m: &PeekRequest| { &m.priority },
+                    |m: &mut PeekRequest| { &mut m.priority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &PeekRequest| { &m.queueName },
+                    |m: &mut PeekRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PeekRequest>(
+                    "PeekRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PeekRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PeekRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PeekRequest,
+        };
+        unsafe {
+            instance.get(PeekRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PeekRequest {
+    fn clear(&mut self) {
+        self.clear_availableCapabilities();
+        self.clear_onlyPriority();
+        self.clear_priority();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PeekRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PeekRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PeekResponse {
+    // message fields
+    pub hadResult: bool,
+    pub message: ::protobuf::SingularPtrField<FoundMessage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PeekResponse {
+    pub fn new() -> PeekResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool hadResult = 1;
+
+    pub fn clear_hadResult(&mut self) {
+        self.hadResult = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hadResult(&mut self, v: bool) {
+        self.hadResult = v;
+    }
+
+    pub fn get_hadResult(&self) -> bool {
+        self.hadResult
+    }
+
+    // .FoundMessage message = 2;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: FoundMessage) {
+        self.message = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut FoundMessage {
+        if self.message.is_none() {
+            self.message.set_default();
+        }
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> FoundMessage {
+        self.message.take().unwrap_or_else(|| FoundMessage::new())
+    }
+
+    pub fn get_message(&self) -> &FoundMessage {
+        self.message.as_ref().unwrap_or_else(|| FoundMessage::default_instance())
+    }
+}
+
+impl ::protobuf::Message for PeekResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.hadResult = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.hadResult != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.hadResult != false {
+            os.write_bool(1, self.hadResult)?;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PeekResponse {
+        PeekResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "hadResult",
+                    |m: &PeekResponse| { &m.hadResult },
+                    |m: &mut PeekResponse| { &mut m.hadResult },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FoundMessage>>(
+                    "message",
+                    |m: &PeekResponse| { &m.message },
+                    |m: &mut PeekResponse| { &mut m.message },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PeekResponse>(
+                    "PeekResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PeekResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PeekResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PeekResponse,
+        };
+        unsafe {
+            instance.get(PeekResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PeekResponse {
+    fn clear(&mut self) {
+        self.clear_hadResult();
+        self.clear_message();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PeekResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PeekResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListQueuedRequest {
+    // message fields
+    pub cursor: ::std::string::String,
+    pub limit: u64,
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ListQueuedRequest {
+    pub fn new() -> ListQueuedRequest {
+        ::std::default::Default::default()
+    }
+
+    // string cursor = 1;
+
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::string::String) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::string::String {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cursor, ::std::string::String::new())
+    }
+
+    pub fn get_cursor(&self) -> &str {
+        &self.cursor
+    }
+
+    // uint64 limit = 2;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = v;
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+
+    // string queueName = 3;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for ListQueuedRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cursor)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.limit = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.cursor);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.cursor.is_empty() {
+            os.write_string(1, &self.cursor)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(2, self.limit)?;
+        }
+        if !self.queueName.is_empty() {
+            os.write_string(3, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListQueuedRequest {
+        ListQueuedRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cursor",
+                    |m: &ListQueuedRequest| { &m.cursor },
+                    |m: &mut ListQueuedRequest| { &mut m.cursor },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "limit",
+                    |m: &ListQueuedRequest| { &m.limit },
+                    |m: &mut ListQueuedRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &ListQueuedRequest| { &m.queueName },
+                    |m: &mut ListQueuedRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListQueuedRequest>(
+                    "ListQueuedRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListQueuedRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ListQueuedRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListQueuedRequest,
+        };
+        unsafe {
+            instance.get(ListQueuedRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListQueuedRequest {
+    fn clear(&mut self) {
+        self.clear_cursor();
+        self.clear_limit();
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListQueuedRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListQueuedRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListQueuedResponse {
+    // message fields
+    pub messages: ::protobuf::RepeatedField<FoundMessage>,
+    pub nextCursor: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ListQueuedResponse {
+    pub fn new() -> ListQueuedResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .FoundMessage messages = 1;
+
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_messages(&mut self, v: ::protobuf::RepeatedField<FoundMessage>) {
+        self.messages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_messages(&mut self) -> &mut ::protobuf::RepeatedField<FoundMessage> {
+        &mut self.messages
+    }
+
+    // Take field
+    pub fn take_messages(&mut self) -> ::protobuf::RepeatedField<FoundMessage> {
+        ::std::mem::replace(&mut self.messages, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_messages(&self) -> &[FoundMessage] {
+        &self.messages
+    }
+
+    // string nextCursor = 2;
+
+    pub fn clear_nextCursor(&mut self) {
+        self.nextCursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nextCursor(&mut self, v: ::std::string::String) {
+        self.nextCursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nextCursor(&mut self) -> &mut ::std::string::String {
+        &mut self.nextCursor
+    }
+
+    // Take field
+    pub fn take_nextCursor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nextCursor, ::std::string::String::new())
+    }
+
+    pub fn get_nextCursor(&self) -> &str {
+        &self.nextCursor
+    }
+}
+
+impl ::protobuf::Message for ListQueuedResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.messages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.messages)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nextCursor)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.nextCursor.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nextCursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.messages {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.nextCursor.is_empty() {
+            os.write_string(2, &self.nextCursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListQueuedResponse {
+        ListQueuedResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FoundMessage>>(
+                    "messages",
+                    |m: &ListQueuedResponse| { &m.messages },
+                    |m: &mut ListQueuedResponse| { &mut m.messages },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "nextCursor",
+                    |m: &ListQueuedResponse| { &m.nextCursor },
+                    |m: &mut ListQueuedResponse| { &mut m.nextCursor },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListQueuedResponse>(
+                    "ListQueuedResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListQueuedResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ListQueuedResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListQueuedResponse,
+        };
+        unsafe {
+            instance.get(ListQueuedResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListQueuedResponse {
+    fn clear(&mut self) {
+        self.clear_messages();
+        self.clear_nextCursor();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListQueuedResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListQueuedResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListProcessingRequest {
+    // message fields
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ListProcessingRequest {
+    pub fn new() -> ListProcessingRequest {
+        ::std::default::Default::default()
+    }
+
+    // string queueName = 1;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for ListProcessingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.queueName.is_empty() {
+            os.write_string(1, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListProcessingRequest {
+        ListProcessingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &ListProcessingRequest| { &m.queueName },
+                    |m: &mut ListProcessingRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListProcessingRequest>(
+                    "ListProcessingRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListProcessingRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ListProcessingRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListProcessingRequest,
+        };
+        unsafe {
+            instance.get(ListProcessingRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListProcessingRequest {
+    fn clear(&mut self) {
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListProcessingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListProcessingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProcessingMessage {
+    // message fields
+    pub id: ::std::string::String,
+    pub priority: Priority,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub ageMilliseconds: u64,
+    pub worker: ::std::string::String,
+    pub enqueuedBy: ::std::string::String,
+    pub requiredCapabilitiesTruncated: bool,
+    pub priorityLevel: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ProcessingMessage {
+    pub fn new() -> ProcessingMessage {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // .Priority priority = 2;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = Priority::LOW;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: Priority) {
+        self.priority = v;
+    }
+
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+
+    // repeated string requiredCapabilities = 3;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+
+    // uint64 ageMilliseconds = 4;
+
+    pub fn clear_ageMilliseconds(&mut self) {
+        self.ageMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ageMilliseconds(&mut self, v: u64) {
+        self.ageMilliseconds = v;
+    }
+
+    pub fn get_ageMilliseconds(&self) -> u64 {
+        self.ageMilliseconds
+    }
+
+    // string worker = 5;
+
+    pub fn clear_worker(&mut self) {
+        self.worker.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker(&mut self, v: ::std::string::String) {
+        self.worker = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
+        &mut self.worker
+    }
+
+    // Take field
+    pub fn take_worker(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker, ::std::string::String::new())
+    }
+
+    pub fn get_worker(&self) -> &str {
+        &self.worker
+    }
+
+    // string enqueuedBy = 6;
+
+    pub fn clear_enqueuedBy(&mut self) {
+        self.enqueuedBy.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueuedBy(&mut self, v: ::std::string::String) {
+        self.enqueuedBy = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_enqueuedBy(&mut self) -> &mut ::std::string::String {
+        &mut self.enqueuedBy
+    }
+
+    // Take field
+    pub fn take_enqueuedBy(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.enqueuedBy, ::std::string::String::new())
+    }
+
+    pub fn get_enqueuedBy(&self) -> &str {
+        &self.enqueuedBy
+    }
+
+    // bool requiredCapabilitiesTruncated = 7;
+
+    pub fn clear_requiredCapabilitiesTruncated(&mut self) {
+        self.requiredCapabilitiesTruncated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilitiesTruncated(&mut self, v: bool) {
+        self.requiredCapabilitiesTruncated = v;
+    }
+
+    pub fn get_requiredCapabilitiesTruncated(&self) -> bool {
+        self.requiredCapabilitiesTruncated
+    }
+
+    // uint32 priorityLevel = 8;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
+}
+
+impl ::protobuf::Message for ProcessingMessage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.priority, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ageMilliseconds = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.enqueuedBy)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.requiredCapabilitiesTruncated = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.priority != Priority::LOW {
+            my_size += ::protobuf::rt::enum_size(2, self.priority);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.ageMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ageMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.worker.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.worker);
+        }
+        if !self.enqueuedBy.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.enqueuedBy);
+        }
+        if self.requiredCapabilitiesTruncated != false {
+            my_size += 2;
+        }
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.priority != Priority::LOW {
+            os.write_enum(2, self.priority.value())?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(3, &v)?;
+        };
+        if self.ageMilliseconds != 0 {
+            os.write_uint64(4, self.ageMilliseconds)?;
+        }
+        if !self.worker.is_empty() {
+            os.write_string(5, &self.worker)?;
+        }
+        if !self.enqueuedBy.is_empty() {
+            os.write_string(6, &self.enqueuedBy)?;
+        }
+        if self.requiredCapabilitiesTruncated != false {
+            os.write_bool(7, self.requiredCapabilitiesTruncated)?;
+        }
+        if self.priorityLevel != 0 {
+            os.write_uint32(8, self.priorityLevel)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ProcessingMessage {
+        ProcessingMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &ProcessingMessage| { &m.id },
+                    |m: &mut ProcessingMessage| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Priority>>(
+                    "priority",
+                    |m: &ProcessingMessage| { &m.priority },
+                    |m: &mut ProcessingMessage| { &mut m.priority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &ProcessingMessage| { &m.requiredCapabilities },
+                    |m: &mut ProcessingMessage| { &mut m.requiredCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ageMilliseconds",
+                    |m: &ProcessingMessage| { &m.ageMilliseconds },
+                    |m: &mut ProcessingMessage| { &mut m.ageMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker",
+                    |m: &ProcessingMessage| { &m.worker },
+                    |m: &mut ProcessingMessage| { &mut m.worker },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "enqueuedBy",
+                    |m: &ProcessingMessage| { &m.enqueuedBy },
+                    |m: &mut ProcessingMessage| { &mut m.enqueuedBy },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "requiredCapabilitiesTruncated",
+                    |m: &ProcessingMessage| { &m.requiredCapabilitiesTruncated },
+                    |m: &mut ProcessingMessage| { &mut m.requiredCapabilitiesTruncated },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &ProcessingMessage| { &m.priorityLevel },
+                    |m: &mut ProcessingMessage| { &mut m.priorityLevel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProcessingMessage>(
+                    "ProcessingMessage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ProcessingMessage {
+        static mut instance: ::protobuf::lazy::Lazy<ProcessingMessage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProcessingMessage,
+        };
+        unsafe {
+            instance.get(ProcessingMessage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProcessingMessage {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_priority();
+        self.clear_requiredCapabilities();
+        self.clear_ageMilliseconds();
+        self.clear_worker();
+        self.clear_enqueuedBy();
+        self.clear_requiredCapabilitiesTruncated();
+        self.clear_priorityLevel();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProcessingMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProcessingMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListProcessingResponse {
+    // message fields
+    pub messages: ::protobuf::RepeatedField<ProcessingMessage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ListProcessingResponse {
+    pub fn new() -> ListProcessingResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ProcessingMessage messages = 1;
+
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_messages(&mut self, v: ::protobuf::RepeatedField<ProcessingMessage>) {
+        self.messages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_messages(&mut self) -> &mut ::protobuf::RepeatedField<ProcessingMessage> {
+        &mut self.messages
+    }
+
+    // Take field
+    pub fn take_messages(&mut self) -> ::protobuf::RepeatedField<ProcessingMessage> {
+        ::std::mem::replace(&mut self.messages, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_messages(&self) -> &[ProcessingMessage] {
+        &self.messages
+    }
+}
+
+impl ::protobuf::Message for ListProcessingResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.messages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.messages)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.messages {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListProcessingResponse {
+        ListProcessingResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ProcessingMessage>>(
+                    "messages",
+                    |m: &ListProcessingResponse| { &m.messages },
+                    |m: &mut ListProcessingResponse| { &mut m.messages },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ListProcessingResponse>(
+                    "ListProcessingResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListProcessingResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ListProcessingResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListProcessingResponse,
+        };
+        unsafe {
+            instance.get(ListProcessingResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListProcessingResponse {
+    fn clear(&mut self) {
+        self.clear_messages();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListProcessingResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListProcessingResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetCapabilityPausedRequest {
+    // message fields
+    pub capability: ::std::string::String,
+    pub paused: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl SetCapabilityPausedRequest {
+    pub fn new() -> SetCapabilityPausedRequest {
+        ::std::default::Default::default()
+    }
+
+    // string capability = 1;
+
+    pub fn clear_capability(&mut self) {
+        self.capability.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capability(&mut self, v: ::std::string::String) {
+        self.capability = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_capability(&mut self) -> &mut ::std::string::String {
+        &mut self.capability
+    }
+
+    // Take field
+    pub fn take_capability(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.capability, ::std::string::String::new())
+    }
+
+    pub fn get_capability(&self) -> &str {
+        &self.capability
+    }
+
+    // bool paused = 2;
+
+    pub fn clear_paused(&mut self) {
+        self.paused = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_paused(&mut self, v: bool) {
+        self.paused = v;
+    }
+
+    pub fn get_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+impl ::protobuf::Message for SetCapabilityPausedRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.capability)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.paused = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.capability.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.capability);
+        }
+        if self.paused != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.capability.is_empty() {
+            os.write_string(1, &self.capability)?;
+        }
+        if self.paused != false {
+            os.write_bool(2, self.paused)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetCapabilityPausedRequest {
+        SetCapabilityPausedRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "capability",
+                    |m: &SetCapabilityPausedRequest| { &m.capability },
+                    |m: &mut SetCapabilityPausedRequest| { &mut m.capability },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "paused",
+                    |m: &SetCapabilityPausedRequest| { &m.paused },
+                    |m: &mut SetCapabilityPausedRequest| { &mut m.paused },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetCapabilityPausedRequest>(
+                    "SetCapabilityPausedRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SetCapabilityPausedRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SetCapabilityPausedRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SetCapabilityPausedRequest,
+        };
+        unsafe {
+            instance.get(SetCapabilityPausedRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SetCapabilityPausedRequest {
+    fn clear(&mut self) {
+        self.clear_capability();
+        self.clear_paused();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetCapabilityPausedRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetCapabilityPausedRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetCapabilityPausedResponse {
+    // message fields
+    pub pausedCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl SetCapabilityPausedResponse {
+    pub fn new() -> SetCapabilityPausedResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated string pausedCapabilities = 1;
+
+    pub fn clear_pausedCapabilities(&mut self) {
+        self.pausedCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pausedCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.pausedCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pausedCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.pausedCapabilities
+    }
+
+    // Take field
+    pub fn take_pausedCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.pausedCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_pausedCapabilities(&self) -> &[::std::string::String] {
+        &self.pausedCapabilities
+    }
+}
+
+impl ::protobuf::Message for SetCapabilityPausedResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.pausedCapabilities)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.pausedCapabilities {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.pausedCapabilities {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetCapabilityPausedResponse {
+        SetCapabilityPausedResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "pausedCapabilities",
+                    |m: &SetCapabilityPausedResponse| { &m.pausedCapabilities },
+                    |m: &mut SetCapabilityPausedResponse| { &mut m.pausedCapabilities },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetCapabilityPausedResponse>(
+                    "SetCapabilityPausedResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SetCapabilityPausedResponse {
+        static mut instance: ::protobuf::lazy::Lazy<SetCapabilityPausedResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SetCapabilityPausedResponse,
+        };
+        unsafe {
+            instance.get(SetCapabilityPausedResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SetCapabilityPausedResponse {
+    fn clear(&mut self) {
+        self.clear_pausedCapabilities();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetCapabilityPausedResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetCapabilityPausedResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HealthRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl HealthRequest {
+    pub fn new() -> HealthRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for HealthRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HealthRequest {
+        HealthRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<HealthRequest>(
+                    "HealthRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static HealthRequest {
+        static mut instance: ::protobuf::lazy::Lazy<HealthRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const HealthRequest,
+        };
+        unsafe {
+            instance.get(HealthRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for HealthRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HealthRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LevelScanDepth {
+    // message fields
+    pub priorityLevel: u32,
+    pub scanDepth: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl LevelScanDepth {
+    pub fn new() -> LevelScanDepth {
+        ::std::default::Default::default()
+    }
+
+    // uint32 priorityLevel = 1;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
+
+    // double scanDepth = 2;
+
+    pub fn clear_scanDepth(&mut self) {
+        self.scanDepth = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scanDepth(&mut self, v: f64) {
+        self.scanDepth = v;
+    }
+
+    pub fn get_scanDepth(&self) -> f64 {
+        self.scanDepth
+    }
+}
+
+impl ::protobuf::Message for LevelScanDepth {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.scanDepth = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.scanDepth != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.priorityLevel != 0 {
+            os.write_uint32(1, self.priorityLevel)?;
+        }
+        if self.scanDepth != 0. {
+            os.write_double(2, self.scanDepth)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LevelScanDepth {
+        LevelScanDepth::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &LevelScanDepth| { &m.priorityLevel },
+                    |m: &mut LevelScanDepth| { &mut m.priorityLevel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "scanDepth",
+                    |m: &LevelScanDepth| { &m.scanDepth },
+                    |m: &mut LevelScanDepth| { &mut m.scanDepth },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LevelScanDepth>(
+                    "LevelScanDepth",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static LevelScanDepth {
+        static mut instance: ::protobuf::lazy::Lazy<LevelScanDepth> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LevelScanDepth,
+        };
+        unsafe {
+            instance.get(LevelScanDepth::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for LevelScanDepth {
+    fn clear(&mut self) {
+        self.clear_priorityLevel();
+        self.clear_scanDepth();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LevelScanDepth {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LevelScanDepth {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HealthResponse {
+    // message fields
+    pub highPriorityScanDepth: f64,
+    pub lowPriorityScanDepth: f64,
+    pub estimatedDrainSeconds: f64,
+    pub ready: bool,
+    pub scanDepthByLevel: ::protobuf::RepeatedField<LevelScanDepth>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl HealthResponse {
+    pub fn new() -> HealthResponse {
+        ::std::default::Default::default()
+    }
+
+    // double highPriorityScanDepth = 1;
+
+    pub fn clear_highPriorityScanDepth(&mut self) {
+        self.highPriorityScanDepth = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highPriorityScanDepth(&mut self, v: f64) {
+        self.highPriorityScanDepth = v;
+    }
+
+    pub fn get_highPriorityScanDepth(&self) -> f64 {
+        self.highPriorityScanDepth
+    }
+
+    // double lowPriorityScanDepth = 2;
+
+    pub fn clear_lowPriorityScanDepth(&mut self) {
+        self.lowPriorityScanDepth = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lowPriorityScanDepth(&mut self, v: f64) {
+        self.lowPriorityScanDepth = v;
+    }
+
+    pub fn get_lowPriorityScanDepth(&self) -> f64 {
+        self.lowPriorityScanDepth
+    }
+
+    // double estimatedDrainSeconds = 3;
+
+    pub fn clear_estimatedDrainSeconds(&mut self) {
+        self.estimatedDrainSeconds = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_estimatedDrainSeconds(&mut self, v: f64) {
+        self.estimatedDrainSeconds = v;
+    }
+
+    pub fn get_estimatedDrainSeconds(&self) -> f64 {
+        self.estimatedDrainSeconds
+    }
+
+    // bool ready = 4;
+
+    pub fn clear_ready(&mut self) {
+        self.ready = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: bool) {
+        self.ready = v;
+    }
+
+    pub fn get_ready(&self) -> bool {
+        self.ready
+    }
+
+    // repeated .LevelScanDepth scanDepthByLevel = 5;
+
+    pub fn clear_scanDepthByLevel(&mut self) {
+        self.scanDepthByLevel.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scanDepthByLevel(&mut self, v: ::protobuf::RepeatedField<LevelScanDepth>) {
+        self.scanDepthByLevel = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scanDepthByLevel(&mut self) -> &mut ::protobuf::RepeatedField<LevelScanDepth> {
+        &mut self.scanDepthByLevel
+    }
+
+    // Take field
+    pub fn take_scanDepthByLevel(&mut self) -> ::protobuf::RepeatedField<LevelScanDepth> {
+        ::std::mem::replace(&mut self.scanDepthByLevel, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scanDepthByLevel(&self) -> &[LevelScanDepth] {
+        &self.scanDepthByLevel
+    }
+}
+
+impl ::protobuf::Message for HealthResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scanDepthByLevel {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.highPriorityScanDepth = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.lowPriorityScanDepth = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.estimatedDrainSeconds = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ready = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.scanDepthByLevel)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.highPriorityScanDepth != 0. {
+            my_size += 9;
+        }
+        if self.lowPriorityScanDepth != 0. {
+            my_size += 9;
+        }
+        if self.estimatedDrainSeconds != 0. {
+            my_size += 9;
+        }
+        if self.ready != false {
+            my_size += 2;
+        }
+        for value in &self.scanDepthByLevel {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.highPriorityScanDepth != 0. {
+            os.write_double(1, self.highPriorityScanDepth)?;
+        }
+        if self.lowPriorityScanDepth != 0. {
+            os.write_double(2, self.lowPriorityScanDepth)?;
+        }
+        if self.estimatedDrainSeconds != 0. {
+            os.write_double(3, self.estimatedDrainSeconds)?;
+        }
+        if self.ready != false {
+            os.write_bool(4, self.ready)?;
+        }
+        for v in &self.scanDepthByLevel {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HealthResponse {
+        HealthResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "highPriorityScanDepth",
+                    |m: &HealthResponse| { &m.highPriorityScanDepth },
+                    |m: &mut HealthResponse| { &mut m.highPriorityScanDepth },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "lowPriorityScanDepth",
+                    |m: &HealthResponse| { &m.lowPriorityScanDepth },
+                    |m: &mut HealthResponse| { &mut m.lowPriorityScanDepth },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "estimatedDrainSeconds",
+                    |m: &HealthResponse| { &m.estimatedDrainSeconds },
+                    |m: &mut HealthResponse| { &mut m.estimatedDrainSeconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ready",
+                    |m: &HealthResponse| { &m.ready },
+                    |m: &mut HealthResponse| { &mut m.ready },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LevelScanDepth>>(
+                    "scanDepthByLevel",
+                    |m: &HealthResponse| { &m.scanDepthByLevel },
+                    |m: &mut HealthResponse| { &mut m.scanDepthByLevel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<HealthResponse>(
+                    "HealthResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static HealthResponse {
+        static mut instance: ::protobuf::lazy::Lazy<HealthResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const HealthResponse,
+        };
+        unsafe {
+            instance.get(HealthResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for HealthResponse {
+    fn clear(&mut self) {
+        self.clear_highPriorityScanDepth();
+        self.clear_lowPriorityScanDepth();
+        self.clear_estimatedDrainSeconds();
+        self.clear_ready();
+        self.clear_scanDepthByLevel();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HealthResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsRequest {
+    // message fields
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl StatsRequest {
+    pub fn new() -> StatsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string queueName = 1;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for StatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.queueName.is_empty() {
+            os.write_string(1, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsRequest {
+        StatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &StatsRequest| { &m.queueName },
+                    |m: &mut StatsRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsRequest>(
+                    "StatsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<StatsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsRequest,
+        };
+        unsafe {
+            instance.get(StatsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsRequest {
+    fn clear(&mut self) {
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PendingCount {
+    // message fields
+    pub priorityLevel: u32,
+    pub count: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PendingCount {
+    pub fn new() -> PendingCount {
+        ::std::default::Default::default()
+    }
+
+    // uint32 priorityLevel = 1;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
+
+    // uint64 count = 2;
+
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = v;
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl ::protobuf::Message for PendingCount {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.priorityLevel != 0 {
+            os.write_uint32(1, self.priorityLevel)?;
+        }
+        if self.count != 0 {
+            os.write_uint64(2, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PendingCount {
+        PendingCount::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &PendingCount| { &m.priorityLevel },
+                    |m: &mut PendingCount| { &mut m.priorityLevel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    |m: &PendingCount| { &m.count },
+                    |m: &mut PendingCount| { &mut m.count },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PendingCount>(
+                    "PendingCount",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PendingCount {
+        static mut instance: ::protobuf::lazy::Lazy<PendingCount> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PendingCount,
+        };
+        unsafe {
+            instance.get(PendingCount::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PendingCount {
+    fn clear(&mut self) {
+        self.clear_priorityLevel();
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PendingCount {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PendingCount {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StatsResponse {
+    // message fields
+    pub pending: ::protobuf::RepeatedField<PendingCount>,
+    pub queued: u64,
+    pub processing: u64,
+    pub highPriority: u64,
+    pub lowPriority: u64,
+    pub enqueued: u64,
+    pub acknowledged: u64,
+    pub estimatedDrainSeconds: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl StatsResponse {
+    pub fn new() -> StatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .PendingCount pending = 1;
+
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending(&mut self, v: ::protobuf::RepeatedField<PendingCount>) {
+        self.pending = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pending(&mut self) -> &mut ::protobuf::RepeatedField<PendingCount> {
+        &mut self.pending
+    }
+
+    // Take field
+    pub fn take_pending(&mut self) -> ::protobuf::RepeatedField<PendingCount> {
+        ::std::mem::replace(&mut self.pending, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_pending(&self) -> &[PendingCount] {
+        &self.pending
+    }
+
+    // uint64 queued = 2;
+
+    pub fn clear_queued(&mut self) {
+        self.queued = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queued(&mut self, v: u64) {
+        self.queued = v;
+    }
+
+    pub fn get_queued(&self) -> u64 {
+        self.queued
+    }
+
+    // uint64 processing = 3;
+
+    pub fn clear_processing(&mut self) {
+        self.processing = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_processing(&mut self, v: u64) {
+        self.processing = v;
+    }
+
+    pub fn get_processing(&self) -> u64 {
+        self.processing
+    }
+
+    // uint64 highPriority = 4;
+
+    pub fn clear_highPriority(&mut self) {
+        self.highPriority = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highPriority(&mut self, v: u64) {
+        self.highPriority = v;
+    }
+
+    pub fn get_highPriority(&self) -> u64 {
+        self.highPriority
+    }
+
+    // uint64 lowPriority = 5;
+
+    pub fn clear_lowPriority(&mut self) {
+        self.lowPriority = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lowPriority(&mut self, v: u64) {
+        self.lowPriority = v;
+    }
+
+    pub fn get_lowPriority(&self) -> u64 {
+        self.lowPriority
+    }
+
+    // uint64 enqueued = 6;
+
+    pub fn clear_enqueued(&mut self) {
+        self.enqueued = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueued(&mut self, v: u64) {
+        self.enqueued = v;
+    }
+
+    pub fn get_enqueued(&self) -> u64 {
+        self.enqueued
+    }
+
+    // uint64 acknowledged = 7;
+
+    pub fn clear_acknowledged(&mut self) {
+        self.acknowledged = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acknowledged(&mut self, v: u64) {
+        self.acknowledged = v;
+    }
+
+    pub fn get_acknowledged(&self) -> u64 {
+        self.acknowledged
+    }
+
+    // double estimatedDrainSeconds = 8;
+
+    pub fn clear_estimatedDrainSeconds(&mut self) {
+        self.estimatedDrainSeconds = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_estimatedDrainSeconds(&mut self, v: f64) {
+        self.estimatedDrainSeconds = v;
+    }
+
+    pub fn get_estimatedDrainSeconds(&self) -> f64 {
+        self.estimatedDrainSeconds
+    }
+}
+
+impl ::protobuf::Message for StatsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pending {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.pending)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.queued = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.processing = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.highPriority = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lowPriority = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.enqueued = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.acknowledged = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.estimatedDrainSeconds = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.pending {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.queued != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.queued, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.processing != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.processing, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.highPriority != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.highPriority, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lowPriority != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.lowPriority, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.enqueued != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.enqueued, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.acknowledged != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.acknowledged, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.estimatedDrainSeconds != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.pending {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.queued != 0 {
+            os.write_uint64(2, self.queued)?;
+        }
+        if self.processing != 0 {
+            os.write_uint64(3, self.processing)?;
+        }
+        if self.highPriority != 0 {
+            os.write_uint64(4, self.highPriority)?;
+        }
+        if self.lowPriority != 0 {
+            os.write_uint64(5, self.lowPriority)?;
+        }
+        if self.enqueued != 0 {
+            os.write_uint64(6, self.enqueued)?;
+        }
+        if self.acknowledged != 0 {
+            os.write_uint64(7, self.acknowledged)?;
+        }
+        if self.estimatedDrainSeconds != 0. {
+            os.write_double(8, self.estimatedDrainSeconds)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsResponse {
+        StatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PendingCount>>(
+                    "pending",
+                    |m: &StatsResponse| { &m.pending },
+                    |m: &mut StatsResponse| { &mut m.pending },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "queued",
+                    |m: &StatsResponse| { &m.queued },
+                    |m: &mut StatsResponse| { &mut m.queued },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "processing",
+                    |m: &StatsResponse| { &m.processing },
+                    |m: &mut StatsResponse| { &mut m.processing },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "highPriority",
+                    |m: &StatsResponse| { &m.highPriority },
+                    |m: &mut StatsResponse| { &mut m.highPriority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lowPriority",
+                    |m: &StatsResponse| { &m.lowPriority },
+                    |m: &mut StatsResponse| { &mut m.lowPriority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "enqueued",
+                    |m: &StatsResponse| { &m.enqueued },
+                    |m: &mut StatsResponse| { &mut m.enqueued },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "acknowledged",
+                    |m: &StatsResponse| { &m.acknowledged },
+                    |m: &mut StatsResponse| { &mut m.acknowledged },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "estimatedDrainSeconds",
+                    |m: &StatsResponse| { &m.estimatedDrainSeconds },
+                    |m: &mut StatsResponse| { &mut m.estimatedDrainSeconds },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsResponse>(
+                    "StatsResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StatsResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsResponse,
+        };
+        unsafe {
+            instance.get(StatsResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsResponse {
+    fn clear(&mut self) {
+        self.clear_pending();
+        self.clear_queued();
+        self.clear_processing();
+        self.clear_highPriority();
+        self.clear_lowPriority();
+        self.clear_enqueued();
+        self.clear_acknowledged();
+        self.clear_estimatedDrainSeconds();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PurgeRequest {
+    // message fields
+    pub queueName: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PurgeRequest {
+    pub fn new() -> PurgeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string queueName = 1;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+}
+
+impl ::protobuf::Message for PurgeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.queueName);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.queueName.is_empty() {
+            os.write_string(1, &self.queueName)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PurgeRequest {
+        PurgeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &PurgeRequest| { &m.queueName },
+                    |m: &mut PurgeRequest| { &mut m.queueName },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PurgeRequest>(
+                    "PurgeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PurgeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PurgeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PurgeRequest,
+        };
+        unsafe {
+            instance.get(PurgeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PurgeRequest {
+    fn clear(&mut self) {
+        self.clear_queueName();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PurgeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PurgeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PurgeResponse {
+    // message fields
+    pub removed: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl PurgeResponse {
+    pub fn new() -> PurgeResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 removed = 1;
+
+    pub fn clear_removed(&mut self) {
+        self.removed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removed(&mut self, v: u64) {
+        self.removed = v;
+    }
+
+    pub fn get_removed(&self) -> u64 {
+        self.removed
+    }
+}
+
+impl ::protobuf::Message for PurgeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.removed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.removed != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.removed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.removed != 0 {
+            os.write_uint64(1, self.removed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PurgeResponse {
+        PurgeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "removed",
+                    |m: &PurgeResponse| { &m.removed },
+                    |m: &mut PurgeResponse| { &mut m.removed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PurgeResponse>(
+                    "PurgeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PurgeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PurgeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PurgeResponse,
+        };
+        unsafe {
+            instance.get(PurgeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PurgeResponse {
+    fn clear(&mut self) {
+        self.clear_removed();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PurgeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PurgeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportDeadLettersRequest {
+    // message fields
+    pub queueName: ::std::string::String,
+    pub clear: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ExportDeadLettersRequest {
+    pub fn new() -> ExportDeadLettersRequest {
+        ::std::default::Default::default()
+    }
+
+    // string queueName = 1;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+
+    // bool clear = 2;
+
+    pub fn clear_clear(&mut self) {
+        self.clear = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clear(&mut self, v: bool) {
+        self.clear = v;
+    }
+
+    pub fn get_clear(&self) -> bool {
+        self.clear
+    }
+}
+
+impl ::protobuf::Message for ExportDeadLettersRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.clear = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.queueName);
+        }
+        if self.clear != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.queueName.is_empty() {
+            os.write_string(1, &self.queueName)?;
+        }
+        if self.clear != false {
+            os.write_bool(2, self.clear)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportDeadLettersRequest {
+        ExportDeadLettersRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &ExportDeadLettersRequest| { &m.queueName },
+                    |m: &mut ExportDeadLettersRequest| { &mut m.queueName },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "clear",
+                    |m: &ExportDeadLettersRequest| { &m.clear },
+                    |m: &mut ExportDeadLettersRequest| { &mut m.clear },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ExportDeadLettersRequest>(
+                    "ExportDeadLettersRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ExportDeadLettersRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ExportDeadLettersRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ExportDeadLettersRequest,
+        };
+        unsafe {
+            instance.get(ExportDeadLettersRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExportDeadLettersRequest {
+    fn clear(&mut self) {
+        self.clear_queueName();
+        self.clear_clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportDeadLettersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportDeadLettersRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportDeadLettersResponse {
+    // message fields
+    pub exported: u64,
+    pub json: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ExportDeadLettersResponse {
+    pub fn new() -> ExportDeadLettersResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 exported = 1;
+
+    pub fn clear_exported(&mut self) {
+        self.exported = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exported(&mut self, v: u64) {
+        self.exported = v;
+    }
+
+    pub fn get_exported(&self) -> u64 {
+        self.exported
+    }
+
+    // string json = 2;
+
+    pub fn clear_json(&mut self) {
+        self.json.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_json(&mut self, v: ::std::string::String) {
+        self.json = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_json(&mut self) -> &mut ::std::string::String {
+        &mut self.json
+    }
+
+    // Take field
+    pub fn take_json(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.json, ::std::string::String::new())
+    }
+
+    pub fn get_json(&self) -> &str {
+        &self.json
+    }
+}
+
+impl ::protobuf::Message for ExportDeadLettersResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.exported = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.json)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.exported != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.exported, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.json.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.json);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.exported != 0 {
+            os.write_uint64(1, self.exported)?;
+        }
+        if !self.json.is_empty() {
+            os.write_string(2, &self.json)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportDeadLettersResponse {
+        ExportDeadLettersResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "exported",
+                    |m: &ExportDeadLettersResponse| { &m.exported },
+                    |m: &mut ExportDeadLettersResponse| { &mut m.exported },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "json",
+                    |m: &ExportDeadLettersResponse| { &m.json },
+                    |m: &mut ExportDeadLettersResponse| { &mut m.json },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ExportDeadLettersResponse>(
+                    "ExportDeadLettersResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ExportDeadLettersResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ExportDeadLettersResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ExportDeadLettersResponse,
+        };
+        unsafe {
+            instance.get(ExportDeadLettersResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ExportDeadLettersResponse {
+    fn clear(&mut self) {
+        self.clear_exported();
+        self.clear_json();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportDeadLettersResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportDeadLettersResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EventSubscribeRequest {
+    // message fields
+    pub queueName: ::std::string::String,
+    pub prefetch: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl EventSubscribeRequest {
+    pub fn new() -> EventSubscribeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string queueName = 1;
+
+    pub fn clear_queueName(&mut self) {
+        self.queueName.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queueName(&mut self, v: ::std::string::String) {
+        self.queueName = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_queueName(&mut self) -> &mut ::std::string::String {
+        &mut self.queueName
+    }
+
+    // Take field
+    pub fn take_queueName(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.queueName, ::std::string::String::new())
+    }
+
+    pub fn get_queueName(&self) -> &str {
+        &self.queueName
+    }
+
+    // uint32 prefetch = 2;
+
+    pub fn clear_prefetch(&mut self) {
+        self.prefetch = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefetch(&mut self, v: u32) {
+        self.prefetch = v;
+    }
+
+    pub fn get_prefetch(&self) -> u32 {
+        self.prefetch
+    }
+}
+
+impl ::protobuf::Message for EventSubscribeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.queueName)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.prefetch = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.queueName.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.queueName);
+        }
+        if self.prefetch != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.prefetch, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.queueName.is_empty() {
+            os.write_string(1, &self.queueName)?;
+        }
+        if self.prefetch != 0 {
+            os.write_uint32(2, self.prefetch)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EventSubscribeRequest {
+        EventSubscribeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "queueName",
+                    |m: &EventSubscribeRequest| { &m.queueName },
+                    |m: &mut EventSubscribeRequest| { &mut m.queueName },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "prefetch",
+                    |m: &EventSubscribeRequest| { &m.prefetch },
+                    |m: &mut EventSubscribeRequest| { &mut m.prefetch },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<EventSubscribeRequest>(
+                    "EventSubscribeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static EventSubscribeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<EventSubscribeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EventSubscribeRequest,
+        };
+        unsafe {
+            instance.get(EventSubscribeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for EventSubscribeRequest {
+    fn clear(&mut self) {
+        self.clear_queueName();
+        self.clear_prefetch();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EventSubscribeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventSubscribeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EventSubscribeResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl EventSubscribeResponse {
+    pub fn new() -> EventSubscribeResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for EventSubscribeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EventSubscribeResponse {
+        EventSubscribeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<EventSubscribeResponse>(
+                    "EventSubscribeResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static EventSubscribeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<EventSubscribeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EventSubscribeResponse,
+        };
+        unsafe {
+            instance.get(EventSubscribeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for EventSubscribeResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EventSubscribeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventSubscribeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueueEventResponse {
+    // message fields
+    pub kind: EventKind,
+    pub id: ::std::string::String,
+    pub hasPriority: bool,
+    pub priorityLevel: u32,
+    pub requiredCapabilities: ::protobuf::RepeatedField<::std::string::String>,
+    pub timestampMilliseconds: u64,
+    pub missedEvents: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl QueueEventResponse {
+    pub fn new() -> QueueEventResponse {
+        ::std::default::Default::default()
+    }
+
+    // .EventKind kind = 1;
+
+    pub fn clear_kind(&mut self) {
+        self.kind = EventKind::ENQUEUED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: EventKind) {
+        self.kind = v;
+    }
+
+    pub fn get_kind(&self) -> EventKind {
+        self.kind
+    }
+
+    // string id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    // bool hasPriority = 3;
+
+    pub fn clear_hasPriority(&mut self) {
+        self.hasPriority = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hasPriority(&mut self, v: bool) {
+        self.hasPriority = v;
+    }
+
+    pub fn get_hasPriority(&self) -> bool {
+        self.hasPriority
+    }
+
+    // uint32 priorityLevel = 4;
+
+    pub fn clear_priorityLevel(&mut self) {
+        self.priorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityLevel(&mut self, v: u32) {
+        self.priorityLevel = v;
+    }
+
+    pub fn get_priorityLevel(&self) -> u32 {
+        self.priorityLevel
+    }
+
+    // repeated string requiredCapabilities = 5;
+
+    pub fn clear_requiredCapabilities(&mut self) {
+        self.requiredCapabilities.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requiredCapabilities(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.requiredCapabilities = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requiredCapabilities(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.requiredCapabilities
+    }
+
+    // Take field
+    pub fn take_requiredCapabilities(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.requiredCapabilities, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_requiredCapabilities(&self) -> &[::std::string::String] {
+        &self.requiredCapabilities
+    }
+
+    // uint64 timestampMilliseconds = 6;
+
+    pub fn clear_timestampMilliseconds(&mut self) {
+        self.timestampMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestampMilliseconds(&mut self, v: u64) {
+        self.timestampMilliseconds = v;
+    }
+
+    pub fn get_timestampMilliseconds(&self) -> u64 {
+        self.timestampMilliseconds
+    }
+
+    // uint64 missedEvents = 7;
+
+    pub fn clear_missedEvents(&mut self) {
+        self.missedEvents = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_missedEvents(&mut self, v: u64) {
+        self.missedEvents = v;
+    }
+
+    pub fn get_missedEvents(&self) -> u64 {
+        self.missedEvents
+    }
+}
+
+impl ::protobuf::Message for QueueEventResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.hasPriority = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.priorityLevel = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.requiredCapabilities)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestampMilliseconds = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.missedEvents = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.kind != EventKind::ENQUEUED {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
+        }
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.id);
+        }
+        if self.hasPriority != false {
+            my_size += 2;
+        }
+        if self.priorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.priorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.requiredCapabilities {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if self.timestampMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.timestampMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.missedEvents != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.missedEvents, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.kind != EventKind::ENQUEUED {
+            os.write_enum(1, self.kind.value())?;
+        }
+        if !self.id.is_empty() {
+            os.write_string(2, &self.id)?;
+        }
+        if self.hasPriority != false {
+            os.write_bool(3, self.hasPriority)?;
+        }
+        if self.priorityLevel != 0 {
+            os.write_uint32(4, self.priorityLevel)?;
+        }
+        for v in &self.requiredCapabilities {
+            os.write_string(5, &v)?;
+        };
+        if self.timestampMilliseconds != 0 {
+            os.write_uint64(6, self.timestampMilliseconds)?;
+        }
+        if self.missedEvents != 0 {
+            os.write_uint64(7, self.missedEvents)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueueEventResponse {
+        QueueEventResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<EventKind>>(
+                    "kind",
+                    |m: &QueueEventResponse| { &m.kind },
+                    |m: &mut QueueEventResponse| { &mut m.kind },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &QueueEventResponse| { &m.id },
+                    |m: &mut QueueEventResponse| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "hasPriority",
+                    |m: &QueueEventResponse| { &m.hasPriority },
+                    |m: &mut QueueEventResponse| { &mut m.hasPriority },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priorityLevel",
+                    |m: &QueueEventResponse| { &m.priorityLevel },
+                    |m: &mut QueueEventResponse| { &mut m.priorityLevel },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requiredCapabilities",
+                    |m: &QueueEventResponse| { &m.requiredCapabilities },
+                    |m: &mut QueueEventResponse| { &mut m.requiredCapabilities },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "timestampMilliseconds",
+                    |m: &QueueEventResponse| { &m.timestampMilliseconds },
+                    |m: &mut QueueEventResponse| { &mut m.timestampMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "missedEvents",
+                    |m: &QueueEventResponse| { &m.missedEvents },
+                    |m: &mut QueueEventResponse| { &mut m.missedEvents },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueueEventResponse>(
+                    "QueueEventResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueueEventResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueueEventResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueueEventResponse,
+        };
+        unsafe {
+            instance.get(QueueEventResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueueEventResponse {
+    fn clear(&mut self) {
+        self.clear_kind();
+        self.clear_id();
+        self.clear_hasPriority();
+        self.clear_priorityLevel();
+        self.clear_requiredCapabilities();
+        self.clear_timestampMilliseconds();
+        self.clear_missedEvents();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueueEventResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueueEventResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServerInfoRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ServerInfoRequest {
+    pub fn new() -> ServerInfoRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ServerInfoRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServerInfoRequest {
+        ServerInfoRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ServerInfoRequest>(
+                    "ServerInfoRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ServerInfoRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ServerInfoRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServerInfoRequest,
+        };
+        unsafe {
+            instance.get(ServerInfoRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServerInfoRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServerInfoRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServerInfoRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ServerInfoResponse {
+    // message fields
+    pub uptimeMilliseconds: u64,
+    pub version: ::std::string::String,
+    pub gitCommit: ::std::string::String,
+    pub storageFileSizes: ::protobuf::SingularPtrField<StorageFileSizes>,
+    pub priorityBands: ::protobuf::RepeatedField<::std::string::String>,
+    pub defaultPriorityLevel: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ServerInfoResponse {
+    pub fn new() -> ServerInfoResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 uptimeMilliseconds = 1;
+
+    pub fn clear_uptimeMilliseconds(&mut self) {
+        self.uptimeMilliseconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uptimeMilliseconds(&mut self, v: u64) {
+        self.uptimeMilliseconds = v;
+    }
+
+    pub fn get_uptimeMilliseconds(&self) -> u64 {
+        self.uptimeMilliseconds
+    }
+
+    // string version = 2;
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+
+    // string gitCommit = 3;
+
+    pub fn clear_gitCommit(&mut self) {
+        self.gitCommit.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gitCommit(&mut self, v: ::std::string::String) {
+        self.gitCommit = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gitCommit(&mut self) -> &mut ::std::string::String {
+        &mut self.gitCommit
+    }
+
+    // Take field
+    pub fn take_gitCommit(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gitCommit, ::std::string::String::new())
+    }
+
+    pub fn get_gitCommit(&self) -> &str {
+        &self.gitCommit
+    }
+
+    // .StorageFileSizes storageFileSizes = 4;
+
+    pub fn clear_storageFileSizes(&mut self) {
+        self.storageFileSizes.clear();
+    }
+
+    pub fn has_storageFileSizes(&self) -> bool {
+        self.storageFileSizes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storageFileSizes(&mut self, v: StorageFileSizes) {
+        self.storageFileSizes = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_storageFileSizes(&mut self) -> &mut StorageFileSizes {
+        if self.storageFileSizes.is_none() {
+            self.storageFileSizes.set_default();
+        }
+        self.storageFileSizes.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_storageFileSizes(&mut self) -> StorageFileSizes {
+        self.storageFileSizes.take().unwrap_or_else(|| StorageFileSizes::new())
+    }
+
+    pub fn get_storageFileSizes(&self) -> &StorageFileSizes {
+        self.storageFileSizes.as_ref().unwrap_or_else(|| StorageFileSizes::default_instance())
+    }
+
+    // repeated string priorityBands = 5;
+
+    pub fn clear_priorityBands(&mut self) {
+        self.priorityBands.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priorityBands(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.priorityBands = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_priorityBands(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.priorityBands
+    }
+
+    // Take field
+    pub fn take_priorityBands(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.priorityBands, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_priorityBands(&self) -> &[::std::string::String] {
+        &self.priorityBands
+    }
+
+    // uint32 defaultPriorityLevel = 6;
+
+    pub fn clear_defaultPriorityLevel(&mut self) {
+        self.defaultPriorityLevel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_defaultPriorityLevel(&mut self, v: u32) {
+        self.defaultPriorityLevel = v;
+    }
+
+    pub fn get_defaultPriorityLevel(&self) -> u32 {
+        self.defaultPriorityLevel
+    }
+}
+
+impl ::protobuf::Message for ServerInfoResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.storageFileSizes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.uptimeMilliseconds = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gitCommit)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.storageFileSizes)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.priorityBands)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.defaultPriorityLevel = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.uptimeMilliseconds != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.uptimeMilliseconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        if !self.gitCommit.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.gitCommit);
+        }
+        if let Some(ref v) = self.storageFileSizes.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.priorityBands {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if self.defaultPriorityLevel != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.defaultPriorityLevel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.uptimeMilliseconds != 0 {
+            os.write_uint64(1, self.uptimeMilliseconds)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        if !self.gitCommit.is_empty() {
+            os.write_string(3, &self.gitCommit)?;
+        }
+        if let Some(ref v) = self.storageFileSizes.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.priorityBands {
+            os.write_string(5, &v)?;
+        };
+        if self.defaultPriorityLevel != 0 {
+            os.write_uint32(6, self.defaultPriorityLevel)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ServerInfoResponse {
+        ServerInfoResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "uptimeMilliseconds",
+                    |m: &ServerInfoResponse| { &m.uptimeMilliseconds },
+                    |m: &mut ServerInfoResponse| { &mut m.uptimeMilliseconds },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "version",
+                    |m: &ServerInfoResponse| { &m.version },
+                    |m: &mut ServerInfoResponse| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "gitCommit",
+                    |m: &ServerInfoResponse| { &m.gitCommit },
+                    |m: &mut ServerInfoResponse| { &mut m.gitCommit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StorageFileSizes>>(
+                    "storageFileSizes",
+                    |m: &ServerInfoResponse| { &m.storageFileSizes },
+                    |m: &mut ServerInfoResponse| { &mut m.storageFileSizes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "priorityBands",
+                    |m: &ServerInfoResponse| { &m.priorityBands },
+                    |m: &mut ServerInfoResponse| { &mut m.priorityBands },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "defaultPriorityLevel",
+                    |m: &ServerInfoResponse| { &m.defaultPriorityLevel },
+                    |m: &mut ServerInfoResponse| { &mut m.defaultPriorityLevel },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServerInfoResponse>(
+                    "ServerInfoResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ServerInfoResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ServerInfoResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServerInfoResponse,
+        };
+        unsafe {
+            instance.get(ServerInfoResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServerInfoResponse {
+    fn clear(&mut self) {
+        self.clear_uptimeMilliseconds();
+        self.clear_version();
+        self.clear_gitCommit();
+        self.clear_storageFileSizes();
+        self.clear_priorityBands();
+        self.clear_defaultPriorityLevel();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ServerInfoResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ServerInfoResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StorageFileSizes {
+    // message fields
+    pub highPriorityBytes: u64,
+    pub lowPriorityBytes: u64,
+    pub completedBytes: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl StorageFileSizes {
+    pub fn new() -> StorageFileSizes {
+        ::std::default::Default::default()
+    }
+
+    // uint64 highPriorityBytes = 1;
+
+    pub fn clear_highPriorityBytes(&mut self) {
+        self.highPriorityBytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highPriorityBytes(&mut self, v: u64) {
+        self.highPriorityBytes = v;
+    }
+
+    pub fn get_highPriorityBytes(&self) -> u64 {
+        self.highPriorityBytes
+    }
+
+    // uint64 lowPriorityBytes = 2;
+
+    pub fn clear_lowPriorityBytes(&mut self) {
+        self.lowPriorityBytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lowPriorityBytes(&mut self, v: u64) {
+        self.lowPriorityBytes = v;
+    }
+
+    pub fn get_lowPriorityBytes(&self) -> u64 {
+        self.lowPriorityBytes
+    }
+
+    // uint64 completedBytes = 3;
+
+    pub fn clear_completedBytes(&mut self) {
+        self.completedBytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_completedBytes(&mut self, v: u64) {
+        self.completedBytes = v;
+    }
+
+    pub fn get_completedBytes(&self) -> u64 {
+        self.completedBytes
+    }
+}
+
+impl ::protobuf::Message for StorageFileSizes {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.highPriorityBytes = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.lowPriorityBytes = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.completedBytes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.highPriorityBytes != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.highPriorityBytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lowPriorityBytes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.lowPriorityBytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.completedBytes != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.completedBytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.highPriorityBytes != 0 {
+            os.write_uint64(1, self.highPriorityBytes)?;
+        }
+        if self.lowPriorityBytes != 0 {
+            os.write_uint64(2, self.lowPriorityBytes)?;
+        }
+        if self.completedBytes != 0 {
+            os.write_uint64(3, self.completedBytes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StorageFileSizes {
+        StorageFileSizes::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "highPriorityBytes",
+                    |m: &StorageFileSizes| { &m.highPriorityBytes },
+                    |m: &mut StorageFileSizes| { &mut m.highPriorityBytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "lowPriorityBytes",
+                    |m: &StorageFileSizes| { &m.lowPriorityBytes },
+                    |m: &mut StorageFileSizes| { &mut m.lowPriorityBytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "completedBytes",
+                    |m: &StorageFileSizes| { &m.completedBytes },
+                    |m: &mut StorageFileSizes| { &mut m.completedBytes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StorageFileSizes>(
+                    "StorageFileSizes",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StorageFileSizes {
+        static mut instance: ::protobuf::lazy::Lazy<StorageFileSizes> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StorageFileSizes,
+        };
+        unsafe {
+            instance.get(StorageFileSizes::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StorageFileSizes {
+    fn clear(&mut self) {
+        self.clear_highPriorityBytes();
+        self.clear_lowPriorityBytes();
+        self.clear_completedBytes();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StorageFileSizes {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageFileSizes {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DiagnosticDumpRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl DiagnosticDumpRequest {
+    pub fn new() -> DiagnosticDumpRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for DiagnosticDumpRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DiagnosticDumpRequest {
+        DiagnosticDumpRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<DiagnosticDumpRequest>(
+                    "DiagnosticDumpRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DiagnosticDumpRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DiagnosticDumpRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DiagnosticDumpRequest,
+        };
+        unsafe {
+            instance.get(DiagnosticDumpRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DiagnosticDumpRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DiagnosticDumpRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DiagnosticDumpRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DiagnosticDumpResponse {
+    // message fields
+    pub json: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl DiagnosticDumpResponse {
+    pub fn new() -> DiagnosticDumpResponse {
+        ::std::default::Default::default()
+    }
+
+    // string json = 1;
+
+    pub fn clear_json(&mut self) {
+        self.json.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_json(&mut self, v: ::std::string::String) {
+        self.json = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_json(&mut self) -> &mut ::std::string::String {
+        &mut self.json
+    }
+
+    // Take field
+    pub fn take_json(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.json, ::std::string::String::new())
+    }
+
+    pub fn get_json(&self) -> &str {
+        &self.json
+    }
+}
+
+impl ::protobuf::Message for DiagnosticDumpResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.json)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.json.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.json);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.json.is_empty() {
+            os.write_string(1, &self.json)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DiagnosticDumpResponse {
+        DiagnosticDumpResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "json",
+                    |m: &DiagnosticDumpResponse| { &m.json },
+                    |m: &mut DiagnosticDumpResponse| { &mut m.json },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DiagnosticDumpResponse>(
+                    "DiagnosticDumpResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DiagnosticDumpResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DiagnosticDumpResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DiagnosticDumpResponse,
+        };
+        unsafe {
+            instance.get(DiagnosticDumpResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DiagnosticDumpResponse {
+    fn clear(&mut self) {
+        self.clear_json();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DiagnosticDumpResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DiagnosticDumpResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ErrorResponse {
+    // message fields
+    pub message: ::std::string::String,
+    pub code: ErrorCode,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ErrorResponse {
+    pub fn new() -> ErrorResponse {
+        ::std::default::Default::default()
+    }
+
+    // string message = 1;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    // .ErrorCode code = 2;
+
+    pub fn clear_code(&mut self) {
+        self.code = ErrorCode::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ErrorCode) {
+        self.code = v;
+    }
+
+    pub fn get_code(&self) -> ErrorCode {
+        self.code
+    }
+}
+
+impl ::protobuf::Message for ErrorResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.code, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message);
+        }
+        if self.code != ErrorCode::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.code);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.message.is_empty() {
+            os.write_string(1, &self.message)?;
+        }
+        if self.code != ErrorCode::UNKNOWN {
+            os.write_enum(2, self.code.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ErrorResponse {
+        ErrorResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    |m: &ErrorResponse| { &m.message },
+                    |m: &mut ErrorResponse| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ErrorCode>>(
+                    "code",
+                    |m: &ErrorResponse| { &m.code },
+                    |m: &mut ErrorResponse| { &mut m.code },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ErrorResponse>(
+                    "ErrorResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ErrorResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ErrorResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ErrorResponse,
+        };
+        unsafe {
+            instance.get(ErrorResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ErrorResponse {
+    fn clear(&mut self) {
+        self.clear_message();
+        self.clear_code();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ErrorResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestWrapper {
+    // message fields
+    pub refId: i32,
+    // message oneof groups
+    pub message: ::std::option::Option<RequestWrapper_oneof_message>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+#[derive(Clone,PartialEq)]
+pub enum RequestWrapper_oneof_message {
+    enqueue(EnqueueRequest),
+    pop(PopRequest),
+    acknowledge(AcknowledgeRequest),
+    authenticate(AuthenticateRequest),
+    health(HealthRequest),
+    acknowledgeMatching(AcknowledgeMatchingRequest),
+    failWithRetag(FailWithRetagRequest),
+    serverInfo(ServerInfoRequest),
+    fail(FailRequest),
+    diagnosticDump(DiagnosticDumpRequest),
+    beginEnqueue(BeginEnqueueRequest),
+    enqueueChunk(EnqueueChunkRequest),
+    commitEnqueue(CommitEnqueueRequest),
+    cancelPop(CancelPopRequest),
+    cancel(CancelRequest),
+    findByTag(FindByTagRequest),
+    listProcessing(ListProcessingRequest),
+    setCapabilityPaused(SetCapabilityPausedRequest),
+    subscribe(SubscribeRequest),
+    reserve(ReserveRequest),
+    confirmReservation(ConfirmReservationRequest),
+    decline(DeclineRequest),
+    enqueueBatch(EnqueueBatchRequest),
+    listQueued(ListQueuedRequest),
+    peek(PeekRequest),
+    stats(StatsRequest),
+    purge(PurgeRequest),
+    eventSubscribe(EventSubscribeRequest),
+    exportDeadLetters(ExportDeadLettersRequest),
+}
+
+impl RequestWrapper {
+    pub fn new() -> RequestWrapper {
+        ::std::default::Default::default()
+    }
+
+    // int32 refId = 10;
+
+    pub fn clear_refId(&mut self) {
+        self.refId = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_refId(&mut self, v: i32) {
+        self.refId = v;
+    }
+
+    pub fn get_refId(&self) -> i32 {
+        self.refId
+    }
+
+    // .EnqueueRequest enqueue = 1;
+
+    pub fn clear_enqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueue(&mut self, v: EnqueueRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueue(&mut self) -> &mut EnqueueRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(EnqueueRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueue(&mut self) -> EnqueueRequest {
+        if self.has_enqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueRequest::new()
+        }
+    }
+
+    pub fn get_enqueue(&self) -> &EnqueueRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(ref v)) => v,
+            _ => EnqueueRequest::default_instance(),
+        }
+    }
+
+    // .PopRequest pop = 2;
+
+    pub fn clear_pop(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_pop(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pop(&mut self, v: PopRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pop(&mut self) -> &mut PopRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::pop(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(PopRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pop(&mut self) -> PopRequest {
+        if self.has_pop() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::pop(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PopRequest::new()
+        }
+    }
+
+    pub fn get_pop(&self) -> &PopRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::pop(ref v)) => v,
+            _ => PopRequest::default_instance(),
+        }
+    }
+
+    // .AcknowledgeRequest acknowledge = 3;
+
+    pub fn clear_acknowledge(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_acknowledge(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acknowledge(&mut self, v: AcknowledgeRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_acknowledge(&mut self) -> &mut AcknowledgeRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(AcknowledgeRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_acknowledge(&mut self) -> AcknowledgeRequest {
+        if self.has_acknowledge() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AcknowledgeRequest::new()
+        }
+    }
+
+    pub fn get_acknowledge(&self) -> &AcknowledgeRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(ref v)) => v,
+            _ => AcknowledgeRequest::default_instance(),
+        }
+    }
+
+    // .AuthenticateRequest authenticate = 6;
+
+    pub fn clear_authenticate(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_authenticate(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_authenticate(&mut self, v: AuthenticateRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_authenticate(&mut self) -> &mut AuthenticateRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(AuthenticateRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_authenticate(&mut self) -> AuthenticateRequest {
+        if self.has_authenticate() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AuthenticateRequest::new()
+        }
+    }
+
+    pub fn get_authenticate(&self) -> &AuthenticateRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(ref v)) => v,
+            _ => AuthenticateRequest::default_instance(),
+        }
+    }
+
+    // .HealthRequest health = 7;
+
+    pub fn clear_health(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::health(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: HealthRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::health(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_health(&mut self) -> &mut HealthRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::health(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::health(HealthRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::health(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_health(&mut self) -> HealthRequest {
+        if self.has_health() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::health(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            HealthRequest::new()
+        }
+    }
+
+    pub fn get_health(&self) -> &HealthRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::health(ref v)) => v,
+            _ => HealthRequest::default_instance(),
+        }
+    }
+
+    // .AcknowledgeMatchingRequest acknowledgeMatching = 8;
+
+    pub fn clear_acknowledgeMatching(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_acknowledgeMatching(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acknowledgeMatching(&mut self, v: AcknowledgeMatchingRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_acknowledgeMatching(&mut self) -> &mut AcknowledgeMatchingRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(AcknowledgeMatchingRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_acknowledgeMatching(&mut self) -> AcknowledgeMatchingRequest {
+        if self.has_acknowledgeMatching() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AcknowledgeMatchingRequest::new()
+        }
+    }
+
+    pub fn get_acknowledgeMatching(&self) -> &AcknowledgeMatchingRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(ref v)) => v,
+            _ => AcknowledgeMatchingRequest::default_instance(),
+        }
+    }
+
+    // .FailWithRetagRequest failWithRetag = 9;
+
+    pub fn clear_failWithRetag(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_failWithRetag(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failWithRetag(&mut self, v: FailWithRetagRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_failWithRetag(&mut self) -> &mut FailWithRetagRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(FailWithRetagRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_failWithRetag(&mut self) -> FailWithRetagRequest {
+        if self.has_failWithRetag() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FailWithRetagRequest::new()
+        }
+    }
+
+    pub fn get_failWithRetag(&self) -> &FailWithRetagRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(ref v)) => v,
+            _ => FailWithRetagRequest::default_instance(),
+        }
+    }
+
+    // .ServerInfoRequest serverInfo = 11;
+
+    pub fn clear_serverInfo(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_serverInfo(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_serverInfo(&mut self, v: ServerInfoRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_serverInfo(&mut self) -> &mut ServerInfoRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(ServerInfoRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_serverInfo(&mut self) -> ServerInfoRequest {
+        if self.has_serverInfo() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServerInfoRequest::new()
+        }
+    }
+
+    pub fn get_serverInfo(&self) -> &ServerInfoRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(ref v)) => v,
+            _ => ServerInfoRequest::default_instance(),
+        }
+    }
+
+    // .FailRequest fail = 12;
+
+    pub fn clear_fail(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_fail(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::fail(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fail(&mut self, v: FailRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::fail(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fail(&mut self) -> &mut FailRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::fail(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::fail(FailRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::fail(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_fail(&mut self) -> FailRequest {
+        if self.has_fail() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::fail(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FailRequest::new()
+        }
+    }
+
+    pub fn get_fail(&self) -> &FailRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::fail(ref v)) => v,
+            _ => FailRequest::default_instance(),
+        }
+    }
+
+    // .DiagnosticDumpRequest diagnosticDump = 13;
+
+    pub fn clear_diagnosticDump(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_diagnosticDump(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnosticDump(&mut self, v: DiagnosticDumpRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_diagnosticDump(&mut self) -> &mut DiagnosticDumpRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(DiagnosticDumpRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_diagnosticDump(&mut self) -> DiagnosticDumpRequest {
+        if self.has_diagnosticDump() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DiagnosticDumpRequest::new()
+        }
+    }
+
+    pub fn get_diagnosticDump(&self) -> &DiagnosticDumpRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(ref v)) => v,
+            _ => DiagnosticDumpRequest::default_instance(),
+        }
+    }
+
+    // .BeginEnqueueRequest beginEnqueue = 14;
+
+    pub fn clear_beginEnqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_beginEnqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_beginEnqueue(&mut self, v: BeginEnqueueRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_beginEnqueue(&mut self) -> &mut BeginEnqueueRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(BeginEnqueueRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_beginEnqueue(&mut self) -> BeginEnqueueRequest {
+        if self.has_beginEnqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            BeginEnqueueRequest::new()
+        }
+    }
+
+    pub fn get_beginEnqueue(&self) -> &BeginEnqueueRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(ref v)) => v,
+            _ => BeginEnqueueRequest::default_instance(),
+        }
+    }
+
+    // .EnqueueChunkRequest enqueueChunk = 15;
+
+    pub fn clear_enqueueChunk(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueueChunk(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueueChunk(&mut self, v: EnqueueChunkRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueueChunk(&mut self) -> &mut EnqueueChunkRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(EnqueueChunkRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueueChunk(&mut self) -> EnqueueChunkRequest {
+        if self.has_enqueueChunk() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueChunkRequest::new()
+        }
+    }
+
+    pub fn get_enqueueChunk(&self) -> &EnqueueChunkRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(ref v)) => v,
+            _ => EnqueueChunkRequest::default_instance(),
+        }
+    }
+
+    // .CommitEnqueueRequest commitEnqueue = 16;
+
+    pub fn clear_commitEnqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_commitEnqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commitEnqueue(&mut self, v: CommitEnqueueRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_commitEnqueue(&mut self) -> &mut CommitEnqueueRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(CommitEnqueueRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_commitEnqueue(&mut self) -> CommitEnqueueRequest {
+        if self.has_commitEnqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CommitEnqueueRequest::new()
+        }
+    }
+
+    pub fn get_commitEnqueue(&self) -> &CommitEnqueueRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(ref v)) => v,
+            _ => CommitEnqueueRequest::default_instance(),
+        }
+    }
+
+    // .CancelPopRequest cancelPop = 17;
+
+    pub fn clear_cancelPop(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_cancelPop(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancelPop(&mut self, v: CancelPopRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cancelPop(&mut self) -> &mut CancelPopRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(CancelPopRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cancelPop(&mut self) -> CancelPopRequest {
+        if self.has_cancelPop() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CancelPopRequest::new()
+        }
+    }
+
+    pub fn get_cancelPop(&self) -> &CancelPopRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(ref v)) => v,
+            _ => CancelPopRequest::default_instance(),
+        }
+    }
+
+    // .CancelRequest cancel = 18;
+
+    pub fn clear_cancel(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_cancel(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancel(&mut self, v: CancelRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cancel(&mut self) -> &mut CancelRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(CancelRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cancel(&mut self) -> CancelRequest {
+        if self.has_cancel() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CancelRequest::new()
+        }
+    }
+
+    pub fn get_cancel(&self) -> &CancelRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(ref v)) => v,
+            _ => CancelRequest::default_instance(),
+        }
+    }
+
+    // .FindByTagRequest findByTag = 19;
+
+    pub fn clear_findByTag(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_findByTag(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_findByTag(&mut self, v: FindByTagRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_findByTag(&mut self) -> &mut FindByTagRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(FindByTagRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_findByTag(&mut self) -> FindByTagRequest {
+        if self.has_findByTag() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FindByTagRequest::new()
+        }
+    }
+
+    pub fn get_findByTag(&self) -> &FindByTagRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(ref v)) => v,
+            _ => FindByTagRequest::default_instance(),
+        }
+    }
+
+    // .ListProcessingRequest listProcessing = 20;
+
+    pub fn clear_listProcessing(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_listProcessing(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listProcessing(&mut self, v: ListProcessingRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listProcessing(&mut self) -> &mut ListProcessingRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(ListProcessingRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listProcessing(&mut self) -> ListProcessingRequest {
+        if self.has_listProcessing() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListProcessingRequest::new()
+        }
+    }
+
+    pub fn get_listProcessing(&self) -> &ListProcessingRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(ref v)) => v,
+            _ => ListProcessingRequest::default_instance(),
+        }
+    }
+
+    // .SetCapabilityPausedRequest setCapabilityPaused = 21;
+
+    pub fn clear_setCapabilityPaused(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_setCapabilityPaused(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setCapabilityPaused(&mut self, v: SetCapabilityPausedRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_setCapabilityPaused(&mut self) -> &mut SetCapabilityPausedRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(SetCapabilityPausedRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_setCapabilityPaused(&mut self) -> SetCapabilityPausedRequest {
+        if self.has_setCapabilityPaused() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetCapabilityPausedRequest::new()
+        }
+    }
+
+    pub fn get_setCapabilityPaused(&self) -> &SetCapabilityPausedRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(ref v)) => v,
+            _ => SetCapabilityPausedRequest::default_instance(),
+        }
+    }
+
+    // .SubscribeRequest subscribe = 22;
+
+    pub fn clear_subscribe(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_subscribe(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subscribe(&mut self, v: SubscribeRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subscribe(&mut self) -> &mut SubscribeRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(SubscribeRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_subscribe(&mut self) -> SubscribeRequest {
+        if self.has_subscribe() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SubscribeRequest::new()
+        }
+    }
+
+    pub fn get_subscribe(&self) -> &SubscribeRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(ref v)) => v,
+            _ => SubscribeRequest::default_instance(),
+        }
+    }
+
+    // .ReserveRequest reserve = 23;
+
+    pub fn clear_reserve(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_reserve(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reserve(&mut self, v: ReserveRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reserve(&mut self) -> &mut ReserveRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(ReserveRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_reserve(&mut self) -> ReserveRequest {
+        if self.has_reserve() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReserveRequest::new()
+        }
+    }
+
+    pub fn get_reserve(&self) -> &ReserveRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(ref v)) => v,
+            _ => ReserveRequest::default_instance(),
+        }
+    }
+
+    // .ConfirmReservationRequest confirmReservation = 24;
+
+    pub fn clear_confirmReservation(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_confirmReservation(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_confirmReservation(&mut self, v: ConfirmReservationRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_confirmReservation(&mut self) -> &mut ConfirmReservationRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(ConfirmReservationRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_confirmReservation(&mut self) -> ConfirmReservationRequest {
+        if self.has_confirmReservation() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ConfirmReservationRequest::new()
+        }
+    }
+
+    pub fn get_confirmReservation(&self) -> &ConfirmReservationRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(ref v)) => v,
+            _ => ConfirmReservationRequest::default_instance(),
+        }
+    }
+
+    // .DeclineRequest decline = 25;
+
+    pub fn clear_decline(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_decline(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::decline(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decline(&mut self, v: DeclineRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::decline(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decline(&mut self) -> &mut DeclineRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::decline(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::decline(DeclineRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::decline(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_decline(&mut self) -> DeclineRequest {
+        if self.has_decline() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::decline(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DeclineRequest::new()
+        }
+    }
+
+    pub fn get_decline(&self) -> &DeclineRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::decline(ref v)) => v,
+            _ => DeclineRequest::default_instance(),
+        }
+    }
+
+    // .EnqueueBatchRequest enqueueBatch = 26;
+
+    pub fn clear_enqueueBatch(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueueBatch(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueueBatch(&mut self, v: EnqueueBatchRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueueBatch(&mut self) -> &mut EnqueueBatchRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(EnqueueBatchRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueueBatch(&mut self) -> EnqueueBatchRequest {
+        if self.has_enqueueBatch() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueBatchRequest::new()
+        }
+    }
+
+    pub fn get_enqueueBatch(&self) -> &EnqueueBatchRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(ref v)) => v,
+            _ => EnqueueBatchRequest::default_instance(),
+        }
+    }
+
+    // .ListQueuedRequest listQueued = 27;
+
+    pub fn clear_listQueued(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_listQueued(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listQueued(&mut self, v: ListQueuedRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listQueued(&mut self) -> &mut ListQueuedRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(ListQueuedRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listQueued(&mut self) -> ListQueuedRequest {
+        if self.has_listQueued() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListQueuedRequest::new()
+        }
+    }
+
+    pub fn get_listQueued(&self) -> &ListQueuedRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(ref v)) => v,
+            _ => ListQueuedRequest::default_instance(),
+        }
+    }
+
+    // .PeekRequest peek = 28;
+
+    pub fn clear_peek(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_peek(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::peek(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peek(&mut self, v: PeekRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::peek(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_peek(&mut self) -> &mut PeekRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::peek(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::peek(PeekRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::peek(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_peek(&mut self) -> PeekRequest {
+        if self.has_peek() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::peek(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PeekRequest::new()
+        }
+    }
+
+    pub fn get_peek(&self) -> &PeekRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::peek(ref v)) => v,
+            _ => PeekRequest::default_instance(),
+        }
+    }
+
+    // .StatsRequest stats = 29;
+
+    pub fn clear_stats(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_stats(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::stats(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stats(&mut self, v: StatsRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::stats(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stats(&mut self) -> &mut StatsRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::stats(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::stats(StatsRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::stats(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_stats(&mut self) -> StatsRequest {
+        if self.has_stats() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::stats(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            StatsRequest::new()
+        }
+    }
+
+    pub fn get_stats(&self) -> &StatsRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::stats(ref v)) => v,
+            _ => StatsRequest::default_instance(),
+        }
+    }
+
+    // .PurgeRequest purge = 30;
+
+    pub fn clear_purge(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_purge(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::purge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_purge(&mut self, v: PurgeRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::purge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_purge(&mut self) -> &mut PurgeRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::purge(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::purge(PurgeRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::purge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_purge(&mut self) -> PurgeRequest {
+        if self.has_purge() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::purge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PurgeRequest::new()
+        }
+    }
+
+    pub fn get_purge(&self) -> &PurgeRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::purge(ref v)) => v,
+            _ => PurgeRequest::default_instance(),
+        }
+    }
+
+    // .EventSubscribeRequest eventSubscribe = 31;
+
+    pub fn clear_eventSubscribe(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_eventSubscribe(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eventSubscribe(&mut self, v: EventSubscribeRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eventSubscribe(&mut self) -> &mut EventSubscribeRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(EventSubscribeRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_eventSubscribe(&mut self) -> EventSubscribeRequest {
+        if self.has_eventSubscribe() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EventSubscribeRequest::new()
+        }
+    }
+
+    pub fn get_eventSubscribe(&self) -> &EventSubscribeRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(ref v)) => v,
+            _ => EventSubscribeRequest::default_instance(),
+        }
+    }
+
+    // .ExportDeadLettersRequest exportDeadLetters = 32;
+
+    pub fn clear_exportDeadLetters(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_exportDeadLetters(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exportDeadLetters(&mut self, v: ExportDeadLettersRequest) {
+        self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exportDeadLetters(&mut self) -> &mut ExportDeadLettersRequest {
+        if let ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(ExportDeadLettersRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_exportDeadLetters(&mut self) -> ExportDeadLettersRequest {
+        if self.has_exportDeadLetters() {
+            match self.message.take() {
+                ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExportDeadLettersRequest::new()
+        }
+    }
+
+    pub fn get_exportDeadLetters(&self) -> &ExportDeadLettersRequest {
+        match self.message {
+            ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(ref v)) => v,
+            _ => ExportDeadLettersRequest::default_instance(),
+        }
+    }
+}
+
+impl ::protobuf::Message for RequestWrapper {
+    fn is_initialized(&self) -> bool {
+        if let Some(RequestWrapper_oneof_message::enqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::pop(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::acknowledge(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::authenticate(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::health(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::acknowledgeMatching(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::failWithRetag(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::serverInfo(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::fail(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::diagnosticDump(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::beginEnqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::enqueueChunk(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::commitEnqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::cancelPop(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::cancel(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::findByTag(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::listProcessing(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::setCapabilityPaused(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::subscribe(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::reserve(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::confirmReservation(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::decline(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::enqueueBatch(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::listQueued(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::peek(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::stats(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::purge(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::eventSubscribe(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(RequestWrapper_oneof_message::exportDeadLetters(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.refId = tmp;
+                },
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueue(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::pop(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledge(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::authenticate(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::health(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::acknowledgeMatching(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::failWithRetag(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::serverInfo(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::fail(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::diagnosticDump(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::beginEnqueue(is.read_message()?));
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueChunk(is.read_message()?));
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::commitEnqueue(is.read_message()?));
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancelPop(is.read_message()?));
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::cancel(is.read_message()?));
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::findByTag(is.read_message()?));
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listProcessing(is.read_message()?));
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::setCapabilityPaused(is.read_message()?));
+                },
+                22 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::subscribe(is.read_message()?));
+                },
+                23 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::reserve(is.read_message()?));
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::confirmReservation(is.read_message()?));
+                },
+                25 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::decline(is.read_message()?));
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::enqueueBatch(is.read_message()?));
+                },
+                27 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::listQueued(is.read_message()?));
+                },
+                28 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::peek(is.read_message()?));
+                },
+                29 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::stats(is.read_message()?));
+                },
+                30 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::purge(is.read_message()?));
+                },
+                31 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::eventSubscribe(is.read_message()?));
+                },
+                32 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(RequestWrapper_oneof_message::exportDeadLetters(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.refId != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.refId, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &RequestWrapper_oneof_message::enqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::pop(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::acknowledge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::authenticate(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::health(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::acknowledgeMatching(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::failWithRetag(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::serverInfo(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::fail(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::diagnosticDump(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::beginEnqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::enqueueChunk(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::commitEnqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::cancelPop(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::cancel(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::findByTag(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::listProcessing(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::setCapabilityPaused(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::subscribe(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::reserve(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::confirmReservation(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::decline(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::enqueueBatch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::listQueued(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::peek(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::stats(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::purge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::eventSubscribe(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &RequestWrapper_oneof_message::exportDeadLetters(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.refId != 0 {
+            os.write_int32(10, self.refId)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &RequestWrapper_oneof_message::enqueue(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::pop(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::acknowledge(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::authenticate(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::health(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::acknowledgeMatching(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::failWithRetag(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::serverInfo(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::fail(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::diagnosticDump(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::beginEnqueue(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::enqueueChunk(ref v) => {
+                    os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::commitEnqueue(ref v) => {
+                    os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::cancelPop(ref v) => {
+                    os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::cancel(ref v) => {
+                    os.write_tag(18, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::findByTag(ref v) => {
+                    os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::listProcessing(ref v) => {
+                    os.write_tag(20, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::setCapabilityPaused(ref v) => {
+                    os.write_tag(21, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::subscribe(ref v) => {
+                    os.write_tag(22, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::reserve(ref v) => {
+                    os.write_tag(23, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::confirmReservation(ref v) => {
+                    os.write_tag(24, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::decline(ref v) => {
+                    os.write_tag(25, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::enqueueBatch(ref v) => {
+                    os.write_tag(26, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::listQueued(ref v) => {
+                    os.write_tag(27, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::peek(ref v) => {
+                    os.write_tag(28, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::stats(ref v) => {
+                    os.write_tag(29, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::purge(ref v) => {
+                    os.write_tag(30, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::eventSubscribe(ref v) => {
+                    os.write_tag(31, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &RequestWrapper_oneof_message::exportDeadLetters(ref v) => {
+                    os.write_tag(32, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestWrapper {
+        RequestWrapper::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "refId",
+                    |m: &RequestWrapper| { &m.refId },
+                    |m: &mut RequestWrapper| { &mut m.refId },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueRequest>(
+                    "enqueue",
+                    RequestWrapper::has_enqueue,
+                    RequestWrapper::get_enqueue,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PopRequest>(
+                    "pop",
+                    RequestWrapper::has_pop,
+                    RequestWrapper::get_pop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AcknowledgeRequest>(
+                    "acknowledge",
+                    RequestWrapper::has_acknowledge,
+                    RequestWrapper::get_acknowledge,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AuthenticateRequest>(
+                    "authenticate",
+                    RequestWrapper::has_authenticate,
+                    RequestWrapper::get_authenticate,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, HealthRequest>(
+                    "health",
+                    RequestWrapper::has_health,
+                    RequestWrapper::get_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AcknowledgeMatchingRequest>(
+                    "acknowledgeMatching",
+                    RequestWrapper::has_acknowledgeMatching,
+                    RequestWrapper::get_acknowledgeMatching,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FailWithRetagRequest>(
+                    "failWithRetag",
+                    RequestWrapper::has_failWithRetag,
+                    RequestWrapper::get_failWithRetag,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServerInfoRequest>(
+                    "serverInfo",
+                    RequestWrapper::has_serverInfo,
+                    RequestWrapper::get_serverInfo,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FailRequest>(
+                    "fail",
+                    RequestWrapper::has_fail,
+                    RequestWrapper::get_fail,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DiagnosticDumpRequest>(
+                    "diagnosticDump",
+                    RequestWrapper::has_diagnosticDump,
+                    RequestWrapper::get_diagnosticDump,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, BeginEnqueueRequest>(
+                    "beginEnqueue",
+                    RequestWrapper::has_beginEnqueue,
+                    RequestWrapper::get_beginEnqueue,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueChunkRequest>(
+                    "enqueueChunk",
+                    RequestWrapper::has_enqueueChunk,
+                    RequestWrapper::get_enqueueChunk,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CommitEnqueueRequest>(
+                    "commitEnqueue",
+                    RequestWrapper::has_commitEnqueue,
+                    RequestWrapper::get_commitEnqueue,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CancelPopRequest>(
+                    "cancelPop",
+                    RequestWrapper::has_cancelPop,
+                    RequestWrapper::get_cancelPop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CancelRequest>(
+                    "cancel",
+                    RequestWrapper::has_cancel,
+                    RequestWrapper::get_cancel,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FindByTagRequest>(
+                    "findByTag",
+                    RequestWrapper::has_findByTag,
+                    RequestWrapper::get_findByTag,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListProcessingRequest>(
+                    "listProcessing",
+                    RequestWrapper::has_listProcessing,
+                    RequestWrapper::get_listProcessing,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SetCapabilityPausedRequest>(
+                    "setCapabilityPaused",
+                    RequestWrapper::has_setCapabilityPaused,
+                    RequestWrapper::get_setCapabilityPaused,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SubscribeRequest>(
+                    "subscribe",
+                    RequestWrapper::has_subscribe,
+                    RequestWrapper::get_subscribe,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReserveRequest>(
+                    "reserve",
+                    RequestWrapper::has_reserve,
+                    RequestWrapper::get_reserve,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ConfirmReservationRequest>(
+                    "confirmReservation",
+                    RequestWrapper::has_confirmReservation,
+                    RequestWrapper::get_confirmReservation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DeclineRequest>(
+                    "decline",
+                    RequestWrapper::has_decline,
+                    RequestWrapper::get_decline,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueBatchRequest>(
+                    "enqueueBatch",
+                    RequestWrapper::has_enqueueBatch,
+                    RequestWrapper::get_enqueueBatch,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListQueuedRequest>(
+                    "listQueued",
+                    RequestWrapper::has_listQueued,
+                    RequestWrapper::get_listQueued,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PeekRequest>(
+                    "peek",
+                    RequestWrapper::has_peek,
+                    RequestWrapper::get_peek,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, StatsRequest>(
+                    "stats",
+                    RequestWrapper::has_stats,
+                    RequestWrapper::get_stats,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PurgeRequest>(
+                    "purge",
+                    RequestWrapper::has_purge,
+                    RequestWrapper::get_purge,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EventSubscribeRequest>(
+                    "eventSubscribe",
+                    RequestWrapper::has_eventSubscribe,
+                    RequestWrapper::get_eventSubscribe,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ExportDeadLettersRequest>(
+                    "exportDeadLetters",
+                    RequestWrapper::has_exportDeadLetters,
+                    RequestWrapper::get_exportDeadLetters,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RequestWrapper>(
+                    "RequestWrapper",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RequestWrapper {
+        static mut instance: ::protobuf::lazy::Lazy<RequestWrapper> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RequestWrapper,
+        };
+        unsafe {
+            instance.get(RequestWrapper::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RequestWrapper {
+    fn clear(&mut self) {
+        self.clear_refId();
+        self.clear_enqueue();
+        self.clear_pop();
+        self.clear_acknowledge();
+        self.clear_authenticate();
+        self.clear_health();
+        self.clear_acknowledgeMatching();
+        self.clear_failWithRetag();
+        self.clear_serverInfo();
+        self.clear_fail();
+        self.clear_diagnosticDump();
+        self.clear_beginEnqueue();
+        self.clear_enqueueChunk();
+        self.clear_commitEnqueue();
+        self.clear_cancelPop();
+        self.clear_cancel();
+        self.clear_findByTag();
+        self.clear_listProcessing();
+        self.clear_setCapabilityPaused();
+        self.clear_subscribe();
+        self.clear_reserve();
+        self.clear_confirmReservation();
+        self.clear_decline();
+        self.clear_enqueueBatch();
+        self.clear_listQueued();
+        self.clear_peek();
+        self.clear_stats();
+        self.clear_purge();
+        self.clear_eventSubscribe();
+        self.clear_exportDeadLetters();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestWrapper {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestWrapper {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResponseWrapper {
+    // message fields
+    pub refId: i32,
+    // message oneof groups
+    pub message: ::std::option::Option<ResponseWrapper_oneof_message>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+#[derive(Clone,PartialEq)]
+pub enum ResponseWrapper_oneof_message {
+    enqueue(EnqueueResponse),
+    pop(PopResponse),
+    acknowledge(AcknowledgeResponse),
+    error(ErrorResponse),
+    authenticate(AuthenticateResponse),
+    health(HealthResponse),
+    acknowledgeMatching(AcknowledgeMatchingResponse),
+    failWithRetag(FailWithRetagResponse),
+    serverInfo(ServerInfoResponse),
+    fail(FailResponse),
+    diagnosticDump(DiagnosticDumpResponse),
+    beginEnqueue(BeginEnqueueResponse),
+    enqueueChunk(EnqueueChunkResponse),
+    commitEnqueue(CommitEnqueueResponse),
+    cancelPop(CancelPopResponse),
+    cancel(CancelResponse),
+    findByTag(FindByTagResponse),
+    listProcessing(ListProcessingResponse),
+    setCapabilityPaused(SetCapabilityPausedResponse),
+    subscribe(SubscribeResponse),
+    reserve(ReserveResponse),
+    confirmReservation(ConfirmReservationResponse),
+    decline(DeclineResponse),
+    enqueueBatch(EnqueueBatchResponse),
+    listQueued(ListQueuedResponse),
+    peek(PeekResponse),
+    stats(StatsResponse),
+    purge(PurgeResponse),
+    eventSubscribe(EventSubscribeResponse),
+    event(QueueEventResponse),
+    exportDeadLetters(ExportDeadLettersResponse),
+}
+
+impl ResponseWrapper {
+    pub fn new() -> ResponseWrapper {
+        ::std::default::Default::default()
+    }
+
+    // int32 refId = 10;
+
+    pub fn clear_refId(&mut self) {
+        self.refId = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_refId(&mut self, v: i32) {
+        self.refId = v;
+    }
+
+    pub fn get_refId(&self) -> i32 {
+        self.refId
+    }
+
+    // .EnqueueResponse enqueue = 1;
+
+    pub fn clear_enqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueue(&mut self, v: EnqueueResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueue(&mut self) -> &mut EnqueueResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(EnqueueResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueue(&mut self) -> EnqueueResponse {
+        if self.has_enqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueResponse::new()
+        }
+    }
+
+    pub fn get_enqueue(&self) -> &EnqueueResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(ref v)) => v,
+            _ => EnqueueResponse::default_instance(),
+        }
+    }
+
+    // .PopResponse pop = 2;
+
+    pub fn clear_pop(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_pop(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pop(&mut self, v: PopResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pop(&mut self) -> &mut PopResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(PopResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pop(&mut self) -> PopResponse {
+        if self.has_pop() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PopResponse::new()
+        }
+    }
+
+    pub fn get_pop(&self) -> &PopResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(ref v)) => v,
+            _ => PopResponse::default_instance(),
+        }
+    }
+
+    // .AcknowledgeResponse acknowledge = 3;
+
+    pub fn clear_acknowledge(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_acknowledge(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acknowledge(&mut self, v: AcknowledgeResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_acknowledge(&mut self) -> &mut AcknowledgeResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(AcknowledgeResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_acknowledge(&mut self) -> AcknowledgeResponse {
+        if self.has_acknowledge() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AcknowledgeResponse::new()
+        }
+    }
+
+    pub fn get_acknowledge(&self) -> &AcknowledgeResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(ref v)) => v,
+            _ => AcknowledgeResponse::default_instance(),
+        }
+    }
+
+    // .ErrorResponse error = 4;
+
+    pub fn clear_error(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_error(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ErrorResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_error(&mut self) -> &mut ErrorResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::error(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ErrorResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ErrorResponse {
+        if self.has_error() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::error(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ErrorResponse::new()
+        }
+    }
+
+    pub fn get_error(&self) -> &ErrorResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::error(ref v)) => v,
+            _ => ErrorResponse::default_instance(),
+        }
+    }
+
+    // .AuthenticateResponse authenticate = 6;
+
+    pub fn clear_authenticate(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_authenticate(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_authenticate(&mut self, v: AuthenticateResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_authenticate(&mut self) -> &mut AuthenticateResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(AuthenticateResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_authenticate(&mut self) -> AuthenticateResponse {
+        if self.has_authenticate() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AuthenticateResponse::new()
+        }
+    }
+
+    pub fn get_authenticate(&self) -> &AuthenticateResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(ref v)) => v,
+            _ => AuthenticateResponse::default_instance(),
+        }
+    }
+
+    // .HealthResponse health = 7;
+
+    pub fn clear_health(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::health(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: HealthResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::health(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_health(&mut self) -> &mut HealthResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::health(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::health(HealthResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::health(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_health(&mut self) -> HealthResponse {
+        if self.has_health() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::health(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            HealthResponse::new()
+        }
+    }
+
+    pub fn get_health(&self) -> &HealthResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::health(ref v)) => v,
+            _ => HealthResponse::default_instance(),
+        }
+    }
+
+    // .AcknowledgeMatchingResponse acknowledgeMatching = 8;
+
+    pub fn clear_acknowledgeMatching(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_acknowledgeMatching(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acknowledgeMatching(&mut self, v: AcknowledgeMatchingResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_acknowledgeMatching(&mut self) -> &mut AcknowledgeMatchingResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(AcknowledgeMatchingResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_acknowledgeMatching(&mut self) -> AcknowledgeMatchingResponse {
+        if self.has_acknowledgeMatching() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AcknowledgeMatchingResponse::new()
+        }
+    }
+
+    pub fn get_acknowledgeMatching(&self) -> &AcknowledgeMatchingResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(ref v)) => v,
+            _ => AcknowledgeMatchingResponse::default_instance(),
+        }
+    }
+
+    // .FailWithRetagResponse failWithRetag = 9;
+
+    pub fn clear_failWithRetag(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_failWithRetag(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failWithRetag(&mut self, v: FailWithRetagResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_failWithRetag(&mut self) -> &mut FailWithRetagResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(FailWithRetagResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_failWithRetag(&mut self) -> FailWithRetagResponse {
+        if self.has_failWithRetag() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FailWithRetagResponse::new()
+        }
+    }
+
+    pub fn get_failWithRetag(&self) -> &FailWithRetagResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(ref v)) => v,
+            _ => FailWithRetagResponse::default_instance(),
+        }
+    }
+
+    // .ServerInfoResponse serverInfo = 11;
+
+    pub fn clear_serverInfo(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_serverInfo(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_serverInfo(&mut self, v: ServerInfoResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_serverInfo(&mut self) -> &mut ServerInfoResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(ServerInfoResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_serverInfo(&mut self) -> ServerInfoResponse {
+        if self.has_serverInfo() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ServerInfoResponse::new()
+        }
+    }
+
+    pub fn get_serverInfo(&self) -> &ServerInfoResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(ref v)) => v,
+            _ => ServerInfoResponse::default_instance(),
+        }
+    }
+
+    // .FailResponse fail = 12;
+
+    pub fn clear_fail(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_fail(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fail(&mut self, v: FailResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fail(&mut self) -> &mut FailResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(FailResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_fail(&mut self) -> FailResponse {
+        if self.has_fail() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FailResponse::new()
+        }
+    }
+
+    pub fn get_fail(&self) -> &FailResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(ref v)) => v,
+            _ => FailResponse::default_instance(),
+        }
+    }
+
+    // .DiagnosticDumpResponse diagnosticDump = 13;
+
+    pub fn clear_diagnosticDump(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_diagnosticDump(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnosticDump(&mut self, v: DiagnosticDumpResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_diagnosticDump(&mut self) -> &mut DiagnosticDumpResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(DiagnosticDumpResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_diagnosticDump(&mut self) -> DiagnosticDumpResponse {
+        if self.has_diagnosticDump() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DiagnosticDumpResponse::new()
+        }
+    }
+
+    pub fn get_diagnosticDump(&self) -> &DiagnosticDumpResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(ref v)) => v,
+            _ => DiagnosticDumpResponse::default_instance(),
+        }
+    }
+
+    // .BeginEnqueueResponse beginEnqueue = 14;
+
+    pub fn clear_beginEnqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_beginEnqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_beginEnqueue(&mut self, v: BeginEnqueueResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_beginEnqueue(&mut self) -> &mut BeginEnqueueResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(BeginEnqueueResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_beginEnqueue(&mut self) -> BeginEnqueueResponse {
+        if self.has_beginEnqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            BeginEnqueueResponse::new()
+        }
+    }
+
+    pub fn get_beginEnqueue(&self) -> &BeginEnqueueResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(ref v)) => v,
+            _ => BeginEnqueueResponse::default_instance(),
+        }
+    }
+
+    // .EnqueueChunkResponse enqueueChunk = 15;
+
+    pub fn clear_enqueueChunk(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueueChunk(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueueChunk(&mut self, v: EnqueueChunkResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueueChunk(&mut self) -> &mut EnqueueChunkResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(EnqueueChunkResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueueChunk(&mut self) -> EnqueueChunkResponse {
+        if self.has_enqueueChunk() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueChunkResponse::new()
+        }
+    }
+
+    pub fn get_enqueueChunk(&self) -> &EnqueueChunkResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(ref v)) => v,
+            _ => EnqueueChunkResponse::default_instance(),
+        }
+    }
+
+    // .CommitEnqueueResponse commitEnqueue = 16;
+
+    pub fn clear_commitEnqueue(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_commitEnqueue(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commitEnqueue(&mut self, v: CommitEnqueueResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_commitEnqueue(&mut self) -> &mut CommitEnqueueResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(CommitEnqueueResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_commitEnqueue(&mut self) -> CommitEnqueueResponse {
+        if self.has_commitEnqueue() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CommitEnqueueResponse::new()
+        }
+    }
+
+    pub fn get_commitEnqueue(&self) -> &CommitEnqueueResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(ref v)) => v,
+            _ => CommitEnqueueResponse::default_instance(),
+        }
+    }
+
+    // .CancelPopResponse cancelPop = 17;
+
+    pub fn clear_cancelPop(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_cancelPop(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancelPop(&mut self, v: CancelPopResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cancelPop(&mut self) -> &mut CancelPopResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(CancelPopResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cancelPop(&mut self) -> CancelPopResponse {
+        if self.has_cancelPop() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CancelPopResponse::new()
+        }
+    }
+
+    pub fn get_cancelPop(&self) -> &CancelPopResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(ref v)) => v,
+            _ => CancelPopResponse::default_instance(),
+        }
+    }
+
+    // .CancelResponse cancel = 18;
+
+    pub fn clear_cancel(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_cancel(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancel(&mut self, v: CancelResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cancel(&mut self) -> &mut CancelResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(CancelResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cancel(&mut self) -> CancelResponse {
+        if self.has_cancel() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CancelResponse::new()
+        }
+    }
+
+    pub fn get_cancel(&self) -> &CancelResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(ref v)) => v,
+            _ => CancelResponse::default_instance(),
+        }
+    }
+
+    // .FindByTagResponse findByTag = 19;
+
+    pub fn clear_findByTag(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_findByTag(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_findByTag(&mut self, v: FindByTagResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_findByTag(&mut self) -> &mut FindByTagResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(FindByTagResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_findByTag(&mut self) -> FindByTagResponse {
+        if self.has_findByTag() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FindByTagResponse::new()
+        }
+    }
+
+    pub fn get_findByTag(&self) -> &FindByTagResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(ref v)) => v,
+            _ => FindByTagResponse::default_instance(),
+        }
+    }
+
+    // .ListProcessingResponse listProcessing = 20;
+
+    pub fn clear_listProcessing(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_listProcessing(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listProcessing(&mut self, v: ListProcessingResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listProcessing(&mut self) -> &mut ListProcessingResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(ListProcessingResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listProcessing(&mut self) -> ListProcessingResponse {
+        if self.has_listProcessing() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListProcessingResponse::new()
+        }
+    }
+
+    pub fn get_listProcessing(&self) -> &ListProcessingResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(ref v)) => v,
+            _ => ListProcessingResponse::default_instance(),
+        }
+    }
+
+    // .SetCapabilityPausedResponse setCapabilityPaused = 21;
+
+    pub fn clear_setCapabilityPaused(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_setCapabilityPaused(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setCapabilityPaused(&mut self, v: SetCapabilityPausedResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_setCapabilityPaused(&mut self) -> &mut SetCapabilityPausedResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(SetCapabilityPausedResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_setCapabilityPaused(&mut self) -> SetCapabilityPausedResponse {
+        if self.has_setCapabilityPaused() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetCapabilityPausedResponse::new()
+        }
+    }
+
+    pub fn get_setCapabilityPaused(&self) -> &SetCapabilityPausedResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(ref v)) => v,
+            _ => SetCapabilityPausedResponse::default_instance(),
+        }
+    }
+
+    // .SubscribeResponse subscribe = 22;
+
+    pub fn clear_subscribe(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_subscribe(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subscribe(&mut self, v: SubscribeResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subscribe(&mut self) -> &mut SubscribeResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(SubscribeResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_subscribe(&mut self) -> SubscribeResponse {
+        if self.has_subscribe() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SubscribeResponse::new()
+        }
+    }
+
+    pub fn get_subscribe(&self) -> &SubscribeResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(ref v)) => v,
+            _ => SubscribeResponse::default_instance(),
+        }
+    }
+
+    // .ReserveResponse reserve = 23;
+
+    pub fn clear_reserve(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_reserve(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reserve(&mut self, v: ReserveResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reserve(&mut self) -> &mut ReserveResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(ReserveResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_reserve(&mut self) -> ReserveResponse {
+        if self.has_reserve() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReserveResponse::new()
+        }
+    }
+
+    pub fn get_reserve(&self) -> &ReserveResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(ref v)) => v,
+            _ => ReserveResponse::default_instance(),
+        }
+    }
+
+    // .ConfirmReservationResponse confirmReservation = 24;
+
+    pub fn clear_confirmReservation(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_confirmReservation(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_confirmReservation(&mut self, v: ConfirmReservationResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_confirmReservation(&mut self) -> &mut ConfirmReservationResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(ConfirmReservationResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_confirmReservation(&mut self) -> ConfirmReservationResponse {
+        if self.has_confirmReservation() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ConfirmReservationResponse::new()
+        }
+    }
+
+    pub fn get_confirmReservation(&self) -> &ConfirmReservationResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(ref v)) => v,
+            _ => ConfirmReservationResponse::default_instance(),
+        }
+    }
+
+    // .DeclineResponse decline = 25;
+
+    pub fn clear_decline(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_decline(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decline(&mut self, v: DeclineResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decline(&mut self) -> &mut DeclineResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(DeclineResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_decline(&mut self) -> DeclineResponse {
+        if self.has_decline() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DeclineResponse::new()
+        }
+    }
+
+    pub fn get_decline(&self) -> &DeclineResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(ref v)) => v,
+            _ => DeclineResponse::default_instance(),
+        }
+    }
+
+    // .EnqueueBatchResponse enqueueBatch = 26;
+
+    pub fn clear_enqueueBatch(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_enqueueBatch(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enqueueBatch(&mut self, v: EnqueueBatchResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enqueueBatch(&mut self) -> &mut EnqueueBatchResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(EnqueueBatchResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enqueueBatch(&mut self) -> EnqueueBatchResponse {
+        if self.has_enqueueBatch() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EnqueueBatchResponse::new()
+        }
+    }
+
+    pub fn get_enqueueBatch(&self) -> &EnqueueBatchResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(ref v)) => v,
+            _ => EnqueueBatchResponse::default_instance(),
+        }
+    }
+
+    // .ListQueuedResponse listQueued = 27;
+
+    pub fn clear_listQueued(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_listQueued(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listQueued(&mut self, v: ListQueuedResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listQueued(&mut self) -> &mut ListQueuedResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(ListQueuedResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listQueued(&mut self) -> ListQueuedResponse {
+        if self.has_listQueued() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListQueuedResponse::new()
+        }
+    }
+
+    pub fn get_listQueued(&self) -> &ListQueuedResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(ref v)) => v,
+            _ => ListQueuedResponse::default_instance(),
+        }
+    }
+
+    // .PeekResponse peek = 28;
+
+    pub fn clear_peek(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_peek(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peek(&mut self, v: PeekResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_peek(&mut self) -> &mut PeekResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(PeekResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_peek(&mut self) -> PeekResponse {
+        if self.has_peek() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PeekResponse::new()
+        }
+    }
+
+    pub fn get_peek(&self) -> &PeekResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(ref v)) => v,
+            _ => PeekResponse::default_instance(),
+        }
+    }
+
+    // .StatsResponse stats = 29;
+
+    pub fn clear_stats(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_stats(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stats(&mut self, v: StatsResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stats(&mut self) -> &mut StatsResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(StatsResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_stats(&mut self) -> StatsResponse {
+        if self.has_stats() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            StatsResponse::new()
+        }
+    }
+
+    pub fn get_stats(&self) -> &StatsResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(ref v)) => v,
+            _ => StatsResponse::default_instance(),
+        }
+    }
+
+    // .PurgeResponse purge = 30;
+
+    pub fn clear_purge(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_purge(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_purge(&mut self, v: PurgeResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_purge(&mut self) -> &mut PurgeResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(PurgeResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_purge(&mut self) -> PurgeResponse {
+        if self.has_purge() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PurgeResponse::new()
+        }
+    }
+
+    pub fn get_purge(&self) -> &PurgeResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(ref v)) => v,
+            _ => PurgeResponse::default_instance(),
+        }
+    }
+
+    // .EventSubscribeResponse eventSubscribe = 31;
+
+    pub fn clear_eventSubscribe(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_eventSubscribe(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eventSubscribe(&mut self, v: EventSubscribeResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eventSubscribe(&mut self) -> &mut EventSubscribeResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(EventSubscribeResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_eventSubscribe(&mut self) -> EventSubscribeResponse {
+        if self.has_eventSubscribe() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            EventSubscribeResponse::new()
+        }
+    }
+
+    pub fn get_eventSubscribe(&self) -> &EventSubscribeResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(ref v)) => v,
+            _ => EventSubscribeResponse::default_instance(),
+        }
+    }
+
+    // .QueueEventResponse event = 32;
+
+    pub fn clear_event(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_event(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::event(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event(&mut self, v: QueueEventResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::event(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_event(&mut self) -> &mut QueueEventResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::event(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::event(QueueEventResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::event(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_event(&mut self) -> QueueEventResponse {
+        if self.has_event() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::event(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueueEventResponse::new()
+        }
+    }
+
+    pub fn get_event(&self) -> &QueueEventResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::event(ref v)) => v,
+            _ => QueueEventResponse::default_instance(),
+        }
+    }
+
+    // .ExportDeadLettersResponse exportDeadLetters = 33;
+
+    pub fn clear_exportDeadLetters(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_exportDeadLetters(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exportDeadLetters(&mut self, v: ExportDeadLettersResponse) {
+        self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exportDeadLetters(&mut self) -> &mut ExportDeadLettersResponse {
+        if let ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(ExportDeadLettersResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_exportDeadLetters(&mut self) -> ExportDeadLettersResponse {
+        if self.has_exportDeadLetters() {
+            match self.message.take() {
+                ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ExportDeadLettersResponse::new()
+        }
+    }
+
+    pub fn get_exportDeadLetters(&self) -> &ExportDeadLettersResponse {
+        match self.message {
+            ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(ref v)) => v,
+            _ => ExportDeadLettersResponse::default_instance(),
+        }
+    }
+}
+
+impl ::protobuf::Message for ResponseWrapper {
+    fn is_initialized(&self) -> bool {
+        if let Some(ResponseWrapper_oneof_message::enqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::pop(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::acknowledge(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
             }
         }
         if let Some(ResponseWrapper_oneof_message::error(ref v)) = self.message {
@@ -2446,6 +18336,136 @@ impl ::protobuf::Message for ResponseWrapper {
                 return false;
             }
         }
+        if let Some(ResponseWrapper_oneof_message::health(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::acknowledgeMatching(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::failWithRetag(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::serverInfo(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::fail(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::diagnosticDump(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::beginEnqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::enqueueChunk(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::commitEnqueue(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::cancelPop(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::cancel(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::findByTag(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::listProcessing(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::setCapabilityPaused(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::subscribe(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::reserve(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::confirmReservation(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::decline(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::enqueueBatch(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::listQueued(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::peek(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::stats(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::purge(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::eventSubscribe(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::event(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ResponseWrapper_oneof_message::exportDeadLetters(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -2457,111 +18477,501 @@ impl ::protobuf::Message for ResponseWrapper {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_int32()?;
-                    self.refId = tmp;
+                    let tmp = is.read_int32()?;
+                    self.refId = tmp;
+                },
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::health(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledgeMatching(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::failWithRetag(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::serverInfo(is.read_message()?));
                 },
-                1 => {
+                12 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueue(is.read_message()?));
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::fail(is.read_message()?));
                 },
-                2 => {
+                13 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::pop(is.read_message()?));
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::diagnosticDump(is.read_message()?));
                 },
-                3 => {
+                14 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::acknowledge(is.read_message()?));
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::beginEnqueue(is.read_message()?));
                 },
-                4 => {
+                15 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::error(is.read_message()?));
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueChunk(is.read_message()?));
                 },
-                6 => {
+                16 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::authenticate(is.read_message()?));
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::commitEnqueue(is.read_message()?));
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancelPop(is.read_message()?));
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::cancel(is.read_message()?));
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::findByTag(is.read_message()?));
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listProcessing(is.read_message()?));
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::setCapabilityPaused(is.read_message()?));
+                },
+                22 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::subscribe(is.read_message()?));
+                },
+                23 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::reserve(is.read_message()?));
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::confirmReservation(is.read_message()?));
+                },
+                25 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::decline(is.read_message()?));
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::enqueueBatch(is.read_message()?));
+                },
+                27 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::listQueued(is.read_message()?));
+                },
+                28 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::peek(is.read_message()?));
+                },
+                29 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::stats(is.read_message()?));
+                },
+                30 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::purge(is.read_message()?));
+                },
+                31 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::eventSubscribe(is.read_message()?));
+                },
+                32 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::event(is.read_message()?));
+                },
+                33 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(ResponseWrapper_oneof_message::exportDeadLetters(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.refId != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.refId, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &ResponseWrapper_oneof_message::enqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::pop(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::acknowledge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::error(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::authenticate(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::health(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::acknowledgeMatching(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::failWithRetag(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::serverInfo(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::fail(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::diagnosticDump(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::beginEnqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::enqueueChunk(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::commitEnqueue(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::cancelPop(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::cancel(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::findByTag(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::listProcessing(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::setCapabilityPaused(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::subscribe(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::reserve(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::confirmReservation(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::decline(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::enqueueBatch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::listQueued(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::peek(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::stats(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::purge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::eventSubscribe(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::event(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ResponseWrapper_oneof_message::exportDeadLetters(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.refId != 0 {
+            os.write_int32(10, self.refId)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &ResponseWrapper_oneof_message::enqueue(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::pop(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::acknowledge(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::error(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::authenticate(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::health(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::acknowledgeMatching(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::failWithRetag(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::serverInfo(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::fail(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::diagnosticDump(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::beginEnqueue(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::enqueueChunk(ref v) => {
+                    os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::commitEnqueue(ref v) => {
+                    os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::cancelPop(ref v) => {
+                    os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::cancel(ref v) => {
+                    os.write_tag(18, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::findByTag(ref v) => {
+                    os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::listProcessing(ref v) => {
+                    os.write_tag(20, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::setCapabilityPaused(ref v) => {
+                    os.write_tag(21, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                &ResponseWrapper_oneof_message::subscribe(ref v) => {
+                    os.write_tag(22, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if self.refId != 0 {
-            my_size += ::protobuf::rt::value_size(10, self.refId, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &ResponseWrapper_oneof_message::enqueue(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &ResponseWrapper_oneof_message::reserve(ref v) => {
+                    os.write_tag(23, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::pop(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &ResponseWrapper_oneof_message::confirmReservation(ref v) => {
+                    os.write_tag(24, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::acknowledge(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &ResponseWrapper_oneof_message::decline(ref v) => {
+                    os.write_tag(25, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::error(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &ResponseWrapper_oneof_message::enqueueBatch(ref v) => {
+                    os.write_tag(26, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::authenticate(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                &ResponseWrapper_oneof_message::listQueued(ref v) => {
+                    os.write_tag(27, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-            };
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.refId != 0 {
-            os.write_int32(10, self.refId)?;
-        }
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &ResponseWrapper_oneof_message::enqueue(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &ResponseWrapper_oneof_message::peek(ref v) => {
+                    os.write_tag(28, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::pop(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &ResponseWrapper_oneof_message::stats(ref v) => {
+                    os.write_tag(29, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::acknowledge(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &ResponseWrapper_oneof_message::purge(ref v) => {
+                    os.write_tag(30, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::error(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &ResponseWrapper_oneof_message::eventSubscribe(ref v) => {
+                    os.write_tag(31, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &ResponseWrapper_oneof_message::authenticate(ref v) => {
-                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                &ResponseWrapper_oneof_message::event(ref v) => {
+                    os.write_tag(32, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ResponseWrapper_oneof_message::exportDeadLetters(ref v) => {
+                    os.write_tag(33, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
@@ -2639,6 +19049,136 @@ impl ::protobuf::Message for ResponseWrapper {
                     ResponseWrapper::has_authenticate,
                     ResponseWrapper::get_authenticate,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, HealthResponse>(
+                    "health",
+                    ResponseWrapper::has_health,
+                    ResponseWrapper::get_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, AcknowledgeMatchingResponse>(
+                    "acknowledgeMatching",
+                    ResponseWrapper::has_acknowledgeMatching,
+                    ResponseWrapper::get_acknowledgeMatching,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FailWithRetagResponse>(
+                    "failWithRetag",
+                    ResponseWrapper::has_failWithRetag,
+                    ResponseWrapper::get_failWithRetag,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ServerInfoResponse>(
+                    "serverInfo",
+                    ResponseWrapper::has_serverInfo,
+                    ResponseWrapper::get_serverInfo,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FailResponse>(
+                    "fail",
+                    ResponseWrapper::has_fail,
+                    ResponseWrapper::get_fail,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DiagnosticDumpResponse>(
+                    "diagnosticDump",
+                    ResponseWrapper::has_diagnosticDump,
+                    ResponseWrapper::get_diagnosticDump,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, BeginEnqueueResponse>(
+                    "beginEnqueue",
+                    ResponseWrapper::has_beginEnqueue,
+                    ResponseWrapper::get_beginEnqueue,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueChunkResponse>(
+                    "enqueueChunk",
+                    ResponseWrapper::has_enqueueChunk,
+                    ResponseWrapper::get_enqueueChunk,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CommitEnqueueResponse>(
+                    "commitEnqueue",
+                    ResponseWrapper::has_commitEnqueue,
+                    ResponseWrapper::get_commitEnqueue,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CancelPopResponse>(
+                    "cancelPop",
+                    ResponseWrapper::has_cancelPop,
+                    ResponseWrapper::get_cancelPop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CancelResponse>(
+                    "cancel",
+                    ResponseWrapper::has_cancel,
+                    ResponseWrapper::get_cancel,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FindByTagResponse>(
+                    "findByTag",
+                    ResponseWrapper::has_findByTag,
+                    ResponseWrapper::get_findByTag,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListProcessingResponse>(
+                    "listProcessing",
+                    ResponseWrapper::has_listProcessing,
+                    ResponseWrapper::get_listProcessing,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SetCapabilityPausedResponse>(
+                    "setCapabilityPaused",
+                    ResponseWrapper::has_setCapabilityPaused,
+                    ResponseWrapper::get_setCapabilityPaused,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SubscribeResponse>(
+                    "subscribe",
+                    ResponseWrapper::has_subscribe,
+                    ResponseWrapper::get_subscribe,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ReserveResponse>(
+                    "reserve",
+                    ResponseWrapper::has_reserve,
+                    ResponseWrapper::get_reserve,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ConfirmReservationResponse>(
+                    "confirmReservation",
+                    ResponseWrapper::has_confirmReservation,
+                    ResponseWrapper::get_confirmReservation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DeclineResponse>(
+                    "decline",
+                    ResponseWrapper::has_decline,
+                    ResponseWrapper::get_decline,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EnqueueBatchResponse>(
+                    "enqueueBatch",
+                    ResponseWrapper::has_enqueueBatch,
+                    ResponseWrapper::get_enqueueBatch,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListQueuedResponse>(
+                    "listQueued",
+                    ResponseWrapper::has_listQueued,
+                    ResponseWrapper::get_listQueued,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PeekResponse>(
+                    "peek",
+                    ResponseWrapper::has_peek,
+                    ResponseWrapper::get_peek,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, StatsResponse>(
+                    "stats",
+                    ResponseWrapper::has_stats,
+                    ResponseWrapper::get_stats,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PurgeResponse>(
+                    "purge",
+                    ResponseWrapper::has_purge,
+                    ResponseWrapper::get_purge,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, EventSubscribeResponse>(
+                    "eventSubscribe",
+                    ResponseWrapper::has_eventSubscribe,
+                    ResponseWrapper::get_eventSubscribe,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueueEventResponse>(
+                    "event",
+                    ResponseWrapper::has_event,
+                    ResponseWrapper::get_event,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ExportDeadLettersResponse>(
+                    "exportDeadLetters",
+                    ResponseWrapper::has_exportDeadLetters,
+                    ResponseWrapper::get_exportDeadLetters,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ResponseWrapper>(
                     "ResponseWrapper",
                     fields,
@@ -2667,6 +19207,32 @@ impl ::protobuf::Clear for ResponseWrapper {
         self.clear_acknowledge();
         self.clear_error();
         self.clear_authenticate();
+        self.clear_health();
+        self.clear_acknowledgeMatching();
+        self.clear_failWithRetag();
+        self.clear_serverInfo();
+        self.clear_fail();
+        self.clear_diagnosticDump();
+        self.clear_beginEnqueue();
+        self.clear_enqueueChunk();
+        self.clear_commitEnqueue();
+        self.clear_cancelPop();
+        self.clear_cancel();
+        self.clear_findByTag();
+        self.clear_listProcessing();
+        self.clear_setCapabilityPaused();
+        self.clear_subscribe();
+        self.clear_reserve();
+        self.clear_confirmReservation();
+        self.clear_decline();
+        self.clear_enqueueBatch();
+        self.clear_listQueued();
+        self.clear_peek();
+        self.clear_stats();
+        self.clear_purge();
+        self.clear_eventSubscribe();
+        self.clear_event();
+        self.clear_exportDeadLetters();
         self.unknown_fields.clear();
     }
 }
@@ -2738,36 +19304,488 @@ impl ::protobuf::reflect::ProtobufValue for Priority {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum EnqueueStatus {
+    CREATED = 0,
+    ALREADY_EXISTS = 1,
+}
+
+impl ::protobuf::ProtobufEnum for EnqueueStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EnqueueStatus> {
+        match value {
+            0 => ::std::option::Option::Some(EnqueueStatus::CREATED),
+            1 => ::std::option::Option::Some(EnqueueStatus::ALREADY_EXISTS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [EnqueueStatus] = &[
+            EnqueueStatus::CREATED,
+            EnqueueStatus::ALREADY_EXISTS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("EnqueueStatus", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for EnqueueStatus {
+}
+
+impl ::std::default::Default for EnqueueStatus {
+    fn default() -> Self {
+        EnqueueStatus::CREATED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EnqueueStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum TagMatchMode {
+    ALL = 0,
+    ANY = 1,
+}
+
+impl ::protobuf::ProtobufEnum for TagMatchMode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TagMatchMode> {
+        match value {
+            0 => ::std::option::Option::Some(TagMatchMode::ALL),
+            1 => ::std::option::Option::Some(TagMatchMode::ANY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [TagMatchMode] = &[
+            TagMatchMode::ALL,
+            TagMatchMode::ANY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("TagMatchMode", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for TagMatchMode {
+}
+
+impl ::std::default::Default for TagMatchMode {
+    fn default() -> Self {
+        TagMatchMode::ALL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TagMatchMode {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum EventKind {
+    ENQUEUED = 0,
+    POPPED = 1,
+    ACKNOWLEDGED = 2,
+    FAILED = 3,
+    DEAD_LETTERED = 4,
+}
+
+impl ::protobuf::ProtobufEnum for EventKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EventKind> {
+        match value {
+            0 => ::std::option::Option::Some(EventKind::ENQUEUED),
+            1 => ::std::option::Option::Some(EventKind::POPPED),
+            2 => ::std::option::Option::Some(EventKind::ACKNOWLEDGED),
+            3 => ::std::option::Option::Some(EventKind::FAILED),
+            4 => ::std::option::Option::Some(EventKind::DEAD_LETTERED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [EventKind] = &[
+            EventKind::ENQUEUED,
+            EventKind::POPPED,
+            EventKind::ACKNOWLEDGED,
+            EventKind::FAILED,
+            EventKind::DEAD_LETTERED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("EventKind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for EventKind {
+}
+
+impl ::std::default::Default for EventKind {
+    fn default() -> Self {
+        EventKind::ENQUEUED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ErrorCode {
+    UNKNOWN = 0,
+    STORAGE_FULL = 1,
+    BAD_REQUEST = 2,
+    NOT_FOUND = 3,
+}
+
+impl ::protobuf::ProtobufEnum for ErrorCode {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ErrorCode> {
+        match value {
+            0 => ::std::option::Option::Some(ErrorCode::UNKNOWN),
+            1 => ::std::option::Option::Some(ErrorCode::STORAGE_FULL),
+            2 => ::std::option::Option::Some(ErrorCode::BAD_REQUEST),
+            3 => ::std::option::Option::Some(ErrorCode::NOT_FOUND),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ErrorCode] = &[
+            ErrorCode::UNKNOWN,
+            ErrorCode::STORAGE_FULL,
+            ErrorCode::BAD_REQUEST,
+            ErrorCode::NOT_FOUND,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("ErrorCode", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for ErrorCode {
+}
+
+impl ::std::default::Default for ErrorCode {
+    fn default() -> Self {
+        ErrorCode::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorCode {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x15src/proto/queue.proto\"M\n\x13AuthenticateRequest\x12\x1a\n\x08use\
+    \n\x15src/proto/queue.proto\"q\n\x13AuthenticateRequest\x12\x1a\n\x08use\
     rname\x18\x01\x20\x01(\tR\x08username\x12\x1a\n\x08password\x18\x02\x20\
-    \x01(\tR\x08password\"0\n\x14AuthenticateResponse\x12\x18\n\x07success\
-    \x18\x01\x20\x01(\x08R\x07success\"\x85\x01\n\x0eEnqueueRequest\x12\x18\
-    \n\x07message\x18\x01\x20\x01(\x0cR\x07message\x12%\n\x08priority\x18\
-    \x02\x20\x01(\x0e2\t.PriorityR\x08priority\x122\n\x14requiredCapabilitie\
-    s\x18\x03\x20\x03(\tR\x14requiredCapabilities\"!\n\x0fEnqueueResponse\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\"j\n\nPopRequest\x124\n\x15av\
-    ailableCapabilities\x18\x01\x20\x03(\tR\x15availableCapabilities\x12&\n\
-    \x0ewaitForMessage\x18\x02\x20\x01(\x08R\x0ewaitForMessage\"U\n\x0bPopRe\
-    sponse\x12\x1c\n\thadResult\x18\x03\x20\x01(\x08R\thadResult\x12\x18\n\
-    \x07message\x18\x01\x20\x01(\x0cR\x07message\x12\x0e\n\x02id\x18\x02\x20\
-    \x01(\tR\x02id\"$\n\x12AcknowledgeRequest\x12\x0e\n\x02id\x18\x01\x20\
-    \x01(\tR\x02id\"\x15\n\x13AcknowledgeResponse\")\n\rErrorResponse\x12\
-    \x18\n\x07message\x18\x01\x20\x01(\tR\x07message\"\xf4\x01\n\x0eRequestW\
-    rapper\x12\x14\n\x05refId\x18\n\x20\x01(\x05R\x05refId\x12+\n\x07enqueue\
-    \x18\x01\x20\x01(\x0b2\x0f.EnqueueRequestH\0R\x07enqueue\x12\x1f\n\x03po\
-    p\x18\x02\x20\x01(\x0b2\x0b.PopRequestH\0R\x03pop\x127\n\x0backnowledge\
-    \x18\x03\x20\x01(\x0b2\x13.AcknowledgeRequestH\0R\x0backnowledge\x12:\n\
-    \x0cauthenticate\x18\x06\x20\x01(\x0b2\x14.AuthenticateRequestH\0R\x0cau\
-    thenticateB\t\n\x07message\"\xa1\x02\n\x0fResponseWrapper\x12\x14\n\x05r\
-    efId\x18\n\x20\x01(\x05R\x05refId\x12,\n\x07enqueue\x18\x01\x20\x01(\x0b\
-    2\x10.EnqueueResponseH\0R\x07enqueue\x12\x20\n\x03pop\x18\x02\x20\x01(\
-    \x0b2\x0c.PopResponseH\0R\x03pop\x128\n\x0backnowledge\x18\x03\x20\x01(\
-    \x0b2\x14.AcknowledgeResponseH\0R\x0backnowledge\x12&\n\x05error\x18\x04\
-    \x20\x01(\x0b2\x0e.ErrorResponseH\0R\x05error\x12;\n\x0cauthenticate\x18\
-    \x06\x20\x01(\x0b2\x15.AuthenticateResponseH\0R\x0cauthenticateB\t\n\x07\
-    message*\x1d\n\x08Priority\x12\x07\n\x03LOW\x10\0\x12\x08\n\x04HIGH\x10\
-    \x01B\x13Z\x07brqueue\xaa\x02\x07brqueueb\x06proto3\
+    \x01(\tR\x08password\x12\"\n\x0csessionToken\x18\x03\x20\x01(\tR\x0csess\
+    ionToken\"T\n\x14AuthenticateResponse\x12\x18\n\x07success\x18\x01\x20\
+    \x01(\x08R\x07success\x12\"\n\x0csessionToken\x18\x02\x20\x01(\tR\x0cses\
+    sionToken\"\xe1\x02\n\x0eEnqueueRequest\x12\x18\n\x07message\x18\x01\x20\
+    \x01(\x0cR\x07message\x12%\n\x08priority\x18\x02\x20\x01(\x0e2\t.Priorit\
+    yR\x08priority\x122\n\x14requiredCapabilities\x18\x03\x20\x03(\tR\x14req\
+    uiredCapabilities\x12,\n\x11delayMilliseconds\x18\x04\x20\x01(\x04R\x11d\
+    elayMilliseconds\x12\x20\n\x0bavailableAt\x18\x05\x20\x01(\tR\x0bavailab\
+    leAt\x12&\n\x0eidempotencyKey\x18\x06\x20\x01(\tR\x0eidempotencyKey\x12\
+    \x1c\n\tqueueName\x18\x07\x20\x01(\tR\tqueueName\x12\x1e\n\nttlSeconds\
+    \x18\x08\x20\x01(\x04R\nttlSeconds\x12$\n\rpriorityLevel\x18\t\x20\x01(\
+    \rR\rpriorityLevel\"I\n\x0fEnqueueResponse\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\tR\x02id\x12&\n\x06status\x18\x02\x20\x01(\x0e2\x0e.EnqueueStatusR\
+    \x06status\"T\n\x13EnqueueBatchRequest\x12%\n\x05items\x18\x01\x20\x03(\
+    \x0b2\x0f.EnqueueRequestR\x05items\x12\x16\n\x06atomic\x18\x02\x20\x01(\
+    \x08R\x06atomic\"b\n\x12EnqueueBatchResult\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\tR\x02id\x12&\n\x06status\x18\x02\x20\x01(\x0e2\x0e.EnqueueStatusR\
+    \x06status\x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05error\"E\n\x14Enque\
+    ueBatchResponse\x12-\n\x07results\x18\x01\x20\x03(\x0b2\x13.EnqueueBatch\
+    ResultR\x07results\"\x86\x02\n\x13BeginEnqueueRequest\x12%\n\x08priority\
+    \x18\x01\x20\x01(\x0e2\t.PriorityR\x08priority\x122\n\x14requiredCapabil\
+    ities\x18\x02\x20\x03(\tR\x14requiredCapabilities\x12,\n\x11delayMillise\
+    conds\x18\x03\x20\x01(\x04R\x11delayMilliseconds\x12\x20\n\x0bavailableA\
+    t\x18\x04\x20\x01(\tR\x0bavailableAt\x12\x1e\n\nttlSeconds\x18\x05\x20\
+    \x01(\x04R\nttlSeconds\x12$\n\rpriorityLevel\x18\x06\x20\x01(\rR\rpriori\
+    tyLevel\"6\n\x14BeginEnqueueResponse\x12\x1e\n\ntransferId\x18\x01\x20\
+    \x01(\tR\ntransferId\"a\n\x13EnqueueChunkRequest\x12\x1e\n\ntransferId\
+    \x18\x01\x20\x01(\tR\ntransferId\x12\x16\n\x06offset\x18\x02\x20\x01(\
+    \x04R\x06offset\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\"2\n\x14\
+    EnqueueChunkResponse\x12\x1a\n\x08received\x18\x01\x20\x01(\x04R\x08rece\
+    ived\"T\n\x14CommitEnqueueRequest\x12\x1e\n\ntransferId\x18\x01\x20\x01(\
+    \tR\ntransferId\x12\x1c\n\tqueueName\x18\x02\x20\x01(\tR\tqueueName\"'\n\
+    \x15CommitEnqueueResponse\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\"\
+    \xce\x01\n\nPopRequest\x124\n\x15availableCapabilities\x18\x01\x20\x03(\
+    \tR\x15availableCapabilities\x12&\n\x0ewaitForMessage\x18\x02\x20\x01(\
+    \x08R\x0ewaitForMessage\x12D\n\x1dvisibilityTimeoutMilliseconds\x18\x03\
+    \x20\x01(\x04R\x1dvisibilityTimeoutMilliseconds\x12\x1c\n\tqueueName\x18\
+    \x04\x20\x01(\tR\tqueueName\"\x85\x04\n\x0bPopResponse\x12\x1c\n\thadRes\
+    ult\x18\x03\x20\x01(\x08R\thadResult\x12\x18\n\x07message\x18\x01\x20\
+    \x01(\x0cR\x07message\x12\x0e\n\x02id\x18\x02\x20\x01(\tR\x02id\x122\n\
+    \x14hasUnmatchedMessages\x18\x04\x20\x01(\x08R\x14hasUnmatchedMessages\
+    \x12\x1c\n\tcancelled\x18\x05\x20\x01(\x08R\tcancelled\x12\x1e\n\nenqueu\
+    edBy\x18\x06\x20\x01(\tR\nenqueuedBy\x12\"\n\x0cbackpressure\x18\x07\x20\
+    \x01(\x08R\x0cbackpressure\x12\x1a\n\x08sequence\x18\x08\x20\x01(\x04R\
+    \x08sequence\x12\x20\n\x0brateLimited\x18\t\x20\x01(\x08R\x0brateLimited\
+    \x12\x1a\n\x08notReady\x18\n\x20\x01(\x08R\x08notReady\x12D\n\x1dvisibil\
+    ityTimeoutMilliseconds\x18\x0b\x20\x01(\x04R\x1dvisibilityTimeoutMillise\
+    conds\x122\n\x14requiredCapabilities\x18\x0c\x20\x03(\tR\x14requiredCapa\
+    bilities\x12D\n\x1drequiredCapabilitiesTruncated\x18\r\x20\x01(\x08R\x1d\
+    requiredCapabilitiesTruncated\"\x82\x01\n\x10SubscribeRequest\x124\n\x15\
+    availableCapabilities\x18\x01\x20\x03(\tR\x15availableCapabilities\x12\
+    \x1a\n\x08prefetch\x18\x02\x20\x01(\rR\x08prefetch\x12\x1c\n\tqueueName\
+    \x18\x03\x20\x01(\tR\tqueueName\"\x13\n\x11SubscribeResponse\"\x9e\x01\n\
+    \x0eReserveRequest\x124\n\x15availableCapabilities\x18\x01\x20\x03(\tR\
+    \x15availableCapabilities\x128\n\x17reservationMilliseconds\x18\x02\x20\
+    \x01(\x04R\x17reservationMilliseconds\x12\x1c\n\tqueueName\x18\x03\x20\
+    \x01(\tR\tqueueName\"X\n\x0fReserveResponse\x12\x1c\n\thadResult\x18\x01\
+    \x20\x01(\x08R\thadResult\x12'\n\x07message\x18\x02\x20\x01(\x0b2\r.Foun\
+    dMessageR\x07message\"I\n\x19ConfirmReservationRequest\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\tR\x02id\x12\x1c\n\tqueueName\x18\x02\x20\x01(\tR\tque\
+    ueName\":\n\x1aConfirmReservationResponse\x12\x1c\n\tconfirmed\x18\x01\
+    \x20\x01(\x08R\tconfirmed\"6\n\x10CancelPopRequest\x12\"\n\x0csessionTok\
+    en\x18\x01\x20\x01(\tR\x0csessionToken\"1\n\x11CancelPopResponse\x12\x1c\
+    \n\tcancelled\x18\x01\x20\x01(\x08R\tcancelled\"=\n\rCancelRequest\x12\
+    \x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x1c\n\tqueueName\x18\x02\x20\
+    \x01(\tR\tqueueName\".\n\x0eCancelResponse\x12\x1c\n\tcancelled\x18\x01\
+    \x20\x01(\x08R\tcancelled\"$\n\x12AcknowledgeRequest\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\"A\n\x13AcknowledgeResponse\x12*\n\x10alreadyComp\
+    leted\x18\x01\x20\x01(\x08R\x10alreadyCompleted\"l\n\x0bFailRequest\x12\
+    \x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12&\n\x0echangePriority\x18\x02\
+    \x20\x01(\x08R\x0echangePriority\x12%\n\x08priority\x18\x03\x20\x01(\x0e\
+    2\t.PriorityR\x08priority\"\x0e\n\x0cFailResponse\"\x20\n\x0eDeclineRequ\
+    est\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\"\x11\n\x0fDeclineResponse\
+    \"Z\n\x14FailWithRetagRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\
+    \x122\n\x14requiredCapabilities\x18\x02\x20\x03(\tR\x14requiredCapabilit\
+    ies\"\x17\n\x15FailWithRetagResponse\"\xa2\x01\n\x1aAcknowledgeMatchingR\
+    equest\x12.\n\x12requiredCapability\x18\x01\x20\x01(\tR\x12requiredCapab\
+    ility\x126\n\x16minimumAgeMilliseconds\x18\x02\x20\x01(\x04R\x16minimumA\
+    geMilliseconds\x12\x1c\n\tqueueName\x18\x03\x20\x01(\tR\tqueueName\"3\n\
+    \x1bAcknowledgeMatchingResponse\x12\x14\n\x05count\x18\x01\x20\x01(\x04R\
+    \x05count\"\x9f\x01\n\x10FindByTagRequest\x12\x12\n\x04tags\x18\x01\x20\
+    \x03(\tR\x04tags\x12+\n\tmatchMode\x18\x02\x20\x01(\x0e2\r.TagMatchModeR\
+    \tmatchMode\x12\x16\n\x06offset\x18\x03\x20\x01(\x04R\x06offset\x12\x14\
+    \n\x05limit\x18\x04\x20\x01(\x04R\x05limit\x12\x1c\n\tqueueName\x18\x05\
+    \x20\x01(\tR\tqueueName\"\x9f\x02\n\x0cFoundMessage\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\x12\x18\n\x07message\x18\x02\x20\x01(\x0cR\x07mes\
+    sage\x12%\n\x08priority\x18\x03\x20\x01(\x0e2\t.PriorityR\x08priority\
+    \x122\n\x14requiredCapabilities\x18\x04\x20\x03(\tR\x14requiredCapabilit\
+    ies\x12\x1e\n\nenqueuedBy\x18\x05\x20\x01(\tR\nenqueuedBy\x12D\n\x1drequ\
+    iredCapabilitiesTruncated\x18\x06\x20\x01(\x08R\x1drequiredCapabilitiesT\
+    runcated\x12$\n\rpriorityLevel\x18\x07\x20\x01(\rR\rpriorityLevel\"T\n\
+    \x11FindByTagResponse\x12)\n\x08messages\x18\x01\x20\x03(\x0b2\r.FoundMe\
+    ssageR\x08messages\x12\x14\n\x05total\x18\x02\x20\x01(\x04R\x05total\"\
+    \xac\x01\n\x0bPeekRequest\x124\n\x15availableCapabilities\x18\x01\x20\
+    \x03(\tR\x15availableCapabilities\x12\"\n\x0conlyPriority\x18\x02\x20\
+    \x01(\x08R\x0conlyPriority\x12%\n\x08priority\x18\x03\x20\x01(\x0e2\t.Pr\
+    iorityR\x08priority\x12\x1c\n\tqueueName\x18\x04\x20\x01(\tR\tqueueName\
+    \"U\n\x0cPeekResponse\x12\x1c\n\thadResult\x18\x01\x20\x01(\x08R\thadRes\
+    ult\x12'\n\x07message\x18\x02\x20\x01(\x0b2\r.FoundMessageR\x07message\"\
+    _\n\x11ListQueuedRequest\x12\x16\n\x06cursor\x18\x01\x20\x01(\tR\x06curs\
+    or\x12\x14\n\x05limit\x18\x02\x20\x01(\x04R\x05limit\x12\x1c\n\tqueueNam\
+    e\x18\x03\x20\x01(\tR\tqueueName\"_\n\x12ListQueuedResponse\x12)\n\x08me\
+    ssages\x18\x01\x20\x03(\x0b2\r.FoundMessageR\x08messages\x12\x1e\n\nnext\
+    Cursor\x18\x02\x20\x01(\tR\nnextCursor\"5\n\x15ListProcessingRequest\x12\
+    \x1c\n\tqueueName\x18\x01\x20\x01(\tR\tqueueName\"\xcc\x02\n\x11Processi\
+    ngMessage\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12%\n\x08priority\
+    \x18\x02\x20\x01(\x0e2\t.PriorityR\x08priority\x122\n\x14requiredCapabil\
+    ities\x18\x03\x20\x03(\tR\x14requiredCapabilities\x12(\n\x0fageMilliseco\
+    nds\x18\x04\x20\x01(\x04R\x0fageMilliseconds\x12\x16\n\x06worker\x18\x05\
+    \x20\x01(\tR\x06worker\x12\x1e\n\nenqueuedBy\x18\x06\x20\x01(\tR\nenqueu\
+    edBy\x12D\n\x1drequiredCapabilitiesTruncated\x18\x07\x20\x01(\x08R\x1dre\
+    quiredCapabilitiesTruncated\x12$\n\rpriorityLevel\x18\x08\x20\x01(\rR\rp\
+    riorityLevel\"H\n\x16ListProcessingResponse\x12.\n\x08messages\x18\x01\
+    \x20\x03(\x0b2\x12.ProcessingMessageR\x08messages\"T\n\x1aSetCapabilityP\
+    ausedRequest\x12\x1e\n\ncapability\x18\x01\x20\x01(\tR\ncapability\x12\
+    \x16\n\x06paused\x18\x02\x20\x01(\x08R\x06paused\"M\n\x1bSetCapabilityPa\
+    usedResponse\x12.\n\x12pausedCapabilities\x18\x01\x20\x03(\tR\x12pausedC\
+    apabilities\"\x0f\n\rHealthRequest\"T\n\x0eLevelScanDepth\x12$\n\rpriori\
+    tyLevel\x18\x01\x20\x01(\rR\rpriorityLevel\x12\x1c\n\tscanDepth\x18\x02\
+    \x20\x01(\x01R\tscanDepth\"\x83\x02\n\x0eHealthResponse\x124\n\x15highPr\
+    iorityScanDepth\x18\x01\x20\x01(\x01R\x15highPriorityScanDepth\x122\n\
+    \x14lowPriorityScanDepth\x18\x02\x20\x01(\x01R\x14lowPriorityScanDepth\
+    \x124\n\x15estimatedDrainSeconds\x18\x03\x20\x01(\x01R\x15estimatedDrain\
+    Seconds\x12\x14\n\x05ready\x18\x04\x20\x01(\x08R\x05ready\x12;\n\x10scan\
+    DepthByLevel\x18\x05\x20\x03(\x0b2\x0f.LevelScanDepthR\x10scanDepthByLev\
+    el\",\n\x0cStatsRequest\x12\x1c\n\tqueueName\x18\x01\x20\x01(\tR\tqueueN\
+    ame\"J\n\x0cPendingCount\x12$\n\rpriorityLevel\x18\x01\x20\x01(\rR\rprio\
+    rityLevel\x12\x14\n\x05count\x18\x02\x20\x01(\x04R\x05count\"\xac\x02\n\
+    \rStatsResponse\x12'\n\x07pending\x18\x01\x20\x03(\x0b2\r.PendingCountR\
+    \x07pending\x12\x16\n\x06queued\x18\x02\x20\x01(\x04R\x06queued\x12\x1e\
+    \n\nprocessing\x18\x03\x20\x01(\x04R\nprocessing\x12\"\n\x0chighPriority\
+    \x18\x04\x20\x01(\x04R\x0chighPriority\x12\x20\n\x0blowPriority\x18\x05\
+    \x20\x01(\x04R\x0blowPriority\x12\x1a\n\x08enqueued\x18\x06\x20\x01(\x04\
+    R\x08enqueued\x12\"\n\x0cacknowledged\x18\x07\x20\x01(\x04R\x0cacknowled\
+    ged\x124\n\x15estimatedDrainSeconds\x18\x08\x20\x01(\x01R\x15estimatedDr\
+    ainSeconds\",\n\x0cPurgeRequest\x12\x1c\n\tqueueName\x18\x01\x20\x01(\tR\
+    \tqueueName\")\n\rPurgeResponse\x12\x18\n\x07removed\x18\x01\x20\x01(\
+    \x04R\x07removed\"N\n\x18ExportDeadLettersRequest\x12\x1c\n\tqueueName\
+    \x18\x01\x20\x01(\tR\tqueueName\x12\x14\n\x05clear\x18\x02\x20\x01(\x08R\
+    \x05clear\"K\n\x19ExportDeadLettersResponse\x12\x1a\n\x08exported\x18\
+    \x01\x20\x01(\x04R\x08exported\x12\x12\n\x04json\x18\x02\x20\x01(\tR\x04\
+    json\"Q\n\x15EventSubscribeRequest\x12\x1c\n\tqueueName\x18\x01\x20\x01(\
+    \tR\tqueueName\x12\x1a\n\x08prefetch\x18\x02\x20\x01(\rR\x08prefetch\"\
+    \x18\n\x16EventSubscribeResponse\"\x9a\x02\n\x12QueueEventResponse\x12\
+    \x1e\n\x04kind\x18\x01\x20\x01(\x0e2\n.EventKindR\x04kind\x12\x0e\n\x02i\
+    d\x18\x02\x20\x01(\tR\x02id\x12\x20\n\x0bhasPriority\x18\x03\x20\x01(\
+    \x08R\x0bhasPriority\x12$\n\rpriorityLevel\x18\x04\x20\x01(\rR\rpriority\
+    Level\x122\n\x14requiredCapabilities\x18\x05\x20\x03(\tR\x14requiredCapa\
+    bilities\x124\n\x15timestampMilliseconds\x18\x06\x20\x01(\x04R\x15timest\
+    ampMilliseconds\x12\"\n\x0cmissedEvents\x18\x07\x20\x01(\x04R\x0cmissedE\
+    vents\"\x13\n\x11ServerInfoRequest\"\x95\x02\n\x12ServerInfoResponse\x12\
+    .\n\x12uptimeMilliseconds\x18\x01\x20\x01(\x04R\x12uptimeMilliseconds\
+    \x12\x18\n\x07version\x18\x02\x20\x01(\tR\x07version\x12\x1c\n\tgitCommi\
+    t\x18\x03\x20\x01(\tR\tgitCommit\x12=\n\x10storageFileSizes\x18\x04\x20\
+    \x01(\x0b2\x11.StorageFileSizesR\x10storageFileSizes\x12$\n\rpriorityBan\
+    ds\x18\x05\x20\x03(\tR\rpriorityBands\x122\n\x14defaultPriorityLevel\x18\
+    \x06\x20\x01(\rR\x14defaultPriorityLevel\"\x94\x01\n\x10StorageFileSizes\
+    \x12,\n\x11highPriorityBytes\x18\x01\x20\x01(\x04R\x11highPriorityBytes\
+    \x12*\n\x10lowPriorityBytes\x18\x02\x20\x01(\x04R\x10lowPriorityBytes\
+    \x12&\n\x0ecompletedBytes\x18\x03\x20\x01(\x04R\x0ecompletedBytes\"\x17\
+    \n\x15DiagnosticDumpRequest\",\n\x16DiagnosticDumpResponse\x12\x12\n\x04\
+    json\x18\x01\x20\x01(\tR\x04json\"I\n\rErrorResponse\x12\x18\n\x07messag\
+    e\x18\x01\x20\x01(\tR\x07message\x12\x1e\n\x04code\x18\x02\x20\x01(\x0e2\
+    \n.ErrorCodeR\x04code\"\xf0\x0c\n\x0eRequestWrapper\x12\x14\n\x05refId\
+    \x18\n\x20\x01(\x05R\x05refId\x12+\n\x07enqueue\x18\x01\x20\x01(\x0b2\
+    \x0f.EnqueueRequestH\0R\x07enqueue\x12\x1f\n\x03pop\x18\x02\x20\x01(\x0b\
+    2\x0b.PopRequestH\0R\x03pop\x127\n\x0backnowledge\x18\x03\x20\x01(\x0b2\
+    \x13.AcknowledgeRequestH\0R\x0backnowledge\x12:\n\x0cauthenticate\x18\
+    \x06\x20\x01(\x0b2\x14.AuthenticateRequestH\0R\x0cauthenticate\x12(\n\
+    \x06health\x18\x07\x20\x01(\x0b2\x0e.HealthRequestH\0R\x06health\x12O\n\
+    \x13acknowledgeMatching\x18\x08\x20\x01(\x0b2\x1b.AcknowledgeMatchingReq\
+    uestH\0R\x13acknowledgeMatching\x12=\n\rfailWithRetag\x18\t\x20\x01(\x0b\
+    2\x15.FailWithRetagRequestH\0R\rfailWithRetag\x124\n\nserverInfo\x18\x0b\
+    \x20\x01(\x0b2\x12.ServerInfoRequestH\0R\nserverInfo\x12\"\n\x04fail\x18\
+    \x0c\x20\x01(\x0b2\x0c.FailRequestH\0R\x04fail\x12@\n\x0ediagnosticDump\
+    \x18\r\x20\x01(\x0b2\x16.DiagnosticDumpRequestH\0R\x0ediagnosticDump\x12\
+    :\n\x0cbeginEnqueue\x18\x0e\x20\x01(\x0b2\x14.BeginEnqueueRequestH\0R\
+    \x0cbeginEnqueue\x12:\n\x0cenqueueChunk\x18\x0f\x20\x01(\x0b2\x14.Enqueu\
+    eChunkRequestH\0R\x0cenqueueChunk\x12=\n\rcommitEnqueue\x18\x10\x20\x01(\
+    \x0b2\x15.CommitEnqueueRequestH\0R\rcommitEnqueue\x121\n\tcancelPop\x18\
+    \x11\x20\x01(\x0b2\x11.CancelPopRequestH\0R\tcancelPop\x12(\n\x06cancel\
+    \x18\x12\x20\x01(\x0b2\x0e.CancelRequestH\0R\x06cancel\x121\n\tfindByTag\
+    \x18\x13\x20\x01(\x0b2\x11.FindByTagRequestH\0R\tfindByTag\x12@\n\x0elis\
+    tProcessing\x18\x14\x20\x01(\x0b2\x16.ListProcessingRequestH\0R\x0elistP\
+    rocessing\x12O\n\x13setCapabilityPaused\x18\x15\x20\x01(\x0b2\x1b.SetCap\
+    abilityPausedRequestH\0R\x13setCapabilityPaused\x121\n\tsubscribe\x18\
+    \x16\x20\x01(\x0b2\x11.SubscribeRequestH\0R\tsubscribe\x12+\n\x07reserve\
+    \x18\x17\x20\x01(\x0b2\x0f.ReserveRequestH\0R\x07reserve\x12L\n\x12confi\
+    rmReservation\x18\x18\x20\x01(\x0b2\x1a.ConfirmReservationRequestH\0R\
+    \x12confirmReservation\x12+\n\x07decline\x18\x19\x20\x01(\x0b2\x0f.Decli\
+    neRequestH\0R\x07decline\x12:\n\x0cenqueueBatch\x18\x1a\x20\x01(\x0b2\
+    \x14.EnqueueBatchRequestH\0R\x0cenqueueBatch\x124\n\nlistQueued\x18\x1b\
+    \x20\x01(\x0b2\x12.ListQueuedRequestH\0R\nlistQueued\x12\"\n\x04peek\x18\
+    \x1c\x20\x01(\x0b2\x0c.PeekRequestH\0R\x04peek\x12%\n\x05stats\x18\x1d\
+    \x20\x01(\x0b2\r.StatsRequestH\0R\x05stats\x12%\n\x05purge\x18\x1e\x20\
+    \x01(\x0b2\r.PurgeRequestH\0R\x05purge\x12@\n\x0eeventSubscribe\x18\x1f\
+    \x20\x01(\x0b2\x16.EventSubscribeRequestH\0R\x0eeventSubscribe\x12I\n\
+    \x11exportDeadLetters\x18\x20\x20\x01(\x0b2\x19.ExportDeadLettersRequest\
+    H\0R\x11exportDeadLettersB\t\n\x07message\"\xe3\r\n\x0fResponseWrapper\
+    \x12\x14\n\x05refId\x18\n\x20\x01(\x05R\x05refId\x12,\n\x07enqueue\x18\
+    \x01\x20\x01(\x0b2\x10.EnqueueResponseH\0R\x07enqueue\x12\x20\n\x03pop\
+    \x18\x02\x20\x01(\x0b2\x0c.PopResponseH\0R\x03pop\x128\n\x0backnowledge\
+    \x18\x03\x20\x01(\x0b2\x14.AcknowledgeResponseH\0R\x0backnowledge\x12&\n\
+    \x05error\x18\x04\x20\x01(\x0b2\x0e.ErrorResponseH\0R\x05error\x12;\n\
+    \x0cauthenticate\x18\x06\x20\x01(\x0b2\x15.AuthenticateResponseH\0R\x0ca\
+    uthenticate\x12)\n\x06health\x18\x07\x20\x01(\x0b2\x0f.HealthResponseH\0\
+    R\x06health\x12P\n\x13acknowledgeMatching\x18\x08\x20\x01(\x0b2\x1c.Ackn\
+    owledgeMatchingResponseH\0R\x13acknowledgeMatching\x12>\n\rfailWithRetag\
+    \x18\t\x20\x01(\x0b2\x16.FailWithRetagResponseH\0R\rfailWithRetag\x125\n\
+    \nserverInfo\x18\x0b\x20\x01(\x0b2\x13.ServerInfoResponseH\0R\nserverInf\
+    o\x12#\n\x04fail\x18\x0c\x20\x01(\x0b2\r.FailResponseH\0R\x04fail\x12A\n\
+    \x0ediagnosticDump\x18\r\x20\x01(\x0b2\x17.DiagnosticDumpResponseH\0R\
+    \x0ediagnosticDump\x12;\n\x0cbeginEnqueue\x18\x0e\x20\x01(\x0b2\x15.Begi\
+    nEnqueueResponseH\0R\x0cbeginEnqueue\x12;\n\x0cenqueueChunk\x18\x0f\x20\
+    \x01(\x0b2\x15.EnqueueChunkResponseH\0R\x0cenqueueChunk\x12>\n\rcommitEn\
+    queue\x18\x10\x20\x01(\x0b2\x16.CommitEnqueueResponseH\0R\rcommitEnqueue\
+    \x122\n\tcancelPop\x18\x11\x20\x01(\x0b2\x12.CancelPopResponseH\0R\tcanc\
+    elPop\x12)\n\x06cancel\x18\x12\x20\x01(\x0b2\x0f.CancelResponseH\0R\x06c\
+    ancel\x122\n\tfindByTag\x18\x13\x20\x01(\x0b2\x12.FindByTagResponseH\0R\
+    \tfindByTag\x12A\n\x0elistProcessing\x18\x14\x20\x01(\x0b2\x17.ListProce\
+    ssingResponseH\0R\x0elistProcessing\x12P\n\x13setCapabilityPaused\x18\
+    \x15\x20\x01(\x0b2\x1c.SetCapabilityPausedResponseH\0R\x13setCapabilityP\
+    aused\x122\n\tsubscribe\x18\x16\x20\x01(\x0b2\x12.SubscribeResponseH\0R\
+    \tsubscribe\x12,\n\x07reserve\x18\x17\x20\x01(\x0b2\x10.ReserveResponseH\
+    \0R\x07reserve\x12M\n\x12confirmReservation\x18\x18\x20\x01(\x0b2\x1b.Co\
+    nfirmReservationResponseH\0R\x12confirmReservation\x12,\n\x07decline\x18\
+    \x19\x20\x01(\x0b2\x10.DeclineResponseH\0R\x07decline\x12;\n\x0cenqueueB\
+    atch\x18\x1a\x20\x01(\x0b2\x15.EnqueueBatchResponseH\0R\x0cenqueueBatch\
+    \x125\n\nlistQueued\x18\x1b\x20\x01(\x0b2\x13.ListQueuedResponseH\0R\nli\
+    stQueued\x12#\n\x04peek\x18\x1c\x20\x01(\x0b2\r.PeekResponseH\0R\x04peek\
+    \x12&\n\x05stats\x18\x1d\x20\x01(\x0b2\x0e.StatsResponseH\0R\x05stats\
+    \x12&\n\x05purge\x18\x1e\x20\x01(\x0b2\x0e.PurgeResponseH\0R\x05purge\
+    \x12A\n\x0eeventSubscribe\x18\x1f\x20\x01(\x0b2\x17.EventSubscribeRespon\
+    seH\0R\x0eeventSubscribe\x12+\n\x05event\x18\x20\x20\x01(\x0b2\x13.Queue\
+    EventResponseH\0R\x05event\x12J\n\x11exportDeadLetters\x18!\x20\x01(\x0b\
+    2\x1a.ExportDeadLettersResponseH\0R\x11exportDeadLettersB\t\n\x07message\
+    *\x1d\n\x08Priority\x12\x07\n\x03LOW\x10\0\x12\x08\n\x04HIGH\x10\x01*0\n\
+    \rEnqueueStatus\x12\x0b\n\x07CREATED\x10\0\x12\x12\n\x0eALREADY_EXISTS\
+    \x10\x01*\x20\n\x0cTagMatchMode\x12\x07\n\x03ALL\x10\0\x12\x07\n\x03ANY\
+    \x10\x01*V\n\tEventKind\x12\x0c\n\x08ENQUEUED\x10\0\x12\n\n\x06POPPED\
+    \x10\x01\x12\x10\n\x0cACKNOWLEDGED\x10\x02\x12\n\n\x06FAILED\x10\x03\x12\
+    \x11\n\rDEAD_LETTERED\x10\x04*J\n\tErrorCode\x12\x0b\n\x07UNKNOWN\x10\0\
+    \x12\x10\n\x0cSTORAGE_FULL\x10\x01\x12\x0f\n\x0bBAD_REQUEST\x10\x02\x12\
+    \r\n\tNOT_FOUND\x10\x03B\x13Z\x07brqueue\xaa\x02\x07brqueueb\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
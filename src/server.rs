@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::io::{Error as IOError, ErrorKind};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{Receiver, RecvTimeoutError};
+
+use crate::authentication::AuthBackend;
+use crate::client;
+use crate::config::Config;
+use crate::queue_server::QueueServer;
+use crate::queues::NamedQueues;
+use crate::rate_limit::ConnectionLimiter;
+use crate::session::Sessions;
+use crate::transfer::Transfers;
+
+// How long to wait for a shutdown signal between checking for new connections
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+// How often the connections are checked while waiting for them to finish on shutdown
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Accepts connections, and handles each of them on its own thread
+pub struct Server {
+    queue_server: QueueServer<Vec<u8>>,
+    auth: Arc<dyn AuthBackend>,
+    sessions: Sessions,
+    transfers: Transfers,
+    config: Config,
+    // Only the default queue can be used if not set
+    named_queues: Option<NamedQueues<Vec<u8>>>,
+    // The connections that are being handled, so they can be closed on shutdown
+    connections: Arc<Mutex<HashMap<u64, TcpStream>>>,
+    next_connection_id: AtomicU64,
+}
+
+impl Server {
+    pub fn new(queue_server: QueueServer<Vec<u8>>, auth: Arc<dyn AuthBackend>, sessions: Sessions, transfers: Transfers, config: Config) -> Server {
+        Server {
+            queue_server,
+            auth,
+            sessions,
+            transfers,
+            config,
+            named_queues: None,
+            connections: Arc::new(Mutex::new(HashMap::new())),
+            next_connection_id: AtomicU64::new(0),
+        }
+    }
+
+    // Lets clients use other queues than the default one
+    pub fn with_named_queues(mut self, named_queues: NamedQueues<Vec<u8>>) -> Server {
+        self.named_queues = Some(named_queues);
+        self
+    }
+
+    // Accepts connections until something is sent on the shutdown channel,
+    // or the sending side is dropped. The listener is closed when this returns,
+    // and the accepted connections have been closed, see close_connections.
+    pub fn run(&self, listener: TcpListener, shutdown: Receiver<()>) -> Result<(), IOError> {
+        let mut connection_limiter = if self.config.connection_rate_per_second > 0.0 {
+            Some(ConnectionLimiter::new(self.config.connection_rate_per_second, self.config.connection_burst))
+        } else {
+            None
+        };
+
+        // Never blocks, so the shutdown channel can be checked between connections
+        listener.set_nonblocking(true)?;
+
+        loop {
+            match listener.accept() {
+                Ok((stream, address)) => {
+                    if let Some(limiter) = &mut connection_limiter {
+                        if !limiter.allow(address.ip(), Instant::now()) {
+                            eprintln!("Refusing connection from {}, too many connections", address);
+                            // Dropping the stream closes the connection
+                            continue;
+                        }
+                    }
+                    // Accepted streams inherit the non-blocking mode on some platforms
+                    match stream.set_nonblocking(false) {
+                        Ok(()) => self.handle_connection(stream),
+                        Err(e) => eprintln!("Stream failed: {}", e),
+                    }
+                    continue;
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => eprintln!("Stream failed: {}", e),
+            }
+
+            match shutdown.recv_timeout(ACCEPT_POLL_INTERVAL) {
+                Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+        }
+
+        drop(listener);
+        self.close_connections();
+        Ok(())
+    }
+
+    // Stops reading requests from the accepted connections, and waits for the requests
+    // being handled to finish, so nothing changes the queues while they are being saved.
+    // Waiting pops are cancelled. Gives up once the shutdown timeout has passed.
+    fn close_connections(&self) {
+        let deadline = Instant::now() + self.config.shutdown_timeout;
+        if let Ok(connections) = self.connections.lock() {
+            for stream in connections.values() {
+                // Replies can still be sent for the requests that are being handled
+                let _ = stream.shutdown(Shutdown::Read);
+            }
+        }
+
+        loop {
+            // A request that was read just before might have started waiting since
+            self.sessions.cancel_all_waiting_pops();
+            let running = match self.connections.lock() {
+                Ok(connections) => connections.len(),
+                Err(_) => return,
+            };
+            if running == 0 {
+                return;
+            }
+            if Instant::now() >= deadline {
+                eprintln!("{} connections are still running after the shutdown timeout", running);
+                return;
+            }
+            thread::sleep(DRAIN_POLL_INTERVAL);
+        }
+    }
+
+    fn handle_connection(&self, s: TcpStream) {
+        let qs = self.queue_server.clone();
+        let auth = self.auth.clone();
+        let sessions = self.sessions.clone();
+        let transfers = self.transfers.clone();
+        let max_response_tags = self.config.max_response_tags;
+        let concurrency = self.config.connection_concurrency;
+        let pop_rate_per_second = self.config.pop_rate_per_second;
+        let pop_burst = self.config.pop_burst;
+        let named_queues = self.named_queues.clone();
+
+        let id = self.next_connection_id.fetch_add(1, Ordering::SeqCst);
+        match (s.try_clone(), self.connections.lock()) {
+            (Ok(stream), Ok(mut connections)) => {
+                connections.insert(id, stream);
+            }
+            _ => {
+                eprintln!("Failed to keep track of connection, closing it");
+                return;
+            }
+        }
+        let connections = self.connections.clone();
+        thread::spawn(move || {
+            let mut c = client::Client::new(qs, auth, sessions, transfers)
+                .with_max_response_tags(max_response_tags)
+                .with_concurrency(concurrency);
+            if pop_rate_per_second > 0.0 {
+                c = c.with_pop_rate_limit(pop_rate_per_second, pop_burst);
+            }
+            if let Some(named_queues) = named_queues {
+                c = c.with_named_queues(named_queues);
+            }
+            c.handle_connection(s);
+            if let Ok(mut connections) = connections.lock() {
+                connections.remove(&id);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use crossbeam::channel::bounded;
+    use protobuf::Message;
+
+    use crate::authentication::Authentication;
+    use crate::binary::get_size_array;
+    use crate::rpc;
+
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    #[test]
+    fn run_returns_and_releases_the_port_on_shutdown() {
+        let root = setup_test_storage().unwrap();
+        let qs = QueueServer::new_with_filename(format!("{}test", root)).expect("Failed to create queue server");
+        let auth = Authentication::new(PathBuf::from(format!("{}users", root))).expect("Failed to create authentication");
+        let transfers = Transfers::new(PathBuf::from(format!("{}transfers", root))).expect("Failed to create transfers");
+        let server = Server::new(qs, Arc::new(auth), Sessions::new(Duration::from_millis(0)), transfers, Config::default());
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (stop, shutdown) = bounded(1);
+        let handle = thread::spawn(move || server.run(listener, shutdown));
+
+        let _client = TcpStream::connect(address).expect("Failed to connect");
+
+        stop.send(()).unwrap();
+        handle.join().unwrap().expect("Server failed");
+        TcpListener::bind(address).expect("The port was not released");
+    }
+
+    fn send_request(s: &mut TcpStream, message: rpc::RequestWrapper) {
+        let mut data = message.write_to_bytes().unwrap();
+        let mut size = get_size_array(data.len() as i32).unwrap();
+        size.append(&mut data);
+        s.write_all(&size).unwrap();
+    }
+
+    #[test]
+    fn run_waits_for_the_connections_to_finish_on_shutdown() {
+        let root = setup_test_storage().unwrap();
+        let qs = QueueServer::new_with_filename(format!("{}test", root)).expect("Failed to create queue server");
+        let mut auth = Authentication::new(PathBuf::from(format!("{}users", root))).expect("Failed to create authentication");
+        auth.add_user("worker".to_string(), "pw".to_string()).unwrap();
+        let transfers = Transfers::new(PathBuf::from(format!("{}transfers", root))).expect("Failed to create transfers");
+        let server = Server::new(qs.clone(), Arc::new(auth), Sessions::new(Duration::from_millis(0)), transfers, Config::default());
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (stop, shutdown) = bounded(1);
+        let handle = thread::spawn(move || server.run(listener, shutdown));
+
+        let mut worker = TcpStream::connect(address).expect("Failed to connect");
+        let mut authenticate = rpc::AuthenticateRequest::new();
+        authenticate.set_username("worker".to_string());
+        authenticate.set_password("pw".to_string());
+        let mut request = rpc::RequestWrapper::new();
+        request.set_authenticate(authenticate);
+        send_request(&mut worker, request);
+        let mut pop = rpc::PopRequest::new();
+        pop.set_waitForMessage(true);
+        let mut request = rpc::RequestWrapper::new();
+        request.set_pop(pop);
+        send_request(&mut worker, request);
+        while qs.waiting_pops() == 0 {
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        stop.send(()).unwrap();
+        handle.join().unwrap().expect("Server failed");
+        // The connection is done before run returns, so it can't change the queue while it's saved
+        assert_eq!(qs.waiting_pops(), 0);
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{bounded, Receiver, Sender};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::queue_server::QueueServer;
+
+// The tasks of a dropped connection, waiting for the worker to come back.
+// Every task is kept with the name of the queue it was popped from.
+struct PendingSession {
+    // Only the same user can take over the session
+    username: Option<String>,
+    tasks: HashMap<Uuid, String>,
+    deadline: Instant,
+}
+
+// A pop waiting for a task, that can be cancelled from another connection of the same user
+struct WaitingPop {
+    username: Option<String>,
+    cancel: Sender<()>,
+}
+
+// Returned when cancelling a waiting pop started by another user
+#[derive(Debug)]
+pub struct NotOwner;
+
+// Keeps track of the tasks of workers that have lost their connection,
+// so they can pick up their work again if they reconnect quickly enough.
+// If the worker doesn't come back within the grace period the tasks are failed,
+// and thereby put back in the queue.
+#[derive(Clone)]
+pub struct Sessions {
+    grace_period: Duration,
+    pending: Arc<Mutex<HashMap<String, PendingSession>>>,
+    // The waiting pops of the connected workers, so they can be
+    // cancelled from another connection
+    waiting_pops: Arc<Mutex<HashMap<String, WaitingPop>>>,
+}
+
+impl Sessions {
+    pub fn new(grace_period: Duration) -> Sessions {
+        Sessions {
+            grace_period,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            waiting_pops: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Generates a new token a worker can use to identify itself when reconnecting
+    pub fn new_token() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    // Marks the tasks of the given session as pending re-enqueue.
+    // The tasks are failed once the grace period runs out, unless
+    // the session has been resumed in the meantime.
+    // queue_of finds the queue with the given name, to fail the tasks in.
+    pub fn disconnect<T, F>(&self, token: String, username: Option<String>, tasks: HashMap<Uuid, String>, queue_of: F)
+        where T: 'static + Send + Sync + Clone + Serialize + DeserializeOwned,
+              F: Fn(&str) -> Option<QueueServer<T>> + Send + 'static {
+        if tasks.is_empty() {
+            return;
+        }
+
+        if self.grace_period == Duration::from_millis(0) {
+            fail_tasks(&queue_of, &token, &tasks);
+            return;
+        }
+
+        let deadline = Instant::now() + self.grace_period;
+
+        match self.pending.lock() {
+            Ok(mut pending) => {
+                pending.insert(token.clone(), PendingSession { username, tasks, deadline });
+            }
+            Err(_) => {
+                eprintln!("Session mutex corrupted, failing tasks right away");
+                fail_tasks(&queue_of, &token, &tasks);
+                return;
+            }
+        }
+
+        let pending = self.pending.clone();
+        let grace_period = self.grace_period;
+        thread::spawn(move || {
+            thread::sleep(grace_period);
+
+            let expired = match pending.lock() {
+                Ok(mut pending) => {
+                    // The session might have been resumed, and dropped again since
+                    // in which case the newer disconnect is responsible for the tasks
+                    let is_expired = match pending.get(&token) {
+                        Some(session) => session.deadline <= Instant::now(),
+                        None => false,
+                    };
+                    if is_expired {
+                        pending.remove(&token)
+                    } else {
+                        None
+                    }
+                }
+                Err(_) => {
+                    eprintln!("Session mutex corrupted, can't expire session");
+                    None
+                }
+            };
+
+            if let Some(session) = expired {
+                fail_tasks(&queue_of, &token, &session.tasks);
+            }
+        });
+    }
+
+    // Resumes the given session if it's still within the grace period,
+    // and it was started by the same user.
+    // Returns the tasks the worker was processing before it disconnected,
+    // with the queues they are from.
+    pub fn reconnect(&self, token: &str, username: Option<&str>) -> Option<HashMap<Uuid, String>> {
+        let mut pending = match self.pending.lock() {
+            Ok(pending) => pending,
+            Err(_) => return None,
+        };
+
+        match pending.get(token) {
+            Some(session) if session.deadline > Instant::now() && session.username.as_deref() == username => {}
+            _ => return None,
+        }
+
+        pending.remove(token).map(|session| session.tasks)
+    }
+
+    // Starts a waiting pop for the session of the given user.
+    // The returned receiver is signalled if the pop is cancelled.
+    pub fn begin_waiting_pop(&self, token: &str, username: Option<String>) -> Receiver<()> {
+        let (cancel, receiver) = bounded(1);
+        match self.waiting_pops.lock() {
+            Ok(mut waiting_pops) => {
+                waiting_pops.insert(token.to_string(), WaitingPop { username, cancel });
+            }
+            Err(_) => eprintln!("Session mutex corrupted, pop can't be cancelled"),
+        }
+        receiver
+    }
+
+    pub fn end_waiting_pop(&self, token: &str) {
+        if let Ok(mut waiting_pops) = self.waiting_pops.lock() {
+            waiting_pops.remove(token);
+        }
+    }
+
+    // Cancels every waiting pop, so the connections can finish when the server shuts down
+    pub fn cancel_all_waiting_pops(&self) {
+        if let Ok(mut waiting_pops) = self.waiting_pops.lock() {
+            for (_, pop) in waiting_pops.drain() {
+                let _ = pop.cancel.try_send(());
+            }
+        }
+    }
+
+    // Cancels the waiting pop of the given session, if it was started by the same user.
+    // Returns false if the session isn't waiting for a message.
+    pub fn cancel_waiting_pop(&self, token: &str, username: Option<&str>) -> Result<bool, NotOwner> {
+        let mut waiting_pops = match self.waiting_pops.lock() {
+            Ok(waiting_pops) => waiting_pops,
+            Err(_) => return Ok(false),
+        };
+        match waiting_pops.get(token) {
+            Some(pop) if pop.username.as_deref() != username => return Err(NotOwner),
+            Some(_) => {}
+            None => return Ok(false),
+        }
+        match waiting_pops.remove(token) {
+            Some(pop) => Ok(pop.cancel.try_send(()).is_ok()),
+            None => Ok(false),
+        }
+    }
+}
+
+// The tasks are popped with the session token as the worker,
+// so tasks that have since been handed to another worker are left alone
+fn fail_tasks<T, F>(queue_of: &F, token: &str, tasks: &HashMap<Uuid, String>)
+    where T: 'static + Send + Clone + Serialize + DeserializeOwned,
+          F: Fn(&str) -> Option<QueueServer<T>> {
+    for (id, queue_name) in tasks {
+        let mut queue_server = match queue_of(queue_name) {
+            Some(queue_server) => queue_server,
+            None => {
+                eprintln!("Failed to fail task {}, queue '{}' is not available", id, queue_name);
+                continue;
+            }
+        };
+        if let Err(e) = queue_server.fail_as_worker(*id, Some(token)) {
+            eprintln!("Failed to fail task: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossbeam::channel::never;
+
+    use crate::models::Priority;
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    fn setup() -> QueueServer<String> {
+        let storage_path = format!("{}test", setup_test_storage().unwrap());
+        QueueServer::new_with_filename(storage_path).expect("Failed to create queue server")
+    }
+
+    fn pop_task(qs: &mut QueueServer<String>, token: &str) -> HashMap<Uuid, String> {
+        qs.enqueue("foo".to_string(), Priority::High, vec![])
+            .expect("Failed to enqueue task");
+        let item = qs.pop_as_worker(Some(token), vec![], false, &never()).unwrap().expect("No item received");
+
+        let mut tasks = HashMap::new();
+        tasks.insert(item.id, String::new());
+        tasks
+    }
+
+    fn only(qs: &QueueServer<String>) -> impl Fn(&str) -> Option<QueueServer<String>> {
+        let qs = qs.clone();
+        move |_| Some(qs.clone())
+    }
+
+    #[test]
+    fn quick_reconnect_keeps_tasks() {
+        let mut qs = setup();
+        let sessions = Sessions::new(Duration::from_millis(200));
+        let token = Sessions::new_token();
+        let tasks = pop_task(&mut qs, &token);
+
+        sessions.disconnect(token.clone(), Some("worker".to_string()), tasks.clone(), only(&qs));
+
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(sessions.reconnect(&token, Some("worker")), Some(tasks));
+
+        // Wait for the grace period to pass, the task should still not be requeued
+        thread::sleep(Duration::from_millis(300));
+        assert!(qs.pop(vec![], false).unwrap().is_none());
+    }
+
+    #[test]
+    fn only_the_same_user_can_reconnect() {
+        let mut qs = setup();
+        let sessions = Sessions::new(Duration::from_millis(200));
+        let token = Sessions::new_token();
+        let tasks = pop_task(&mut qs, &token);
+
+        sessions.disconnect(token.clone(), Some("worker".to_string()), tasks.clone(), only(&qs));
+
+        assert_eq!(sessions.reconnect(&token, Some("intruder")), None);
+        assert_eq!(sessions.reconnect(&token, None), None);
+        // The session is still there for the worker that started it
+        assert_eq!(sessions.reconnect(&token, Some("worker")), Some(tasks));
+    }
+
+    #[test]
+    fn can_cancel_waiting_pop() {
+        let sessions = Sessions::new(Duration::from_millis(0));
+        let token = Sessions::new_token();
+
+        assert!(!sessions.cancel_waiting_pop(&token, None).unwrap());
+
+        let cancelled = sessions.begin_waiting_pop(&token, None);
+        assert!(sessions.cancel_waiting_pop(&token, None).unwrap());
+        assert!(cancelled.try_recv().is_ok());
+
+        // Once the pop is over there is nothing to cancel
+        let _cancelled = sessions.begin_waiting_pop(&token, None);
+        sessions.end_waiting_pop(&token);
+        assert!(!sessions.cancel_waiting_pop(&token, None).unwrap());
+    }
+
+    #[test]
+    fn only_the_same_user_can_cancel_waiting_pop() {
+        let sessions = Sessions::new(Duration::from_millis(0));
+        let token = Sessions::new_token();
+
+        let cancelled = sessions.begin_waiting_pop(&token, Some("worker".to_string()));
+        assert!(sessions.cancel_waiting_pop(&token, Some("intruder")).is_err());
+        assert!(sessions.cancel_waiting_pop(&token, None).is_err());
+        assert!(cancelled.try_recv().is_err());
+
+        assert!(sessions.cancel_waiting_pop(&token, Some("worker")).unwrap());
+        assert!(cancelled.try_recv().is_ok());
+    }
+
+    #[test]
+    fn slow_reconnect_requeues_tasks() {
+        let mut qs = setup();
+        let sessions = Sessions::new(Duration::from_millis(50));
+        let token = Sessions::new_token();
+        let tasks = pop_task(&mut qs, &token);
+
+        sessions.disconnect(token.clone(), Some("worker".to_string()), tasks.clone(), only(&qs));
+
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(sessions.reconnect(&token, Some("worker")), None);
+
+        let item = qs.pop(vec![], false).unwrap().expect("Task was not requeued");
+        assert!(tasks.contains_key(&item.id));
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use crossbeam::channel::{bounded, Receiver};
+
+// How often the flag set by the signal handler is checked
+const SIGNAL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Set by the signal handler. Hardly anything is safe to do inside a signal handler,
+// so it only sets the flag, and the rest is done by the thread watching it.
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+// Sends on the returned channel once the process is asked to stop with SIGINT or SIGTERM,
+// so the server can stop accepting connections and save everything before exiting.
+pub fn on_signal() -> Receiver<()> {
+    install_handlers();
+
+    let (sender, receiver) = bounded(1);
+    thread::spawn(move || {
+        while !SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+            thread::sleep(SIGNAL_POLL_INTERVAL);
+        }
+        // Nobody is listening if the server has already stopped
+        let _ = sender.send(());
+    });
+    receiver
+}
+
+#[cfg(unix)]
+fn install_handlers() {
+    extern "C" fn request_shutdown(_signal: libc::c_int) {
+        SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+    }
+
+    unsafe {
+        libc::signal(libc::SIGINT, request_shutdown as *const () as libc::sighandler_t);
+        libc::signal(libc::SIGTERM, request_shutdown as *const () as libc::sighandler_t);
+    }
+}
+
+// Other platforms keep stopping the process right away
+#[cfg(not(unix))]
+fn install_handlers() {}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sigterm_requests_a_shutdown() {
+        let shutdown = on_signal();
+        assert!(shutdown.try_recv().is_err());
+
+        unsafe {
+            libc::raise(libc::SIGTERM);
+        }
+        shutdown.recv_timeout(Duration::from_secs(5)).expect("No shutdown was requested");
+    }
+}
@@ -0,0 +1,14 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Converts the duration to whole milliseconds
+pub fn duration_to_millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + u64::from(duration.subsec_millis())
+}
+
+// Milliseconds since the unix epoch
+pub fn unix_timestamp_millis() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration_to_millis(duration),
+        Err(_) => 0,
+    }
+}
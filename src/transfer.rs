@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::convert;
+use std::fmt;
+use std::fs::{create_dir_all, File, remove_dir_all, remove_file};
+use std::io::{BufWriter, Read, Write};
+use std::io::Error as IOError;
+use std::io::ErrorKind as IOErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use log::error;
+use uuid::Uuid;
+
+use crate::models::Priority;
+
+// How often abandoned transfers are looked for, at most
+const EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(IOError),
+    UnknownTransfer,
+    UnexpectedOffset { expected: u64, received: u64 },
+    // The payload would be larger than the maximum size
+    TooLarge(u64),
+    MutexCorrupted,
+}
+
+impl convert::From<IOError> for Error {
+    fn from(e: IOError) -> Self {
+        Error::IOError(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IOError(e) => write!(f, "Failed to write transfer: {}", e),
+            Error::UnknownTransfer => write!(f, "Unknown transfer"),
+            Error::UnexpectedOffset { expected, received } => {
+                write!(f, "Expected chunk at offset {}, got offset {}", expected, received)
+            }
+            Error::TooLarge(max) => write!(f, "Payload is larger than the maximum of {} bytes", max),
+            Error::MutexCorrupted => write!(f, "Transfer mutex corrupted"),
+        }
+    }
+}
+
+// A payload that is being received in chunks
+struct Transfer {
+    // The user that began the transfer, only they can add to it or commit it
+    owner: Option<String>,
+    priority: Priority,
+    required_capabilities: Vec<String>,
+    available_at: Option<SystemTime>,
+    expires_at: Option<SystemTime>,
+    // The chunks are written to disk as they arrive, so huge
+    // payloads don't have to be kept in memory while they are received
+    file: BufWriter<File>,
+    path: PathBuf,
+    // How many bytes has been received so far
+    received: u64,
+    last_activity: Instant,
+    // Set once the transfer has been committed or has expired,
+    // in case a chunk was on its way in at the same time
+    closed: bool,
+}
+
+// A payload that has been fully received, and is ready to be enqueued
+pub struct CompletedTransfer {
+    pub data: Vec<u8>,
+    pub priority: Priority,
+    pub required_capabilities: Vec<String>,
+    pub available_at: Option<SystemTime>,
+    pub expires_at: Option<SystemTime>,
+}
+
+// Keeps track of the payloads that are currently being transferred in chunks.
+// The transfers are shared between connections, so a client that loses the
+// connection can resume the transfer from the last received offset.
+#[derive(Clone)]
+pub struct Transfers {
+    directory: PathBuf,
+    // Every transfer has its own lock, so chunks are written without holding up the other transfers
+    transfers: Arc<Mutex<HashMap<Uuid, Arc<Mutex<Transfer>>>>>,
+    // How large a payload can be. Unlimited if zero.
+    max_bytes: u64,
+}
+
+impl Transfers {
+    pub fn new(directory: PathBuf) -> Result<Transfers, Error> {
+        // Transfers doesn't survive a restart, so anything left is garbage
+        match remove_dir_all(&directory) {
+            Ok(()) => {}
+            Err(ref e) if e.kind() == IOErrorKind::NotFound => {}
+            Err(e) => return Err(Error::IOError(e)),
+        }
+        create_dir_all(&directory)?;
+
+        Ok(Transfers {
+            directory,
+            transfers: Arc::new(Mutex::new(HashMap::new())),
+            max_bytes: 0,
+        })
+    }
+
+    // Rejects chunks that would make the payload larger than max_bytes
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Transfers {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    // Deletes transfers that haven't received anything for the given time,
+    // so transfers abandoned by their client don't take up disk space until a restart
+    pub fn with_idle_timeout(self, idle_timeout: Duration) -> Transfers {
+        let transfers = Arc::downgrade(&self.transfers);
+        thread::spawn(move || {
+            loop {
+                thread::sleep(idle_timeout.min(EXPIRY_SWEEP_INTERVAL));
+
+                let transfers = match transfers.upgrade() {
+                    Some(transfers) => transfers,
+                    None => return,
+                };
+                if let Err(e) = remove_idle(&transfers, idle_timeout) {
+                    error!("Failed to remove abandoned transfers: {}", e);
+                }
+            }
+        });
+        self
+    }
+
+    // Starts a new transfer, returns the id the chunks should be sent to
+    pub fn begin(
+        &self,
+        owner: Option<String>,
+        priority: Priority,
+        required_capabilities: Vec<String>,
+        available_at: Option<SystemTime>,
+        expires_at: Option<SystemTime>,
+    ) -> Result<Uuid, Error> {
+        let id = Uuid::new_v4();
+        let path = self.directory.join(id.to_string());
+        let file = BufWriter::new(File::create(&path)?);
+
+        let transfer = Transfer {
+            owner,
+            priority,
+            required_capabilities,
+            available_at,
+            expires_at,
+            file,
+            path,
+            received: 0,
+            last_activity: Instant::now(),
+            closed: false,
+        };
+        match self.transfers.lock() {
+            Ok(mut transfers) => {
+                transfers.insert(id, Arc::new(Mutex::new(transfer)));
+                Ok(id)
+            }
+            Err(_) => Err(Error::MutexCorrupted),
+        }
+    }
+
+    // The transfer, if it exists and belongs to the owner.
+    // Other users are told it doesn't exist, so they can't find the ids of other transfers.
+    fn find(&self, id: &Uuid, owner: Option<&str>) -> Result<Arc<Mutex<Transfer>>, Error> {
+        let transfer = match self.transfers.lock() {
+            Ok(transfers) => match transfers.get(id) {
+                Some(transfer) => transfer.clone(),
+                None => return Err(Error::UnknownTransfer),
+            },
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        let is_owner = match transfer.lock() {
+            Ok(transfer) => transfer.owner.as_deref() == owner,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        if is_owner {
+            Ok(transfer)
+        } else {
+            Err(Error::UnknownTransfer)
+        }
+    }
+
+    // Appends a chunk to the transfer. The offset has to match the number of
+    // bytes received so far, so chunks are never lost or applied twice.
+    // Returns the total number of bytes received.
+    pub fn append(&self, id: &Uuid, owner: Option<&str>, offset: u64, data: &[u8]) -> Result<u64, Error> {
+        let transfer = self.find(id, owner)?;
+        let mut transfer = match transfer.lock() {
+            Ok(transfer) => transfer,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        if transfer.closed {
+            return Err(Error::UnknownTransfer);
+        }
+
+        if transfer.received != offset {
+            return Err(Error::UnexpectedOffset { expected: transfer.received, received: offset });
+        }
+        if self.max_bytes > 0 && transfer.received + data.len() as u64 > self.max_bytes {
+            return Err(Error::TooLarge(self.max_bytes));
+        }
+
+        transfer.file.write_all(data)?;
+        transfer.received += data.len() as u64;
+        transfer.last_activity = Instant::now();
+
+        Ok(transfer.received)
+    }
+
+    // Finishes the transfer, and returns the complete payload
+    pub fn commit(&self, id: &Uuid, owner: Option<&str>) -> Result<CompletedTransfer, Error> {
+        let transfer = self.find(id, owner)?;
+        match self.transfers.lock() {
+            Ok(mut transfers) => {
+                if transfers.remove(id).is_none() {
+                    // Committed by another connection in the meantime
+                    return Err(Error::UnknownTransfer);
+                }
+            }
+            Err(_) => return Err(Error::MutexCorrupted),
+        }
+
+        let mut transfer = match transfer.lock() {
+            Ok(transfer) => transfer,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        transfer.closed = true;
+        transfer.file.flush()?;
+
+        let mut data = Vec::with_capacity(transfer.received as usize);
+        File::open(&transfer.path)?.read_to_end(&mut data)?;
+        remove_file(&transfer.path)?;
+
+        Ok(CompletedTransfer {
+            data,
+            priority: transfer.priority.clone(),
+            required_capabilities: transfer.required_capabilities.clone(),
+            available_at: transfer.available_at,
+            expires_at: transfer.expires_at,
+        })
+    }
+}
+
+// Removes the transfers that haven't received anything within the idle timeout, and deletes their files
+fn remove_idle(transfers: &Mutex<HashMap<Uuid, Arc<Mutex<Transfer>>>>, idle_timeout: Duration) -> Result<(), Error> {
+    let now = Instant::now();
+    let idle: Vec<Arc<Mutex<Transfer>>> = match transfers.lock() {
+        Ok(mut transfers) => {
+            let ids: Vec<Uuid> = transfers
+                .iter()
+                .filter(|(_, transfer)| match transfer.try_lock() {
+                    Ok(transfer) => now.duration_since(transfer.last_activity) >= idle_timeout,
+                    // Busy receiving a chunk
+                    Err(_) => false,
+                })
+                .map(|(id, _)| *id)
+                .collect();
+            ids.iter().filter_map(|id| transfers.remove(id)).collect()
+        }
+        Err(_) => return Err(Error::MutexCorrupted),
+    };
+
+    for transfer in idle {
+        let mut transfer = match transfer.lock() {
+            Ok(transfer) => transfer,
+            Err(_) => return Err(Error::MutexCorrupted),
+        };
+        transfer.closed = true;
+        match remove_file(&transfer.path) {
+            Ok(()) => {}
+            Err(ref e) if e.kind() == IOErrorKind::NotFound => {}
+            Err(e) => return Err(Error::IOError(e)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helpers::setup_test_storage;
+
+    use super::*;
+
+    fn setup() -> Transfers {
+        let root = setup_test_storage().unwrap();
+        Transfers::new(PathBuf::from(format!("{}transfers", root))).unwrap()
+    }
+
+    #[test]
+    fn assembles_chunks() {
+        let transfers = setup();
+
+        let id = transfers.begin(None, Priority::High, vec!["foo".to_string()], None, None).unwrap();
+        assert_eq!(transfers.append(&id, None, 0, b"foo").unwrap(), 3);
+        assert_eq!(transfers.append(&id, None, 3, b"bar").unwrap(), 6);
+
+        let completed = transfers.commit(&id, None).unwrap();
+        assert_eq!(completed.data, b"foobar".to_vec());
+        assert_eq!(completed.priority, Priority::High);
+        assert_eq!(completed.required_capabilities, vec!["foo".to_string()]);
+
+        // The transfer is gone once committed
+        match transfers.commit(&id, None) {
+            Err(Error::UnknownTransfer) => {}
+            _ => panic!("Transfer was committed twice"),
+        }
+    }
+
+    #[test]
+    fn rejects_chunks_at_wrong_offset() {
+        let transfers = setup();
+
+        let id = transfers.begin(None, Priority::Low, vec![], None, None).unwrap();
+        transfers.append(&id, None, 0, b"foo").unwrap();
+
+        match transfers.append(&id, None, 0, b"foo") {
+            Err(Error::UnexpectedOffset { expected: 3, received: 0 }) => {}
+            _ => panic!("Chunk was applied twice"),
+        }
+
+        // The transfer can be resumed from the right offset
+        transfers.append(&id, None, 3, b"bar").unwrap();
+        assert_eq!(transfers.commit(&id, None).unwrap().data, b"foobar".to_vec());
+    }
+
+    #[test]
+    fn only_the_owner_can_use_the_transfer() {
+        let transfers = setup();
+
+        let id = transfers.begin(Some("alice".to_string()), Priority::Low, vec![], None, None).unwrap();
+        for other in &[Some("bob"), None] {
+            match transfers.append(&id, *other, 0, b"foo") {
+                Err(Error::UnknownTransfer) => {}
+                _ => panic!("Chunk was added by another user"),
+            }
+            match transfers.commit(&id, *other) {
+                Err(Error::UnknownTransfer) => {}
+                _ => panic!("Transfer was committed by another user"),
+            }
+        }
+
+        transfers.append(&id, Some("alice"), 0, b"foo").unwrap();
+        assert_eq!(transfers.commit(&id, Some("alice")).unwrap().data, b"foo".to_vec());
+    }
+
+    #[test]
+    fn rejects_payloads_over_the_maximum_size() {
+        let transfers = setup().with_max_bytes(5);
+
+        let id = transfers.begin(None, Priority::Low, vec![], None, None).unwrap();
+        transfers.append(&id, None, 0, b"foo").unwrap();
+        match transfers.append(&id, None, 3, b"bar") {
+            Err(Error::TooLarge(5)) => {}
+            _ => panic!("Payload grew past the maximum size"),
+        }
+
+        transfers.append(&id, None, 3, b"ba").unwrap();
+        assert_eq!(transfers.commit(&id, None).unwrap().data, b"fooba".to_vec());
+    }
+
+    #[test]
+    fn idle_transfers_are_removed() {
+        let root = setup_test_storage().unwrap();
+        let directory = PathBuf::from(format!("{}transfers", root));
+        let transfers = Transfers::new(directory.clone()).unwrap().with_idle_timeout(Duration::from_millis(50));
+
+        let id = transfers.begin(None, Priority::Low, vec![], None, None).unwrap();
+        transfers.append(&id, None, 0, b"foo").unwrap();
+        assert!(directory.join(id.to_string()).exists());
+
+        thread::sleep(Duration::from_millis(200));
+        match transfers.append(&id, None, 3, b"bar") {
+            Err(Error::UnknownTransfer) => {}
+            _ => panic!("Idle transfer was kept"),
+        }
+        assert!(!directory.join(id.to_string()).exists());
+    }
+}
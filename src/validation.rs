@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+// Checks the payload of every enqueued task, so malformed tasks are rejected
+// when they are enqueued, instead of failing in the workers.
+// Returns a description of what is wrong with the payload if it's rejected.
+pub type PayloadValidator<T> = Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+// Only accepts payloads that are valid JSON
+pub fn json_payload(payload: &[u8]) -> Result<(), String> {
+    match serde_json::from_slice::<Value>(payload) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("Payload is not valid JSON: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_only_json() {
+        assert!(json_payload(b"{\"foo\": [1, 2]}").is_ok());
+        assert!(json_payload(b"{\"foo\": ").is_err());
+    }
+}